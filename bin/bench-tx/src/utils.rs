@@ -41,6 +41,8 @@ pub struct MeasurementsPrinter {
     note_execution: BTreeMap<String, usize>,
     tx_script_processing: usize,
     epilogue: usize,
+    account_commitment_cache_hits: usize,
+    account_commitment_cycles_saved: usize,
 }
 
 impl From<TransactionMeasurements> for MeasurementsPrinter {
@@ -54,6 +56,8 @@ impl From<TransactionMeasurements> for MeasurementsPrinter {
             note_execution: note_execution_map,
             tx_script_processing: value.tx_script_processing,
             epilogue: value.epilogue,
+            account_commitment_cache_hits: value.account_commitment_cache_hits,
+            account_commitment_cycles_saved: value.account_commitment_cycles_saved,
         }
     }
 }
@@ -1,5 +1,6 @@
 use std::{collections::BTreeMap, vec::Vec};
 
+use anyhow::Context;
 use assert_matches::assert_matches;
 use miden_objects::{
     MAX_BATCHES_PER_BLOCK, ProposedBlockError,
@@ -9,20 +10,22 @@ use miden_objects::{
     testing::account_id::ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET,
     transaction::ProvenTransaction,
 };
+use miden_tx::testing::WitnessCorruption;
 
 use crate::tests::utils::{
-    ProvenTransactionExt, TestSetup, generate_account, generate_batch,
+    ProvenTransactionExt, TestAccount, TestSetup, generate_account, generate_batch,
     generate_executed_tx_with_authenticated_notes, generate_fungible_asset, generate_output_note,
     generate_tracked_note, generate_tracked_note_with_asset, generate_tx_with_authenticated_notes,
     generate_tx_with_expiration, generate_tx_with_unauthenticated_notes, generate_untracked_note,
-    generate_untracked_note_with_output_note, setup_chain,
+    generate_untracked_note_with_output_note,
 };
 
 /// Tests that too many batches produce an error.
 #[test]
 fn proposed_block_fails_on_too_many_batches() -> anyhow::Result<()> {
     let count = MAX_BATCHES_PER_BLOCK;
-    let TestSetup { mut chain, accounts, mut txs, .. } = setup_chain(count);
+    let TestSetup { mut chain, accounts, mut txs, .. } =
+        TestSetup::builder().accounts(count, TestAccount::mock()).build();
 
     // At this time, MockChain won't let us build more than 64 transactions before sealing a block,
     // so we add one more tx manually.
@@ -56,7 +59,8 @@ fn proposed_block_fails_on_too_many_batches() -> anyhow::Result<()> {
 /// Tests that duplicate batches produce an error.
 #[test]
 fn proposed_block_fails_on_duplicate_batches() -> anyhow::Result<()> {
-    let TestSetup { mut chain, mut txs, .. } = setup_chain(1);
+    let TestSetup { mut chain, mut txs, .. } =
+        TestSetup::builder().accounts(1, TestAccount::mock()).build();
     let proven_tx0 = txs.remove(&0).unwrap();
     let batch0 = generate_batch(&mut chain, vec![proven_tx0]);
 
@@ -80,7 +84,8 @@ fn proposed_block_fails_on_duplicate_batches() -> anyhow::Result<()> {
 /// Tests that an expired batch produces an error.
 #[test]
 fn proposed_block_fails_on_expired_batches() -> anyhow::Result<()> {
-    let TestSetup { mut chain, mut accounts, .. } = setup_chain(2);
+    let TestSetup { mut chain, mut accounts, .. } =
+        TestSetup::builder().accounts(2, TestAccount::mock()).build();
     let block1_num = chain.block_header(1).block_num();
     let account0 = accounts.remove(&0).unwrap();
     let account1 = accounts.remove(&1).unwrap();
@@ -117,7 +122,8 @@ fn proposed_block_fails_on_expired_batches() -> anyhow::Result<()> {
 /// Tests that a timestamp at or before the previous block header produces an error.
 #[test]
 fn proposed_block_fails_on_timestamp_not_increasing_monotonically() -> anyhow::Result<()> {
-    let TestSetup { mut chain, mut txs, .. } = setup_chain(1);
+    let TestSetup { mut chain, mut txs, .. } =
+        TestSetup::builder().accounts(1, TestAccount::mock()).build();
     let proven_tx0 = txs.remove(&0).unwrap();
     let batch0 = generate_batch(&mut chain, vec![proven_tx0]);
     let batches = vec![batch0];
@@ -146,7 +152,8 @@ fn proposed_block_fails_on_timestamp_not_increasing_monotonically() -> anyhow::R
 /// Tests that a chain MMR that is not at the state of the previous block header produces an error.
 #[test]
 fn proposed_block_fails_on_chain_mmr_and_prev_block_inconsistency() -> anyhow::Result<()> {
-    let TestSetup { mut chain, mut txs, .. } = setup_chain(1);
+    let TestSetup { mut chain, mut txs, .. } =
+        TestSetup::builder().accounts(1, TestAccount::mock()).build();
     let proven_tx0 = txs.remove(&0).unwrap();
     let batch0 = generate_batch(&mut chain, vec![proven_tx0]);
     let batches = vec![batch0];
@@ -199,7 +206,8 @@ fn proposed_block_fails_on_chain_mmr_and_prev_block_inconsistency() -> anyhow::R
 /// error.
 #[test]
 fn proposed_block_fails_on_missing_batch_reference_block() -> anyhow::Result<()> {
-    let TestSetup { mut chain, mut txs, .. } = setup_chain(1);
+    let TestSetup { mut chain, mut txs, .. } =
+        TestSetup::builder().accounts(1, TestAccount::mock()).build();
     let proven_tx0 = txs.remove(&0).unwrap();
 
     // This batch will reference the latest block with number 1.
@@ -236,7 +244,8 @@ fn proposed_block_fails_on_missing_batch_reference_block() -> anyhow::Result<()>
 /// Tests that duplicate input notes across batches produce an error.
 #[test]
 fn proposed_block_fails_on_duplicate_input_note() -> anyhow::Result<()> {
-    let TestSetup { mut chain, mut accounts, .. } = setup_chain(2);
+    let TestSetup { mut chain, mut accounts, .. } =
+        TestSetup::builder().accounts(2, TestAccount::mock()).build();
 
     let account0 = accounts.remove(&0).unwrap();
     let account1 = accounts.remove(&1).unwrap();
@@ -270,7 +279,8 @@ fn proposed_block_fails_on_duplicate_input_note() -> anyhow::Result<()> {
 /// Tests that duplicate output notes across batches produce an error.
 #[test]
 fn proposed_block_fails_on_duplicate_output_note() -> anyhow::Result<()> {
-    let TestSetup { mut chain, mut accounts, .. } = setup_chain(1);
+    let TestSetup { mut chain, mut accounts, .. } =
+        TestSetup::builder().accounts(1, TestAccount::mock()).build();
     let account = accounts.remove(&0).unwrap();
 
     let output_note = generate_output_note(account.id(), [10; 32]);
@@ -311,7 +321,8 @@ fn proposed_block_fails_on_duplicate_output_note() -> anyhow::Result<()> {
 #[test]
 fn proposed_block_fails_on_invalid_proof_or_missing_note_inclusion_reference_block()
 -> anyhow::Result<()> {
-    let TestSetup { mut chain, mut accounts, .. } = setup_chain(2);
+    let TestSetup { mut chain, mut accounts, .. } =
+        TestSetup::builder().accounts(2, TestAccount::mock()).build();
 
     let account0 = accounts.remove(&0).unwrap();
     let account1 = accounts.remove(&1).unwrap();
@@ -385,7 +396,8 @@ fn proposed_block_fails_on_invalid_proof_or_missing_note_inclusion_reference_blo
 /// Tests that a missing note inclusion proof produces an error.
 #[test]
 fn proposed_block_fails_on_missing_note_inclusion_proof() -> anyhow::Result<()> {
-    let TestSetup { mut chain, mut accounts, .. } = setup_chain(2);
+    let TestSetup { mut chain, mut accounts, .. } =
+        TestSetup::builder().accounts(2, TestAccount::mock()).build();
 
     let account0 = accounts.remove(&0).unwrap();
     let account1 = accounts.remove(&1).unwrap();
@@ -411,7 +423,8 @@ fn proposed_block_fails_on_missing_note_inclusion_proof() -> anyhow::Result<()>
 /// Tests that a missing nullifier witness produces an error.
 #[test]
 fn proposed_block_fails_on_missing_nullifier_witness() -> anyhow::Result<()> {
-    let TestSetup { mut chain, mut accounts, .. } = setup_chain(2);
+    let TestSetup { mut chain, mut accounts, .. } =
+        TestSetup::builder().accounts(2, TestAccount::mock()).build();
 
     let account0 = accounts.remove(&0).unwrap();
     let account1 = accounts.remove(&1).unwrap();
@@ -450,7 +463,8 @@ fn proposed_block_fails_on_missing_nullifier_witness() -> anyhow::Result<()> {
 /// Tests that a nullifier witness pointing to a spent nullifier produces an error.
 #[test]
 fn proposed_block_fails_on_spent_nullifier_witness() -> anyhow::Result<()> {
-    let TestSetup { mut chain, mut accounts, .. } = setup_chain(2);
+    let TestSetup { mut chain, mut accounts, .. } =
+        TestSetup::builder().accounts(2, TestAccount::mock()).build();
     let account0 = accounts.remove(&0).unwrap();
     let account1 = accounts.remove(&1).unwrap();
 
@@ -466,17 +480,15 @@ fn proposed_block_fails_on_spent_nullifier_witness() -> anyhow::Result<()> {
     chain.add_pending_note(note0.clone());
     let _block2 = chain.seal_next_block();
 
-    // Create an alternative chain where we consume the note so it is marked as spent in the
-    // nullifier tree.
-    let mut alternative_chain = chain.clone();
-    let transaction = generate_executed_tx_with_authenticated_notes(
-        &mut alternative_chain,
-        account1.id(),
-        &[note0.id()],
-    );
-    alternative_chain.apply_executed_transaction(&transaction);
-    alternative_chain.seal_next_block();
-    let spent_proof = alternative_chain.nullifiers().open(&note0.nullifier().inner());
+    // Consume the note so it is marked as spent in the nullifier tree and restore the chain to the
+    // state before the note was consumed afterwards.
+    let snapshot = chain.snapshot();
+    let transaction =
+        generate_executed_tx_with_authenticated_notes(&mut chain, account1.id(), &[note0.id()]);
+    chain.apply_executed_transaction(&transaction);
+    chain.seal_next_block();
+    let spent_proof = chain.nullifiers().open(&note0.nullifier().inner());
+    chain.restore(snapshot);
 
     let batches = vec![batch0.clone()];
     let mut block_inputs = chain.get_block_inputs(&batches);
@@ -499,7 +511,8 @@ fn proposed_block_fails_on_spent_nullifier_witness() -> anyhow::Result<()> {
 /// batch IDs will be unique to avoid triggering the duplicate batches check.
 #[test]
 fn proposed_block_fails_on_conflicting_transactions_updating_same_account() -> anyhow::Result<()> {
-    let TestSetup { mut chain, mut accounts, mut txs, .. } = setup_chain(2);
+    let TestSetup { mut chain, mut accounts, mut txs, .. } =
+        TestSetup::builder().accounts(2, TestAccount::mock()).build();
 
     let account0 = accounts.remove(&0).unwrap();
     let account1 = accounts.remove(&1).unwrap();
@@ -539,10 +552,58 @@ fn proposed_block_fails_on_conflicting_transactions_updating_same_account() -> a
     Ok(())
 }
 
+/// Tests that the witness corruptions injected into the mock chain produce the corresponding
+/// errors.
+#[test]
+fn proposed_block_fails_on_corrupted_witnesses() -> anyhow::Result<()> {
+    let TestSetup { mut chain, mut accounts, .. } =
+        TestSetup::builder().accounts(2, TestAccount::mock()).build();
+    let account0 = accounts.remove(&0).unwrap();
+    let account1 = accounts.remove(&1).unwrap();
+
+    let note0 = generate_untracked_note(account0.id(), account1.id());
+    let tx0 = generate_tx_with_unauthenticated_notes(&mut chain, account1.id(), &[note0.clone()]);
+    let batch0 = generate_batch(&mut chain, vec![tx0]);
+
+    // Add the note to the chain so the unauthenticated note can be authenticated in the block.
+    chain.add_pending_note(note0.clone());
+    let block2 = chain.seal_next_block();
+
+    let batches = vec![batch0];
+    ProposedBlock::new(chain.get_block_inputs(&batches), batches.clone())
+        .context("block inputs without corruptions should be valid")?;
+
+    // Error: Stale account witness.
+    // --------------------------------------------------------------------------------------------
+
+    chain.corrupt_witness(WitnessCorruption::StaleAccountProof(account1.id()));
+    let error = ProposedBlock::new(chain.get_block_inputs(&batches), batches.clone()).unwrap_err();
+    assert_matches!(error, ProposedBlockError::InconsistentAccountStateTransition { account_id, .. } if account_id == account1.id());
+    chain.clear_witness_corruptions();
+
+    // Error: Nullifier witness for a different leaf.
+    // --------------------------------------------------------------------------------------------
+
+    chain.corrupt_witness(WitnessCorruption::WrongNullifierPath(note0.nullifier()));
+    let error = ProposedBlock::new(chain.get_block_inputs(&batches), batches.clone()).unwrap_err();
+    assert_matches!(error, ProposedBlockError::NullifierProofMissing(nullifier) if nullifier == note0.nullifier());
+    chain.clear_witness_corruptions();
+
+    // Error: Note inclusion proof with a wrong merkle path.
+    // --------------------------------------------------------------------------------------------
+
+    chain.corrupt_witness(WitnessCorruption::MismatchedNoteProof(note0.id()));
+    let error = ProposedBlock::new(chain.get_block_inputs(&batches), batches).unwrap_err();
+    assert_matches!(error, ProposedBlockError::UnauthenticatedNoteAuthenticationFailed { block_num, note_id, .. } if block_num == block2.header().block_num() && note_id == note0.id());
+
+    Ok(())
+}
+
 /// Tests that a missing account witness produces an error.
 #[test]
 fn proposed_block_fails_on_missing_account_witness() -> anyhow::Result<()> {
-    let TestSetup { mut chain, mut accounts, mut txs, .. } = setup_chain(2);
+    let TestSetup { mut chain, mut accounts, mut txs, .. } =
+        TestSetup::builder().accounts(2, TestAccount::mock()).build();
     let account0 = accounts.remove(&0).unwrap();
     let tx0 = txs.remove(&0).unwrap();
 
@@ -568,7 +629,8 @@ fn proposed_block_fails_on_missing_account_witness() -> anyhow::Result<()> {
 /// build on top of each other produce an error when tx 1 is missing from the block.
 #[test]
 fn proposed_block_fails_on_inconsistent_account_state_transition() -> anyhow::Result<()> {
-    let TestSetup { mut chain, mut accounts, .. } = setup_chain(2);
+    let TestSetup { mut chain, mut accounts, .. } =
+        TestSetup::builder().accounts(2, TestAccount::mock()).build();
     let asset = generate_fungible_asset(
         100,
         AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET).unwrap(),
@@ -587,31 +649,22 @@ fn proposed_block_fails_on_inconsistent_account_state_transition() -> anyhow::Re
     // Create three transactions on the same account that build on top of each other.
     // The MockChain only updates the account state when sealing a block, but we don't want the
     // transactions to actually be added to the chain because of unintended side effects like spent
-    // nullifiers. So we create an alternative chain on which we generate the transactions, but
-    // then actually use the transactions on the original chain.
-    let mut alternative_chain = chain.clone();
-    let executed_tx0 = generate_executed_tx_with_authenticated_notes(
-        &mut alternative_chain,
-        account1.id(),
-        &[note0.id()],
-    );
-    alternative_chain.apply_executed_transaction(&executed_tx0);
-    alternative_chain.seal_next_block();
+    // nullifiers. So we generate the transactions on the chain and then restore the chain to the
+    // snapshot taken before, on which we actually use the transactions.
+    let snapshot = chain.snapshot();
+    let executed_tx0 =
+        generate_executed_tx_with_authenticated_notes(&mut chain, account1.id(), &[note0.id()]);
+    chain.apply_executed_transaction(&executed_tx0);
+    chain.seal_next_block();
 
-    let executed_tx1 = generate_executed_tx_with_authenticated_notes(
-        &mut alternative_chain,
-        account1.id(),
-        &[note1.id()],
-    );
-    alternative_chain.apply_executed_transaction(&executed_tx1);
-    alternative_chain.seal_next_block();
+    let executed_tx1 =
+        generate_executed_tx_with_authenticated_notes(&mut chain, account1.id(), &[note1.id()]);
+    chain.apply_executed_transaction(&executed_tx1);
+    chain.seal_next_block();
 
-    let executed_tx2 = generate_executed_tx_with_authenticated_notes(
-        &mut alternative_chain,
-        account1.id(),
-        &[note2.id()],
-    );
-    alternative_chain.apply_executed_transaction(&executed_tx2);
+    let executed_tx2 =
+        generate_executed_tx_with_authenticated_notes(&mut chain, account1.id(), &[note2.id()]);
+    chain.restore(snapshot);
 
     // We will only include tx0 and tx2 and leave out tx1, which will trigger the error condition
     // that there is no transition from tx0 -> tx2.
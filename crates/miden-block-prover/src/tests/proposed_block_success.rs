@@ -1,23 +1,26 @@
 use std::{collections::BTreeMap, vec::Vec};
 
 use anyhow::Context;
+use assert_matches::assert_matches;
 use miden_objects::{
-    account::AccountId,
+    account::{AccountId, AccountStorageMode, AccountType, delta::AccountUpdateDetails},
     block::{BlockInputs, ProposedBlock},
     testing::account_id::ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET,
     transaction::ProvenTransaction,
 };
+use miden_tx::testing::Auth;
 
 use crate::tests::utils::{
-    ProvenTransactionExt, TestSetup, generate_batch, generate_executed_tx_with_authenticated_notes,
-    generate_fungible_asset, generate_tracked_note_with_asset, generate_tx_with_expiration,
-    generate_tx_with_unauthenticated_notes, generate_untracked_note, setup_chain,
+    ProvenTransactionExt, TestAccount, TestSetup, generate_batch,
+    generate_executed_tx_with_authenticated_notes, generate_fungible_asset,
+    generate_tracked_note_with_asset, generate_tx_with_expiration,
+    generate_tx_with_unauthenticated_notes, generate_untracked_note,
 };
 
 /// Tests that we can build empty blocks.
 #[test]
 fn proposed_block_succeeds_with_empty_batches() -> anyhow::Result<()> {
-    let TestSetup { chain, .. } = setup_chain(2);
+    let TestSetup { chain, .. } = TestSetup::builder().accounts(2, TestAccount::mock()).build();
 
     let block_inputs = BlockInputs::new(
         chain.latest_block_header(),
@@ -40,7 +43,8 @@ fn proposed_block_succeeds_with_empty_batches() -> anyhow::Result<()> {
 /// built.
 #[test]
 fn proposed_block_basic_success() -> anyhow::Result<()> {
-    let TestSetup { mut chain, mut accounts, mut txs, .. } = setup_chain(2);
+    let TestSetup { mut chain, mut accounts, mut txs, .. } =
+        TestSetup::builder().accounts(2, TestAccount::mock()).build();
     let account0 = accounts.remove(&0).unwrap();
     let account1 = accounts.remove(&1).unwrap();
     let proven_tx0 = txs.remove(&0).unwrap();
@@ -92,11 +96,55 @@ fn proposed_block_basic_success() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Tests that a proposed block can be built from the transactions of a heterogeneous mix of
+/// accounts.
+#[test]
+fn proposed_block_with_heterogeneous_accounts() -> anyhow::Result<()> {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET).unwrap();
+    let asset = generate_fungible_asset(100, faucet_id);
+    let TestSetup { mut chain, accounts, txs } = TestSetup::builder()
+        .account(TestAccount::mock())
+        .account(
+            TestAccount::wallet()
+                .storage_mode(AccountStorageMode::Public)
+                .assets(vec![asset])
+                .auth(Auth::BasicAuth),
+        )
+        .account(
+            TestAccount::fungible_faucet()
+                .storage_mode(AccountStorageMode::Public)
+                .auth(Auth::BasicAuth),
+        )
+        .build();
+
+    assert_eq!(accounts[&1].vault().get_balance(faucet_id)?, 100);
+    assert_eq!(accounts[&2].account_type(), AccountType::FungibleFaucet);
+
+    let batches = vec![generate_batch(&mut chain, txs.values().cloned().collect())];
+    let block_inputs = chain.get_block_inputs(&batches);
+    let block = ProposedBlock::new(block_inputs, batches).context("failed to propose block")?;
+
+    let updated_accounts = block.updated_accounts().iter().cloned().collect::<BTreeMap<_, _>>();
+    assert_eq!(updated_accounts.len(), 3);
+    assert!(updated_accounts[&accounts[&0].id()].details().is_private());
+    for i in [1, 2] {
+        assert_matches!(
+            updated_accounts[&accounts[&i].id()].details(),
+            AccountUpdateDetails::Delta(_)
+        );
+    }
+    // Only the mock account and the wallet consume a note.
+    assert_eq!(block.created_nullifiers().len(), 2);
+
+    Ok(())
+}
+
 /// Tests that account updates are correctly aggregated into a block-level account update.
 #[test]
 fn proposed_block_aggregates_account_state_transition() -> anyhow::Result<()> {
     // We need authentication because we're modifying accounts with the input notes.
-    let TestSetup { mut chain, mut accounts, .. } = setup_chain(2);
+    let TestSetup { mut chain, mut accounts, .. } =
+        TestSetup::builder().accounts(2, TestAccount::mock()).build();
     let asset = generate_fungible_asset(
         100,
         AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET).unwrap(),
@@ -115,31 +163,22 @@ fn proposed_block_aggregates_account_state_transition() -> anyhow::Result<()> {
     // Create three transactions on the same account that build on top of each other.
     // The MockChain only updates the account state when sealing a block, but we don't want the
     // transactions to actually be added to the chain because of unintended side effects like spent
-    // nullifiers. So we create an alternative chain on which we generate the transactions, but
-    // then actually use the transactions on the original chain.
-    let mut alternative_chain = chain.clone();
-    let executed_tx0 = generate_executed_tx_with_authenticated_notes(
-        &mut alternative_chain,
-        account1.id(),
-        &[note0.id()],
-    );
-    alternative_chain.apply_executed_transaction(&executed_tx0);
-    alternative_chain.seal_next_block();
+    // nullifiers. So we generate the transactions on the chain and then restore the chain to the
+    // snapshot taken before, on which we actually use the transactions.
+    let snapshot = chain.snapshot();
+    let executed_tx0 =
+        generate_executed_tx_with_authenticated_notes(&mut chain, account1.id(), &[note0.id()]);
+    chain.apply_executed_transaction(&executed_tx0);
+    chain.seal_next_block();
 
-    let executed_tx1 = generate_executed_tx_with_authenticated_notes(
-        &mut alternative_chain,
-        account1.id(),
-        &[note1.id()],
-    );
-    alternative_chain.apply_executed_transaction(&executed_tx1);
-    alternative_chain.seal_next_block();
+    let executed_tx1 =
+        generate_executed_tx_with_authenticated_notes(&mut chain, account1.id(), &[note1.id()]);
+    chain.apply_executed_transaction(&executed_tx1);
+    chain.seal_next_block();
 
-    let executed_tx2 = generate_executed_tx_with_authenticated_notes(
-        &mut alternative_chain,
-        account1.id(),
-        &[note2.id()],
-    );
-    alternative_chain.apply_executed_transaction(&executed_tx2);
+    let executed_tx2 =
+        generate_executed_tx_with_authenticated_notes(&mut chain, account1.id(), &[note2.id()]);
+    chain.restore(snapshot);
 
     let [tx0, tx1, tx2] = [executed_tx0, executed_tx1, executed_tx2]
         .into_iter()
@@ -180,7 +219,8 @@ fn proposed_block_aggregates_account_state_transition() -> anyhow::Result<()> {
 /// Tests that unauthenticated notes can be authenticated when inclusion proofs are provided.
 #[test]
 fn proposed_block_authenticating_unauthenticated_notes() -> anyhow::Result<()> {
-    let TestSetup { mut chain, mut accounts, .. } = setup_chain(3);
+    let TestSetup { mut chain, mut accounts, .. } =
+        TestSetup::builder().accounts(3, TestAccount::mock()).build();
     let account0 = accounts.remove(&0).unwrap();
     let account1 = accounts.remove(&1).unwrap();
     let account2 = accounts.remove(&2).unwrap();
@@ -229,7 +269,8 @@ fn proposed_block_authenticating_unauthenticated_notes() -> anyhow::Result<()> {
 /// Tests that a batch that expires at the block being proposed is still accepted.
 #[test]
 fn proposed_block_with_batch_at_expiration_limit() -> anyhow::Result<()> {
-    let TestSetup { mut chain, mut accounts, .. } = setup_chain(2);
+    let TestSetup { mut chain, mut accounts, .. } =
+        TestSetup::builder().accounts(2, TestAccount::mock()).build();
     let block1_num = chain.block_header(1).block_num();
     let account0 = accounts.remove(&0).unwrap();
     let account1 = accounts.remove(&1).unwrap();
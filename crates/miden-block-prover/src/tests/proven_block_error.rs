@@ -10,8 +10,8 @@ use miden_objects::{
 use crate::{
     LocalBlockProver, ProvenBlockError,
     tests::utils::{
-        TestSetup, generate_batch, generate_executed_tx_with_authenticated_notes,
-        generate_tracked_note, setup_chain,
+        TestAccount, TestSetup, generate_batch, generate_executed_tx_with_authenticated_notes,
+        generate_tracked_note,
     },
 };
 
@@ -25,7 +25,8 @@ struct WitnessTestSetup {
 /// commitments of the latest block and the stale inputs match the commitments of the latest block
 /// minus 1.
 fn witness_test_setup() -> WitnessTestSetup {
-    let TestSetup { mut chain, mut accounts, mut txs, .. } = setup_chain(4);
+    let TestSetup { mut chain, mut accounts, mut txs, .. } =
+        TestSetup::builder().accounts(4, TestAccount::mock()).build();
 
     let account0 = accounts.remove(&0).unwrap();
     let account1 = accounts.remove(&1).unwrap();
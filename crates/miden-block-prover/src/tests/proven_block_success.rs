@@ -13,10 +13,9 @@ use rand::Rng;
 use crate::{
     LocalBlockProver,
     tests::utils::{
-        TestSetup, generate_batch, generate_executed_tx_with_authenticated_notes,
+        TestAccount, TestSetup, generate_batch, generate_executed_tx_with_authenticated_notes,
         generate_output_note, generate_tracked_note, generate_tx_with_authenticated_notes,
         generate_tx_with_unauthenticated_notes, generate_untracked_note_with_output_note,
-        setup_chain,
     },
 };
 
@@ -28,7 +27,8 @@ fn proven_block_success() -> anyhow::Result<()> {
     // computation.
     // --------------------------------------------------------------------------------------------
 
-    let TestSetup { mut chain, mut accounts, .. } = setup_chain(4);
+    let TestSetup { mut chain, mut accounts, .. } =
+        TestSetup::builder().accounts(4, TestAccount::mock()).build();
 
     let account0 = accounts.remove(&0).unwrap();
     let account1 = accounts.remove(&1).unwrap();
@@ -197,7 +197,8 @@ fn proven_block_success() -> anyhow::Result<()> {
 /// of the subtree of the overall block note tree computed from the block's output notes.
 #[test]
 fn proven_block_erasing_unauthenticated_notes() -> anyhow::Result<()> {
-    let TestSetup { mut chain, mut accounts, .. } = setup_chain(4);
+    let TestSetup { mut chain, mut accounts, .. } =
+        TestSetup::builder().accounts(4, TestAccount::mock()).build();
     let account0 = accounts.remove(&0).unwrap();
     let account1 = accounts.remove(&1).unwrap();
     let account2 = accounts.remove(&2).unwrap();
@@ -339,7 +340,8 @@ fn proven_block_succeeds_with_empty_batches() -> anyhow::Result<()> {
     // Setup a chain with a non-empty nullifier tree by consuming some notes.
     // --------------------------------------------------------------------------------------------
 
-    let TestSetup { mut chain, mut accounts, .. } = setup_chain(2);
+    let TestSetup { mut chain, mut accounts, .. } =
+        TestSetup::builder().accounts(2, TestAccount::mock()).build();
 
     let account0 = accounts.remove(&0).unwrap();
     let account1 = accounts.remove(&1).unwrap();
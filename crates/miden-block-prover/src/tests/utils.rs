@@ -1,11 +1,18 @@
 use std::{collections::BTreeMap, vec, vec::Vec};
 
 use miden_crypto::rand::RpoRandomCoin;
-use miden_lib::{note::create_p2id_note, transaction::TransactionKernel};
+use miden_lib::{
+    account::{faucets::BasicFungibleFaucet, wallets::BasicWallet},
+    note::create_p2id_note,
+    transaction::TransactionKernel,
+};
 use miden_objects::{
     self, Felt,
-    account::{Account, AccountId, delta::AccountUpdateDetails},
-    asset::{Asset, FungibleAsset},
+    account::{
+        Account, AccountBuilder, AccountId, AccountStorageMode, AccountType,
+        delta::AccountUpdateDetails,
+    },
+    asset::{Asset, FungibleAsset, TokenSymbol},
     batch::ProvenBatch,
     block::{BlockHeader, BlockNumber},
     note::{Note, NoteId, NoteTag, NoteType},
@@ -26,6 +33,175 @@ pub struct TestSetup {
     pub txs: BTreeMap<usize, ProvenTransaction>,
 }
 
+impl TestSetup {
+    /// Returns a builder for a [`TestSetup`].
+    pub fn builder() -> TestSetupBuilder {
+        TestSetupBuilder::default()
+    }
+}
+
+/// The kind of account created by a [`TestSetupBuilder`].
+#[derive(Debug, Clone, Copy)]
+pub enum TestAccountKind {
+    /// An account with the mock account component, whose transactions increment the nonce through
+    /// a transaction script instead of an authentication procedure.
+    Mock,
+    /// An account with the basic wallet component.
+    Wallet,
+    /// A fungible faucet with the basic fungible faucet component.
+    FungibleFaucet,
+}
+
+/// The description of an account created by a [`TestSetupBuilder`].
+#[derive(Debug, Clone)]
+pub struct TestAccount {
+    kind: TestAccountKind,
+    storage_mode: AccountStorageMode,
+    assets: Vec<Asset>,
+    auth: Auth,
+}
+
+impl TestAccount {
+    /// Returns a private account of the given kind without assets and authentication.
+    pub fn new(kind: TestAccountKind) -> Self {
+        Self {
+            kind,
+            storage_mode: AccountStorageMode::Private,
+            assets: Vec::new(),
+            auth: Auth::NoAuth,
+        }
+    }
+
+    /// Returns a private account with the mock account component.
+    pub fn mock() -> Self {
+        Self::new(TestAccountKind::Mock)
+    }
+
+    /// Returns a private basic wallet.
+    pub fn wallet() -> Self {
+        Self::new(TestAccountKind::Wallet)
+    }
+
+    /// Returns a private basic fungible faucet.
+    pub fn fungible_faucet() -> Self {
+        Self::new(TestAccountKind::FungibleFaucet)
+    }
+
+    /// Sets the storage mode of the account.
+    pub fn storage_mode(mut self, storage_mode: AccountStorageMode) -> Self {
+        self.storage_mode = storage_mode;
+        self
+    }
+
+    /// Sets the initial assets of the account.
+    pub fn assets(mut self, assets: Vec<Asset>) -> Self {
+        self.assets = assets;
+        self
+    }
+
+    /// Sets the authentication scheme of the account.
+    pub fn auth(mut self, auth: Auth) -> Self {
+        self.auth = auth;
+        self
+    }
+
+    fn build(&self, chain: &mut MockChain) -> Account {
+        let account_builder = match self.kind {
+            TestAccountKind::Mock => AccountBuilder::new(rand::rng().random()).with_component(
+                AccountMockComponent::new_with_empty_slots(TransactionKernel::assembler()).unwrap(),
+            ),
+            TestAccountKind::Wallet => {
+                AccountBuilder::new(rand::rng().random()).with_component(BasicWallet)
+            },
+            TestAccountKind::FungibleFaucet => AccountBuilder::new(rand::rng().random())
+                .account_type(AccountType::FungibleFaucet)
+                .with_component(
+                    BasicFungibleFaucet::new(
+                        TokenSymbol::new("TST").unwrap(),
+                        8,
+                        Felt::new(1_000_000),
+                    )
+                    .unwrap(),
+                ),
+        };
+        let account_builder = account_builder
+            .storage_mode(self.storage_mode)
+            .with_assets(self.assets.iter().copied());
+
+        chain.add_from_account_builder(self.auth, account_builder, AccountState::Exists)
+    }
+}
+
+/// A builder for a [`TestSetup`] with a configurable set of accounts.
+///
+/// Building the setup adds all accounts to a new chain, together with a note for each account
+/// other than fungible faucets, which is consumed by the transaction of the account.
+#[derive(Debug, Clone, Default)]
+pub struct TestSetupBuilder {
+    accounts: Vec<TestAccount>,
+}
+
+impl TestSetupBuilder {
+    /// Adds the provided account to the setup.
+    pub fn account(mut self, account: TestAccount) -> Self {
+        self.accounts.push(account);
+        self
+    }
+
+    /// Adds `num_accounts` copies of the provided account to the setup.
+    pub fn accounts(mut self, num_accounts: usize, account: TestAccount) -> Self {
+        self.accounts.extend(vec![account; num_accounts]);
+        self
+    }
+
+    /// Builds the test setup.
+    ///
+    /// The accounts and their transactions are keyed by the order in which the accounts were added
+    /// to the builder.
+    pub fn build(self) -> TestSetup {
+        let mut chain = MockChain::new();
+        let sender_account = generate_account(&mut chain);
+        let mut accounts = BTreeMap::new();
+        let mut notes = BTreeMap::new();
+        let mut txs = BTreeMap::new();
+
+        for (i, test_account) in self.accounts.iter().enumerate() {
+            let account = test_account.build(&mut chain);
+            if !matches!(test_account.kind, TestAccountKind::FungibleFaucet) {
+                let note = generate_tracked_note(&mut chain, sender_account.id(), account.id());
+                notes.insert(i, note);
+            }
+            accounts.insert(i, account);
+        }
+
+        chain.seal_next_block();
+
+        for (i, test_account) in self.accounts.iter().enumerate() {
+            let account_id = accounts[&i].id();
+            let note_ids: Vec<_> = notes.get(&i).map(Note::id).into_iter().collect();
+            let tx = match test_account.kind {
+                TestAccountKind::Mock => {
+                    generate_tx_with_authenticated_notes(&mut chain, account_id, &note_ids)
+                },
+                TestAccountKind::Wallet | TestAccountKind::FungibleFaucet => {
+                    let executed_tx = chain
+                        .build_tx_context(account_id, &note_ids, &[])
+                        .build()
+                        .execute()
+                        .unwrap();
+                    ProvenTransaction::from_executed_transaction_mocked(
+                        executed_tx,
+                        &chain.latest_block_header(),
+                    )
+                },
+            };
+            txs.insert(i, tx);
+        }
+
+        TestSetup { chain, accounts, txs }
+    }
+}
+
 pub fn generate_account(chain: &mut MockChain) -> Account {
     let account_builder = Account::builder(rand::rng().random()).with_component(
         AccountMockComponent::new_with_empty_slots(TransactionKernel::assembler()).unwrap(),
@@ -208,34 +384,6 @@ pub fn generate_batch(chain: &mut MockChain, txs: Vec<ProvenTransaction>) -> Pro
         .unwrap()
 }
 
-/// Setup a test mock chain with the number of accounts, notes and transactions.
-///
-/// This is merely generating some valid data for testing purposes.
-pub fn setup_chain(num_accounts: usize) -> TestSetup {
-    let mut chain = MockChain::new();
-    let sender_account = generate_account(&mut chain);
-    let mut accounts = BTreeMap::new();
-    let mut notes = BTreeMap::new();
-    let mut txs = BTreeMap::new();
-
-    for i in 0..num_accounts {
-        let account = generate_account(&mut chain);
-        let note = generate_tracked_note(&mut chain, sender_account.id(), account.id());
-        accounts.insert(i, account);
-        notes.insert(i, note);
-    }
-
-    chain.seal_next_block();
-
-    for i in 0..num_accounts {
-        let tx =
-            generate_tx_with_authenticated_notes(&mut chain, accounts[&i].id(), &[notes[&i].id()]);
-        txs.insert(i, tx);
-    }
-
-    TestSetup { chain, accounts, txs }
-}
-
 pub trait ProvenTransactionExt {
     fn from_executed_transaction_mocked(
         executed_tx: ExecutedTransaction,
@@ -36,6 +36,12 @@ const ASM_ACCOUNT_COMPONENTS_DIR: &str = "account_components";
 const SHARED_DIR: &str = "shared";
 const ASM_TX_KERNEL_DIR: &str = "kernels/transaction";
 const KERNEL_V0_RS_FILE: &str = "src/transaction/procedures/kernel_v0.rs";
+const KERNEL_EVENT_ID_RS_FILE: &str = "src/transaction/kernel_event_id.rs";
+const KERNEL_EVENT_ID_PREFIX: u32 = 2;
+const KERNEL_PARAMS_FILE: &str = "kernel_params.toml";
+const KERNEL_PARAMS_FILE_ENV: &str = "MIDEN_KERNEL_PARAMS_FILE";
+const KERNEL_PARAMS_RS_FILE: &str = "kernel_params.rs";
+const CUSTOM_KERNEL_PARAMS_CFG: &str = "custom_kernel_params";
 
 const TX_KERNEL_ERRORS_FILE: &str = "src/errors/tx_kernel_errors.rs";
 const NOTE_SCRIPT_ERRORS_FILE: &str = "src/errors/note_script_errors.rs";
@@ -44,7 +50,7 @@ const TX_KERNEL_ERRORS_ARRAY_NAME: &str = "TX_KERNEL_ERRORS";
 const NOTE_SCRIPT_ERRORS_ARRAY_NAME: &str = "NOTE_SCRIPT_ERRORS";
 
 const ERROR_CATEGORIES: [ErrorCategory; 2] = [ErrorCategory::TxKernel, ErrorCategory::NoteScript];
-const TX_KERNEL_ERROR_CATEGORIES: [TxKernelErrorCategory; 11] = [
+const TX_KERNEL_ERROR_CATEGORIES: [TxKernelErrorCategory; 12] = [
     TxKernelErrorCategory::Kernel,
     TxKernelErrorCategory::Prologue,
     TxKernelErrorCategory::Epilogue,
@@ -56,6 +62,7 @@ const TX_KERNEL_ERROR_CATEGORIES: [TxKernelErrorCategory; 11] = [
     TxKernelErrorCategory::FungibleAsset,
     TxKernelErrorCategory::NonFugibleAsset,
     TxKernelErrorCategory::Vault,
+    TxKernelErrorCategory::Auth,
 ];
 
 // PRE-PROCESSING
@@ -69,6 +76,8 @@ fn main() -> Result<()> {
     // re-build when the MASM code changes
     println!("cargo:rerun-if-changed={ASM_DIR}");
     println!("cargo::rerun-if-env-changed=BUILD_GENERATED_FILES_IN_SRC");
+    println!("cargo::rerun-if-env-changed={KERNEL_PARAMS_FILE_ENV}");
+    println!("cargo::rustc-check-cfg=cfg({CUSTOM_KERNEL_PARAMS_CFG})");
 
     // Copies the MASM code to the build directory
     let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
@@ -83,9 +92,20 @@ fn main() -> Result<()> {
     // set target directory to {OUT_DIR}/assets
     let target_dir = Path::new(&build_dir).join(ASSETS_DIR);
 
+    // apply the kernel parameters to the copied kernel code
+    let kernel_params = KernelParams::load(Path::new(&crate_dir))?;
+    kernel_params.apply(&source_dir.join(ASM_TX_KERNEL_DIR))?;
+    kernel_params.write_rust_constants(&dst)?;
+    if !kernel_params.is_default() {
+        println!("cargo::rustc-cfg={CUSTOM_KERNEL_PARAMS_CFG}");
+    }
+
     // compile transaction kernel
-    let mut assembler =
-        compile_tx_kernel(&source_dir.join(ASM_TX_KERNEL_DIR), &target_dir.join("kernels"))?;
+    let mut assembler = compile_tx_kernel(
+        &source_dir.join(ASM_TX_KERNEL_DIR),
+        &target_dir.join("kernels"),
+        &kernel_params,
+    )?;
 
     // compile miden library
     let miden_lib = compile_miden_lib(&source_dir, &target_dir, assembler.clone())?;
@@ -106,10 +126,160 @@ fn main() -> Result<()> {
     )?;
 
     generate_error_constants(&source_dir)?;
+    generate_kernel_event_file(&source_dir)?;
 
     Ok(())
 }
 
+// KERNEL PARAMETERS
+// ================================================================================================
+
+/// Limits of the transaction kernel which can be configured at build time, e.g. for private
+/// deployments with different resource envelopes.
+///
+/// The parameters are read from the file pointed to by the `MIDEN_KERNEL_PARAMS_FILE` environment
+/// variable or, if it is not set, from `kernel_params.toml` in the crate root. The file consists of
+/// `name = value` lines, and parameters which are not listed keep their default value.
+///
+/// The kernel memory layout is sized for the default limits, so the limits can only be lowered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct KernelParams {
+    max_num_storage_slots: u32,
+    max_assets_per_note: u32,
+    max_output_notes_per_tx: u32,
+}
+
+impl KernelParams {
+    /// The limits of the transaction kernel as defined in its MASM code.
+    const DEFAULT: Self = Self {
+        max_num_storage_slots: 255,
+        max_assets_per_note: 256,
+        max_output_notes_per_tx: 1024,
+    };
+
+    /// Reads the kernel parameters from the configured parameters file.
+    fn load(crate_dir: &Path) -> Result<Self> {
+        let params_file = match env::var(KERNEL_PARAMS_FILE_ENV) {
+            Ok(path) => PathBuf::from(path),
+            Err(_) => crate_dir.join(KERNEL_PARAMS_FILE),
+        };
+        println!("cargo::rerun-if-changed={}", params_file.display());
+
+        let contents = fs::read_to_string(&params_file).into_diagnostic().wrap_err_with(|| {
+            format!("failed to read kernel parameters file {}", params_file.display())
+        })?;
+
+        Self::parse(&contents)
+    }
+
+    /// Parses the kernel parameters from the contents of a parameters file.
+    fn parse(contents: &str) -> Result<Self> {
+        let mut params = Self::DEFAULT;
+        for line in contents.lines() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let Some((name, value)) = line.split_once('=') else {
+                return Err(Report::msg(format!("invalid kernel parameter definition `{line}`")));
+            };
+            let name = name.trim();
+            let value: u32 = value
+                .trim()
+                .replace('_', "")
+                .parse()
+                .into_diagnostic()
+                .wrap_err_with(|| format!("invalid value for kernel parameter `{name}`"))?;
+
+            let (param, max_value) = match name {
+                "max_num_storage_slots" => {
+                    (&mut params.max_num_storage_slots, Self::DEFAULT.max_num_storage_slots)
+                },
+                "max_assets_per_note" => {
+                    (&mut params.max_assets_per_note, Self::DEFAULT.max_assets_per_note)
+                },
+                "max_output_notes_per_tx" => {
+                    (&mut params.max_output_notes_per_tx, Self::DEFAULT.max_output_notes_per_tx)
+                },
+                _ => return Err(Report::msg(format!("unknown kernel parameter `{name}`"))),
+            };
+
+            if value == 0 || value > max_value {
+                return Err(Report::msg(format!(
+                    "kernel parameter `{name}` must be within 1 and {max_value}, but was {value}"
+                )));
+            }
+            *param = value;
+        }
+
+        Ok(params)
+    }
+
+    /// Returns `true` if all parameters have their default value.
+    fn is_default(&self) -> bool {
+        *self == Self::DEFAULT
+    }
+
+    /// Overrides the limits defined in the transaction kernel MASM code in `kernel_dir`.
+    fn apply(&self, kernel_dir: &Path) -> Result<()> {
+        if self.is_default() {
+            return Ok(());
+        }
+
+        let lib_dir = kernel_dir.join("lib");
+        set_masm_constant(
+            &lib_dir.join("account.masm"),
+            "MAX_NUM_STORAGE_SLOTS",
+            self.max_num_storage_slots,
+        )?;
+        set_masm_constant(
+            &lib_dir.join("constants.masm"),
+            "MAX_ASSETS_PER_NOTE",
+            self.max_assets_per_note,
+        )?;
+        set_masm_constant(
+            &lib_dir.join("constants.masm"),
+            "MAX_OUTPUT_NOTES_PER_TX",
+            self.max_output_notes_per_tx,
+        )
+    }
+
+    /// Writes the limits as Rust constants into the "{target_dir}/kernel_params.rs" file.
+    fn write_rust_constants(&self, target_dir: &Path) -> Result<()> {
+        fs::write(
+            target_dir.join(KERNEL_PARAMS_RS_FILE),
+            format!(
+                r#"// This file is generated by build.rs, do not modify
+
+/// The maximum number of storage slots of an account.
+pub const MAX_NUM_STORAGE_SLOTS: usize = {};
+
+/// The maximum number of assets in a single note.
+pub const MAX_ASSETS_PER_NOTE: usize = {};
+
+/// The maximum number of notes created by a single transaction.
+pub const MAX_OUTPUT_NOTES_PER_TX: usize = {};
+"#,
+                self.max_num_storage_slots, self.max_assets_per_note, self.max_output_notes_per_tx,
+            ),
+        )
+        .into_diagnostic()
+    }
+}
+
+/// Replaces the value of the constant `name` defined in the MASM file at `path` with `value`.
+fn set_masm_constant(path: &Path, name: &str, value: u32) -> Result<()> {
+    let regex = Regex::new(&format!(r"(?m)^const\.{name}=.*$")).unwrap();
+    let source = fs::read_to_string(path).into_diagnostic()?;
+    if !regex.is_match(&source) {
+        return Err(Report::msg(format!("constant `{name}` not found in {}", path.display())));
+    }
+
+    let source = regex.replace(&source, format!("const.{name}={value}"));
+    fs::write(path, source.as_ref()).into_diagnostic()
+}
+
 // COMPILE TRANSACTION KERNEL
 // ================================================================================================
 
@@ -133,8 +303,13 @@ fn main() -> Result<()> {
 /// - {target_dir}/tx_kernel.masb             -> contains the executable compiled from main.masm.
 /// - {target_dir}/tx_script_main.masb        -> contains the executable compiled from
 ///   tx_script_main.masm.
-/// - src/transaction/procedures/kernel_v0.rs -> contains the kernel procedures table.
-fn compile_tx_kernel(source_dir: &Path, target_dir: &Path) -> Result<Assembler> {
+/// - src/transaction/procedures/kernel_v0.rs -> contains the kernel procedures table. If custom
+///   kernel parameters are used, the table is written to {OUT_DIR}/kernel_v0.rs instead.
+fn compile_tx_kernel(
+    source_dir: &Path,
+    target_dir: &Path,
+    kernel_params: &KernelParams,
+) -> Result<Assembler> {
     let shared_path = Path::new(ASM_DIR).join(SHARED_DIR);
     let kernel_namespace = LibraryNamespace::new("kernel").expect("namespace should be valid");
 
@@ -150,7 +325,7 @@ fn compile_tx_kernel(source_dir: &Path, target_dir: &Path) -> Result<Assembler>
     )?;
 
     // generate `kernel_v0.rs` file
-    generate_kernel_proc_hash_file(kernel_lib.clone())?;
+    generate_kernel_proc_hash_file(kernel_lib.clone(), kernel_params)?;
 
     let output_file = target_dir.join("tx_kernel").with_extension(Library::LIBRARY_EXTENSION);
     kernel_lib.write_to_file(output_file).into_diagnostic()?;
@@ -213,19 +388,30 @@ fn compile_tx_script_main(
 }
 
 /// Generates `kernel_v0.rs` file based on the kernel library
-fn generate_kernel_proc_hash_file(kernel: KernelLibrary) -> Result<()> {
-    // Because the kernel Rust file will be stored under ./src, this should be a no-op if we can't
-    // write there
-    if !BUILD_GENERATED_FILES_IN_SRC {
-        return Ok(());
-    }
+fn generate_kernel_proc_hash_file(
+    kernel: KernelLibrary,
+    kernel_params: &KernelParams,
+) -> Result<()> {
+    // The file under ./src always describes the kernel built with the default parameters. Kernels
+    // built with custom parameters have different procedure roots, so their table is written to
+    // OUT_DIR and included from there instead.
+    let output_file = if kernel_params.is_default() {
+        // Because the kernel Rust file will be stored under ./src, this should be a no-op if we
+        // can't write there
+        if !BUILD_GENERATED_FILES_IN_SRC {
+            return Ok(());
+        }
+        PathBuf::from(KERNEL_V0_RS_FILE)
+    } else {
+        Path::new(&env::var("OUT_DIR").unwrap()).join("kernel_v0.rs")
+    };
 
     let (_, module_info, _) = kernel.into_parts();
 
     let to_exclude = BTreeSet::from_iter(["exec_kernel_proc"]);
     let offsets_filename = Path::new(ASM_DIR).join(ASM_MIDEN_DIR).join("kernel_proc_offsets.masm");
     let offsets = parse_proc_offsets(&offsets_filename)?;
-    let generated_procs: BTreeMap<usize, String> = module_info
+    let generated_procs: BTreeMap<usize, (String, String)> = module_info
         .procedures()
         .filter(|(_, proc_info)| !to_exclude.contains::<str>(proc_info.name.as_ref()))
         .map(|(_, proc_info)| {
@@ -235,39 +421,102 @@ fn generate_kernel_proc_hash_file(kernel: KernelLibrary) -> Result<()> {
                 panic!("Offset constant for function `{name}` not found in `{offsets_filename:?}`");
             };
 
-            (offset, format!("    // {name}\n    digest!(\"{}\"),", proc_info.digest))
+            (offset, (name, proc_info.digest.to_string()))
         })
         .collect();
 
     let proc_count = generated_procs.len();
-    let generated_procs: String = generated_procs.into_iter().enumerate().map(|(index, (offset, txt))| {
+    for (index, &offset) in generated_procs.keys().enumerate() {
         if index != offset {
-            panic!("Offset constants in the file `{offsets_filename:?}` are not contiguous (missing offset: {index})");
+            panic!(
+                "Offset constants in the file `{offsets_filename:?}` are not contiguous (missing offset: {index})"
+            );
         }
+    }
 
-        txt
-    }).collect::<Vec<_>>().join("\n");
+    let generated_variants: String = generated_procs
+        .iter()
+        .map(|(offset, (name, _))| {
+            format!("    /// `{name}`\n    {} = {offset},", to_upper_camel_case(name))
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let generated_all: String = generated_procs
+        .values()
+        .map(|(name, _)| format!("        Self::{},", to_upper_camel_case(name)))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let generated_names: String = generated_procs
+        .values()
+        .map(|(name, _)| format!("    \"{name}\","))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let generated_procs: String = generated_procs
+        .values()
+        .map(|(name, digest)| format!("    // {name}\n    digest!(\"{digest}\"),"))
+        .collect::<Vec<_>>()
+        .join("\n");
 
     fs::write(
-        KERNEL_V0_RS_FILE,
+        output_file,
         format!(
-            r#"//! This file is generated by build.rs, do not modify
+            r#"// This file is generated by build.rs, do not modify
 
 use miden_objects::{{digest, Digest}};
 
 // KERNEL V0 PROCEDURES
 // ================================================================================================
 
+/// Dynamically executed procedures of the kernel 0, with the offset of each procedure as its
+/// discriminant.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum KernelProcedure {{
+{generated_variants}
+}}
+
+impl KernelProcedure {{
+    /// All procedures of the kernel 0, sorted by their offset.
+    pub const ALL: [Self; {proc_count}] = [
+{generated_all}
+    ];
+}}
+
 /// Hashes of all dynamically executed procedures from the kernel 0.
 pub const KERNEL0_PROCEDURES: [Digest; {proc_count}] = [
 {generated_procs}
 ];
+
+/// Names of all dynamically executed procedures from the kernel 0, in the same order as
+/// [KERNEL0_PROCEDURES].
+pub const KERNEL0_PROCEDURE_NAMES: [&str; {proc_count}] = [
+{generated_names}
+];
 "#,
         ),
     )
     .into_diagnostic()
 }
 
+/// Converts a name whose words are separated by underscores (e.g. `account_get_id` or
+/// `ACCOUNT_GET_ID`) into upper camel case (e.g. `AccountGetId`).
+fn to_upper_camel_case(name: &str) -> String {
+    name.split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => {
+                    first.to_ascii_uppercase().to_string() + &chars.as_str().to_lowercase()
+                },
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
 fn parse_proc_offsets(filename: impl AsRef<Path>) -> Result<BTreeMap<String, usize>> {
     let regex: Regex = Regex::new(r"^const\.(?P<name>\w+)_OFFSET\s*=\s*(?P<offset>\d+)").unwrap();
     let mut result = BTreeMap::new();
@@ -471,6 +720,279 @@ fn is_masm_file(path: &Path) -> io::Result<bool> {
     }
 }
 
+// KERNEL EVENTS FILE GENERATION
+// ================================================================================================
+
+/// Reads all MASM files from the `asm` directory, extracts the events emitted via `emit.<NAME>` and
+/// generates the `KernelEventId` enum into `src/transaction/kernel_event_id.rs`.
+///
+/// An event is a constant which is used in an `emit` instruction of the same file, e.g.:
+///
+/// ```text
+/// # Event emitted after an asset is added to the account vault.
+/// # Stack: [ASSET]
+/// const.ACCOUNT_VAULT_AFTER_ADD_ASSET_EVENT=131073
+/// ```
+///
+/// The comment lines directly above the constant become the documentation of the variant, except
+/// for the mandatory `Stack:` line which describes the top of the operand stack at the time the
+/// event is emitted. The name of the variant is the name of the constant without the `_EVENT`
+/// suffix in upper camel case, and its discriminant is the value of the constant.
+///
+/// An event may be defined in multiple files, as long as its ID and stack layout are the same.
+///
+/// Because the file will be written to ./src/transaction, this should be a no-op if ./src is
+/// read-only. To enable writing to ./src, set the `BUILD_GENERATED_FILES_IN_SRC` environment
+/// variable.
+fn generate_kernel_event_file(asm_source_dir: &Path) -> Result<()> {
+    if !BUILD_GENERATED_FILES_IN_SRC {
+        return Ok(());
+    }
+
+    let events =
+        extract_all_masm_events(asm_source_dir).context("failed to extract all masm events")?;
+    let content = generate_kernel_event_file_content(events)?;
+    fs::write(KERNEL_EVENT_ID_RS_FILE, content).into_diagnostic()
+}
+
+/// Extracts the events from all MASM files in the given directory and returns them sorted by ID.
+fn extract_all_masm_events(asm_source_dir: &Path) -> Result<Vec<KernelEvent>> {
+    let mut events: BTreeMap<String, KernelEvent> = BTreeMap::new();
+
+    // walk the files in a deterministic order, so that the documentation of an event defined in
+    // multiple files does not depend on the order in which the file system lists them
+    for entry in WalkDir::new(asm_source_dir).sort_by_file_name() {
+        let entry = entry.into_diagnostic()?;
+        if !is_masm_file(entry.path()).into_diagnostic()? {
+            continue;
+        }
+        let file_contents = fs::read_to_string(entry.path()).into_diagnostic()?;
+
+        for event in extract_masm_events(&file_contents)? {
+            match events.get(&event.masm_name) {
+                Some(existing) if existing.id != event.id => {
+                    return Err(Report::msg(format!(
+                        "event {} is already defined elsewhere but its ID is different",
+                        event.masm_name
+                    )));
+                },
+                Some(existing) if existing.stack != event.stack => {
+                    return Err(Report::msg(format!(
+                        "event {} is already defined elsewhere but its stack layout is different",
+                        event.masm_name
+                    )));
+                },
+                Some(_) => (),
+                None => {
+                    events.insert(event.masm_name.clone(), event);
+                },
+            }
+        }
+    }
+
+    // check that no ID is used by multiple events
+    let mut ids = BTreeMap::new();
+    for event in events.values() {
+        if let Some(existing_name) = ids.insert(event.id, &event.masm_name) {
+            return Err(Report::msg(format!(
+                "event ID {} is used multiple times; non-exhaustive list: {existing_name}, {}",
+                event.id, event.masm_name
+            )));
+        }
+    }
+
+    let mut events: Vec<KernelEvent> = events.into_values().collect();
+    events.sort_by_key(|event| event.id);
+
+    Ok(events)
+}
+
+/// Extracts the events emitted in a single MASM file.
+fn extract_masm_events(file_contents: &str) -> Result<Vec<KernelEvent>> {
+    let const_regex =
+        Regex::new(r"(?m)(?<comment>(^#[^!\n].*\n)*)^const\.(?<name>\w+)=(?<id>\d+)\s*$").unwrap();
+    let emit_regex = Regex::new(r"emit\.(?<name>[A-Z]\w*)").unwrap();
+
+    let emitted: BTreeSet<&str> = emit_regex
+        .captures_iter(file_contents)
+        .map(|capture| capture.name("name").expect("event name should be captured").as_str())
+        .collect();
+
+    let mut events = Vec::new();
+    for capture in const_regex.captures_iter(file_contents) {
+        let masm_name = &capture["name"];
+        if !emitted.contains(masm_name) {
+            continue;
+        }
+
+        let id: u32 = capture["id"].parse().into_diagnostic()?;
+        if id >> 16 != KERNEL_EVENT_ID_PREFIX {
+            return Err(Report::msg(format!(
+                "event {masm_name} has ID {id} which does not start with the kernel event prefix {KERNEL_EVENT_ID_PREFIX}"
+            )));
+        }
+
+        let mut doc = Vec::new();
+        let mut stack = None;
+        for line in capture["comment"].lines() {
+            let line = line.trim_start_matches('#').trim();
+            match line.strip_prefix("Stack:") {
+                Some(layout) => stack = Some(parse_event_stack_layout(masm_name, layout)?),
+                None => doc.push(line.to_string()),
+            }
+        }
+
+        let Some(stack) = stack else {
+            return Err(Report::msg(format!(
+                "stack layout of event {masm_name} not found; add a `# Stack: [...]` comment line above the constant"
+            )));
+        };
+        if doc.is_empty() {
+            return Err(Report::msg(format!(
+                "documentation of event {masm_name} not found; add a comment above the constant"
+            )));
+        }
+
+        events.push(KernelEvent {
+            masm_name: masm_name.to_string(),
+            id,
+            doc,
+            stack,
+        });
+    }
+
+    Ok(events)
+}
+
+/// Parses a stack layout of the form `[a, B, c]` into the names of its items.
+fn parse_event_stack_layout(masm_name: &str, layout: &str) -> Result<Vec<String>> {
+    let items = layout
+        .trim()
+        .strip_prefix('[')
+        .and_then(|layout| layout.strip_suffix(']'))
+        .ok_or_else(|| {
+            Report::msg(format!("stack layout of event {masm_name} must be enclosed in brackets"))
+        })?;
+
+    Ok(items
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+/// Generates the content of the kernel event file for the given events.
+fn generate_kernel_event_file_content(events: Vec<KernelEvent>) -> Result<String> {
+    let mut variants = String::new();
+    let mut all = String::new();
+    let mut masm_names = String::new();
+    let mut stack_layouts = String::new();
+    let mut from_ids = String::new();
+
+    for event in events.iter() {
+        let KernelEvent { masm_name, id, doc, stack } = event;
+        let variant = event.variant_name();
+        let stack_items = stack.iter().map(|item| format!("\"{item}\"")).collect::<Vec<_>>();
+
+        for line in doc {
+            writeln!(variants, "    /// {line}").into_diagnostic()?;
+        }
+        writeln!(variants, "    ///").into_diagnostic()?;
+        writeln!(variants, "    /// Stack: `[{}]`", stack.join(", ")).into_diagnostic()?;
+        writeln!(variants, "    {variant} = 0x{:x}_{:04x},", id >> 16, id & 0xffff)
+            .into_diagnostic()?;
+
+        writeln!(all, "        Self::{variant},").into_diagnostic()?;
+        writeln!(masm_names, "            Self::{variant} => \"{masm_name}\",")
+            .into_diagnostic()?;
+        writeln!(stack_layouts, "            Self::{variant} => &[{}],", stack_items.join(", "))
+            .into_diagnostic()?;
+        writeln!(from_ids, "            {id} => Ok(Self::{variant}),").into_diagnostic()?;
+    }
+
+    let num_events = events.len();
+
+    Ok(format!(
+        r#"// This file is generated by build.rs, do not modify manually.
+// It is generated by extracting the events emitted via `emit.<NAME>` from the masm files in the
+// `miden-lib/asm` directory.
+//
+// To add a new event, define a constant with a new ID in masm and document it with a comment
+// directly above the constant. The comment must contain a `Stack: [...]` line which describes the
+// top of the operand stack at the time the event is emitted.
+
+use super::TransactionEventError;
+
+// KERNEL EVENT ID
+// ================================================================================================
+
+/// IDs of the events which may be emitted by the transaction kernel and the procedures of the
+/// `miden` library.
+///
+/// The discriminant of each variant is the event ID passed to the `emit` instruction. Event IDs
+/// are stable, i.e. the ID of an existing event never changes.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum KernelEventId {{
+{variants}}}
+
+impl KernelEventId {{
+    /// All events, sorted by their ID.
+    pub const ALL: [Self; {num_events}] = [
+{all}    ];
+
+    /// Returns the name of the MASM constant which defines the event.
+    pub const fn masm_name(&self) -> &'static str {{
+        match self {{
+{masm_names}        }}
+    }}
+
+    /// Returns the names of the items on top of the operand stack at the time the event is
+    /// emitted, starting with the top of the stack.
+    ///
+    /// Items with an uppercase name are words, all other items are single elements.
+    pub const fn stack_layout(&self) -> &'static [&'static str] {{
+        match self {{
+{stack_layouts}        }}
+    }}
+}}
+
+impl TryFrom<u32> for KernelEventId {{
+    type Error = TransactionEventError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {{
+        if value >> 16 != {KERNEL_EVENT_ID_PREFIX} {{
+            return Err(TransactionEventError::NotTransactionEvent(value));
+        }}
+
+        match value {{
+{from_ids}            _ => Err(TransactionEventError::InvalidTransactionEvent(value)),
+        }}
+    }}
+}}
+"#
+    ))
+}
+
+/// An event extracted from the MASM code.
+#[derive(Debug, Clone)]
+struct KernelEvent {
+    masm_name: String,
+    id: u32,
+    doc: Vec<String>,
+    stack: Vec<String>,
+}
+
+impl KernelEvent {
+    /// Returns the name of the constant without the `_EVENT` suffix in upper camel case, e.g.
+    /// `AccountVaultAfterAddAsset` for `ACCOUNT_VAULT_AFTER_ADD_ASSET_EVENT`.
+    fn variant_name(&self) -> String {
+        let name = self.masm_name.strip_suffix("_EVENT").unwrap_or(&self.masm_name);
+        to_upper_camel_case(name)
+    }
+}
+
 // ERROR CONSTANTS FILE GENERATION
 // ================================================================================================
 
@@ -841,6 +1363,7 @@ enum TxKernelErrorCategory {
     FungibleAsset,
     NonFugibleAsset,
     Vault,
+    Auth,
 }
 
 impl TxKernelErrorCategory {
@@ -857,6 +1380,7 @@ impl TxKernelErrorCategory {
             TxKernelErrorCategory::FungibleAsset => 0x2_0200..0x2_0240,
             TxKernelErrorCategory::NonFugibleAsset => 0x2_0240..0x2_0280,
             TxKernelErrorCategory::Vault => 0x2_0280..0x2_02c0,
+            TxKernelErrorCategory::Auth => 0x2_02c0..0x2_0300,
         }
     }
 
@@ -873,6 +1397,7 @@ impl TxKernelErrorCategory {
             TxKernelErrorCategory::FungibleAsset => "FUNGIBLE_ASSET",
             TxKernelErrorCategory::NonFugibleAsset => "NON_FUNGIBLE_ASSET",
             TxKernelErrorCategory::Vault => "VAULT",
+            TxKernelErrorCategory::Auth => "AUTH",
         }
     }
 }
@@ -1,9 +1,13 @@
 use miden_objects::{
-    account::{AccountComponent, StorageSlot},
+    Word,
+    account::{AccountComponent, AccountType, StorageSlot},
     crypto::dsa::rpo_falcon512::PublicKey,
 };
 
-use crate::account::components::rpo_falcon_512_library;
+use crate::account::components::{
+    bls12_381_library, ecdsa_k256_library, no_auth_library, rpo_falcon_512_library,
+    two_factor_auth_library,
+};
 
 /// An [`AccountComponent`] implementing the RpoFalcon512 signature scheme for authentication of
 /// transactions.
@@ -39,3 +43,161 @@ impl From<RpoFalcon512> for AccountComponent {
         .with_supports_all_types()
     }
 }
+
+/// An [`AccountComponent`] implementing the ECDSA signature scheme over the secp256k1 curve for
+/// authentication of transactions, which allows reusing keys of EVM accounts.
+///
+/// It reexports the procedures from `miden::contracts::auth::ecdsa_k256`. When linking against
+/// this component, the `miden` library (i.e. [`MidenLib`](crate::MidenLib)) must be available to
+/// the assembler which is the case when using [`TransactionKernel::assembler()`][kasm]. The
+/// procedures of this component are:
+/// - `auth_tx_ecdsa_k256`, which can be used to verify a signature provided via the advice stack to
+///   authenticate a transaction.
+///
+/// Since a secp256k1 public key does not fit into a single storage slot, the component stores the
+/// commitment to the public key (see
+/// [`PublicKey::commitment`](miden_objects::crypto::ecdsa_k256::PublicKey::commitment)) and the
+/// public key itself is provided via the advice stack together with the signature.
+///
+/// This component supports all account types.
+///
+/// [kasm]: crate::transaction::TransactionKernel::assembler
+pub struct EcdsaK256 {
+    public_key_commitment: Word,
+}
+
+impl EcdsaK256 {
+    /// Creates a new [`EcdsaK256`] component with the given commitment to a secp256k1 public key.
+    pub fn new(public_key_commitment: Word) -> Self {
+        Self { public_key_commitment }
+    }
+}
+
+impl From<EcdsaK256> for AccountComponent {
+    fn from(ecdsa: EcdsaK256) -> Self {
+        AccountComponent::new(
+            ecdsa_k256_library(),
+            vec![StorageSlot::Value(ecdsa.public_key_commitment)],
+        )
+        .expect("ecdsa component should satisfy the requirements of a valid account component")
+        .with_supports_all_types()
+    }
+}
+
+/// An [`AccountComponent`] implementing the BLS signature scheme over the BLS12-381 curve for
+/// authentication of transactions.
+///
+/// It reexports the procedures from `miden::contracts::auth::bls12_381`. When linking against
+/// this component, the `miden` library (i.e. [`MidenLib`](crate::MidenLib)) must be available to
+/// the assembler which is the case when using [`TransactionKernel::assembler()`][kasm]. The
+/// procedures of this component are:
+/// - `auth_tx_bls12_381`, which can be used to verify a signature provided via the advice stack to
+///   authenticate a transaction.
+///
+/// Since a BLS12-381 public key does not fit into a single storage slot, the component stores the
+/// commitment to the public key (see
+/// [`PublicKey::commitment`](miden_objects::crypto::bls12_381::PublicKey::commitment)) and the
+/// public key itself is provided via the advice stack together with the signature.
+///
+/// The public key can be the aggregate of the public keys of multiple signers (see
+/// [`PublicKey::aggregate`](miden_objects::crypto::bls12_381::PublicKey::aggregate)), in which case
+/// transactions are authenticated by the aggregate of their signatures.
+///
+/// This component supports all account types.
+///
+/// [kasm]: crate::transaction::TransactionKernel::assembler
+pub struct Bls12381 {
+    public_key_commitment: Word,
+}
+
+impl Bls12381 {
+    /// Creates a new [`Bls12381`] component with the given commitment to a BLS12-381 public key.
+    pub fn new(public_key_commitment: Word) -> Self {
+        Self { public_key_commitment }
+    }
+}
+
+impl From<Bls12381> for AccountComponent {
+    fn from(bls: Bls12381) -> Self {
+        AccountComponent::new(
+            bls12_381_library(),
+            vec![StorageSlot::Value(bls.public_key_commitment)],
+        )
+        .expect("bls component should satisfy the requirements of a valid account component")
+        .with_supports_all_types()
+    }
+}
+
+/// An [`AccountComponent`] requiring both an RpoFalcon512 signature and a secp256k1 ECDSA signature
+/// over the same message to authenticate a transaction, which is useful for high-value accounts
+/// whose keys are held on separate devices.
+///
+/// It reexports the procedures from `miden::contracts::auth::two_factor`. When linking against
+/// this component, the `miden` library (i.e. [`MidenLib`](crate::MidenLib)) must be available to
+/// the assembler which is the case when using [`TransactionKernel::assembler()`][kasm]. The
+/// procedures of this component are:
+/// - `auth_tx_two_factor`, which can be used to verify the two signatures provided via the advice
+///   stack to authenticate a transaction.
+///
+/// The component stores the Falcon public key in its first storage slot and the commitment to the
+/// secp256k1 public key in its second storage slot. Both signatures can be provided via the advice
+/// map of the transaction args, see `miden_tx::auth::signatures::get_two_factor_signatures`.
+///
+/// This component supports all account types.
+///
+/// [kasm]: crate::transaction::TransactionKernel::assembler
+pub struct TwoFactorAuth {
+    falcon_public_key: PublicKey,
+    ecdsa_public_key_commitment: Word,
+}
+
+impl TwoFactorAuth {
+    /// Creates a new [`TwoFactorAuth`] component with the given Falcon `falcon_public_key` and the
+    /// given commitment to a secp256k1 public key.
+    pub fn new(falcon_public_key: PublicKey, ecdsa_public_key_commitment: Word) -> Self {
+        Self {
+            falcon_public_key,
+            ecdsa_public_key_commitment,
+        }
+    }
+}
+
+impl From<TwoFactorAuth> for AccountComponent {
+    fn from(two_factor: TwoFactorAuth) -> Self {
+        AccountComponent::new(
+            two_factor_auth_library(),
+            vec![
+                StorageSlot::Value(two_factor.falcon_public_key.into()),
+                StorageSlot::Value(two_factor.ecdsa_public_key_commitment),
+            ],
+        )
+        .expect("two-factor component should satisfy the requirements of a valid account component")
+        .with_supports_all_types()
+    }
+}
+
+/// An [`AccountComponent`] which approves every transaction without authentication, for accounts
+/// which are intentionally meant to be used by anyone, e.g. game objects or burn-only sinks.
+///
+/// It reexports the procedures from `miden::contracts::auth::no_auth`. When linking against this
+/// component, the `miden` library (i.e. [`MidenLib`](crate::MidenLib)) must be available to the
+/// assembler which is the case when using [`TransactionKernel::assembler()`][kasm]. The procedures
+/// of this component are:
+/// - `auth_tx_no_auth`, which increments the nonce of the account and emits the
+///   [`UnauthenticatedTx`](crate::transaction::TransactionEvent::UnauthenticatedTx) event.
+///
+/// Since anyone can execute any procedure of an account with this component, it only supports
+/// regular accounts with immutable code. In particular, it cannot be used for faucets.
+///
+/// [kasm]: crate::transaction::TransactionKernel::assembler
+pub struct NoAuth;
+
+impl From<NoAuth> for AccountComponent {
+    fn from(_: NoAuth) -> Self {
+        AccountComponent::new(no_auth_library(), vec![])
+            .expect(
+                "no-auth component should satisfy the requirements of a valid account component",
+            )
+            .with_supported_type(AccountType::RegularAccountImmutableCode)
+    }
+}
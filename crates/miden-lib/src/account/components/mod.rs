@@ -10,6 +10,13 @@ static BASIC_WALLET_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
     Library::read_from_bytes(bytes).expect("Shipped Basic Wallet library is well-formed")
 });
 
+// Initialize the Basic Wallet V2 library only once.
+static BASIC_WALLET_V2_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
+    let bytes =
+        include_bytes!(concat!(env!("OUT_DIR"), "/assets/account_components/basic_wallet_v2.masl"));
+    Library::read_from_bytes(bytes).expect("Shipped Basic Wallet V2 library is well-formed")
+});
+
 // Initialize the Rpo Falcon 512 library only once.
 static RPO_FALCON_512_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
     let bytes =
@@ -17,6 +24,33 @@ static RPO_FALCON_512_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
     Library::read_from_bytes(bytes).expect("Shipped Rpo Falcon 512 library is well-formed")
 });
 
+// Initialize the ECDSA secp256k1 library only once.
+static ECDSA_K256_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
+    let bytes =
+        include_bytes!(concat!(env!("OUT_DIR"), "/assets/account_components/ecdsa_k256.masl"));
+    Library::read_from_bytes(bytes).expect("Shipped ECDSA secp256k1 library is well-formed")
+});
+
+// Initialize the BLS12-381 library only once.
+static BLS12_381_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
+    let bytes =
+        include_bytes!(concat!(env!("OUT_DIR"), "/assets/account_components/bls12_381.masl"));
+    Library::read_from_bytes(bytes).expect("Shipped BLS12-381 library is well-formed")
+});
+
+// Initialize the Two-Factor Auth library only once.
+static TWO_FACTOR_AUTH_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
+    let bytes =
+        include_bytes!(concat!(env!("OUT_DIR"), "/assets/account_components/two_factor_auth.masl"));
+    Library::read_from_bytes(bytes).expect("Shipped Two-Factor Auth library is well-formed")
+});
+
+// Initialize the No-Auth library only once.
+static NO_AUTH_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/account_components/no_auth.masl"));
+    Library::read_from_bytes(bytes).expect("Shipped No-Auth library is well-formed")
+});
+
 // Initialize the Basic Fungible Faucet library only once.
 static BASIC_FUNGIBLE_FAUCET_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
     let bytes = include_bytes!(concat!(
@@ -26,17 +60,95 @@ static BASIC_FUNGIBLE_FAUCET_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
     Library::read_from_bytes(bytes).expect("Shipped Basic Fungible Faucet library is well-formed")
 });
 
+// Initialize the Basic Non-Fungible Faucet library only once.
+static BASIC_NON_FUNGIBLE_FAUCET_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(
+        env!("OUT_DIR"),
+        "/assets/account_components/basic_non_fungible_faucet.masl"
+    ));
+    Library::read_from_bytes(bytes)
+        .expect("Shipped Basic Non-Fungible Faucet library is well-formed")
+});
+
+// Initialize the Standard Price Oracle library only once.
+static STANDARD_PRICE_ORACLE_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(
+        env!("OUT_DIR"),
+        "/assets/account_components/standard_price_oracle.masl"
+    ));
+    Library::read_from_bytes(bytes).expect("Shipped Standard Price Oracle library is well-formed")
+});
+
+// Initialize the Asset Allowlist library only once.
+static ASSET_ALLOWLIST_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
+    let bytes =
+        include_bytes!(concat!(env!("OUT_DIR"), "/assets/account_components/asset_allowlist.masl"));
+    Library::read_from_bytes(bytes).expect("Shipped Asset Allowlist library is well-formed")
+});
+
+// Initialize the Atomic Counter library only once.
+static ATOMIC_COUNTER_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
+    let bytes =
+        include_bytes!(concat!(env!("OUT_DIR"), "/assets/account_components/atomic_counter.masl"));
+    Library::read_from_bytes(bytes).expect("Shipped Atomic Counter library is well-formed")
+});
+
 /// Returns the Basic Wallet Library.
 pub fn basic_wallet_library() -> Library {
     BASIC_WALLET_LIBRARY.clone()
 }
 
+/// Returns the Basic Wallet V2 Library.
+pub fn basic_wallet_v2_library() -> Library {
+    BASIC_WALLET_V2_LIBRARY.clone()
+}
+
 /// Returns the Rpo Falcon 512 Library.
 pub fn rpo_falcon_512_library() -> Library {
     RPO_FALCON_512_LIBRARY.clone()
 }
 
+/// Returns the ECDSA secp256k1 Library.
+pub fn ecdsa_k256_library() -> Library {
+    ECDSA_K256_LIBRARY.clone()
+}
+
+/// Returns the BLS12-381 Library.
+pub fn bls12_381_library() -> Library {
+    BLS12_381_LIBRARY.clone()
+}
+
+/// Returns the Two-Factor Auth Library.
+pub fn two_factor_auth_library() -> Library {
+    TWO_FACTOR_AUTH_LIBRARY.clone()
+}
+
+/// Returns the No-Auth Library.
+pub fn no_auth_library() -> Library {
+    NO_AUTH_LIBRARY.clone()
+}
+
 /// Returns the Basic Fungible Faucet Library.
 pub fn basic_fungible_faucet_library() -> Library {
     BASIC_FUNGIBLE_FAUCET_LIBRARY.clone()
 }
+
+/// Returns the Basic Non-Fungible Faucet Library.
+pub fn basic_non_fungible_faucet_library() -> Library {
+    BASIC_NON_FUNGIBLE_FAUCET_LIBRARY.clone()
+}
+
+/// Returns the Standard Price Oracle Library.
+pub fn standard_price_oracle_library() -> Library {
+    STANDARD_PRICE_ORACLE_LIBRARY.clone()
+}
+
+/// Returns the Asset Allowlist Library.
+pub fn asset_allowlist_library() -> Library {
+    ASSET_ALLOWLIST_LIBRARY.clone()
+}
+
+/// Returns the Atomic Counter Library.
+pub fn atomic_counter_library() -> Library {
+    ATOMIC_COUNTER_LIBRARY.clone()
+}
@@ -0,0 +1,77 @@
+use miden_objects::{
+    Digest, Felt, ZERO,
+    account::{AccountComponent, StorageSlot},
+    assembly::{ProcedureName, QualifiedProcedureName},
+};
+
+use crate::account::components::atomic_counter_library;
+
+// ATOMIC COUNTER
+// ================================================================================================
+
+/// An [`AccountComponent`] implementing a counter which can be used as a source of unique sequence
+/// numbers, e.g. order IDs.
+///
+/// It reexports the procedures from `miden::contracts::counters::atomic`. When linking against this
+/// component, the `miden` library (i.e. [`MidenLib`](crate::MidenLib)) must be available to the
+/// assembler which is the case when using [`TransactionKernel::assembler()`][kasm]. The procedures
+/// of this component are:
+/// - `increment_and_get`, which increments the counter by one and returns the new value. Since the
+///   counter is part of the account state, each value is returned by at most one transaction.
+/// - `get_count`, which returns the current value of the counter. It is meant to be invoked by
+///   other accounts or notes via foreign procedure invocation.
+///
+/// The counter is stored as `[count, 0, 0, 0]` in the first storage slot of the component and
+/// cannot exceed `u32::MAX`.
+///
+/// `increment_and_get` requires authentication. Thus, this component must be combined with a
+/// component providing authentication.
+///
+/// This component supports all account types.
+///
+/// [kasm]: crate::transaction::TransactionKernel::assembler
+pub struct AtomicCounter {
+    initial_count: u32,
+}
+
+impl AtomicCounter {
+    /// Returns a new [`AtomicCounter`] component whose counter starts at the provided value.
+    pub fn new(initial_count: u32) -> Self {
+        Self { initial_count }
+    }
+
+    /// Returns the MAST root of the `increment_and_get` procedure of this component.
+    pub fn increment_and_get_root() -> Digest {
+        Self::procedure_root("increment_and_get")
+    }
+
+    /// Returns the MAST root of the `get_count` procedure of this component.
+    pub fn get_count_root() -> Digest {
+        Self::procedure_root("get_count")
+    }
+
+    /// Returns the MAST root of the procedure with the provided name.
+    fn procedure_root(name: &str) -> Digest {
+        let proc_name =
+            QualifiedProcedureName::new(Default::default(), ProcedureName::new(name).unwrap());
+        let library = atomic_counter_library();
+        let node_id = library.get_export_node_id(&proc_name);
+
+        library.mast_forest()[node_id].digest()
+    }
+}
+
+impl From<AtomicCounter> for AccountComponent {
+    fn from(counter: AtomicCounter) -> Self {
+        let count = Felt::from(counter.initial_count);
+
+        AccountComponent::new(
+            atomic_counter_library(),
+            vec![StorageSlot::Value([count, ZERO, ZERO, ZERO])],
+        )
+        .expect(
+            "atomic counter component should satisfy the requirements of a valid account component",
+        )
+        .with_supports_all_types()
+    }
+}
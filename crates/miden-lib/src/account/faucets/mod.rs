@@ -1,14 +1,21 @@
+use alloc::vec::Vec;
+
 use miden_objects::{
-    AccountError, Felt, FieldElement, Word,
+    AccountError, Digest, Felt, FieldElement, Hasher, Word,
     account::{
-        Account, AccountBuilder, AccountComponent, AccountIdAnchor, AccountStorageMode,
+        Account, AccountBuilder, AccountComponent, AccountId, AccountIdAnchor, AccountStorageMode,
         AccountType, StorageSlot,
     },
     asset::{FungibleAsset, TokenSymbol},
+    crypto::dsa::rpo_falcon512::PublicKey,
+    note::NoteMetadata,
 };
 
 use super::AuthScheme;
-use crate::account::{auth::RpoFalcon512, components::basic_fungible_faucet_library};
+use crate::account::{
+    auth::{Bls12381, EcdsaK256, NoAuth, RpoFalcon512, TwoFactorAuth},
+    components::{basic_fungible_faucet_library, basic_non_fungible_faucet_library},
+};
 
 // BASIC FUNGIBLE FAUCET ACCOUNT COMPONENT
 // ================================================================================================
@@ -20,11 +27,23 @@ use crate::account::{auth::RpoFalcon512, components::basic_fungible_faucet_libra
 /// available to the assembler which is the case when using
 /// [`TransactionKernel::assembler()`][kasm]. The procedures of this component are:
 /// - `distribute`, which mints an assets and create a note for the provided recipient.
+/// - `auth_tx_minter`, which authenticates a transaction performing a single distribution signed
+///   by the minter.
+/// - `set_minter`, which sets the public key of the minter.
 /// - `burn`, which burns the provided asset.
 ///
-/// `distribute` requires authentication while `burn` does not require authentication and can be
-/// called by anyone. Thus, this component must be combined with a component providing
-/// authentication.
+/// `distribute` and `set_minter` require authentication while `burn` does not require
+/// authentication and can be called by anyone. Thus, this component must be combined with a
+/// component providing authentication.
+///
+/// `auth_tx_minter` is an alternative authentication procedure for the minter, i.e., a separate
+/// Falcon key which is stored in the storage of this component and can be rotated by the owner of
+/// the faucet via `set_minter`. It verifies a signature of the minter over a single distribution,
+/// performs it and increments the nonce. The account state must be unchanged before it is called
+/// and `distribute` is rejected after it, so a minter signature never authorizes more than the
+/// signed distribution. This allows delegating the issuance of tokens to a service without giving
+/// it control over the faucet. The message signed by the minter can be computed via
+/// [`BasicFungibleFaucet::minter_message`].
 ///
 /// This component supports accounts of type [`AccountType::FungibleFaucet`].
 ///
@@ -33,6 +52,7 @@ pub struct BasicFungibleFaucet {
     symbol: TokenSymbol,
     decimals: u8,
     max_supply: Felt,
+    minter: Option<PublicKey>,
 }
 
 impl BasicFungibleFaucet {
@@ -60,7 +80,48 @@ impl BasicFungibleFaucet {
             });
         }
 
-        Ok(Self { symbol, decimals, max_supply })
+        Ok(Self { symbol, decimals, max_supply, minter: None })
+    }
+
+    /// Sets the public key of the minter which is allowed to distribute tokens on behalf of the
+    /// owner of the faucet.
+    pub fn with_minter(mut self, minter: PublicKey) -> Self {
+        self.minter = Some(minter);
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the message the minter has to sign to authorize the distribution of `amount`
+    /// tokens of the specified faucet in a note with the provided metadata and recipient.
+    ///
+    /// `nonce` is the nonce of the faucet account before the transaction is executed. Binding the
+    /// message to it prevents the signature from being replayed in another transaction.
+    pub fn minter_message(
+        faucet_id: AccountId,
+        nonce: Felt,
+        amount: u64,
+        metadata: &NoteMetadata,
+        recipient: Digest,
+    ) -> Word {
+        // words are laid out such that they match their order on the operand stack of
+        // `auth_tx_minter`
+        let params = [
+            metadata.note_type().into(),
+            metadata.aux(),
+            metadata.tag().into(),
+            Felt::new(amount),
+        ];
+        let execution_hint = [Felt::ZERO, Felt::ZERO, Felt::ZERO, metadata.execution_hint().into()];
+        let params_commitment = Hasher::merge(&[params.into(), execution_hint.into()]);
+        let mint_commitment = Hasher::merge(&[params_commitment, recipient]);
+
+        let nonce = [nonce, Felt::ZERO, Felt::ZERO, Felt::ZERO];
+        let account_id = [faucet_id.suffix(), faucet_id.prefix().as_felt(), Felt::ZERO, Felt::ZERO];
+        let account_commitment = Hasher::merge(&[nonce.into(), account_id.into()]);
+
+        Hasher::merge(&[mint_commitment, account_commitment]).into()
     }
 }
 
@@ -71,9 +132,95 @@ impl From<BasicFungibleFaucet> for AccountComponent {
         let metadata =
             [faucet.max_supply, Felt::from(faucet.decimals), faucet.symbol.into(), Felt::ZERO];
 
-        AccountComponent::new(basic_fungible_faucet_library(), vec![StorageSlot::Value(metadata)])
-            .expect("basic fungible faucet component should satisfy the requirements of a valid account component")
-            .with_supported_type(AccountType::FungibleFaucet)
+        let minter = faucet.minter.map(Word::from).unwrap_or_default();
+
+        // the initial account commitment of the last transaction authenticated by the minter
+        let minter_tx = Word::default();
+
+        AccountComponent::new(
+            basic_fungible_faucet_library(),
+            vec![
+                StorageSlot::Value(metadata),
+                StorageSlot::Value(minter),
+                StorageSlot::Value(minter_tx),
+            ],
+        )
+        .expect("basic fungible faucet component should satisfy the requirements of a valid account component")
+        .with_supported_type(AccountType::FungibleFaucet)
+    }
+}
+
+// BASIC NON-FUNGIBLE FAUCET ACCOUNT COMPONENT
+// ================================================================================================
+
+/// An [`AccountComponent`] implementing a basic non-fungible faucet.
+///
+/// It reexports the procedures from `miden::contracts::faucets::basic_non_fungible`. When linking
+/// against this component, the `miden` library (i.e. [`MidenLib`](crate::MidenLib)) must be
+/// available to the assembler which is the case when using
+/// [`TransactionKernel::assembler()`][kasm]. The procedures of this component are:
+/// - `distribute_batch`, which mints up to [`BasicNonFungibleFaucet::MAX_BATCH_SIZE`]
+///   non-fungible assets and creates a single note containing them for the provided recipient.
+/// - `burn`, which burns the provided asset.
+///
+/// The data hashes of the assets to mint are read from the advice map. The corresponding entry can
+/// be generated via [`BasicNonFungibleFaucet::distribute_batch_advice_inputs`]. Every minted asset
+/// is recorded in the issuance map of the faucet by the kernel, so an asset cannot be issued twice.
+///
+/// `distribute_batch` requires authentication while `burn` does not require authentication and can
+/// be called by anyone. Thus, this component must be combined with a component providing
+/// authentication.
+///
+/// This component supports accounts of type [`AccountType::NonFungibleFaucet`].
+///
+/// [kasm]: crate::transaction::TransactionKernel::assembler
+pub struct BasicNonFungibleFaucet;
+
+impl BasicNonFungibleFaucet {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The maximum number of non-fungible assets which can be distributed in a single batch.
+    pub const MAX_BATCH_SIZE: usize = 64;
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the advice map entry from which `distribute_batch` reads the provided data hashes
+    /// of the non-fungible assets to mint.
+    ///
+    /// The key of the entry is the commitment to the data hashes, which must be passed to
+    /// `distribute_batch` as `DATA_HASHES_COMMITMENT`, and the value holds the data hashes padded
+    /// to an even number of words.
+    ///
+    /// # Errors
+    /// Returns an error if no data hashes or more than [`Self::MAX_BATCH_SIZE`] data hashes are
+    /// provided.
+    pub fn distribute_batch_advice_inputs(
+        data_hashes: &[Word],
+    ) -> Result<(Digest, Vec<Felt>), AccountError> {
+        if data_hashes.is_empty() || data_hashes.len() > Self::MAX_BATCH_SIZE {
+            return Err(AccountError::NonFungibleFaucetInvalidBatchSize {
+                actual: data_hashes.len(),
+                max: Self::MAX_BATCH_SIZE,
+            });
+        }
+
+        // the data hashes are read from the advice stack two words at a time
+        let mut elements: Vec<Felt> = data_hashes.iter().flatten().copied().collect();
+        if data_hashes.len() % 2 == 1 {
+            elements.extend(Word::default());
+        }
+
+        Ok((Hasher::hash_elements(&elements), elements))
+    }
+}
+
+impl From<BasicNonFungibleFaucet> for AccountComponent {
+    fn from(_: BasicNonFungibleFaucet) -> Self {
+        AccountComponent::new(basic_non_fungible_faucet_library(), vec![])
+            .expect("basic non-fungible faucet component should satisfy the requirements of a valid account component")
+            .with_supported_type(AccountType::NonFungibleFaucet)
     }
 }
 
@@ -95,6 +242,11 @@ impl From<BasicFungibleFaucet> for AccountComponent {
 /// - Slot 0: Reserved slot for faucets.
 /// - Slot 1: Public Key of the authentication component.
 /// - Slot 2: Token metadata of the faucet.
+/// - Slot 3: Public key of the minter of the faucet, which is initially not set.
+/// - Slot 4: Initial account commitment of the last transaction authenticated by the minter.
+///
+/// The two-factor authentication scheme stores two values, in which case the slots of the faucet
+/// component are shifted by one.
 pub fn create_basic_fungible_faucet(
     init_seed: [u8; 32],
     id_anchor: AccountIdAnchor,
@@ -104,10 +256,14 @@ pub fn create_basic_fungible_faucet(
     account_storage_mode: AccountStorageMode,
     auth_scheme: AuthScheme,
 ) -> Result<(Account, Word), AccountError> {
-    // Atm we only have RpoFalcon512 as authentication scheme and this is also the default in the
-    // faucet contract.
-    let auth_component: RpoFalcon512 = match auth_scheme {
-        AuthScheme::RpoFalcon512 { pub_key } => RpoFalcon512::new(pub_key),
+    let auth_component: AccountComponent = match auth_scheme {
+        AuthScheme::RpoFalcon512 { pub_key } => RpoFalcon512::new(pub_key).into(),
+        AuthScheme::EcdsaK256 { pub_key } => EcdsaK256::new(pub_key).into(),
+        AuthScheme::Bls12381 { pub_key } => Bls12381::new(pub_key).into(),
+        AuthScheme::TwoFactor { falcon_pub_key, ecdsa_pub_key } => {
+            TwoFactorAuth::new(falcon_pub_key, ecdsa_pub_key).into()
+        },
+        AuthScheme::NoAuth => NoAuth.into(),
     };
 
     let (account, account_seed) = AccountBuilder::new(init_seed)
@@ -131,7 +287,10 @@ mod tests {
     };
     use vm_processor::Word;
 
-    use super::{AccountStorageMode, AuthScheme, Felt, TokenSymbol, create_basic_fungible_faucet};
+    use super::{
+        AccountError, AccountStorageMode, AuthScheme, BasicNonFungibleFaucet, Felt, Hasher,
+        TokenSymbol, create_basic_fungible_faucet,
+    };
 
     #[test]
     fn faucet_contract_creation() {
@@ -183,6 +342,32 @@ mod tests {
             [Felt::new(123), Felt::new(2), token_symbol.into(), Felt::ZERO].into()
         );
 
+        // The minter of the faucet is not set initially.
+        assert_eq!(faucet_account.storage().get_item(3).unwrap(), Word::default().into());
+
+        // No transaction has been authenticated by the minter yet.
+        assert_eq!(faucet_account.storage().get_item(4).unwrap(), Word::default().into());
+
         assert!(faucet_account.is_faucet());
     }
+    #[test]
+    fn non_fungible_faucet_batch_advice_inputs() {
+        let data_hashes = [[ONE; 4], [Felt::new(2); 4], [Felt::new(3); 4]];
+
+        let (commitment, elements) =
+            BasicNonFungibleFaucet::distribute_batch_advice_inputs(&data_hashes).unwrap();
+
+        // an odd number of data hashes is padded with an empty word
+        assert_eq!(elements.len(), 16);
+        assert_eq!(elements[12..], [Felt::ZERO; 4]);
+        assert_eq!(commitment, Hasher::hash_elements(&elements));
+
+        for num_assets in [0, BasicNonFungibleFaucet::MAX_BATCH_SIZE + 1] {
+            assert!(matches!(
+                BasicNonFungibleFaucet::distribute_batch_advice_inputs(&vec![[ONE; 4]; num_assets]),
+                Err(AccountError::NonFungibleFaucetInvalidBatchSize { actual, .. })
+                    if actual == num_assets
+            ));
+        }
+    }
 }
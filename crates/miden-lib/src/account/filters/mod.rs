@@ -0,0 +1,65 @@
+use alloc::vec::Vec;
+
+use miden_objects::{
+    Felt, ONE, ZERO,
+    account::{AccountComponent, AccountIdPrefix, StorageMap, StorageSlot},
+};
+
+use crate::account::components::asset_allowlist_library;
+
+// ASSET ALLOWLIST
+// ================================================================================================
+
+/// An [`AccountComponent`] implementing an asset filter which only accepts assets issued by an
+/// allowlist of faucets.
+///
+/// It reexports the procedures from `miden::contracts::filters::allowlist`. When linking against
+/// this component, the `miden` library (i.e. [`MidenLib`](crate::MidenLib)) must be available to
+/// the assembler which is the case when using [`TransactionKernel::assembler()`][kasm]. The
+/// procedures of this component are:
+/// - `filter_incoming_asset`, which is the asset filter of the account (see
+///   [`AccountCode::ASSET_FILTER_NAME`](miden_objects::account::AccountCode::ASSET_FILTER_NAME)).
+///   It is invoked by the transaction kernel for every asset added to the account vault and
+///   rejects the transaction if the asset was not issued by one of the allowed faucets.
+///
+/// The allowed faucets are stored in a storage map in the first storage slot of the component. The
+/// key of a faucet is `[0, 0, 0, faucet_id_prefix]`, which stores every faucet in its own leaf of
+/// the map, and the value stored under it is `[1, 0, 0, 0]`.
+///
+/// An account can have at most one asset filter, so this component cannot be combined with other
+/// components exporting an asset filter.
+///
+/// This component supports all account types.
+///
+/// [kasm]: crate::transaction::TransactionKernel::assembler
+pub struct AssetAllowlist {
+    allowed_faucets: StorageMap,
+}
+
+impl AssetAllowlist {
+    /// Returns a new [`AssetAllowlist`] component which only accepts assets issued by the provided
+    /// faucets.
+    pub fn new(allowed_faucets: impl IntoIterator<Item = AccountIdPrefix>) -> Self {
+        let mut map = StorageMap::new();
+        for faucet_id_prefix in allowed_faucets {
+            map.insert(Self::faucet_key(faucet_id_prefix).into(), [ONE, ZERO, ZERO, ZERO]);
+        }
+
+        Self { allowed_faucets: map }
+    }
+
+    /// Returns the storage map key under which the provided faucet is stored in the allowlist.
+    pub fn faucet_key(faucet_id_prefix: AccountIdPrefix) -> [Felt; 4] {
+        [ZERO, ZERO, ZERO, faucet_id_prefix.as_felt()]
+    }
+}
+
+impl From<AssetAllowlist> for AccountComponent {
+    fn from(allowlist: AssetAllowlist) -> Self {
+        let storage_slots: Vec<StorageSlot> = vec![StorageSlot::Map(allowlist.allowed_faucets)];
+
+        AccountComponent::new(asset_allowlist_library(), storage_slots)
+            .expect("asset allowlist component should satisfy the requirements of a valid account component")
+            .with_supports_all_types()
+    }
+}
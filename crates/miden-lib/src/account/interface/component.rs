@@ -12,8 +12,13 @@ use miden_objects::{
 };
 
 use crate::account::{
-    components::{basic_fungible_faucet_library, basic_wallet_library, rpo_falcon_512_library},
+    components::{
+        basic_fungible_faucet_library, basic_wallet_library, basic_wallet_v2_library,
+        bls12_381_library, ecdsa_k256_library, no_auth_library, rpo_falcon_512_library,
+        two_factor_auth_library,
+    },
     interface::AccountInterfaceError,
+    wallets::SendAssetArgs,
 };
 
 // ACCOUNT COMPONENT INTERFACE
@@ -24,6 +29,9 @@ use crate::account::{
 pub enum AccountComponentInterface {
     /// Exposes procedures from the [`BasicWallet`][crate::account::wallets::BasicWallet] module.
     BasicWallet,
+    /// Exposes procedures from the [`BasicWalletV2`][crate::account::wallets::BasicWalletV2]
+    /// module.
+    BasicWalletV2,
     /// Exposes procedures from the
     /// [`BasicFungibleFaucet`][crate::account::faucets::BasicFungibleFaucet] module.
     BasicFungibleFaucet,
@@ -33,6 +41,24 @@ pub enum AccountComponentInterface {
     /// Internal value holds the storage index where the public key for the RpoFalcon512
     /// authentication scheme is stored.
     RpoFalcon512(u8),
+    /// Exposes procedures from the [`EcdsaK256`][crate::account::auth::EcdsaK256] module.
+    ///
+    /// Internal value holds the storage index where the public key commitment for the EcdsaK256
+    /// authentication scheme is stored.
+    EcdsaK256(u8),
+    /// Exposes procedures from the [`Bls12381`][crate::account::auth::Bls12381] module.
+    ///
+    /// Internal value holds the storage index where the public key commitment for the Bls12381
+    /// authentication scheme is stored.
+    Bls12381(u8),
+    /// Exposes procedures from the [`TwoFactorAuth`][crate::account::auth::TwoFactorAuth] module.
+    ///
+    /// Internal value holds the storage index where the Falcon public key of the two-factor
+    /// authentication scheme is stored. The commitment to the ECDSA public key is stored at the
+    /// subsequent storage index.
+    TwoFactorAuth(u8),
+    /// Exposes procedures from the [`NoAuth`][crate::account::auth::NoAuth] module.
+    NoAuth,
     /// A non-standard, custom interface which exposes the contained procedures.
     ///
     /// Custom interface holds procedures which are not part of some standard interface which is
@@ -49,8 +75,13 @@ impl AccountComponentInterface {
     pub fn name(&self) -> String {
         match self {
             AccountComponentInterface::BasicWallet => "Basic Wallet".to_string(),
+            AccountComponentInterface::BasicWalletV2 => "Basic Wallet V2".to_string(),
             AccountComponentInterface::BasicFungibleFaucet => "Basic Fungible Faucet".to_string(),
             AccountComponentInterface::RpoFalcon512(_) => "RPO Falcon512".to_string(),
+            AccountComponentInterface::EcdsaK256(_) => "ECDSA secp256k1".to_string(),
+            AccountComponentInterface::Bls12381(_) => "BLS12-381".to_string(),
+            AccountComponentInterface::TwoFactorAuth(_) => "Two-Factor Auth".to_string(),
+            AccountComponentInterface::NoAuth => "No Auth".to_string(),
             AccountComponentInterface::Custom(proc_info_vec) => {
                 let result = proc_info_vec
                     .iter()
@@ -89,6 +120,23 @@ impl AccountComponentInterface {
             component_interface_vec.push(AccountComponentInterface::BasicWallet);
         }
 
+        // Basic Wallet V2
+        // ------------------------------------------------------------------------------------------------
+
+        if basic_wallet_v2_library()
+            .mast_forest()
+            .procedure_digests()
+            .all(|proc_digest| procedures.contains_key(&proc_digest))
+        {
+            basic_wallet_v2_library().mast_forest().procedure_digests().for_each(
+                |component_procedure| {
+                    procedures.remove(&component_procedure);
+                },
+            );
+
+            component_interface_vec.push(AccountComponentInterface::BasicWalletV2);
+        }
+
         // Basic Fungible Faucet
         // ------------------------------------------------------------------------------------------------
 
@@ -120,6 +168,61 @@ impl AccountComponentInterface {
                 .push(AccountComponentInterface::RpoFalcon512(proc_info.storage_offset()));
         }
 
+        // ECDSA secp256k1
+        // ------------------------------------------------------------------------------------------------
+
+        let ecdsa_k256_proc = ecdsa_k256_library()
+            .mast_forest()
+            .procedure_digests()
+            .next()
+            .expect("ecdsa k256 component should export exactly one procedure");
+
+        if let Some(proc_info) = procedures.remove(&ecdsa_k256_proc) {
+            component_interface_vec
+                .push(AccountComponentInterface::EcdsaK256(proc_info.storage_offset()));
+        }
+
+        // BLS12-381
+        // ------------------------------------------------------------------------------------------------
+
+        let bls12_381_proc = bls12_381_library()
+            .mast_forest()
+            .procedure_digests()
+            .next()
+            .expect("bls12-381 component should export exactly one procedure");
+
+        if let Some(proc_info) = procedures.remove(&bls12_381_proc) {
+            component_interface_vec
+                .push(AccountComponentInterface::Bls12381(proc_info.storage_offset()));
+        }
+
+        // Two-factor auth
+        // ------------------------------------------------------------------------------------------------
+
+        let two_factor_auth_proc = two_factor_auth_library()
+            .mast_forest()
+            .procedure_digests()
+            .next()
+            .expect("two-factor auth component should export exactly one procedure");
+
+        if let Some(proc_info) = procedures.remove(&two_factor_auth_proc) {
+            component_interface_vec
+                .push(AccountComponentInterface::TwoFactorAuth(proc_info.storage_offset()));
+        }
+
+        // No auth
+        // ------------------------------------------------------------------------------------------------
+
+        let no_auth_proc = no_auth_library()
+            .mast_forest()
+            .procedure_digests()
+            .next()
+            .expect("no-auth component should export exactly one procedure");
+
+        if procedures.remove(&no_auth_proc).is_some() {
+            component_interface_vec.push(AccountComponentInterface::NoAuth);
+        }
+
         // Custom interfaces
         // ------------------------------------------------------------------------------------------------
 
@@ -152,6 +255,8 @@ impl AccountComponentInterface {
     ///   - For basic wallet: creating a note, pushing the assets on the stack and moving them to
     ///     the created note.
     ///
+    /// For the basic wallet v2 both sections are combined into a single `send_asset` call per note.
+    ///
     /// # Examples
     ///
     /// Example script for the [`AccountComponentInterface::BasicWallet`] with one note:
@@ -174,11 +279,19 @@ impl AccountComponentInterface {
     ///     call.::miden::contracts::faucets::basic_fungible::distribute dropw dropw drop
     /// ```
     ///
+    /// Example script for the [`AccountComponentInterface::BasicWalletV2`] with one note:
+    ///
+    /// ```masm
+    ///     push.{note information}
+    ///     push.{note asset}
+    ///     call.::miden::contracts::wallets::basic_v2::send_asset dropw dropw dropw
+    /// ```
+    ///
     /// # Errors:
     /// Returns an error if:
     /// - the interface does not support the generation of the standard `send_note` procedure.
     /// - the sender of the note isn't the account for which the script is being built.
-    /// - the note created by the faucet doesn't contain exactly one asset.
+    /// - the note created by the faucet or the basic wallet v2 doesn't contain exactly one asset.
     /// - a faucet tries to distribute an asset with a different faucet ID.
     pub(crate) fn send_note_body(
         &self,
@@ -188,12 +301,33 @@ impl AccountComponentInterface {
         let mut body = String::new();
 
         for partial_note in notes {
-            if partial_note.metadata().sender() != sender_account_id {
+            if partial_note.metadata().sender().account_id() != Some(sender_account_id) {
                 return Err(AccountInterfaceError::InvalidSenderAccount(
                     partial_note.metadata().sender(),
                 ));
             }
 
+            if let AccountComponentInterface::BasicWalletV2 = self {
+                let mut assets = partial_note.assets().iter();
+                let (Some(asset), None) = (assets.next(), assets.next()) else {
+                    return Err(AccountInterfaceError::SendAssetNoteWithoutSingleAsset);
+                };
+
+                let send_asset_args = SendAssetArgs::new(
+                    *asset,
+                    partial_note.recipient_digest(),
+                    partial_note.metadata().note_type(),
+                    partial_note.metadata().tag(),
+                    partial_note.metadata().execution_hint(),
+                    partial_note.metadata().aux(),
+                )
+                .map_err(AccountInterfaceError::InvalidOutputNote)?;
+
+                body.push_str(&send_asset_args.to_masm());
+                // stack => []
+                continue;
+            }
+
             body.push_str(&format!(
                 "push.{recipient}
                 push.{execution_hint}
@@ -0,0 +1,259 @@
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+
+use miden_objects::{
+    Digest,
+    account::AccountId,
+    assembly::{
+        DefaultSourceManager, Library, LibraryPath, Module, ModuleKind, ProcedureName,
+        QualifiedProcedureName,
+    },
+};
+
+use crate::{account::interface::AccountInterfaceError, transaction::TransactionKernel};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The maximum number of inputs a procedure invoked via FPI can receive.
+pub const MAX_FOREIGN_PROCEDURE_INPUTS: u8 = 15;
+
+/// The maximum number of outputs a procedure invoked via FPI can return.
+pub const MAX_FOREIGN_PROCEDURE_OUTPUTS: u8 = 16;
+
+// FOREIGN PROCEDURE
+// ================================================================================================
+
+/// A read procedure of an account for which an FPI stub is generated.
+///
+/// The stub of the procedure is exported under the provided name and takes exactly `num_inputs`
+/// stack elements, returning exactly `num_outputs` stack elements.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForeignProcedure {
+    name: ProcedureName,
+    root: Digest,
+    num_inputs: u8,
+    num_outputs: u8,
+}
+
+impl ForeignProcedure {
+    /// Returns a new [ForeignProcedure] instantiated from the provided parameters.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `num_inputs` is greater than [MAX_FOREIGN_PROCEDURE_INPUTS].
+    /// - `num_outputs` is greater than [MAX_FOREIGN_PROCEDURE_OUTPUTS].
+    pub fn new(
+        name: ProcedureName,
+        root: Digest,
+        num_inputs: u8,
+        num_outputs: u8,
+    ) -> Result<Self, AccountInterfaceError> {
+        if num_inputs > MAX_FOREIGN_PROCEDURE_INPUTS {
+            return Err(AccountInterfaceError::TooManyForeignProcedureInputs { name, num_inputs });
+        }
+        if num_outputs > MAX_FOREIGN_PROCEDURE_OUTPUTS {
+            return Err(AccountInterfaceError::TooManyForeignProcedureOutputs {
+                name,
+                num_outputs,
+            });
+        }
+
+        Ok(Self { name, root, num_inputs, num_outputs })
+    }
+
+    /// Returns the name under which the stub of the procedure is exported.
+    pub fn name(&self) -> &ProcedureName {
+        &self.name
+    }
+
+    /// Returns the MAST root of the procedure of the foreign account.
+    pub fn root(&self) -> Digest {
+        self.root
+    }
+
+    /// Returns the number of stack inputs of the procedure.
+    pub fn num_inputs(&self) -> u8 {
+        self.num_inputs
+    }
+
+    /// Returns the number of stack outputs of the procedure.
+    pub fn num_outputs(&self) -> u8 {
+        self.num_outputs
+    }
+
+    /// Returns the source code of the stub invoking this procedure against the specified account.
+    ///
+    /// The stub pads the inputs to the 15 elements expected by `execute_foreign_procedure`, and
+    /// drops the outputs exceeding `num_outputs` after the invocation. For a procedure with one input
+    /// and two outputs, the resulting code is:
+    ///
+    /// ```masm
+    /// export.get_item
+    ///     padw padw padw push.0.0
+    ///     movup.14
+    ///     push.{FOREIGN_PROC_ROOT}
+    ///     push.{account_id_suffix}.{account_id_prefix}
+    ///     exec.::miden::tx::execute_foreign_procedure
+    ///     movup.2 drop movup.2 drop ...
+    /// end
+    /// ```
+    fn stub_source(&self, account_id: AccountId) -> String {
+        let num_pads = (MAX_FOREIGN_PROCEDURE_INPUTS - self.num_inputs) as usize;
+        let num_drops = (MAX_FOREIGN_PROCEDURE_OUTPUTS - self.num_outputs) as usize;
+
+        let mut pads = vec!["padw"; num_pads / 4];
+        pads.extend(vec!["push.0"; num_pads % 4]);
+        let move_inputs = vec!["movup.14"; self.num_inputs as usize];
+        let drop_outputs = match self.num_outputs {
+            0 => vec!["drop".to_string(); num_drops],
+            1 => vec!["swap drop".to_string(); num_drops],
+            num_outputs => vec![format!("movup.{num_outputs} drop"); num_drops],
+        };
+
+        format!(
+            "
+#! Invokes the procedure with MAST root {root} against account {account_id} via foreign
+#! procedure invocation.
+#!
+#! Inputs:  [inputs({num_inputs})]
+#! Outputs: [outputs({num_outputs})]
+#!
+#! Invocation: exec
+export.{name}
+    {pads}
+    # => [pad({num_pads}), inputs({num_inputs})]
+
+    {move_inputs}
+    # => [inputs({num_inputs}), pad({num_pads})]
+
+    push.{root}
+    push.{account_id_suffix}.{account_id_prefix}
+    # => [account_id_prefix, account_id_suffix, FOREIGN_PROC_ROOT, inputs({num_inputs}), pad({num_pads})]
+
+    exec.::miden::tx::execute_foreign_procedure
+    # => [outputs(16)]
+
+    {drop_outputs}
+    # => [outputs({num_outputs})]
+end
+",
+            name = self.name,
+            root = self.root,
+            num_inputs = self.num_inputs,
+            num_outputs = self.num_outputs,
+            pads = pads.join(" "),
+            move_inputs = move_inputs.join(" "),
+            drop_outputs = drop_outputs.join(" "),
+            account_id_prefix = account_id.prefix().as_felt(),
+            account_id_suffix = account_id.suffix(),
+        )
+    }
+}
+
+// FPI STUBS
+// ================================================================================================
+
+/// A library of stub procedures invoking read procedures of a single account via foreign
+/// procedure invocation (FPI).
+///
+/// Each stub performs the setup of the foreign context, the invocation of the foreign procedure and
+/// the teardown of the foreign context, so that FPI consumers can `exec` the stub like a local
+/// procedure. The stubs are built via [`AccountInterface::build_fpi_stubs`][build] and can be
+/// linked into an assembler using the [`LibraryLinker`](crate::transaction::LibraryLinker).
+///
+/// [build]: crate::account::interface::AccountInterface::build_fpi_stubs
+#[derive(Debug, Clone)]
+pub struct FpiStubs {
+    module_path: LibraryPath,
+    source: String,
+    library: Library,
+    roots: BTreeMap<ProcedureName, Digest>,
+}
+
+impl FpiStubs {
+    /// Returns a new [FpiStubs] library for the provided procedures of the specified account,
+    /// assembled as the module at the specified path.
+    pub(super) fn new(
+        account_id: AccountId,
+        module_path: LibraryPath,
+        procedures: &[ForeignProcedure],
+    ) -> Self {
+        let source: String =
+            procedures.iter().map(|procedure| procedure.stub_source(account_id)).collect();
+
+        let source_manager = Arc::new(DefaultSourceManager::default());
+        let module = Module::parser(ModuleKind::Library)
+            .parse_str(module_path.clone(), &source, &source_manager)
+            .expect("generated FPI stubs should be valid MASM");
+        let library = TransactionKernel::assembler()
+            .assemble_library([module])
+            .expect("generated FPI stubs should assemble");
+
+        let roots = procedures
+            .iter()
+            .map(|procedure| {
+                let name =
+                    QualifiedProcedureName::new(module_path.clone(), procedure.name().clone());
+                let node_id = library.get_export_node_id(&name);
+                (procedure.name().clone(), library.mast_forest()[node_id].digest())
+            })
+            .collect();
+
+        Self { module_path, source, library, roots }
+    }
+
+    /// Returns the path of the module containing the stubs.
+    pub fn module_path(&self) -> &LibraryPath {
+        &self.module_path
+    }
+
+    /// Returns the MASM source code of the stubs.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the assembled library of the stubs.
+    pub fn library(&self) -> &Library {
+        &self.library
+    }
+
+    /// Returns the MAST root of the stub with the provided name, or `None` if there is no such
+    /// stub.
+    pub fn root(&self, name: &ProcedureName) -> Option<Digest> {
+        self.roots.get(name).copied()
+    }
+
+    /// Returns an iterator over the names and MAST roots of the stubs.
+    pub fn roots(&self) -> impl Iterator<Item = (&ProcedureName, Digest)> {
+        self.roots.iter().map(|(name, root)| (name, *root))
+    }
+
+    /// Returns Rust source code declaring a constant for the MAST root of each stub, e.g. to be
+    /// written to a file by a build script.
+    ///
+    /// The constant of a stub named `get_count` is declared as:
+    ///
+    /// ```ignore
+    /// /// MAST root of the `get_count` FPI stub.
+    /// pub const GET_COUNT_FPI_STUB_ROOT: Digest = digest!("0x...");
+    /// ```
+    ///
+    /// The generated code expects `miden_objects::{Digest, digest}` to be in scope.
+    pub fn to_rust_constants(&self) -> String {
+        self.roots
+            .iter()
+            .map(|(name, root)| {
+                format!(
+                    "/// MAST root of the `{name}` FPI stub.\npub const {}_FPI_STUB_ROOT: Digest = digest!(\"{root}\");\n",
+                    name.as_str().to_uppercase(),
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
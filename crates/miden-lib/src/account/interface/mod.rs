@@ -1,11 +1,18 @@
 use alloc::{collections::BTreeSet, string::String, sync::Arc, vec::Vec};
 
 use miden_objects::{
-    Digest, TransactionScriptError,
+    Digest, MAX_OUTPUT_NOTES_PER_TX, NoteError, TransactionScriptError, Word, ZERO,
     account::{Account, AccountCode, AccountId, AccountIdPrefix, AccountType},
-    assembly::mast::{MastForest, MastNode, MastNodeId},
+    assembly::{
+        LibraryPath, ProcedureName,
+        mast::{MastForest, MastNode, MastNodeId},
+    },
+    asset::{Asset, FungibleAsset},
     crypto::dsa::rpo_falcon512,
-    note::{Note, NoteScript, PartialNote},
+    note::{
+        Note, NoteAssets, NoteDetails, NoteExecutionHint, NoteExecutionMode, NoteId, NoteMetadata,
+        NoteScript, NoteSender, NoteTag, NoteType, PartialNote,
+    },
     transaction::TransactionScript,
 };
 use thiserror::Error;
@@ -13,9 +20,14 @@ use thiserror::Error;
 use crate::{
     AuthScheme,
     account::components::{
-        basic_fungible_faucet_library, basic_wallet_library, rpo_falcon_512_library,
+        basic_fungible_faucet_library, basic_wallet_library, basic_wallet_v2_library,
+        bls12_381_library, ecdsa_k256_library, no_auth_library, rpo_falcon_512_library,
+        two_factor_auth_library,
+    },
+    note::{
+        utils::{build_p2id_recipient, derive_serial_num},
+        well_known_note::WellKnownNote,
     },
-    note::well_known_note::WellKnownNote,
     transaction::TransactionKernel,
 };
 
@@ -25,6 +37,11 @@ mod test;
 mod component;
 pub use component::AccountComponentInterface;
 
+mod fpi;
+pub use fpi::{
+    ForeignProcedure, FpiStubs, MAX_FOREIGN_PROCEDURE_INPUTS, MAX_FOREIGN_PROCEDURE_OUTPUTS,
+};
+
 // ACCOUNT INTERFACE
 // ================================================================================================
 
@@ -113,6 +130,10 @@ impl AccountInterface {
                     component_proc_digests
                         .extend(basic_wallet_library().mast_forest().procedure_digests());
                 },
+                AccountComponentInterface::BasicWalletV2 => {
+                    component_proc_digests
+                        .extend(basic_wallet_v2_library().mast_forest().procedure_digests());
+                },
                 AccountComponentInterface::BasicFungibleFaucet => {
                     component_proc_digests
                         .extend(basic_fungible_faucet_library().mast_forest().procedure_digests());
@@ -121,6 +142,22 @@ impl AccountInterface {
                     component_proc_digests
                         .extend(rpo_falcon_512_library().mast_forest().procedure_digests());
                 },
+                AccountComponentInterface::EcdsaK256(_) => {
+                    component_proc_digests
+                        .extend(ecdsa_k256_library().mast_forest().procedure_digests());
+                },
+                AccountComponentInterface::Bls12381(_) => {
+                    component_proc_digests
+                        .extend(bls12_381_library().mast_forest().procedure_digests());
+                },
+                AccountComponentInterface::TwoFactorAuth(_) => {
+                    component_proc_digests
+                        .extend(two_factor_auth_library().mast_forest().procedure_digests());
+                },
+                AccountComponentInterface::NoAuth => {
+                    component_proc_digests
+                        .extend(no_auth_library().mast_forest().procedure_digests());
+                },
                 AccountComponentInterface::Custom(custom_procs) => {
                     component_proc_digests
                         .extend(custom_procs.iter().map(|info| *info.mast_root()));
@@ -168,7 +205,8 @@ impl AccountInterface {
     /// can be included into the chain by block 110. If this does not happen, the transaction is
     /// considered expired and cannot be included into the chain.
     ///
-    /// Currently only [`AccountComponentInterface::BasicWallet`] and
+    /// Currently only [`AccountComponentInterface::BasicWallet`],
+    /// [`AccountComponentInterface::BasicWalletV2`] and
     /// [`AccountComponentInterface::BasicFungibleFaucet`] interfaces are supported for the
     /// `send_note` script creation. Attempt to generate the script using some other interface will
     /// lead to an error. In case both supported interfaces are available in the account, the script
@@ -197,7 +235,7 @@ impl AccountInterface {
     /// - the available interfaces does not support the generation of the standard `send_note`
     ///   procedure.
     /// - the sender of the note isn't the account for which the script is being built.
-    /// - the note created by the faucet doesn't contain exactly one asset.
+    /// - the note created by the faucet or the basic wallet v2 doesn't contain exactly one asset.
     /// - a faucet tries to distribute an asset with a different faucet ID.
     ///
     /// [wallet]: miden_lib::account::interface::AccountComponentInterface::BasicWallet
@@ -224,14 +262,265 @@ impl AccountInterface {
         Ok(tx_script)
     }
 
+    /// Returns a transaction script which sends a P2ID note for each of the provided
+    /// `(target, asset)` pairs, together with the [NoteDetails] of the created notes.
+    ///
+    /// The serial number of the note at index `i` is derived from `serial_num_seed` using
+    /// [`derive_serial_num`](crate::note::utils::derive_serial_num), and its tag is set to the
+    /// target's account ID. The returned note details are in the same order as the provided
+    /// payments.
+    ///
+    /// See [`Self::build_send_notes_script`] for the meaning of the remaining parameters.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - the number of payments exceeds [`MAX_OUTPUT_NOTES_PER_TX`].
+    /// - any of the notes cannot be constructed.
+    /// - the `send_note` script cannot be built for the notes.
+    pub fn build_send_p2id_notes_script(
+        &self,
+        payments: &[(AccountId, Asset)],
+        note_type: NoteType,
+        serial_num_seed: Word,
+        expiration_delta: Option<u16>,
+        in_debug_mode: bool,
+    ) -> Result<(TransactionScript, Vec<NoteDetails>), AccountInterfaceError> {
+        if payments.len() > MAX_OUTPUT_NOTES_PER_TX {
+            return Err(AccountInterfaceError::TooManyOutputNotes(payments.len()));
+        }
+
+        let mut output_notes = Vec::with_capacity(payments.len());
+        let mut note_details = Vec::with_capacity(payments.len());
+        for (index, (target, asset)) in payments.iter().enumerate() {
+            let assets =
+                NoteAssets::new(vec![*asset]).map_err(AccountInterfaceError::InvalidOutputNote)?;
+            let (output_note, details) = self.build_p2id_output_note(
+                *target,
+                assets,
+                note_type,
+                derive_serial_num(serial_num_seed, index as u32),
+            )?;
+
+            output_notes.push(output_note);
+            note_details.push(details);
+        }
+
+        let tx_script =
+            self.build_send_notes_script(&output_notes, expiration_delta, in_debug_mode)?;
+
+        Ok((tx_script, note_details))
+    }
+
+    /// Returns a transaction script which pays `amount` to `target` using the assets of the
+    /// provided notes and sends the remainder back to this account in a change note.
+    ///
+    /// The notes to consume are selected from `input_notes` in order of decreasing balance of the
+    /// faucet which issued `amount`, until the selected notes cover the payment. All assets of the
+    /// selected notes which are not used for the payment, including assets issued by other
+    /// faucets, are put into a P2ID change note targeting this account. If the selected notes
+    /// cover the payment exactly, no change note is created.
+    ///
+    /// Returns the transaction script, the IDs of the selected notes which must be consumed by the
+    /// transaction, and the [NoteDetails] of the created notes, i.e. the payment note followed by
+    /// the change note, if any. The serial numbers of the created notes are derived from
+    /// `serial_num_seed` using [`derive_serial_num`](crate::note::utils::derive_serial_num).
+    ///
+    /// See [`Self::build_send_notes_script`] for the meaning of the remaining parameters.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - the provided notes do not contain enough units of the fungible asset to cover the payment.
+    /// - any of the notes cannot be constructed.
+    /// - the `send_note` script cannot be built for the notes.
+    #[allow(clippy::too_many_arguments)]
+    pub fn build_send_with_change_script(
+        &self,
+        input_notes: &[Note],
+        target: AccountId,
+        amount: FungibleAsset,
+        note_type: NoteType,
+        serial_num_seed: Word,
+        expiration_delta: Option<u16>,
+        in_debug_mode: bool,
+    ) -> Result<(TransactionScript, Vec<NoteId>, Vec<NoteDetails>), AccountInterfaceError> {
+        let faucet_id = amount.faucet_id();
+        let balance_of = |note: &Note| {
+            note.assets()
+                .iter()
+                .filter_map(|asset| match asset {
+                    Asset::Fungible(asset) if asset.faucet_id() == faucet_id => {
+                        Some(asset.amount())
+                    },
+                    _ => None,
+                })
+                .sum::<u64>()
+        };
+
+        // select the notes with the largest balances until they cover the payment
+        let mut candidates: Vec<&Note> =
+            input_notes.iter().filter(|note| balance_of(note) > 0).collect();
+        candidates.sort_by_key(|note| core::cmp::Reverse(balance_of(note)));
+
+        let mut selected_notes = Vec::new();
+        let mut selected_balance = 0;
+        for note in candidates {
+            if selected_balance >= amount.amount() {
+                break;
+            }
+            selected_balance += balance_of(note);
+            selected_notes.push(note);
+        }
+
+        if selected_balance < amount.amount() {
+            return Err(AccountInterfaceError::InsufficientFungibleAssetBalance {
+                faucet_id,
+                required: amount.amount(),
+                available: selected_balance,
+            });
+        }
+
+        // collect the assets of the selected notes which are not used for the payment
+        let mut change_assets = NoteAssets::default();
+        if selected_balance > amount.amount() {
+            let change = FungibleAsset::new(faucet_id, selected_balance - amount.amount())
+                .map_err(|err| {
+                    AccountInterfaceError::InvalidOutputNote(
+                        NoteError::AddFungibleAssetBalanceError(err),
+                    )
+                })?;
+            change_assets
+                .add_asset(change.into())
+                .map_err(AccountInterfaceError::InvalidOutputNote)?;
+        }
+        for asset in selected_notes.iter().flat_map(|note| note.assets().iter()) {
+            match asset {
+                Asset::Fungible(asset) if asset.faucet_id() == faucet_id => {},
+                asset => change_assets
+                    .add_asset(*asset)
+                    .map_err(AccountInterfaceError::InvalidOutputNote)?,
+            }
+        }
+
+        let payment_assets = NoteAssets::new(vec![amount.into()])
+            .map_err(AccountInterfaceError::InvalidOutputNote)?;
+        let mut output_notes = Vec::with_capacity(2);
+        let mut note_details = Vec::with_capacity(2);
+
+        let (output_note, details) = self.build_p2id_output_note(
+            target,
+            payment_assets,
+            note_type,
+            derive_serial_num(serial_num_seed, 0),
+        )?;
+        output_notes.push(output_note);
+        note_details.push(details);
+
+        if !change_assets.is_empty() {
+            let (output_note, details) = self.build_p2id_output_note(
+                self.account_id,
+                change_assets,
+                note_type,
+                derive_serial_num(serial_num_seed, 1),
+            )?;
+            output_notes.push(output_note);
+            note_details.push(details);
+        }
+
+        let tx_script =
+            self.build_send_notes_script(&output_notes, expiration_delta, in_debug_mode)?;
+        let input_note_ids = selected_notes.iter().map(|note| note.id()).collect();
+
+        Ok((tx_script, input_note_ids, note_details))
+    }
+
+    /// Returns the [PartialNote] and [NoteDetails] of a P2ID note sent by this account to
+    /// `target`, with its tag set to the target's account ID.
+    pub(crate) fn build_p2id_output_note(
+        &self,
+        target: AccountId,
+        assets: NoteAssets,
+        note_type: NoteType,
+        serial_num: Word,
+    ) -> Result<(PartialNote, NoteDetails), AccountInterfaceError> {
+        let recipient = build_p2id_recipient(target, serial_num)
+            .map_err(AccountInterfaceError::InvalidOutputNote)?;
+
+        let tag = NoteTag::from_account_id(target, NoteExecutionMode::Local)
+            .map_err(AccountInterfaceError::InvalidOutputNote)?;
+        let metadata =
+            NoteMetadata::new(self.account_id, note_type, tag, NoteExecutionHint::always(), ZERO)
+                .map_err(AccountInterfaceError::InvalidOutputNote)?;
+
+        Ok((
+            PartialNote::new(metadata, recipient.digest(), assets.clone()),
+            NoteDetails::new(assets, recipient),
+        ))
+    }
+
+    /// Returns a library of stubs invoking the provided read procedures of the account via foreign
+    /// procedure invocation, assembled as the module at the specified path.
+    ///
+    /// Each stub is exported under the name of its [ForeignProcedure] and wraps the setup of the
+    /// foreign context, the invocation of the procedure and the teardown of the foreign context,
+    /// e.g. the stub of a procedure `get_count` without inputs and with one output can be used as:
+    ///
+    /// ```masm
+    /// use.counter_fpi::atomic
+    ///
+    /// begin
+    ///     exec.atomic::get_count
+    ///     # => [count]
+    /// end
+    /// ```
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - any of the procedures is not exported by the account.
+    /// - multiple procedures have the same name.
+    pub fn build_fpi_stubs(
+        &self,
+        module_path: LibraryPath,
+        procedures: &[ForeignProcedure],
+    ) -> Result<FpiStubs, AccountInterfaceError> {
+        let procedure_digests = self.get_procedure_digests();
+        let mut names = BTreeSet::new();
+        for procedure in procedures {
+            if !procedure_digests.contains(&procedure.root()) {
+                return Err(AccountInterfaceError::ForeignProcedureNotFound(procedure.root()));
+            }
+            if !names.insert(procedure.name()) {
+                return Err(AccountInterfaceError::DuplicateForeignProcedureName(
+                    procedure.name().clone(),
+                ));
+            }
+        }
+
+        Ok(FpiStubs::new(self.account_id, module_path, procedures))
+    }
+
     /// Returns a string with the authentication procedure call for the script.
-    fn build_tx_authentication_section(&self) -> String {
+    pub(crate) fn build_tx_authentication_section(&self) -> String {
         let mut auth_script = String::new();
         self.auth().iter().for_each(|auth_scheme| match auth_scheme {
             &AuthScheme::RpoFalcon512 { pub_key: _ } => {
                 auth_script
                     .push_str("call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512\n");
             },
+            &AuthScheme::EcdsaK256 { pub_key: _ } => {
+                auth_script
+                    .push_str("call.::miden::contracts::auth::ecdsa_k256::auth_tx_ecdsa_k256\n");
+            },
+            &AuthScheme::Bls12381 { pub_key: _ } => {
+                auth_script
+                    .push_str("call.::miden::contracts::auth::bls12_381::auth_tx_bls12_381\n");
+            },
+            &AuthScheme::TwoFactor { falcon_pub_key: _, ecdsa_pub_key: _ } => {
+                auth_script
+                    .push_str("call.::miden::contracts::auth::two_factor::auth_tx_two_factor\n");
+            },
+            &AuthScheme::NoAuth => {
+                auth_script.push_str("call.::miden::contracts::auth::no_auth::auth_tx_no_auth\n");
+            },
         });
 
         auth_script
@@ -247,9 +536,9 @@ impl AccountInterface {
     /// - the available interfaces does not support the generation of the standard `send_note`
     ///   procedure.
     /// - the sender of the note isn't the account for which the script is being built.
-    /// - the note created by the faucet doesn't contain exactly one asset.
+    /// - the note created by the faucet or the basic wallet v2 doesn't contain exactly one asset.
     /// - a faucet tries to distribute an asset with a different faucet ID.
-    fn build_create_notes_section(
+    pub(crate) fn build_create_notes_section(
         &self,
         output_notes: &[PartialNote],
     ) -> Result<String, AccountInterfaceError> {
@@ -257,6 +546,8 @@ impl AccountInterface {
             AccountComponentInterface::BasicFungibleFaucet.send_note_body(*self.id(), output_notes)
         } else if self.components().contains(&AccountComponentInterface::BasicWallet) {
             AccountComponentInterface::BasicWallet.send_note_body(*self.id(), output_notes)
+        } else if self.components().contains(&AccountComponentInterface::BasicWalletV2) {
+            AccountComponentInterface::BasicWalletV2.send_note_body(*self.id(), output_notes)
         } else {
             return Err(AccountInterfaceError::UnsupportedAccountInterface);
         }
@@ -276,8 +567,8 @@ impl From<&Account> for AccountInterface {
     fn from(account: &Account) -> Self {
         let components = AccountComponentInterface::from_procedures(account.code().procedures());
         let mut auth = Vec::new();
-        components.iter().for_each(|interface| {
-            if let AccountComponentInterface::RpoFalcon512(storage_index) = interface {
+        components.iter().for_each(|interface| match interface {
+            AccountComponentInterface::RpoFalcon512(storage_index) => {
                 auth.push(AuthScheme::RpoFalcon512 {
                     pub_key: rpo_falcon512::PublicKey::new(
                         *account
@@ -286,7 +577,37 @@ impl From<&Account> for AccountInterface {
                             .expect("invalid storage index of the public key"),
                     ),
                 })
-            }
+            },
+            AccountComponentInterface::EcdsaK256(storage_index) => {
+                auth.push(AuthScheme::EcdsaK256 {
+                    pub_key: *account
+                        .storage()
+                        .get_item(*storage_index)
+                        .expect("invalid storage index of the public key commitment"),
+                })
+            },
+            AccountComponentInterface::Bls12381(storage_index) => auth.push(AuthScheme::Bls12381 {
+                pub_key: *account
+                    .storage()
+                    .get_item(*storage_index)
+                    .expect("invalid storage index of the public key commitment"),
+            }),
+            AccountComponentInterface::TwoFactorAuth(storage_index) => {
+                auth.push(AuthScheme::TwoFactor {
+                    falcon_pub_key: rpo_falcon512::PublicKey::new(
+                        *account
+                            .storage()
+                            .get_item(*storage_index)
+                            .expect("invalid storage index of the public key"),
+                    ),
+                    ecdsa_pub_key: *account
+                        .storage()
+                        .get_item(*storage_index + 1)
+                        .expect("invalid storage index of the public key commitment"),
+                })
+            },
+            AccountComponentInterface::NoAuth => auth.push(AuthScheme::NoAuth),
+            _ => {},
         });
 
         Self {
@@ -404,14 +725,40 @@ pub enum AccountInterfaceError {
     IssuanceFaucetMismatch(AccountIdPrefix),
     #[error("note created by the basic fungible faucet doesn't contain exactly one asset")]
     FaucetNoteWithoutAsset,
+    #[error("note sent by the basic wallet v2 doesn't contain exactly one asset")]
+    SendAssetNoteWithoutSingleAsset,
     #[error("invalid transaction script")]
     InvalidTransactionScript(#[source] TransactionScriptError),
+    #[error("failed to build output note")]
+    InvalidOutputNote(#[source] NoteError),
+    #[error("number of output notes {0} exceeds the maximum of {MAX_OUTPUT_NOTES_PER_TX}")]
+    TooManyOutputNotes(usize),
+    #[error(
+        "input notes contain {available} units of the fungible asset issued by faucet {faucet_id} but {required} are required"
+    )]
+    InsufficientFungibleAssetBalance {
+        faucet_id: AccountId,
+        required: u64,
+        available: u64,
+    },
     #[error("invalid sender account: {0}")]
-    InvalidSenderAccount(AccountId),
+    InvalidSenderAccount(NoteSender),
     #[error("{} interface does not support the generation of the standard send_note script", interface.name())]
     UnsupportedInterface { interface: AccountComponentInterface },
     #[error(
         "account does not contain the basic fungible faucet or basic wallet interfaces which are needed to support the send_note script generation"
     )]
     UnsupportedAccountInterface,
+    #[error("procedure with MAST root {0} is not exported by the account")]
+    ForeignProcedureNotFound(Digest),
+    #[error("multiple foreign procedures are named {0}")]
+    DuplicateForeignProcedureName(ProcedureName),
+    #[error(
+        "foreign procedure {name} has {num_inputs} inputs but at most {MAX_FOREIGN_PROCEDURE_INPUTS} are supported"
+    )]
+    TooManyForeignProcedureInputs { name: ProcedureName, num_inputs: u8 },
+    #[error(
+        "foreign procedure {name} has {num_outputs} outputs but at most {MAX_FOREIGN_PROCEDURE_OUTPUTS} are supported"
+    )]
+    TooManyForeignProcedureOutputs { name: ProcedureName, num_outputs: u8 },
 }
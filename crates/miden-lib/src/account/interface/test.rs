@@ -3,7 +3,7 @@ use alloc::{string::ToString, sync::Arc, vec::Vec};
 use miden_objects::{
     AccountError, Digest, Felt, ONE, ZERO,
     account::{AccountBuilder, AccountComponent, AccountType, StorageSlot},
-    assembly::{Assembler, DefaultSourceManager, LibraryPath, Module, ModuleKind},
+    assembly::{Assembler, DefaultSourceManager, LibraryPath, Module, ModuleKind, ProcedureName},
     asset::{FungibleAsset, NonFungibleAsset, TokenSymbol},
     block::BlockNumber,
     crypto::{
@@ -23,8 +23,11 @@ use miden_objects::{
 use crate::{
     account::{
         auth::RpoFalcon512,
+        counters::AtomicCounter,
         faucets::BasicFungibleFaucet,
-        interface::{AccountInterface, NoteAccountCompatibility},
+        interface::{
+            AccountInterface, AccountInterfaceError, ForeignProcedure, NoteAccountCompatibility,
+        },
         wallets::BasicWallet,
     },
     note::{create_p2id_note, create_p2idr_note, create_swap_note},
@@ -652,6 +655,62 @@ fn test_custom_account_multiple_components_custom_notes() {
     );
 }
 
+// FPI STUBS
+// ================================================================================================
+
+#[test]
+fn test_build_fpi_stubs() {
+    let mock_seed = Digest::from([ZERO, ONE, Felt::new(2), Felt::new(3)]).as_bytes();
+    let counter_account = AccountBuilder::new(mock_seed)
+        .with_component(AtomicCounter::new(5))
+        .with_component(RpoFalcon512::new(PublicKey::new([ZERO, ONE, Felt::new(2), Felt::new(3)])))
+        .build_existing()
+        .expect("failed to create counter account");
+    let counter_interface = AccountInterface::from(&counter_account);
+
+    let get_count = ProcedureName::new("get_count").unwrap();
+    let get_count_procedure =
+        ForeignProcedure::new(get_count.clone(), AtomicCounter::get_count_root(), 0, 1).unwrap();
+    let stubs = counter_interface
+        .build_fpi_stubs(
+            LibraryPath::new("counter_fpi::atomic").unwrap(),
+            &[get_count_procedure.clone()],
+        )
+        .unwrap();
+
+    let stub_root = stubs.root(&get_count).unwrap();
+    assert!(stubs.library().mast_forest().procedure_digests().any(|root| root == stub_root));
+    assert!(stubs.to_rust_constants().contains(&format!(
+        "pub const GET_COUNT_FPI_STUB_ROOT: Digest = digest!(\"{stub_root}\");"
+    )));
+
+    // procedures which are not exported by the account are rejected
+    let procedures = [ForeignProcedure::new(get_count.clone(), Digest::default(), 0, 1).unwrap()];
+    assert!(matches!(
+        counter_interface
+            .build_fpi_stubs(LibraryPath::new("counter_fpi::atomic").unwrap(), &procedures),
+        Err(AccountInterfaceError::ForeignProcedureNotFound(_))
+    ));
+
+    // stubs with the same name are rejected
+    let procedures = [
+        get_count_procedure,
+        ForeignProcedure::new(get_count.clone(), AtomicCounter::increment_and_get_root(), 0, 1)
+            .unwrap(),
+    ];
+    assert!(matches!(
+        counter_interface
+            .build_fpi_stubs(LibraryPath::new("counter_fpi::atomic").unwrap(), &procedures),
+        Err(AccountInterfaceError::DuplicateForeignProcedureName(_))
+    ));
+
+    // procedures with too many inputs are rejected
+    assert!(matches!(
+        ForeignProcedure::new(get_count, AtomicCounter::get_count_root(), 16, 1),
+        Err(AccountInterfaceError::TooManyForeignProcedureInputs { .. })
+    ));
+}
+
 // HELPER TRAIT
 // ================================================================================================
 
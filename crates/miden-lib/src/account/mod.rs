@@ -2,6 +2,9 @@ use super::auth::AuthScheme;
 
 pub mod auth;
 pub mod components;
+pub mod counters;
 pub mod faucets;
+pub mod filters;
 pub mod interface;
+pub mod oracles;
 pub mod wallets;
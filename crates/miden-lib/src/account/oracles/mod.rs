@@ -0,0 +1,168 @@
+use alloc::vec::Vec;
+
+use miden_objects::{
+    Digest, Felt, Hasher, Word, ZERO,
+    account::{AccountComponent, AccountId, ProcedureCallers, StorageMap, StorageSlot},
+    assembly::{ProcedureName, QualifiedProcedureName},
+};
+
+use crate::account::components::standard_price_oracle_library;
+
+// STANDARD PRICE ORACLE
+// ================================================================================================
+
+/// An [`AccountComponent`] implementing a standard price oracle which stores the latest price of
+/// pairs of assets together with the time the price was observed.
+///
+/// It reexports the procedures from `miden::contracts::oracles::standard`. When linking against
+/// this component, the `miden` library (i.e. [`MidenLib`](crate::MidenLib)) must be available to
+/// the assembler which is the case when using [`TransactionKernel::assembler()`][kasm]. The
+/// procedures of this component are:
+/// - `push_price`, which stores a new price for the specified pair. The price is rejected unless
+///   its timestamp is greater than the timestamp of the currently stored price.
+/// - `read_price`, which returns the latest price and its timestamp for the specified pair. It is
+///   meant to be invoked by other accounts or notes via foreign procedure invocation.
+///
+/// The prices are stored in a storage map in the first storage slot of the component, mapping
+/// the [storage key](PricePair::storage_key) of each [`PricePair`] to a [`PriceEntry`].
+///
+/// `push_price` requires authentication, i.e. it can only be used by the admin of the oracle who
+/// controls the authentication component of the account. In addition, it may only be invoked from
+/// the transaction script, so that notes consumed by the oracle cannot push prices. Thus, this
+/// component must be combined with a component providing authentication.
+///
+/// This component supports all account types.
+///
+/// [kasm]: crate::transaction::TransactionKernel::assembler
+pub struct StandardPriceOracle {
+    prices: StorageMap,
+}
+
+impl StandardPriceOracle {
+    /// Returns a new [`StandardPriceOracle`] component initialized with the provided prices.
+    pub fn new(prices: impl IntoIterator<Item = (PricePair, PriceEntry)>) -> Self {
+        let mut map = StorageMap::new();
+        for (pair, entry) in prices {
+            map.insert(pair.storage_key(), entry.into());
+        }
+
+        Self { prices: map }
+    }
+
+    /// Returns the MAST root of the `read_price` procedure of this component.
+    pub fn read_price_root() -> Digest {
+        let read_price_proc_name = QualifiedProcedureName::new(
+            Default::default(),
+            ProcedureName::new("read_price").unwrap(),
+        );
+        let library = standard_price_oracle_library();
+        let node_id = library.get_export_node_id(&read_price_proc_name);
+
+        library.mast_forest()[node_id].digest()
+    }
+}
+
+impl From<StandardPriceOracle> for AccountComponent {
+    fn from(oracle: StandardPriceOracle) -> Self {
+        let storage_slots: Vec<StorageSlot> = vec![StorageSlot::Map(oracle.prices)];
+
+        AccountComponent::new(standard_price_oracle_library(), storage_slots)
+            .expect("standard price oracle component should satisfy the requirements of a valid account component")
+            .with_supports_all_types()
+            .with_allowed_callers("push_price", ProcedureCallers::TX_SCRIPT)
+    }
+}
+
+// PRICE PAIR
+// ================================================================================================
+
+/// A pair of assets for which a [`StandardPriceOracle`] stores prices, identified by the IDs of the
+/// faucets issuing the assets.
+///
+/// The pair is encoded as `[base_suffix, base_prefix, quote_suffix, quote_prefix]`, and the hash of
+/// the encoded pair is used as the key of the storage map holding the prices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PricePair {
+    base: AccountId,
+    quote: AccountId,
+}
+
+impl PricePair {
+    /// Returns a new [`PricePair`] for the price of the `base` asset in units of the `quote` asset.
+    pub fn new(base: AccountId, quote: AccountId) -> Self {
+        Self { base, quote }
+    }
+
+    /// Returns the ID of the faucet issuing the base asset of this pair.
+    pub fn base(&self) -> AccountId {
+        self.base
+    }
+
+    /// Returns the ID of the faucet issuing the quote asset of this pair.
+    pub fn quote(&self) -> AccountId {
+        self.quote
+    }
+
+    /// Returns the key under which the price of this pair is stored by a [`StandardPriceOracle`].
+    ///
+    /// The key is the hash of the encoded pair, so that pairs sharing an asset are not stored in
+    /// the same leaf of the storage map.
+    pub fn storage_key(&self) -> Digest {
+        Hasher::hash_elements(&Word::from(*self))
+    }
+}
+
+impl From<PricePair> for Word {
+    fn from(pair: PricePair) -> Self {
+        [
+            pair.base.suffix(),
+            pair.base.prefix().as_felt(),
+            pair.quote.suffix(),
+            pair.quote.prefix().as_felt(),
+        ]
+    }
+}
+
+// PRICE ENTRY
+// ================================================================================================
+
+/// The latest price of a [`PricePair`] stored by a [`StandardPriceOracle`].
+///
+/// The entry is encoded as `[timestamp, price, 0, 0]` in the storage map holding the prices, so
+/// that `read_price` returns `[price, timestamp]` on the stack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PriceEntry {
+    price: Felt,
+    timestamp: u32,
+}
+
+impl PriceEntry {
+    /// Returns a new [`PriceEntry`] for the provided price observed at the provided timestamp.
+    pub fn new(price: Felt, timestamp: u32) -> Self {
+        Self { price, timestamp }
+    }
+
+    /// Returns the entry encoded in the provided storage map value, or `None` if the value does not
+    /// hold a price, i.e., its timestamp is zero or not a valid `u32` value.
+    pub fn from_storage_value(value: Word) -> Option<Self> {
+        let timestamp =
+            u32::try_from(value[0].as_int()).ok().filter(|&timestamp| timestamp != 0)?;
+        Some(Self { price: value[1], timestamp })
+    }
+
+    /// Returns the price of one unit of the base asset in units of the quote asset.
+    pub fn price(&self) -> Felt {
+        self.price
+    }
+
+    /// Returns the timestamp at which the price was observed.
+    pub fn timestamp(&self) -> u32 {
+        self.timestamp
+    }
+}
+
+impl From<PriceEntry> for Word {
+    fn from(entry: PriceEntry) -> Self {
+        [Felt::from(entry.timestamp), entry.price, ZERO, ZERO]
+    }
+}
@@ -1,14 +1,20 @@
-use alloc::string::ToString;
+use alloc::string::{String, ToString};
 
 use miden_objects::{
-    AccountError, Word,
+    AccountError, Digest, Felt, NoteError, Word,
     account::{
         Account, AccountBuilder, AccountComponent, AccountIdAnchor, AccountStorageMode, AccountType,
     },
+    asset::Asset,
+    note::{NoteExecutionHint, NoteTag, NoteType},
+    utils::word_to_masm_push_string,
 };
 
 use super::AuthScheme;
-use crate::account::{auth::RpoFalcon512, components::basic_wallet_library};
+use crate::account::{
+    auth::{Bls12381, EcdsaK256, NoAuth, RpoFalcon512, TwoFactorAuth},
+    components::{basic_wallet_library, basic_wallet_v2_library},
+};
 
 // BASIC WALLET
 // ================================================================================================
@@ -64,8 +70,14 @@ pub fn create_basic_wallet(
         ));
     }
 
-    let auth_component: RpoFalcon512 = match auth_scheme {
-        AuthScheme::RpoFalcon512 { pub_key } => RpoFalcon512::new(pub_key),
+    let auth_component: AccountComponent = match auth_scheme {
+        AuthScheme::RpoFalcon512 { pub_key } => RpoFalcon512::new(pub_key).into(),
+        AuthScheme::EcdsaK256 { pub_key } => EcdsaK256::new(pub_key).into(),
+        AuthScheme::Bls12381 { pub_key } => Bls12381::new(pub_key).into(),
+        AuthScheme::TwoFactor { falcon_pub_key, ecdsa_pub_key } => {
+            TwoFactorAuth::new(falcon_pub_key, ecdsa_pub_key).into()
+        },
+        AuthScheme::NoAuth => NoAuth.into(),
     };
 
     let (account, account_seed) = AccountBuilder::new(init_seed)
@@ -79,6 +91,98 @@ pub fn create_basic_wallet(
     Ok((account, account_seed))
 }
 
+// BASIC WALLET V2
+// ================================================================================================
+
+/// An [`AccountComponent`] implementing a basic wallet which sends assets in a single call.
+///
+/// It reexports the procedures from `miden::contracts::wallets::basic_v2`. When linking against
+/// this component, the `miden` library (i.e. [`MidenLib`](crate::MidenLib)) must be available to
+/// the assembler which is the case when using [`TransactionKernel::assembler()`][kasm]. The
+/// procedures of this component are:
+/// - `receive_asset`, which can be used to add an asset to the account. This is the same procedure
+///   as the one of the [`BasicWallet`].
+/// - `send_asset`, which can be used to create a new note with the specified note type, tag,
+///   execution hint and recipient, and to move the specified asset from the account to it. The
+///   arguments of this procedure can be constructed via [`SendAssetArgs`].
+///
+/// All methods require authentication. Thus, this component must be combined with a component
+/// providing authentication.
+///
+/// This component supports all account types.
+///
+/// [kasm]: crate::transaction::TransactionKernel::assembler
+pub struct BasicWalletV2;
+
+impl From<BasicWalletV2> for AccountComponent {
+    fn from(_: BasicWalletV2) -> Self {
+        AccountComponent::new(basic_wallet_v2_library(), vec![])
+          .expect("basic wallet v2 component should satisfy the requirements of a valid account component")
+          .with_supports_all_types()
+    }
+}
+
+/// The arguments of the `send_asset` procedure of the [`BasicWalletV2`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SendAssetArgs {
+    asset: Asset,
+    recipient: Digest,
+    note_type: NoteType,
+    tag: NoteTag,
+    execution_hint: NoteExecutionHint,
+    aux: Felt,
+}
+
+impl SendAssetArgs {
+    /// Returns new [`SendAssetArgs`] for sending the asset to the specified recipient in a note
+    /// with the provided parameters.
+    ///
+    /// # Errors
+    /// Returns an error if the tag does not support the note type.
+    pub fn new(
+        asset: Asset,
+        recipient: Digest,
+        note_type: NoteType,
+        tag: NoteTag,
+        execution_hint: NoteExecutionHint,
+        aux: Felt,
+    ) -> Result<Self, NoteError> {
+        let tag = tag.validate(note_type)?;
+
+        Ok(Self {
+            asset,
+            recipient,
+            note_type,
+            tag,
+            execution_hint,
+            aux,
+        })
+    }
+
+    /// Returns the MASM code which pushes these arguments onto the stack, calls `send_asset` and
+    /// removes the outputs of the procedure from the stack.
+    ///
+    /// The code expects the top 4 elements of the stack to be padding and leaves the stack in the
+    /// state it was before.
+    pub fn to_masm(&self) -> String {
+        format!(
+            "push.{recipient}
+            push.{execution_hint}
+            push.{note_type}
+            push.{aux}
+            push.{tag}
+            push.{asset}
+            call.::miden::contracts::wallets::basic_v2::send_asset dropw dropw dropw\n",
+            recipient = word_to_masm_push_string(&self.recipient.into()),
+            execution_hint = Felt::from(self.execution_hint),
+            note_type = Felt::from(self.note_type),
+            aux = self.aux,
+            tag = Felt::from(self.tag),
+            asset = word_to_masm_push_string(&self.asset.into()),
+        )
+    }
+}
+
 // TESTS
 // ================================================================================================
 
@@ -1,4 +1,4 @@
-use miden_objects::crypto::dsa::rpo_falcon512;
+use miden_objects::{Word, crypto::dsa::rpo_falcon512};
 
 /// Defines authentication schemes available to standard and faucet accounts.
 pub enum AuthScheme {
@@ -7,4 +7,24 @@ pub enum AuthScheme {
     /// the standard in that instead of using SHAKE256 hash function in the hash-to-point algorithm
     /// we use RPO256. This makes the signature more efficient to verify in Miden VM.
     RpoFalcon512 { pub_key: rpo_falcon512::PublicKey },
+    /// A single-key authentication scheme which relies on ECDSA signatures over the secp256k1
+    /// curve, as used by Ethereum accounts. Since the public key does not fit into a single word,
+    /// the scheme is defined by the commitment to the public key, see
+    /// [`PublicKey::commitment`](miden_objects::crypto::ecdsa_k256::PublicKey::commitment).
+    EcdsaK256 { pub_key: Word },
+    /// A single-key authentication scheme which relies on BLS signatures over the BLS12-381 curve.
+    /// As for [`AuthScheme::EcdsaK256`], the scheme is defined by the commitment to the public
+    /// key, see
+    /// [`PublicKey::commitment`](miden_objects::crypto::bls12_381::PublicKey::commitment).
+    Bls12381 { pub_key: Word },
+    /// A two-factor authentication scheme which requires both an RpoFalcon512 signature and an
+    /// ECDSA signature over the secp256k1 curve. The ECDSA key is defined by the commitment to its
+    /// public key, as for [`AuthScheme::EcdsaK256`].
+    TwoFactor {
+        falcon_pub_key: rpo_falcon512::PublicKey,
+        ecdsa_pub_key: Word,
+    },
+    /// No authentication, i.e. anyone can execute transactions against the account. This scheme
+    /// is only supported by regular accounts with immutable code.
+    NoAuth,
 }
@@ -17,7 +17,7 @@ pub const ERR_P2ID_WRONG_NUMBER_OF_INPUTS: u32 = 0x2c000;
 /// P2ID's target account address and transaction address do not match
 pub const ERR_P2ID_TARGET_ACCT_MISMATCH: u32 = 0x2c001;
 
-/// P2IDR scripts expect exactly 3 note inputs
+/// P2IDR scripts expect exactly 5 note inputs
 pub const ERR_P2IDR_WRONG_NUMBER_OF_INPUTS: u32 = 0x2c002;
 /// P2IDR's reclaimer is not the original sender
 pub const ERR_P2IDR_RECLAIM_ACCT_IS_NOT_SENDER: u32 = 0x2c003;
@@ -29,14 +29,49 @@ pub const ERR_SWAP_WRONG_NUMBER_OF_INPUTS: u32 = 0x2c005;
 /// SWAP script requires exactly 1 note asset
 pub const ERR_SWAP_WRONG_NUMBER_OF_ASSETS: u32 = 0x2c006;
 
-pub const NOTE_SCRIPT_ERRORS: [(u32, &str); 7] = [
+/// P2IDR's reclaimer is not the fallback account specified by the note inputs
+pub const ERR_P2IDR_RECLAIM_ACCT_IS_NOT_FALLBACK: u32 = 0x2c007;
+
+/// PSWAP script expects exactly 23 note inputs
+pub const ERR_PSWAP_WRONG_NUMBER_OF_INPUTS: u32 = 0x2c008;
+/// PSWAP script requires exactly 1 note asset
+pub const ERR_PSWAP_WRONG_NUMBER_OF_ASSETS: u32 = 0x2c009;
+/// PSWAP can not be consumed as the price provided by the oracle is below the minimum price
+pub const ERR_PSWAP_PRICE_BELOW_MINIMUM: u32 = 0x2c00a;
+/// PSWAP can not be consumed as the price provided by the oracle is above the maximum price
+pub const ERR_PSWAP_PRICE_ABOVE_MAXIMUM: u32 = 0x2c00b;
+
+/// P2IDM script expects exactly 6 note inputs
+pub const ERR_P2IDM_WRONG_NUMBER_OF_INPUTS: u32 = 0x2c00c;
+/// P2IDM's target account address and transaction address do not match
+pub const ERR_P2IDM_TARGET_ACCT_MISMATCH: u32 = 0x2c00d;
+
+/// PSWAP can not be consumed as the oracle did not provide a price for the pair
+pub const ERR_PSWAP_PRICE_NOT_SET: u32 = 0x2c00e;
+/// PSWAP can not be consumed as the price provided by the oracle is older than the maximum price age
+pub const ERR_PSWAP_PRICE_TOO_OLD: u32 = 0x2c00f;
+
+pub const NOTE_SCRIPT_ERRORS: [(u32, &str); 16] = [
     (ERR_P2ID_WRONG_NUMBER_OF_INPUTS, "P2ID script expects exactly 2 note inputs"),
     (ERR_P2ID_TARGET_ACCT_MISMATCH, "P2ID's target account address and transaction address do not match"),
 
-    (ERR_P2IDR_WRONG_NUMBER_OF_INPUTS, "P2IDR scripts expect exactly 3 note inputs"),
+    (ERR_P2IDR_WRONG_NUMBER_OF_INPUTS, "P2IDR scripts expect exactly 5 note inputs"),
     (ERR_P2IDR_RECLAIM_ACCT_IS_NOT_SENDER, "P2IDR's reclaimer is not the original sender"),
     (ERR_P2IDR_RECLAIM_HEIGHT_NOT_REACHED, "P2IDR can not be reclaimed as the transaction's reference block is lower than the reclaim height"),
 
     (ERR_SWAP_WRONG_NUMBER_OF_INPUTS, "SWAP script expects exactly 10 note inputs"),
     (ERR_SWAP_WRONG_NUMBER_OF_ASSETS, "SWAP script requires exactly 1 note asset"),
+
+    (ERR_P2IDR_RECLAIM_ACCT_IS_NOT_FALLBACK, "P2IDR's reclaimer is not the fallback account specified by the note inputs"),
+
+    (ERR_PSWAP_WRONG_NUMBER_OF_INPUTS, "PSWAP script expects exactly 23 note inputs"),
+    (ERR_PSWAP_WRONG_NUMBER_OF_ASSETS, "PSWAP script requires exactly 1 note asset"),
+    (ERR_PSWAP_PRICE_BELOW_MINIMUM, "PSWAP can not be consumed as the price provided by the oracle is below the minimum price"),
+    (ERR_PSWAP_PRICE_ABOVE_MAXIMUM, "PSWAP can not be consumed as the price provided by the oracle is above the maximum price"),
+
+    (ERR_P2IDM_WRONG_NUMBER_OF_INPUTS, "P2IDM script expects exactly 6 note inputs"),
+    (ERR_P2IDM_TARGET_ACCT_MISMATCH, "P2IDM's target account address and transaction address do not match"),
+
+    (ERR_PSWAP_PRICE_NOT_SET, "PSWAP can not be consumed as the oracle did not provide a price for the pair"),
+    (ERR_PSWAP_PRICE_TOO_OLD, "PSWAP can not be consumed as the price provided by the oracle is older than the maximum price age"),
 ];
@@ -20,6 +20,7 @@
 // FUNGIBLE_ASSET is in range 0x20200..0x20240
 // NON_FUNGIBLE_ASSET is in range 0x20240..0x20280
 // VAULT is in range 0x20280..0x202c0
+// AUTH is in range 0x202c0..0x20300
 
 // TX KERNEL ERRORS
 // ================================================================================================
@@ -61,6 +62,8 @@ pub const ERR_PROLOGUE_NUMBER_OF_NOTE_INPUTS_EXCEEDED_LIMIT: u32 = 0x2004e;
 pub const ERR_PROLOGUE_PROVIDED_ACCOUNT_DATA_DOES_NOT_MATCH_ON_CHAIN_COMMITMENT: u32 = 0x2004f;
 /// Provided info about assets of an input does not match its commitment
 pub const ERR_PROLOGUE_PROVIDED_INPUT_ASSETS_INFO_DOES_NOT_MATCH_ITS_COMMITMENT: u32 = 0x20050;
+/// Input note has expired at or before the reference block of the transaction
+pub const ERR_PROLOGUE_INPUT_NOTE_EXPIRED: u32 = 0x20051;
 
 /// Total number of assets in the account and all involved notes must stay the same
 pub const ERR_EPILOGUE_TOTAL_NUMBER_OF_ASSETS_MUST_STAY_THE_SAME: u32 = 0x20080;
@@ -71,6 +74,14 @@ pub const ERR_TX_INVALID_EXPIRATION_DELTA: u32 = 0x200c0;
 pub const ERR_TX_NUMBER_OF_OUTPUT_NOTES_EXCEEDS_LIMIT: u32 = 0x200c1;
 /// The transaction script is missing.
 pub const ERR_TX_TRANSACTION_SCRIPT_IS_MISSING: u32 = 0x200c2;
+/// Requested block number must not exceed the reference block number of the transaction
+pub const ERR_TX_BLOCK_NUMBER_EXCEEDS_REFERENCE_BLOCK_NUMBER: u32 = 0x200c3;
+/// Block header data does not match the commitment of the block
+pub const ERR_TX_BLOCK_HEADER_DATA_DOES_NOT_MATCH_COMMITMENT: u32 = 0x200c4;
+/// Requested input note index must be less than the number of input notes of the transaction
+pub const ERR_TX_INPUT_NOTE_INDEX_OUT_OF_BOUNDS: u32 = 0x200c5;
+/// MMR proof provided via the advice provider does not authenticate the block against the chain MMR
+pub const ERR_TX_BLOCK_MMR_PROOF_INVALID: u32 = 0x200c6;
 
 /// Attempted to access note assets from incorrect context
 pub const ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_ASSETS_FROM_INCORRECT_CONTEXT: u32 = 0x20100;
@@ -92,6 +103,18 @@ pub const ERR_NOTE_INVALID_TYPE: u32 = 0x20107;
 pub const ERR_NOTE_NUM_OF_ASSETS_EXCEED_LIMIT: u32 = 0x20108;
 /// The note's tag must fit into a u32 so the 32 most significant bits must be zero.
 pub const ERR_NOTE_TAG_MUST_BE_U32: u32 = 0x20109;
+/// Note inputs data does not match the note inputs commitment
+pub const ERR_NOTE_INPUTS_DATA_DOES_NOT_MATCH_COMMITMENT: u32 = 0x2010a;
+/// Requested note inputs start after the last note input
+pub const ERR_NOTE_INPUTS_START_INDEX_OUT_OF_BOUNDS: u32 = 0x2010b;
+/// Requested note input index must be less than the number of note inputs
+pub const ERR_NOTE_INPUT_INDEX_OUT_OF_BOUNDS: u32 = 0x2010c;
+/// Requested note input must be a valid u32 value
+pub const ERR_NOTE_INPUT_NOT_U32: u32 = 0x2010d;
+/// Sender of the note does not match the expected account ID
+pub const ERR_NOTE_SENDER_MISMATCH: u32 = 0x2010e;
+/// Attempted to access note metadata from incorrect context
+pub const ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_METADATA_FROM_INCORRECT_CONTEXT: u32 = 0x2010f;
 
 /// Anchor block commitment must not be empty
 pub const ERR_ACCOUNT_ANCHOR_BLOCK_COMMITMENT_MUST_NOT_BE_EMPTY: u32 = 0x20140;
@@ -139,6 +162,12 @@ pub const ERR_ACCOUNT_TOO_MANY_STORAGE_SLOTS: u32 = 0x20154;
 pub const ERR_ACCOUNT_STACK_OVERFLOW: u32 = 0x20155;
 /// Failed to end foreign context because the current account is the native account
 pub const ERR_ACCOUNT_STACK_UNDERFLOW: u32 = 0x20156;
+/// Account procedure may not be invoked by the current caller
+pub const ERR_ACCOUNT_PROC_CALLER_NOT_ALLOWED: u32 = 0x20157;
+/// Counter cannot be incremented beyond u32::MAX
+pub const ERR_ACCOUNT_COUNTER_OVERFLOW: u32 = 0x20159;
+/// Timestamp of the pushed price must be a u32 greater than the timestamp of the stored price
+pub const ERR_ACCOUNT_PRICE_ORACLE_STALE_PRICE: u32 = 0x2015a;
 
 /// Creation of a foreign context against the native account is forbidden
 pub const ERR_FOREIGN_ACCOUNT_CONTEXT_AGAINST_NATIVE_ACCOUNT: u32 = 0x20180;
@@ -182,6 +211,12 @@ pub const ERR_FUNGIBLE_ASSET_FORMAT_ELEMENT_TWO_AND_THREE_MUST_BE_FUNGIBLE_FAUCE
 pub const ERR_FUNGIBLE_ASSET_FORMAT_ELEMENT_ZERO_MUST_BE_WITHIN_LIMITS: u32 = 0x20205;
 /// Failed to build the fungible asset because the provided faucet id is not from a fungible faucet
 pub const ERR_FUNGIBLE_ASSET_PROVIDED_FAUCET_ID_IS_INVALID: u32 = 0x20206;
+/// Distribute by minter requires the minter public key of the faucet to be set
+pub const ERR_FUNGIBLE_ASSET_DISTRIBUTE_BY_MINTER_MINTER_NOT_SET: u32 = 0x20207;
+/// Minter operations require the account state to be unchanged by the transaction so far
+pub const ERR_FUNGIBLE_ASSET_MINTER_ACCOUNT_STATE_ALREADY_CHANGED: u32 = 0x20208;
+/// Distribute cannot be called after the transaction was authenticated by the minter
+pub const ERR_FUNGIBLE_ASSET_DISTRIBUTE_AFTER_MINTER_AUTHENTICATION: u32 = 0x20209;
 
 /// Non-fungible asset that already exists in the note cannot be added again
 pub const ERR_NON_FUNGIBLE_ASSET_ALREADY_EXISTS: u32 = 0x20240;
@@ -193,6 +228,10 @@ pub const ERR_NON_FUNGIBLE_ASSET_FORMAT_ELEMENT_THREE_MUST_BE_FUNGIBLE_FAUCET_ID
 pub const ERR_NON_FUNGIBLE_ASSET_FORMAT_MOST_SIGNIFICANT_BIT_MUST_BE_ZERO: u32 = 0x20243;
 /// Failed to build the non-fungible asset because the provided faucet id is not from a non-fungible faucet
 pub const ERR_NON_FUNGIBLE_ASSET_PROVIDED_FAUCET_ID_IS_INVALID: u32 = 0x20244;
+/// Data hashes of the non-fungible assets to distribute do not match the provided commitment
+pub const ERR_NON_FUNGIBLE_ASSET_DISTRIBUTE_BATCH_DATA_HASHES_DO_NOT_MATCH_COMMITMENT: u32 = 0x20245;
+/// Number of non-fungible assets to distribute must be between 1 and 64
+pub const ERR_NON_FUNGIBLE_ASSET_DISTRIBUTE_BATCH_INVALID_NUM_ASSETS: u32 = 0x20246;
 
 /// Failed to add fungible asset to the asset vault due to the initial value being invalid
 pub const ERR_VAULT_ADD_FUNGIBLE_ASSET_FAILED_INITIAL_VALUE_INVALID: u32 = 0x20280;
@@ -210,8 +249,49 @@ pub const ERR_VAULT_NON_FUNGIBLE_ASSET_ALREADY_EXISTS: u32 = 0x20285;
 pub const ERR_VAULT_NON_FUNGIBLE_ASSET_TO_REMOVE_NOT_FOUND: u32 = 0x20286;
 /// Failed to remove fungible asset from the asset vault due to the initial value being invalid
 pub const ERR_VAULT_REMOVE_FUNGIBLE_ASSET_FAILED_INITIAL_VALUE_INVALID: u32 = 0x20287;
+/// Asset was issued by a faucet which is not in the asset allowlist of the account
+pub const ERR_VAULT_ASSET_NOT_ALLOWED: u32 = 0x20288;
 
-pub const TX_KERNEL_ERRORS: [(u32, &str); 88] = [
+/// ECDSA public key provided via the advice stack does not match the public key commitment
+pub const ERR_AUTH_ECDSA_K256_PUBLIC_KEY_DOES_NOT_MATCH_COMMITMENT: u32 = 0x202c0;
+/// ECDSA public key is not a point on the secp256k1 curve
+pub const ERR_AUTH_ECDSA_K256_PUBLIC_KEY_NOT_ON_CURVE: u32 = 0x202c1;
+/// ECDSA signature components must be in the range [1, n) where n is the order of the secp256k1 group
+pub const ERR_AUTH_ECDSA_K256_SIGNATURE_SCALAR_OUT_OF_RANGE: u32 = 0x202c2;
+/// ECDSA signature is not valid for the provided public key and message
+pub const ERR_AUTH_ECDSA_K256_INVALID_SIGNATURE: u32 = 0x202c3;
+/// 256-bit value provided via the advice stack contains a limb which is not a u32 value
+pub const ERR_AUTH_ECDSA_K256_INVALID_LIMB: u32 = 0x202c4;
+/// Quotient and remainder of a modular multiplication provided via the advice stack are not valid
+pub const ERR_AUTH_ECDSA_K256_INVALID_MUL_MOD_HINT: u32 = 0x202c5;
+/// Point addition during ECDSA signature verification degenerated to a point doubling or to the point at infinity
+pub const ERR_AUTH_ECDSA_K256_DEGENERATE_POINT_ADDITION: u32 = 0x202c6;
+/// BLS12-381 public key provided via the advice stack does not match the public key commitment
+pub const ERR_AUTH_BLS12_381_PUBLIC_KEY_DOES_NOT_MATCH_COMMITMENT: u32 = 0x202c7;
+/// BLS12-381 public key is not a point on the G2 curve
+pub const ERR_AUTH_BLS12_381_PUBLIC_KEY_NOT_ON_CURVE: u32 = 0x202c8;
+/// Point provided via the advice stack for hashing the message to G1 is not valid
+pub const ERR_AUTH_BLS12_381_INVALID_HASH_TO_CURVE_HINT: u32 = 0x202c9;
+/// BLS12-381 signature is not a point on the G1 curve
+pub const ERR_AUTH_BLS12_381_SIGNATURE_NOT_ON_CURVE: u32 = 0x202ca;
+/// BLS12-381 signature is not in the prime-order subgroup of G1
+pub const ERR_AUTH_BLS12_381_SIGNATURE_NOT_IN_SUBGROUP: u32 = 0x202cb;
+/// BLS12-381 signature is not valid for the provided public key and message
+pub const ERR_AUTH_BLS12_381_INVALID_SIGNATURE: u32 = 0x202cc;
+/// 384-bit value provided via the advice stack contains a limb which is not a u32 value
+pub const ERR_AUTH_BLS12_381_INVALID_LIMB: u32 = 0x202cd;
+/// Quotient and remainder of a 384-bit modular multiplication provided via the advice stack are not valid
+pub const ERR_AUTH_BLS12_381_INVALID_MUL_MOD_HINT: u32 = 0x202ce;
+/// Modular inverse provided via the advice stack is not valid
+pub const ERR_AUTH_BLS12_381_INVALID_INVERSE_HINT: u32 = 0x202cf;
+/// Point addition during BLS12-381 signature verification degenerated to a point doubling or to the point at infinity
+pub const ERR_AUTH_BLS12_381_DEGENERATE_POINT_ADDITION: u32 = 0x202d0;
+/// BLS12-381 public key is not in the prime-order subgroup of G2
+pub const ERR_AUTH_BLS12_381_PUBLIC_KEY_NOT_IN_SUBGROUP: u32 = 0x202d1;
+/// Counter provided via the advice stack for hashing the message to G1 is not the smallest valid one
+pub const ERR_AUTH_BLS12_381_HASH_TO_CURVE_COUNTER_NOT_MINIMAL: u32 = 0x202d2;
+
+pub const TX_KERNEL_ERRORS: [(u32, &str); 127] = [
     (ERR_KERNEL_PROCEDURE_OFFSET_OUT_OF_BOUNDS, "Provided kernel procedure offset is out of bounds"),
 
     (ERR_PROLOGUE_EXISTING_ACCOUNT_MUST_HAVE_NON_ZERO_NONCE, "Existing accounts must have a non-zero nonce"),
@@ -231,12 +311,17 @@ pub const TX_KERNEL_ERRORS: [(u32, &str); 88] = [
     (ERR_PROLOGUE_NUMBER_OF_NOTE_INPUTS_EXCEEDED_LIMIT, "Number of note inputs exceeded the maximum limit of 128"),
     (ERR_PROLOGUE_PROVIDED_ACCOUNT_DATA_DOES_NOT_MATCH_ON_CHAIN_COMMITMENT, "Account data provided does not match the commitment recorded on-chain"),
     (ERR_PROLOGUE_PROVIDED_INPUT_ASSETS_INFO_DOES_NOT_MATCH_ITS_COMMITMENT, "Provided info about assets of an input does not match its commitment"),
+    (ERR_PROLOGUE_INPUT_NOTE_EXPIRED, "Input note has expired at or before the reference block of the transaction"),
 
     (ERR_EPILOGUE_TOTAL_NUMBER_OF_ASSETS_MUST_STAY_THE_SAME, "Total number of assets in the account and all involved notes must stay the same"),
 
     (ERR_TX_INVALID_EXPIRATION_DELTA, "Transaction expiration block delta must be within 0x1 and 0xFFFF."),
     (ERR_TX_NUMBER_OF_OUTPUT_NOTES_EXCEEDS_LIMIT, "Number of output notes in the transaction exceeds the maximum limit of 1024"),
     (ERR_TX_TRANSACTION_SCRIPT_IS_MISSING, "The transaction script is missing."),
+    (ERR_TX_BLOCK_NUMBER_EXCEEDS_REFERENCE_BLOCK_NUMBER, "Requested block number must not exceed the reference block number of the transaction"),
+    (ERR_TX_BLOCK_HEADER_DATA_DOES_NOT_MATCH_COMMITMENT, "Block header data does not match the commitment of the block"),
+    (ERR_TX_INPUT_NOTE_INDEX_OUT_OF_BOUNDS, "Requested input note index must be less than the number of input notes of the transaction"),
+    (ERR_TX_BLOCK_MMR_PROOF_INVALID, "MMR proof provided via the advice provider does not authenticate the block against the chain MMR"),
 
     (ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_ASSETS_FROM_INCORRECT_CONTEXT, "Attempted to access note assets from incorrect context"),
     (ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_INPUTS_FROM_INCORRECT_CONTEXT, "Attempted to access note inputs from incorrect context"),
@@ -248,6 +333,12 @@ pub const TX_KERNEL_ERRORS: [(u32, &str); 88] = [
     (ERR_NOTE_INVALID_TYPE, "Invalid note type"),
     (ERR_NOTE_NUM_OF_ASSETS_EXCEED_LIMIT, "Number of assets in a note exceed 255"),
     (ERR_NOTE_TAG_MUST_BE_U32, "The note's tag must fit into a u32 so the 32 most significant bits must be zero."),
+    (ERR_NOTE_INPUTS_DATA_DOES_NOT_MATCH_COMMITMENT, "Note inputs data does not match the note inputs commitment"),
+    (ERR_NOTE_INPUTS_START_INDEX_OUT_OF_BOUNDS, "Requested note inputs start after the last note input"),
+    (ERR_NOTE_INPUT_INDEX_OUT_OF_BOUNDS, "Requested note input index must be less than the number of note inputs"),
+    (ERR_NOTE_INPUT_NOT_U32, "Requested note input must be a valid u32 value"),
+    (ERR_NOTE_SENDER_MISMATCH, "Sender of the note does not match the expected account ID"),
+    (ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_METADATA_FROM_INCORRECT_CONTEXT, "Attempted to access note metadata from incorrect context"),
 
     (ERR_ACCOUNT_ANCHOR_BLOCK_COMMITMENT_MUST_NOT_BE_EMPTY, "Anchor block commitment must not be empty"),
     (ERR_ACCOUNT_CODE_COMMITMENT_MISMATCH, "Computed account code commitment does not match recorded account code commitment"),
@@ -272,6 +363,9 @@ pub const TX_KERNEL_ERRORS: [(u32, &str); 88] = [
     (ERR_ACCOUNT_TOO_MANY_STORAGE_SLOTS, "Number of account storage slots exceeds the maximum limit of 255"),
     (ERR_ACCOUNT_STACK_OVERFLOW, "Depth of the nested FPI calls exceeded 64"),
     (ERR_ACCOUNT_STACK_UNDERFLOW, "Failed to end foreign context because the current account is the native account"),
+    (ERR_ACCOUNT_PROC_CALLER_NOT_ALLOWED, "Account procedure may not be invoked by the current caller"),
+    (ERR_ACCOUNT_COUNTER_OVERFLOW, "Counter cannot be incremented beyond u32::MAX"),
+    (ERR_ACCOUNT_PRICE_ORACLE_STALE_PRICE, "Timestamp of the pushed price must be a u32 greater than the timestamp of the stored price"),
 
     (ERR_FOREIGN_ACCOUNT_CONTEXT_AGAINST_NATIVE_ACCOUNT, "Creation of a foreign context against the native account is forbidden"),
     (ERR_FOREIGN_ACCOUNT_ID_IS_ZERO, "ID of the provided foreign account equals zero."),
@@ -295,12 +389,17 @@ pub const TX_KERNEL_ERRORS: [(u32, &str); 88] = [
     (ERR_FUNGIBLE_ASSET_FORMAT_ELEMENT_TWO_AND_THREE_MUST_BE_FUNGIBLE_FAUCET_ID, "Malformed fungible asset: `ASSET[2]` and `ASSET[3]` must be a valid fungible faucet id"),
     (ERR_FUNGIBLE_ASSET_FORMAT_ELEMENT_ZERO_MUST_BE_WITHIN_LIMITS, "Malformed fungible asset: `ASSET[0]` exceeds the maximum allowed amount"),
     (ERR_FUNGIBLE_ASSET_PROVIDED_FAUCET_ID_IS_INVALID, "Failed to build the fungible asset because the provided faucet id is not from a fungible faucet"),
+    (ERR_FUNGIBLE_ASSET_DISTRIBUTE_BY_MINTER_MINTER_NOT_SET, "Distribute by minter requires the minter public key of the faucet to be set"),
+    (ERR_FUNGIBLE_ASSET_MINTER_ACCOUNT_STATE_ALREADY_CHANGED, "Minter operations require the account state to be unchanged by the transaction so far"),
+    (ERR_FUNGIBLE_ASSET_DISTRIBUTE_AFTER_MINTER_AUTHENTICATION, "Distribute cannot be called after the transaction was authenticated by the minter"),
 
     (ERR_NON_FUNGIBLE_ASSET_ALREADY_EXISTS, "Non-fungible asset that already exists in the note cannot be added again"),
     (ERR_NON_FUNGIBLE_ASSET_FAUCET_IS_NOT_ORIGIN, "The origin of the non-fungible asset is not this faucet"),
     (ERR_NON_FUNGIBLE_ASSET_FORMAT_ELEMENT_THREE_MUST_BE_FUNGIBLE_FAUCET_ID, "Malformed non-fungible asset: `ASSET[3]` is not a valid non-fungible faucet id"),
     (ERR_NON_FUNGIBLE_ASSET_FORMAT_MOST_SIGNIFICANT_BIT_MUST_BE_ZERO, "Malformed non-fungible asset: the most significant bit must be 0"),
     (ERR_NON_FUNGIBLE_ASSET_PROVIDED_FAUCET_ID_IS_INVALID, "Failed to build the non-fungible asset because the provided faucet id is not from a non-fungible faucet"),
+    (ERR_NON_FUNGIBLE_ASSET_DISTRIBUTE_BATCH_DATA_HASHES_DO_NOT_MATCH_COMMITMENT, "Data hashes of the non-fungible assets to distribute do not match the provided commitment"),
+    (ERR_NON_FUNGIBLE_ASSET_DISTRIBUTE_BATCH_INVALID_NUM_ASSETS, "Number of non-fungible assets to distribute must be between 1 and 64"),
 
     (ERR_VAULT_ADD_FUNGIBLE_ASSET_FAILED_INITIAL_VALUE_INVALID, "Failed to add fungible asset to the asset vault due to the initial value being invalid"),
     (ERR_VAULT_FUNGIBLE_ASSET_AMOUNT_LESS_THAN_AMOUNT_TO_WITHDRAW, "Failed to remove the fungible asset from the vault since the amount of the asset in the vault is less than the amount to remove"),
@@ -310,4 +409,25 @@ pub const TX_KERNEL_ERRORS: [(u32, &str); 88] = [
     (ERR_VAULT_NON_FUNGIBLE_ASSET_ALREADY_EXISTS, "The non-fungible asset already exists in the asset vault"),
     (ERR_VAULT_NON_FUNGIBLE_ASSET_TO_REMOVE_NOT_FOUND, "Failed to remove non-existent non-fungible asset from the vault"),
     (ERR_VAULT_REMOVE_FUNGIBLE_ASSET_FAILED_INITIAL_VALUE_INVALID, "Failed to remove fungible asset from the asset vault due to the initial value being invalid"),
+    (ERR_VAULT_ASSET_NOT_ALLOWED, "Asset was issued by a faucet which is not in the asset allowlist of the account"),
+
+    (ERR_AUTH_ECDSA_K256_PUBLIC_KEY_DOES_NOT_MATCH_COMMITMENT, "ECDSA public key provided via the advice stack does not match the public key commitment"),
+    (ERR_AUTH_ECDSA_K256_PUBLIC_KEY_NOT_ON_CURVE, "ECDSA public key is not a point on the secp256k1 curve"),
+    (ERR_AUTH_ECDSA_K256_SIGNATURE_SCALAR_OUT_OF_RANGE, "ECDSA signature components must be in the range [1, n) where n is the order of the secp256k1 group"),
+    (ERR_AUTH_ECDSA_K256_INVALID_SIGNATURE, "ECDSA signature is not valid for the provided public key and message"),
+    (ERR_AUTH_ECDSA_K256_INVALID_LIMB, "256-bit value provided via the advice stack contains a limb which is not a u32 value"),
+    (ERR_AUTH_ECDSA_K256_INVALID_MUL_MOD_HINT, "Quotient and remainder of a modular multiplication provided via the advice stack are not valid"),
+    (ERR_AUTH_ECDSA_K256_DEGENERATE_POINT_ADDITION, "Point addition during ECDSA signature verification degenerated to a point doubling or to the point at infinity"),
+    (ERR_AUTH_BLS12_381_PUBLIC_KEY_DOES_NOT_MATCH_COMMITMENT, "BLS12-381 public key provided via the advice stack does not match the public key commitment"),
+    (ERR_AUTH_BLS12_381_PUBLIC_KEY_NOT_ON_CURVE, "BLS12-381 public key is not a point on the G2 curve"),
+    (ERR_AUTH_BLS12_381_INVALID_HASH_TO_CURVE_HINT, "Point provided via the advice stack for hashing the message to G1 is not valid"),
+    (ERR_AUTH_BLS12_381_SIGNATURE_NOT_ON_CURVE, "BLS12-381 signature is not a point on the G1 curve"),
+    (ERR_AUTH_BLS12_381_SIGNATURE_NOT_IN_SUBGROUP, "BLS12-381 signature is not in the prime-order subgroup of G1"),
+    (ERR_AUTH_BLS12_381_INVALID_SIGNATURE, "BLS12-381 signature is not valid for the provided public key and message"),
+    (ERR_AUTH_BLS12_381_INVALID_LIMB, "384-bit value provided via the advice stack contains a limb which is not a u32 value"),
+    (ERR_AUTH_BLS12_381_INVALID_MUL_MOD_HINT, "Quotient and remainder of a 384-bit modular multiplication provided via the advice stack are not valid"),
+    (ERR_AUTH_BLS12_381_INVALID_INVERSE_HINT, "Modular inverse provided via the advice stack is not valid"),
+    (ERR_AUTH_BLS12_381_DEGENERATE_POINT_ADDITION, "Point addition during BLS12-381 signature verification degenerated to a point doubling or to the point at infinity"),
+    (ERR_AUTH_BLS12_381_PUBLIC_KEY_NOT_IN_SUBGROUP, "BLS12-381 public key is not in the prime-order subgroup of G2"),
+    (ERR_AUTH_BLS12_381_HASH_TO_CURVE_COUNTER_NOT_MINIMAL, "Counter provided via the advice stack for hashing the message to G1 is not the smallest valid one"),
 ];
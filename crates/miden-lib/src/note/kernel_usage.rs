@@ -0,0 +1,324 @@
+use alloc::collections::{BTreeSet, VecDeque};
+
+use miden_objects::{
+    Digest, Felt, ONE, ZERO,
+    assembly::mast::{MastForest, MastNode, MastNodeId},
+    note::NoteScript,
+    vm::Operation,
+};
+use miden_stdlib::StdLibrary;
+
+use crate::{MidenLib, transaction::TransactionKernel};
+
+// KERNEL PROCEDURE USAGE
+// ================================================================================================
+
+/// The set of kernel procedures a note script can invoke, as determined by static analysis of its
+/// MAST.
+///
+/// Note scripts invoke kernel procedures by executing `syscall.exec_kernel_proc` with the offset of
+/// the desired procedure on top of the stack, which is usually done via the procedures of the
+/// `miden` library. The analysis walks the MAST of the note script, including the procedures of
+/// the `miden` and standard libraries it references, and determines the offset at each syscall by
+/// tracking the constants pushed onto the stack before it.
+///
+/// The analysis is conservative: if an invoked procedure cannot be determined, e.g. because the
+/// offset is computed at runtime or because the script uses dynamic calls, the usage is marked as
+/// incomplete and the script should be assumed to be able to invoke any kernel procedure.
+/// Procedures which are neither part of the note script nor of the `miden` and standard libraries
+/// (e.g. procedures of the consuming account) are reported as unresolved, and kernel procedures
+/// invoked by them are not included in the usage.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KernelProcedureUsage {
+    /// Offsets of the invoked procedures in the kernel procedure list.
+    procedures: BTreeSet<usize>,
+    /// Roots of the procedures invoked by the note script which could not be resolved.
+    unresolved_procedures: BTreeSet<Digest>,
+    /// Whether some of the kernel procedures invoked by the note script could not be determined.
+    is_incomplete: bool,
+}
+
+impl KernelProcedureUsage {
+    /// Analyzes the provided note script and returns the kernel procedures it can invoke.
+    ///
+    /// The note script is assumed to be compiled against the latest version of the transaction
+    /// kernel.
+    pub fn from_note_script(note_script: &NoteScript) -> Self {
+        let miden_lib = MidenLib::default();
+        let std_lib = StdLibrary::default();
+        let mut analyzer = Analyzer {
+            libraries: [miden_lib.mast_forest(), std_lib.mast_forest()],
+            visited_procedures: BTreeSet::new(),
+            usage: KernelProcedureUsage::default(),
+        };
+
+        analyzer.visit(
+            &note_script.mast(),
+            note_script.entrypoint(),
+            &mut AbstractStack::default(),
+        );
+
+        analyzer.usage
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the names of the kernel procedures the note script can invoke.
+    pub fn procedure_names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.procedures.iter().map(|&offset| Self::kernel_procedure_names()[offset])
+    }
+
+    /// Returns the MAST roots of the kernel procedures the note script can invoke.
+    pub fn procedure_roots(&self) -> impl Iterator<Item = Digest> + '_ {
+        self.procedures.iter().map(|&offset| Self::kernel_procedures()[offset])
+    }
+
+    /// Returns `true` if the note script can invoke the kernel procedure with the provided name,
+    /// e.g. `account_set_item`.
+    ///
+    /// If the usage is incomplete, this returns `true` for all procedures.
+    pub fn can_invoke(&self, procedure_name: &str) -> bool {
+        self.is_incomplete || self.procedure_names().any(|name| name == procedure_name)
+    }
+
+    /// Returns the MAST roots of the procedures invoked by the note script which are neither part
+    /// of the note script itself nor of the `miden` and standard libraries, e.g. account
+    /// procedures.
+    pub fn unresolved_procedures(&self) -> &BTreeSet<Digest> {
+        &self.unresolved_procedures
+    }
+
+    /// Returns `true` if some of the kernel procedures invoked by the note script could not be
+    /// determined, in which case the note script should be assumed to be able to invoke any kernel
+    /// procedure.
+    pub fn is_incomplete(&self) -> bool {
+        self.is_incomplete
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    fn kernel_procedures() -> &'static [Digest] {
+        TransactionKernel::latest().procedures()
+    }
+
+    fn kernel_procedure_names() -> &'static [&'static str] {
+        TransactionKernel::latest().procedure_names()
+    }
+}
+
+// ANALYZER
+// ================================================================================================
+
+/// Walks the MAST of a note script and collects the kernel procedures it invokes.
+struct Analyzer<'a> {
+    /// The MAST forests against which external nodes are resolved.
+    libraries: [&'a MastForest; 2],
+    /// Roots of the library procedures which have already been analyzed.
+    visited_procedures: BTreeSet<Digest>,
+    usage: KernelProcedureUsage,
+}
+
+impl Analyzer<'_> {
+    /// Visits the provided node, updating the stack with the effects of its execution.
+    fn visit(&mut self, forest: &MastForest, node_id: MastNodeId, stack: &mut AbstractStack) {
+        match &forest[node_id] {
+            MastNode::Block(basic_block) => {
+                basic_block.operations().for_each(|op| stack.apply(op));
+                return;
+            },
+            MastNode::Join(join_node) => {
+                self.visit(forest, join_node.first(), stack);
+                self.visit(forest, join_node.second(), stack);
+                return;
+            },
+            MastNode::Split(split_node) => {
+                self.visit(forest, split_node.on_true(), &mut stack.clone());
+                self.visit(forest, split_node.on_false(), &mut stack.clone());
+            },
+            MastNode::Loop(loop_node) => {
+                self.visit(forest, loop_node.body(), &mut AbstractStack::default());
+            },
+            MastNode::Call(call_node) if call_node.is_syscall() => {
+                // kernel procedures are invoked via `exec_kernel_proc` with their offset on top
+                // of the stack
+                let offset = stack.top().map(|offset| offset.as_int() as usize);
+                match offset
+                    .filter(|&offset| offset < KernelProcedureUsage::kernel_procedures().len())
+                {
+                    Some(offset) => {
+                        self.usage.procedures.insert(offset);
+                    },
+                    None => self.usage.is_incomplete = true,
+                }
+            },
+            MastNode::Call(call_node) => {
+                self.visit(forest, call_node.callee(), &mut AbstractStack::default());
+            },
+            MastNode::Dyn(_) => self.usage.is_incomplete = true,
+            MastNode::External(external_node) => self.visit_external(external_node.digest()),
+        }
+
+        // the effects of control flow and calls on the stack are not tracked
+        *stack = AbstractStack::default();
+    }
+
+    /// Resolves the procedure with the provided root against the libraries and visits it.
+    fn visit_external(&mut self, root: Digest) {
+        if !self.visited_procedures.insert(root) {
+            return;
+        }
+
+        let libraries = self.libraries;
+        let resolved = libraries
+            .into_iter()
+            .find_map(|forest| forest.find_procedure_root(root).map(|node_id| (forest, node_id)));
+
+        match resolved {
+            Some((forest, node_id)) => self.visit(forest, node_id, &mut AbstractStack::default()),
+            None => {
+                self.usage.unresolved_procedures.insert(root);
+            },
+        }
+    }
+}
+
+// ABSTRACT STACK
+// ================================================================================================
+
+/// Tracks the constant values at the top of the operand stack.
+///
+/// The element at index 0 is the top of the stack. Elements beyond the tracked ones, as well as
+/// elements produced by operations which are not modeled, are unknown.
+#[derive(Debug, Clone, Default)]
+struct AbstractStack(VecDeque<Option<Felt>>);
+
+impl AbstractStack {
+    fn top(&self) -> Option<Felt> {
+        self.get(0)
+    }
+
+    fn get(&self, index: usize) -> Option<Felt> {
+        self.0.get(index).copied().flatten()
+    }
+
+    /// Makes sure the first `depth` elements are tracked, so that they can be rearranged.
+    fn ensure_depth(&mut self, depth: usize) {
+        while self.0.len() < depth {
+            self.0.push_back(None);
+        }
+    }
+
+    fn swap_ranges(&mut self, a: usize, b: usize, len: usize) {
+        self.ensure_depth(b + len);
+        for i in 0..len {
+            self.0.swap(a + i, b + i);
+        }
+    }
+
+    fn move_up(&mut self, index: usize) {
+        self.ensure_depth(index + 1);
+        let value = self.0.remove(index).flatten();
+        self.0.push_front(value);
+    }
+
+    fn move_down(&mut self, index: usize) {
+        self.ensure_depth(index + 1);
+        let value = self.0.pop_front().flatten();
+        self.0.insert(index, value);
+    }
+
+    /// Applies the effects of the provided operation to the stack.
+    fn apply(&mut self, op: &Operation) {
+        match op {
+            Operation::Noop => {},
+            Operation::Push(value) => self.0.push_front(Some(*value)),
+            Operation::Pad => self.0.push_front(Some(ZERO)),
+            Operation::Incr => {
+                if let Some(top) = self.0.front_mut() {
+                    *top = top.map(|value| value + ONE);
+                }
+            },
+            Operation::Drop => {
+                self.0.pop_front();
+            },
+            Operation::Dup0 => self.0.push_front(self.get(0)),
+            Operation::Dup1 => self.0.push_front(self.get(1)),
+            Operation::Dup2 => self.0.push_front(self.get(2)),
+            Operation::Dup3 => self.0.push_front(self.get(3)),
+            Operation::Dup4 => self.0.push_front(self.get(4)),
+            Operation::Dup5 => self.0.push_front(self.get(5)),
+            Operation::Dup6 => self.0.push_front(self.get(6)),
+            Operation::Dup7 => self.0.push_front(self.get(7)),
+            Operation::Dup9 => self.0.push_front(self.get(9)),
+            Operation::Dup11 => self.0.push_front(self.get(11)),
+            Operation::Dup13 => self.0.push_front(self.get(13)),
+            Operation::Dup15 => self.0.push_front(self.get(15)),
+            Operation::Swap => self.swap_ranges(0, 1, 1),
+            Operation::SwapW => self.swap_ranges(0, 4, 4),
+            Operation::SwapW2 => self.swap_ranges(0, 8, 4),
+            Operation::SwapW3 => self.swap_ranges(0, 12, 4),
+            Operation::SwapDW => self.swap_ranges(0, 8, 8),
+            Operation::MovUp2 => self.move_up(2),
+            Operation::MovUp3 => self.move_up(3),
+            Operation::MovUp4 => self.move_up(4),
+            Operation::MovUp5 => self.move_up(5),
+            Operation::MovUp6 => self.move_up(6),
+            Operation::MovUp7 => self.move_up(7),
+            Operation::MovUp8 => self.move_up(8),
+            Operation::MovDn2 => self.move_down(2),
+            Operation::MovDn3 => self.move_down(3),
+            Operation::MovDn4 => self.move_down(4),
+            Operation::MovDn5 => self.move_down(5),
+            Operation::MovDn6 => self.move_down(6),
+            Operation::MovDn7 => self.move_down(7),
+            Operation::MovDn8 => self.move_down(8),
+            // the effects of all other operations are not modeled
+            _ => self.0.clear(),
+        }
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::note::well_known_note::WellKnownNote;
+
+    #[test]
+    fn well_known_note_kernel_usage() {
+        let usage = KernelProcedureUsage::from_note_script(&WellKnownNote::P2ID.script());
+        assert!(!usage.is_incomplete());
+        assert!(usage.unresolved_procedures().is_empty());
+        assert_eq!(
+            usage.procedure_names().collect::<Vec<_>>(),
+            [
+                "account_get_id",
+                "account_add_asset",
+                "note_get_assets_info",
+                "note_get_inputs_commitment"
+            ]
+        );
+        assert!(!usage.can_invoke("account_set_item"));
+
+        let usage = KernelProcedureUsage::from_note_script(&WellKnownNote::SWAP.script());
+        assert!(!usage.is_incomplete());
+        assert!(usage.can_invoke("tx_create_note"));
+        assert!(usage.can_invoke("account_remove_asset"));
+    }
+
+    #[test]
+    fn dynamic_calls_make_kernel_usage_incomplete() {
+        let note_script =
+            NoteScript::compile("begin dynexec end", TransactionKernel::assembler()).unwrap();
+
+        let usage = KernelProcedureUsage::from_note_script(&note_script);
+        assert!(usage.is_incomplete());
+        assert!(usage.can_invoke("account_set_item"));
+    }
+}
@@ -0,0 +1,125 @@
+use alloc::vec::Vec;
+
+use miden_objects::{
+    Digest, Hasher, NoteError, Word,
+    account::AccountId,
+    note::{NoteInputs, NoteRecipient},
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
+
+use super::well_known_note::WellKnownNote;
+
+// PAYMENT MEMO
+// ================================================================================================
+
+/// A memo attached to a P2IDM payment.
+///
+/// The memo allows the recipient of a payment to associate it with some off-chain context, e.g. an
+/// exchange matching a deposit to the ID of one of its users. The plaintext of the memo is shared
+/// with the recipient off-chain, together with the rest of the note details, while the note itself
+/// only carries a commitment to the memo. The commitment is appended to the target account ID in
+/// the P2IDM note inputs, which look as follows:
+///
+/// ```text
+/// [target_id_suffix, target_id_prefix, MEMO_COMMITMENT]
+/// ```
+///
+/// The P2IDM script ignores the commitment, so a payment with a memo is consumed exactly like a
+/// P2ID note. Since the commitment is part of the note inputs, it is bound to the note
+/// recipient and hence to the note ID, which allows the recipient to verify that the memo it
+/// received is the one the sender committed to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaymentMemo(Vec<u8>);
+
+impl PaymentMemo {
+    /// The number of inputs of a P2IDM note.
+    pub const NUM_P2IDM_INPUTS: usize = 6;
+
+    /// Returns a new [PaymentMemo] instantiated from the provided bytes.
+    pub fn new(memo: impl Into<Vec<u8>>) -> Self {
+        Self(memo.into())
+    }
+
+    /// Returns the plaintext of this memo.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Returns the commitment to this memo, i.e. the hash of its plaintext.
+    pub fn commitment(&self) -> Digest {
+        Hasher::hash(&self.0)
+    }
+
+    /// Returns the inputs of a P2IDM note paying to `target` and carrying the commitment to this
+    /// memo.
+    pub fn to_p2idm_inputs(&self, target: AccountId) -> Result<NoteInputs, NoteError> {
+        let commitment = Word::from(self.commitment());
+        let mut inputs = vec![target.suffix(), target.prefix().as_felt()];
+        inputs.extend_from_slice(&commitment);
+
+        NoteInputs::new(inputs)
+    }
+
+    /// Returns the memo commitment carried by the provided recipient, or `None` if the recipient is
+    /// not a P2IDM recipient.
+    pub fn commitment_from_recipient(recipient: &NoteRecipient) -> Option<Digest> {
+        let inputs = recipient.inputs().values();
+        if recipient.script().root() != WellKnownNote::P2IDM.script_root()
+            || inputs.len() != Self::NUM_P2IDM_INPUTS
+        {
+            return None;
+        }
+
+        Some(Digest::new([inputs[2], inputs[3], inputs[4], inputs[5]]))
+    }
+
+    /// Returns `true` if the provided recipient is a P2IDM recipient carrying the commitment to
+    /// this memo.
+    pub fn verify(&self, recipient: &NoteRecipient) -> bool {
+        Self::commitment_from_recipient(recipient) == Some(self.commitment())
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for PaymentMemo {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.0.write_into(target);
+    }
+}
+
+impl Deserializable for PaymentMemo {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Vec::<u8>::read_from(source).map(Self)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_objects::testing::account_id::ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE;
+
+    use super::*;
+    use crate::note::utils::{build_p2id_recipient, build_p2idm_recipient};
+
+    #[test]
+    fn payment_memo_verification() {
+        let target = AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap();
+        let memo = PaymentMemo::new("user-1337");
+
+        let recipient = build_p2idm_recipient(target, &memo, Word::default()).unwrap();
+        assert_eq!(PaymentMemo::commitment_from_recipient(&recipient), Some(memo.commitment()));
+        assert!(memo.verify(&recipient));
+        assert!(!PaymentMemo::new("user-1338").verify(&recipient));
+
+        let recipient = build_p2id_recipient(target, Word::default()).unwrap();
+        assert_eq!(PaymentMemo::commitment_from_recipient(&recipient), None);
+        assert!(!memo.verify(&recipient));
+
+        let memo_bytes = memo.to_bytes();
+        assert_eq!(PaymentMemo::read_from_bytes(&memo_bytes).unwrap(), memo);
+    }
+}
@@ -1,7 +1,8 @@
 use alloc::vec::Vec;
 
+use memo::PaymentMemo;
 use miden_objects::{
-    Felt, NoteError, Word,
+    Felt, NoteError, Word, ZERO,
     account::AccountId,
     asset::Asset,
     block::BlockNumber,
@@ -11,9 +12,15 @@ use miden_objects::{
         NoteMetadata, NoteRecipient, NoteTag, NoteType,
     },
 };
+use oracle::OraclePriceBounds;
 use utils::build_swap_tag;
 use well_known_note::WellKnownNote;
 
+pub mod kernel_usage;
+pub mod memo;
+pub mod note_utils;
+pub mod oracle;
+pub mod serial_num;
 pub mod utils;
 pub mod well_known_note;
 
@@ -49,6 +56,71 @@ pub fn create_p2id_note<R: FeltRng>(
     Ok(Note::new(vault, metadata, recipient))
 }
 
+/// Generates a P2ID note carrying multiple assets, and returns it together with its
+/// [NoteDetails].
+///
+/// This works like [create_p2id_note], but additionally validates the asset bundle before the
+/// note is built: the bundle must not be empty, must not contain more than
+/// [`NoteAssets::MAX_NUM_ASSETS`] assets or any duplicate assets, and must not contain fungible
+/// assets with a zero amount. The returned [NoteDetails] can be shared with the target off-chain
+/// if the note is private.
+///
+/// # Errors
+/// Returns an error if the asset bundle is invalid or the note cannot be built.
+pub fn create_multi_asset_p2id_note<R: FeltRng>(
+    sender: AccountId,
+    target: AccountId,
+    assets: Vec<Asset>,
+    note_type: NoteType,
+    aux: Felt,
+    rng: &mut R,
+) -> Result<(Note, NoteDetails), NoteError> {
+    if assets.is_empty() {
+        return Err(NoteError::EmptyAssets);
+    }
+
+    if let Some(asset) = assets.iter().find_map(|asset| match asset {
+        Asset::Fungible(asset) if asset.amount() == 0 => Some(asset),
+        _ => None,
+    }) {
+        return Err(NoteError::ZeroAmountFungibleAsset(asset.faucet_id()));
+    }
+
+    let note = create_p2id_note(sender, target, assets, note_type, aux, rng)?;
+    let details = NoteDetails::from(&note);
+
+    Ok((note, details))
+}
+
+/// Generates a P2IDM note - pay to id with a commitment to the provided memo.
+///
+/// This works like [create_p2id_note], but uses the `P2IDM` script which additionally carries the
+/// commitment to `memo` in the note inputs. The plaintext of the memo is not part of the note and
+/// must be shared with the target off-chain, together with the note details. The target can then
+/// use [PaymentMemo::verify] to check that the received memo matches the note.
+///
+/// # Errors
+/// Returns an error if deserialization or compilation of the `P2IDM` script fails.
+pub fn create_p2idm_note<R: FeltRng>(
+    sender: AccountId,
+    target: AccountId,
+    assets: Vec<Asset>,
+    note_type: NoteType,
+    aux: Felt,
+    memo: &PaymentMemo,
+    rng: &mut R,
+) -> Result<Note, NoteError> {
+    let serial_num = rng.draw_word();
+    let recipient = utils::build_p2idm_recipient(target, memo, serial_num)?;
+
+    let tag = NoteTag::from_account_id(target, NoteExecutionMode::Local)?;
+
+    let metadata = NoteMetadata::new(sender, note_type, tag, NoteExecutionHint::always(), aux)?;
+    let vault = NoteAssets::new(assets)?;
+
+    Ok(Note::new(vault, metadata, recipient))
+}
+
 /// Generates a P2IDR note - pay to id with recall after a certain block height.
 ///
 /// This script enables the transfer of assets from the sender `sender` account to the `target`
@@ -72,8 +144,57 @@ pub fn create_p2idr_note<R: FeltRng>(
 ) -> Result<Note, NoteError> {
     let note_script = WellKnownNote::P2IDR.script();
 
-    let inputs =
-        NoteInputs::new(vec![target.suffix(), target.prefix().as_felt(), recall_height.into()])?;
+    // a zero fallback account ID means that the note can be reclaimed by the sender
+    let inputs = NoteInputs::new(vec![
+        target.suffix(),
+        target.prefix().as_felt(),
+        recall_height.into(),
+        ZERO,
+        ZERO,
+    ])?;
+    let tag = NoteTag::from_account_id(target, NoteExecutionMode::Local)?;
+    let serial_num = rng.draw_word();
+
+    let vault = NoteAssets::new(assets)?;
+    let metadata = NoteMetadata::new(sender, note_type, tag, NoteExecutionHint::always(), aux)?;
+    let recipient = NoteRecipient::new(serial_num, note_script, inputs);
+    Ok(Note::new(vault, metadata, recipient))
+}
+
+/// Generates a P2IDR note with a fallback account - pay to id with recall by a fallback account
+/// after a certain block height.
+///
+/// This script enables the transfer of assets from the `sender` account to the `target` account
+/// by specifying the target's account ID. If the note has not been consumed by the target within
+/// the specified timeframe, the assets can be reclaimed by the `fallback` account instead of the
+/// sender. This allows for dead-man-switch and inheritance flows, where the assets should end up
+/// in an account other than the one which created the note.
+///
+/// The passed-in `rng` is used to generate a serial number for the note. The returned note's tag
+/// is set to the target's account ID.
+///
+/// # Errors
+/// Returns an error if deserialization or compilation of the `P2IDR` script fails.
+#[allow(clippy::too_many_arguments)]
+pub fn create_p2idr_note_with_fallback<R: FeltRng>(
+    sender: AccountId,
+    target: AccountId,
+    fallback: AccountId,
+    assets: Vec<Asset>,
+    note_type: NoteType,
+    aux: Felt,
+    recall_height: BlockNumber,
+    rng: &mut R,
+) -> Result<Note, NoteError> {
+    let note_script = WellKnownNote::P2IDR.script();
+
+    let inputs = NoteInputs::new(vec![
+        target.suffix(),
+        target.prefix().as_felt(),
+        recall_height.into(),
+        fallback.suffix(),
+        fallback.prefix().as_felt(),
+    ])?;
     let tag = NoteTag::from_account_id(target, NoteExecutionMode::Local)?;
     let serial_num = rng.draw_word();
 
@@ -138,3 +259,55 @@ pub fn create_swap_note<R: FeltRng>(
 
     Ok((note, payback_note))
 }
+
+/// Generates a PSWAP note - price-aware swap of assets between two accounts - and returns the note
+/// as well as [NoteDetails] for the payback note.
+///
+/// This works like [create_swap_note], but the note can only be consumed while the price provided
+/// by the oracle account specified in `price_bounds` is recent enough and within the specified
+/// bounds. The oracle
+/// account must be provided as a foreign account to the transaction consuming the note.
+///
+/// # Errors
+/// Returns an error if deserialization or compilation of the `PSWAP` script fails.
+pub fn create_pswap_note<R: FeltRng>(
+    sender: AccountId,
+    offered_asset: Asset,
+    requested_asset: Asset,
+    price_bounds: &OraclePriceBounds,
+    note_type: NoteType,
+    aux: Felt,
+    rng: &mut R,
+) -> Result<(Note, NoteDetails), NoteError> {
+    let note_script = WellKnownNote::PSWAP.script();
+
+    let payback_serial_num = rng.draw_word();
+    let payback_recipient = utils::build_p2id_recipient(sender, payback_serial_num)?;
+
+    let payback_recipient_word: Word = payback_recipient.digest().into();
+    let requested_asset_word: Word = requested_asset.into();
+    let payback_tag = NoteTag::from_account_id(sender, NoteExecutionMode::Local)?;
+
+    let mut inputs = Vec::with_capacity(23);
+    inputs.extend_from_slice(&payback_recipient_word);
+    inputs.extend_from_slice(&requested_asset_word);
+    inputs.extend_from_slice(&[payback_tag.inner().into(), NoteExecutionHint::always().into()]);
+    inputs.extend(price_bounds.to_inputs());
+    let inputs = NoteInputs::new(inputs)?;
+
+    // build the tag for the SWAP use case
+    let tag = build_swap_tag(note_type, &offered_asset, &requested_asset)?;
+    let serial_num = rng.draw_word();
+
+    // build the outgoing note
+    let metadata = NoteMetadata::new(sender, note_type, tag, NoteExecutionHint::always(), aux)?;
+    let assets = NoteAssets::new(vec![offered_asset])?;
+    let recipient = NoteRecipient::new(serial_num, note_script, inputs);
+    let note = Note::new(assets, metadata, recipient);
+
+    // build the payback note details
+    let payback_assets = NoteAssets::new(vec![requested_asset])?;
+    let payback_note = NoteDetails::new(payback_assets, payback_recipient);
+
+    Ok((note, payback_note))
+}
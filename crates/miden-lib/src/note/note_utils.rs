@@ -0,0 +1,124 @@
+use alloc::vec::Vec;
+
+use miden_objects::{
+    Digest,
+    assembly::{LibraryPath, ProcedureName, QualifiedProcedureName},
+    utils::sync::LazyLock,
+};
+
+use crate::MidenLib;
+
+// NOTE UTILS ROOTS
+// ================================================================================================
+
+// Initialize the MAST roots of the note utility procedures only once
+static NOTE_UTILS_ROOTS: LazyLock<Vec<Digest>> = LazyLock::new(|| {
+    let miden_lib = MidenLib::default();
+    let library = miden_lib.as_ref();
+    let module_path = LibraryPath::new(NoteUtilsProcedure::MODULE_PATH)
+        .expect("note utils module path should be valid");
+
+    NoteUtilsProcedure::ALL
+        .iter()
+        .map(|procedure| {
+            let name = QualifiedProcedureName::new(
+                module_path.clone(),
+                ProcedureName::new(procedure.name())
+                    .expect("note utils procedure name should be valid"),
+            );
+            let node_id = library.get_export_node_id(&name);
+            library.mast_forest()[node_id].digest()
+        })
+        .collect()
+});
+
+// NOTE UTILS PROCEDURE
+// ================================================================================================
+
+/// The procedures of the `miden::note_utils` module, a library of reusable helpers for note script
+/// authors.
+///
+/// The module is part of the `miden` library (i.e. [`MidenLib`]), so note scripts compiled with
+/// [`TransactionKernel::assembler()`][kasm] can use it via `use.miden::note_utils`. The MAST roots
+/// of its procedures can be obtained via [`NoteUtilsProcedure::root`], e.g. to check whether a
+/// note script invokes one of them.
+///
+/// [kasm]: crate::transaction::TransactionKernel::assembler
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteUtilsProcedure {
+    /// `word_eq`: returns whether the two words on top of the stack are equal.
+    WordEq,
+    /// `word_is_empty`: returns whether the word on top of the stack is the empty word.
+    WordIsEmpty,
+    /// `has_balance_at_least`: returns whether the native account holds at least the specified
+    /// amount of a fungible asset.
+    HasBalanceAtLeast,
+    /// `get_input_felt`: returns the note input at the specified index.
+    GetInputFelt,
+    /// `get_input_u32`: returns the note input at the specified index, asserting it is a u32.
+    GetInputU32,
+    /// `get_input_word`: returns the four note inputs starting at the specified index as a word.
+    GetInputWord,
+    /// `get_input_account_id`: returns the account ID stored in the two note inputs starting at the
+    /// specified index.
+    GetInputAccountId,
+    /// `assert_sender_is`: asserts that the sender of the note is the specified account.
+    AssertSenderIs,
+}
+
+impl NoteUtilsProcedure {
+    /// The path of the MASM module containing the note utility procedures.
+    pub const MODULE_PATH: &'static str = "miden::note_utils";
+
+    /// All procedures of the `miden::note_utils` module.
+    pub const ALL: [Self; 8] = [
+        Self::WordEq,
+        Self::WordIsEmpty,
+        Self::HasBalanceAtLeast,
+        Self::GetInputFelt,
+        Self::GetInputU32,
+        Self::GetInputWord,
+        Self::GetInputAccountId,
+        Self::AssertSenderIs,
+    ];
+
+    /// Returns the name of the procedure within the `miden::note_utils` module.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::WordEq => "word_eq",
+            Self::WordIsEmpty => "word_is_empty",
+            Self::HasBalanceAtLeast => "has_balance_at_least",
+            Self::GetInputFelt => "get_input_felt",
+            Self::GetInputU32 => "get_input_u32",
+            Self::GetInputWord => "get_input_word",
+            Self::GetInputAccountId => "get_input_account_id",
+            Self::AssertSenderIs => "assert_sender_is",
+        }
+    }
+
+    /// Returns the MAST root of the procedure.
+    pub fn root(&self) -> Digest {
+        NOTE_UTILS_ROOTS[*self as usize]
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::BTreeSet;
+
+    use super::*;
+
+    #[test]
+    fn note_utils_roots_are_exported_by_miden_lib() {
+        let miden_lib = MidenLib::default();
+        let exported: BTreeSet<Digest> = miden_lib.mast_forest().procedure_digests().collect();
+        let roots: BTreeSet<Digest> =
+            NoteUtilsProcedure::ALL.iter().map(NoteUtilsProcedure::root).collect();
+
+        assert_eq!(roots.len(), NoteUtilsProcedure::ALL.len());
+        assert!(roots.is_subset(&exported));
+    }
+}
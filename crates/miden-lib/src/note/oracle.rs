@@ -0,0 +1,101 @@
+use alloc::vec::Vec;
+
+use miden_objects::{Digest, Felt, Word, account::AccountId};
+
+use crate::account::oracles::{PricePair, StandardPriceOracle};
+
+// ORACLE PRICE BOUNDS
+// ================================================================================================
+
+/// The consumption condition of a PSWAP note, i.e. the oracle account to read the price from, the
+/// bounds the price must be within and the maximum age of the price for the note to be consumable.
+///
+/// The price is read by invoking the procedure with root `price_proc_root` of the oracle account
+/// via foreign procedure invocation, with `price_key` on top of the stack. By default, this is the
+/// `read_price` procedure of the [`StandardPriceOracle`] component.
+///
+/// A PSWAP note can only be consumed if `min_price <= price <= max_price`, where the prices are
+/// compared as field elements, and if the price is at most `max_price_age` seconds older than the
+/// reference block of the consuming transaction. Prices with a zero timestamp, i.e. prices which
+/// were never set by the oracle, are always rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OraclePriceBounds {
+    oracle_id: AccountId,
+    price_proc_root: Digest,
+    price_key: Word,
+    min_price: Felt,
+    max_price: Felt,
+    max_price_age: u32,
+}
+
+impl OraclePriceBounds {
+    /// Returns new [OraclePriceBounds] reading the price of the provided pair from the
+    /// [`StandardPriceOracle`] component of the oracle account.
+    pub fn new(
+        oracle_id: AccountId,
+        pair: PricePair,
+        min_price: Felt,
+        max_price: Felt,
+        max_price_age: u32,
+    ) -> Self {
+        Self {
+            oracle_id,
+            price_proc_root: StandardPriceOracle::read_price_root(),
+            price_key: pair.into(),
+            min_price,
+            max_price,
+            max_price_age,
+        }
+    }
+
+    /// Sets the root of the oracle procedure which returns the price.
+    ///
+    /// This allows using oracle accounts which do not use the [`StandardPriceOracle`] component.
+    pub fn with_price_procedure(mut self, price_proc_root: Digest) -> Self {
+        self.price_proc_root = price_proc_root;
+        self
+    }
+
+    /// Returns the ID of the oracle account.
+    pub fn oracle_id(&self) -> AccountId {
+        self.oracle_id
+    }
+
+    /// Returns the root of the oracle procedure which returns the price.
+    pub fn price_proc_root(&self) -> Digest {
+        self.price_proc_root
+    }
+
+    /// Returns the key identifying the price.
+    pub fn price_key(&self) -> Word {
+        self.price_key
+    }
+
+    /// Returns the minimum price at which the note can be consumed.
+    pub fn min_price(&self) -> Felt {
+        self.min_price
+    }
+
+    /// Returns the maximum price at which the note can be consumed.
+    pub fn max_price(&self) -> Felt {
+        self.max_price
+    }
+
+    /// Returns the maximum number of seconds by which the timestamp of the price may precede the
+    /// timestamp of the reference block of the consuming transaction.
+    pub fn max_price_age(&self) -> u32 {
+        self.max_price_age
+    }
+
+    /// Returns the note inputs encoding these bounds, which follow the common SWAP inputs.
+    ///
+    /// The returned inputs are `[oracle_id_suffix, oracle_id_prefix, ORACLE_PROC_ROOT, PRICE_KEY,
+    /// min_price, max_price, max_price_age]`.
+    pub(crate) fn to_inputs(&self) -> Vec<Felt> {
+        let mut inputs = vec![self.oracle_id.suffix(), self.oracle_id.prefix().as_felt()];
+        inputs.extend_from_slice(self.price_proc_root.as_elements());
+        inputs.extend_from_slice(&self.price_key);
+        inputs.extend_from_slice(&[self.min_price, self.max_price, self.max_price_age.into()]);
+        inputs
+    }
+}
@@ -0,0 +1,105 @@
+use core::ops::Range;
+
+use miden_objects::{Digest, Hasher, ONE, Word, ZERO, account::AccountId};
+
+use super::utils::derive_serial_num;
+
+// SERIAL NUMBER DERIVER
+// ================================================================================================
+
+/// Derives note serial numbers deterministically from a secret.
+///
+/// Derivation is hierarchical: a deriver instantiated from a wallet's master secret can derive a
+/// child deriver per account via [`Self::for_account`], whose secret is computed as
+/// `hash(SECRET || [account_id_suffix, account_id_prefix, 0, 1])`. The serial number at a given
+/// index is then computed as `hash(SECRET || [index, 0, 0, 0])` (see
+/// [`derive_serial_num`](super::utils::derive_serial_num)). The last element of the hashed word
+/// separates both derivation steps, so a child secret never coincides with a serial number.
+///
+/// Since the serial numbers of the notes created by an account only depend on the secret and the
+/// index, a wallet does not have to persist them: it can recover all its notes from the secret by
+/// re-deriving the serial numbers for increasing indices (see [`Self::serial_nums`]) and looking up
+/// the resulting notes.
+///
+/// The secret must not be shared, as knowledge of a serial number allows computing the nullifier
+/// of the corresponding note.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SerialNumberDeriver {
+    secret: Word,
+}
+
+impl SerialNumberDeriver {
+    /// Returns a new [SerialNumberDeriver] instantiated from the provided secret.
+    pub fn new(secret: Word) -> Self {
+        Self { secret }
+    }
+
+    /// Returns the deriver for the serial numbers of notes created by the specified account.
+    pub fn for_account(&self, account_id: AccountId) -> Self {
+        let account_word =
+            Digest::from([account_id.suffix(), account_id.prefix().as_felt(), ZERO, ONE]);
+        let secret = Hasher::merge(&[Digest::from(self.secret), account_word]);
+
+        Self { secret: secret.into() }
+    }
+
+    /// Returns the serial number at the specified index.
+    pub fn serial_num(&self, index: u32) -> Word {
+        derive_serial_num(self.secret, index)
+    }
+
+    /// Returns an iterator over the serial numbers in the specified range of indices, together with
+    /// their indices.
+    pub fn serial_nums(&self, indices: Range<u32>) -> impl Iterator<Item = (u32, Word)> + '_ {
+        indices.map(|index| (index, self.serial_num(index)))
+    }
+
+    /// Returns the index of the provided serial number, if it is one of the serial numbers in the
+    /// specified range of indices.
+    pub fn find_index(&self, serial_num: Word, indices: Range<u32>) -> Option<u32> {
+        self.serial_nums(indices)
+            .find_map(|(index, candidate)| (candidate == serial_num).then_some(index))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_objects::{
+        Felt,
+        testing::account_id::{
+            ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
+            ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE_2,
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn serial_number_derivation() {
+        let account_1 =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap();
+        let account_2 =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE_2).unwrap();
+        let master = SerialNumberDeriver::new([ONE, Felt::new(2), Felt::new(3), Felt::new(4)]);
+
+        let deriver_1 = master.for_account(account_1);
+        let deriver_2 = master.for_account(account_2);
+
+        // derivation is deterministic
+        assert_eq!(deriver_1.serial_num(5), master.for_account(account_1).serial_num(5));
+
+        // serial numbers differ across indices and accounts
+        assert_ne!(deriver_1.serial_num(0), deriver_1.serial_num(1));
+        assert_ne!(deriver_1.serial_num(0), deriver_2.serial_num(0));
+        assert_ne!(deriver_1.serial_num(0), master.serial_num(0));
+
+        // serial numbers can be recovered
+        let serial_num = deriver_1.serial_num(42);
+        assert_eq!(deriver_1.find_index(serial_num, 0..100), Some(42));
+        assert_eq!(deriver_1.find_index(serial_num, 0..42), None);
+        assert_eq!(deriver_2.find_index(serial_num, 0..100), None);
+    }
+}
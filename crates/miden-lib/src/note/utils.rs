@@ -1,11 +1,11 @@
 use miden_objects::{
-    NoteError, Word,
+    Digest, Felt, Hasher, NoteError, Word, ZERO,
     account::AccountId,
     asset::Asset,
     note::{NoteExecutionMode, NoteInputs, NoteRecipient, NoteTag, NoteType},
 };
 
-use super::well_known_note::WellKnownNote;
+use super::{memo::PaymentMemo, well_known_note::WellKnownNote};
 
 /// Creates a [NoteRecipient] for the P2ID note.
 ///
@@ -21,6 +21,30 @@ pub fn build_p2id_recipient(
     Ok(NoteRecipient::new(serial_num, note_script, note_inputs))
 }
 
+/// Creates a [NoteRecipient] for the P2IDM note carrying a commitment to the provided memo.
+///
+/// Notes created with this recipient will be P2IDM notes consumable by the specified target
+/// account. See [PaymentMemo] for details on how the memo is committed to.
+pub fn build_p2idm_recipient(
+    target: AccountId,
+    memo: &PaymentMemo,
+    serial_num: Word,
+) -> Result<NoteRecipient, NoteError> {
+    let note_script = WellKnownNote::P2IDM.script();
+    let note_inputs = memo.to_p2idm_inputs(target)?;
+
+    Ok(NoteRecipient::new(serial_num, note_script, note_inputs))
+}
+
+/// Derives the serial number of the note at the specified index from the provided seed.
+///
+/// The serial number is computed as `hash(SEED || [index, 0, 0, 0])`, which allows creating many
+/// notes with distinct serial numbers from a single seed, and recomputing them later on.
+pub fn derive_serial_num(seed: Word, index: u32) -> Word {
+    let index = Digest::from([Felt::from(index), ZERO, ZERO, ZERO]);
+    Hasher::merge(&[Digest::from(seed), index]).into()
+}
+
 /// Returns a note tag for a swap note with the specified parameters.
 ///
 /// Use case ID for the returned tag is set to 0.
@@ -1,3 +1,5 @@
+use alloc::collections::BTreeMap;
+
 use miden_objects::{
     Digest,
     assembly::{ProcedureName, QualifiedProcedureName},
@@ -21,6 +23,13 @@ static P2ID_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
     NoteScript::new(program)
 });
 
+// Initialize the P2IDM note script only once
+static P2IDM_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2IDM.masb"));
+    let program = Program::read_from_bytes(bytes).expect("Shipped P2IDM script is well-formed");
+    NoteScript::new(program)
+});
+
 // Initialize the P2IDR note script only once
 static P2IDR_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
     let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2IDR.masb"));
@@ -35,6 +44,17 @@ static SWAP_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
     NoteScript::new(program)
 });
 
+// Initialize the PSWAP note script only once
+static PSWAP_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/PSWAP.masb"));
+    let program = Program::read_from_bytes(bytes).expect("Shipped PSWAP script is well-formed");
+    NoteScript::new(program)
+});
+
+// Initialize the registry of well-known note scripts only once
+static WELL_KNOWN_NOTES: LazyLock<BTreeMap<Digest, WellKnownNote>> =
+    LazyLock::new(|| WellKnownNote::ALL.iter().map(|note| (note.script_root(), *note)).collect());
+
 /// Returns the P2ID (Pay-to-ID) note script.
 fn p2id() -> NoteScript {
     P2ID_SCRIPT.clone()
@@ -45,6 +65,16 @@ fn p2id_root() -> Digest {
     P2ID_SCRIPT.root()
 }
 
+/// Returns the P2IDM (Pay-to-ID with memo) note script.
+fn p2idm() -> NoteScript {
+    P2IDM_SCRIPT.clone()
+}
+
+/// Returns the P2IDM (Pay-to-ID with memo) note script root.
+fn p2idm_root() -> Digest {
+    P2IDM_SCRIPT.root()
+}
+
 /// Returns the P2IDR (Pay-to-ID with recall) note script.
 fn p2idr() -> NoteScript {
     P2IDR_SCRIPT.clone()
@@ -65,41 +95,53 @@ fn swap_root() -> Digest {
     SWAP_SCRIPT.root()
 }
 
+/// Returns the PSWAP (Price-aware swap note) note script.
+fn pswap() -> NoteScript {
+    PSWAP_SCRIPT.clone()
+}
+
+/// Returns the PSWAP (Price-aware swap note) note script root.
+fn pswap_root() -> Digest {
+    PSWAP_SCRIPT.root()
+}
+
 // WELL KNOWN NOTE
 // ================================================================================================
 
 /// The enum holding the types of basic well-known notes provided by the `miden-lib`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WellKnownNote {
     P2ID,
+    P2IDM,
     P2IDR,
     SWAP,
+    PSWAP,
 }
 
 impl WellKnownNote {
+    /// All well-known notes provided by the `miden-lib`.
+    pub const ALL: [Self; 5] = [Self::P2ID, Self::P2IDM, Self::P2IDR, Self::SWAP, Self::PSWAP];
+
+    /// Returns the [WellKnownNote] whose note script has the provided MAST root. Returns `None` if
+    /// the root does not belong to a basic well-known note.
+    pub fn identify(script_root: Digest) -> Option<Self> {
+        WELL_KNOWN_NOTES.get(&script_root).copied()
+    }
+
     /// Returns a [WellKnownNote] instance based on the note script of the provided [Note]. Returns
     /// `None` if the provided note is not a basic well-known note.
     pub fn from_note(note: &Note) -> Option<Self> {
-        let note_script_root = note.script().root();
-
-        if note_script_root == p2id_root() {
-            return Some(Self::P2ID);
-        }
-        if note_script_root == p2idr_root() {
-            return Some(Self::P2IDR);
-        }
-        if note_script_root == swap_root() {
-            return Some(Self::SWAP);
-        }
-
-        None
+        Self::identify(note.script().root())
     }
 
     /// Returns the note script of the current [WellKnownNote] instance.
     pub fn script(&self) -> NoteScript {
         match self {
             Self::P2ID => p2id(),
+            Self::P2IDM => p2idm(),
             Self::P2IDR => p2idr(),
             Self::SWAP => swap(),
+            Self::PSWAP => pswap(),
         }
     }
 
@@ -107,8 +149,10 @@ impl WellKnownNote {
     pub fn script_root(&self) -> Digest {
         match self {
             Self::P2ID => p2id_root(),
+            Self::P2IDM => p2idm_root(),
             Self::P2IDR => p2idr_root(),
             Self::SWAP => swap_root(),
+            Self::PSWAP => pswap_root(),
         }
     }
 
@@ -121,10 +165,10 @@ impl WellKnownNote {
 
         let interface_proc_digests = account_interface.get_procedure_digests();
         match self {
-            Self::P2ID | &Self::P2IDR => {
+            Self::P2ID | Self::P2IDM | &Self::P2IDR => {
                 // Get the hash of the "receive_asset" procedure and check that this procedure is
-                // presented in the provided account interfaces. P2ID and P2IDR notes requires only
-                // this procedure to be consumed by the account.
+                // presented in the provided account interfaces. P2ID, P2IDM and P2IDR notes
+                // requires only this procedure to be consumed by the account.
                 let receive_asset_proc_name = QualifiedProcedureName::new(
                     Default::default(),
                     ProcedureName::new("receive_asset").unwrap(),
@@ -134,10 +178,10 @@ impl WellKnownNote {
 
                 interface_proc_digests.contains(&receive_asset_digest)
             },
-            Self::SWAP => {
+            Self::SWAP | Self::PSWAP => {
                 // Make sure that all procedures from the basic wallet library are presented in the
-                // provided account interfaces. SWAP note requires the whole basic wallet interface
-                // to be consumed by the account.
+                // provided account interfaces. SWAP and PSWAP notes require the whole basic wallet
+                // interface to be consumed by the account.
                 basic_wallet_library()
                     .mast_forest()
                     .procedure_digests()
@@ -146,3 +190,20 @@ impl WellKnownNote {
         }
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identify_well_known_notes() {
+        for note in WellKnownNote::ALL {
+            assert_eq!(WellKnownNote::identify(note.script_root()), Some(note));
+        }
+
+        assert_eq!(WellKnownNote::identify(Digest::default()), None);
+    }
+}
@@ -1,9 +1,16 @@
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{boxed::Box, string::String, vec::Vec};
 use core::error::Error;
 
-use miden_objects::{AccountDeltaError, AssetError, Digest, Felt, NoteError, note::NoteMetadata};
+use miden_objects::{
+    AccountDeltaError, AssetError, Digest, Felt, NoteError, TransactionScriptError,
+    assembly::{LibraryNamespace, LibraryPath},
+    note::{NoteId, NoteMetadata},
+};
 use thiserror::Error;
 
+use super::request::MAX_ACCOUNT_CALL_INPUTS;
+use crate::account::interface::AccountInterfaceError;
+
 // TRANSACTION KERNEL ERROR
 // ================================================================================================
 
@@ -63,8 +70,20 @@ pub enum TransactionKernelError {
     UnknownAccountProcedure(Digest),
     #[error("code commitment {0} is not in the advice provider")]
     UnknownCodeCommitment(Digest),
+    #[error(
+        "operands of the 256-bit modular multiplication requested by the transaction are not well formed"
+    )]
+    MalformedU256MulModOperands,
+    #[error(
+        "operands of the 384-bit modular arithmetic requested by the transaction are not well formed"
+    )]
+    MalformedU384Operands,
     #[error("account storage slots number is missing in memory at address {0}")]
     AccountStorageSlotsNumMissing(u32),
+    #[error(
+        "transaction kernel version {version} is not supported, the number of kernel versions is {num_versions}"
+    )]
+    UnsupportedKernelVersion { version: u8, num_versions: usize },
 }
 
 // TRANSACTION EVENT PARSING ERROR
@@ -89,6 +108,52 @@ pub enum TransactionTraceParsingError {
     UnknownTransactionTrace(u32),
 }
 
+// TRANSACTION REQUEST ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum TransactionRequestError {
+    #[error("failed to build the transaction script from the account interface")]
+    AccountInterface(#[source] AccountInterfaceError),
+    #[error("input note {0} is added to the transaction request more than once")]
+    DuplicateInputNote(NoteId),
+    #[error("invalid transaction script")]
+    InvalidTransactionScript(#[source] TransactionScriptError),
+    #[error(
+        "call to account procedure `{procedure}` has {num_inputs} inputs but at most {MAX_ACCOUNT_CALL_INPUTS} are allowed"
+    )]
+    TooManyAccountCallInputs { procedure: String, num_inputs: usize },
+}
+
+// LIBRARY LINKER ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum LibraryLinkerError {
+    #[error("module {0} is already provided by a linked library")]
+    ModuleCollision(LibraryPath),
+    #[error("module {module} is not part of namespace {namespace} the library is registered under")]
+    NamespaceMismatch {
+        namespace: LibraryNamespace,
+        module: LibraryPath,
+    },
+    #[error("namespace {0} is reserved for the libraries linked by the transaction kernel")]
+    ReservedNamespace(LibraryNamespace),
+}
+
+// TRANSACTION SCRIPT TEMPLATE ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum TransactionScriptTemplateError {
+    #[error("invalid asset for transaction script template")]
+    InvalidAsset(#[source] AssetError),
+    #[error("failed to build note for transaction script template")]
+    InvalidNote(#[source] NoteError),
+    #[error("invalid transaction script template arguments")]
+    InvalidScriptArgs(#[source] TransactionScriptError),
+}
+
 #[cfg(test)]
 mod error_assertions {
     use super::*;
@@ -1,38 +1,12 @@
 use core::fmt;
 
-use super::{TransactionEventError, TransactionTraceParsingError};
+use miden_objects::{Felt, Word};
 
-// CONSTANTS
-// ================================================================================================
+use super::{KernelEventId, TransactionTraceParsingError};
 
 // TRANSACTION EVENT
 // ================================================================================================
 
-const ACCOUNT_VAULT_BEFORE_ADD_ASSET: u32 = 0x2_0000; // 131072
-const ACCOUNT_VAULT_AFTER_ADD_ASSET: u32 = 0x2_0001; // 131073
-
-const ACCOUNT_VAULT_BEFORE_REMOVE_ASSET: u32 = 0x2_0002; // 131074
-const ACCOUNT_VAULT_AFTER_REMOVE_ASSET: u32 = 0x2_0003; // 131075
-
-const ACCOUNT_STORAGE_BEFORE_SET_ITEM: u32 = 0x2_0004; // 131076
-const ACCOUNT_STORAGE_AFTER_SET_ITEM: u32 = 0x2_0005; // 131077
-
-const ACCOUNT_STORAGE_BEFORE_SET_MAP_ITEM: u32 = 0x2_0006; // 131078
-const ACCOUNT_STORAGE_AFTER_SET_MAP_ITEM: u32 = 0x2_0007; // 131079
-
-const ACCOUNT_BEFORE_INCREMENT_NONCE: u32 = 0x2_0008; // 131080
-const ACCOUNT_AFTER_INCREMENT_NONCE: u32 = 0x2_0009; // 131081
-
-const ACCOUNT_PUSH_PROCEDURE_INDEX: u32 = 0x2_000a; // 131082
-
-const NOTE_BEFORE_CREATED: u32 = 0x2_000b; // 131083
-const NOTE_AFTER_CREATED: u32 = 0x2_000c; // 131084
-
-const NOTE_BEFORE_ADD_ASSET: u32 = 0x2_000d; // 131085
-const NOTE_AFTER_ADD_ASSET: u32 = 0x2_000e; // 131086
-
-const FALCON_SIG_TO_STACK: u32 = 0x2_000f; // 131087
-
 /// Events which may be emitted by a transaction kernel.
 ///
 /// The events are emitted via the `emit.<event_id>` instruction. The event ID is a 32-bit
@@ -40,107 +14,103 @@ const FALCON_SIG_TO_STACK: u32 = 0x2_000f; // 131087
 /// transaction kernel, the event_id is structured as follows:
 /// - The upper 16 bits of the event ID are set to 2.
 /// - The lower 16 bits represent a unique event ID within the transaction kernel.
-#[repr(u32)]
-#[derive(Debug, Clone, Eq, PartialEq)]
-pub enum TransactionEvent {
-    AccountVaultBeforeAddAsset = ACCOUNT_VAULT_BEFORE_ADD_ASSET,
-    AccountVaultAfterAddAsset = ACCOUNT_VAULT_AFTER_ADD_ASSET,
-
-    AccountVaultBeforeRemoveAsset = ACCOUNT_VAULT_BEFORE_REMOVE_ASSET,
-    AccountVaultAfterRemoveAsset = ACCOUNT_VAULT_AFTER_REMOVE_ASSET,
-
-    AccountStorageBeforeSetItem = ACCOUNT_STORAGE_BEFORE_SET_ITEM,
-    AccountStorageAfterSetItem = ACCOUNT_STORAGE_AFTER_SET_ITEM,
-
-    AccountStorageBeforeSetMapItem = ACCOUNT_STORAGE_BEFORE_SET_MAP_ITEM,
-    AccountStorageAfterSetMapItem = ACCOUNT_STORAGE_AFTER_SET_MAP_ITEM,
-
-    AccountBeforeIncrementNonce = ACCOUNT_BEFORE_INCREMENT_NONCE,
-    AccountAfterIncrementNonce = ACCOUNT_AFTER_INCREMENT_NONCE,
-
-    AccountPushProcedureIndex = ACCOUNT_PUSH_PROCEDURE_INDEX,
-
-    NoteBeforeCreated = NOTE_BEFORE_CREATED,
-    NoteAfterCreated = NOTE_AFTER_CREATED,
-
-    NoteBeforeAddAsset = NOTE_BEFORE_ADD_ASSET,
-    NoteAfterAddAsset = NOTE_AFTER_ADD_ASSET,
+///
+/// This is an alias of the [KernelEventId] enum generated from the MASM sources, so the event IDs
+/// are defined in a single place.
+pub type TransactionEvent = KernelEventId;
 
-    FalconSigToStack = FALCON_SIG_TO_STACK,
-}
+// KERNEL EVENT ID
+// ================================================================================================
 
-impl TransactionEvent {
+impl KernelEventId {
     /// Value of the top 16 bits of a transaction kernel event ID.
     pub const ID_PREFIX: u32 = 2;
-}
 
-impl fmt::Display for TransactionEvent {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{self:?}")
+    /// Returns the ID of the event, i.e. the value passed to the `emit` instruction.
+    pub const fn event_id(&self) -> u32 {
+        *self as u32
     }
-}
-
-impl TryFrom<u32> for TransactionEvent {
-    type Error = TransactionEventError;
 
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        if value >> 16 != TransactionEvent::ID_PREFIX {
-            return Err(TransactionEventError::NotTransactionEvent(value));
+    /// Returns the position of the specified item on the operand stack at the time the event is
+    /// emitted, or `None` if the item is not part of the [stack layout](Self::stack_layout) of the
+    /// event.
+    ///
+    /// For a word, the position of its last element (which is on top of the stack) is returned.
+    pub fn stack_position(&self, item: &str) -> Option<usize> {
+        let mut position = 0;
+        for layout_item in self.stack_layout() {
+            if *layout_item == item {
+                return Some(position);
+            }
+            position += if is_word_item(layout_item) { 4 } else { 1 };
         }
 
-        match value {
-            ACCOUNT_VAULT_BEFORE_ADD_ASSET => Ok(TransactionEvent::AccountVaultBeforeAddAsset),
-            ACCOUNT_VAULT_AFTER_ADD_ASSET => Ok(TransactionEvent::AccountVaultAfterAddAsset),
-
-            ACCOUNT_VAULT_BEFORE_REMOVE_ASSET => {
-                Ok(TransactionEvent::AccountVaultBeforeRemoveAsset)
-            },
-            ACCOUNT_VAULT_AFTER_REMOVE_ASSET => Ok(TransactionEvent::AccountVaultAfterRemoveAsset),
-
-            ACCOUNT_STORAGE_BEFORE_SET_ITEM => Ok(TransactionEvent::AccountStorageBeforeSetItem),
-            ACCOUNT_STORAGE_AFTER_SET_ITEM => Ok(TransactionEvent::AccountStorageAfterSetItem),
-
-            ACCOUNT_STORAGE_BEFORE_SET_MAP_ITEM => {
-                Ok(TransactionEvent::AccountStorageBeforeSetMapItem)
-            },
-            ACCOUNT_STORAGE_AFTER_SET_MAP_ITEM => {
-                Ok(TransactionEvent::AccountStorageAfterSetMapItem)
-            },
+        None
+    }
 
-            ACCOUNT_BEFORE_INCREMENT_NONCE => Ok(TransactionEvent::AccountBeforeIncrementNonce),
-            ACCOUNT_AFTER_INCREMENT_NONCE => Ok(TransactionEvent::AccountAfterIncrementNonce),
+    /// Reads the specified single element item from the provided operand stack, where the first
+    /// element of `stack` is the top of the stack.
+    ///
+    /// Returns `None` if the item is not a single element of the stack layout of the event or if
+    /// the stack is too short.
+    pub fn read_felt(&self, stack: &[Felt], item: &str) -> Option<Felt> {
+        if is_word_item(item) {
+            return None;
+        }
 
-            ACCOUNT_PUSH_PROCEDURE_INDEX => Ok(TransactionEvent::AccountPushProcedureIndex),
+        stack.get(self.stack_position(item)?).copied()
+    }
 
-            NOTE_BEFORE_CREATED => Ok(TransactionEvent::NoteBeforeCreated),
-            NOTE_AFTER_CREATED => Ok(TransactionEvent::NoteAfterCreated),
+    /// Reads the specified word item from the provided operand stack, where the first element of
+    /// `stack` is the top of the stack.
+    ///
+    /// The word is returned in the same layout as if it was loaded from memory, i.e. the element
+    /// on top of the stack becomes the last element of the word.
+    ///
+    /// Returns `None` if the item is not a word of the stack layout of the event or if the stack is
+    /// too short.
+    pub fn read_word(&self, stack: &[Felt], item: &str) -> Option<Word> {
+        if !is_word_item(item) {
+            return None;
+        }
 
-            NOTE_BEFORE_ADD_ASSET => Ok(TransactionEvent::NoteBeforeAddAsset),
-            NOTE_AFTER_ADD_ASSET => Ok(TransactionEvent::NoteAfterAddAsset),
+        let position = self.stack_position(item)?;
+        let elements = stack.get(position..position + 4)?;
 
-            FALCON_SIG_TO_STACK => Ok(TransactionEvent::FalconSigToStack),
+        Some([elements[3], elements[2], elements[1], elements[0]])
+    }
+}
 
-            _ => Err(TransactionEventError::InvalidTransactionEvent(value)),
-        }
+impl fmt::Display for KernelEventId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
     }
 }
 
+/// Returns `true` if the item of a stack layout denotes a word, i.e. if its name is uppercase.
+fn is_word_item(item: &str) -> bool {
+    item.chars().any(|c| c.is_ascii_uppercase()) && !item.chars().any(|c| c.is_ascii_lowercase())
+}
+
 // TRANSACTION TRACE
 // ================================================================================================
 
 #[repr(u32)]
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum TransactionTrace {
-    PrologueStart = 0x2_0000,           // 131072
-    PrologueEnd = 0x2_0001,             // 131073
-    NotesProcessingStart = 0x2_0002,    // 131074
-    NotesProcessingEnd = 0x2_0003,      // 131075
-    NoteExecutionStart = 0x2_0004,      // 131076
-    NoteExecutionEnd = 0x2_0005,        // 131077
-    TxScriptProcessingStart = 0x2_0006, // 131078
-    TxScriptProcessingEnd = 0x2_0007,   // 131079
-    EpilogueStart = 0x2_0008,           // 131080
-    EpilogueEnd = 0x2_0009,             // 131081
+    PrologueStart = 0x2_0000,                     // 131072
+    PrologueEnd = 0x2_0001,                       // 131073
+    NotesProcessingStart = 0x2_0002,              // 131074
+    NotesProcessingEnd = 0x2_0003,                // 131075
+    NoteExecutionStart = 0x2_0004,                // 131076
+    NoteExecutionEnd = 0x2_0005,                  // 131077
+    TxScriptProcessingStart = 0x2_0006,           // 131078
+    TxScriptProcessingEnd = 0x2_0007,             // 131079
+    EpilogueStart = 0x2_0008,                     // 131080
+    EpilogueEnd = 0x2_0009,                       // 131081
+    AccountCommitmentCacheHit = 0x2_000a,         // 131082
+    AccountCommitmentComputationStart = 0x2_000b, // 131083
+    AccountCommitmentComputationEnd = 0x2_000c,   // 131084
 }
 
 impl fmt::Display for TransactionTrace {
@@ -168,7 +138,52 @@ impl TryFrom<u32> for TransactionTrace {
             0x2_0007 => Ok(TransactionTrace::TxScriptProcessingEnd),
             0x2_0008 => Ok(TransactionTrace::EpilogueStart),
             0x2_0009 => Ok(TransactionTrace::EpilogueEnd),
+            0x2_000a => Ok(TransactionTrace::AccountCommitmentCacheHit),
+            0x2_000b => Ok(TransactionTrace::AccountCommitmentComputationStart),
+            0x2_000c => Ok(TransactionTrace::AccountCommitmentComputationEnd),
             _ => Err(TransactionTraceParsingError::UnknownTransactionTrace(value)),
         }
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use miden_objects::ZERO;
+
+    use super::*;
+
+    #[test]
+    fn kernel_event_ids_round_trip() {
+        for event_id in KernelEventId::ALL {
+            assert_eq!(event_id.event_id() >> 16, KernelEventId::ID_PREFIX);
+            assert_eq!(KernelEventId::try_from(event_id.event_id()).unwrap(), event_id);
+        }
+
+        assert!(KernelEventId::try_from(0x2_ffff).is_err());
+        assert!(KernelEventId::try_from(0x3_0000).is_err());
+    }
+
+    #[test]
+    fn kernel_event_id_stack_decoding() {
+        let event_id = KernelEventId::AccountStorageAfterSetItem;
+        let stack: Vec<Felt> = (0..12u32).map(Felt::from).collect();
+
+        assert_eq!(event_id.stack_position("slot_index"), Some(0));
+        assert_eq!(event_id.stack_position("NEW_VALUE"), Some(1));
+        assert_eq!(event_id.stack_position("OLD_VALUE"), Some(5));
+        assert_eq!(event_id.stack_position("ASSET"), None);
+
+        assert_eq!(event_id.read_felt(&stack, "slot_index"), Some(ZERO));
+        assert_eq!(event_id.read_felt(&stack, "NEW_VALUE"), None);
+        assert_eq!(
+            event_id.read_word(&stack, "OLD_VALUE"),
+            Some([Felt::from(8u8), Felt::from(7u8), Felt::from(6u8), Felt::from(5u8)])
+        );
+        assert_eq!(event_id.read_word(&stack[..8], "OLD_VALUE"), None);
+    }
+}
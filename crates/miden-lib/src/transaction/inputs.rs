@@ -1,10 +1,15 @@
-use alloc::vec::Vec;
+use alloc::{collections::BTreeSet, vec::Vec};
 
 use miden_objects::{
     Digest, EMPTY_WORD, Felt, FieldElement, WORD_SIZE, Word, ZERO,
-    account::{Account, StorageSlot},
-    transaction::{ChainMmr, InputNote, TransactionArgs, TransactionInputs, TransactionScript},
-    vm::AdviceInputs,
+    account::{Account, AccountCode, AccountHeader, AccountStorageHeader, StorageSlot},
+    block::BlockHeader,
+    transaction::{
+        ChainMmr, InputNote, InputNotes, RedactedTransactionWitness, TransactionArgs,
+        TransactionInputs,
+    },
+    utils::{Deserializable, Serializable, SliceReader},
+    vm::{AdviceInputs, AdviceMap},
 };
 
 use super::TransactionKernel;
@@ -22,18 +27,155 @@ pub(super) fn extend_advice_inputs(
     tx_inputs: &TransactionInputs,
     tx_args: &TransactionArgs,
     advice_inputs: &mut AdviceInputs,
+    kernel_version: u8,
 ) {
-    // TODO: remove this value and use a user input instead
-    let kernel_version = 0;
+    build_advice_stack(
+        tx_inputs.block_header(),
+        &AccountHeader::from(tx_inputs.account()),
+        tx_inputs.input_notes(),
+        tx_args,
+        advice_inputs,
+        kernel_version,
+    );
+    add_kernel_advice_data(tx_inputs, tx_args, advice_inputs, kernel_version);
+    advice_inputs.extend(tx_args.advice_inputs().clone());
+}
 
-    build_advice_stack(tx_inputs, tx_args.tx_script(), advice_inputs, kernel_version);
+/// Extends the provided advice inputs with the data required for proving a transaction from the
+/// specified redacted witness.
+///
+/// Unlike [`extend_advice_inputs`], this does not add the assets of the account vault and the
+/// entries of the account storage maps, which are provided by the advice witness instead.
+pub(super) fn extend_redacted_advice_inputs(
+    tx_witness: &RedactedTransactionWitness,
+    advice_inputs: &mut AdviceInputs,
+    kernel_version: u8,
+) {
+    build_advice_stack(
+        &tx_witness.block_header,
+        &tx_witness.account,
+        &tx_witness.input_notes,
+        &tx_witness.tx_args,
+        advice_inputs,
+        kernel_version,
+    );
+    add_kernel_commitments_to_advice_inputs(advice_inputs, kernel_version);
+    add_chain_mmr_to_advice_inputs(&tx_witness.block_chain, advice_inputs);
+    add_block_headers_to_advice_inputs(
+        &tx_witness.block_header,
+        &tx_witness.block_chain,
+        advice_inputs,
+    );
+    add_account_header_to_advice_inputs(
+        &tx_witness.account,
+        &tx_witness.storage_header,
+        &tx_witness.account_code,
+        None,
+        advice_inputs,
+    );
+    add_input_notes_to_advice_inputs(
+        &tx_witness.block_header,
+        &tx_witness.block_chain,
+        &tx_witness.input_notes,
+        &tx_witness.tx_args,
+        advice_inputs,
+    );
+    advice_inputs.extend(tx_witness.tx_args.advice_inputs().clone());
+}
 
-    // build the advice map and Merkle store for relevant components
+/// Returns the key of an entry in the advice map of the provided transaction args which has
+/// different values than the entry the transaction kernel adds under the same key, if any.
+pub(super) fn find_advice_map_collision(
+    tx_inputs: &TransactionInputs,
+    tx_args: &TransactionArgs,
+    kernel_version: u8,
+) -> Option<Digest> {
+    let mut kernel_inputs = AdviceInputs::default();
+    add_kernel_advice_data(tx_inputs, tx_args, &mut kernel_inputs, kernel_version);
+
+    advice_map_entries(&kernel_inputs).into_iter().find_map(|(key, kernel_values)| {
+        match tx_args.advice_inputs().mapped_values(&key) {
+            Some(values) if values != kernel_values.as_slice() => Some(key),
+            _ => None,
+        }
+    })
+}
+
+/// Returns the provided advice witness without the advice map entries and Merkle store nodes which
+/// are added to the advice inputs for the specified transaction inputs and args by
+/// [`extend_advice_inputs`].
+///
+/// Since [`TransactionKernel::prepare_inputs`] extends the advice witness with these inputs, any
+/// map entry under the same key is replaced and any such node is re-inserted, so removing them does
+/// not change the advice inputs the transaction is executed with.
+pub(super) fn minimize_advice_witness(
+    tx_inputs: &TransactionInputs,
+    tx_args: &TransactionArgs,
+    advice_witness: AdviceInputs,
+) -> AdviceInputs {
+    let mut prepared_inputs = AdviceInputs::default();
+    extend_advice_inputs(
+        tx_inputs,
+        tx_args,
+        &mut prepared_inputs,
+        TransactionKernel::LATEST_VERSION,
+    );
+
+    let prepared_nodes: BTreeSet<Digest> =
+        prepared_inputs.merkle_store().inner_nodes().map(|node| node.value).collect();
+
+    let map_entries = advice_map_entries(&advice_witness)
+        .into_iter()
+        .filter(|(key, _)| prepared_inputs.mapped_values(key).is_none());
+    let store_nodes = advice_witness
+        .merkle_store()
+        .inner_nodes()
+        .filter(|node| !prepared_nodes.contains(&node.value));
+
+    let mut minimized = AdviceInputs::default().with_stack(advice_witness.stack().iter().copied());
+    minimized.extend_map(map_entries);
+    minimized.extend_merkle_store(store_nodes);
+
+    minimized
+}
+
+/// Returns the entries of the advice map of the provided advice inputs.
+///
+/// [AdviceInputs] only allows looking up the values of a known key, so the advice map is read back
+/// from the serialized advice inputs, which consist of the advice stack, the advice map and the
+/// Merkle store, in this order.
+fn advice_map_entries(advice_inputs: &AdviceInputs) -> AdviceMap {
+    let bytes = advice_inputs.to_bytes();
+    let mut reader = SliceReader::new(&bytes);
+
+    Vec::<Felt>::read_from(&mut reader)
+        .and_then(|_| AdviceMap::read_from(&mut reader))
+        .expect("serialized advice inputs should contain a valid advice map")
+}
+
+/// Builds the advice map and Merkle store for the kernel commitments, the chain MMR, the block
+/// headers, the account and the input notes.
+fn add_kernel_advice_data(
+    tx_inputs: &TransactionInputs,
+    tx_args: &TransactionArgs,
+    advice_inputs: &mut AdviceInputs,
+    kernel_version: u8,
+) {
     add_kernel_commitments_to_advice_inputs(advice_inputs, kernel_version);
     add_chain_mmr_to_advice_inputs(tx_inputs.block_chain(), advice_inputs);
+    add_block_headers_to_advice_inputs(
+        tx_inputs.block_header(),
+        tx_inputs.block_chain(),
+        advice_inputs,
+    );
     add_account_to_advice_inputs(tx_inputs.account(), tx_inputs.account_seed(), advice_inputs);
-    add_input_notes_to_advice_inputs(tx_inputs, tx_args, advice_inputs);
-    advice_inputs.extend(tx_args.advice_inputs().clone());
+    add_input_notes_to_advice_inputs(
+        tx_inputs.block_header(),
+        tx_inputs.block_chain(),
+        tx_inputs.input_notes(),
+        tx_args,
+        advice_inputs,
+    );
 }
 
 // ADVICE STACK BUILDER
@@ -60,31 +202,19 @@ pub(super) fn extend_advice_inputs(
 ///     ACCOUNT_CODE_COMMITMENT,
 ///     number_of_input_notes,
 ///     TX_SCRIPT_ROOT,
+///     expiration_delta,
 /// ]
 fn build_advice_stack(
-    tx_inputs: &TransactionInputs,
-    tx_script: Option<&TransactionScript>,
+    block_header: &BlockHeader,
+    account: &AccountHeader,
+    input_notes: &InputNotes<InputNote>,
+    tx_args: &TransactionArgs,
     inputs: &mut AdviceInputs,
     kernel_version: u8,
 ) {
-    let header = tx_inputs.block_header();
-
     // push block header info into the stack
     // Note: keep in sync with the process_block_data kernel procedure
-    inputs.extend_stack(header.prev_block_commitment());
-    inputs.extend_stack(header.chain_commitment());
-    inputs.extend_stack(header.account_root());
-    inputs.extend_stack(header.nullifier_root());
-    inputs.extend_stack(header.tx_commitment());
-    inputs.extend_stack(header.tx_kernel_commitment());
-    inputs.extend_stack(header.proof_commitment());
-    inputs.extend_stack([
-        header.block_num().into(),
-        header.version().into(),
-        header.timestamp().into(),
-        ZERO,
-    ]);
-    inputs.extend_stack(header.note_root());
+    inputs.extend_stack(block_header_elements(block_header));
 
     // push the version of the kernel which will be used for this transaction
     // Note: keep in sync with the process_kernel_data kernel procedure
@@ -92,22 +222,25 @@ fn build_advice_stack(
 
     // push core account items onto the stack
     // Note: keep in sync with the process_account_data kernel procedure
-    let account = tx_inputs.account();
     inputs.extend_stack([
         account.id().suffix(),
         account.id().prefix().as_felt(),
         ZERO,
         account.nonce(),
     ]);
-    inputs.extend_stack(account.vault().root());
-    inputs.extend_stack(account.storage().commitment());
-    inputs.extend_stack(account.code().commitment());
+    inputs.extend_stack(account.vault_root());
+    inputs.extend_stack(account.storage_commitment());
+    inputs.extend_stack(account.code_commitment());
 
     // push the number of input notes onto the stack
-    inputs.extend_stack([Felt::from(tx_inputs.input_notes().num_notes() as u32)]);
+    inputs.extend_stack([Felt::from(input_notes.num_notes() as u32)]);
 
     // push tx_script root onto the stack
-    inputs.extend_stack(tx_script.map_or(Word::default(), |script| *script.root()));
+    inputs.extend_stack(tx_args.tx_script().map_or(Word::default(), |script| *script.root()));
+
+    // push the requested expiration delta onto the stack, where 0 means no delta was requested
+    // Note: keep in sync with the process_tx_expiration_delta kernel procedure
+    inputs.extend_stack([Felt::from(tx_args.expiration_delta().unwrap_or(0))]);
 }
 
 // CHAIN MMR INJECTOR
@@ -139,6 +272,51 @@ fn add_chain_mmr_to_advice_inputs(mmr: &ChainMmr, inputs: &mut AdviceInputs) {
     inputs.extend_map([(peaks.hash_peaks(), elements)]);
 }
 
+// BLOCK HEADERS INJECTOR
+// ------------------------------------------------------------------------------------------------
+
+/// Inserts the headers of the transaction reference block and of all blocks tracked by the chain
+/// MMR into the provided advice inputs.
+///
+/// Inserts the following entries into the advice map:
+///
+/// > {BLOCK_COMMITMENT: [PREV_BLOCK_COMMITMENT, CHAIN_COMMITMENT, ACCOUNT_ROOT, NULLIFIER_ROOT,
+/// > TX_COMMITMENT, TX_KERNEL_COMMITMENT, PROOF_COMMITMENT, [block_num, version, timestamp, 0],
+/// > NOTE_ROOT]}
+///
+/// This allows the transaction to read the header of a past block after authenticating its
+/// commitment against the chain MMR.
+fn add_block_headers_to_advice_inputs(
+    block_header: &BlockHeader,
+    block_chain: &ChainMmr,
+    inputs: &mut AdviceInputs,
+) {
+    // NOTE: keep this code in sync with the `get_historical_block_header` procedure
+    let headers = core::iter::once(block_header).chain(block_chain.block_headers());
+    inputs.extend_map(headers.map(|header| (header.commitment(), block_header_elements(header))));
+}
+
+/// Returns the elements of the provided block header in the layout expected by the transaction
+/// kernel.
+fn block_header_elements(header: &BlockHeader) -> Vec<Felt> {
+    let mut elements = Vec::with_capacity(9 * WORD_SIZE);
+    elements.extend_from_slice(header.prev_block_commitment().as_elements());
+    elements.extend_from_slice(header.chain_commitment().as_elements());
+    elements.extend_from_slice(header.account_root().as_elements());
+    elements.extend_from_slice(header.nullifier_root().as_elements());
+    elements.extend_from_slice(header.tx_commitment().as_elements());
+    elements.extend_from_slice(header.tx_kernel_commitment().as_elements());
+    elements.extend_from_slice(header.proof_commitment().as_elements());
+    elements.extend([
+        header.block_num().into(),
+        header.version().into(),
+        header.timestamp().into(),
+        ZERO,
+    ]);
+    elements.extend_from_slice(header.note_root().as_elements());
+    elements
+}
+
 // ACCOUNT DATA INJECTOR
 // ------------------------------------------------------------------------------------------------
 
@@ -172,9 +350,6 @@ fn add_account_to_advice_inputs(
         }
     }
 
-    // extend advice map with storage commitment |-> length, storage slots and types vector
-    inputs.extend_map([(storage.commitment(), storage.as_elements())]);
-
     // --- account vault ------------------------------------------------------
     let vault = account.vault();
 
@@ -185,9 +360,34 @@ fn add_account_to_advice_inputs(
     inputs
         .extend_map(vault.asset_tree().leaves().map(|(_, leaf)| (leaf.hash(), leaf.to_elements())));
 
-    // --- account code -------------------------------------------------------
-    let code = account.code();
+    add_account_header_to_advice_inputs(
+        &AccountHeader::from(account),
+        &storage.get_header(),
+        account.code(),
+        account_seed,
+        inputs,
+    );
+}
+
+/// Inserts the account data which does not depend on the contents of the account vault and storage
+/// maps into the provided advice inputs.
+///
+/// Inserts the following entries into the advice map:
+/// - The account storage commitment |-> storage slots and types vector.
+/// - The account code commitment |-> procedures vector.
+/// - [account_id_suffix, account_id_prefix, 0, 0] |-> account_seed, when account seed is provided.
+fn add_account_header_to_advice_inputs(
+    account: &AccountHeader,
+    storage_header: &AccountStorageHeader,
+    code: &AccountCode,
+    account_seed: Option<Word>,
+    inputs: &mut AdviceInputs,
+) {
+    // --- account storage ----------------------------------------------------
+    // extend advice map with storage commitment |-> length, storage slots and types vector
+    inputs.extend_map([(account.storage_commitment(), storage_header.as_elements())]);
 
+    // --- account code -------------------------------------------------------
     // extend the advice map with the account code data
     inputs.extend_map([(code.commitment(), code.as_elements())]);
 
@@ -220,17 +420,19 @@ fn add_account_to_advice_inputs(
 ///
 /// The data above is processed by `prologue::process_input_notes_data`.
 fn add_input_notes_to_advice_inputs(
-    tx_inputs: &TransactionInputs,
+    block_header: &BlockHeader,
+    block_chain: &ChainMmr,
+    input_notes: &InputNotes<InputNote>,
     tx_args: &TransactionArgs,
     inputs: &mut AdviceInputs,
 ) {
     // if there are no input notes, nothing is added to the advice inputs
-    if tx_inputs.input_notes().is_empty() {
+    if input_notes.is_empty() {
         return;
     }
 
     let mut note_data = Vec::new();
-    for input_note in tx_inputs.input_notes().iter() {
+    for input_note in input_notes.iter() {
         let note = input_note.note();
         let assets = note.assets();
         let recipient = note.recipient();
@@ -262,13 +464,10 @@ fn add_input_notes_to_advice_inputs(
         match input_note {
             InputNote::Authenticated { note, proof } => {
                 let block_num = proof.location().block_num();
-                let note_block_header = if block_num == tx_inputs.block_header().block_num() {
-                    tx_inputs.block_header()
+                let note_block_header = if block_num == block_header.block_num() {
+                    block_header
                 } else {
-                    tx_inputs
-                        .block_chain()
-                        .get_block(block_num)
-                        .expect("block not found in chain MMR")
+                    block_chain.get_block(block_num).expect("block not found in chain MMR")
                 };
 
                 // NOTE: keep in sync with the `prologue::process_input_note` kernel procedure
@@ -299,7 +498,7 @@ fn add_input_notes_to_advice_inputs(
     }
 
     // NOTE: keep map in sync with the `prologue::process_input_notes_data` kernel procedure
-    inputs.extend_map([(tx_inputs.input_notes().commitment(), note_data)]);
+    inputs.extend_map([(input_notes.commitment(), note_data)]);
 }
 
 // KERNEL COMMITMENTS INJECTOR
@@ -319,9 +518,10 @@ pub fn add_kernel_commitments_to_advice_inputs(inputs: &mut AdviceInputs, kernel
     }
 
     // insert the selected kernel commitment with its procedure roots into the advice map
+    let kernel_offset = kernel_version as usize * WORD_SIZE;
     inputs.extend_map([(
         Digest::new(
-            kernel_commitments[kernel_version as usize..kernel_version as usize + WORD_SIZE]
+            kernel_commitments[kernel_offset..kernel_offset + WORD_SIZE]
                 .try_into()
                 .expect("invalid kernel offset"),
         ),
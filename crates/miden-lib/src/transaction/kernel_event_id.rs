@@ -0,0 +1,254 @@
+// This file is generated by build.rs, do not modify manually.
+// It is generated by extracting the events emitted via `emit.<NAME>` from the masm files in the
+// `miden-lib/asm` directory.
+//
+// To add a new event, define a constant with a new ID in masm and document it with a comment
+// directly above the constant. The comment must contain a `Stack: [...]` line which describes the
+// top of the operand stack at the time the event is emitted.
+
+use super::TransactionEventError;
+
+// KERNEL EVENT ID
+// ================================================================================================
+
+/// IDs of the events which may be emitted by the transaction kernel and the procedures of the
+/// `miden` library.
+///
+/// The discriminant of each variant is the event ID passed to the `emit` instruction. Event IDs
+/// are stable, i.e. the ID of an existing event never changes.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum KernelEventId {
+    /// Event emitted before an asset is added to the account vault.
+    ///
+    /// Stack: `[ASSET]`
+    AccountVaultBeforeAddAsset = 0x2_0000,
+    /// Event emitted after an asset is added to the account vault.
+    ///
+    /// Stack: `[ASSET]`
+    AccountVaultAfterAddAsset = 0x2_0001,
+    /// Event emitted before an asset is removed from the account vault.
+    ///
+    /// Stack: `[ASSET]`
+    AccountVaultBeforeRemoveAsset = 0x2_0002,
+    /// Event emitted after an asset is removed from the account vault.
+    ///
+    /// Stack: `[ASSET]`
+    AccountVaultAfterRemoveAsset = 0x2_0003,
+    /// Event emitted before an account storage item is updated.
+    ///
+    /// Stack: `[slot_index, NEW_VALUE]`
+    AccountStorageBeforeSetItem = 0x2_0004,
+    /// Event emitted after an account storage item is updated.
+    ///
+    /// Stack: `[slot_index, NEW_VALUE, OLD_VALUE]`
+    AccountStorageAfterSetItem = 0x2_0005,
+    /// Event emitted before an account storage map item is updated.
+    ///
+    /// Stack: `[slot_index, KEY, NEW_VALUE, OLD_ROOT]`
+    AccountStorageBeforeSetMapItem = 0x2_0006,
+    /// Event emitted after an account storage map item is updated.
+    ///
+    /// Stack: `[slot_index, KEY, NEW_VALUE, OLD_VALUE]`
+    AccountStorageAfterSetMapItem = 0x2_0007,
+    /// Event emitted before an account nonce is incremented.
+    ///
+    /// Stack: `[nonce_delta]`
+    AccountBeforeIncrementNonce = 0x2_0008,
+    /// Event emitted after an account nonce is incremented.
+    ///
+    /// Stack: `[]`
+    AccountAfterIncrementNonce = 0x2_0009,
+    /// Event emitted to push the index of the account procedure at the top of the operand stack onto
+    /// the advice stack.
+    ///
+    /// Stack: `[PROC_ROOT]`
+    AccountPushProcedureIndex = 0x2_000a,
+    /// Event emitted before a new note is created.
+    ///
+    /// Stack: `[tag, aux, note_type, execution_hint, RECIPIENT]`
+    NoteBeforeCreated = 0x2_000b,
+    /// Event emitted after a new note is created.
+    ///
+    /// Stack: `[NOTE_METADATA, note_ptr, RECIPIENT, note_idx]`
+    NoteAfterCreated = 0x2_000c,
+    /// Event emitted before an ASSET is added to a note
+    ///
+    /// Stack: `[ASSET, note_ptr, num_of_assets, note_idx]`
+    NoteBeforeAddAsset = 0x2_000d,
+    /// Event emitted after an ASSET is added to a note
+    ///
+    /// Stack: `[note_ptr, note_idx]`
+    NoteAfterAddAsset = 0x2_000e,
+    /// Event to place the falcon signature of a provided message and public key on the advice stack.
+    ///
+    /// Stack: `[PUB_KEY, MESSAGE]`
+    FalconSigToStack = 0x2_000f,
+    /// Event to place the secp256k1 ECDSA signature of a provided message and public key commitment on
+    /// the advice stack.
+    ///
+    /// Stack: `[PUB_KEY_COMMITMENT, MESSAGE]`
+    EcdsaK256SigToStack = 0x2_0010,
+    /// Event to place the quotient and the remainder of a 256-bit modular multiplication on the advice
+    /// stack.
+    ///
+    /// Stack: `[a_ptr, b_ptr, m_ptr, dst_ptr]`
+    U256MulModToStack = 0x2_0011,
+    /// Event emitted when a transaction is approved without authentication.
+    ///
+    /// Stack: `[]`
+    UnauthenticatedTx = 0x2_0012,
+    /// Event emitted before the RECIPIENT of a note is set
+    ///
+    /// Stack: `[note_ptr, RECIPIENT, note_idx]`
+    NoteBeforeSetRecipient = 0x2_0013,
+    /// Event emitted after the RECIPIENT of a note is set
+    ///
+    /// Stack: `[note_idx]`
+    NoteAfterSetRecipient = 0x2_0014,
+    /// Event to place the BLS12-381 signature of a provided message and public key commitment on
+    /// the advice stack.
+    ///
+    /// Stack: `[PUB_KEY_COMMITMENT, MESSAGE]`
+    Bls12381SigToStack = 0x2_0015,
+    /// Event to place the quotient and the remainder of a 384-bit modular multiplication on the advice
+    /// stack.
+    ///
+    /// Stack: `[a_ptr, b_ptr, m_ptr]`
+    U384MulModToStack = 0x2_0016,
+    /// Event to place the inverse of a 384-bit value modulo a 384-bit modulus on the advice stack.
+    ///
+    /// Stack: `[a_ptr, m_ptr]`
+    U384InvModToStack = 0x2_0017,
+}
+
+impl KernelEventId {
+    /// All events, sorted by their ID.
+    pub const ALL: [Self; 24] = [
+        Self::AccountVaultBeforeAddAsset,
+        Self::AccountVaultAfterAddAsset,
+        Self::AccountVaultBeforeRemoveAsset,
+        Self::AccountVaultAfterRemoveAsset,
+        Self::AccountStorageBeforeSetItem,
+        Self::AccountStorageAfterSetItem,
+        Self::AccountStorageBeforeSetMapItem,
+        Self::AccountStorageAfterSetMapItem,
+        Self::AccountBeforeIncrementNonce,
+        Self::AccountAfterIncrementNonce,
+        Self::AccountPushProcedureIndex,
+        Self::NoteBeforeCreated,
+        Self::NoteAfterCreated,
+        Self::NoteBeforeAddAsset,
+        Self::NoteAfterAddAsset,
+        Self::FalconSigToStack,
+        Self::EcdsaK256SigToStack,
+        Self::U256MulModToStack,
+        Self::UnauthenticatedTx,
+        Self::NoteBeforeSetRecipient,
+        Self::NoteAfterSetRecipient,
+        Self::Bls12381SigToStack,
+        Self::U384MulModToStack,
+        Self::U384InvModToStack,
+    ];
+
+    /// Returns the name of the MASM constant which defines the event.
+    pub const fn masm_name(&self) -> &'static str {
+        match self {
+            Self::AccountVaultBeforeAddAsset => "ACCOUNT_VAULT_BEFORE_ADD_ASSET_EVENT",
+            Self::AccountVaultAfterAddAsset => "ACCOUNT_VAULT_AFTER_ADD_ASSET_EVENT",
+            Self::AccountVaultBeforeRemoveAsset => "ACCOUNT_VAULT_BEFORE_REMOVE_ASSET_EVENT",
+            Self::AccountVaultAfterRemoveAsset => "ACCOUNT_VAULT_AFTER_REMOVE_ASSET_EVENT",
+            Self::AccountStorageBeforeSetItem => "ACCOUNT_STORAGE_BEFORE_SET_ITEM_EVENT",
+            Self::AccountStorageAfterSetItem => "ACCOUNT_STORAGE_AFTER_SET_ITEM_EVENT",
+            Self::AccountStorageBeforeSetMapItem => "ACCOUNT_STORAGE_BEFORE_SET_MAP_ITEM_EVENT",
+            Self::AccountStorageAfterSetMapItem => "ACCOUNT_STORAGE_AFTER_SET_MAP_ITEM_EVENT",
+            Self::AccountBeforeIncrementNonce => "ACCOUNT_BEFORE_INCREMENT_NONCE_EVENT",
+            Self::AccountAfterIncrementNonce => "ACCOUNT_AFTER_INCREMENT_NONCE_EVENT",
+            Self::AccountPushProcedureIndex => "ACCOUNT_PUSH_PROCEDURE_INDEX_EVENT",
+            Self::NoteBeforeCreated => "NOTE_BEFORE_CREATED_EVENT",
+            Self::NoteAfterCreated => "NOTE_AFTER_CREATED_EVENT",
+            Self::NoteBeforeAddAsset => "NOTE_BEFORE_ADD_ASSET_EVENT",
+            Self::NoteAfterAddAsset => "NOTE_AFTER_ADD_ASSET_EVENT",
+            Self::FalconSigToStack => "FALCON_SIG_TO_STACK",
+            Self::EcdsaK256SigToStack => "ECDSA_K256_SIG_TO_STACK",
+            Self::U256MulModToStack => "U256_MUL_MOD_TO_STACK",
+            Self::UnauthenticatedTx => "UNAUTHENTICATED_TX",
+            Self::NoteBeforeSetRecipient => "NOTE_BEFORE_SET_RECIPIENT_EVENT",
+            Self::NoteAfterSetRecipient => "NOTE_AFTER_SET_RECIPIENT_EVENT",
+            Self::Bls12381SigToStack => "BLS12_381_SIG_TO_STACK",
+            Self::U384MulModToStack => "U384_MUL_MOD_TO_STACK",
+            Self::U384InvModToStack => "U384_INV_MOD_TO_STACK",
+        }
+    }
+
+    /// Returns the names of the items on top of the operand stack at the time the event is
+    /// emitted, starting with the top of the stack.
+    ///
+    /// Items with an uppercase name are words, all other items are single elements.
+    pub const fn stack_layout(&self) -> &'static [&'static str] {
+        match self {
+            Self::AccountVaultBeforeAddAsset => &["ASSET"],
+            Self::AccountVaultAfterAddAsset => &["ASSET"],
+            Self::AccountVaultBeforeRemoveAsset => &["ASSET"],
+            Self::AccountVaultAfterRemoveAsset => &["ASSET"],
+            Self::AccountStorageBeforeSetItem => &["slot_index", "NEW_VALUE"],
+            Self::AccountStorageAfterSetItem => &["slot_index", "NEW_VALUE", "OLD_VALUE"],
+            Self::AccountStorageBeforeSetMapItem => &["slot_index", "KEY", "NEW_VALUE", "OLD_ROOT"],
+            Self::AccountStorageAfterSetMapItem => &["slot_index", "KEY", "NEW_VALUE", "OLD_VALUE"],
+            Self::AccountBeforeIncrementNonce => &["nonce_delta"],
+            Self::AccountAfterIncrementNonce => &[],
+            Self::AccountPushProcedureIndex => &["PROC_ROOT"],
+            Self::NoteBeforeCreated => &["tag", "aux", "note_type", "execution_hint", "RECIPIENT"],
+            Self::NoteAfterCreated => &["NOTE_METADATA", "note_ptr", "RECIPIENT", "note_idx"],
+            Self::NoteBeforeAddAsset => &["ASSET", "note_ptr", "num_of_assets", "note_idx"],
+            Self::NoteAfterAddAsset => &["note_ptr", "note_idx"],
+            Self::FalconSigToStack => &["PUB_KEY", "MESSAGE"],
+            Self::EcdsaK256SigToStack => &["PUB_KEY_COMMITMENT", "MESSAGE"],
+            Self::U256MulModToStack => &["a_ptr", "b_ptr", "m_ptr", "dst_ptr"],
+            Self::UnauthenticatedTx => &[],
+            Self::NoteBeforeSetRecipient => &["note_ptr", "RECIPIENT", "note_idx"],
+            Self::NoteAfterSetRecipient => &["note_idx"],
+            Self::Bls12381SigToStack => &["PUB_KEY_COMMITMENT", "MESSAGE"],
+            Self::U384MulModToStack => &["a_ptr", "b_ptr", "m_ptr"],
+            Self::U384InvModToStack => &["a_ptr", "m_ptr"],
+        }
+    }
+}
+
+impl TryFrom<u32> for KernelEventId {
+    type Error = TransactionEventError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        if value >> 16 != 2 {
+            return Err(TransactionEventError::NotTransactionEvent(value));
+        }
+
+        match value {
+            131072 => Ok(Self::AccountVaultBeforeAddAsset),
+            131073 => Ok(Self::AccountVaultAfterAddAsset),
+            131074 => Ok(Self::AccountVaultBeforeRemoveAsset),
+            131075 => Ok(Self::AccountVaultAfterRemoveAsset),
+            131076 => Ok(Self::AccountStorageBeforeSetItem),
+            131077 => Ok(Self::AccountStorageAfterSetItem),
+            131078 => Ok(Self::AccountStorageBeforeSetMapItem),
+            131079 => Ok(Self::AccountStorageAfterSetMapItem),
+            131080 => Ok(Self::AccountBeforeIncrementNonce),
+            131081 => Ok(Self::AccountAfterIncrementNonce),
+            131082 => Ok(Self::AccountPushProcedureIndex),
+            131083 => Ok(Self::NoteBeforeCreated),
+            131084 => Ok(Self::NoteAfterCreated),
+            131085 => Ok(Self::NoteBeforeAddAsset),
+            131086 => Ok(Self::NoteAfterAddAsset),
+            131087 => Ok(Self::FalconSigToStack),
+            131088 => Ok(Self::EcdsaK256SigToStack),
+            131089 => Ok(Self::U256MulModToStack),
+            131090 => Ok(Self::UnauthenticatedTx),
+            131091 => Ok(Self::NoteBeforeSetRecipient),
+            131092 => Ok(Self::NoteAfterSetRecipient),
+            131093 => Ok(Self::Bls12381SigToStack),
+            131094 => Ok(Self::U384MulModToStack),
+            131095 => Ok(Self::U384InvModToStack),
+            _ => Err(TransactionEventError::InvalidTransactionEvent(value)),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use super::TransactionKernel;
+
+// Include the kernel limits generated in build.rs
+mod generated {
+    include!(concat!(env!("OUT_DIR"), "/kernel_params.rs"));
+}
+
+// KERNEL LIMITS
+// ================================================================================================
+
+// The limits of the transaction kernel are configured at build time via a kernel parameters file
+// (see `kernel_params.toml` in the crate root). A kernel built with custom limits has different
+// procedure roots and thus a different kernel commitment than the default kernel.
+//
+// The limits of `miden-objects` are not affected by the kernel parameters and always use the
+// defaults, so objects which are valid may still exceed the limits of a kernel with custom limits.
+impl TransactionKernel {
+    /// The maximum number of storage slots of an account enforced by the transaction kernel.
+    pub const MAX_NUM_STORAGE_SLOTS: usize = generated::MAX_NUM_STORAGE_SLOTS;
+
+    /// The maximum number of assets in a single note enforced by the transaction kernel.
+    pub const MAX_ASSETS_PER_NOTE: usize = generated::MAX_ASSETS_PER_NOTE;
+
+    /// The maximum number of notes a single transaction can create enforced by the transaction
+    /// kernel.
+    pub const MAX_OUTPUT_NOTES_PER_TX: usize = generated::MAX_OUTPUT_NOTES_PER_TX;
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(all(test, not(custom_kernel_params)))]
+mod tests {
+    use miden_objects::{MAX_OUTPUT_NOTES_PER_TX, account::AccountStorage};
+
+    use super::TransactionKernel;
+
+    #[test]
+    fn default_kernel_limits_match_protocol_limits() {
+        assert_eq!(TransactionKernel::MAX_NUM_STORAGE_SLOTS, AccountStorage::MAX_NUM_STORAGE_SLOTS);
+        assert_eq!(TransactionKernel::MAX_OUTPUT_NOTES_PER_TX, MAX_OUTPUT_NOTES_PER_TX);
+    }
+}
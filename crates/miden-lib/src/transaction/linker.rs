@@ -0,0 +1,336 @@
+use alloc::{
+    collections::BTreeSet,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use miden_objects::{
+    Digest, Felt, NoteError, TransactionScriptError, Word,
+    assembly::{Assembler, Compile, Library, LibraryNamespace, LibraryPath},
+    note::NoteScript,
+    transaction::TransactionScript,
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
+
+use super::{LibraryLinkerError, TransactionKernel};
+use crate::{MidenLib, StdLibrary};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Namespaces of the libraries linked by the transaction kernel assembler which user libraries
+/// cannot be registered under.
+const RESERVED_NAMESPACES: [&str; 3] = ["std", "miden", "kernel"];
+
+// LIBRARY LINKER
+// ================================================================================================
+
+/// Links user libraries into the assembler of the transaction kernel.
+///
+/// Each library is registered under an explicit namespace, and all of its modules must be part of
+/// that namespace. Libraries whose modules collide with the modules of an already linked library
+/// (including the standard library and `miden-lib`) are rejected, so a procedure path always
+/// resolves to a single procedure.
+///
+/// Scripts compiled via the linker carry a [LibraryManifest] listing the roots of the linked
+/// libraries, which allows checking which library versions a script was compiled against.
+#[derive(Clone)]
+pub struct LibraryLinker {
+    assembler: Assembler,
+    modules: BTreeSet<LibraryPath>,
+    manifest: LibraryManifest,
+}
+
+impl LibraryLinker {
+    /// Returns a new [LibraryLinker] backed by the assembler of the latest transaction kernel.
+    pub fn new() -> Self {
+        let modules = [StdLibrary::default().as_ref(), MidenLib::default().as_ref()]
+            .into_iter()
+            .flat_map(|library: &Library| {
+                library.module_infos().map(|module| module.path().clone())
+            })
+            .collect();
+
+        Self {
+            assembler: TransactionKernel::assembler(),
+            modules,
+            manifest: LibraryManifest::default(),
+        }
+    }
+
+    /// Registers the provided library under the specified namespace.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The namespace is reserved for the libraries linked by default (`std`, `miden` or
+    ///   `kernel`).
+    /// - Any module of the library is not part of the namespace.
+    /// - Any module of the library is already provided by a linked library.
+    pub fn with_library(
+        mut self,
+        namespace: LibraryNamespace,
+        library: Library,
+    ) -> Result<Self, LibraryLinkerError> {
+        if RESERVED_NAMESPACES.contains(&namespace.as_str()) {
+            return Err(LibraryLinkerError::ReservedNamespace(namespace));
+        }
+
+        let mut modules = BTreeSet::new();
+        for module in library.module_infos() {
+            let path = module.path();
+            if path.namespace() != &namespace {
+                return Err(LibraryLinkerError::NamespaceMismatch {
+                    namespace,
+                    module: path.clone(),
+                });
+            }
+            if self.modules.contains(path) || !modules.insert(path.clone()) {
+                return Err(LibraryLinkerError::ModuleCollision(path.clone()));
+            }
+        }
+
+        self.manifest.libraries.push((namespace, *library.digest()));
+        self.modules.extend(modules);
+        self.assembler = self
+            .assembler
+            .with_library(library)
+            .expect("library modules should not collide with the linked modules");
+
+        Ok(self)
+    }
+
+    /// Returns the assembler with all registered libraries linked.
+    pub fn assembler(&self) -> Assembler {
+        self.assembler.clone()
+    }
+
+    /// Returns the manifest of the registered libraries.
+    pub fn manifest(&self) -> &LibraryManifest {
+        &self.manifest
+    }
+
+    /// Compiles the provided source code into a [TransactionScript] with the specified inputs.
+    ///
+    /// # Errors
+    /// Returns an error if the compilation of the provided source code fails.
+    pub fn compile_tx_script(
+        &self,
+        source_code: impl Compile,
+        inputs: impl IntoIterator<Item = (Word, Vec<Felt>)>,
+    ) -> Result<LinkedScript<TransactionScript>, TransactionScriptError> {
+        let script = TransactionScript::compile(source_code, inputs, self.assembler())?;
+        Ok(LinkedScript::new(script, self.manifest.clone()))
+    }
+
+    /// Compiles the provided source code into a [NoteScript].
+    ///
+    /// # Errors
+    /// Returns an error if the compilation of the provided source code fails.
+    pub fn compile_note_script(
+        &self,
+        source_code: impl Compile,
+    ) -> Result<LinkedScript<NoteScript>, NoteError> {
+        let script = NoteScript::compile(source_code, self.assembler())?;
+        Ok(LinkedScript::new(script, self.manifest.clone()))
+    }
+}
+
+impl Default for LibraryLinker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// LIBRARY MANIFEST
+// ================================================================================================
+
+/// The user libraries a script was linked against, in the order in which they were registered.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LibraryManifest {
+    libraries: Vec<(LibraryNamespace, Digest)>,
+}
+
+impl LibraryManifest {
+    /// Returns an iterator over the namespaces and roots of the linked libraries.
+    pub fn iter(&self) -> impl Iterator<Item = (&LibraryNamespace, Digest)> {
+        self.libraries.iter().map(|(namespace, root)| (namespace, *root))
+    }
+
+    /// Returns the number of linked libraries.
+    pub fn num_libraries(&self) -> usize {
+        self.libraries.len()
+    }
+
+    /// Returns `true` if a library with the provided root was linked.
+    pub fn contains(&self, root: Digest) -> bool {
+        self.libraries.iter().any(|(_, library_root)| *library_root == root)
+    }
+}
+
+impl Serializable for LibraryManifest {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.libraries.len());
+        for (namespace, root) in &self.libraries {
+            namespace.to_string().write_into(target);
+            root.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for LibraryManifest {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_libraries = source.read_usize()?;
+        let mut libraries = Vec::with_capacity(num_libraries);
+        for _ in 0..num_libraries {
+            let namespace = LibraryNamespace::new(String::read_from(source)?)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+            let root = Digest::read_from(source)?;
+            libraries.push((namespace, root));
+        }
+
+        Ok(Self { libraries })
+    }
+}
+
+// LINKED SCRIPT
+// ================================================================================================
+
+/// A compiled script together with the manifest of the user libraries it was linked against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkedScript<S> {
+    script: S,
+    manifest: LibraryManifest,
+}
+
+impl<S> LinkedScript<S> {
+    /// Returns a new [LinkedScript] instantiated from the provided script and manifest.
+    pub fn new(script: S, manifest: LibraryManifest) -> Self {
+        Self { script, manifest }
+    }
+
+    /// Returns a reference to the compiled script.
+    pub fn script(&self) -> &S {
+        &self.script
+    }
+
+    /// Returns the manifest of the libraries the script was linked against.
+    pub fn manifest(&self) -> &LibraryManifest {
+        &self.manifest
+    }
+
+    /// Consumes self and returns the compiled script and the library manifest.
+    pub fn into_parts(self) -> (S, LibraryManifest) {
+        (self.script, self.manifest)
+    }
+}
+
+impl<S: Serializable> Serializable for LinkedScript<S> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.script.write_into(target);
+        self.manifest.write_into(target);
+    }
+}
+
+impl<S: Deserializable> Deserializable for LinkedScript<S> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let script = S::read_from(source)?;
+        let manifest = LibraryManifest::read_from(source)?;
+
+        Ok(Self { script, manifest })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::sync::Arc;
+
+    use miden_objects::assembly::{DefaultSourceManager, Module, ModuleKind};
+
+    use super::*;
+
+    const LIBRARY_SOURCE: &str = "
+        export.foo
+            push.1 push.2 add
+        end
+    ";
+
+    fn library(paths: &[&str]) -> Library {
+        let source_manager = Arc::new(DefaultSourceManager::default());
+        let modules = paths.iter().map(|path| {
+            let path = LibraryPath::new(path).unwrap();
+            *Module::parser(ModuleKind::Library)
+                .parse_str(path, LIBRARY_SOURCE, &source_manager)
+                .unwrap()
+        });
+
+        Assembler::default().assemble_library(modules).unwrap()
+    }
+
+    fn namespace(name: &str) -> LibraryNamespace {
+        LibraryNamespace::new(name).unwrap()
+    }
+
+    #[test]
+    fn link_multiple_libraries() {
+        let acme = library(&["acme::wallet", "acme::utils"]);
+        let other = library(&["other::utils"]);
+
+        let linker = LibraryLinker::new()
+            .with_library(namespace("acme"), acme.clone())
+            .unwrap()
+            .with_library(namespace("other"), other.clone())
+            .unwrap();
+
+        let linked = linker
+            .compile_tx_script(
+                "
+                use.acme::wallet
+                use.other::utils
+
+                begin
+                    exec.wallet::foo exec.utils::foo drop drop
+                end
+                ",
+                [],
+            )
+            .unwrap();
+
+        let manifest = linked.manifest();
+        assert_eq!(manifest.num_libraries(), 2);
+        assert!(manifest.contains(*acme.digest()));
+        assert!(manifest.contains(*other.digest()));
+        assert_eq!(LinkedScript::read_from_bytes(&linked.to_bytes()).unwrap(), linked);
+    }
+
+    #[test]
+    fn reject_reserved_namespace() {
+        let result =
+            LibraryLinker::new().with_library(namespace("miden"), library(&["miden::custom"]));
+
+        assert!(matches!(result, Err(LibraryLinkerError::ReservedNamespace(_))));
+    }
+
+    #[test]
+    fn reject_module_outside_namespace() {
+        let result =
+            LibraryLinker::new().with_library(namespace("acme"), library(&["other::wallet"]));
+
+        assert!(matches!(result, Err(LibraryLinkerError::NamespaceMismatch { .. })));
+    }
+
+    #[test]
+    fn reject_module_collision() {
+        let result = LibraryLinker::new()
+            .with_library(namespace("acme"), library(&["acme::wallet"]))
+            .unwrap()
+            .with_library(namespace("acme"), library(&["acme::wallet", "acme::utils"]));
+
+        assert!(matches!(
+            result,
+            Err(LibraryLinkerError::ModuleCollision(path)) if path.to_string() == "acme::wallet"
+        ));
+    }
+}
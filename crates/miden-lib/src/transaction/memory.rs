@@ -92,6 +92,17 @@ pub const TX_EXPIRATION_BLOCK_NUM_PTR: MemoryAddress = 24;
 /// ```
 pub const ACCOUNT_STACK_TOP_PTR: MemoryAddress = 28;
 
+/// The memory address at which the transaction fee asset is stored.
+pub const TX_FEE_PTR: MemoryAddress = 96;
+
+/// The memory address at which the type of the script currently being executed is stored, which is
+/// 0 if no script is being executed, 1 for note scripts and 2 for the transaction script.
+pub const CURRENT_SCRIPT_CALLER_PTR: MemoryAddress = 100;
+
+/// The memory address at which a pointer to the asset filter procedure of the native account is
+/// stored, which is 0 if the account does not have an asset filter.
+pub const ASSET_FILTER_PROC_PTR: MemoryAddress = 104;
+
 // GLOBAL INPUTS
 // ------------------------------------------------------------------------------------------------
 
@@ -180,6 +191,18 @@ pub const NUM_KERNEL_PROCEDURES_PTR: MemoryAddress = 1600;
 /// begins
 pub const KERNEL_PROCEDURES_PTR: MemoryAddress = 1604;
 
+// INCOMING ASSETS
+// ------------------------------------------------------------------------------------------------
+
+/// The memory address at which the number of assets added to the native account vault is stored.
+/// The assets are only tracked if the native account has an asset filter.
+pub const NUM_INCOMING_ASSETS_PTR: MemoryAddress = 2048;
+
+/// The memory address at which the assets added to the native account vault begin. The section is
+/// placed after all other sections, so that the number of tracked assets is not limited by the
+/// memory layout.
+pub const INCOMING_ASSETS_PTR: MemoryAddress = 33_554_432;
+
 // ACCOUNT DATA
 // ------------------------------------------------------------------------------------------------
 
@@ -231,6 +254,23 @@ pub const ACCT_CODE_COMMITMENT_OFFSET: MemoryOffset = 12;
 pub const NATIVE_ACCT_CODE_COMMITMENT_PTR: MemoryAddress =
     NATIVE_ACCOUNT_DATA_PTR + ACCT_CODE_COMMITMENT_OFFSET;
 
+/// The offset at which the cached account commitment is stored relative to the start of the
+/// account data segment.
+pub const ACCT_COMMITMENT_CACHE_OFFSET: MemoryOffset = 16;
+
+/// The memory address at which the cached account commitment is stored in the native account.
+pub const NATIVE_ACCT_COMMITMENT_CACHE_PTR: MemoryAddress =
+    NATIVE_ACCOUNT_DATA_PTR + ACCT_COMMITMENT_CACHE_OFFSET;
+
+/// The offset at which the flag indicating whether the cached account commitment is valid is
+/// stored relative to the start of the account data segment.
+pub const ACCT_COMMITMENT_CACHE_VALID_OFFSET: MemoryOffset = 20;
+
+/// The memory address at which the flag indicating whether the cached account commitment is valid
+/// is stored in the native account.
+pub const NATIVE_ACCT_COMMITMENT_CACHE_VALID_PTR: MemoryAddress =
+    NATIVE_ACCOUNT_DATA_PTR + ACCT_COMMITMENT_CACHE_VALID_OFFSET;
+
 /// The offset at which the number of procedures contained in the account code is stored relative to
 /// the start of the account data segment.
 pub const NUM_ACCT_PROCEDURES_OFFSET: MemoryAddress = 28;
@@ -297,6 +337,17 @@ pub const NOTE_MEM_SIZE: MemoryAddress = 2048;
 // - NUM_ASSETS is encoded [num_assets, 0, 0, 0].
 // - INPUTS_COMMITMENT is the key to look up note inputs in the advice map.
 // - ASSETS_HASH is the key to look up note assets in the advice map.
+//
+// The assets are followed by the note inputs, which are loaded into memory the first time they are
+// requested via the `note_get_inputs` kernel procedure:
+//
+// ┌───────────┬───────────┬───────┬─────┬───────┬─────────┐
+// │  INPUTS   │    NUM    │ INPUT │ ... │ INPUT │ PADDING │
+// │  LOADED   │  INPUTS   │   0   │     │   n   │         │
+// ├───────────┼───────────┼───────┼─────┼───────┼─────────┤
+// 1056        1057        1060          1060 + n
+//
+// - INPUTS_LOADED is 1 if the note inputs have been loaded into memory and 0 otherwise.
 
 /// The memory address at which the input note section begins.
 pub const INPUT_NOTE_SECTION_PTR: MemoryAddress = 4_194_304;
@@ -320,6 +371,9 @@ pub const INPUT_NOTE_METADATA_OFFSET: MemoryOffset = 20;
 pub const INPUT_NOTE_ARGS_OFFSET: MemoryOffset = 24;
 pub const INPUT_NOTE_NUM_ASSETS_OFFSET: MemoryOffset = 28;
 pub const INPUT_NOTE_ASSETS_OFFSET: MemoryOffset = 32;
+pub const INPUT_NOTE_INPUTS_LOADED_OFFSET: MemoryOffset = 1056;
+pub const INPUT_NOTE_NUM_INPUTS_OFFSET: MemoryOffset = 1057;
+pub const INPUT_NOTE_INPUTS_OFFSET: MemoryOffset = 1060;
 
 // OUTPUT NOTES DATA
 // ------------------------------------------------------------------------------------------------
@@ -1,65 +1,58 @@
-use alloc::{string::ToString, sync::Arc, vec::Vec};
+use alloc::{string::ToString, vec::Vec};
 
 use miden_objects::{
-    Digest, EMPTY_WORD, Felt, TransactionOutputError, ZERO,
+    Digest, EMPTY_WORD, Felt, TransactionOutputError, Word, ZERO,
     account::{AccountCode, AccountHeader, AccountId, AccountStorageHeader},
-    assembly::{Assembler, DefaultSourceManager, KernelLibrary},
+    assembly::{Assembler, KernelLibrary},
+    asset::FungibleAsset,
     block::BlockNumber,
     crypto::merkle::{MerkleError, MerklePath},
     transaction::{
-        OutputNote, OutputNotes, TransactionArgs, TransactionInputs, TransactionOutputs,
+        OutputNote, OutputNotes, RedactedTransactionWitness, TransactionArgs, TransactionInputs,
+        TransactionOutputs, TransactionWitness,
     },
-    utils::{serde::Deserializable, sync::LazyLock},
     vm::{AdviceInputs, AdviceMap, Program, ProgramInfo, StackInputs, StackOutputs},
 };
-use miden_stdlib::StdLibrary;
 use outputs::EXPIRATION_BLOCK_ELEMENT_IDX;
 
-use super::MidenLib;
-
 pub mod memory;
 
 mod events;
 pub use events::{TransactionEvent, TransactionTrace};
 
+mod kernel_event_id;
+pub use kernel_event_id::KernelEventId;
+
 mod inputs;
 
 mod outputs;
 pub use outputs::{
-    FINAL_ACCOUNT_COMMITMENT_WORD_IDX, OUTPUT_NOTES_COMMITMENT_WORD_IDX, parse_final_account_header,
+    FEE_ASSET_WORD_IDX, FINAL_ACCOUNT_COMMITMENT_WORD_IDX, OUTPUT_NOTES_COMMITMENT_WORD_IDX,
+    parse_final_account_header,
 };
 
 mod errors;
-pub use errors::{TransactionEventError, TransactionKernelError, TransactionTraceParsingError};
+pub use errors::{
+    LibraryLinkerError, TransactionEventError, TransactionKernelError, TransactionRequestError,
+    TransactionScriptTemplateError, TransactionTraceParsingError,
+};
+
+mod request;
+pub use request::{MAX_ACCOUNT_CALL_INPUTS, TransactionRequest, TransactionRequestBuilder};
+
+mod templates;
+pub use templates::TransactionScriptTemplate;
+
+mod linker;
+pub use linker::{LibraryLinker, LibraryManifest, LinkedScript};
 
 mod procedures;
+pub use procedures::KernelProcedure;
 
-// CONSTANTS
-// ================================================================================================
+mod kernel_params;
 
-// Initialize the kernel library only once
-static KERNEL_LIB: LazyLock<KernelLibrary> = LazyLock::new(|| {
-    let kernel_lib_bytes =
-        include_bytes!(concat!(env!("OUT_DIR"), "/assets/kernels/tx_kernel.masl"));
-    KernelLibrary::read_from_bytes(kernel_lib_bytes)
-        .expect("failed to deserialize transaction kernel library")
-});
-
-// Initialize the kernel main program only once
-static KERNEL_MAIN: LazyLock<Program> = LazyLock::new(|| {
-    let kernel_main_bytes =
-        include_bytes!(concat!(env!("OUT_DIR"), "/assets/kernels/tx_kernel.masb"));
-    Program::read_from_bytes(kernel_main_bytes)
-        .expect("failed to deserialize transaction kernel runtime")
-});
-
-// Initialize the transaction script executor program only once
-static TX_SCRIPT_MAIN: LazyLock<Program> = LazyLock::new(|| {
-    let tx_script_main_bytes =
-        include_bytes!(concat!(env!("OUT_DIR"), "/assets/kernels/tx_script_main.masb"));
-    Program::read_from_bytes(tx_script_main_bytes)
-        .expect("failed to deserialize tx script executor runtime")
-});
+mod versions;
+pub use versions::VersionedTransactionKernel;
 
 // TRANSACTION KERNEL
 // ================================================================================================
@@ -70,44 +63,40 @@ impl TransactionKernel {
     // KERNEL SOURCE CODE
     // --------------------------------------------------------------------------------------------
 
-    /// Returns a library with the transaction kernel system procedures.
+    /// Returns a library with the system procedures of the latest transaction kernel.
     ///
     /// # Panics
     /// Panics if the transaction kernel source is not well-formed.
     pub fn kernel() -> KernelLibrary {
-        KERNEL_LIB.clone()
+        Self::latest().kernel()
     }
 
-    /// Returns an AST of the transaction kernel executable program.
+    /// Returns an AST of the latest transaction kernel executable program.
     ///
     /// # Panics
     /// Panics if the transaction kernel source is not well-formed.
     pub fn main() -> Program {
-        KERNEL_MAIN.clone()
+        Self::latest().main()
     }
 
-    /// Returns an AST of the transaction script executor program.
+    /// Returns an AST of the transaction script executor program of the latest transaction kernel.
     ///
     /// # Panics
     /// Panics if the transaction kernel source is not well-formed.
     pub fn tx_script_main() -> Program {
-        TX_SCRIPT_MAIN.clone()
+        Self::latest().tx_script_main()
     }
 
-    /// Returns [ProgramInfo] for the transaction kernel executable program.
+    /// Returns [ProgramInfo] for the latest transaction kernel executable program.
     ///
     /// # Panics
     /// Panics if the transaction kernel source is not well-formed.
     pub fn program_info() -> ProgramInfo {
-        // TODO: make static
-        let program_hash = Self::main().hash();
-        let kernel = Self::kernel().kernel().clone();
-
-        ProgramInfo::new(program_hash, kernel)
+        Self::latest().program_info()
     }
 
     /// Transforms the provided [TransactionInputs] and [TransactionArgs] into stack and advice
-    /// inputs needed to execute a transaction kernel for a specific transaction.
+    /// inputs needed to execute the latest transaction kernel for a specific transaction.
     ///
     /// If `init_advice_inputs` is provided, they will be included in the returned advice inputs.
     pub fn prepare_inputs(
@@ -115,34 +104,79 @@ impl TransactionKernel {
         tx_args: &TransactionArgs,
         init_advice_inputs: Option<AdviceInputs>,
     ) -> (StackInputs, AdviceInputs) {
-        let account = tx_inputs.account();
-
-        let stack_inputs = TransactionKernel::build_input_stack(
-            account.id(),
-            account.init_commitment(),
-            tx_inputs.input_notes().commitment(),
-            tx_inputs.block_header().commitment(),
-            tx_inputs.block_header().block_num(),
-        );
+        Self::latest().prepare_inputs(tx_inputs, tx_args, init_advice_inputs)
+    }
 
-        let mut advice_inputs = init_advice_inputs.unwrap_or_default();
-        inputs::extend_advice_inputs(tx_inputs, tx_args, &mut advice_inputs);
+    /// Transforms the provided [RedactedTransactionWitness] into stack and advice inputs needed to
+    /// prove the transaction it was created from with the latest transaction kernel.
+    ///
+    /// The advice witness of the redacted witness is used as the initial advice inputs, since it
+    /// provides the account vault assets and storage map entries accessed by the transaction.
+    pub fn prepare_redacted_inputs(
+        tx_witness: &RedactedTransactionWitness,
+    ) -> (StackInputs, AdviceInputs) {
+        Self::latest().prepare_redacted_inputs(tx_witness)
+    }
 
-        (stack_inputs, advice_inputs)
+    /// Returns the key of an entry in the advice map of the provided [TransactionArgs] which
+    /// collides with an entry the latest transaction kernel adds to the advice map for the
+    /// provided [TransactionInputs], if any.
+    ///
+    /// An entry collides if the kernel adds different values under the same key, in which case the
+    /// entry of the transaction args would silently replace the data required by the kernel.
+    pub fn find_advice_map_collision(
+        tx_inputs: &TransactionInputs,
+        tx_args: &TransactionArgs,
+    ) -> Option<Digest> {
+        Self::latest().find_advice_map_collision(tx_inputs, tx_args)
+    }
+
+    /// Returns the provided [TransactionWitness] stripped of the data which is not required for
+    /// re-executing or proving the transaction.
+    ///
+    /// The following data is removed from the witness:
+    /// - the codes of the foreign accounts whose procedures were never loaded by the transaction
+    ///   kernel during execution.
+    /// - the entries of the advice map and the nodes of the Merkle store of the advice witness which
+    ///   are rebuilt from the transaction inputs and args by [`Self::prepare_inputs`] anyway.
+    ///
+    /// The advice stack of the witness is left untouched.
+    pub fn minimize_witness(tx_witness: TransactionWitness) -> TransactionWitness {
+        let TransactionWitness {
+            tx_inputs,
+            tx_args,
+            advice_witness,
+            mut account_codes,
+        } = tx_witness;
+
+        // the procedures of a foreign account are loaded by the kernel from the advice map under
+        // the code commitment, so the map of the witness contains the commitments of all codes
+        // which were loaded during execution
+        account_codes.retain(|code| advice_witness.mapped_values(&code.commitment()).is_some());
+
+        let advice_witness = inputs::minimize_advice_witness(&tx_inputs, &tx_args, advice_witness);
+
+        TransactionWitness {
+            tx_inputs,
+            tx_args,
+            advice_witness,
+            account_codes,
+        }
     }
 
     // ASSEMBLER CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
 
-    /// Returns a new Miden assembler instantiated with the transaction kernel and loaded with the
-    /// Miden stdlib as well as with miden-lib.
+    /// Returns a new Miden assembler instantiated with the latest transaction kernel and loaded
+    /// with the Miden stdlib as well as with miden-lib.
     pub fn assembler() -> Assembler {
-        let source_manager = Arc::new(DefaultSourceManager::default());
-        Assembler::with_kernel(source_manager, Self::kernel())
-            .with_library(StdLibrary::default())
-            .expect("failed to load std-lib")
-            .with_library(MidenLib::default())
-            .expect("failed to load miden-lib")
+        Self::latest().assembler()
+    }
+
+    /// Returns a new [LibraryLinker] which allows linking user libraries into the assembler
+    /// returned by [TransactionKernel::assembler()] under explicit namespaces.
+    pub fn library_linker() -> LibraryLinker {
+        LibraryLinker::new()
     }
 
     // STACK INPUTS / OUTPUTS
@@ -236,6 +270,7 @@ impl TransactionKernel {
     /// ```text
     /// [
     ///     expiration_block_num,
+    ///     FEE_ASSET,
     ///     OUTPUT_NOTES_COMMITMENT,
     ///     FINAL_ACCOUNT_COMMITMENT,
     /// ]
@@ -244,14 +279,18 @@ impl TransactionKernel {
     /// Where:
     /// - OUTPUT_NOTES_COMMITMENT is a commitment to the output notes.
     /// - FINAL_ACCOUNT_COMMITMENT is a hash of the account's final state.
+    /// - FEE_ASSET is the fungible asset paid as the transaction fee, or an empty word if the
+    ///   transaction did not pay a fee.
     /// - expiration_block_num is the block number at which the transaction will expire.
     pub fn build_output_stack(
         final_account_commitment: Digest,
         output_notes_commitment: Digest,
+        fee: Option<FungibleAsset>,
         expiration_block_num: BlockNumber,
     ) -> StackOutputs {
-        let mut outputs: Vec<Felt> = Vec::with_capacity(9);
+        let mut outputs: Vec<Felt> = Vec::with_capacity(13);
         outputs.push(Felt::from(expiration_block_num));
+        outputs.extend(fee.map(Word::from).unwrap_or(EMPTY_WORD));
         outputs.extend(final_account_commitment);
         outputs.extend(output_notes_commitment);
         outputs.reverse();
@@ -264,23 +303,26 @@ impl TransactionKernel {
     ///
     /// The data on the stack is expected to be arranged as follows:
     ///
-    /// Stack: [OUTPUT_NOTES_COMMITMENT, FINAL_ACCOUNT_COMMITMENT, tx_expiration_block_num]
+    /// Stack: [OUTPUT_NOTES_COMMITMENT, FINAL_ACCOUNT_COMMITMENT, FEE_ASSET, tx_expiration_block_num]
     ///
     /// Where:
     /// - OUTPUT_NOTES_COMMITMENT is the commitment of the output notes.
     /// - FINAL_ACCOUNT_COMMITMENT is the final account commitment of the account that the
     ///   transaction is being executed against.
+    /// - FEE_ASSET is the fungible asset paid as the transaction fee, or an empty word if the
+    ///   transaction did not pay a fee.
     /// - tx_expiration_block_num is the block height at which the transaction will become expired,
     ///   defined by the sum of the execution block ref and the transaction's block expiration delta
     ///   (if set during transaction execution).
     ///
     /// # Errors
     /// Returns an error if:
-    /// - Words 3 and 4 on the stack are not 0.
+    /// - The fee asset is neither an empty word nor a valid fungible asset.
+    /// - The last 3 elements on the stack are not 0.
     /// - Overflow addresses are not empty.
     pub fn parse_output_stack(
         stack: &StackOutputs,
-    ) -> Result<(Digest, Digest, BlockNumber), TransactionOutputError> {
+    ) -> Result<(Digest, Digest, Option<FungibleAsset>, BlockNumber), TransactionOutputError> {
         let output_notes_commitment = stack
             .get_stack_word(OUTPUT_NOTES_COMMITMENT_WORD_IDX * 4)
             .expect("first word missing")
@@ -291,9 +333,16 @@ impl TransactionKernel {
             .expect("second word missing")
             .into();
 
+        let fee = stack.get_stack_word(FEE_ASSET_WORD_IDX * 4).expect("third word missing");
+        let fee = if fee == EMPTY_WORD {
+            None
+        } else {
+            Some(FungibleAsset::try_from(fee).map_err(TransactionOutputError::FeeAssetInvalid)?)
+        };
+
         let expiration_block_num = stack
             .get_stack_item(EXPIRATION_BLOCK_ELEMENT_IDX)
-            .expect("element on index 8 missing");
+            .expect("element on index 12 missing");
 
         let expiration_block_num = u32::try_from(expiration_block_num.as_int())
             .map_err(|_| {
@@ -303,13 +352,16 @@ impl TransactionKernel {
             })?
             .into();
 
-        if stack.get_stack_word(12).expect("fourth word missing") != EMPTY_WORD {
+        if (EXPIRATION_BLOCK_ELEMENT_IDX + 1..16)
+            .any(|idx| stack.get_stack_item(idx).expect("element missing") != ZERO)
+        {
             return Err(TransactionOutputError::OutputStackInvalid(
-                "Fourth word on output stack should consist only of ZEROs".into(),
+                "Elements following the expiration block number on the output stack should be ZEROs"
+                    .into(),
             ));
         }
 
-        Ok((final_account_commitment, output_notes_commitment, expiration_block_num))
+        Ok((final_account_commitment, output_notes_commitment, fee, expiration_block_num))
     }
 
     // TRANSACTION OUTPUT PARSER
@@ -319,12 +371,14 @@ impl TransactionKernel {
     ///
     /// The output stack is expected to be arrange as follows:
     ///
-    /// Stack: [OUTPUT_NOTES_COMMITMENT, FINAL_ACCOUNT_COMMITMENT, tx_expiration_block_num]
+    /// Stack: [OUTPUT_NOTES_COMMITMENT, FINAL_ACCOUNT_COMMITMENT, FEE_ASSET, tx_expiration_block_num]
     ///
     /// Where:
     /// - OUTPUT_NOTES_COMMITMENT is the commitment of the output notes.
     /// - FINAL_ACCOUNT_COMMITMENT is the final account commitment of the account that the
     ///   transaction is being executed against.
+    /// - FEE_ASSET is the fungible asset paid as the transaction fee, or an empty word if the
+    ///   transaction did not pay a fee.
     /// - tx_expiration_block_num is the block height at which the transaction will become expired,
     ///   defined by the sum of the execution block ref and the transaction's block expiration delta
     ///   (if set during transaction execution).
@@ -337,7 +391,7 @@ impl TransactionKernel {
         adv_map: &AdviceMap,
         output_notes: Vec<OutputNote>,
     ) -> Result<TransactionOutputs, TransactionOutputError> {
-        let (final_account_commitment, output_notes_commitment, expiration_block_num) =
+        let (final_account_commitment, output_notes_commitment, fee, expiration_block_num) =
             Self::parse_output_stack(stack)?;
 
         // parse final account state
@@ -359,6 +413,7 @@ impl TransactionKernel {
         Ok(TransactionOutputs {
             account,
             output_notes,
+            fee,
             expiration_block_num,
         })
     }
@@ -370,6 +425,8 @@ impl TransactionKernel {
         include_bytes!(concat!(env!("OUT_DIR"), "/assets/kernels/kernel_library.masl"));
 
     pub fn kernel_as_library() -> miden_objects::assembly::Library {
+        use miden_objects::utils::serde::Deserializable;
+
         miden_objects::assembly::Library::read_from_bytes(Self::KERNEL_TESTING_LIB_BYTES)
             .expect("failed to deserialize transaction kernel library")
     }
@@ -382,14 +439,9 @@ impl TransactionKernel {
     /// the kernel binary (`main.masm`) include this code, it is not exposed explicitly. By adding
     /// it separately, we can expose procedures from `/lib` and test them individually.
     pub fn testing_assembler() -> Assembler {
-        let source_manager = Arc::new(DefaultSourceManager::default());
         let kernel_library = Self::kernel_as_library();
 
-        Assembler::with_kernel(source_manager, Self::kernel())
-            .with_library(StdLibrary::default())
-            .expect("failed to load std-lib")
-            .with_library(MidenLib::default())
-            .expect("failed to load miden-lib")
+        Self::assembler()
             .with_library(kernel_library)
             .expect("failed to load kernel library (/lib)")
     }
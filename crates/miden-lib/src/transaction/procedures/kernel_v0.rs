@@ -1,48 +1,212 @@
-//! This file is generated by build.rs, do not modify
+// This file is generated by build.rs, do not modify
 
 use miden_objects::{digest, Digest};
 
 // KERNEL V0 PROCEDURES
 // ================================================================================================
 
+/// Dynamically executed procedures of the kernel 0, with the offset of each procedure as its
+/// discriminant.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum KernelProcedure {
+    /// `account_get_initial_commitment`
+    AccountGetInitialCommitment = 0,
+    /// `account_get_current_commitment`
+    AccountGetCurrentCommitment = 1,
+    /// `account_get_id`
+    AccountGetId = 2,
+    /// `account_get_nonce`
+    AccountGetNonce = 3,
+    /// `account_incr_nonce`
+    AccountIncrNonce = 4,
+    /// `account_get_code_commitment`
+    AccountGetCodeCommitment = 5,
+    /// `account_get_procedure_info`
+    AccountGetProcedureInfo = 6,
+    /// `account_get_storage_commitment`
+    AccountGetStorageCommitment = 7,
+    /// `account_get_item`
+    AccountGetItem = 8,
+    /// `account_set_item`
+    AccountSetItem = 9,
+    /// `account_get_map_item`
+    AccountGetMapItem = 10,
+    /// `account_get_map_item_with_presence`
+    AccountGetMapItemWithPresence = 11,
+    /// `account_set_map_item`
+    AccountSetMapItem = 12,
+    /// `account_get_vault_root`
+    AccountGetVaultRoot = 13,
+    /// `account_add_asset`
+    AccountAddAsset = 14,
+    /// `account_remove_asset`
+    AccountRemoveAsset = 15,
+    /// `account_get_balance`
+    AccountGetBalance = 16,
+    /// `account_has_non_fungible_asset`
+    AccountHasNonFungibleAsset = 17,
+    /// `faucet_mint_asset`
+    FaucetMintAsset = 18,
+    /// `faucet_burn_asset`
+    FaucetBurnAsset = 19,
+    /// `faucet_get_total_fungible_asset_issuance`
+    FaucetGetTotalFungibleAssetIssuance = 20,
+    /// `faucet_is_non_fungible_asset_issued`
+    FaucetIsNonFungibleAssetIssued = 21,
+    /// `note_get_assets_info`
+    NoteGetAssetsInfo = 22,
+    /// `note_add_asset`
+    NoteAddAsset = 23,
+    /// `note_get_serial_number`
+    NoteGetSerialNumber = 24,
+    /// `note_get_inputs_commitment`
+    NoteGetInputsCommitment = 25,
+    /// `note_get_inputs`
+    NoteGetInputs = 26,
+    /// `note_get_sender`
+    NoteGetSender = 27,
+    /// `note_get_script_root`
+    NoteGetScriptRoot = 28,
+    /// `tx_create_note`
+    TxCreateNote = 29,
+    /// `tx_create_note_with_sender_commitment`
+    TxCreateNoteWithSenderCommitment = 30,
+    /// `tx_get_input_notes_commitment`
+    TxGetInputNotesCommitment = 31,
+    /// `tx_get_output_notes_commitment`
+    TxGetOutputNotesCommitment = 32,
+    /// `tx_get_num_input_notes`
+    TxGetNumInputNotes = 33,
+    /// `tx_get_input_note_id`
+    TxGetInputNoteId = 34,
+    /// `tx_get_block_commitment`
+    TxGetBlockCommitment = 35,
+    /// `tx_get_block_number`
+    TxGetBlockNumber = 36,
+    /// `tx_get_block_timestamp`
+    TxGetBlockTimestamp = 37,
+    /// `tx_get_historical_block_commitment`
+    TxGetHistoricalBlockCommitment = 38,
+    /// `tx_start_foreign_context`
+    TxStartForeignContext = 39,
+    /// `tx_end_foreign_context`
+    TxEndForeignContext = 40,
+    /// `tx_get_expiration_delta`
+    TxGetExpirationDelta = 41,
+    /// `tx_update_expiration_block_num`
+    TxUpdateExpirationBlockNum = 42,
+    /// `tx_get_fee`
+    TxGetFee = 43,
+    /// `tx_set_fee`
+    TxSetFee = 44,
+    /// `tx_get_block_commitment_from_mmr_proof`
+    TxGetBlockCommitmentFromMmrProof = 45,
+    /// `note_get_metadata`
+    NoteGetMetadata = 46,
+    /// `note_set_recipient`
+    NoteSetRecipient = 47,
+    /// `tx_get_block_randomness`
+    TxGetBlockRandomness = 48,
+}
+
+impl KernelProcedure {
+    /// All procedures of the kernel 0, sorted by their offset.
+    pub const ALL: [Self; 49] = [
+        Self::AccountGetInitialCommitment,
+        Self::AccountGetCurrentCommitment,
+        Self::AccountGetId,
+        Self::AccountGetNonce,
+        Self::AccountIncrNonce,
+        Self::AccountGetCodeCommitment,
+        Self::AccountGetProcedureInfo,
+        Self::AccountGetStorageCommitment,
+        Self::AccountGetItem,
+        Self::AccountSetItem,
+        Self::AccountGetMapItem,
+        Self::AccountGetMapItemWithPresence,
+        Self::AccountSetMapItem,
+        Self::AccountGetVaultRoot,
+        Self::AccountAddAsset,
+        Self::AccountRemoveAsset,
+        Self::AccountGetBalance,
+        Self::AccountHasNonFungibleAsset,
+        Self::FaucetMintAsset,
+        Self::FaucetBurnAsset,
+        Self::FaucetGetTotalFungibleAssetIssuance,
+        Self::FaucetIsNonFungibleAssetIssued,
+        Self::NoteGetAssetsInfo,
+        Self::NoteAddAsset,
+        Self::NoteGetSerialNumber,
+        Self::NoteGetInputsCommitment,
+        Self::NoteGetInputs,
+        Self::NoteGetSender,
+        Self::NoteGetScriptRoot,
+        Self::TxCreateNote,
+        Self::TxCreateNoteWithSenderCommitment,
+        Self::TxGetInputNotesCommitment,
+        Self::TxGetOutputNotesCommitment,
+        Self::TxGetNumInputNotes,
+        Self::TxGetInputNoteId,
+        Self::TxGetBlockCommitment,
+        Self::TxGetBlockNumber,
+        Self::TxGetBlockTimestamp,
+        Self::TxGetHistoricalBlockCommitment,
+        Self::TxStartForeignContext,
+        Self::TxEndForeignContext,
+        Self::TxGetExpirationDelta,
+        Self::TxUpdateExpirationBlockNum,
+        Self::TxGetFee,
+        Self::TxSetFee,
+        Self::TxGetBlockCommitmentFromMmrProof,
+        Self::NoteGetMetadata,
+        Self::NoteSetRecipient,
+        Self::TxGetBlockRandomness,
+    ];
+}
+
 /// Hashes of all dynamically executed procedures from the kernel 0.
-pub const KERNEL0_PROCEDURES: [Digest; 36] = [
+pub const KERNEL0_PROCEDURES: [Digest; 49] = [
     // account_get_initial_commitment
     digest!("0x920898348bacd6d98a399301eb308478fd32b32eab019a5a6ef7a6b44abb61f6"),
     // account_get_current_commitment
-    digest!("0x1c294b4d92eedce11252353164d712200adfa5cc443ac3667dd86ef33b303c8c"),
+    digest!("0x2ed67f03895bf6aa1c9c2834b048d8fb01e3fc5097e45940ce5aa73d32ba62ad"),
     // account_get_id
     digest!("0x1a5583b3a4011d0ca83ac9633fc12b0c6ec2cba03ee8c5f380ac69fc4f767075"),
     // account_get_nonce
     digest!("0xf1dfe3621b9147803b6668352915be7fb7f85df476c9d18052272270a854fa75"),
     // account_incr_nonce
-    digest!("0x726357558767a56aae315c4c8aa406cdf57ad400832222622eec58dc42cce406"),
+    digest!("0x6b3cbe50bf450e0d9e3c5e05bd6d81df32fa624f9c3d2b064fe348d5612f8f74"),
     // account_get_code_commitment
-    digest!("0xbab83830e881bdbee08fa1506d651388c20ebb0cadfb6794189542dd257841aa"),
+    digest!("0xd619184c399dd03a608f62cb9280ff5f4c7984cbc8fccbf257b5170fadbaf93a"),
+    // account_get_procedure_info
+    digest!("0x2d46468037247953569e9efa4311e9d48ba9df5f819f4324f5c972af8af99222"),
     // account_get_storage_commitment
-    digest!("0xe4cd056f358b2438c3b378a31650ddbbf8d631f135bf966e01a2b57eebd458a5"),
+    digest!("0x389737672f844c5a0f1c1e26a044f394530169a43d65a70dfb32ae75dc345f5a"),
     // account_get_item
-    digest!("0xdd8f439cb6f7f3edcda15c9c339e7c2b2dada2fc94952a8199081a197aeebb7a"),
+    digest!("0x26e24bf0833b00c0b163c8a912c232bce2baa2328fcc402f8489afc94b2a4f12"),
     // account_set_item
-    digest!("0x61104ec016c3ed9b49aee53650ddde9e984a72e4c4e13001cbf98b9cef426758"),
+    digest!("0xd8ccecec6d0bba34d77509555092787d29ff97ff34ba23df45c0cd2268fbbe10"),
     // account_get_map_item
-    digest!("0x21237825d10004f77d3e7b32aee9052b519752fd03f839c19440e2010f73132e"),
+    digest!("0x94abeaaf8e48d75ed623aea05282a5bacdbf6c96bc4a89c8523b3e859bb0339d"),
+    // account_get_map_item_with_presence
+    digest!("0x4bad79f3ca9d528c4f9bdd93a050b73a3a60070842a208b4d83ba2850446d3f1"),
     // account_set_map_item
-    digest!("0x876168292d11aea0e2886ee6d7f9e723a95ec3aa4e467e6f46c898567bfc3604"),
+    digest!("0x02ddde4ce316580d4fcad59a0cc16376d850ed17dd522a87a5f4a92b0f9e04c3"),
     // account_get_vault_root
     digest!("0x279b4a9e5adca07f01cadf8ecc1303fa3c670003a7a4e69f09506b070c4023df"),
     // account_add_asset
-    digest!("0x7ae966cc7da065c7d06248ccdc5cf319eddba55c063900fc97833f39870234f1"),
+    digest!("0x66650795da1097e6b47171c33a36a04af802b85c8725b225afb67ad86436b1be"),
     // account_remove_asset
-    digest!("0x50935b368b7843258ae86f6392df9346721b60dd9563d77a2d548da8ab81e44c"),
+    digest!("0x437ffd03347e6f7a87a38e0912a875eeeff34682c3e65e420a2a91d3369071db"),
     // account_get_balance
     digest!("0xc3385953bc66def5211f53a3c44de8facfb4060abbb1c9708859c314268989e8"),
     // account_has_non_fungible_asset
     digest!("0x4fea67ed25474d5494a23c5e1e06a93f8aa140d0a673c6e140e0d4f1dd8bd835"),
     // faucet_mint_asset
-    digest!("0x836ecba4b7caa770b24ffa51115e2c08724ecb7f0854ee3a94848bec5b0ee2ce"),
+    digest!("0x1288f7a1511d04a42467b7f57e6309378cdebd0403bd0451341f6094f243f275"),
     // faucet_burn_asset
-    digest!("0x37e6b7c80f478c11ea7f12c065013c4bc3a09100fd6ecc4893e17d0abd675254"),
+    digest!("0x301e36ec6c97d56cc9257309bf16c26ce1586c4bbd4588adc5f28b98257bc6b4"),
     // faucet_get_total_fungible_asset_issuance
     digest!("0xd2ee4bd330f989165ee2be0f121a4db916f95e58f6fd2d040d57672f2f0cef63"),
     // faucet_is_non_fungible_asset_issued
@@ -50,33 +214,109 @@ pub const KERNEL0_PROCEDURES: [Digest; 36] = [
     // note_get_assets_info
     digest!("0x34e4f1ea83eb4342ab8f5acec89962b2ab4b56d9c631e807d8e4dc8efd270bf2"),
     // note_add_asset
-    digest!("0x999a790c639966f85b4420f7b00a5965db163e7a69fa288b059f79162f3f81a8"),
+    digest!("0x35e6fd69d4248167d2842a5f759613cd104e8c32de21f2288fee849d5bb92f86"),
     // note_get_serial_number
     digest!("0x59b3ea650232049bb333867841012c3694bd557fa199cd65655c0006edccc3ab"),
     // note_get_inputs_commitment
     digest!("0x9d4af62050a2024dbd9e1967f2ba9b81f7801e8eb704494498904d3affd74a55"),
+    // note_get_inputs
+    digest!("0xff347c7cb2a59c5a7aa0ea0ae1f519eee84c591610f4a729b7fa8c94233266be"),
     // note_get_sender
     digest!("0x01172024b89517e5da80121cedfa6c19dd2ace0fe4d09a8cde6605103fe62952"),
     // note_get_script_root
     digest!("0x66fb188ca538d9f8bc6fd1aedbd19336bf6e3a1c0ae67b5f725cbc9cb4f7867f"),
     // tx_create_note
-    digest!("0xb0ce6d0bd9dfa8b77408fac615eb570381d28769914e7ca0475ba5dd00a2283a"),
+    digest!("0x7bd07766fd425aad1a8d2e21c1c352f2038511f3ad347997cabf7b90d1d5d700"),
+    // tx_create_note_with_sender_commitment
+    digest!("0x8b7d4f25255fc8a70ae7d7f05bc13f917642d35378fd8bda9c820f44e714bf3a"),
     // tx_get_input_notes_commitment
     digest!("0x16cb840dc9131e2fd2b3e83b8d796eb466722ae36f29f27b4b053f1bee2ed473"),
     // tx_get_output_notes_commitment
     digest!("0x0c241940512d130ad36c70c4e946285cb5841f2655c4fe12df001cb834256a29"),
+    // tx_get_num_input_notes
+    digest!("0xfcc186d4b65c584f3126dda1460b01eef977efd76f9e36f972554af28e33c685"),
+    // tx_get_input_note_id
+    digest!("0x9d81c45766565b63ca18b2a837a47620f5d5ce108d3171e2395ae959514cee6a"),
     // tx_get_block_commitment
     digest!("0xe474b491a64d222397fcf83ee5db7b048061988e5e83ce99b91bae6fd75a3522"),
     // tx_get_block_number
     digest!("0x297797dff54b8108dd2df254b95d43895d3f917ab10399efc62adaf861c905ae"),
     // tx_get_block_timestamp
     digest!("0x786863e6dbcd5026619afd3831b7dcbf824cda54950b0e0724ebf9d9370ec723"),
+    // tx_get_historical_block_commitment
+    digest!("0xa4da41dfbc0ed9080104e667b29e3d3c851ce55e5c25cee102aa18dbee66c3ae"),
     // tx_start_foreign_context
-    digest!("0x6e7ab409b5661e1d03dc73bd7b6e32ca7785a983e49ab2d056e9c786620e5c20"),
+    digest!("0x7fe77267ef562471ca563e00511b15944811c1d3ed5c596e6a17e7a3909b932c"),
     // tx_end_foreign_context
     digest!("0x90a107168d81c1c0c23890e61fb7910a64b4711afd0bf8c3098d74737e4853ba"),
     // tx_get_expiration_delta
     digest!("0x756352beed1624a42d4540c434a4faa986d6d9d08ef8437699d9086fcd9ad9e7"),
     // tx_update_expiration_block_num
     digest!("0x11ca0c8662d20e6b05fbff4a20423bfa52595862b6c7c5c5ef1cc0a917e4cb62"),
+    // tx_get_fee
+    digest!("0x8187c2bd545a25f8c80a8796b54ca041ca65ab91fcc4251b3b786b2d79d8ee2a"),
+    // tx_set_fee
+    digest!("0x2c5e0348a86357cafcc46f0be55ce17b0a7d0faab5137184bd008886bf432532"),
+    // tx_get_block_commitment_from_mmr_proof
+    digest!("0xe89575446b5bb4777c1c307142cdfbeeac8c8ccbae6a310e1f010dcddb1a429b"),
+    // note_get_metadata
+    digest!("0x739cb6e36dfbb344c8408ddd8ae3c666a45180a1dbf71621eebe4a028d87b642"),
+    // note_set_recipient
+    digest!("0x3651bb3fa1ef9c5f7b8f4041a9a62b0ac4cb0cfc73ba1874956e4d8112caea53"),
+    // tx_get_block_randomness
+    digest!("0xfef9573d03f799a9a23ba17da3f52d41608306270dc9f855b1556f377c8f8469"),
+];
+
+/// Names of all dynamically executed procedures from the kernel 0, in the same order as
+/// [KERNEL0_PROCEDURES].
+pub const KERNEL0_PROCEDURE_NAMES: [&str; 49] = [
+    "account_get_initial_commitment",
+    "account_get_current_commitment",
+    "account_get_id",
+    "account_get_nonce",
+    "account_incr_nonce",
+    "account_get_code_commitment",
+    "account_get_procedure_info",
+    "account_get_storage_commitment",
+    "account_get_item",
+    "account_set_item",
+    "account_get_map_item",
+    "account_get_map_item_with_presence",
+    "account_set_map_item",
+    "account_get_vault_root",
+    "account_add_asset",
+    "account_remove_asset",
+    "account_get_balance",
+    "account_has_non_fungible_asset",
+    "faucet_mint_asset",
+    "faucet_burn_asset",
+    "faucet_get_total_fungible_asset_issuance",
+    "faucet_is_non_fungible_asset_issued",
+    "note_get_assets_info",
+    "note_add_asset",
+    "note_get_serial_number",
+    "note_get_inputs_commitment",
+    "note_get_inputs",
+    "note_get_sender",
+    "note_get_script_root",
+    "tx_create_note",
+    "tx_create_note_with_sender_commitment",
+    "tx_get_input_notes_commitment",
+    "tx_get_output_notes_commitment",
+    "tx_get_num_input_notes",
+    "tx_get_input_note_id",
+    "tx_get_block_commitment",
+    "tx_get_block_number",
+    "tx_get_block_timestamp",
+    "tx_get_historical_block_commitment",
+    "tx_start_foreign_context",
+    "tx_end_foreign_context",
+    "tx_get_expiration_delta",
+    "tx_update_expiration_block_num",
+    "tx_get_fee",
+    "tx_set_fee",
+    "tx_get_block_commitment_from_mmr_proof",
+    "note_get_metadata",
+    "note_set_recipient",
+    "tx_get_block_randomness",
 ];
@@ -1,14 +1,57 @@
 use alloc::vec::Vec;
 
-use kernel_v0::KERNEL0_PROCEDURES;
+pub use kernel_v0::KernelProcedure;
+use kernel_v0::{KERNEL0_PROCEDURE_NAMES, KERNEL0_PROCEDURES};
 use miden_objects::{Digest, Felt, Hasher};
 
 use super::TransactionKernel;
 
 // Include kernel v0 procedure roots generated in build.rs
+#[cfg(not(custom_kernel_params))]
 #[rustfmt::skip]
 mod kernel_v0;
 
+// A kernel built with custom kernel parameters has different procedure roots, so the procedure
+// roots generated for it in build.rs are included from OUT_DIR instead
+#[cfg(custom_kernel_params)]
+#[rustfmt::skip]
+mod kernel_v0 {
+    include!(concat!(env!("OUT_DIR"), "/kernel_v0.rs"));
+}
+
+// KERNEL PROCEDURE
+// ================================================================================================
+
+impl KernelProcedure {
+    /// Returns the offset of the procedure, i.e. the value which must be on top of the stack when
+    /// the procedure is invoked via `syscall.exec_kernel_proc`.
+    pub const fn offset(&self) -> usize {
+        *self as usize
+    }
+
+    /// Returns the name of the procedure, e.g. `account_get_item`.
+    pub const fn name(&self) -> &'static str {
+        KERNEL0_PROCEDURE_NAMES[self.offset()]
+    }
+
+    /// Returns the MAST root of the procedure.
+    pub const fn digest(&self) -> Digest {
+        KERNEL0_PROCEDURES[self.offset()]
+    }
+
+    /// Returns the procedure with the provided MAST root, or `None` if the kernel has no such
+    /// procedure.
+    pub fn from_digest(digest: Digest) -> Option<Self> {
+        Self::ALL.into_iter().find(|procedure| procedure.digest() == digest)
+    }
+
+    /// Returns the procedure with the provided name, or `None` if the kernel has no such
+    /// procedure.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|procedure| procedure.name() == name)
+    }
+}
+
 // TRANSACTION KERNEL
 // ================================================================================================
 
@@ -22,6 +65,11 @@ impl TransactionKernel {
     /// Array of all available kernels.
     pub const PROCEDURES: [&'static [Digest]; Self::NUM_VERSIONS] = [&KERNEL0_PROCEDURES];
 
+    /// Array of the procedure names of all available kernels, in the same order as
+    /// [Self::PROCEDURES].
+    pub const PROCEDURE_NAMES: [&'static [&'static str]; Self::NUM_VERSIONS] =
+        [&KERNEL0_PROCEDURE_NAMES];
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -41,8 +89,36 @@ impl TransactionKernel {
         Hasher::hash_elements(&Self::procedures_as_elements(kernel_version))
     }
 
-    /// Computes a hash from all kernel commitments.
+    /// Computes a hash from the commitments of all kernel versions.
     pub fn kernel_commitment() -> Digest {
-        Hasher::hash_elements(&[Self::commitment(0).as_elements()].concat())
+        let kernel_commitments: Vec<Felt> = (0..Self::NUM_VERSIONS)
+            .flat_map(|version| Self::commitment(version as u8).as_elements().to_vec())
+            .collect();
+        Hasher::hash_elements(&kernel_commitments)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kernel_procedures_match_procedure_table() {
+        assert_eq!(KernelProcedure::ALL.len(), KERNEL0_PROCEDURES.len());
+
+        for (offset, procedure) in KernelProcedure::ALL.into_iter().enumerate() {
+            assert_eq!(procedure.offset(), offset);
+            assert_eq!(procedure.name(), KERNEL0_PROCEDURE_NAMES[offset]);
+            assert_eq!(KernelProcedure::from_name(procedure.name()), Some(procedure));
+        }
+
+        assert_eq!(
+            KernelProcedure::from_digest(KernelProcedure::AccountGetItem.digest()),
+            Some(KernelProcedure::AccountGetItem)
+        );
+        assert_eq!(KernelProcedure::from_name("exec_kernel_proc"), None);
     }
 }
@@ -0,0 +1,323 @@
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use miden_objects::{
+    Felt, MAX_OUTPUT_NOTES_PER_TX, TransactionScriptError, Word,
+    account::AccountId,
+    assembly::Library,
+    asset::Asset,
+    note::{NoteAssets, NoteDetails, NoteId, NoteType},
+    transaction::{TransactionArgs, TransactionScript},
+    vm::AdviceMap,
+};
+
+use super::{TransactionKernel, TransactionRequestError};
+use crate::{
+    account::interface::{AccountInterface, AccountInterfaceError},
+    note::utils::derive_serial_num,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The maximum number of inputs which can be passed to a single account procedure call.
+pub const MAX_ACCOUNT_CALL_INPUTS: usize = 16;
+
+// TRANSACTION REQUEST
+// ================================================================================================
+
+/// A description of a routine transaction, ready to be passed to a transaction executor.
+///
+/// A [TransactionRequest] is created by a [TransactionRequestBuilder] and contains the IDs of the
+/// notes the transaction consumes, the [TransactionArgs] (including the generated
+/// [TransactionScript]) to execute the transaction with and the details of the notes the
+/// transaction is expected to create.
+#[derive(Clone, Debug)]
+pub struct TransactionRequest {
+    input_note_ids: Vec<NoteId>,
+    tx_args: TransactionArgs,
+    expected_output_notes: Vec<NoteDetails>,
+}
+
+impl TransactionRequest {
+    /// Returns the IDs of the notes consumed by the transaction, in the order they were added.
+    pub fn input_note_ids(&self) -> &[NoteId] {
+        &self.input_note_ids
+    }
+
+    /// Returns the arguments the transaction must be executed with.
+    pub fn tx_args(&self) -> &TransactionArgs {
+        &self.tx_args
+    }
+
+    /// Returns the generated transaction script.
+    pub fn tx_script(&self) -> &TransactionScript {
+        self.tx_args
+            .tx_script()
+            .expect("transaction request should always contain a transaction script")
+    }
+
+    /// Returns the details of the notes the transaction is expected to create, in the order the
+    /// payments were added.
+    pub fn expected_output_notes(&self) -> &[NoteDetails] {
+        &self.expected_output_notes
+    }
+
+    /// Consumes the request and returns its input note IDs, transaction arguments and expected
+    /// output notes.
+    pub fn into_parts(self) -> (Vec<NoteId>, TransactionArgs, Vec<NoteDetails>) {
+        (self.input_note_ids, self.tx_args, self.expected_output_notes)
+    }
+}
+
+// TRANSACTION REQUEST BUILDER
+// ================================================================================================
+
+/// A builder for [TransactionRequest]s which assembles the transaction script for routine
+/// operations of an account.
+///
+/// The builder collects the notes to consume, the P2ID payments to make and the calls to the
+/// account's own procedures, and generates a transaction script which:
+/// - calls the account procedures in the order they were added,
+/// - creates a P2ID note for each payment using the `send_note` procedures of the account
+///   interface (see [`AccountInterface::build_send_notes_script`]),
+/// - authenticates the transaction using the authentication schemes of the account.
+///
+/// The serial number of the payment note at index `i` is derived from the serial number seed
+/// using [`derive_serial_num`](crate::note::utils::derive_serial_num). If an expiration delta is
+/// set, it is applied via [`TransactionArgs::with_expiration_delta`] rather than through the
+/// transaction script.
+///
+/// # Example
+///
+/// The transaction script generated for one account call and one payment from a basic wallet
+/// with RpoFalcon512 authentication:
+///
+/// ```masm
+/// begin
+///     push.{call inputs}
+///     call.{procedure} drop...
+///
+///     push.{note information}
+///     call.::miden::contracts::wallets::basic::create_note
+///
+///     push.{note asset}
+///     call.::miden::contracts::wallets::basic::move_asset_to_note dropw
+///     dropw dropw dropw drop
+///
+///     call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+/// end
+/// ```
+pub struct TransactionRequestBuilder {
+    account_interface: AccountInterface,
+    input_notes: Vec<(NoteId, Option<Word>)>,
+    payments: Vec<(AccountId, Asset)>,
+    account_calls: Vec<(String, Vec<Felt>)>,
+    libraries: Vec<Library>,
+    note_type: NoteType,
+    serial_num_seed: Word,
+    expiration_delta: Option<u16>,
+    in_debug_mode: bool,
+}
+
+impl TransactionRequestBuilder {
+    /// Returns a new [TransactionRequestBuilder] for the account with the provided interface.
+    ///
+    /// The serial numbers of the payment notes are derived from `serial_num_seed`. Payment notes
+    /// are private unless a different note type is set via [`Self::with_note_type`].
+    pub fn new(account_interface: AccountInterface, serial_num_seed: Word) -> Self {
+        Self {
+            account_interface,
+            input_notes: Vec::new(),
+            payments: Vec::new(),
+            account_calls: Vec::new(),
+            libraries: Vec::new(),
+            note_type: NoteType::Private,
+            serial_num_seed,
+            expiration_delta: None,
+            in_debug_mode: false,
+        }
+    }
+
+    /// Adds a note to be consumed by the transaction, optionally with the note args which are put
+    /// onto the stack before the note script is executed.
+    pub fn with_input_note(mut self, note_id: NoteId, note_args: Option<Word>) -> Self {
+        self.input_notes.push((note_id, note_args));
+        self
+    }
+
+    /// Adds a payment of `asset` to `target`, sent in a P2ID note.
+    pub fn with_payment(mut self, target: AccountId, asset: Asset) -> Self {
+        self.payments.push((target, asset));
+        self
+    }
+
+    /// Adds a call to a procedure of the account, e.g. `::miden::contracts::wallets::basic::...`.
+    ///
+    /// The inputs are put onto the stack before the call such that `inputs[0]` is on top of the
+    /// stack. The outputs of the procedure are dropped.
+    ///
+    /// If the procedure is not part of the miden library, the library containing it must be
+    /// provided via [`Self::with_library`].
+    pub fn with_account_call(mut self, procedure: impl Into<String>, inputs: Vec<Felt>) -> Self {
+        self.account_calls.push((procedure.into(), inputs));
+        self
+    }
+
+    /// Adds a library to the assembler used to compile the transaction script.
+    pub fn with_library(mut self, library: Library) -> Self {
+        self.libraries.push(library);
+        self
+    }
+
+    /// Sets the type of the created payment notes.
+    pub fn with_note_type(mut self, note_type: NoteType) -> Self {
+        self.note_type = note_type;
+        self
+    }
+
+    /// Sets the number of blocks after the reference block at which the transaction expires.
+    pub fn with_expiration_delta(mut self, expiration_delta: u16) -> Self {
+        self.expiration_delta = Some(expiration_delta);
+        self
+    }
+
+    /// Sets whether the transaction script is compiled in debug mode.
+    pub fn with_debug_mode(mut self, in_debug_mode: bool) -> Self {
+        self.in_debug_mode = in_debug_mode;
+        self
+    }
+
+    /// Builds the [TransactionRequest].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - the same input note is added more than once.
+    /// - the number of payments exceeds [`MAX_OUTPUT_NOTES_PER_TX`].
+    /// - any of the account calls has more than [`MAX_ACCOUNT_CALL_INPUTS`] inputs.
+    /// - any of the payment notes cannot be constructed, or the account interface does not
+    ///   support sending notes.
+    /// - the transaction script fails to compile.
+    pub fn build(self) -> Result<TransactionRequest, TransactionRequestError> {
+        let mut note_args = BTreeMap::new();
+        let mut input_note_ids = Vec::with_capacity(self.input_notes.len());
+        for (note_id, args) in self.input_notes {
+            if input_note_ids.contains(&note_id) {
+                return Err(TransactionRequestError::DuplicateInputNote(note_id));
+            }
+            if let Some(args) = args {
+                note_args.insert(note_id, args);
+            }
+            input_note_ids.push(note_id);
+        }
+
+        if self.payments.len() > MAX_OUTPUT_NOTES_PER_TX {
+            return Err(TransactionRequestError::AccountInterface(
+                AccountInterfaceError::TooManyOutputNotes(self.payments.len()),
+            ));
+        }
+
+        let mut output_notes = Vec::with_capacity(self.payments.len());
+        let mut expected_output_notes = Vec::with_capacity(self.payments.len());
+        for (index, (target, asset)) in self.payments.iter().enumerate() {
+            let assets = NoteAssets::new(vec![*asset]).map_err(|err| {
+                TransactionRequestError::AccountInterface(AccountInterfaceError::InvalidOutputNote(
+                    err,
+                ))
+            })?;
+            let (output_note, details) = self
+                .account_interface
+                .build_p2id_output_note(
+                    *target,
+                    assets,
+                    self.note_type,
+                    derive_serial_num(self.serial_num_seed, index as u32),
+                )
+                .map_err(TransactionRequestError::AccountInterface)?;
+
+            output_notes.push(output_note);
+            expected_output_notes.push(details);
+        }
+
+        let mut account_calls_section = String::new();
+        for (procedure, inputs) in self.account_calls.iter() {
+            account_calls_section.push_str(&build_account_call(procedure, inputs)?);
+        }
+
+        let note_creation_section = if output_notes.is_empty() {
+            String::new()
+        } else {
+            self.account_interface
+                .build_create_notes_section(&output_notes)
+                .map_err(TransactionRequestError::AccountInterface)?
+        };
+
+        let script = format!(
+            "begin\n{}\n{}\n{}\nend",
+            account_calls_section,
+            note_creation_section,
+            self.account_interface.build_tx_authentication_section()
+        );
+
+        let mut assembler = TransactionKernel::assembler().with_debug_mode(self.in_debug_mode);
+        for library in self.libraries.iter() {
+            assembler = assembler.with_library(library).map_err(|err| {
+                TransactionRequestError::InvalidTransactionScript(
+                    TransactionScriptError::AssemblyError(err),
+                )
+            })?;
+        }
+        let tx_script = TransactionScript::compile(script, [], assembler)
+            .map_err(TransactionRequestError::InvalidTransactionScript)?;
+
+        let mut tx_args =
+            TransactionArgs::new(Some(tx_script), Some(note_args), AdviceMap::default());
+        tx_args.extend_output_note_recipients(expected_output_notes.iter());
+        if let Some(expiration_delta) = self.expiration_delta {
+            tx_args = tx_args.with_expiration_delta(expiration_delta);
+        }
+
+        Ok(TransactionRequest {
+            input_note_ids,
+            tx_args,
+            expected_output_notes,
+        })
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the code which calls the specified account procedure with the provided inputs and
+/// drops its outputs, leaving the depth of the stack unchanged.
+fn build_account_call(procedure: &str, inputs: &[Felt]) -> Result<String, TransactionRequestError> {
+    if inputs.len() > MAX_ACCOUNT_CALL_INPUTS {
+        return Err(TransactionRequestError::TooManyAccountCallInputs {
+            procedure: procedure.into(),
+            num_inputs: inputs.len(),
+        });
+    }
+
+    let mut code = String::new();
+    if !inputs.is_empty() {
+        // the last pushed element ends up on top of the stack
+        let values: Vec<String> = inputs.iter().rev().map(|input| input.to_string()).collect();
+        code.push_str(&format!("push.{}\n", values.join(".")));
+    }
+
+    code.push_str(&format!("call.{procedure}"));
+
+    // the called procedure returns 16 elements, so the stack grows by the number of inputs
+    for _ in 0..inputs.len() / 4 {
+        code.push_str(" dropw");
+    }
+    for _ in 0..inputs.len() % 4 {
+        code.push_str(" drop");
+    }
+    code.push('\n');
+
+    Ok(code)
+}
@@ -0,0 +1,367 @@
+use miden_objects::{
+    Felt, Word, ZERO,
+    account::AccountId,
+    asset::{Asset, FungibleAsset},
+    note::{
+        NoteAssets, NoteDetails, NoteExecutionHint, NoteExecutionMode, NoteRecipient, NoteTag,
+        NoteType,
+    },
+    transaction::{
+        ScriptArgType, TransactionScript, TransactionScriptArgs, TransactionScriptArgsBuilder,
+    },
+    utils::sync::LazyLock,
+    vm::Program,
+};
+
+use super::{TransactionKernel, TransactionScriptTemplateError};
+use crate::note::utils::build_p2id_recipient;
+
+// TEMPLATE SOURCES
+// ================================================================================================
+
+/// Arguments of the notes created by the templates, read from the advice stack as
+/// `[tag, aux, note_type, execution_hint, RECIPIENT]`.
+const NOTE_ARGS_LAYOUT: [ScriptArgType; 5] = [
+    ScriptArgType::Word,
+    ScriptArgType::Felt,
+    ScriptArgType::Felt,
+    ScriptArgType::Felt,
+    ScriptArgType::U32,
+];
+
+const SEND_ASSET_LAYOUT: [ScriptArgType; 6] = [
+    ScriptArgType::Word,
+    ScriptArgType::Felt,
+    ScriptArgType::Felt,
+    ScriptArgType::Felt,
+    ScriptArgType::U32,
+    ScriptArgType::Asset,
+];
+
+const SEND_ASSET_SOURCE: &str = "
+begin
+    # load the template arguments onto the advice stack
+    push.1413827920.1279349829.0.0 adv.push_mapval dropw
+    # => [pad(16)]
+
+    padw adv_loadw adv_push.4
+    # => [tag, aux, note_type, execution_hint, RECIPIENT, pad(16)]
+
+    call.::miden::contracts::wallets::basic::create_note
+    # => [note_idx, pad(15), pad(8)]
+
+    padw adv_loadw
+    # => [ASSET, note_idx, pad(15), pad(8)]
+
+    call.::miden::contracts::wallets::basic::move_asset_to_note
+    # => [ASSET, note_idx, pad(11), pad(12)]
+
+    dropw dropw dropw
+    # => [pad(16)]
+
+    call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+end
+";
+
+const MINT_LAYOUT: [ScriptArgType; 6] = [
+    ScriptArgType::Word,
+    ScriptArgType::Felt,
+    ScriptArgType::Felt,
+    ScriptArgType::Felt,
+    ScriptArgType::U32,
+    ScriptArgType::U64,
+];
+
+const MINT_SOURCE: &str = "
+begin
+    # load the template arguments onto the advice stack
+    push.1413827920.1279349829.0.0 adv.push_mapval dropw
+    # => [pad(16)]
+
+    padw adv_loadw adv_push.5
+    # => [amount, tag, aux, note_type, execution_hint, RECIPIENT, pad(16)]
+
+    call.::miden::contracts::faucets::basic_fungible::distribute
+    # => [note_idx, pad(15), pad(9)]
+
+    dropw dropw drop
+    # => [pad(16)]
+
+    call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+end
+";
+
+const BURN_LAYOUT: [ScriptArgType; 1] = [ScriptArgType::Asset];
+
+const BURN_SOURCE: &str = "
+begin
+    # load the template arguments onto the advice stack
+    push.1413827920.1279349829.0.0 adv.push_mapval dropw
+    # => [pad(16)]
+
+    padw adv_loadw
+    # => [ASSET, pad(16)]
+
+    call.::miden::contracts::faucets::basic_fungible::burn
+    # => [pad(16), pad(4)]
+
+    dropw
+    # => [pad(16)]
+end
+";
+
+const CONSUME_AND_FORWARD_LAYOUT: [ScriptArgType; 6] = [
+    ScriptArgType::Word,
+    ScriptArgType::Felt,
+    ScriptArgType::Felt,
+    ScriptArgType::Felt,
+    ScriptArgType::U32,
+    ScriptArgType::AccountId,
+];
+
+const CONSUME_AND_FORWARD_SOURCE: &str = "
+use.miden::account
+use.miden::asset
+
+begin
+    # load the template arguments onto the advice stack
+    push.1413827920.1279349829.0.0 adv.push_mapval dropw
+    # => [pad(16)]
+
+    padw adv_loadw adv_push.4
+    # => [tag, aux, note_type, execution_hint, RECIPIENT, pad(16)]
+
+    call.::miden::contracts::wallets::basic::create_note
+    # => [note_idx, pad(15), pad(8)]
+
+    adv_push.2 dup.1 dup.1 exec.account::get_balance movdn.2
+    # => [faucet_id_prefix, faucet_id_suffix, balance, note_idx, pad(15), pad(8)]
+
+    exec.asset::build_fungible_asset
+    # => [ASSET, note_idx, pad(15), pad(8)]
+
+    call.::miden::contracts::wallets::basic::move_asset_to_note
+    # => [ASSET, note_idx, pad(11), pad(12)]
+
+    dropw dropw dropw
+    # => [pad(16)]
+
+    call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+end
+";
+
+static SEND_ASSET_PROGRAM: LazyLock<Program> = LazyLock::new(|| compile(SEND_ASSET_SOURCE));
+static MINT_PROGRAM: LazyLock<Program> = LazyLock::new(|| compile(MINT_SOURCE));
+static BURN_PROGRAM: LazyLock<Program> = LazyLock::new(|| compile(BURN_SOURCE));
+static CONSUME_AND_FORWARD_PROGRAM: LazyLock<Program> =
+    LazyLock::new(|| compile(CONSUME_AND_FORWARD_SOURCE));
+
+// TRANSACTION SCRIPT TEMPLATE
+// ================================================================================================
+
+/// Ready-made transaction scripts for routine operations of the accounts shipped with this crate.
+///
+/// The code of each template is fixed and compiled only once. A template is instantiated with
+/// typed parameters, which are encoded as [TransactionScriptArgs] under
+/// [`TransactionScriptTemplate::ARGS_KEY`] and read by the script from the advice map at runtime.
+/// Hence, all instances of a template share the same script root.
+///
+/// The following templates are available:
+/// - [`Self::SendAsset`]: a basic wallet sends an asset to a target account in a P2ID note.
+/// - [`Self::Mint`]: a basic fungible faucet mints an amount of its asset to a target account in a
+///   P2ID note.
+/// - [`Self::Burn`]: a basic fungible faucet burns an asset of one of the consumed notes.
+/// - [`Self::ConsumeAndForward`]: a basic wallet forwards its entire balance of a fungible asset,
+///   including the amounts received from the consumed notes, to a target account in a P2ID note.
+///
+/// The templates which are executed against basic wallets and the `Mint` template authenticate the
+/// transaction using the RpoFalcon512 authentication scheme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionScriptTemplate {
+    SendAsset,
+    Mint,
+    Burn,
+    ConsumeAndForward,
+}
+
+impl TransactionScriptTemplate {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The advice map key under which the arguments of a template instance are stored.
+    pub const ARGS_KEY: Word = [Felt::new(1413827920), Felt::new(1279349829), ZERO, ZERO];
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the MASM source code of this template.
+    pub fn source(&self) -> &'static str {
+        match self {
+            Self::SendAsset => SEND_ASSET_SOURCE,
+            Self::Mint => MINT_SOURCE,
+            Self::Burn => BURN_SOURCE,
+            Self::ConsumeAndForward => CONSUME_AND_FORWARD_SOURCE,
+        }
+    }
+
+    /// Returns the layout of the arguments of this template.
+    pub fn layout(&self) -> &'static [ScriptArgType] {
+        match self {
+            Self::SendAsset => &SEND_ASSET_LAYOUT,
+            Self::Mint => &MINT_LAYOUT,
+            Self::Burn => &BURN_LAYOUT,
+            Self::ConsumeAndForward => &CONSUME_AND_FORWARD_LAYOUT,
+        }
+    }
+
+    /// Returns the compiled program of this template.
+    pub fn program(&self) -> &'static Program {
+        match self {
+            Self::SendAsset => &SEND_ASSET_PROGRAM,
+            Self::Mint => &MINT_PROGRAM,
+            Self::Burn => &BURN_PROGRAM,
+            Self::ConsumeAndForward => &CONSUME_AND_FORWARD_PROGRAM,
+        }
+    }
+
+    // INSTANTIATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a [`Self::SendAsset`] script which sends `asset` to `target` in a P2ID note, together
+    /// with the details of the created note.
+    ///
+    /// # Errors
+    /// Returns an error if the P2ID note cannot be built.
+    pub fn send_asset(
+        target: AccountId,
+        asset: Asset,
+        note_type: NoteType,
+        serial_num: Word,
+    ) -> Result<(TransactionScript, NoteDetails), TransactionScriptTemplateError> {
+        let recipient = build_p2id_recipient(target, serial_num)
+            .map_err(TransactionScriptTemplateError::InvalidNote)?;
+        let args = note_args(Self::SendAsset, target, &recipient, note_type)?
+            .asset(asset)
+            .map_err(TransactionScriptTemplateError::InvalidScriptArgs)?;
+
+        let assets =
+            NoteAssets::new(vec![asset]).map_err(TransactionScriptTemplateError::InvalidNote)?;
+
+        Ok((Self::SendAsset.instantiate(args)?, NoteDetails::new(assets, recipient)))
+    }
+
+    /// Returns a [`Self::Mint`] script which mints `amount` of the asset of the faucet with ID
+    /// `faucet_id` to `target` in a P2ID note, together with the details of the created note.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - the amount exceeds the maximum amount of a fungible asset, or `faucet_id` is not the ID
+    ///   of a fungible faucet.
+    /// - the P2ID note cannot be built.
+    pub fn mint(
+        faucet_id: AccountId,
+        target: AccountId,
+        amount: u64,
+        note_type: NoteType,
+        serial_num: Word,
+    ) -> Result<(TransactionScript, NoteDetails), TransactionScriptTemplateError> {
+        let asset = FungibleAsset::new(faucet_id, amount)
+            .map_err(TransactionScriptTemplateError::InvalidAsset)?;
+        let recipient = build_p2id_recipient(target, serial_num)
+            .map_err(TransactionScriptTemplateError::InvalidNote)?;
+        let args = note_args(Self::Mint, target, &recipient, note_type)?
+            .u64(amount)
+            .map_err(TransactionScriptTemplateError::InvalidScriptArgs)?;
+
+        let assets = NoteAssets::new(vec![asset.into()])
+            .map_err(TransactionScriptTemplateError::InvalidNote)?;
+
+        Ok((Self::Mint.instantiate(args)?, NoteDetails::new(assets, recipient)))
+    }
+
+    /// Returns a [`Self::Burn`] script which burns `asset`.
+    ///
+    /// The asset must be contained in one of the notes consumed by the transaction, and the notes
+    /// must not add it to the vault of the faucet.
+    ///
+    /// # Errors
+    /// Returns an error if the arguments cannot be encoded.
+    pub fn burn(asset: FungibleAsset) -> Result<TransactionScript, TransactionScriptTemplateError> {
+        let args = TransactionScriptArgs::builder(BURN_LAYOUT)
+            .asset(asset.into())
+            .map_err(TransactionScriptTemplateError::InvalidScriptArgs)?;
+
+        Self::Burn.instantiate(args)
+    }
+
+    /// Returns a [`Self::ConsumeAndForward`] script which sends the entire balance of the asset of
+    /// the fungible faucet with ID `faucet_id` to `target` in a P2ID note, together with the
+    /// recipient of the created note.
+    ///
+    /// Since the forwarded amount is only known once the notes have been consumed, the details of
+    /// the created note are not known upfront.
+    ///
+    /// # Errors
+    /// Returns an error if the P2ID note recipient cannot be built.
+    pub fn consume_and_forward(
+        faucet_id: AccountId,
+        target: AccountId,
+        note_type: NoteType,
+        serial_num: Word,
+    ) -> Result<(TransactionScript, NoteRecipient), TransactionScriptTemplateError> {
+        let recipient = build_p2id_recipient(target, serial_num)
+            .map_err(TransactionScriptTemplateError::InvalidNote)?;
+        let args = note_args(Self::ConsumeAndForward, target, &recipient, note_type)?
+            .account_id(faucet_id)
+            .map_err(TransactionScriptTemplateError::InvalidScriptArgs)?;
+
+        Ok((Self::ConsumeAndForward.instantiate(args)?, recipient))
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a script instance of this template with the provided arguments.
+    fn instantiate(
+        &self,
+        args: TransactionScriptArgsBuilder,
+    ) -> Result<TransactionScript, TransactionScriptTemplateError> {
+        let args = args
+            .build(Self::ARGS_KEY)
+            .map_err(TransactionScriptTemplateError::InvalidScriptArgs)?;
+
+        Ok(TransactionScript::new(self.program().clone(), [args.to_input()]))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a builder of the arguments of the provided template, with the arguments describing the
+/// created P2ID note already set.
+fn note_args(
+    template: TransactionScriptTemplate,
+    target: AccountId,
+    recipient: &NoteRecipient,
+    note_type: NoteType,
+) -> Result<TransactionScriptArgsBuilder, TransactionScriptTemplateError> {
+    debug_assert_eq!(template.layout()[..NOTE_ARGS_LAYOUT.len()], NOTE_ARGS_LAYOUT);
+
+    let tag = NoteTag::from_account_id(target, NoteExecutionMode::Local)
+        .map_err(TransactionScriptTemplateError::InvalidNote)?;
+
+    TransactionScriptArgs::builder(template.layout().iter().copied())
+        .word(recipient.digest().into())
+        .and_then(|args| args.felt(Felt::from(NoteExecutionHint::always())))
+        .and_then(|args| args.felt(Felt::from(note_type)))
+        .and_then(|args| args.felt(ZERO))
+        .and_then(|args| args.u32(tag.into()))
+        .map_err(TransactionScriptTemplateError::InvalidScriptArgs)
+}
+
+/// Compiles the provided template source code.
+fn compile(source: &str) -> Program {
+    TransactionKernel::assembler()
+        .assemble_program(source)
+        .expect("transaction script template should compile")
+}
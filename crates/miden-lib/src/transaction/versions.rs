@@ -0,0 +1,241 @@
+use alloc::sync::Arc;
+
+use miden_objects::{
+    Digest,
+    assembly::{Assembler, DefaultSourceManager, KernelLibrary},
+    transaction::{RedactedTransactionWitness, TransactionArgs, TransactionInputs},
+    utils::{serde::Deserializable, sync::LazyLock},
+    vm::{AdviceInputs, Program, ProgramInfo, StackInputs},
+};
+use miden_stdlib::StdLibrary;
+
+use super::{TransactionKernel, TransactionKernelError, inputs};
+use crate::MidenLib;
+
+// CONSTANTS
+// ================================================================================================
+
+// Initialize the compiled artifacts of all kernel versions only once
+static KERNELS: LazyLock<[KernelArtifacts; TransactionKernel::NUM_VERSIONS]> =
+    LazyLock::new(|| {
+        [KernelArtifacts::read(
+            include_bytes!(concat!(env!("OUT_DIR"), "/assets/kernels/tx_kernel.masl")),
+            include_bytes!(concat!(env!("OUT_DIR"), "/assets/kernels/tx_kernel.masb")),
+            include_bytes!(concat!(env!("OUT_DIR"), "/assets/kernels/tx_script_main.masb")),
+        )]
+    });
+
+// TRANSACTION KERNEL
+// ================================================================================================
+
+impl TransactionKernel {
+    /// The version of the most recent transaction kernel.
+    ///
+    /// This version is used by the version-less methods of [TransactionKernel], e.g.
+    /// [`TransactionKernel::main`] or [`TransactionKernel::prepare_inputs`].
+    pub const LATEST_VERSION: u8 = (Self::NUM_VERSIONS - 1) as u8;
+
+    /// Returns the transaction kernel with the specified version.
+    ///
+    /// # Errors
+    /// Returns an error if there is no transaction kernel with the specified version.
+    pub fn for_version(version: u8) -> Result<VersionedTransactionKernel, TransactionKernelError> {
+        if version as usize >= Self::NUM_VERSIONS {
+            return Err(TransactionKernelError::UnsupportedKernelVersion {
+                version,
+                num_versions: Self::NUM_VERSIONS,
+            });
+        }
+
+        Ok(VersionedTransactionKernel { version })
+    }
+
+    /// Returns the most recent transaction kernel.
+    pub fn latest() -> VersionedTransactionKernel {
+        VersionedTransactionKernel { version: Self::LATEST_VERSION }
+    }
+}
+
+// VERSIONED TRANSACTION KERNEL
+// ================================================================================================
+
+/// A specific version of the transaction kernel.
+///
+/// Multiple versions of the transaction kernel can be supported at the same time. All versions
+/// share the same set of kernel commitments, which is provided to the kernel via the advice inputs,
+/// but every version has its own procedure table and thus its own kernel library, executable
+/// program and assembler.
+///
+/// A [VersionedTransactionKernel] can only be obtained for a supported version via
+/// [`TransactionKernel::for_version`] or [`TransactionKernel::latest`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VersionedTransactionKernel {
+    version: u8,
+}
+
+impl VersionedTransactionKernel {
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the version of this transaction kernel.
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Returns a library with the system procedures of this transaction kernel.
+    pub fn kernel(&self) -> KernelLibrary {
+        self.artifacts().kernel.clone()
+    }
+
+    /// Returns the executable program of this transaction kernel.
+    pub fn main(&self) -> Program {
+        self.artifacts().main.clone()
+    }
+
+    /// Returns the transaction script executor program of this transaction kernel.
+    pub fn tx_script_main(&self) -> Program {
+        self.artifacts().tx_script_main.clone()
+    }
+
+    /// Returns [ProgramInfo] for the executable program of this transaction kernel.
+    pub fn program_info(&self) -> ProgramInfo {
+        let artifacts = self.artifacts();
+        ProgramInfo::new(artifacts.main.hash(), artifacts.kernel.kernel().clone())
+    }
+
+    /// Returns the roots of the procedures of this transaction kernel.
+    pub fn procedures(&self) -> &'static [Digest] {
+        TransactionKernel::PROCEDURES[self.version as usize]
+    }
+
+    /// Returns the names of the procedures of this transaction kernel, in the same order as
+    /// [Self::procedures].
+    pub fn procedure_names(&self) -> &'static [&'static str] {
+        TransactionKernel::PROCEDURE_NAMES[self.version as usize]
+    }
+
+    /// Returns the commitment to the procedures of this transaction kernel.
+    pub fn commitment(&self) -> Digest {
+        TransactionKernel::commitment(self.version)
+    }
+
+    // INPUTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Transforms the provided [TransactionInputs] and [TransactionArgs] into stack and advice
+    /// inputs needed to execute this transaction kernel for a specific transaction.
+    ///
+    /// If `init_advice_inputs` is provided, they will be included in the returned advice inputs.
+    pub fn prepare_inputs(
+        &self,
+        tx_inputs: &TransactionInputs,
+        tx_args: &TransactionArgs,
+        init_advice_inputs: Option<AdviceInputs>,
+    ) -> (StackInputs, AdviceInputs) {
+        let account = tx_inputs.account();
+
+        let stack_inputs = TransactionKernel::build_input_stack(
+            account.id(),
+            account.init_commitment(),
+            tx_inputs.input_notes().commitment(),
+            tx_inputs.block_header().commitment(),
+            tx_inputs.block_header().block_num(),
+        );
+
+        let mut advice_inputs = init_advice_inputs.unwrap_or_default();
+        inputs::extend_advice_inputs(tx_inputs, tx_args, &mut advice_inputs, self.version);
+
+        (stack_inputs, advice_inputs)
+    }
+
+    /// Transforms the provided [RedactedTransactionWitness] into stack and advice inputs needed to
+    /// prove the transaction it was created from with this transaction kernel.
+    ///
+    /// The advice witness of the redacted witness is used as the initial advice inputs, since it
+    /// provides the account vault assets and storage map entries accessed by the transaction.
+    pub fn prepare_redacted_inputs(
+        &self,
+        tx_witness: &RedactedTransactionWitness,
+    ) -> (StackInputs, AdviceInputs) {
+        let account = &tx_witness.account;
+
+        let stack_inputs = TransactionKernel::build_input_stack(
+            account.id(),
+            account.commitment(),
+            tx_witness.input_notes.commitment(),
+            tx_witness.block_header.commitment(),
+            tx_witness.block_header.block_num(),
+        );
+
+        let mut advice_inputs = tx_witness.advice_witness.clone();
+        inputs::extend_redacted_advice_inputs(tx_witness, &mut advice_inputs, self.version);
+
+        (stack_inputs, advice_inputs)
+    }
+
+    /// Returns the key of an entry in the advice map of the provided [TransactionArgs] which
+    /// collides with an entry this transaction kernel adds to the advice map for the provided
+    /// [TransactionInputs], if any.
+    ///
+    /// See [`TransactionKernel::find_advice_map_collision`] for details.
+    pub fn find_advice_map_collision(
+        &self,
+        tx_inputs: &TransactionInputs,
+        tx_args: &TransactionArgs,
+    ) -> Option<Digest> {
+        inputs::find_advice_map_collision(tx_inputs, tx_args, self.version)
+    }
+
+    // ASSEMBLER CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new Miden assembler instantiated with this transaction kernel and loaded with the
+    /// Miden stdlib as well as with miden-lib.
+    pub fn assembler(&self) -> Assembler {
+        let source_manager = Arc::new(DefaultSourceManager::default());
+        Assembler::with_kernel(source_manager, self.kernel())
+            .with_library(StdLibrary::default())
+            .expect("failed to load std-lib")
+            .with_library(MidenLib::default())
+            .expect("failed to load miden-lib")
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the compiled artifacts of this transaction kernel.
+    fn artifacts(&self) -> &'static KernelArtifacts {
+        &KERNELS[self.version as usize]
+    }
+}
+
+// KERNEL ARTIFACTS
+// ================================================================================================
+
+/// The compiled artifacts of a single transaction kernel version.
+struct KernelArtifacts {
+    kernel: KernelLibrary,
+    main: Program,
+    tx_script_main: Program,
+}
+
+impl KernelArtifacts {
+    /// Deserializes the artifacts of a kernel version from the files generated in build.rs.
+    ///
+    /// # Panics
+    /// Panics if any of the provided files cannot be deserialized.
+    fn read(
+        kernel_lib_bytes: &[u8],
+        kernel_main_bytes: &[u8],
+        tx_script_main_bytes: &[u8],
+    ) -> Self {
+        Self {
+            kernel: KernelLibrary::read_from_bytes(kernel_lib_bytes)
+                .expect("failed to deserialize transaction kernel library"),
+            main: Program::read_from_bytes(kernel_main_bytes)
+                .expect("failed to deserialize transaction kernel runtime"),
+            tx_script_main: Program::read_from_bytes(tx_script_main_bytes)
+                .expect("failed to deserialize tx script executor runtime"),
+        }
+    }
+}
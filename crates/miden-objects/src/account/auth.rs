@@ -3,8 +3,9 @@
 
 use miden_crypto::dsa::rpo_falcon512::{self, SecretKey};
 
-use crate::utils::serde::{
-    ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
+use crate::{
+    crypto::{bls12_381, ecdsa_k256},
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
 };
 
 /// Types of secret keys used for signing messages
@@ -12,6 +13,8 @@ use crate::utils::serde::{
 #[repr(u8)]
 pub enum AuthSecretKey {
     RpoFalcon512(rpo_falcon512::SecretKey) = 0,
+    EcdsaK256(ecdsa_k256::SecretKey) = 1,
+    Bls12381(bls12_381::SecretKey) = 2,
 }
 
 impl AuthSecretKey {
@@ -19,6 +22,8 @@ impl AuthSecretKey {
     pub fn auth_scheme_id(&self) -> u8 {
         match self {
             AuthSecretKey::RpoFalcon512(_) => 0u8,
+            AuthSecretKey::EcdsaK256(_) => 1u8,
+            AuthSecretKey::Bls12381(_) => 2u8,
         }
     }
 }
@@ -30,6 +35,12 @@ impl Serializable for AuthSecretKey {
             AuthSecretKey::RpoFalcon512(secret_key) => {
                 secret_key.write_into(target);
             },
+            AuthSecretKey::EcdsaK256(secret_key) => {
+                secret_key.write_into(target);
+            },
+            AuthSecretKey::Bls12381(secret_key) => {
+                secret_key.write_into(target);
+            },
         }
     }
 }
@@ -43,6 +54,16 @@ impl Deserializable for AuthSecretKey {
                 let secret_key = SecretKey::read_from(source)?;
                 Ok(AuthSecretKey::RpoFalcon512(secret_key))
             },
+            // EcdsaK256
+            1u8 => {
+                let secret_key = ecdsa_k256::SecretKey::read_from(source)?;
+                Ok(AuthSecretKey::EcdsaK256(secret_key))
+            },
+            // Bls12381
+            2u8 => {
+                let secret_key = bls12_381::SecretKey::read_from(source)?;
+                Ok(AuthSecretKey::Bls12381(secret_key))
+            },
             val => Err(DeserializationError::InvalidValue(format!("Invalid auth scheme ID {val}"))),
         }
     }
@@ -1,5 +1,10 @@
-use alloc::{collections::BTreeSet, sync::Arc, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+    vec::Vec,
+};
 
+use assembly::ast::ProcedureName;
 use vm_core::mast::MastForest;
 
 use super::{
@@ -9,7 +14,7 @@ use super::{
 use crate::account::{AccountComponent, AccountType};
 
 pub mod procedure;
-use procedure::AccountProcedureInfo;
+use procedure::{AccountProcedureInfo, ProcedureCallers};
 
 // ACCOUNT CODE
 // ================================================================================================
@@ -19,15 +24,23 @@ use procedure::AccountProcedureInfo;
 /// Account's public interface consists of a set of account procedures, each procedure being a
 /// Miden VM program. Thus, MAST root of each procedure commits to the underlying program.
 ///
-/// Each exported procedure is associated with a storage offset and a storage size.
+/// Each exported procedure is associated with a storage offset and a storage size. At most one
+/// procedure can be marked as the final state validator of the account, see
+/// [`AccountCode::FINAL_STATE_VALIDATOR_NAME`], and at most one as its asset filter, see
+/// [`AccountCode::ASSET_FILTER_NAME`]. Each procedure also declares which callers may
+/// invoke it, see [`ProcedureCallers`].
 ///
 /// We commit to the entire account interface by building a sequential hash of all procedure MAST
 /// roots and associated storage_offset's. Specifically, each procedure contributes exactly 8 field
 /// elements to the sequence of elements to be hashed. These elements are defined as follows:
 ///
 /// ```text
-/// [PROCEDURE_MAST_ROOT, storage_offset, 0, 0, storage_size]
+/// [PROCEDURE_MAST_ROOT, storage_offset, storage_size, hook_flags, caller_restrictions]
 /// ```
+///
+/// Where `hook_flags` marks the procedure as the final state validator (bit 0) and/or the asset
+/// filter (bit 1) of the account and `caller_restrictions` is the mask of callers which may not invoke the procedure, see
+/// [`ProcedureCallers::restrictions`].
 #[derive(Debug, Clone)]
 pub struct AccountCode {
     mast: Arc<MastForest>,
@@ -39,6 +52,26 @@ impl AccountCode {
     /// The maximum number of account interface procedures.
     pub const MAX_NUM_PROCEDURES: usize = 256;
 
+    /// The name of the procedure which, when exported by an account component, is marked as the
+    /// final state validator of the account.
+    ///
+    /// The transaction kernel invokes the final state validator in the epilogue of every
+    /// transaction executed against the account, i.e. after all notes and the transaction script
+    /// were executed. This allows the account to enforce invariants over its final state (e.g. a
+    /// collateral ratio) regardless of which scripts were executed, by panicking if they are
+    /// violated. The procedure is called with an empty operand stack and its outputs are
+    /// discarded.
+    pub const FINAL_STATE_VALIDATOR_NAME: &'static str = "validate_final_state";
+
+    /// The name of the procedure which, when exported by an account component, is marked as the
+    /// asset filter of the account.
+    ///
+    /// The transaction kernel invokes the asset filter in the epilogue of a transaction for every
+    /// asset added to the account vault during the transaction. This allows the account to reject
+    /// unwanted assets (e.g. spam non-fungible assets) by panicking. The procedure is called with
+    /// the added asset on top of an otherwise empty operand stack and its outputs are discarded.
+    pub const ASSET_FILTER_NAME: &'static str = "filter_incoming_asset";
+
     // CONSTRUCTORS
     // --------------------------------------------------------------------------------------------
 
@@ -66,6 +99,9 @@ impl AccountCode {
     /// - The number of procedures in all merged libraries is 0 or exceeds
     ///   [`AccountCode::MAX_NUM_PROCEDURES`].
     /// - Two or more libraries export a procedure with the same MAST root.
+    /// - Two or more libraries export a procedure named [`AccountCode::FINAL_STATE_VALIDATOR_NAME`].
+    /// - Two or more libraries export a procedure named [`AccountCode::ASSET_FILTER_NAME`].
+    /// - A component restricts the callers of a procedure it does not export.
     /// - The number of [`StorageSlot`](crate::account::StorageSlot)s of a component or of all
     ///   components exceeds 255.
     /// - [`MastForest::merge`] fails on all libraries.
@@ -79,6 +115,13 @@ impl AccountCode {
 
         let mut procedures = Vec::new();
         let mut proc_root_set = BTreeSet::new();
+        let mut final_state_validator = None;
+        let mut asset_filter = None;
+
+        let final_state_validator_name = ProcedureName::new(Self::FINAL_STATE_VALIDATOR_NAME)
+            .expect("final state validator name should be a valid procedure name");
+        let asset_filter_name = ProcedureName::new(Self::ASSET_FILTER_NAME)
+            .expect("asset filter name should be a valid procedure name");
 
         // Slot 0 is globally reserved for faucet accounts so the accessible slots begin at 1 if
         // there is a faucet component present.
@@ -87,6 +130,31 @@ impl AccountCode {
         for component in components {
             let component_storage_size = component.storage_size();
 
+            let library = component.library();
+            let component_validator = library
+                .exports()
+                .find(|export| export.name == final_state_validator_name)
+                .map(|export| library.mast_forest()[library.get_export_node_id(export)].digest());
+            let component_asset_filter = library
+                .exports()
+                .find(|export| export.name == asset_filter_name)
+                .map(|export| library.mast_forest()[library.get_export_node_id(export)].digest());
+
+            // resolve the caller restrictions of the component from procedure names to MAST roots
+            let mut component_callers = BTreeMap::new();
+            for (procedure_name, allowed_callers) in component.allowed_callers() {
+                let unknown_procedure =
+                    || AccountError::AccountComponentUnknownProcedure(procedure_name.clone());
+                let name = ProcedureName::new(procedure_name).map_err(|_| unknown_procedure())?;
+                let export = library
+                    .exports()
+                    .find(|export| export.name == name)
+                    .ok_or_else(unknown_procedure)?;
+                let proc_mast_root =
+                    library.mast_forest()[library.get_export_node_id(export)].digest();
+                component_callers.insert(proc_mast_root, *allowed_callers);
+            }
+
             for module in component.library().module_infos() {
                 for proc_mast_root in module.procedure_digests() {
                     // We cannot support procedures from multiple components with the same MAST root
@@ -107,11 +175,36 @@ impl AccountCode {
                     };
 
                     // Note: Offset and size are validated in `AccountProcedureInfo::new`.
-                    procedures.push(AccountProcedureInfo::new(
-                        proc_mast_root,
-                        storage_offset,
-                        storage_size,
-                    )?);
+                    let mut procedure =
+                        AccountProcedureInfo::new(proc_mast_root, storage_offset, storage_size)?;
+
+                    if component_validator == Some(proc_mast_root) {
+                        if let Some(existing_validator) =
+                            final_state_validator.replace(proc_mast_root)
+                        {
+                            return Err(AccountError::AccountCodeMultipleFinalStateValidators(
+                                existing_validator,
+                                proc_mast_root,
+                            ));
+                        }
+                        procedure = procedure.with_final_state_validator();
+                    }
+
+                    if component_asset_filter == Some(proc_mast_root) {
+                        if let Some(existing_filter) = asset_filter.replace(proc_mast_root) {
+                            return Err(AccountError::AccountCodeMultipleAssetFilters(
+                                existing_filter,
+                                proc_mast_root,
+                            ));
+                        }
+                        procedure = procedure.with_asset_filter();
+                    }
+
+                    if let Some(allowed_callers) = component_callers.get(&proc_mast_root) {
+                        procedure = procedure.with_allowed_callers(*allowed_callers);
+                    }
+
+                    procedures.push(procedure);
                 }
             }
 
@@ -210,11 +303,20 @@ impl AccountCode {
             .position(|r| r == &root)
     }
 
+    /// Returns the callers which may invoke the procedure with the specified MAST root or None if
+    /// such procedure is not defined in this [AccountCode].
+    pub fn get_procedure_allowed_callers(&self, root: Digest) -> Option<ProcedureCallers> {
+        self.procedures
+            .iter()
+            .find(|procedure| procedure.mast_root() == &root)
+            .map(AccountProcedureInfo::allowed_callers)
+    }
+
     /// Converts procedure information in this [AccountCode] into a vector of field elements.
     ///
     /// This is done by first converting each procedure into 8 field elements as follows:
     /// ```text
-    /// [PROCEDURE_MAST_ROOT, storage_offset, storage_size, 0, 0]
+    /// [PROCEDURE_MAST_ROOT, storage_offset, storage_size, hook_flags, caller_restrictions]
     /// ```
     /// And then concatenating the resulting elements into a single vector.
     pub fn as_elements(&self) -> Vec<Felt> {
@@ -304,14 +406,17 @@ fn build_procedure_commitment(procedures: &[AccountProcedureInfo]) -> Digest {
 
 #[cfg(test)]
 mod tests {
-    use assembly::Assembler;
+    use assembly::{Assembler, ast::ProcedureName};
     use assert_matches::assert_matches;
     use vm_core::Word;
 
     use super::{AccountCode, Deserializable, Serializable};
     use crate::{
         AccountError,
-        account::{AccountComponent, AccountType, StorageSlot, code::build_procedure_commitment},
+        account::{
+            AccountComponent, AccountType, ProcedureCallers, StorageSlot,
+            code::build_procedure_commitment,
+        },
     };
 
     #[test]
@@ -363,4 +468,105 @@ mod tests {
 
         assert_matches!(err, AccountError::StorageOffsetPlusSizeOutOfBounds(256))
     }
+
+    #[test]
+    fn test_account_code_final_state_validator() {
+        let code1 = "export.foo add end export.validate_final_state push.1 assert end";
+        let library1 = Assembler::default().assemble_library([code1]).unwrap();
+        let code2 = "export.bar sub end export.validate_final_state push.2 assert end";
+        let library2 = Assembler::default().assemble_library([code2]).unwrap();
+
+        let component1 = AccountComponent::new(library1, vec![]).unwrap().with_supports_all_types();
+        let component2 = AccountComponent::new(library2, vec![]).unwrap().with_supports_all_types();
+
+        // only the validate_final_state procedure is marked as the final state validator
+        let code = AccountCode::from_components(
+            &[component1.clone()],
+            AccountType::RegularAccountUpdatableCode,
+        )
+        .unwrap();
+        assert_eq!(
+            code.procedures()
+                .iter()
+                .filter(|procedure| procedure.is_final_state_validator())
+                .count(),
+            1
+        );
+        assert_eq!(AccountCode::read_from_bytes(&code.to_bytes()).unwrap(), code);
+
+        let err = AccountCode::from_components(
+            &[component1, component2],
+            AccountType::RegularAccountUpdatableCode,
+        )
+        .unwrap_err();
+        assert_matches!(err, AccountError::AccountCodeMultipleFinalStateValidators(..))
+    }
+
+    #[test]
+    fn test_account_code_asset_filter() {
+        let code1 = "export.foo add end export.filter_incoming_asset dropw end";
+        let library1 = Assembler::default().assemble_library([code1]).unwrap();
+        let code2 = "export.bar sub end export.filter_incoming_asset dropw push.1 drop end";
+        let library2 = Assembler::default().assemble_library([code2]).unwrap();
+
+        let component1 = AccountComponent::new(library1, vec![]).unwrap().with_supports_all_types();
+        let component2 = AccountComponent::new(library2, vec![]).unwrap().with_supports_all_types();
+
+        // only the filter_incoming_asset procedure is marked as the asset filter
+        let code = AccountCode::from_components(
+            &[component1.clone()],
+            AccountType::RegularAccountUpdatableCode,
+        )
+        .unwrap();
+        assert_eq!(
+            code.procedures().iter().filter(|procedure| procedure.is_asset_filter()).count(),
+            1
+        );
+        assert_eq!(AccountCode::read_from_bytes(&code.to_bytes()).unwrap(), code);
+
+        let err = AccountCode::from_components(
+            &[component1, component2],
+            AccountType::RegularAccountUpdatableCode,
+        )
+        .unwrap_err();
+        assert_matches!(err, AccountError::AccountCodeMultipleAssetFilters(..))
+    }
+
+    #[test]
+    fn test_account_code_procedure_allowed_callers() {
+        let code = "export.foo add end export.bar sub end";
+        let library = Assembler::default().assemble_library([code]).unwrap();
+        let foo_name = ProcedureName::new("foo").unwrap();
+        let foo_export = library.exports().find(|export| export.name == foo_name).unwrap();
+        let foo_root = library.mast_forest()[library.get_export_node_id(foo_export)].digest();
+
+        let component = AccountComponent::new(library, vec![])
+            .unwrap()
+            .with_supports_all_types()
+            .with_allowed_callers("foo", ProcedureCallers::TX_SCRIPT);
+
+        let code = AccountCode::from_components(
+            &[component.clone()],
+            AccountType::RegularAccountUpdatableCode,
+        )
+        .unwrap();
+        for procedure in code.procedures() {
+            let expected = if procedure.mast_root() == &foo_root {
+                ProcedureCallers::TX_SCRIPT
+            } else {
+                ProcedureCallers::ALL
+            };
+            assert_eq!(procedure.allowed_callers(), expected);
+        }
+        assert_eq!(code.get_procedure_allowed_callers(foo_root), Some(ProcedureCallers::TX_SCRIPT));
+        assert_eq!(AccountCode::read_from_bytes(&code.to_bytes()).unwrap(), code);
+
+        // restricting a procedure which is not exported by the component fails
+        let err = AccountCode::from_components(
+            &[component.with_allowed_callers("baz", ProcedureCallers::NONE)],
+            AccountType::RegularAccountUpdatableCode,
+        )
+        .unwrap_err();
+        assert_matches!(err, AccountError::AccountComponentUnknownProcedure(name) if name == "baz")
+    }
 }
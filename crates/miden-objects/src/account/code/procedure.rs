@@ -1,4 +1,4 @@
-use alloc::string::ToString;
+use alloc::{format, string::ToString};
 
 use super::{Digest, Felt};
 use crate::{
@@ -6,6 +6,86 @@ use crate::{
     utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
 };
 
+// PROCEDURE CALLERS
+// ================================================================================================
+
+/// A set of callers which may invoke an account procedure.
+///
+/// The transaction kernel distinguishes three kinds of callers of account procedures:
+/// - note scripts, i.e. the scripts of the input notes consumed by the native account.
+/// - the transaction script executed against the native account.
+/// - foreign procedure invocation (FPI), i.e. procedures of a foreign account invoked from within
+///   the transaction.
+///
+/// The restriction is enforced by the kernel when the procedure invokes a kernel procedure which
+/// requires authentication (e.g. accessing the account's storage or vault), so procedures which do
+/// not invoke the kernel are not affected by it.
+///
+/// Procedures invoked by the kernel itself, i.e. the final state validator and the asset filter,
+/// are never restricted when the kernel invokes them in the transaction epilogue, because no
+/// script is being executed at that point. Their restrictions only apply when they are invoked
+/// directly by a note script, the transaction script or via FPI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProcedureCallers(u8);
+
+impl ProcedureCallers {
+    /// Note scripts may invoke the procedure.
+    pub const NOTE_SCRIPT: Self = Self(0b001);
+
+    /// The transaction script may invoke the procedure.
+    pub const TX_SCRIPT: Self = Self(0b010);
+
+    /// The procedure may be invoked via foreign procedure invocation.
+    pub const FOREIGN: Self = Self(0b100);
+
+    /// All callers may invoke the procedure.
+    pub const ALL: Self = Self(0b111);
+
+    /// No caller may invoke the procedure.
+    pub const NONE: Self = Self(0);
+
+    /// Returns the union of this set of callers and `other`.
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Returns `true` if all callers in `other` are contained in this set, `false` otherwise.
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the mask of callers which are not allowed to invoke the procedure.
+    ///
+    /// This is the representation used in kernel memory and in the account code commitment, so that
+    /// a procedure without restrictions is represented by 0.
+    pub const fn restrictions(&self) -> u8 {
+        !self.0 & Self::ALL.0
+    }
+
+    /// Returns the set of callers from the provided mask of callers which are not allowed to invoke
+    /// the procedure, or `None` if the mask contains unknown callers.
+    pub const fn from_restrictions(restrictions: u8) -> Option<Self> {
+        if restrictions & !Self::ALL.0 != 0 {
+            return None;
+        }
+        Some(Self(!restrictions & Self::ALL.0))
+    }
+}
+
+impl Default for ProcedureCallers {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl core::ops::BitOr for ProcedureCallers {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
 // ACCOUNT PROCEDURE INFO
 // ================================================================================================
 
@@ -24,17 +104,37 @@ use crate::{
 /// account's storage. For example, if storage size for a procedure is set to 3, the procedure will
 /// be bounded to access storage slots in the range [storage_offset, storage_offset + 3 - 1].
 /// Furthermore storage_size = 0 indicates that a procedure does not need to access storage.
+///
+/// A procedure can also be marked as a final state validator, in which case the transaction kernel
+/// invokes it in the epilogue of every transaction executed against the account, see
+/// [`AccountCode::FINAL_STATE_VALIDATOR_NAME`](super::AccountCode::FINAL_STATE_VALIDATOR_NAME).
+///
+/// Similarly, a procedure can be marked as the asset filter of the account, in which case the
+/// transaction kernel invokes it for every asset added to the account vault, see
+/// [`AccountCode::ASSET_FILTER_NAME`](super::AccountCode::ASSET_FILTER_NAME).
+///
+/// Finally, a procedure can restrict the callers which may invoke it, see [`ProcedureCallers`]. By
+/// default, a procedure may be invoked by all callers.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct AccountProcedureInfo {
     mast_root: Digest,
     storage_offset: u8,
     storage_size: u8,
+    is_final_state_validator: bool,
+    is_asset_filter: bool,
+    allowed_callers: ProcedureCallers,
 }
 
 impl AccountProcedureInfo {
     /// The number of field elements needed to represent an [AccountProcedureInfo] in kernel memory.
     pub const NUM_ELEMENTS_PER_PROC: usize = 8;
 
+    /// The hook flag marking a procedure as a final state validator.
+    const FINAL_STATE_VALIDATOR_HOOK: u8 = 0b01;
+
+    /// The hook flag marking a procedure as an asset filter.
+    const ASSET_FILTER_HOOK: u8 = 0b10;
+
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
 
@@ -60,7 +160,32 @@ impl AccountProcedureInfo {
             ));
         }
 
-        Ok(Self { mast_root, storage_offset, storage_size })
+        Ok(Self {
+            mast_root,
+            storage_offset,
+            storage_size,
+            is_final_state_validator: false,
+            is_asset_filter: false,
+            allowed_callers: ProcedureCallers::ALL,
+        })
+    }
+
+    /// Marks this procedure as a final state validator of the account.
+    pub fn with_final_state_validator(mut self) -> Self {
+        self.is_final_state_validator = true;
+        self
+    }
+
+    /// Marks this procedure as the asset filter of the account.
+    pub fn with_asset_filter(mut self) -> Self {
+        self.is_asset_filter = true;
+        self
+    }
+
+    /// Restricts the callers which may invoke this procedure to `allowed_callers`.
+    pub fn with_allowed_callers(mut self, allowed_callers: ProcedureCallers) -> Self {
+        self.allowed_callers = allowed_callers;
+        self
     }
 
     // PUBLIC ACCESSORS
@@ -80,6 +205,36 @@ impl AccountProcedureInfo {
     pub fn storage_size(&self) -> u8 {
         self.storage_size
     }
+
+    /// Returns `true` if the procedure is invoked by the transaction kernel to validate the final
+    /// state of the account, `false` otherwise.
+    pub fn is_final_state_validator(&self) -> bool {
+        self.is_final_state_validator
+    }
+
+    /// Returns `true` if the procedure is invoked by the transaction kernel for every asset added to
+    /// the account vault, `false` otherwise.
+    pub fn is_asset_filter(&self) -> bool {
+        self.is_asset_filter
+    }
+
+    /// Returns the hook flags of the procedure, i.e. the bitwise OR of the hooks the transaction
+    /// kernel invokes the procedure for.
+    fn hook_flags(&self) -> u8 {
+        let mut flags = 0;
+        if self.is_final_state_validator {
+            flags |= Self::FINAL_STATE_VALIDATOR_HOOK;
+        }
+        if self.is_asset_filter {
+            flags |= Self::ASSET_FILTER_HOOK;
+        }
+        flags
+    }
+
+    /// Returns the callers which may invoke the procedure.
+    pub fn allowed_callers(&self) -> ProcedureCallers {
+        self.allowed_callers
+    }
 }
 
 impl From<AccountProcedureInfo> for [Felt; 8] {
@@ -95,6 +250,12 @@ impl From<AccountProcedureInfo> for [Felt; 8] {
         // copy the storage size into value[5]
         result[5] = Felt::from(value.storage_size);
 
+        // copy the hook flags (final state validator and asset filter) into value[6]
+        result[6] = Felt::from(value.hook_flags());
+
+        // copy the caller restrictions into value[7]
+        result[7] = Felt::from(value.allowed_callers.restrictions());
+
         result
     }
 }
@@ -116,12 +277,31 @@ impl TryFrom<[Felt; 8]> for AccountProcedureInfo {
             AccountError::AccountCodeProcedureStorageSizeTooLarge(mast_root, value[5])
         })?;
 
-        // Check if the remaining values are 0
-        if value[6] != Felt::ZERO || value[7] != Felt::ZERO {
-            return Err(AccountError::AccountCodeProcedureInvalidPadding(mast_root));
+        // get the hook flags from value[6]
+        let hook_flags = value[6].as_int();
+        let all_hooks = (Self::FINAL_STATE_VALIDATOR_HOOK | Self::ASSET_FILTER_HOOK) as u64;
+        if hook_flags & !all_hooks != 0 {
+            return Err(AccountError::AccountCodeProcedureInvalidHookFlags(mast_root, value[6]));
         }
-
-        Ok(Self { mast_root, storage_offset, storage_size })
+        let is_final_state_validator = hook_flags & Self::FINAL_STATE_VALIDATOR_HOOK as u64 != 0;
+        let is_asset_filter = hook_flags & Self::ASSET_FILTER_HOOK as u64 != 0;
+
+        // get the caller restrictions from value[7]
+        let allowed_callers = u8::try_from(value[7].as_int())
+            .ok()
+            .and_then(ProcedureCallers::from_restrictions)
+            .ok_or(AccountError::AccountCodeProcedureInvalidCallerRestrictions(
+                mast_root, value[7],
+            ))?;
+
+        Ok(Self {
+            mast_root,
+            storage_offset,
+            storage_size,
+            is_final_state_validator,
+            is_asset_filter,
+            allowed_callers,
+        })
     }
 }
 
@@ -129,13 +309,19 @@ impl Serializable for AccountProcedureInfo {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         target.write(self.mast_root);
         target.write_u8(self.storage_offset);
-        target.write_u8(self.storage_size)
+        target.write_u8(self.storage_size);
+        target.write_bool(self.is_final_state_validator);
+        target.write_bool(self.is_asset_filter);
+        target.write_u8(self.allowed_callers.restrictions());
     }
 
     fn get_size_hint(&self) -> usize {
         self.mast_root.get_size_hint()
             + self.storage_offset.get_size_hint()
             + self.storage_size.get_size_hint()
+            + 1 // is_final_state_validator
+            + 1 // is_asset_filter
+            + self.allowed_callers.restrictions().get_size_hint()
     }
 }
 
@@ -144,8 +330,29 @@ impl Deserializable for AccountProcedureInfo {
         let mast_root: Digest = source.read()?;
         let storage_offset = source.read_u8()?;
         let storage_size = source.read_u8()?;
-        Self::new(mast_root, storage_offset, storage_size)
-            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+        let is_final_state_validator = source.read_bool()?;
+        let is_asset_filter = source.read_bool()?;
+        let restrictions = source.read_u8()?;
+
+        let allowed_callers =
+            ProcedureCallers::from_restrictions(restrictions).ok_or_else(|| {
+                DeserializationError::InvalidValue(format!(
+                    "invalid procedure caller restrictions {restrictions}"
+                ))
+            })?;
+
+        let mut procedure = Self::new(mast_root, storage_offset, storage_size)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?
+            .with_allowed_callers(allowed_callers);
+
+        if is_final_state_validator {
+            procedure = procedure.with_final_state_validator();
+        }
+        if is_asset_filter {
+            procedure = procedure.with_asset_filter();
+        }
+
+        Ok(procedure)
     }
 }
 
@@ -158,7 +365,11 @@ mod tests {
     use miden_crypto::utils::{Deserializable, Serializable};
     use vm_core::Felt;
 
-    use crate::account::{AccountCode, AccountProcedureInfo};
+    use super::ProcedureCallers;
+    use crate::{
+        AccountError,
+        account::{AccountCode, AccountProcedureInfo},
+    };
 
     #[test]
     fn test_from_to_account_procedure() {
@@ -175,6 +386,56 @@ mod tests {
         assert_eq!(procedure, final_procedure);
     }
 
+    #[test]
+    fn test_account_procedure_caller_restrictions() {
+        let procedure = AccountCode::mock().procedures()[0]
+            .with_allowed_callers(ProcedureCallers::NOTE_SCRIPT | ProcedureCallers::FOREIGN);
+
+        // the restrictions are encoded in the last element, i.e. only the transaction script is
+        // restricted
+        let mut felts: [Felt; 8] = procedure.into();
+        assert_eq!(felts[7], Felt::from(0b010u8));
+
+        let decoded = AccountProcedureInfo::try_from(felts).unwrap();
+        assert_eq!(decoded, procedure);
+        assert!(decoded.allowed_callers().contains(ProcedureCallers::FOREIGN));
+        assert!(!decoded.allowed_callers().contains(ProcedureCallers::TX_SCRIPT));
+
+        let deserialized = AccountProcedureInfo::read_from_bytes(&procedure.to_bytes()).unwrap();
+        assert_eq!(deserialized, procedure);
+
+        // unknown callers are rejected
+        felts[7] = Felt::from(8u8);
+        assert!(matches!(
+            AccountProcedureInfo::try_from(felts),
+            Err(AccountError::AccountCodeProcedureInvalidCallerRestrictions(..))
+        ));
+    }
+
+    #[test]
+    fn test_account_procedure_hook_flags() {
+        let procedure = AccountCode::mock().procedures()[0]
+            .with_final_state_validator()
+            .with_asset_filter();
+
+        let mut felts: [Felt; 8] = procedure.into();
+        assert_eq!(felts[6], Felt::from(3u8));
+
+        let decoded = AccountProcedureInfo::try_from(felts).unwrap();
+        assert!(decoded.is_final_state_validator());
+        assert!(decoded.is_asset_filter());
+
+        let deserialized = AccountProcedureInfo::read_from_bytes(&procedure.to_bytes()).unwrap();
+        assert_eq!(deserialized, procedure);
+
+        // unknown hooks are rejected
+        felts[6] = Felt::from(4u8);
+        assert!(matches!(
+            AccountProcedureInfo::try_from(felts),
+            Err(AccountError::AccountCodeProcedureInvalidHookFlags(..))
+        ));
+    }
+
     #[test]
     fn test_serde_account_procedure() {
         let account_code = AccountCode::mock();
@@ -1,4 +1,8 @@
-use alloc::{collections::BTreeSet, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec::Vec,
+};
 
 use assembly::{Assembler, Compile, Library};
 use vm_processor::MastForest;
@@ -8,7 +12,7 @@ pub use template::*;
 
 use crate::{
     AccountError,
-    account::{AccountType, StorageSlot},
+    account::{AccountType, ProcedureCallers, StorageSlot},
 };
 
 /// An [`AccountComponent`] defines a [`Library`] of code and the initial value and types of
@@ -26,11 +30,16 @@ use crate::{
 /// would only specify support for [`AccountType::FungibleFaucet`]. Using it to instantiate a
 /// regular account would fail. By default, the set of supported types is empty, so each component
 /// is forced to explicitly define what it supports.
+///
+/// Components can also restrict which callers may invoke their procedures, see
+/// [`AccountComponent::with_allowed_callers`]. By default, all procedures may be invoked by all
+/// callers.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AccountComponent {
     pub(super) library: Library,
     pub(super) storage_slots: Vec<StorageSlot>,
     pub(super) supported_types: BTreeSet<AccountType>,
+    pub(super) allowed_callers: BTreeMap<String, ProcedureCallers>,
 }
 
 impl AccountComponent {
@@ -60,6 +69,7 @@ impl AccountComponent {
             library: code,
             storage_slots,
             supported_types: BTreeSet::new(),
+            allowed_callers: BTreeMap::new(),
         })
     }
 
@@ -146,6 +156,14 @@ impl AccountComponent {
         self.supported_types.contains(&account_type)
     }
 
+    /// Returns a reference to the callers allowed to invoke the procedures of this component, keyed
+    /// by procedure name.
+    ///
+    /// Procedures which are not contained in the map may be invoked by all callers.
+    pub fn allowed_callers(&self) -> &BTreeMap<String, ProcedureCallers> {
+        &self.allowed_callers
+    }
+
     // MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -177,6 +195,21 @@ impl AccountComponent {
         ]);
         self
     }
+
+    /// Restricts the callers which may invoke the procedure named `procedure_name` to
+    /// `allowed_callers`.
+    ///
+    /// Calling this function again for the same procedure overwrites the previously set callers.
+    /// The procedure must be exported by the component's library, otherwise building
+    /// [`AccountCode`](crate::account::AccountCode) from this component fails.
+    pub fn with_allowed_callers(
+        mut self,
+        procedure_name: impl Into<String>,
+        allowed_callers: ProcedureCallers,
+    ) -> Self {
+        self.allowed_callers.insert(procedure_name.into(), allowed_callers);
+        self
+    }
 }
 
 impl From<AccountComponent> for Library {
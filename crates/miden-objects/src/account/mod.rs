@@ -18,7 +18,10 @@ mod builder;
 pub use builder::AccountBuilder;
 
 pub mod code;
-pub use code::{AccountCode, procedure::AccountProcedureInfo};
+pub use code::{
+    AccountCode,
+    procedure::{AccountProcedureInfo, ProcedureCallers},
+};
 
 mod component;
 pub use component::{
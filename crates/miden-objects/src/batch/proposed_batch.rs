@@ -110,6 +110,8 @@ impl ProposedBatch {
     /// - There are duplicate transactions.
     /// - If any transaction's expiration block number is less than or equal to the batch's
     ///   reference block.
+    /// - If the expiration block number of any unauthenticated input note is less than or equal to
+    ///   the batch's reference block.
     pub fn new(
         transactions: Vec<Arc<ProvenTransaction>>,
         reference_block_header: BlockHeader,
@@ -226,6 +228,27 @@ impl ProposedBatch {
             // The expiration block of the batch is the minimum of all transaction's expiration
             // block.
             batch_expiration_block_num = batch_expiration_block_num.min(tx.expiration_block_num());
+
+            // The transaction kernel caps the transaction's expiration block number at the
+            // expiration block number of its input notes, but the batch's reference block may be
+            // more recent than the transaction's, so we check the expiration of notes with known
+            // metadata explicitly.
+            for note_header in tx.input_notes().iter().filter_map(|note| note.header()) {
+                let Some(note_expiration_block_num) =
+                    note_header.metadata().execution_hint().expiration_block_num()
+                else {
+                    continue;
+                };
+
+                if note_expiration_block_num <= reference_block_header.block_num() {
+                    return Err(ProposedBatchError::ExpiredInputNote {
+                        transaction_id: tx.id(),
+                        note_id: note_header.id(),
+                        note_expiration_block_num,
+                        reference_block_num: reference_block_header.block_num(),
+                    });
+                }
+            }
         }
 
         // Check for duplicates in input notes.
@@ -77,6 +77,8 @@ impl ProposedBlock {
     /// - There are duplicate batches, i.e. they have the same [`BatchId`].
     /// - The expiration block number of any batch is less than the block number of the currently
     ///   proposed block.
+    /// - The expiration block number of any unauthenticated input note of a batch is less than the
+    ///   block number of the currently proposed block.
     ///
     /// ## Chain
     ///
@@ -144,6 +146,7 @@ impl ProposedBlock {
         // --------------------------------------------------------------------------------------------
 
         check_batch_expiration(&batches, block_inputs.prev_block_header())?;
+        check_input_note_expiration(&batches, block_inputs.prev_block_header())?;
 
         // Check for consistency between the chain MMR and the referenced previous block.
         // --------------------------------------------------------------------------------------------
@@ -410,6 +413,39 @@ fn check_batch_expiration(
     Ok(())
 }
 
+/// Checks whether any of the unauthenticated input notes of the batches is expired and can no
+/// longer be consumed in this block.
+///
+/// This follows the same rules as [`check_batch_expiration`], i.e. a note which expires at block 5
+/// can still be consumed in block 5.
+fn check_input_note_expiration(
+    batches: &[ProvenBatch],
+    prev_block_header: &BlockHeader,
+) -> Result<(), ProposedBlockError> {
+    let current_block_num = prev_block_header.block_num() + 1;
+
+    for batch in batches {
+        for note_header in batch.input_notes().iter().filter_map(|note| note.header()) {
+            let Some(note_expiration_block_num) =
+                note_header.metadata().execution_hint().expiration_block_num()
+            else {
+                continue;
+            };
+
+            if note_expiration_block_num < current_block_num {
+                return Err(ProposedBlockError::ExpiredInputNote {
+                    batch_id: batch.id(),
+                    note_id: note_header.id(),
+                    note_expiration_block_num,
+                    current_block_num,
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
 /// Check that each nullifier in the block has a proof provided and that the nullifier is
 /// unspent. The proofs are required to update the nullifier tree.
 fn check_nullifiers(
@@ -0,0 +1,563 @@
+//! BLS signatures over the BLS12-381 curve.
+//!
+//! Signatures are points of G1 and public keys are points of G2, i.e., this is the "minimal
+//! signature size" variant of BLS. A signature `S` over a message is valid for the public key `PK`
+//! if `e(S, G) = e(H(MSG), PK)`, where `G` is the generator of G2 and `H` hashes the message to G1.
+//!
+//! Messages signed with these keys are [Word]s. A message is hashed to G1 by interpreting the 381
+//! least significant bits of `h(h(MSG, [ctr, 0, 0, 0]), [0, 0, 0, 0]) || h(MSG, [ctr, 0, 0, 0])`
+//! as the x-coordinate of a point, where `h` is the RPO merge function and `ctr` is the smallest
+//! counter for which a point with this x-coordinate exists, and multiplying the point whose
+//! y-coordinate is smaller than `(p + 1) / 2` by the effective cofactor of G1.
+//!
+//! Signatures over the same message can be aggregated into a single signature which is valid for
+//! the aggregate of the public keys of the signers, e.g. to implement threshold or validator set
+//! schemes. To prevent rogue key attacks, i.e., an attacker choosing its public key as a function
+//! of the other public keys such that it can sign for the aggregate alone, public keys can only be
+//! aggregated together with a [ProofOfPossession] of their secret keys. Proofs of possession are
+//! signatures over the commitment to the public key which are hashed to G1 with the counter
+//! element `[ctr, 1, 0, 0]` instead of `[ctr, 0, 0, 0]`, so that a proof of possession is never a
+//! valid signature over a message and vice versa.
+//!
+//! Field elements of the base field are represented inside the VM as 12 little-endian u32 limbs,
+//! i.e., the limb at index `0` holds the least significant 32 bits of the value.
+
+use alloc::{vec, vec::Vec};
+
+use bls12_381::{G1Affine, G1Projective, G2Affine, G2Projective, Scalar, pairing};
+use crypto_bigint::{
+    Encoding, U384,
+    modular::runtime_mod::{DynResidue, DynResidueParams},
+};
+
+use crate::{
+    Felt, Hasher, ONE, Word, ZERO,
+    errors::Bls12381Error,
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The number of bytes of a serialized secret key.
+pub const SECRET_KEY_BYTES: usize = 32;
+
+/// The number of bytes of a serialized (compressed) public key.
+pub const PUBLIC_KEY_BYTES: usize = 96;
+
+/// The number of bytes of a serialized (compressed) signature.
+pub const SIGNATURE_BYTES: usize = 48;
+
+/// The number of u32 limbs an element of the base field is represented with inside the VM.
+pub const FIELD_ELEMENT_NUM_LIMBS: usize = 12;
+
+/// The number of u32 limbs a public key is represented with inside the VM, i.e., 12 limbs for each
+/// of the two base field coefficients of the two affine coordinates of the public key point.
+pub const PUBLIC_KEY_NUM_LIMBS: usize = 48;
+
+/// The number of u32 limbs a signature is represented with inside the VM, i.e., 12 limbs for each
+/// of the two affine coordinates of the signature point.
+pub const SIGNATURE_NUM_LIMBS: usize = 24;
+
+/// The domain separator with which messages signed via [SecretKey::sign] are hashed to G1.
+const SIGNATURE_DOMAIN: Felt = ZERO;
+
+/// The domain separator with which public key commitments are hashed to G1 for proofs of
+/// possession.
+const PROOF_OF_POSSESSION_DOMAIN: Felt = ONE;
+
+/// The modulus of the BLS12-381 base field.
+const FIELD_MODULUS: U384 = U384::from_be_hex(
+    "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab",
+);
+
+// SECRET KEY
+// ================================================================================================
+
+/// A BLS12-381 secret key.
+#[derive(Clone, Debug)]
+pub struct SecretKey(Scalar);
+
+impl SecretKey {
+    /// Returns a secret key decoded from the provided little-endian scalar bytes.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes do not encode a non-zero scalar smaller than the order of the
+    /// BLS12-381 groups.
+    pub fn from_bytes(bytes: &[u8; SECRET_KEY_BYTES]) -> Result<Self, DeserializationError> {
+        Option::<Scalar>::from(Scalar::from_bytes(bytes))
+            .filter(|scalar| *scalar != Scalar::zero())
+            .map(Self)
+            .ok_or_else(|| {
+                DeserializationError::InvalidValue("invalid BLS12-381 secret key".into())
+            })
+    }
+
+    /// Returns the little-endian scalar bytes of this secret key.
+    pub fn to_bytes(&self) -> [u8; SECRET_KEY_BYTES] {
+        self.0.to_bytes()
+    }
+
+    /// Returns the public key corresponding to this secret key.
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey(G2Affine::from(G2Affine::generator() * self.0))
+    }
+
+    /// Signs the provided message.
+    pub fn sign(&self, message: Word) -> Signature {
+        Signature(self.sign_with_domain(message, SIGNATURE_DOMAIN))
+    }
+
+    /// Returns a proof of possession of this secret key, which is required to aggregate its
+    /// public key with other public keys.
+    pub fn prove_possession(&self) -> ProofOfPossession {
+        let message = self.public_key().commitment();
+        ProofOfPossession(self.sign_with_domain(message, PROOF_OF_POSSESSION_DOMAIN))
+    }
+
+    /// Signs the provided message hashed to G1 with the provided domain separator.
+    fn sign_with_domain(&self, message: Word, domain: Felt) -> G1Affine {
+        G1Affine::from(hash_to_g1_subgroup(message, domain) * self.0)
+    }
+}
+
+impl Serializable for SecretKey {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_bytes(&self.to_bytes());
+    }
+}
+
+impl Deserializable for SecretKey {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let bytes: [u8; SECRET_KEY_BYTES] = source.read_array()?;
+        Self::from_bytes(&bytes)
+    }
+}
+
+// PUBLIC KEY
+// ================================================================================================
+
+/// A BLS12-381 public key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PublicKey(G2Affine);
+
+impl PublicKey {
+    /// Returns the affine coordinates of this public key as 48 u32 limbs: the 12 little-endian
+    /// limbs of each of `x.c0`, `x.c1`, `y.c0` and `y.c1`, in that order.
+    pub fn to_elements(&self) -> [Felt; PUBLIC_KEY_NUM_LIMBS] {
+        // the uncompressed encoding consists of the big-endian encodings of x.c1, x.c0, y.c1 and
+        // y.c0
+        let bytes = self.0.to_uncompressed();
+
+        let mut elements = [ZERO; PUBLIC_KEY_NUM_LIMBS];
+        for (limbs, chunk) in elements.chunks_exact_mut(24).zip(bytes.chunks_exact(96)) {
+            limbs[..12].copy_from_slice(&limbs_from_be_bytes(&chunk[48..]));
+            limbs[12..].copy_from_slice(&limbs_from_be_bytes(&chunk[..48]));
+        }
+        elements
+    }
+
+    /// Returns the commitment to this public key, i.e., the hash of its 48 limbs as returned by
+    /// [PublicKey::to_elements].
+    ///
+    /// This commitment is what auth components store in account storage, since the public key
+    /// itself does not fit into a single [Word].
+    pub fn commitment(&self) -> Word {
+        Hasher::hash_elements(&self.to_elements()).into()
+    }
+
+    /// Returns true if the provided signature over the provided message is valid for this public
+    /// key.
+    pub fn verify(&self, message: Word, signature: &Signature) -> bool {
+        self.verify_with_domain(message, &signature.0, SIGNATURE_DOMAIN)
+    }
+
+    /// Returns true if the provided proof of possession is valid for this public key.
+    pub fn verify_possession(&self, proof: &ProofOfPossession) -> bool {
+        self.verify_with_domain(self.commitment(), &proof.0, PROOF_OF_POSSESSION_DOMAIN)
+    }
+
+    /// Returns the aggregate of the provided public keys, for which the aggregate of signatures by
+    /// the corresponding secret keys over the same message is valid (see [Signature::aggregate]).
+    ///
+    /// Every public key must be accompanied by a proof of possession of its secret key, which
+    /// prevents rogue key attacks. The proofs of possession only have to be checked once, e.g.
+    /// when the public keys of a validator set are registered, so the returned public key can be
+    /// stored and used like any other public key afterwards.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - no public keys are provided.
+    /// - the proof of possession of any of the public keys is invalid.
+    /// - the public keys sum up to the identity point.
+    pub fn aggregate(keys: &[(PublicKey, ProofOfPossession)]) -> Result<Self, Bls12381Error> {
+        if keys.is_empty() {
+            return Err(Bls12381Error::EmptyAggregation);
+        }
+
+        let mut aggregate = G2Projective::identity();
+        for (index, (public_key, proof)) in keys.iter().enumerate() {
+            if !public_key.verify_possession(proof) {
+                return Err(Bls12381Error::InvalidProofOfPossession { index });
+            }
+            aggregate += public_key.0;
+        }
+
+        Some(G2Affine::from(aggregate))
+            .filter(|point| !bool::from(point.is_identity()))
+            .map(Self)
+            .ok_or(Bls12381Error::AggregateIsIdentity)
+    }
+
+    /// Returns true if the provided point is a valid signature over the provided message hashed
+    /// to G1 with the provided domain separator.
+    fn verify_with_domain(&self, message: Word, signature: &G1Affine, domain: Felt) -> bool {
+        let message_point = G1Affine::from(hash_to_g1_subgroup(message, domain));
+        pairing(signature, &G2Affine::generator()) == pairing(&message_point, &self.0)
+    }
+}
+
+#[cfg(any(feature = "testing", test))]
+impl PublicKey {
+    /// Returns a point of the curve of G2 which is not in the prime-order subgroup of G2 as a
+    /// public key.
+    ///
+    /// Signatures can never be valid for such a public key and verifiers must reject it.
+    pub fn outside_subgroup() -> Self {
+        (1..=u8::MAX)
+            .find_map(|x| {
+                let mut bytes = [0u8; PUBLIC_KEY_BYTES];
+                bytes[0] = 0x80;
+                bytes[PUBLIC_KEY_BYTES - 1] = x;
+
+                Option::<G2Affine>::from(G2Affine::from_compressed_unchecked(&bytes))
+                    .filter(|point| !bool::from(point.is_torsion_free()))
+            })
+            .map(Self)
+            .expect("a small x-coordinate should yield a point outside of the subgroup")
+    }
+}
+
+impl From<PublicKey> for Word {
+    fn from(public_key: PublicKey) -> Self {
+        public_key.commitment()
+    }
+}
+
+impl Serializable for PublicKey {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_bytes(&self.0.to_compressed());
+    }
+}
+
+impl Deserializable for PublicKey {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let bytes: [u8; PUBLIC_KEY_BYTES] = source.read_array()?;
+        Option::<G2Affine>::from(G2Affine::from_compressed(&bytes))
+            .filter(|point| !bool::from(point.is_identity()))
+            .map(Self)
+            .ok_or_else(|| {
+                DeserializationError::InvalidValue("invalid BLS12-381 public key".into())
+            })
+    }
+}
+
+// SIGNATURE
+// ================================================================================================
+
+/// A BLS12-381 signature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature(G1Affine);
+
+impl Signature {
+    /// Returns the aggregate of the provided signatures.
+    ///
+    /// The aggregate of signatures over the same message is valid for the aggregate of the
+    /// public keys of the signers, see [PublicKey::aggregate].
+    ///
+    /// # Errors
+    /// Returns an error if no signatures are provided or if the signatures sum up to the identity
+    /// point.
+    pub fn aggregate(signatures: &[Signature]) -> Result<Self, Bls12381Error> {
+        if signatures.is_empty() {
+            return Err(Bls12381Error::EmptyAggregation);
+        }
+
+        let aggregate = signatures
+            .iter()
+            .fold(G1Projective::identity(), |aggregate, signature| aggregate + signature.0);
+
+        Some(G1Affine::from(aggregate))
+            .filter(|point| !bool::from(point.is_identity()))
+            .map(Self)
+            .ok_or(Bls12381Error::AggregateIsIdentity)
+    }
+
+    /// Returns the affine coordinates of this signature as 24 u32 limbs: the 12 little-endian
+    /// limbs of the x-coordinate followed by the 12 little-endian limbs of the y-coordinate.
+    pub fn to_elements(&self) -> [Felt; SIGNATURE_NUM_LIMBS] {
+        let bytes = self.0.to_uncompressed();
+
+        let mut elements = [ZERO; SIGNATURE_NUM_LIMBS];
+        elements[..12].copy_from_slice(&limbs_from_be_bytes(&bytes[..48]));
+        elements[12..].copy_from_slice(&limbs_from_be_bytes(&bytes[48..]));
+        elements
+    }
+}
+
+impl Serializable for Signature {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_bytes(&self.0.to_compressed());
+    }
+}
+
+impl Deserializable for Signature {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let bytes: [u8; SIGNATURE_BYTES] = source.read_array()?;
+        Option::<G1Affine>::from(G1Affine::from_compressed(&bytes))
+            .filter(|point| !bool::from(point.is_identity()))
+            .map(Self)
+            .ok_or_else(|| DeserializationError::InvalidValue("invalid BLS12-381 signature".into()))
+    }
+}
+
+// PROOF OF POSSESSION
+// ================================================================================================
+
+/// A proof of possession of the secret key of a BLS12-381 public key.
+///
+/// It is a signature over the commitment to the public key, which is hashed to G1 with a different
+/// domain separator than messages, see [SecretKey::prove_possession].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProofOfPossession(G1Affine);
+
+impl Serializable for ProofOfPossession {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_bytes(&self.0.to_compressed());
+    }
+}
+
+impl Deserializable for ProofOfPossession {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let bytes: [u8; SIGNATURE_BYTES] = source.read_array()?;
+        Option::<G1Affine>::from(G1Affine::from_compressed(&bytes))
+            .filter(|point| !bool::from(point.is_identity()))
+            .map(Self)
+            .ok_or_else(|| {
+                DeserializationError::InvalidValue("invalid BLS12-381 proof of possession".into())
+            })
+    }
+}
+
+// HASH TO CURVE
+// ================================================================================================
+
+/// Returns the values consumed from the advice stack when hashing the provided message to G1
+/// inside the VM.
+///
+/// These are the counter, followed by a square root of `-(x^3 + 4)` for the candidate
+/// x-coordinate `x` of each smaller counter which is smaller than `p` (proving that `x^3 + 4` is
+/// not a square and the counter thus minimal), followed by the y-coordinate of the point the
+/// message is hashed to. Field elements are represented as 12 little-endian u32 limbs.
+///
+/// Verification inside the VM consumes these values rather than finding the point itself, which
+/// would require computing square roots inside the VM.
+pub fn hash_to_curve_hint(message: Word) -> Vec<Felt> {
+    let (counter, point) = hash_to_g1(message, SIGNATURE_DOMAIN);
+
+    let params = DynResidueParams::new(&FIELD_MODULUS);
+    let four = DynResidue::new(&U384::from_u8(4), params);
+    // since p = 3 mod 4, a^((p + 1) / 4) is a square root of a if a is a square
+    let sqrt_exponent = FIELD_MODULUS.wrapping_add(&U384::ONE).shr_vartime(2);
+
+    let mut hint = vec![Felt::from(counter)];
+    for x in (0..counter).map(|counter| hash_to_x(message, counter, SIGNATURE_DOMAIN)) {
+        let x = U384::from_be_slice(&x);
+        if x >= FIELD_MODULUS {
+            continue;
+        }
+
+        let x = DynResidue::new(&x, params);
+        let non_residue = -(x.square() * x + four);
+        let root = non_residue.pow(&sqrt_exponent);
+        debug_assert_eq!(root.square(), non_residue, "x^3 + 4 should not be a square");
+
+        hint.extend_from_slice(&limbs_from_be_bytes(&root.retrieve().to_be_bytes()));
+    }
+
+    let bytes = point.to_uncompressed();
+    hint.extend_from_slice(&limbs_from_be_bytes(&bytes[48..]));
+    hint
+}
+
+/// Returns the point of G1 the provided message is hashed to with the provided domain separator,
+/// i.e., the point returned by [hash_to_g1] multiplied by the effective cofactor of G1.
+fn hash_to_g1_subgroup(message: Word, domain: Felt) -> G1Projective {
+    let (_, point) = hash_to_g1(message, domain);
+    G1Projective::from(point).clear_cofactor()
+}
+
+/// Returns the smallest counter for which the provided message hashes to the x-coordinate of a
+/// point of the curve with the provided domain separator, together with that point.
+///
+/// The returned point is not multiplied by the effective cofactor of G1 yet.
+fn hash_to_g1(message: Word, domain: Felt) -> (u32, G1Affine) {
+    (0..=u32::MAX)
+        .find_map(|counter| {
+            // set the compression flag, which makes the decoding pick the y-coordinate smaller
+            // than (p + 1) / 2
+            let mut bytes = hash_to_x(message, counter, domain);
+            bytes[0] |= 0x80;
+
+            Option::<G1Affine>::from(G1Affine::from_compressed_unchecked(&bytes))
+                .map(|point| (counter, point))
+        })
+        .expect("a message should hash to a point of the curve for some counter")
+}
+
+/// Returns the big-endian bytes of the candidate x-coordinate the provided message is hashed to
+/// for the specified counter and domain separator, i.e., the 381 least significant bits of
+/// `h(h(MSG, [ctr, domain, 0, 0]), [0, 0, 0, 0]) || h(MSG, [ctr, domain, 0, 0])`.
+///
+/// The candidate may not be smaller than the field modulus.
+fn hash_to_x(message: Word, counter: u32, domain: Felt) -> [u8; 48] {
+    let d1 = Hasher::merge(&[message.into(), [Felt::from(counter), domain, ZERO, ZERO].into()]);
+    let d2 = Hasher::merge(&[d1, Default::default()]);
+
+    // the limbs of the x-coordinate are the low and high halves of the elements of d1, followed by
+    // the low and high halves of the first two elements of d2
+    let mut bytes = [0u8; 48];
+    for (chunk, element) in bytes
+        .rchunks_exact_mut(8)
+        .zip(d1.as_elements().iter().chain(&d2.as_elements()[..2]))
+    {
+        chunk.copy_from_slice(&element.as_int().to_be_bytes());
+    }
+
+    // keep the 381 least significant bits
+    bytes[0] &= 0x1f;
+    bytes
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Converts 48 big-endian bytes into 12 little-endian u32 limbs.
+fn limbs_from_be_bytes(bytes: &[u8]) -> [Felt; FIELD_ELEMENT_NUM_LIMBS] {
+    debug_assert_eq!(bytes.len(), 48);
+
+    let mut limbs = [ZERO; FIELD_ELEMENT_NUM_LIMBS];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.rchunks_exact(4)) {
+        let chunk: [u8; 4] = chunk.try_into().expect("chunk should have 4 bytes");
+        *limb = Felt::from(u32::from_be_bytes(chunk));
+    }
+    limbs
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::*;
+
+    #[test]
+    fn sign_and_verify() {
+        let secret_key = SecretKey::from_bytes(&[7u8; SECRET_KEY_BYTES]).unwrap();
+        let public_key = secret_key.public_key();
+        let message = [ONE, Felt::new(2), Felt::new(3), Felt::new(u64::MAX - 1)];
+
+        let signature = secret_key.sign(message);
+        assert!(public_key.verify(message, &signature));
+        assert!(!public_key.verify([ONE; 4], &signature));
+
+        let decoded = SecretKey::read_from_bytes(&secret_key.to_bytes()).unwrap();
+        assert_eq!(decoded.public_key(), public_key);
+        assert_eq!(PublicKey::read_from_bytes(&public_key.to_bytes()).unwrap(), public_key);
+        assert_eq!(Signature::read_from_bytes(&signature.to_bytes()).unwrap(), signature);
+    }
+
+    #[test]
+    fn hash_to_curve_hint_proves_counter_minimality() {
+        // find a message for which the smallest valid counter is not zero
+        let (message, counter) = (0..)
+            .map(|i| [ONE, Felt::new(2), Felt::new(3), Felt::new(i)])
+            .map(|message| (message, hash_to_g1(message, SIGNATURE_DOMAIN).0))
+            .find(|(_, counter)| *counter > 0)
+            .unwrap();
+
+        let num_candidates = (0..counter)
+            .filter(|&counter| {
+                U384::from_be_slice(&hash_to_x(message, counter, SIGNATURE_DOMAIN)) < FIELD_MODULUS
+            })
+            .count();
+
+        let hint = hash_to_curve_hint(message);
+        assert_eq!(hint[0], Felt::from(counter));
+        assert_eq!(hint.len(), 1 + (num_candidates + 1) * FIELD_ELEMENT_NUM_LIMBS);
+    }
+
+    #[test]
+    fn public_key_outside_subgroup() {
+        let public_key = PublicKey::outside_subgroup();
+        assert!(bool::from(public_key.0.is_on_curve()));
+        assert!(!bool::from(public_key.0.is_torsion_free()));
+    }
+
+    #[test]
+    fn aggregate_signatures_and_public_keys() {
+        let secret_keys: Vec<SecretKey> = (1..=3)
+            .map(|i| SecretKey::from_bytes(&[i; SECRET_KEY_BYTES]).unwrap())
+            .collect();
+        let keys: Vec<(PublicKey, ProofOfPossession)> = secret_keys
+            .iter()
+            .map(|secret_key| (secret_key.public_key(), secret_key.prove_possession()))
+            .collect();
+        let message = [ONE, Felt::new(2), Felt::new(3), Felt::new(4)];
+
+        let public_key = PublicKey::aggregate(&keys).unwrap();
+        let signatures: Vec<Signature> =
+            secret_keys.iter().map(|secret_key| secret_key.sign(message)).collect();
+        let signature = Signature::aggregate(&signatures).unwrap();
+        assert!(public_key.verify(message, &signature));
+
+        // the aggregate signature of a subset of the signers is not valid for the aggregate key
+        let partial_signature = Signature::aggregate(&signatures[..2]).unwrap();
+        assert!(!public_key.verify(message, &partial_signature));
+
+        assert_matches!(PublicKey::aggregate(&[]), Err(Bls12381Error::EmptyAggregation));
+        assert_matches!(Signature::aggregate(&[]), Err(Bls12381Error::EmptyAggregation));
+    }
+
+    #[test]
+    fn aggregation_requires_proofs_of_possession() {
+        let honest_key = SecretKey::from_bytes(&[1; SECRET_KEY_BYTES]).unwrap();
+        let attacker_key = SecretKey::from_bytes(&[2; SECRET_KEY_BYTES]).unwrap();
+
+        // a rogue key cancels out the honest key in the aggregate, so that the attacker can sign
+        // for the aggregate alone, but the attacker cannot prove possession of its secret key
+        let rogue_key = PublicKey(G2Affine::from(
+            attacker_key.public_key().0 - G2Projective::from(honest_key.public_key().0),
+        ));
+        let keys = [
+            (honest_key.public_key(), honest_key.prove_possession()),
+            (rogue_key, attacker_key.prove_possession()),
+        ];
+        assert_matches!(
+            PublicKey::aggregate(&keys),
+            Err(Bls12381Error::InvalidProofOfPossession { index: 1 })
+        );
+
+        // a proof of possession is not a signature over the commitment to the public key
+        let public_key = honest_key.public_key();
+        let proof = honest_key.prove_possession();
+        assert!(public_key.verify_possession(&proof));
+        assert!(!public_key.verify(public_key.commitment(), &Signature(proof.0)));
+        assert!(
+            !public_key
+                .verify_possession(&ProofOfPossession(honest_key.sign(public_key.commitment()).0))
+        );
+    }
+}
@@ -0,0 +1,222 @@
+//! ECDSA signatures over the secp256k1 curve.
+//!
+//! Messages signed with these keys are [Word]s. The 256-bit value which is signed (i.e., the
+//! "prehash" of ECDSA) is the integer whose little-endian encoding is the concatenation of the
+//! little-endian encodings of the four elements of the message word, starting with element `0`.
+//!
+//! 256-bit values are represented inside the VM as 8 little-endian u32 limbs, i.e., the limb at
+//! index `0` holds the least significant 32 bits of the value.
+
+use k256::{
+    Scalar,
+    ecdsa::{
+        self,
+        signature::hazmat::{PrehashSigner, PrehashVerifier},
+    },
+};
+
+use crate::{
+    Felt, Hasher, Word, ZERO,
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The number of bytes of a serialized secret key.
+pub const SECRET_KEY_BYTES: usize = 32;
+
+/// The number of bytes of a serialized (compressed) public key.
+pub const PUBLIC_KEY_BYTES: usize = 33;
+
+/// The number of u32 limbs a public key is represented with inside the VM, i.e., 8 limbs for each
+/// of the two affine coordinates of the public key point.
+pub const PUBLIC_KEY_NUM_LIMBS: usize = 16;
+
+// SECRET KEY
+// ================================================================================================
+
+/// A secp256k1 ECDSA secret key.
+#[derive(Clone, Debug)]
+pub struct SecretKey(ecdsa::SigningKey);
+
+impl SecretKey {
+    /// Returns a secret key decoded from the provided big-endian scalar bytes.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes do not encode a non-zero scalar smaller than the order of the
+    /// secp256k1 group.
+    pub fn from_bytes(bytes: &[u8; SECRET_KEY_BYTES]) -> Result<Self, DeserializationError> {
+        ecdsa::SigningKey::from_slice(bytes)
+            .map(Self)
+            .map_err(|_| DeserializationError::InvalidValue("invalid secp256k1 secret key".into()))
+    }
+
+    /// Returns the big-endian scalar bytes of this secret key.
+    pub fn to_bytes(&self) -> [u8; SECRET_KEY_BYTES] {
+        self.0.to_bytes().into()
+    }
+
+    /// Returns the public key corresponding to this secret key.
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey(*self.0.verifying_key())
+    }
+
+    /// Signs the provided message.
+    ///
+    /// The nonce is derived deterministically from the secret key and the message as specified in
+    /// RFC 6979.
+    pub fn sign(&self, message: Word) -> Signature {
+        let signature: ecdsa::Signature = self
+            .0
+            .sign_prehash(&message_to_prehash(message))
+            .expect("signing a 32-byte prehash should not fail");
+        Signature(signature)
+    }
+}
+
+impl Serializable for SecretKey {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_bytes(&self.to_bytes());
+    }
+}
+
+impl Deserializable for SecretKey {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let bytes: [u8; SECRET_KEY_BYTES] = source.read_array()?;
+        Self::from_bytes(&bytes)
+    }
+}
+
+// PUBLIC KEY
+// ================================================================================================
+
+/// A secp256k1 ECDSA public key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PublicKey(ecdsa::VerifyingKey);
+
+impl PublicKey {
+    /// Returns the affine coordinates of this public key as 16 u32 limbs: the 8 little-endian
+    /// limbs of the x-coordinate followed by the 8 little-endian limbs of the y-coordinate.
+    pub fn to_elements(&self) -> [Felt; PUBLIC_KEY_NUM_LIMBS] {
+        let point = self.0.to_encoded_point(false);
+        let x = point.x().expect("public key should not be the identity");
+        let y = point.y().expect("public key should not be the identity");
+
+        let mut elements = [ZERO; PUBLIC_KEY_NUM_LIMBS];
+        elements[..8].copy_from_slice(&limbs_from_be_bytes(x));
+        elements[8..].copy_from_slice(&limbs_from_be_bytes(y));
+        elements
+    }
+
+    /// Returns the commitment to this public key, i.e., the hash of its 16 limbs as returned by
+    /// [PublicKey::to_elements].
+    ///
+    /// This commitment is what auth components store in account storage, since the public key
+    /// itself does not fit into a single [Word].
+    pub fn commitment(&self) -> Word {
+        Hasher::hash_elements(&self.to_elements()).into()
+    }
+
+    /// Returns true if the provided signature over the provided message is valid for this public
+    /// key.
+    pub fn verify(&self, message: Word, signature: &Signature) -> bool {
+        self.0.verify_prehash(&message_to_prehash(message), &signature.0).is_ok()
+    }
+}
+
+impl From<PublicKey> for Word {
+    fn from(public_key: PublicKey) -> Self {
+        public_key.commitment()
+    }
+}
+
+impl Serializable for PublicKey {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_bytes(self.0.to_encoded_point(true).as_bytes());
+    }
+}
+
+impl Deserializable for PublicKey {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let bytes: [u8; PUBLIC_KEY_BYTES] = source.read_array()?;
+        ecdsa::VerifyingKey::from_sec1_bytes(&bytes)
+            .map(Self)
+            .map_err(|_| DeserializationError::InvalidValue("invalid secp256k1 public key".into()))
+    }
+}
+
+// SIGNATURE
+// ================================================================================================
+
+/// A secp256k1 ECDSA signature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature(ecdsa::Signature);
+
+impl Signature {
+    /// Returns the `r` component of this signature as 8 little-endian u32 limbs.
+    pub fn r(&self) -> [Felt; 8] {
+        limbs_from_be_bytes(&self.0.r().to_bytes())
+    }
+
+    /// Returns the multiplicative inverse of the `s` component of this signature modulo the order
+    /// of the secp256k1 group, as 8 little-endian u32 limbs.
+    ///
+    /// Verification inside the VM consumes `s^-1` rather than `s`, which avoids inverting a scalar
+    /// inside the VM. Since `s` is uniquely determined by its inverse, providing the inverse is
+    /// equivalent to providing `s`.
+    pub fn s_inv(&self) -> [Felt; 8] {
+        let s: Scalar = *self.0.s();
+        let s_inv = s.invert().unwrap();
+        limbs_from_be_bytes(&s_inv.to_bytes())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the 32-byte big-endian ECDSA prehash of the provided message.
+fn message_to_prehash(message: Word) -> [u8; 32] {
+    let mut prehash = [0u8; 32];
+    for (chunk, element) in prehash.chunks_exact_mut(8).zip(message.iter()) {
+        chunk.copy_from_slice(&element.as_int().to_le_bytes());
+    }
+    prehash.reverse();
+    prehash
+}
+
+/// Converts 32 big-endian bytes into 8 little-endian u32 limbs.
+fn limbs_from_be_bytes(bytes: &[u8]) -> [Felt; 8] {
+    debug_assert_eq!(bytes.len(), 32);
+
+    let mut limbs = [ZERO; 8];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.rchunks_exact(4)) {
+        let chunk: [u8; 4] = chunk.try_into().expect("chunk should have 4 bytes");
+        *limb = Felt::from(u32::from_be_bytes(chunk));
+    }
+    limbs
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ONE;
+
+    #[test]
+    fn sign_and_verify() {
+        let secret_key = SecretKey::from_bytes(&[7u8; SECRET_KEY_BYTES]).unwrap();
+        let public_key = secret_key.public_key();
+        let message = [ONE, Felt::new(2), Felt::new(3), Felt::new(u64::MAX - 1)];
+
+        let signature = secret_key.sign(message);
+        assert!(public_key.verify(message, &signature));
+        assert!(!public_key.verify([ONE; 4], &signature));
+
+        let decoded = SecretKey::read_from_bytes(&secret_key.to_bytes()).unwrap();
+        assert_eq!(decoded.public_key(), public_key);
+        assert_eq!(PublicKey::read_from_bytes(&public_key.to_bytes()).unwrap(), public_key);
+    }
+}
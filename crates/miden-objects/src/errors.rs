@@ -1,7 +1,7 @@
 use alloc::{boxed::Box, string::String, vec::Vec};
 use core::error::Error;
 
-use assembly::{Report, diagnostics::reporting::PrintDiagnostic};
+use assembly::{Report, ast::QualifiedProcedureName, diagnostics::reporting::PrintDiagnostic};
 use miden_crypto::utils::HexParseError;
 use thiserror::Error;
 use vm_core::{Felt, FieldElement, mast::MastForestError};
@@ -23,8 +23,10 @@ use crate::{
     },
     batch::BatchId,
     block::BlockNumber,
-    note::{NoteAssets, NoteExecutionHint, NoteTag, NoteType, Nullifier},
-    transaction::TransactionId,
+    note::{
+        NoteAssets, NoteExecutionHint, NoteInputType, NoteTag, NoteTagFilter, NoteType, Nullifier,
+    },
+    transaction::{ScriptArgType, TransactionId},
 };
 
 // ACCOUNT COMPONENT TEMPLATE ERROR
@@ -81,14 +83,24 @@ pub enum AccountError {
     AccountCodeProcedureStorageOffsetTooLarge(Digest, Felt),
     #[error("account procedure {0}'s storage size {1} does not fit into u8")]
     AccountCodeProcedureStorageSizeTooLarge(Digest, Felt),
-    #[error("account procedure {0}'s final two elements must be Felt::ZERO")]
-    AccountCodeProcedureInvalidPadding(Digest),
+    #[error("account procedure {0}'s hook flags {1} must be a value between 0 and 3")]
+    AccountCodeProcedureInvalidHookFlags(Digest, Felt),
+    #[error("account procedure {0}'s caller restrictions {1} must be a value between 0 and 7")]
+    AccountCodeProcedureInvalidCallerRestrictions(Digest, Felt),
+    #[error(
+        "account code contains more than one final state validator, i.e. procedures {0} and {1}"
+    )]
+    AccountCodeMultipleFinalStateValidators(Digest, Digest),
+    #[error("account code contains more than one asset filter, i.e. procedures {0} and {1}")]
+    AccountCodeMultipleAssetFilters(Digest, Digest),
     #[error("failed to assemble account component:\n{}", PrintDiagnostic::new(.0))]
     AccountComponentAssemblyError(Report),
     #[error("failed to merge components into one account code mast forest")]
     AccountComponentMastForestMergeError(#[source] MastForestError),
     #[error("procedure with MAST root {0} is present in multiple account components")]
     AccountComponentDuplicateProcedureRoot(Digest),
+    #[error("account component restricts the callers of procedure {0} which it does not export")]
+    AccountComponentUnknownProcedure(String),
     #[error("failed to create account component")]
     AccountComponentTemplateInstantiationError(#[source] AccountComponentTemplateError),
     #[error("failed to update asset vault")]
@@ -101,6 +113,10 @@ pub enum AccountError {
     FungibleFaucetMaxSupplyTooLarge { actual: u64, max: u64 },
     #[error("account header data has length {actual} but it must be of length {expected}")]
     HeaderDataIncorrectLength { actual: usize, expected: usize },
+    #[error(
+        "number of non-fungible assets to distribute in a batch is {actual} but must be between 1 and {max}"
+    )]
+    NonFungibleFaucetInvalidBatchSize { actual: usize, max: usize },
     #[error("new account nonce {new} is less than the current nonce {current}")]
     NonceNotMonotonicallyIncreasing { current: u64, new: u64 },
     #[error(
@@ -183,6 +199,19 @@ pub enum Bech32Error {
     InvalidDataLength { expected: usize, actual: usize },
 }
 
+// BLS12-381 ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum Bls12381Error {
+    #[error("cannot aggregate an empty set of BLS12-381 public keys or signatures")]
+    EmptyAggregation,
+    #[error("proof of possession of the BLS12-381 public key at index {index} is invalid")]
+    InvalidProofOfPossession { index: usize },
+    #[error("aggregate of the BLS12-381 public keys or signatures is the identity point")]
+    AggregateIsIdentity,
+}
+
 // NETWORK ID ERROR
 // ================================================================================================
 
@@ -319,24 +348,54 @@ pub enum NoteError {
     DuplicateFungibleAsset(AccountId),
     #[error("duplicate non fungible asset {0} in note")]
     DuplicateNonFungibleAsset(NonFungibleAsset),
+    #[error("note input name `{0}` is declared more than once")]
+    DuplicateNoteInputName(String),
     #[error("note type {0:?} is inconsistent with note tag {1}")]
     InconsistentNoteTag(NoteType, u64),
     #[error("adding fungible asset amounts would exceed maximum allowed amount")]
     AddFungibleAssetBalanceError(#[source] AssetError),
     #[error("note sender is not a valid account ID")]
     NoteSenderInvalidAccountId(#[source] AccountIdError),
+    #[error("note sender commitment with elements {0} and {1} is not in canonical form")]
+    NoteSenderInvalidCommitment(Felt, Felt),
     #[error("note tag use case {0} must be less than 2^{exp}", exp = NoteTag::MAX_USE_CASE_ID_EXPONENT)]
     NoteTagUseCaseTooLarge(u16),
+    #[error("note tag filter prefix length {0} exceeds the maximum of {max}", max = NoteTagFilter::MAX_PREFIX_LENGTH)]
+    NoteTagFilterPrefixLengthTooLarge(u8),
     #[error(
         "note execution hint tag {0} must be in range {from}..={to}",
         from = NoteExecutionHint::NONE_TAG,
-        to = NoteExecutionHint::ON_BLOCK_SLOT_TAG,
+        to = NoteExecutionHint::AFTER_TIMESTAMP_TAG,
     )]
     NoteExecutionHintTagOutOfRange(u8),
     #[error("note execution hint after block variant cannot contain u32::MAX")]
     NoteExecutionHintAfterBlockCannotBeU32Max,
+    #[error("note execution hint until block variant cannot contain u32::MAX")]
+    NoteExecutionHintUntilBlockCannotBeU32Max,
+    #[error("note execution hint after timestamp variant cannot contain u32::MAX")]
+    NoteExecutionHintAfterTimestampCannotBeU32Max,
     #[error("invalid note execution hint payload {1} for tag {0}")]
     InvalidNoteExecutionHintPayload(u8, u32),
+    #[error("note input `{name}` is not a valid account ID")]
+    InvalidNoteInputAccountId {
+        name: String,
+        #[source]
+        source: AccountIdError,
+    },
+    #[error("note input `{name}` has value {value} which is not a valid {input_type}")]
+    InvalidNoteInputValue {
+        name: String,
+        input_type: NoteInputType,
+        value: Felt,
+    },
+    #[error("large note inputs values are not a valid byte encoding")]
+    InvalidLargeInputsByteEncoding,
+    #[error("large note inputs length {0} must be non-zero and fit into a u32")]
+    InvalidLargeInputsLength(usize),
+    #[error("large note inputs padding must consist of zeros")]
+    InvalidLargeInputsPadding,
+    #[error("large note inputs commitment {actual} does not match expected commitment {expected}")]
+    LargeInputsCommitmentMismatch { expected: Digest, actual: Digest },
     #[error("note type {0:b} does not match any of the valid note types {public}, {private} or {encrypted}",
       public = NoteType::Public as u8,
       private = NoteType::Private as u8,
@@ -348,6 +407,8 @@ pub enum NoteError {
         node_index_in_block: u16,
         highest_index: usize,
     },
+    #[error("note inputs layout expects {expected} input values but {actual} were provided")]
+    NoteInputsLayoutMismatch { expected: usize, actual: usize },
     #[error("note network execution requires public accounts")]
     NetworkExecutionRequiresPublicAccount,
     #[error("note network execution requires a public note but note is of type {0:?}")]
@@ -360,6 +421,10 @@ pub enum NoteError {
     PublicUseCaseRequiresPublicNote(NoteType),
     #[error("note contains {0} assets which exceeds the maximum of {max}", max = NoteAssets::MAX_NUM_ASSETS)]
     TooManyAssets(usize),
+    #[error("note must contain at least one asset")]
+    EmptyAssets,
+    #[error("fungible asset issued by faucet {0} has a zero amount")]
+    ZeroAmountFungibleAsset(AccountId),
     #[error("note contains {0} inputs which exceeds the maximum of {max}", max = MAX_INPUTS_PER_NOTE)]
     TooManyInputs(usize),
 }
@@ -401,6 +466,49 @@ impl ChainMmrError {
 pub enum TransactionScriptError {
     #[error("failed to assemble transaction script:\n{}", PrintDiagnostic::new(.0))]
     AssemblyError(Report),
+    #[error(
+        "transaction script argument {index} has value {value} which exceeds the field modulus"
+    )]
+    ScriptArgOutOfRange { index: usize, value: u64 },
+    #[error(
+        "transaction script argument {index} has type {actual:?} but the layout declares {expected:?}"
+    )]
+    ScriptArgTypeMismatch {
+        index: usize,
+        expected: ScriptArgType,
+        actual: ScriptArgType,
+    },
+    #[error(
+        "transaction script arguments layout declares {expected} arguments but {actual} were provided"
+    )]
+    TooFewScriptArgs { expected: usize, actual: usize },
+    #[error("transaction script arguments layout declares only {0} arguments")]
+    TooManyScriptArgs(usize),
+    #[error("procedure {0} is not exported by the provided library")]
+    ProcedureNotExported(QualifiedProcedureName),
+    #[error(
+        "procedure with root {0} declared by the transaction script is not a procedure of the account"
+    )]
+    UnknownAccountProcedure(Digest),
+    #[error(
+        "transaction script calls procedure with root {0} which is not declared in its interface"
+    )]
+    UndeclaredProcedureCall(Digest),
+}
+
+// TRANSACTION ARGS ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum TransactionArgsError {
+    #[error(
+        "advice map entry for key {key} in namespace {namespace} collides with an existing entry under the derived key {derived_key}"
+    )]
+    AdviceMapKeyCollision {
+        namespace: Digest,
+        key: Digest,
+        derived_key: Digest,
+    },
 }
 
 // TRANSACTION INPUT ERROR
@@ -419,6 +527,8 @@ pub enum TransactionInputError {
     AnchorBlockHeaderNotProvidedForNewAccount(u16),
     #[error("transaction input note with nullifier {0} is a duplicate")]
     DuplicateInputNote(Nullifier),
+    #[error("the state of new account {0} cannot be redacted from a transaction witness")]
+    NewAccountCannotBeRedacted(AccountId),
     #[error(
         "ID {expected} of the new account does not match the ID {actual} computed from the provided seed"
     )]
@@ -442,6 +552,19 @@ pub enum TransactionInputError {
         "total number of input notes is {0} which exceeds the maximum of {MAX_INPUT_NOTES_PER_TX}"
     )]
     TooManyInputNotes(usize),
+    #[error(
+        "total number of input notes is {num_notes} which exceeds the maximum of {max_notes}, the notes can be split into {} transactions",
+        suggested_split.len()
+    )]
+    InputNotesSplitRequired {
+        num_notes: usize,
+        max_notes: usize,
+        suggested_split: Vec<Vec<Nullifier>>,
+    },
+    #[error(
+        "group of {num_notes} dependent input notes exceeds the maximum of {max_notes} input notes per transaction"
+    )]
+    DependentInputNotesExceedLimit { num_notes: usize, max_notes: usize },
 }
 
 // TRANSACTION OUTPUT ERROR
@@ -451,6 +574,8 @@ pub enum TransactionInputError {
 pub enum TransactionOutputError {
     #[error("transaction output note with id {0} is a duplicate")]
     DuplicateOutputNote(NoteId),
+    #[error("transaction fee asset on the kernel output stack is invalid")]
+    FeeAssetInvalid(#[source] AssetError),
     #[error("final account commitment is not in the advice map")]
     FinalAccountHashMissingInAdviceMap,
     #[error("failed to parse final account header")]
@@ -538,6 +663,16 @@ pub enum ProposedBatchError {
         reference_block_num: BlockNumber,
     },
 
+    #[error(
+        "input note {note_id} consumed by transaction {transaction_id} expires at block number {note_expiration_block_num} which is not greater than the number of the batch's reference block {reference_block_num}"
+    )]
+    ExpiredInputNote {
+        transaction_id: TransactionId,
+        note_id: NoteId,
+        note_expiration_block_num: BlockNumber,
+        reference_block_num: BlockNumber,
+    },
+
     #[error("transaction batch must contain at least one transaction")]
     EmptyTransactionBatch,
 
@@ -632,6 +767,16 @@ pub enum ProposedBlockError {
         current_block_num: BlockNumber,
     },
 
+    #[error(
+        "input note {note_id} consumed by batch {batch_id} expired at block {note_expiration_block_num} but the current block number is {current_block_num}"
+    )]
+    ExpiredInputNote {
+        batch_id: BatchId,
+        note_id: NoteId,
+        note_expiration_block_num: BlockNumber,
+        current_block_num: BlockNumber,
+    },
+
     #[error("batch {batch_id} appears twice in the block inputs")]
     DuplicateBatch { batch_id: BatchId },
 
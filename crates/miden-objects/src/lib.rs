@@ -25,9 +25,9 @@ mod errors;
 pub use constants::*;
 pub use errors::{
     AccountDeltaError, AccountError, AccountIdError, AssetError, AssetVaultError,
-    BatchAccountUpdateError, ChainMmrError, NoteError, NullifierTreeError, ProposedBatchError,
-    ProposedBlockError, ProvenTransactionError, TransactionInputError, TransactionOutputError,
-    TransactionScriptError,
+    BatchAccountUpdateError, Bls12381Error, ChainMmrError, NoteError, NullifierTreeError,
+    ProposedBatchError, ProposedBlockError, ProvenTransactionError, TransactionArgsError,
+    TransactionInputError, TransactionOutputError, TransactionScriptError,
 };
 pub use miden_crypto::hash::rpo::{Rpo256 as Hasher, RpoDigest as Digest};
 pub use vm_core::{
@@ -47,6 +47,9 @@ pub mod assembly {
 
 pub mod crypto {
     pub use miden_crypto::{dsa, hash, merkle, rand, utils};
+
+    pub mod bls12_381;
+    pub mod ecdsa_k256;
 }
 
 pub mod utils {
@@ -210,6 +213,6 @@ pub mod utils {
 
 pub mod vm {
     pub use miden_verifier::ExecutionProof;
-    pub use vm_core::{AdviceMap, Program, ProgramInfo, sys_events::SystemEvent};
+    pub use vm_core::{AdviceMap, Operation, Program, ProgramInfo, sys_events::SystemEvent};
     pub use vm_processor::{AdviceInputs, RowIndex, StackInputs, StackOutputs};
 }
@@ -64,7 +64,9 @@ impl NoteDetails {
 
     /// Returns the note's nullifier.
     ///
-    /// This is public data, used to prevent double spend.
+    /// This is public data, used to prevent double spend. Since the nullifier does not depend on
+    /// the note's metadata, it can be computed from the note details alone, e.g. by services
+    /// tracking the consumption of notes they only know off-chain.
     pub fn nullifier(&self) -> Nullifier {
         Nullifier::from(self)
     }
@@ -48,6 +48,25 @@ pub enum NoteExecutionHint {
         slot_len: u8,
         slot_offset: u8,
     },
+    /// The note's script can be executed in blocks before the specified block number.
+    ///
+    /// Unlike the other variants, this hint is enforced by the transaction kernel: a note with
+    /// this hint can only be consumed by a transaction whose reference block is before the
+    /// specified block, and the expiration block number of such a transaction is set to at most
+    /// the specified block number.
+    ///
+    /// The block number cannot be [`u32::MAX`] which is enforced by
+    /// [`NoteExecutionHint::until_block`].
+    UntilBlock { block_num: BlockNumber },
+    /// The note's script can be executed at or after the specified UNIX timestamp (in seconds).
+    ///
+    /// Since the time between blocks can vary, this allows expressing conditions in wall-clock
+    /// time. The hint is not enforced by the transaction kernel and is meant to be interpreted by
+    /// clients and network operators, e.g. by comparing it to the timestamp of the latest block.
+    ///
+    /// The timestamp cannot be [`u32::MAX`] which is enforced by
+    /// [`NoteExecutionHint::after_timestamp`].
+    AfterTimestamp { timestamp: u32 },
 }
 
 impl NoteExecutionHint {
@@ -58,6 +77,8 @@ impl NoteExecutionHint {
     pub(crate) const ALWAYS_TAG: u8 = 1;
     pub(crate) const AFTER_BLOCK_TAG: u8 = 2;
     pub(crate) const ON_BLOCK_SLOT_TAG: u8 = 3;
+    pub(crate) const UNTIL_BLOCK_TAG: u8 = 4;
+    pub(crate) const AFTER_TIMESTAMP_TAG: u8 = 5;
 
     // CONSTRUCTORS
     // ------------------------------------------------------------------------------------------------
@@ -82,6 +103,32 @@ impl NoteExecutionHint {
             .map(|block_number| NoteExecutionHint::AfterBlock { block_num: block_number })
     }
 
+    /// Creates a [NoteExecutionHint::UntilBlock] variant based on the given `block_num`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `block_num` is equal to [`u32::MAX`].
+    pub fn until_block(block_num: BlockNumber) -> Result<Self, NoteError> {
+        if block_num.as_u32() == u32::MAX {
+            return Err(NoteError::NoteExecutionHintUntilBlockCannotBeU32Max);
+        }
+
+        Ok(NoteExecutionHint::UntilBlock { block_num })
+    }
+
+    /// Creates a [NoteExecutionHint::AfterTimestamp] variant based on the given `timestamp`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `timestamp` is equal to [`u32::MAX`].
+    pub fn after_timestamp(timestamp: u32) -> Result<Self, NoteError> {
+        if timestamp == u32::MAX {
+            return Err(NoteError::NoteExecutionHintAfterTimestampCannotBeU32Max);
+        }
+
+        Ok(NoteExecutionHint::AfterTimestamp { timestamp })
+    }
+
     /// Creates a [NoteExecutionHint::OnBlockSlot] for the given parameters. See the variants
     /// documentation for details on the parameters.
     pub fn on_block_slot(round_len: u8, slot_len: u8, slot_offset: u8) -> Self {
@@ -116,12 +163,17 @@ impl NoteExecutionHint {
 
                 Ok(hint)
             },
+            Self::UNTIL_BLOCK_TAG => NoteExecutionHint::until_block(payload.into()),
+            Self::AFTER_TIMESTAMP_TAG => NoteExecutionHint::after_timestamp(payload),
             _ => Err(NoteError::NoteExecutionHintTagOutOfRange(tag)),
         }
     }
 
     /// Returns whether the note execution conditions validate for the given `block_num`
     ///
+    /// Timestamp-based hints cannot be evaluated from a block number alone, see
+    /// [`Self::can_be_consumed_at`] for these.
+    ///
     /// # Returns
     /// - `None` if we don't know whether the note can be consumed.
     /// - `Some(true)` if the note is consumable for the given `block_num`
@@ -147,6 +199,35 @@ impl NoteExecutionHint {
                 let can_be_consumed = block_num >= slot_start_block && block_num < slot_end_block;
                 Some(can_be_consumed)
             },
+            NoteExecutionHint::UntilBlock { block_num: expiration_block_num } => {
+                Some(block_num < expiration_block_num.as_u32())
+            },
+            NoteExecutionHint::AfterTimestamp { .. } => None,
+        }
+    }
+
+    /// Returns whether the note execution conditions validate for the given `block_num` and
+    /// `timestamp`, i.e., the UNIX timestamp (in seconds) of the block.
+    ///
+    /// # Returns
+    /// - `None` if we don't know whether the note can be consumed.
+    /// - `Some(true)` if the note is consumable for the given `block_num` and `timestamp`
+    /// - `Some(false)` if the note is not consumable for the given `block_num` and `timestamp`
+    pub fn can_be_consumed_at(&self, block_num: BlockNumber, timestamp: u32) -> Option<bool> {
+        match self {
+            NoteExecutionHint::AfterTimestamp { timestamp: hint_timestamp } => {
+                Some(timestamp >= *hint_timestamp)
+            },
+            _ => self.can_be_consumed(block_num),
+        }
+    }
+
+    /// Returns the number of the block after which a note with this hint can no longer be
+    /// consumed, or `None` if this hint does not impose an expiration.
+    pub fn expiration_block_num(&self) -> Option<BlockNumber> {
+        match self {
+            NoteExecutionHint::UntilBlock { block_num } => Some(*block_num),
+            _ => None,
         }
     }
 
@@ -171,6 +252,12 @@ impl NoteExecutionHint {
                     ((*round_len as u32) << 16) | ((*slot_len as u32) << 8) | (*slot_offset as u32);
                 (Self::ON_BLOCK_SLOT_TAG, payload)
             },
+            NoteExecutionHint::UntilBlock { block_num } => {
+                (Self::UNTIL_BLOCK_TAG, block_num.as_u32())
+            },
+            NoteExecutionHint::AfterTimestamp { timestamp } => {
+                (Self::AFTER_TIMESTAMP_TAG, *timestamp)
+            },
         }
     }
 }
@@ -274,6 +361,8 @@ mod tests {
             slot_len: 12,
             slot_offset: 18,
         });
+        assert_hint_serde(NoteExecutionHint::until_block(15.into()).unwrap());
+        assert_hint_serde(NoteExecutionHint::after_timestamp(1_700_000_000).unwrap());
     }
 
     #[test]
@@ -318,6 +407,17 @@ mod tests {
         assert!(on_block_slot.can_be_consumed(2176.into()).unwrap()); // Block 2176 is in the slot 2176..2303
         assert!(!on_block_slot.can_be_consumed(2175.into()).unwrap()); // Block 1279 is in the slot
         // 2176..2303
+
+        let until_block = NoteExecutionHint::until_block(12345.into()).unwrap();
+        assert!(until_block.can_be_consumed(12344.into()).unwrap());
+        assert!(!until_block.can_be_consumed(12345.into()).unwrap());
+        assert!(!until_block.can_be_consumed(12346.into()).unwrap());
+
+        let after_timestamp = NoteExecutionHint::after_timestamp(1_700_000_000).unwrap();
+        assert!(after_timestamp.can_be_consumed(100.into()).is_none());
+        assert!(!after_timestamp.can_be_consumed_at(100.into(), 1_699_999_999).unwrap());
+        assert!(after_timestamp.can_be_consumed_at(100.into(), 1_700_000_000).unwrap());
+        assert!(always.can_be_consumed_at(100.into(), 0).unwrap());
     }
 
     #[test]
@@ -337,5 +437,13 @@ mod tests {
             NoteExecutionHint::after_block(u32::MAX.into()).unwrap_err(),
             NoteError::NoteExecutionHintAfterBlockCannotBeU32Max
         );
+        assert_matches!(
+            NoteExecutionHint::until_block(u32::MAX.into()).unwrap_err(),
+            NoteError::NoteExecutionHintUntilBlockCannotBeU32Max
+        );
+        assert_matches!(
+            NoteExecutionHint::after_timestamp(u32::MAX).unwrap_err(),
+            NoteError::NoteExecutionHintAfterTimestampCannotBeU32Max
+        );
     }
 }
@@ -11,7 +11,7 @@ use vm_core::utils::SliceReader;
 use vm_core::utils::{ByteReader, ByteWriter, Deserializable, Serializable};
 use vm_processor::DeserializationError;
 
-use super::{Note, NoteDetails, NoteId, NoteInclusionProof, NoteTag};
+use super::{Note, NoteDetails, NoteId, NoteInclusionProof, NotePayload, NoteTag};
 use crate::block::BlockNumber;
 
 const MAGIC: &str = "note";
@@ -31,10 +31,14 @@ pub enum NoteFile {
     ///
     /// An optional tag specifies the tag associated with the note, though this also should be
     /// treated as a hint.
+    ///
+    /// An optional payload carries off-chain data attached to the note, which can be verified
+    /// against the commitment in the note inputs via [NotePayload::verify].
     NoteDetails {
         details: NoteDetails,
         after_block_num: BlockNumber,
         tag: Option<NoteTag>,
+        payload: Option<NotePayload>,
     },
     /// The note has been recorded on chain.
     NoteWithProof(Note, NoteInclusionProof),
@@ -65,6 +69,7 @@ impl From<NoteDetails> for NoteFile {
             details,
             after_block_num: 0.into(),
             tag: None,
+            payload: None,
         }
     }
 }
@@ -86,11 +91,12 @@ impl Serializable for NoteFile {
                 target.write_u8(0);
                 note_id.write_into(target);
             },
-            NoteFile::NoteDetails { details, after_block_num, tag } => {
+            NoteFile::NoteDetails { details, after_block_num, tag, payload } => {
                 target.write_u8(1);
                 details.write_into(target);
                 after_block_num.write_into(target);
                 tag.write_into(target);
+                payload.write_into(target);
             },
             NoteFile::NoteWithProof(note, proof) => {
                 target.write_u8(2);
@@ -115,7 +121,8 @@ impl Deserializable for NoteFile {
                 let details = NoteDetails::read_from(source)?;
                 let after_block_num = BlockNumber::read_from(source)?;
                 let tag = Option::<NoteTag>::read_from(source)?;
-                Ok(NoteFile::NoteDetails { details, after_block_num, tag })
+                let payload = Option::<NotePayload>::read_from(source)?;
+                Ok(NoteFile::NoteDetails { details, after_block_num, tag, payload })
             },
             2 => {
                 let note = Note::read_from(source)?;
@@ -146,7 +153,7 @@ mod tests {
         asset::{Asset, FungibleAsset},
         block::BlockNumber,
         note::{
-            Note, NoteAssets, NoteFile, NoteInclusionProof, NoteInputs, NoteMetadata,
+            Note, NoteAssets, NoteFile, NoteInclusionProof, NoteInputs, NoteMetadata, NotePayload,
             NoteRecipient, NoteScript, NoteTag, NoteType,
         },
         testing::account_id::{
@@ -212,6 +219,7 @@ mod tests {
             details: note.details.clone(),
             after_block_num: 456.into(),
             tag: Some(NoteTag::from(123)),
+            payload: Some(NotePayload::new("invoice")),
         };
         let mut buffer = Vec::new();
         file.write_into(&mut buffer);
@@ -219,10 +227,11 @@ mod tests {
         let file_copy = NoteFile::read_from_bytes(&buffer).unwrap();
 
         match file_copy {
-            NoteFile::NoteDetails { details, after_block_num, tag } => {
+            NoteFile::NoteDetails { details, after_block_num, tag, payload } => {
                 assert_eq!(details, note.details);
                 assert_eq!(after_block_num, 456.into());
                 assert_eq!(tag, Some(NoteTag::from(123)));
+                assert_eq!(payload, Some(NotePayload::new("invoice")));
             },
             _ => panic!("Invalid note file variant"),
         }
@@ -0,0 +1,229 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use super::NoteInputs;
+use crate::{
+    Felt, MAX_INPUTS_PER_NOTE, NoteError, WORD_SIZE,
+    account::AccountId,
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
+
+// NOTE INPUT TYPE
+// ================================================================================================
+
+/// The type of a single entry of a [NoteInputLayout].
+///
+/// Each type occupies a fixed number of input values and imposes constraints on those values,
+/// which allows catching inputs which were provided in the wrong order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum NoteInputType {
+    /// An arbitrary field element.
+    Felt = 0,
+    /// A field element which must fit into a u8.
+    U8 = 1,
+    /// A field element which must fit into a u16.
+    U16 = 2,
+    /// A field element which must fit into a u32.
+    U32 = 3,
+    /// Four arbitrary field elements.
+    Word = 4,
+    /// An account ID encoded as `[suffix, prefix]`, i.e. in the same order as it is read onto the
+    /// stack by `mem_loadw`.
+    AccountId = 5,
+}
+
+impl NoteInputType {
+    /// Returns the number of input values occupied by this type.
+    pub const fn num_values(&self) -> usize {
+        match self {
+            Self::Felt | Self::U8 | Self::U16 | Self::U32 => 1,
+            Self::Word => WORD_SIZE,
+            Self::AccountId => 2,
+        }
+    }
+
+    /// Validates that the provided values are a valid encoding of this type.
+    ///
+    /// The number of values is expected to be equal to [Self::num_values].
+    fn validate(&self, name: &str, values: &[Felt]) -> Result<(), NoteError> {
+        debug_assert_eq!(values.len(), self.num_values());
+
+        let max_value = match self {
+            Self::Felt | Self::Word => return Ok(()),
+            Self::AccountId => {
+                return AccountId::try_from([values[1], values[0]]).map(|_| ()).map_err(|source| {
+                    NoteError::InvalidNoteInputAccountId { name: name.into(), source }
+                });
+            },
+            Self::U8 => u8::MAX as u64,
+            Self::U16 => u16::MAX as u64,
+            Self::U32 => u32::MAX as u64,
+        };
+
+        if values[0].as_int() > max_value {
+            return Err(NoteError::InvalidNoteInputValue {
+                name: name.into(),
+                input_type: *self,
+                value: values[0],
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for NoteInputType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Felt => write!(f, "felt"),
+            Self::U8 => write!(f, "u8"),
+            Self::U16 => write!(f, "u16"),
+            Self::U32 => write!(f, "u32"),
+            Self::Word => write!(f, "word"),
+            Self::AccountId => write!(f, "account_id"),
+        }
+    }
+}
+
+impl TryFrom<u8> for NoteInputType {
+    type Error = DeserializationError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Felt),
+            1 => Ok(Self::U8),
+            2 => Ok(Self::U16),
+            3 => Ok(Self::U32),
+            4 => Ok(Self::Word),
+            5 => Ok(Self::AccountId),
+            _ => {
+                Err(DeserializationError::InvalidValue(format!("invalid note input type: {value}")))
+            },
+        }
+    }
+}
+
+// NOTE INPUT LAYOUT
+// ================================================================================================
+
+/// Describes the expected layout of the inputs of a note script.
+///
+/// The layout is an ordered list of named entries, each with a [NoteInputType]. The entries are
+/// laid out in the note inputs in the order in which they were declared, and the total number of
+/// values occupied by the entries must be equal to the number of note inputs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NoteInputLayout {
+    entries: Vec<(String, NoteInputType)>,
+}
+
+impl NoteInputLayout {
+    /// Returns a new [NoteInputLayout] instantiated from the provided entries.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - any name is used more than once.
+    /// - the entries occupy more than 128 input values.
+    pub fn new(
+        entries: impl IntoIterator<Item = (impl Into<String>, NoteInputType)>,
+    ) -> Result<Self, NoteError> {
+        let mut layout = Self::default();
+        for (name, input_type) in entries {
+            layout.push(name.into(), input_type)?;
+        }
+
+        Ok(layout)
+    }
+
+    /// Returns the entries of this layout.
+    pub fn entries(&self) -> &[(String, NoteInputType)] {
+        &self.entries
+    }
+
+    /// Returns the total number of input values occupied by the entries of this layout.
+    pub fn num_values(&self) -> usize {
+        self.entries.iter().map(|(_, input_type)| input_type.num_values()).sum()
+    }
+
+    /// Returns the offset of the first input value of the entry with the provided name, or `None`
+    /// if there is no such entry.
+    pub fn offset_of(&self, name: &str) -> Option<usize> {
+        let mut offset = 0;
+        for (entry_name, input_type) in self.entries.iter() {
+            if entry_name == name {
+                return Some(offset);
+            }
+            offset += input_type.num_values();
+        }
+
+        None
+    }
+
+    /// Validates the provided note inputs against this layout.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - the number of note inputs is not equal to the number of values of this layout.
+    /// - any of the entries is not a valid value of its declared type.
+    pub fn validate(&self, inputs: &NoteInputs) -> Result<(), NoteError> {
+        let values = inputs.values();
+        if values.len() != self.num_values() {
+            return Err(NoteError::NoteInputsLayoutMismatch {
+                expected: self.num_values(),
+                actual: values.len(),
+            });
+        }
+
+        let mut offset = 0;
+        for (name, input_type) in self.entries.iter() {
+            let num_values = input_type.num_values();
+            input_type.validate(name, &values[offset..offset + num_values])?;
+            offset += num_values;
+        }
+
+        Ok(())
+    }
+
+    /// Appends an entry to this layout.
+    fn push(&mut self, name: String, input_type: NoteInputType) -> Result<(), NoteError> {
+        if self.offset_of(&name).is_some() {
+            return Err(NoteError::DuplicateNoteInputName(name));
+        }
+
+        let num_values = self.num_values() + input_type.num_values();
+        if num_values > MAX_INPUTS_PER_NOTE {
+            return Err(NoteError::TooManyInputs(num_values));
+        }
+
+        self.entries.push((name, input_type));
+
+        Ok(())
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for NoteInputLayout {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.entries.len() as u8);
+        for (name, input_type) in self.entries.iter() {
+            name.write_into(target);
+            target.write_u8(*input_type as u8);
+        }
+    }
+}
+
+impl Deserializable for NoteInputLayout {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_entries = source.read_u8()?;
+        let mut entries = Vec::with_capacity(num_entries as usize);
+        for _ in 0..num_entries {
+            let name = String::read_from(source)?;
+            let input_type = NoteInputType::try_from(source.read_u8()?)?;
+            entries.push((name, input_type));
+        }
+
+        Self::new(entries).map_err(|err| DeserializationError::InvalidValue(format!("{err}")))
+    }
+}
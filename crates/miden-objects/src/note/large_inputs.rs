@@ -0,0 +1,307 @@
+use alloc::vec::Vec;
+
+use super::NoteInputs;
+use crate::{
+    Digest, Felt, Hasher, MAX_INPUTS_PER_NOTE, WORD_SIZE, ZERO,
+    errors::NoteError,
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
+
+// LARGE NOTE INPUTS
+// ================================================================================================
+
+/// A container for note input data which does not fit into [NoteInputs].
+///
+/// A note can be associated with at most 128 input values. To attach more data to a note, the data
+/// is split into chunks of 8 elements (padding the last chunk with ZEROs), and the chunks are
+/// sequentially hashed into a single commitment. The commitment and the number of values are then
+/// stored in the note inputs, while the data itself is provided via the advice map when the note
+/// is consumed.
+///
+/// The note inputs of a note with large inputs have the following layout:
+///
+/// `[DATA_COMMITMENT, num_values, ADDITIONAL_INPUTS]`
+///
+/// where `ADDITIONAL_INPUTS` are at most 123 arbitrary input values.
+///
+/// On the kernel side, the data can be loaded into memory and verified against the commitment via
+/// the `miden::note::get_large_inputs` procedure.
+#[derive(Clone, Debug)]
+pub struct LargeNoteInputs {
+    values: Vec<Felt>,
+    commitment: Digest,
+}
+
+impl LargeNoteInputs {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The number of values hashed together in each chunk.
+    pub const CHUNK_SIZE: usize = 2 * WORD_SIZE;
+
+    /// The number of note inputs used to store the commitment and the number of values.
+    pub const NUM_RESERVED_INPUTS: usize = WORD_SIZE + 1;
+
+    /// The number of bytes encoded in a single field element by [LargeNoteInputs::from_bytes].
+    const BYTES_PER_FELT: usize = 7;
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns [LargeNoteInputs] instantiated from the provided values.
+    ///
+    /// # Errors
+    /// Returns an error if the provided values are empty or their number does not fit into a u32.
+    pub fn new(values: Vec<Felt>) -> Result<Self, NoteError> {
+        if values.is_empty() || u32::try_from(values.len()).is_err() {
+            return Err(NoteError::InvalidLargeInputsLength(values.len()));
+        }
+
+        let commitment = Hasher::hash_elements(&pad_values(&values));
+
+        Ok(Self { values, commitment })
+    }
+
+    /// Returns [LargeNoteInputs] encoding the provided bytes.
+    ///
+    /// The first value stores the number of bytes, followed by the bytes packed into field
+    /// elements in little-endian order, 7 bytes per element.
+    ///
+    /// # Errors
+    /// Returns an error if the number of bytes does not fit into a u32.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, NoteError> {
+        let num_bytes = u32::try_from(bytes.len())
+            .map_err(|_| NoteError::InvalidLargeInputsLength(bytes.len()))?;
+
+        let mut values = Vec::with_capacity(1 + bytes.len().div_ceil(Self::BYTES_PER_FELT));
+        values.push(Felt::from(num_bytes));
+        for chunk in bytes.chunks(Self::BYTES_PER_FELT) {
+            let mut felt_bytes = [0u8; 8];
+            felt_bytes[..chunk.len()].copy_from_slice(chunk);
+            values.push(Felt::new(u64::from_le_bytes(felt_bytes)));
+        }
+
+        Self::new(values)
+    }
+
+    /// Returns [LargeNoteInputs] instantiated from the provided values after checking that they
+    /// match the expected commitment.
+    ///
+    /// This is intended to be used to decode data retrieved from the advice map, in which case the
+    /// values are expected to be padded to a multiple of [Self::CHUNK_SIZE], and `num_values` is
+    /// used to strip the padding.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `num_values` is greater than the number of provided values.
+    /// - any of the values after the first `num_values` values is not ZERO.
+    /// - the commitment to the values does not match the expected commitment.
+    pub fn from_advice(
+        expected_commitment: Digest,
+        num_values: usize,
+        mut values: Vec<Felt>,
+    ) -> Result<Self, NoteError> {
+        if num_values > values.len() {
+            return Err(NoteError::InvalidLargeInputsLength(num_values));
+        }
+        if values[num_values..].iter().any(|value| *value != ZERO) {
+            return Err(NoteError::InvalidLargeInputsPadding);
+        }
+        values.truncate(num_values);
+
+        let large_inputs = Self::new(values)?;
+        if large_inputs.commitment != expected_commitment {
+            return Err(NoteError::LargeInputsCommitmentMismatch {
+                expected: expected_commitment,
+                actual: large_inputs.commitment,
+            });
+        }
+
+        Ok(large_inputs)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the commitment to these inputs.
+    pub fn commitment(&self) -> Digest {
+        self.commitment
+    }
+
+    /// Returns the number of values.
+    pub fn num_values(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns a reference to the values.
+    pub fn values(&self) -> &[Felt] {
+        &self.values
+    }
+
+    /// Returns an iterator over the chunks of the values, each padded to [Self::CHUNK_SIZE].
+    pub fn chunks(&self) -> impl Iterator<Item = [Felt; Self::CHUNK_SIZE]> + '_ {
+        self.values.chunks(Self::CHUNK_SIZE).map(|chunk| {
+            let mut padded_chunk = [ZERO; Self::CHUNK_SIZE];
+            padded_chunk[..chunk.len()].copy_from_slice(chunk);
+            padded_chunk
+        })
+    }
+
+    /// Decodes the bytes encoded by [LargeNoteInputs::from_bytes].
+    ///
+    /// # Errors
+    /// Returns an error if the values are not a valid byte encoding.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, NoteError> {
+        let (num_bytes, values) = self.values.split_first().expect("values are not empty");
+        let num_bytes = usize::try_from(num_bytes.as_int())
+            .map_err(|_| NoteError::InvalidLargeInputsByteEncoding)?;
+        if values.len() != num_bytes.div_ceil(Self::BYTES_PER_FELT) {
+            return Err(NoteError::InvalidLargeInputsByteEncoding);
+        }
+
+        let mut bytes = Vec::with_capacity(values.len() * Self::BYTES_PER_FELT);
+        for value in values {
+            let felt_bytes = value.as_int().to_le_bytes();
+            if felt_bytes[Self::BYTES_PER_FELT] != 0 {
+                return Err(NoteError::InvalidLargeInputsByteEncoding);
+            }
+            bytes.extend_from_slice(&felt_bytes[..Self::BYTES_PER_FELT]);
+        }
+
+        if bytes[num_bytes..].iter().any(|byte| *byte != 0) {
+            return Err(NoteError::InvalidLargeInputsByteEncoding);
+        }
+        bytes.truncate(num_bytes);
+
+        Ok(bytes)
+    }
+
+    /// Returns the [NoteInputs] committing to these inputs, followed by the provided additional
+    /// inputs.
+    ///
+    /// # Errors
+    /// Returns an error if the number of additional inputs is greater than 123.
+    pub fn to_note_inputs(&self, additional_inputs: &[Felt]) -> Result<NoteInputs, NoteError> {
+        let num_inputs = Self::NUM_RESERVED_INPUTS + additional_inputs.len();
+        if num_inputs > MAX_INPUTS_PER_NOTE {
+            return Err(NoteError::TooManyInputs(num_inputs));
+        }
+
+        let mut inputs = Vec::with_capacity(num_inputs);
+        inputs.extend_from_slice(self.commitment.as_elements());
+        inputs.push(Felt::from(self.values.len() as u32));
+        inputs.extend_from_slice(additional_inputs);
+
+        NoteInputs::new(inputs)
+    }
+
+    /// Returns the advice map entry under which these inputs are expected to be provided.
+    ///
+    /// The entry maps the commitment to the values padded to a multiple of [Self::CHUNK_SIZE].
+    pub fn to_advice_map_entry(&self) -> (Digest, Vec<Felt>) {
+        // NOTE: keep in sync with the `miden::note::get_large_inputs` procedure
+        (self.commitment, pad_values(&self.values))
+    }
+}
+
+impl PartialEq for LargeNoteInputs {
+    fn eq(&self, other: &Self) -> bool {
+        self.values == other.values
+    }
+}
+
+impl Eq for LargeNoteInputs {}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the provided values padded with ZEROs to the next multiple of
+/// [LargeNoteInputs::CHUNK_SIZE].
+fn pad_values(values: &[Felt]) -> Vec<Felt> {
+    let padded_len = values.len().next_multiple_of(LargeNoteInputs::CHUNK_SIZE);
+    let mut padded_values = Vec::with_capacity(padded_len);
+    padded_values.extend_from_slice(values);
+    padded_values.resize(padded_len, ZERO);
+
+    padded_values
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for LargeNoteInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u32(self.values.len() as u32);
+        target.write_many(&self.values);
+    }
+}
+
+impl Deserializable for LargeNoteInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_values = source.read_u32()? as usize;
+        let values = source.read_many::<Felt>(num_values)?;
+        Self::new(values).map_err(|err| DeserializationError::InvalidValue(format!("{err}")))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::*;
+
+    #[test]
+    fn large_inputs_byte_encoding_roundtrip() {
+        for len in [0, 1, 6, 7, 8, 300, 1025] {
+            let bytes: Vec<u8> = (0..len).map(|i| (i % 251) as u8).collect();
+            let large_inputs = LargeNoteInputs::from_bytes(&bytes).unwrap();
+            assert_eq!(large_inputs.to_bytes().unwrap(), bytes);
+        }
+    }
+
+    #[test]
+    fn large_inputs_commitment_matches_chunks() {
+        let values: Vec<Felt> = (0..300).map(Felt::new).collect();
+        let large_inputs = LargeNoteInputs::new(values.clone()).unwrap();
+
+        let chunked: Vec<Felt> = large_inputs.chunks().flatten().collect();
+        assert_eq!(chunked.len(), 304);
+        assert_eq!(large_inputs.commitment(), Hasher::hash_elements(&chunked));
+
+        let (key, advice_values) = large_inputs.to_advice_map_entry();
+        let decoded =
+            LargeNoteInputs::from_advice(key, large_inputs.num_values(), advice_values).unwrap();
+        assert_eq!(decoded, large_inputs);
+
+        let note_inputs = large_inputs.to_note_inputs(&[Felt::new(42)]).unwrap();
+        assert_eq!(&note_inputs.values()[..4], large_inputs.commitment().as_elements());
+        assert_eq!(note_inputs.values()[4], Felt::new(300));
+        assert_eq!(note_inputs.values()[5], Felt::new(42));
+    }
+
+    #[test]
+    fn large_inputs_from_advice_fails_on_wrong_commitment() {
+        let large_inputs = LargeNoteInputs::new(vec![Felt::new(1), Felt::new(2)]).unwrap();
+        let (_, advice_values) = large_inputs.to_advice_map_entry();
+
+        assert_matches!(
+            LargeNoteInputs::from_advice(Digest::default(), 2, advice_values),
+            Err(NoteError::LargeInputsCommitmentMismatch { .. })
+        );
+    }
+
+    #[test]
+    fn large_inputs_from_advice_fails_on_non_zero_padding() {
+        let large_inputs = LargeNoteInputs::new(vec![Felt::new(1), Felt::new(2)]).unwrap();
+        let (key, mut advice_values) = large_inputs.to_advice_map_entry();
+        *advice_values.last_mut().unwrap() = Felt::new(3);
+
+        assert_matches!(
+            LargeNoteInputs::from_advice(key, 2, advice_values),
+            Err(NoteError::InvalidLargeInputsPadding)
+        );
+    }
+}
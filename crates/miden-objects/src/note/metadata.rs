@@ -2,7 +2,7 @@ use alloc::string::ToString;
 
 use super::{
     AccountId, ByteReader, ByteWriter, Deserializable, DeserializationError, Felt, NoteError,
-    NoteTag, NoteType, Serializable, Word, execution_hint::NoteExecutionHint,
+    NoteSender, NoteTag, NoteType, Serializable, Word, execution_hint::NoteExecutionHint,
 };
 
 // NOTE METADATA
@@ -26,6 +26,10 @@ use super::{
 /// 4th felt: [aux (64 bits)]
 /// ```
 ///
+/// If the sender of the note is hidden behind a [`SenderCommitment`](super::SenderCommitment), the
+/// sender ID prefix and suffix are replaced by the two elements of the commitment, which follow the
+/// same validity rules.
+///
 /// The rationale for the above layout is to ensure the validity of each felt:
 /// - 1st felt: Is equivalent to the prefix of the account ID so it inherits its validity.
 /// - 2nd felt: The lower 8 bits of the account ID suffix are `0` by construction, so that they can
@@ -38,8 +42,8 @@ use super::{
 /// - 4th felt: The `aux` value must be a felt itself.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct NoteMetadata {
-    /// The ID of the account which created the note.
-    sender: AccountId,
+    /// The account which created the note, or a commitment to it.
+    sender: NoteSender,
 
     /// Defines how the note is to be stored (e.g. public or private).
     note_type: NoteType,
@@ -65,6 +69,21 @@ impl NoteMetadata {
         tag: NoteTag,
         execution_hint: NoteExecutionHint,
         aux: Felt,
+    ) -> Result<Self, NoteError> {
+        Self::new_with_sender(sender.into(), note_type, tag, execution_hint, aux)
+    }
+
+    /// Returns a new [NoteMetadata] instantiated with the specified parameters, where the sender
+    /// can also be a [`SenderCommitment`](super::SenderCommitment).
+    ///
+    /// # Errors
+    /// Returns an error if the note type and note tag are inconsistent.
+    pub fn new_with_sender(
+        sender: NoteSender,
+        note_type: NoteType,
+        tag: NoteTag,
+        execution_hint: NoteExecutionHint,
+        aux: Felt,
     ) -> Result<Self, NoteError> {
         let tag = tag.validate(note_type)?;
         Ok(Self {
@@ -76,8 +95,8 @@ impl NoteMetadata {
         })
     }
 
-    /// Returns the account which created the note.
-    pub fn sender(&self) -> AccountId {
+    /// Returns the account which created the note, or the commitment to it.
+    pub fn sender(&self) -> NoteSender {
         self.sender
     }
 
@@ -122,7 +141,7 @@ impl From<&NoteMetadata> for Word {
     /// The produced layout of the word is documented on the [`NoteMetadata`] type.
     fn from(metadata: &NoteMetadata) -> Self {
         let mut elements = Word::default();
-        elements[0] = metadata.sender.prefix().as_felt();
+        elements[0] = metadata.sender.prefix();
         elements[1] = merge_id_type_and_hint_tag(
             metadata.sender.suffix(),
             metadata.note_type,
@@ -146,13 +165,12 @@ impl TryFrom<Word> for NoteMetadata {
         let (sender_id_suffix, note_type, execution_hint_tag) =
             unmerge_id_type_and_hint_tag(elements[1])?;
 
-        let sender = AccountId::try_from([sender_id_prefix, sender_id_suffix])
-            .map_err(NoteError::NoteSenderInvalidAccountId)?;
+        let sender = NoteSender::try_from_elements(sender_id_prefix, sender_id_suffix)?;
 
         let (execution_hint, note_tag) =
             unmerge_note_tag_and_hint_payload(elements[2], execution_hint_tag)?;
 
-        Self::new(sender, note_type, note_tag, execution_hint, elements[3])
+        Self::new_with_sender(sender, note_type, note_tag, execution_hint, elements[3])
     }
 }
 
@@ -291,7 +309,11 @@ mod tests {
     use anyhow::Context;
 
     use super::*;
-    use crate::{note::NoteExecutionMode, testing::account_id::ACCOUNT_ID_MAX_ONES};
+    use crate::{
+        ONE,
+        note::{NoteExecutionMode, SenderCommitment},
+        testing::account_id::ACCOUNT_ID_MAX_ONES,
+    };
 
     #[test]
     fn note_metadata_serde() -> anyhow::Result<()> {
@@ -313,6 +335,17 @@ mod tests {
                 .context(format!("failed for execution hint {execution_hint:?}"))?;
         }
 
+        let sender_commitment = SenderCommitment::new(sender, [ONE; 4]);
+        let metadata = NoteMetadata::new_with_sender(
+            sender_commitment.into(),
+            note_type,
+            tag,
+            NoteExecutionHint::always(),
+            aux,
+        )?;
+        let decoded = NoteMetadata::read_from_bytes(&metadata.to_bytes())?;
+        assert_eq!(decoded.sender(), NoteSender::Commitment(sender_commitment));
+
         Ok(())
     }
 
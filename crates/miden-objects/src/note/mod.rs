@@ -18,9 +18,18 @@ pub use header::{NoteHeader, compute_note_commitment};
 mod inputs;
 pub use inputs::NoteInputs;
 
+mod input_layout;
+pub use input_layout::{NoteInputLayout, NoteInputType};
+
+mod large_inputs;
+pub use large_inputs::LargeNoteInputs;
+
 mod metadata;
 pub use metadata::NoteMetadata;
 
+mod sender;
+pub use sender::{NoteSender, SenderCommitment};
+
 mod execution_hint;
 pub use execution_hint::{AfterBlockNumber, NoteExecutionHint};
 
@@ -33,6 +42,9 @@ pub use note_tag::{NoteExecutionMode, NoteTag};
 mod note_type;
 pub use note_type::NoteType;
 
+mod tag_filter;
+pub use tag_filter::NoteTagFilter;
+
 mod nullifier;
 pub use nullifier::Nullifier;
 
@@ -42,12 +54,18 @@ pub use location::{NoteInclusionProof, NoteLocation};
 mod partial;
 pub use partial::PartialNote;
 
+mod payload;
+pub use payload::NotePayload;
+
 mod recipient;
-pub use recipient::NoteRecipient;
+pub use recipient::{NoteRecipient, NoteRecipientTemplate};
 
 mod script;
 pub use script::NoteScript;
 
+mod script_builder;
+pub use script_builder::{NoteScriptBuilder, TypedNoteScript};
+
 mod file;
 pub use file::NoteFile;
 
@@ -175,7 +175,47 @@ impl Deserializable for Nullifier {
 
 #[cfg(test)]
 mod tests {
-    use crate::note::Nullifier;
+    use crate::{
+        Felt, ONE, Word, ZERO,
+        account::AccountId,
+        assembly::Assembler,
+        note::{
+            Note, NoteAssets, NoteDetails, NoteExecutionHint, NoteInputs, NoteMetadata,
+            NoteRecipient, NoteScript, NoteTag, NoteType, Nullifier,
+        },
+        testing::account_id::ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
+    };
+
+    #[test]
+    fn test_nullifier_from_note_details() {
+        let sender = AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap();
+        let serial_num: Word = [ONE, Felt::new(2), Felt::new(3), Felt::new(4)];
+        let script = NoteScript::compile("begin nop end", Assembler::default()).unwrap();
+        let inputs = NoteInputs::new(vec![Felt::new(5), Felt::new(6)]).unwrap();
+        let recipient = NoteRecipient::new(serial_num, script, inputs);
+
+        let details = NoteDetails::new(NoteAssets::default(), recipient.clone());
+        let metadata = NoteMetadata::new(
+            sender,
+            NoteType::Public,
+            NoteTag::from(0),
+            NoteExecutionHint::always(),
+            ZERO,
+        )
+        .unwrap();
+        let note = Note::new(NoteAssets::default(), metadata, recipient);
+
+        assert_eq!(details.nullifier(), note.nullifier());
+        assert_eq!(
+            details.nullifier(),
+            Nullifier::new(
+                details.script().root(),
+                details.inputs().commitment(),
+                details.assets().commitment(),
+                serial_num,
+            )
+        );
+    }
 
     #[test]
     fn test_from_hex_and_back() {
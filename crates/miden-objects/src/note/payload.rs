@@ -0,0 +1,114 @@
+use alloc::vec::Vec;
+
+use super::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Felt, Hasher, NoteError,
+    NoteInputs, NoteRecipient, Serializable, WORD_SIZE,
+};
+
+// NOTE PAYLOAD
+// ================================================================================================
+
+/// An arbitrary off-chain payload attached to a note, e.g. an invoice or order data.
+///
+/// The payload itself is never recorded on chain. Instead, the creator of a note commits to the
+/// payload by appending its commitment, i.e. the hash of the payload bytes, as the last word of
+/// the note inputs. Since the inputs are part of the note recipient, the commitment is bound to
+/// the note ID, and anyone receiving the payload together with the note details (e.g. via a
+/// [`NoteFile`](super::NoteFile)) can verify that the payload is the one the creator committed to.
+///
+/// The note script must be written to tolerate the additional inputs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotePayload(Vec<u8>);
+
+impl NotePayload {
+    /// Returns a new [NotePayload] instantiated from the provided bytes.
+    pub fn new(payload: impl Into<Vec<u8>>) -> Self {
+        Self(payload.into())
+    }
+
+    /// Returns the bytes of this payload.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Returns the commitment to this payload, i.e. the hash of its bytes.
+    pub fn commitment(&self) -> Digest {
+        Hasher::hash(&self.0)
+    }
+
+    /// Returns note inputs consisting of the provided values followed by the commitment to this
+    /// payload.
+    ///
+    /// # Errors
+    /// Returns an error if the resulting number of inputs exceeds the maximum.
+    pub fn to_inputs(
+        &self,
+        values: impl IntoIterator<Item = Felt>,
+    ) -> Result<NoteInputs, NoteError> {
+        let mut inputs: Vec<Felt> = values.into_iter().collect();
+        inputs.extend_from_slice(self.commitment().as_elements());
+
+        NoteInputs::new(inputs)
+    }
+
+    /// Returns the payload commitment carried by the provided note inputs, i.e. their last word,
+    /// or `None` if there are fewer than four inputs.
+    pub fn commitment_from_inputs(inputs: &NoteInputs) -> Option<Digest> {
+        let values = inputs.values();
+        let start = values.len().checked_sub(WORD_SIZE)?;
+
+        Some(Digest::new([
+            values[start],
+            values[start + 1],
+            values[start + 2],
+            values[start + 3],
+        ]))
+    }
+
+    /// Returns `true` if the inputs of the provided recipient carry the commitment to this
+    /// payload.
+    pub fn verify(&self, recipient: &NoteRecipient) -> bool {
+        Self::commitment_from_inputs(recipient.inputs()) == Some(self.commitment())
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for NotePayload {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.0.write_into(target);
+    }
+}
+
+impl Deserializable for NotePayload {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Vec::<u8>::read_from(source).map(Self)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ONE, Word, note::NoteScript};
+
+    #[test]
+    fn note_payload_verification() {
+        let payload = NotePayload::new("invoice #1337: 3 widgets");
+        let inputs = payload.to_inputs([ONE, Felt::new(2)]).unwrap();
+        assert_eq!(inputs.num_values(), 6);
+        assert_eq!(NotePayload::commitment_from_inputs(&inputs), Some(payload.commitment()));
+
+        let recipient = NoteRecipient::new(Word::default(), NoteScript::mock(), inputs);
+        assert!(payload.verify(&recipient));
+        assert!(!NotePayload::new("invoice #1338: 3 widgets").verify(&recipient));
+
+        let inputs = NoteInputs::new(vec![ONE, Felt::new(2)]).unwrap();
+        assert_eq!(NotePayload::commitment_from_inputs(&inputs), None);
+
+        assert_eq!(NotePayload::read_from_bytes(&payload.to_bytes()).unwrap(), payload);
+    }
+}
@@ -4,8 +4,8 @@ use core::fmt::Debug;
 use miden_crypto::Felt;
 
 use super::{
-    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Hasher, NoteInputs,
-    NoteScript, Serializable, Word,
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Hasher, NoteDetails,
+    NoteInputs, NoteScript, Serializable, Word,
 };
 
 /// Value that describes under which condition a note can be consumed.
@@ -28,7 +28,7 @@ pub struct NoteRecipient {
 
 impl NoteRecipient {
     pub fn new(serial_num: Word, script: NoteScript, inputs: NoteInputs) -> Self {
-        let digest = compute_recipient_digest(serial_num, &script, &inputs);
+        let digest = compute_recipient_digest(serial_num, script.root(), &inputs);
         Self { serial_num, script, inputs, digest }
     }
 
@@ -67,12 +67,67 @@ impl NoteRecipient {
     }
 }
 
-fn compute_recipient_digest(serial_num: Word, script: &NoteScript, inputs: &NoteInputs) -> Digest {
+fn compute_recipient_digest(serial_num: Word, script_root: Digest, inputs: &NoteInputs) -> Digest {
     let serial_num_hash = Hasher::merge(&[serial_num.into(), Digest::default()]);
-    let merge_script = Hasher::merge(&[serial_num_hash, script.root()]);
+    let merge_script = Hasher::merge(&[serial_num_hash, script_root]);
     Hasher::merge(&[merge_script, inputs.commitment()])
 }
 
+// NOTE RECIPIENT TEMPLATE
+// ================================================================================================
+
+/// The expected script root and inputs of a [NoteRecipient], without the serial number.
+///
+/// A template allows checking that received notes have the expected recipient, e.g. an exchange
+/// validating that deposit notes are P2ID notes targeting one of its accounts. Since the serial
+/// number of a note is chosen by its sender, a template matches all recipients with the expected
+/// script root and inputs, regardless of their serial number.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NoteRecipientTemplate {
+    script_root: Digest,
+    inputs: NoteInputs,
+}
+
+impl NoteRecipientTemplate {
+    /// Returns a new [NoteRecipientTemplate] instantiated from the provided script root and
+    /// inputs.
+    pub fn new(script_root: Digest, inputs: NoteInputs) -> Self {
+        Self { script_root, inputs }
+    }
+
+    /// Returns the expected script root.
+    pub fn script_root(&self) -> Digest {
+        self.script_root
+    }
+
+    /// Returns the expected inputs.
+    pub fn inputs(&self) -> &NoteInputs {
+        &self.inputs
+    }
+
+    /// Returns the digest of the recipient with the provided serial number defined by this
+    /// template.
+    pub fn recipient_digest(&self, serial_num: Word) -> Digest {
+        compute_recipient_digest(serial_num, self.script_root, &self.inputs)
+    }
+
+    /// Returns `true` if the script root and the inputs of the provided note details match this
+    /// template.
+    ///
+    /// The inputs are compared value by value rather than via the recipient digest, since the
+    /// commitment to the inputs does not commit to their number, e.g. inputs which differ only by
+    /// trailing ZEROs result in the same recipient digest.
+    pub fn matches(&self, note: &NoteDetails) -> bool {
+        note.script().root() == self.script_root && note.inputs() == &self.inputs
+    }
+
+    /// Returns for each of the provided note details whether its script root and inputs match this
+    /// template, see [Self::matches].
+    pub fn matches_batch<'a>(&self, notes: impl IntoIterator<Item = &'a NoteDetails>) -> Vec<bool> {
+        notes.into_iter().map(|note| self.matches(note)).collect()
+    }
+}
+
 // SERIALIZATION
 // ================================================================================================
 
@@ -103,3 +158,66 @@ impl Deserializable for NoteRecipient {
         Ok(Self::new(serial_num, script, inputs))
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ONE, ZERO, assembly::Assembler, note::NoteAssets};
+
+    #[test]
+    fn test_recipient_template_matching() {
+        let script = NoteScript::compile("begin nop end", Assembler::default()).unwrap();
+        let inputs = NoteInputs::new(vec![Felt::new(5), Felt::new(6)]).unwrap();
+        let template = NoteRecipientTemplate::new(script.root(), inputs.clone());
+
+        let serial_num = [ONE, Felt::new(2), Felt::new(3), Felt::new(4)];
+        let matching = NoteDetails::new(
+            NoteAssets::default(),
+            NoteRecipient::new(serial_num, script.clone(), inputs),
+        );
+        let other_inputs = NoteDetails::new(
+            NoteAssets::default(),
+            NoteRecipient::new(serial_num, script.clone(), NoteInputs::default()),
+        );
+        let other_script = NoteDetails::new(
+            NoteAssets::default(),
+            NoteRecipient::new(
+                serial_num,
+                NoteScript::compile("begin push.1 drop end", Assembler::default()).unwrap(),
+                NoteInputs::new(vec![Felt::new(5), Felt::new(6)]).unwrap(),
+            ),
+        );
+
+        assert_eq!(template.recipient_digest(serial_num), matching.recipient().digest());
+        assert!(template.matches(&matching));
+        assert!(!template.matches(&other_inputs));
+        assert!(!template.matches(&other_script));
+        assert_eq!(
+            template.matches_batch([&matching, &other_inputs, &other_script, &matching]),
+            vec![true, false, false, true]
+        );
+    }
+
+    #[test]
+    fn test_recipient_template_rejects_padded_inputs() {
+        let script = NoteScript::compile("begin nop end", Assembler::default()).unwrap();
+        let inputs = NoteInputs::new(vec![Felt::new(5), Felt::new(6)]).unwrap();
+        let template = NoteRecipientTemplate::new(script.root(), inputs);
+
+        // the inputs differ from the expected ones only by a trailing ZERO, so that the recipient
+        // digest of the note equals the one defined by the template
+        let serial_num = [ONE, Felt::new(2), Felt::new(3), Felt::new(4)];
+        let padded_inputs = NoteInputs::new(vec![Felt::new(5), Felt::new(6), ZERO]).unwrap();
+        let padded = NoteDetails::new(
+            NoteAssets::default(),
+            NoteRecipient::new(serial_num, script, padded_inputs),
+        );
+
+        assert_eq!(template.recipient_digest(serial_num), padded.recipient().digest());
+        assert!(!template.matches(&padded));
+        assert_eq!(template.matches_batch([&padded]), vec![false]);
+    }
+}
@@ -0,0 +1,174 @@
+use alloc::{string::String, vec::Vec};
+
+use super::{NoteInputLayout, NoteInputType, NoteInputs, NoteRecipient, NoteScript};
+use crate::{Digest, NoteError, Word};
+
+// NOTE SCRIPT BUILDER
+// ================================================================================================
+
+/// A builder for a [TypedNoteScript], i.e. a [NoteScript] paired with the layout of the inputs it
+/// expects.
+///
+/// # Example
+///
+/// ```ignore
+/// let typed_script = NoteScriptBuilder::new(note_script)
+///     .input("target", NoteInputType::AccountId)
+///     .input("reclaim_height", NoteInputType::U32)
+///     .build()?;
+///
+/// // fails if the inputs do not match the declared layout
+/// let recipient = typed_script.build_recipient(serial_num, inputs)?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct NoteScriptBuilder {
+    script: NoteScript,
+    inputs: Vec<(String, NoteInputType)>,
+}
+
+impl NoteScriptBuilder {
+    /// Returns a new [NoteScriptBuilder] for the provided script with an empty input layout.
+    pub fn new(script: NoteScript) -> Self {
+        Self { script, inputs: Vec::new() }
+    }
+
+    /// Declares the next input of the script.
+    pub fn input(mut self, name: impl Into<String>, input_type: NoteInputType) -> Self {
+        self.inputs.push((name.into(), input_type));
+        self
+    }
+
+    /// Builds the [TypedNoteScript].
+    ///
+    /// # Errors
+    /// Returns an error if the declared inputs do not form a valid [NoteInputLayout].
+    pub fn build(self) -> Result<TypedNoteScript, NoteError> {
+        let layout = NoteInputLayout::new(self.inputs)?;
+
+        Ok(TypedNoteScript { script: self.script, layout })
+    }
+}
+
+// TYPED NOTE SCRIPT
+// ================================================================================================
+
+/// A [NoteScript] together with the [NoteInputLayout] of the inputs it expects.
+///
+/// Note recipients built from a [TypedNoteScript] are guaranteed to have inputs matching the
+/// declared layout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypedNoteScript {
+    script: NoteScript,
+    layout: NoteInputLayout,
+}
+
+impl TypedNoteScript {
+    /// Returns the note script.
+    pub fn script(&self) -> &NoteScript {
+        &self.script
+    }
+
+    /// Returns the root of the note script.
+    pub fn root(&self) -> Digest {
+        self.script.root()
+    }
+
+    /// Returns the input layout expected by the note script.
+    pub fn layout(&self) -> &NoteInputLayout {
+        &self.layout
+    }
+
+    /// Returns a [NoteRecipient] for this script with the provided serial number and inputs.
+    ///
+    /// # Errors
+    /// Returns an error if the inputs do not match the input layout of this script.
+    pub fn build_recipient(
+        &self,
+        serial_num: Word,
+        inputs: NoteInputs,
+    ) -> Result<NoteRecipient, NoteError> {
+        self.layout.validate(&inputs)?;
+
+        Ok(NoteRecipient::new(serial_num, self.script.clone(), inputs))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::*;
+    use crate::{
+        Felt, account::AccountId, assembly::Assembler,
+        testing::account_id::ACCOUNT_ID_REGULAR_PRIVATE_ACCOUNT_UPDATABLE_CODE,
+    };
+
+    fn typed_script() -> TypedNoteScript {
+        let script = NoteScript::compile("begin nop end", Assembler::default()).unwrap();
+        NoteScriptBuilder::new(script)
+            .input("target", NoteInputType::AccountId)
+            .input("reclaim_height", NoteInputType::U32)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn typed_note_script_validates_inputs() {
+        let typed_script = typed_script();
+        let target =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_PRIVATE_ACCOUNT_UPDATABLE_CODE).unwrap();
+
+        assert_eq!(typed_script.layout().num_values(), 3);
+        assert_eq!(typed_script.layout().offset_of("reclaim_height"), Some(2));
+
+        let inputs =
+            NoteInputs::new(vec![target.suffix(), target.prefix().as_felt(), Felt::from(10u32)])
+                .unwrap();
+        typed_script.build_recipient(Word::default(), inputs).unwrap();
+
+        // account ID felts in the wrong order
+        let inputs =
+            NoteInputs::new(vec![target.prefix().as_felt(), target.suffix(), Felt::from(10u32)])
+                .unwrap();
+        assert_matches!(
+            typed_script.build_recipient(Word::default(), inputs),
+            Err(NoteError::InvalidNoteInputAccountId { name, .. }) if name == "target"
+        );
+
+        // reclaim height out of range
+        let inputs = NoteInputs::new(vec![
+            target.suffix(),
+            target.prefix().as_felt(),
+            Felt::new(u32::MAX as u64 + 1),
+        ])
+        .unwrap();
+        assert_matches!(
+            typed_script.build_recipient(Word::default(), inputs),
+            Err(NoteError::InvalidNoteInputValue { input_type: NoteInputType::U32, .. })
+        );
+
+        // wrong number of inputs
+        let inputs = NoteInputs::new(vec![target.suffix(), target.prefix().as_felt()]).unwrap();
+        assert_matches!(
+            typed_script.build_recipient(Word::default(), inputs),
+            Err(NoteError::NoteInputsLayoutMismatch { expected: 3, actual: 2 })
+        );
+    }
+
+    #[test]
+    fn note_input_layout_rejects_invalid_declarations() {
+        let script = NoteScript::compile("begin nop end", Assembler::default()).unwrap();
+
+        let result = NoteScriptBuilder::new(script.clone())
+            .input("a", NoteInputType::Felt)
+            .input("a", NoteInputType::Word)
+            .build();
+        assert_matches!(result, Err(NoteError::DuplicateNoteInputName(name)) if name == "a");
+
+        let result = NoteInputLayout::new((0..33).map(|i| (format!("w{i}"), NoteInputType::Word)));
+        assert_matches!(result, Err(NoteError::TooManyInputs(132)));
+    }
+}
@@ -0,0 +1,234 @@
+use alloc::string::String;
+use core::fmt;
+
+use crate::{Digest, Felt, Hasher, NoteError, Word, ZERO, account::AccountId};
+
+// NOTE SENDER
+// ================================================================================================
+
+/// The sender of a note as recorded in its [`NoteMetadata`](super::NoteMetadata).
+///
+/// By default, the metadata of a note contains the ID of the account which created it. Since note
+/// metadata is always public, this reveals the sender of a note to all observers of the chain. To
+/// avoid this, a note can instead be created with a [`SenderCommitment`] which can only be opened
+/// by parties knowing the salt used to compute it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NoteSender {
+    /// The ID of the account which created the note.
+    Account(AccountId),
+    /// A commitment to the ID of the account which created the note.
+    Commitment(SenderCommitment),
+}
+
+impl NoteSender {
+    /// Returns the ID of the account which created the note, or `None` if the sender is hidden
+    /// behind a commitment.
+    pub fn account_id(&self) -> Option<AccountId> {
+        match self {
+            NoteSender::Account(account_id) => Some(*account_id),
+            NoteSender::Commitment(_) => None,
+        }
+    }
+
+    /// Returns the commitment to the sender, or `None` if the sender is not hidden.
+    pub fn commitment(&self) -> Option<SenderCommitment> {
+        match self {
+            NoteSender::Account(_) => None,
+            NoteSender::Commitment(commitment) => Some(*commitment),
+        }
+    }
+
+    /// Returns `true` if the sender is hidden behind a commitment.
+    pub fn is_private(&self) -> bool {
+        matches!(self, NoteSender::Commitment(_))
+    }
+
+    /// Returns the prefix of this sender, i.e. the first element of the note metadata.
+    pub(super) fn prefix(&self) -> Felt {
+        match self {
+            NoteSender::Account(account_id) => account_id.prefix().as_felt(),
+            NoteSender::Commitment(commitment) => commitment.prefix,
+        }
+    }
+
+    /// Returns the suffix of this sender, i.e. the upper 56 bits of the second element of the note
+    /// metadata.
+    pub(super) fn suffix(&self) -> Felt {
+        match self {
+            NoteSender::Account(account_id) => account_id.suffix(),
+            NoteSender::Commitment(commitment) => commitment.suffix,
+        }
+    }
+
+    /// Decodes the sender from the prefix and suffix elements of the note metadata.
+    ///
+    /// # Errors
+    /// Returns an error if the elements are neither a valid account ID nor a valid sender
+    /// commitment.
+    pub(super) fn try_from_elements(prefix: Felt, suffix: Felt) -> Result<Self, NoteError> {
+        if SenderCommitment::is_commitment_prefix(prefix) {
+            SenderCommitment::try_from_elements(prefix, suffix).map(NoteSender::Commitment)
+        } else {
+            AccountId::try_from([prefix, suffix])
+                .map(NoteSender::Account)
+                .map_err(NoteError::NoteSenderInvalidAccountId)
+        }
+    }
+}
+
+impl From<AccountId> for NoteSender {
+    fn from(account_id: AccountId) -> Self {
+        NoteSender::Account(account_id)
+    }
+}
+
+impl From<SenderCommitment> for NoteSender {
+    fn from(commitment: SenderCommitment) -> Self {
+        NoteSender::Commitment(commitment)
+    }
+}
+
+impl fmt::Display for NoteSender {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NoteSender::Account(account_id) => write!(f, "{account_id}"),
+            NoteSender::Commitment(commitment) => write!(f, "{commitment}"),
+        }
+    }
+}
+
+// SENDER COMMITMENT
+// ================================================================================================
+
+/// A commitment to the ID of the account which created a note.
+///
+/// The commitment is computed as `hash([sender_id_suffix, sender_id_prefix, 0, 0] || SALT)` and the
+/// first two elements of the resulting digest are truncated to fit into the space occupied by the
+/// sender ID in the note metadata:
+///
+/// ```text
+/// 1st felt: [0 (1 bit) | digest[0] (59 bits) | 0b1111 (4 bits)]
+/// 2nd felt: [0 (1 bit) | digest[1] (55 bits) | 0 (8 bits)]
+/// ```
+///
+/// The four least significant bits of the first element correspond to the version of an account
+/// ID, and `0b1111` is not a valid account ID version. This is how sender commitments are
+/// distinguished from account IDs.
+///
+/// The commitment is computed by the transaction kernel from the ID of the native account, so it
+/// is guaranteed to commit to the actual sender of the note. The sender can later prove that it
+/// created the note by revealing the salt, e.g. via the `miden::note::is_sender` procedure.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SenderCommitment {
+    prefix: Felt,
+    suffix: Felt,
+}
+
+impl SenderCommitment {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The value of the four least significant bits of the first element of a sender commitment.
+    const MARKER: u64 = 0b1111;
+
+    /// The mask applied to the first element of the digest.
+    const PREFIX_MASK: u64 = 0x7fff_ffff_ffff_fff0;
+
+    /// The mask applied to the second element of the digest.
+    const SUFFIX_MASK: u64 = 0x7fff_ffff_ffff_ff00;
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [`SenderCommitment`] to the provided sender and salt.
+    pub fn new(sender: AccountId, salt: Word) -> Self {
+        // NOTE: keep in sync with the `util::note::compute_sender_commitment` procedure
+        let sender_word: Word = [sender.suffix(), sender.prefix().as_felt(), ZERO, ZERO];
+        let digest = Hasher::merge(&[Digest::from(sender_word), Digest::from(salt)]);
+
+        let prefix = (digest[0].as_int() & Self::PREFIX_MASK) | Self::MARKER;
+        let suffix = digest[1].as_int() & Self::SUFFIX_MASK;
+
+        // SAFETY: the most significant bit of both values is zero, so they are valid felts.
+        Self {
+            prefix: Felt::try_from(prefix).expect("prefix should be a valid felt"),
+            suffix: Felt::try_from(suffix).expect("suffix should be a valid felt"),
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the first element of this commitment.
+    pub fn prefix(&self) -> Felt {
+        self.prefix
+    }
+
+    /// Returns the second element of this commitment.
+    pub fn suffix(&self) -> Felt {
+        self.suffix
+    }
+
+    /// Returns `true` if this commitment was computed from the provided sender and salt.
+    pub fn verify(&self, sender: AccountId, salt: Word) -> bool {
+        *self == Self::new(sender, salt)
+    }
+
+    /// Returns the big-endian, hex-encoded representation of this commitment.
+    pub fn to_hex(&self) -> String {
+        format!("0x{:016x}{:016x}", self.prefix.as_int(), self.suffix.as_int())
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns `true` if the provided note metadata prefix encodes a sender commitment.
+    fn is_commitment_prefix(prefix: Felt) -> bool {
+        prefix.as_int() & Self::MARKER == Self::MARKER
+    }
+
+    /// Decodes a [`SenderCommitment`] from the prefix and suffix elements of the note metadata.
+    ///
+    /// # Errors
+    /// Returns an error if the elements are not a truncated digest as produced by [`Self::new`].
+    fn try_from_elements(prefix: Felt, suffix: Felt) -> Result<Self, NoteError> {
+        if prefix.as_int() & !Self::PREFIX_MASK != Self::MARKER
+            || suffix.as_int() & !Self::SUFFIX_MASK != 0
+        {
+            return Err(NoteError::NoteSenderInvalidCommitment(prefix, suffix));
+        }
+
+        Ok(Self { prefix, suffix })
+    }
+}
+
+impl fmt::Display for SenderCommitment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_hex())
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ONE, testing::account_id::ACCOUNT_ID_MAX_ONES};
+
+    #[test]
+    fn sender_commitment_roundtrip() {
+        let sender = AccountId::try_from(ACCOUNT_ID_MAX_ONES).unwrap();
+        let salt = [ONE, Felt::new(2), Felt::new(3), Felt::new(4)];
+        let commitment = SenderCommitment::new(sender, salt);
+
+        assert!(commitment.verify(sender, salt));
+        assert!(!commitment.verify(sender, [ONE; 4]));
+
+        let decoded = NoteSender::try_from_elements(commitment.prefix(), commitment.suffix());
+        assert_eq!(decoded.unwrap(), NoteSender::Commitment(commitment));
+
+        let decoded = NoteSender::try_from_elements(sender.prefix().as_felt(), sender.suffix());
+        assert_eq!(decoded.unwrap(), NoteSender::Account(sender));
+    }
+}
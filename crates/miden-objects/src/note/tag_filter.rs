@@ -0,0 +1,233 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+use core::ops::RangeInclusive;
+
+use super::{NoteError, NoteTag};
+
+// NOTE TAG FILTER
+// ================================================================================================
+
+/// A filter over [NoteTag]s which matches tags exactly, by prefix, or by range.
+///
+/// A tag matches the filter if it matches any of the patterns added to it:
+/// - exact patterns match a single tag.
+/// - prefix patterns match all tags sharing the specified number of most significant bits with the
+///   pattern tag. Since tags for specific targets are derived from the most significant bits of an
+///   account ID, this can be used to match notes for an account with a custom precision.
+/// - range patterns match all tags within an inclusive range.
+///
+/// The patterns are indexed on insertion, so that evaluating a tag only requires a logarithmic
+/// number of lookups per distinct prefix length, which makes the filter suitable for scanning
+/// large streams of notes.
+///
+/// An empty filter does not match any tag.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NoteTagFilter {
+    /// Tags matched exactly.
+    tags: BTreeSet<u32>,
+    /// Masked prefixes matched by prefix, grouped by their length in bits.
+    prefixes: BTreeMap<u8, BTreeSet<u32>>,
+    /// Sorted, disjoint and non-adjacent inclusive ranges of matched tags.
+    ranges: Vec<(u32, u32)>,
+}
+
+impl NoteTagFilter {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The maximum length of a prefix pattern in bits.
+    pub const MAX_PREFIX_LENGTH: u8 = u32::BITS as u8;
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [NoteTagFilter] which does not match any tag.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a pattern matching exactly the provided tag and returns the filter.
+    pub fn with_tag(mut self, tag: NoteTag) -> Self {
+        self.add_tag(tag);
+        self
+    }
+
+    /// Adds a pattern matching all tags sharing the `prefix_length` most significant bits with the
+    /// provided tag and returns the filter.
+    ///
+    /// # Errors
+    /// Returns an error if `prefix_length` exceeds [`Self::MAX_PREFIX_LENGTH`].
+    pub fn with_prefix(mut self, tag: NoteTag, prefix_length: u8) -> Result<Self, NoteError> {
+        self.add_prefix(tag, prefix_length)?;
+        Ok(self)
+    }
+
+    /// Adds a pattern matching all tags within the provided range and returns the filter.
+    pub fn with_range(mut self, range: RangeInclusive<NoteTag>) -> Self {
+        self.add_range(range);
+        self
+    }
+
+    // PUBLIC MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds a pattern matching exactly the provided tag.
+    pub fn add_tag(&mut self, tag: NoteTag) {
+        self.tags.insert(tag.inner());
+    }
+
+    /// Adds a pattern matching all tags sharing the `prefix_length` most significant bits with the
+    /// provided tag.
+    ///
+    /// # Errors
+    /// Returns an error if `prefix_length` exceeds [`Self::MAX_PREFIX_LENGTH`].
+    pub fn add_prefix(&mut self, tag: NoteTag, prefix_length: u8) -> Result<(), NoteError> {
+        if prefix_length > Self::MAX_PREFIX_LENGTH {
+            return Err(NoteError::NoteTagFilterPrefixLengthTooLarge(prefix_length));
+        }
+
+        let prefix = tag.inner() & prefix_mask(prefix_length);
+        self.prefixes.entry(prefix_length).or_default().insert(prefix);
+
+        Ok(())
+    }
+
+    /// Adds a pattern matching all tags within the provided range.
+    ///
+    /// Empty ranges are ignored.
+    pub fn add_range(&mut self, range: RangeInclusive<NoteTag>) {
+        let (mut start, mut end) = (range.start().inner(), range.end().inner());
+        if start > end {
+            return;
+        }
+
+        // merge all existing ranges which overlap or are adjacent to the new range
+        let first = self.ranges.partition_point(|&(_, e)| e.saturating_add(1) < start);
+        let last = self.ranges.partition_point(|&(s, _)| s <= end.saturating_add(1));
+        if first < last {
+            start = start.min(self.ranges[first].0);
+            end = end.max(self.ranges[last - 1].1);
+        }
+
+        self.ranges.splice(first..last, [(start, end)]);
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns `true` if the filter does not contain any patterns.
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty() && self.prefixes.is_empty() && self.ranges.is_empty()
+    }
+
+    /// Returns `true` if the provided tag matches any of the patterns of this filter.
+    pub fn matches(&self, tag: NoteTag) -> bool {
+        let tag = tag.inner();
+
+        if self.tags.contains(&tag) {
+            return true;
+        }
+
+        if self
+            .prefixes
+            .iter()
+            .any(|(&length, prefixes)| prefixes.contains(&(tag & prefix_mask(length))))
+        {
+            return true;
+        }
+
+        // find the last range starting at or before the tag
+        let index = self.ranges.partition_point(|&(start, _)| start <= tag);
+        index > 0 && self.ranges[index - 1].1 >= tag
+    }
+}
+
+impl FromIterator<NoteTag> for NoteTagFilter {
+    fn from_iter<T: IntoIterator<Item = NoteTag>>(iter: T) -> Self {
+        let mut filter = Self::new();
+        iter.into_iter().for_each(|tag| filter.add_tag(tag));
+        filter
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the mask selecting the `length` most significant bits of a tag.
+fn prefix_mask(length: u8) -> u32 {
+    u32::MAX.checked_shl(u32::BITS - length as u32).unwrap_or(0)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::*;
+
+    #[test]
+    fn note_tag_filter_matching() {
+        let filter = NoteTagFilter::new()
+            .with_tag(NoteTag::from(7))
+            .with_prefix(NoteTag::from(0xabcd_0000), 16)
+            .unwrap()
+            .with_range(NoteTag::from(100)..=NoteTag::from(200));
+
+        assert!(filter.matches(NoteTag::from(7)));
+        assert!(!filter.matches(NoteTag::from(8)));
+        assert!(filter.matches(NoteTag::from(0xabcd_1234)));
+        assert!(!filter.matches(NoteTag::from(0xabce_0000)));
+        assert!(filter.matches(NoteTag::from(100)));
+        assert!(filter.matches(NoteTag::from(200)));
+        assert!(!filter.matches(NoteTag::from(99)));
+        assert!(!filter.matches(NoteTag::from(201)));
+
+        assert!(!NoteTagFilter::new().matches(NoteTag::from(0)));
+        assert!(
+            NoteTagFilter::new()
+                .with_prefix(NoteTag::from(1), 0)
+                .unwrap()
+                .matches(u32::MAX.into())
+        );
+        assert!(
+            !NoteTagFilter::new()
+                .with_prefix(NoteTag::from(1), 32)
+                .unwrap()
+                .matches(NoteTag::from(0))
+        );
+        assert_matches!(
+            NoteTagFilter::new().with_prefix(NoteTag::from(1), 33),
+            Err(NoteError::NoteTagFilterPrefixLengthTooLarge(33))
+        );
+    }
+
+    #[test]
+    fn note_tag_filter_merges_ranges() {
+        let mut filter = NoteTagFilter::new();
+        filter.add_range(NoteTag::from(10)..=NoteTag::from(20));
+        filter.add_range(NoteTag::from(30)..=NoteTag::from(40));
+        filter.add_range(NoteTag::from(50)..=NoteTag::from(u32::MAX));
+        assert_eq!(filter.ranges, [(10, 20), (30, 40), (50, u32::MAX)]);
+
+        // adjacent and overlapping ranges are merged
+        filter.add_range(NoteTag::from(21)..=NoteTag::from(35));
+        assert_eq!(filter.ranges, [(10, 40), (50, u32::MAX)]);
+
+        filter.add_range(NoteTag::from(0)..=NoteTag::from(5));
+        filter.add_range(NoteTag::from(45)..=NoteTag::from(46));
+        assert_eq!(filter.ranges, [(0, 5), (10, 40), (45, 46), (50, u32::MAX)]);
+
+        // empty ranges are ignored
+        #[allow(clippy::reversed_empty_ranges)]
+        filter.add_range(NoteTag::from(8)..=NoteTag::from(7));
+        assert_eq!(filter.ranges.len(), 4);
+
+        assert!(filter.matches(NoteTag::from(u32::MAX)));
+        assert!(!filter.matches(NoteTag::from(47)));
+        assert!(!filter.matches(NoteTag::from(7)));
+    }
+}
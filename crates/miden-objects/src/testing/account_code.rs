@@ -86,6 +86,13 @@ pub(crate) const MOCK_ACCOUNT_CODE: &str = "
         # => [ASSET, note_idx, pad(11)]
     end
 
+    # Inputs:  [RECIPIENT, note_idx, pad(11)]
+    # Outputs: [note_idx, pad(15)]
+    export.set_note_recipient
+        exec.tx::set_note_recipient
+        # => [note_idx, pad(15)]
+    end
+
     # Stack:  [ASSET, pad(12)]
     # Output: [ASSET', pad(12)]
     export.add_asset
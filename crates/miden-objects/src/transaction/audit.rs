@@ -0,0 +1,302 @@
+use alloc::vec::Vec;
+
+use super::{
+    AccountDelta, AccountId, Digest, ExecutedTransaction, NoteId, Nullifier, TransactionId,
+    TransactionMeasurements, TransactionTimings,
+};
+use crate::{
+    block::BlockNumber,
+    note::NoteMetadata,
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
+
+// TRANSACTION AUDIT RECORD
+// ================================================================================================
+
+/// A structured record of an executed transaction, intended for compliance archiving.
+///
+/// The record is created via [ExecutedTransaction::to_audit_record] and describes the effects of
+/// the transaction without the data required to re-execute or prove it:
+/// - the transaction ID, the account against which it was executed and the initial and final
+///   account commitments,
+/// - the reference block and the block at which the transaction expires,
+/// - the account delta,
+/// - the consumed and created notes, together with the roots of their scripts where known,
+/// - the root of the transaction script, if any,
+/// - the number of cycles spent in each stage of the transaction. The timings of the stages are not
+///   recorded, since they are not deterministic.
+///
+/// The serialized record is prefixed with [TransactionAuditRecord::SCHEMA_VERSION]. The schema of a
+/// given version never changes, so archived records remain readable by later versions of this
+/// crate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransactionAuditRecord {
+    tx_id: TransactionId,
+    account_id: AccountId,
+    initial_account_commitment: Digest,
+    final_account_commitment: Digest,
+    ref_block_num: BlockNumber,
+    ref_block_commitment: Digest,
+    expiration_block_num: BlockNumber,
+    account_delta: AccountDelta,
+    input_notes: Vec<AuditedInputNote>,
+    output_notes: Vec<AuditedOutputNote>,
+    tx_script_root: Option<Digest>,
+    measurements: TransactionMeasurements,
+}
+
+impl TransactionAuditRecord {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The version of the schema of serialized audit records.
+    pub const SCHEMA_VERSION: u8 = 1;
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the transaction.
+    pub fn tx_id(&self) -> TransactionId {
+        self.tx_id
+    }
+
+    /// Returns the ID of the account against which the transaction was executed.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the commitment of the account before the transaction was executed, which is
+    /// [`Digest::default`] for new accounts.
+    pub fn initial_account_commitment(&self) -> Digest {
+        self.initial_account_commitment
+    }
+
+    /// Returns the commitment of the account after the transaction was executed.
+    pub fn final_account_commitment(&self) -> Digest {
+        self.final_account_commitment
+    }
+
+    /// Returns the number of the block against which the transaction was executed.
+    pub fn ref_block_num(&self) -> BlockNumber {
+        self.ref_block_num
+    }
+
+    /// Returns the commitment of the block against which the transaction was executed.
+    pub fn ref_block_commitment(&self) -> Digest {
+        self.ref_block_commitment
+    }
+
+    /// Returns the block number at which the transaction expires.
+    pub fn expiration_block_num(&self) -> BlockNumber {
+        self.expiration_block_num
+    }
+
+    /// Returns the changes made to the account by the transaction.
+    pub fn account_delta(&self) -> &AccountDelta {
+        &self.account_delta
+    }
+
+    /// Returns the notes consumed by the transaction, in the order they were consumed.
+    pub fn input_notes(&self) -> &[AuditedInputNote] {
+        &self.input_notes
+    }
+
+    /// Returns the notes created by the transaction, in the order they were created.
+    pub fn output_notes(&self) -> &[AuditedOutputNote] {
+        &self.output_notes
+    }
+
+    /// Returns the root of the transaction script, if the transaction was executed with one.
+    pub fn tx_script_root(&self) -> Option<Digest> {
+        self.tx_script_root
+    }
+
+    /// Returns the number of cycles spent in the individual stages of the transaction.
+    pub fn measurements(&self) -> &TransactionMeasurements {
+        &self.measurements
+    }
+}
+
+impl From<&ExecutedTransaction> for TransactionAuditRecord {
+    fn from(tx: &ExecutedTransaction) -> Self {
+        let input_notes = tx
+            .input_notes()
+            .iter()
+            .map(|input_note| {
+                let note = input_note.note();
+                AuditedInputNote {
+                    note_id: note.id(),
+                    nullifier: note.nullifier(),
+                    script_root: note.script().root(),
+                }
+            })
+            .collect();
+
+        let output_notes = tx
+            .output_notes()
+            .iter()
+            .map(|note| AuditedOutputNote {
+                note_id: note.id(),
+                metadata: *note.metadata(),
+                script_root: note.recipient().map(|recipient| recipient.script().root()),
+            })
+            .collect();
+
+        Self {
+            tx_id: tx.id(),
+            account_id: tx.account_id(),
+            initial_account_commitment: tx.initial_account().init_commitment(),
+            final_account_commitment: tx.final_account().commitment(),
+            ref_block_num: tx.block_header().block_num(),
+            ref_block_commitment: tx.block_header().commitment(),
+            expiration_block_num: tx.expiration_block_num(),
+            account_delta: tx.account_delta().clone(),
+            input_notes,
+            output_notes,
+            tx_script_root: tx.tx_args().tx_script().map(|script| script.root()),
+            measurements: TransactionMeasurements {
+                account_commitment_cache_hits: 0,
+                account_commitment_cycles_saved: 0,
+                timings: TransactionTimings::default(),
+                ..tx.measurements().clone()
+            },
+        }
+    }
+}
+
+impl Serializable for TransactionAuditRecord {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(Self::SCHEMA_VERSION);
+        self.tx_id.write_into(target);
+        self.account_id.write_into(target);
+        self.initial_account_commitment.write_into(target);
+        self.final_account_commitment.write_into(target);
+        self.ref_block_num.write_into(target);
+        self.ref_block_commitment.write_into(target);
+        self.expiration_block_num.write_into(target);
+        self.account_delta.write_into(target);
+        self.input_notes.write_into(target);
+        self.output_notes.write_into(target);
+        self.tx_script_root.write_into(target);
+        self.measurements.write_cycles_into(target);
+    }
+}
+
+impl Deserializable for TransactionAuditRecord {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let version = source.read_u8()?;
+        if version != Self::SCHEMA_VERSION {
+            return Err(DeserializationError::InvalidValue(format!(
+                "unsupported transaction audit record schema version: {version}"
+            )));
+        }
+
+        Ok(Self {
+            tx_id: TransactionId::read_from(source)?,
+            account_id: AccountId::read_from(source)?,
+            initial_account_commitment: Digest::read_from(source)?,
+            final_account_commitment: Digest::read_from(source)?,
+            ref_block_num: BlockNumber::read_from(source)?,
+            ref_block_commitment: Digest::read_from(source)?,
+            expiration_block_num: BlockNumber::read_from(source)?,
+            account_delta: AccountDelta::read_from(source)?,
+            input_notes: Vec::<AuditedInputNote>::read_from(source)?,
+            output_notes: Vec::<AuditedOutputNote>::read_from(source)?,
+            tx_script_root: Option::<Digest>::read_from(source)?,
+            measurements: TransactionMeasurements::read_cycles_from(source)?,
+        })
+    }
+}
+
+// AUDITED INPUT NOTE
+// ================================================================================================
+
+/// A note consumed by a transaction, as recorded in a [TransactionAuditRecord].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuditedInputNote {
+    note_id: NoteId,
+    nullifier: Nullifier,
+    script_root: Digest,
+}
+
+impl AuditedInputNote {
+    /// Returns the ID of the note.
+    pub fn note_id(&self) -> NoteId {
+        self.note_id
+    }
+
+    /// Returns the nullifier of the note.
+    pub fn nullifier(&self) -> Nullifier {
+        self.nullifier
+    }
+
+    /// Returns the root of the note script.
+    pub fn script_root(&self) -> Digest {
+        self.script_root
+    }
+}
+
+impl Serializable for AuditedInputNote {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.note_id.write_into(target);
+        self.nullifier.write_into(target);
+        self.script_root.write_into(target);
+    }
+}
+
+impl Deserializable for AuditedInputNote {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            note_id: NoteId::read_from(source)?,
+            nullifier: Nullifier::read_from(source)?,
+            script_root: Digest::read_from(source)?,
+        })
+    }
+}
+
+// AUDITED OUTPUT NOTE
+// ================================================================================================
+
+/// A note created by a transaction, as recorded in a [TransactionAuditRecord].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuditedOutputNote {
+    note_id: NoteId,
+    metadata: NoteMetadata,
+    script_root: Option<Digest>,
+}
+
+impl AuditedOutputNote {
+    /// Returns the ID of the note.
+    pub fn note_id(&self) -> NoteId {
+        self.note_id
+    }
+
+    /// Returns the metadata of the note.
+    pub fn metadata(&self) -> &NoteMetadata {
+        &self.metadata
+    }
+
+    /// Returns the root of the note script, or `None` if the recipient of the note is not known,
+    /// e.g. for private notes created without recipient details.
+    pub fn script_root(&self) -> Option<Digest> {
+        self.script_root
+    }
+}
+
+impl Serializable for AuditedOutputNote {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.note_id.write_into(target);
+        self.metadata.write_into(target);
+        self.script_root.write_into(target);
+    }
+}
+
+impl Deserializable for AuditedOutputNote {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            note_id: NoteId::read_from(source)?,
+            metadata: NoteMetadata::read_from(source)?,
+            script_root: Option::<Digest>::read_from(source)?,
+        })
+    }
+}
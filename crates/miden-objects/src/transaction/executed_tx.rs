@@ -1,14 +1,16 @@
 use alloc::vec::Vec;
-use core::cell::OnceCell;
+use core::{cell::OnceCell, time::Duration};
 
 use super::{
     Account, AccountDelta, AccountHeader, AccountId, AdviceInputs, BlockHeader, InputNote,
-    InputNotes, NoteId, OutputNotes, TransactionArgs, TransactionId, TransactionInputs,
-    TransactionOutputs, TransactionWitness,
+    InputNotes, NoteId, OutputNote, OutputNotes, TransactionArgs, TransactionAuditRecord,
+    TransactionId, TransactionInputs, TransactionOutputs, TransactionWitness,
 };
 use crate::{
     account::AccountCode,
+    asset::FungibleAsset,
     block::BlockNumber,
+    note::{Note, NoteFile},
     utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
 };
 
@@ -102,11 +104,45 @@ impl ExecutedTransaction {
         &self.tx_outputs.output_notes
     }
 
+    /// Returns an iterator over the output notes whose full details are known.
+    ///
+    /// This includes all public notes as well as the private notes whose recipient details were
+    /// provided to the executor, e.g. via [TransactionArgs::add_output_note_recipient]. Private
+    /// notes created without recipient details are skipped.
+    pub fn full_output_notes(&self) -> impl Iterator<Item = &Note> {
+        self.output_notes().iter().filter_map(|note| match note {
+            OutputNote::Full(note) => Some(note),
+            OutputNote::Partial(_) | OutputNote::Header(_) => None,
+        })
+    }
+
+    /// Returns a [NoteFile] for each output note whose full details are known.
+    ///
+    /// The files contain the note details together with the note tag, and the reference block of
+    /// this transaction as the block after which the note is expected to appear on chain. This
+    /// allows the details of outgoing notes to be persisted before the transaction is proven.
+    pub fn output_note_files(&self) -> Vec<NoteFile> {
+        let after_block_num = self.block_header().block_num();
+        self.full_output_notes()
+            .map(|note| NoteFile::NoteDetails {
+                details: note.into(),
+                after_block_num,
+                tag: Some(note.metadata().tag()),
+                payload: None,
+            })
+            .collect()
+    }
+
     /// Returns the block number at which the transaction will expire.
     pub fn expiration_block_num(&self) -> BlockNumber {
         self.tx_outputs.expiration_block_num
     }
 
+    /// Returns the fee paid by the transaction from the account's vault, if any.
+    pub fn fee(&self) -> Option<FungibleAsset> {
+        self.tx_outputs.fee
+    }
+
     /// Returns a reference to the transaction args.
     pub fn tx_args(&self) -> &TransactionArgs {
         &self.tx_args
@@ -133,8 +169,8 @@ impl ExecutedTransaction {
         &self.advice_witness
     }
 
-    /// Returns a reference to the transaction measurements which are the cycle counts for
-    /// each stage.
+    /// Returns a reference to the transaction measurements which are the cycle counts and the
+    /// timings for each stage.
     pub fn measurements(&self) -> &TransactionMeasurements {
         &self.tx_measurements
     }
@@ -142,6 +178,12 @@ impl ExecutedTransaction {
     // CONVERSIONS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns a [TransactionAuditRecord] which describes the effects of this transaction for
+    /// compliance archiving.
+    pub fn to_audit_record(&self) -> TransactionAuditRecord {
+        self.into()
+    }
+
     /// Returns individual components of this transaction.
     pub fn into_parts(
         self,
@@ -207,8 +249,8 @@ impl Deserializable for ExecutedTransaction {
 // TRANSACTION MEASUREMENTS
 // ================================================================================================
 
-/// Stores the resulting number of cycles for each transaction execution stage obtained from the
-/// `TransactionProgress` struct.
+/// Stores the resulting number of cycles and the time spent for each transaction execution stage
+/// obtained from the `TransactionProgress` struct.
 #[derive(Debug, Clone, PartialEq)]
 pub struct TransactionMeasurements {
     pub prologue: usize,
@@ -216,6 +258,13 @@ pub struct TransactionMeasurements {
     pub note_execution: Vec<(NoteId, usize)>,
     pub tx_script_processing: usize,
     pub epilogue: usize,
+    /// The number of times the account commitment was served from the commitment cache of the
+    /// transaction kernel instead of being recomputed.
+    pub account_commitment_cache_hits: usize,
+    /// The estimated number of cycles saved by the account commitment cache.
+    pub account_commitment_cycles_saved: usize,
+    /// The wall-clock time spent in each of the stages.
+    pub timings: TransactionTimings,
 }
 
 impl TransactionMeasurements {
@@ -230,26 +279,109 @@ impl TransactionMeasurements {
         let total_cycles = self.total_cycles();
         total_cycles.next_power_of_two()
     }
-}
 
-impl Serializable for TransactionMeasurements {
-    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+    /// Writes the number of cycles of the stages, without the timings, into the provided target.
+    pub(crate) fn write_cycles_into<W: ByteWriter>(&self, target: &mut W) {
         self.prologue.write_into(target);
         self.notes_processing.write_into(target);
         self.note_execution.write_into(target);
         self.tx_script_processing.write_into(target);
         self.epilogue.write_into(target);
     }
-}
 
-impl Deserializable for TransactionMeasurements {
-    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+    /// Reads the number of cycles of the stages written by [Self::write_cycles_into] and returns
+    /// measurements with empty timings and account commitment cache statistics.
+    pub(crate) fn read_cycles_from<R: ByteReader>(
+        source: &mut R,
+    ) -> Result<Self, DeserializationError> {
         let prologue = usize::read_from(source)?;
         let notes_processing = usize::read_from(source)?;
         let note_execution = Vec::<(NoteId, usize)>::read_from(source)?;
         let tx_script_processing = usize::read_from(source)?;
         let epilogue = usize::read_from(source)?;
 
+        Ok(Self {
+            prologue,
+            notes_processing,
+            note_execution,
+            tx_script_processing,
+            epilogue,
+            account_commitment_cache_hits: 0,
+            account_commitment_cycles_saved: 0,
+            timings: TransactionTimings::default(),
+        })
+    }
+}
+
+impl Serializable for TransactionMeasurements {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.write_cycles_into(target);
+        self.account_commitment_cache_hits.write_into(target);
+        self.account_commitment_cycles_saved.write_into(target);
+        self.timings.write_into(target);
+    }
+}
+
+impl Deserializable for TransactionMeasurements {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let mut measurements = Self::read_cycles_from(source)?;
+        measurements.account_commitment_cache_hits = usize::read_from(source)?;
+        measurements.account_commitment_cycles_saved = usize::read_from(source)?;
+        measurements.timings = TransactionTimings::read_from(source)?;
+
+        Ok(measurements)
+    }
+}
+
+// TRANSACTION TIMINGS
+// ================================================================================================
+
+/// Stores the wall-clock time spent in each transaction execution stage.
+///
+/// The timings are only measured when the transaction is executed in an environment which provides
+/// a clock (i.e., with the `std` feature of `miden-tx`), and are zero otherwise. Unlike the number
+/// of cycles, the timings vary between executions of the same transaction.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransactionTimings {
+    pub prologue: Duration,
+    pub notes_processing: Duration,
+    pub note_execution: Vec<(NoteId, Duration)>,
+    pub tx_script_processing: Duration,
+    pub epilogue: Duration,
+}
+
+impl TransactionTimings {
+    /// Returns the total time spent executing the transaction.
+    pub fn total_time(&self) -> Duration {
+        self.prologue + self.notes_processing + self.tx_script_processing + self.epilogue
+    }
+}
+
+impl Serializable for TransactionTimings {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        write_duration(self.prologue, target);
+        write_duration(self.notes_processing, target);
+        target.write_usize(self.note_execution.len());
+        for (note_id, duration) in self.note_execution.iter() {
+            note_id.write_into(target);
+            write_duration(*duration, target);
+        }
+        write_duration(self.tx_script_processing, target);
+        write_duration(self.epilogue, target);
+    }
+}
+
+impl Deserializable for TransactionTimings {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let prologue = read_duration(source)?;
+        let notes_processing = read_duration(source)?;
+        let num_notes = source.read_usize()?;
+        let note_execution = (0..num_notes)
+            .map(|_| Ok((NoteId::read_from(source)?, read_duration(source)?)))
+            .collect::<Result<_, DeserializationError>>()?;
+        let tx_script_processing = read_duration(source)?;
+        let epilogue = read_duration(source)?;
+
         Ok(Self {
             prologue,
             notes_processing,
@@ -259,3 +391,13 @@ impl Deserializable for TransactionMeasurements {
         })
     }
 }
+
+/// Writes the provided duration as a number of nanoseconds, saturating at `u64::MAX`.
+fn write_duration<W: ByteWriter>(duration: Duration, target: &mut W) {
+    target.write_u64(u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX));
+}
+
+/// Reads a duration written by [write_duration].
+fn read_duration<R: ByteReader>(source: &mut R) -> Result<Duration, DeserializationError> {
+    source.read_u64().map(Duration::from_nanos)
+}
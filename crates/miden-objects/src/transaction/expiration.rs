@@ -0,0 +1,169 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+
+use super::{ProvenTransaction, TransactionId};
+use crate::block::BlockNumber;
+
+// EXPIRATION INDEX
+// ================================================================================================
+
+/// An index of transactions by their expiration block number, e.g. for pruning the expired
+/// transactions from a mempool.
+///
+/// Transactions are added to the index when they enter the mempool and removed once they are
+/// included in a block via [`Self::remove`]. Whenever a new block is added to the chain,
+/// [`Self::advance_chain_tip`] removes and returns the transactions which expired with respect to
+/// the new chain tip, following the semantics of [`ProvenTransaction::is_expired`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExpirationIndex {
+    /// The IDs of the tracked transactions grouped by their expiration block number.
+    by_expiration: BTreeMap<BlockNumber, BTreeSet<TransactionId>>,
+    /// The expiration block number of each tracked transaction.
+    expirations: BTreeMap<TransactionId, BlockNumber>,
+}
+
+impl ExpirationIndex {
+    /// Returns a new, empty [ExpirationIndex].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of tracked transactions.
+    pub fn len(&self) -> usize {
+        self.expirations.len()
+    }
+
+    /// Returns `true` if no transactions are tracked.
+    pub fn is_empty(&self) -> bool {
+        self.expirations.is_empty()
+    }
+
+    /// Returns `true` if the transaction with the specified ID is tracked.
+    pub fn contains(&self, tx_id: &TransactionId) -> bool {
+        self.expirations.contains_key(tx_id)
+    }
+
+    /// Returns the expiration block number of the transaction with the specified ID, or `None` if
+    /// the transaction is not tracked.
+    pub fn expiration_block_num(&self, tx_id: &TransactionId) -> Option<BlockNumber> {
+        self.expirations.get(tx_id).copied()
+    }
+
+    /// Returns the earliest expiration block number of the tracked transactions, or `None` if no
+    /// transactions are tracked.
+    pub fn next_expiration(&self) -> Option<BlockNumber> {
+        self.by_expiration.keys().next().copied()
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Starts tracking the provided transaction.
+    ///
+    /// Returns `false` if the transaction was already tracked, in which case the index is left
+    /// unchanged.
+    pub fn insert(&mut self, tx: &ProvenTransaction) -> bool {
+        self.insert_id(tx.id(), tx.expiration_block_num())
+    }
+
+    /// Starts tracking the transaction with the specified ID and expiration block number.
+    ///
+    /// Returns `false` if the transaction was already tracked, in which case the index is left
+    /// unchanged.
+    pub fn insert_id(&mut self, tx_id: TransactionId, expiration_block_num: BlockNumber) -> bool {
+        if self.expirations.contains_key(&tx_id) {
+            return false;
+        }
+
+        self.expirations.insert(tx_id, expiration_block_num);
+        self.by_expiration.entry(expiration_block_num).or_default().insert(tx_id);
+        true
+    }
+
+    /// Stops tracking the transaction with the specified ID, e.g. because it was included in a
+    /// block, and returns its expiration block number.
+    ///
+    /// Returns `None` if the transaction was not tracked.
+    pub fn remove(&mut self, tx_id: &TransactionId) -> Option<BlockNumber> {
+        let expiration_block_num = self.expirations.remove(tx_id)?;
+
+        let tx_ids = self
+            .by_expiration
+            .get_mut(&expiration_block_num)
+            .expect("expiring transactions should be indexed by their expiration block number");
+        tx_ids.remove(tx_id);
+        if tx_ids.is_empty() {
+            self.by_expiration.remove(&expiration_block_num);
+        }
+
+        Some(expiration_block_num)
+    }
+
+    /// Removes and returns the IDs of the transactions which expired with respect to the provided
+    /// chain tip, i.e. the transactions whose expiration block number is less than or equal to
+    /// `chain_tip`.
+    ///
+    /// The IDs are returned in the order of the expiration block numbers of the transactions.
+    pub fn advance_chain_tip(&mut self, chain_tip: BlockNumber) -> Vec<TransactionId> {
+        // split off the transactions which expire after the chain tip and keep them in the index
+        let not_expired = match chain_tip.as_u32().checked_add(1) {
+            Some(next_block_num) => {
+                self.by_expiration.split_off(&BlockNumber::from(next_block_num))
+            },
+            None => BTreeMap::new(),
+        };
+        let expired = core::mem::replace(&mut self.by_expiration, not_expired);
+
+        let expired_ids: Vec<TransactionId> = expired.into_values().flatten().collect();
+        for tx_id in expired_ids.iter() {
+            self.expirations.remove(tx_id);
+        }
+
+        expired_ids
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Felt, ZERO};
+
+    fn tx_id(value: u64) -> TransactionId {
+        TransactionId::from([Felt::new(value), ZERO, ZERO, ZERO])
+    }
+
+    #[test]
+    fn expiration_index_yields_expired_transactions() {
+        let mut index = ExpirationIndex::new();
+        assert!(index.insert_id(tx_id(1), BlockNumber::from(5)));
+        assert!(index.insert_id(tx_id(2), BlockNumber::from(3)));
+        assert!(index.insert_id(tx_id(3), BlockNumber::from(5)));
+        assert!(index.insert_id(tx_id(4), BlockNumber::from(8)));
+        assert!(!index.insert_id(tx_id(4), BlockNumber::from(9)));
+        assert_eq!(index.len(), 4);
+        assert_eq!(index.next_expiration(), Some(BlockNumber::from(3)));
+
+        assert!(index.advance_chain_tip(BlockNumber::from(2)).is_empty());
+        assert_eq!(index.advance_chain_tip(BlockNumber::from(3)), [tx_id(2)]);
+
+        // transactions which were included in a block do not expire
+        assert_eq!(index.remove(&tx_id(3)), Some(BlockNumber::from(5)));
+        assert_eq!(index.remove(&tx_id(3)), None);
+
+        assert_eq!(index.advance_chain_tip(BlockNumber::from(6)), [tx_id(1)]);
+        assert!(index.contains(&tx_id(4)));
+        assert_eq!(index.expiration_block_num(&tx_id(4)), Some(BlockNumber::from(8)));
+
+        assert_eq!(index.advance_chain_tip(BlockNumber::from(u32::MAX)), [tx_id(4)]);
+        assert!(index.is_empty());
+        assert_eq!(index, ExpirationIndex::default());
+    }
+}
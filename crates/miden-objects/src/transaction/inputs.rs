@@ -1,4 +1,4 @@
-use alloc::{collections::BTreeSet, vec::Vec};
+use alloc::{collections::BTreeSet, vec, vec::Vec};
 use core::fmt::Debug;
 
 use super::{BlockHeader, ChainMmr, Digest, Felt, Hasher, Word};
@@ -113,6 +113,27 @@ impl TransactionInputs {
         &self.input_notes
     }
 
+    // TRANSFORMATIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns these transaction inputs with the input notes reordered such that the notes with
+    /// the specified IDs come first, in the order in which they are specified.
+    ///
+    /// The transaction kernel consumes the input notes in the order in which they appear in
+    /// [Self::input_notes]. Notes which are not referenced by `note_ids` keep their relative order
+    /// and are placed after the referenced notes. IDs which do not refer to any of the input notes
+    /// are ignored.
+    pub fn with_input_note_order(mut self, note_ids: &[NoteId]) -> Self {
+        let mut notes = self.input_notes.into_vec();
+        notes.sort_by_key(|note| {
+            note_ids.iter().position(|note_id| *note_id == note.id()).unwrap_or(usize::MAX)
+        });
+
+        // reordering the notes does not affect the validity of the set of notes
+        self.input_notes = InputNotes::new_unchecked(notes);
+        self
+    }
+
     // CONVERSIONS
     // --------------------------------------------------------------------------------------------
 
@@ -321,10 +342,184 @@ impl<T: Deserializable + ToInputNoteCommitments> Deserializable for InputNotes<T
     }
 }
 
+// INPUT NOTES BUILDER
+// ================================================================================================
+
+/// A builder for [InputNotes] which, if the notes exceed the maximum number of input notes per
+/// transaction, suggests how to split them into multiple transactions.
+///
+/// Notes which must be consumed in the same transaction, e.g. because the script of one note
+/// relies on the other note being consumed alongside it, are added as a group via
+/// [Self::add_dependent_notes]. Groups are never split across transactions.
+#[derive(Debug, Clone)]
+pub struct InputNotesBuilder<T> {
+    groups: Vec<Vec<T>>,
+    max_notes: usize,
+}
+
+impl<T: ToInputNoteCommitments> InputNotesBuilder<T> {
+    /// Returns a new, empty [InputNotesBuilder] which allows up to [`MAX_INPUT_NOTES_PER_TX`] notes
+    /// per transaction.
+    pub fn new() -> Self {
+        Self {
+            groups: Vec::new(),
+            max_notes: MAX_INPUT_NOTES_PER_TX,
+        }
+    }
+
+    /// Sets the maximum number of notes per transaction, capped at [`MAX_INPUT_NOTES_PER_TX`].
+    pub fn with_max_notes(mut self, max_notes: usize) -> Self {
+        self.max_notes = max_notes.min(MAX_INPUT_NOTES_PER_TX);
+        self
+    }
+
+    /// Adds a note which does not depend on any other note.
+    pub fn add_note(mut self, note: T) -> Self {
+        self.groups.push(vec![note]);
+        self
+    }
+
+    /// Adds a group of notes which must be consumed in the same transaction.
+    pub fn add_dependent_notes(mut self, notes: impl IntoIterator<Item = T>) -> Self {
+        let notes: Vec<T> = notes.into_iter().collect();
+        if !notes.is_empty() {
+            self.groups.push(notes);
+        }
+        self
+    }
+
+    /// Returns the total number of notes added to this builder.
+    pub fn num_notes(&self) -> usize {
+        self.groups.iter().map(Vec::len).sum()
+    }
+
+    /// Returns the [InputNotes] consisting of all notes added to this builder, in the order they
+    /// were added.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The notes contain duplicates.
+    /// - The total number of notes exceeds the maximum number of notes per transaction. The error
+    ///   contains the nullifiers of the notes grouped into the transactions suggested by
+    ///   [Self::split].
+    /// - A group of dependent notes exceeds the maximum number of notes per transaction.
+    pub fn build(self) -> Result<InputNotes<T>, TransactionInputError> {
+        self.check_duplicates()?;
+
+        let num_notes = self.num_notes();
+        if num_notes > self.max_notes {
+            let suggested_split = self
+                .pack_groups()?
+                .into_iter()
+                .map(|group_indices| {
+                    group_indices
+                        .into_iter()
+                        .flat_map(|idx| self.groups[idx].iter().map(|note| note.nullifier()))
+                        .collect()
+                })
+                .collect();
+
+            return Err(TransactionInputError::InputNotesSplitRequired {
+                num_notes,
+                max_notes: self.max_notes,
+                suggested_split,
+            });
+        }
+
+        InputNotes::new(self.groups.into_iter().flatten().collect())
+    }
+
+    /// Splits the notes added to this builder into as few [InputNotes] as possible, each of which
+    /// can be consumed by a single transaction.
+    ///
+    /// Groups of dependent notes are always assigned to the same transaction. Within every
+    /// transaction, the notes keep the order in which they were added.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The notes contain duplicates.
+    /// - A group of dependent notes exceeds the maximum number of notes per transaction.
+    pub fn split(self) -> Result<Vec<InputNotes<T>>, TransactionInputError> {
+        self.check_duplicates()?;
+
+        let txs = self.pack_groups()?;
+
+        let mut groups: Vec<Option<Vec<T>>> = self.groups.into_iter().map(Some).collect();
+        txs.into_iter()
+            .map(|group_indices| {
+                let notes = group_indices
+                    .into_iter()
+                    .flat_map(|idx| groups[idx].take().expect("group should be assigned once"))
+                    .collect();
+                InputNotes::new(notes)
+            })
+            .collect()
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns an error if any note was added more than once.
+    fn check_duplicates(&self) -> Result<(), TransactionInputError> {
+        let mut seen_notes = BTreeSet::new();
+        for note in self.groups.iter().flatten() {
+            if !seen_notes.insert(note.nullifier().inner()) {
+                return Err(TransactionInputError::DuplicateInputNote(note.nullifier()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Assigns the groups of notes to transactions using first-fit decreasing bin packing and
+    /// returns the indices of the groups assigned to each transaction, in ascending order.
+    fn pack_groups(&self) -> Result<Vec<Vec<usize>>, TransactionInputError> {
+        if let Some(group) = self.groups.iter().find(|group| group.len() > self.max_notes) {
+            return Err(TransactionInputError::DependentInputNotesExceedLimit {
+                num_notes: group.len(),
+                max_notes: self.max_notes,
+            });
+        }
+
+        // place the largest groups first, so that the smaller groups can fill the gaps
+        let mut group_indices: Vec<usize> = (0..self.groups.len()).collect();
+        group_indices.sort_by_key(|&idx| core::cmp::Reverse(self.groups[idx].len()));
+
+        let mut tx_sizes: Vec<usize> = Vec::new();
+        let mut group_txs = vec![0; self.groups.len()];
+        for idx in group_indices {
+            let group_size = self.groups[idx].len();
+            let tx_idx = match tx_sizes.iter().position(|size| size + group_size <= self.max_notes)
+            {
+                Some(tx_idx) => tx_idx,
+                None => {
+                    tx_sizes.push(0);
+                    tx_sizes.len() - 1
+                },
+            };
+            tx_sizes[tx_idx] += group_size;
+            group_txs[idx] = tx_idx;
+        }
+
+        let mut txs: Vec<Vec<usize>> = vec![Vec::new(); tx_sizes.len()];
+        for (idx, tx_idx) in group_txs.into_iter().enumerate() {
+            txs[tx_idx].push(idx);
+        }
+
+        Ok(txs)
+    }
+}
+
+impl<T: ToInputNoteCommitments> Default for InputNotesBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // HELPER FUNCTIONS
 // ------------------------------------------------------------------------------------------------
 
-fn build_input_note_commitment<T: ToInputNoteCommitments>(notes: &[T]) -> Digest {
+pub(super) fn build_input_note_commitment<T: ToInputNoteCommitments>(notes: &[T]) -> Digest {
     // Note: This implementation must be kept in sync with the kernel's `process_input_notes_data`
     if notes.is_empty() {
         return Digest::default();
@@ -531,3 +726,69 @@ pub fn validate_account_seed(
         (false, None) => Ok(()),
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::InputNotesBuilder;
+    use crate::{TransactionInputError, note::Nullifier, transaction::InputNoteCommitment};
+
+    fn note(n: u64) -> InputNoteCommitment {
+        InputNoteCommitment::from(Nullifier::dummy(n))
+    }
+
+    fn nullifiers(notes: &[u64]) -> Vec<Nullifier> {
+        notes.iter().map(|n| Nullifier::dummy(*n)).collect()
+    }
+
+    #[test]
+    fn input_notes_builder_suggests_split() {
+        let builder = || {
+            InputNotesBuilder::new()
+                .with_max_notes(4)
+                .add_note(note(1))
+                .add_dependent_notes([note(2), note(3), note(4)])
+                .add_note(note(5))
+                .add_dependent_notes([note(6), note(7)])
+        };
+
+        // within the limit, the notes are built in the order they were added
+        let input_notes = builder().with_max_notes(7).build().unwrap();
+        assert_eq!(
+            input_notes.iter().map(|note| note.nullifier()).collect::<Vec<_>>(),
+            nullifiers(&[1, 2, 3, 4, 5, 6, 7])
+        );
+
+        // dependent notes are never split across transactions
+        let expected_split = [nullifiers(&[1, 2, 3, 4]), nullifiers(&[5, 6, 7])];
+        let err = builder().build().unwrap_err();
+        assert!(matches!(
+            err,
+            TransactionInputError::InputNotesSplitRequired { num_notes: 7, max_notes: 4, ref suggested_split }
+                if *suggested_split == expected_split
+        ));
+
+        let split = builder().split().unwrap();
+        assert_eq!(split.len(), 2);
+        for (input_notes, expected) in split.iter().zip(expected_split) {
+            assert_eq!(
+                input_notes.iter().map(|note| note.nullifier()).collect::<Vec<_>>(),
+                expected
+            );
+        }
+
+        // a group which does not fit into a single transaction cannot be split
+        let err = builder().with_max_notes(2).split().unwrap_err();
+        assert!(matches!(
+            err,
+            TransactionInputError::DependentInputNotesExceedLimit { num_notes: 3, max_notes: 2 }
+        ));
+
+        let err = builder().add_note(note(1)).split().unwrap_err();
+        assert!(matches!(err, TransactionInputError::DuplicateInputNote(_)));
+    }
+}
@@ -6,22 +6,36 @@ use super::{
     vm::AdviceInputs,
 };
 
+mod audit;
 mod chain_mmr;
 mod executed_tx;
+mod expiration;
 mod inputs;
 mod outputs;
 mod proven_tx;
+mod script_args;
 mod transaction_id;
 mod tx_args;
 mod tx_witness;
 
+pub use audit::{AuditedInputNote, AuditedOutputNote, TransactionAuditRecord};
 pub use chain_mmr::ChainMmr;
-pub use executed_tx::{ExecutedTransaction, TransactionMeasurements};
-pub use inputs::{InputNote, InputNotes, ToInputNoteCommitments, TransactionInputs};
+pub use executed_tx::{ExecutedTransaction, TransactionMeasurements, TransactionTimings};
+pub use expiration::ExpirationIndex;
+pub use inputs::{
+    InputNote, InputNotes, InputNotesBuilder, ToInputNoteCommitments, TransactionInputs,
+};
 pub use outputs::{OutputNote, OutputNotes, TransactionOutputs};
 pub use proven_tx::{
-    InputNoteCommitment, ProvenTransaction, ProvenTransactionBuilder, TxAccountUpdate,
+    InputNoteCommitment, ProvenTransaction, ProvenTransactionBuilder,
+    TransactionWeightCoefficients, TxAccountUpdate,
 };
+pub use script_args::{ScriptArgType, TransactionScriptArgs, TransactionScriptArgsBuilder};
 pub use transaction_id::TransactionId;
-pub use tx_args::{TransactionArgs, TransactionScript};
-pub use tx_witness::TransactionWitness;
+pub use tx_args::{
+    AdviceMapNamespace, TransactionArgs, TransactionScript, TransactionScriptInterface,
+};
+pub use tx_witness::{
+    RedactedTransactionWitness, TransactionWitness, TransactionWitnessChunk,
+    TransactionWitnessDecoder, TransactionWitnessSection,
+};
@@ -4,9 +4,11 @@ use core::fmt::Debug;
 use crate::{
     Digest, Felt, Hasher, MAX_OUTPUT_NOTES_PER_TX, TransactionOutputError, Word,
     account::AccountHeader,
+    asset::FungibleAsset,
     block::BlockNumber,
     note::{
-        Note, NoteAssets, NoteHeader, NoteId, NoteMetadata, PartialNote, compute_note_commitment,
+        Note, NoteAssets, NoteHeader, NoteId, NoteMetadata, NoteRecipient, PartialNote,
+        compute_note_commitment,
     },
     utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
 };
@@ -20,6 +22,8 @@ pub struct TransactionOutputs {
     pub account: AccountHeader,
     /// Set of output notes created by the transaction.
     pub output_notes: OutputNotes,
+    /// The fee paid by the transaction from the account's vault, if any.
+    pub fee: Option<FungibleAsset>,
     /// Defines up to which block the transaction is considered valid.
     pub expiration_block_num: BlockNumber,
 }
@@ -28,6 +32,7 @@ impl Serializable for TransactionOutputs {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         self.account.write_into(target);
         self.output_notes.write_into(target);
+        self.fee.write_into(target);
         self.expiration_block_num.write_into(target);
     }
 }
@@ -36,11 +41,13 @@ impl Deserializable for TransactionOutputs {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let account = AccountHeader::read_from(source)?;
         let output_notes = OutputNotes::read_from(source)?;
+        let fee = <Option<FungibleAsset>>::read_from(source)?;
         let expiration_block_num = BlockNumber::read_from(source)?;
 
         Ok(Self {
             account,
             output_notes,
+            fee,
             expiration_block_num,
         })
     }
@@ -145,14 +152,22 @@ impl Deserializable for OutputNotes {
 /// For a non-empty list of notes, this is a sequential hash of (note_id, metadata) tuples for the
 /// notes created in a transaction. For an empty list, [EMPTY_WORD] is returned.
 fn build_output_notes_commitment(notes: &[OutputNote]) -> Digest {
-    if notes.is_empty() {
+    build_note_headers_commitment(notes.iter().map(|note| (note.id(), note.metadata())))
+}
+
+/// Computes the commitment to the output notes with the provided IDs and metadata, as described
+/// in [OutputNotes::commitment].
+pub(super) fn build_note_headers_commitment<'a>(
+    notes: impl ExactSizeIterator<Item = (NoteId, &'a NoteMetadata)>,
+) -> Digest {
+    if notes.len() == 0 {
         return Digest::default();
     }
 
     let mut elements: Vec<Felt> = Vec::with_capacity(notes.len() * 8);
-    for note in notes.iter() {
-        elements.extend_from_slice(note.id().as_elements());
-        elements.extend_from_slice(&Word::from(note.metadata()));
+    for (note_id, metadata) in notes {
+        elements.extend_from_slice(note_id.as_elements());
+        elements.extend_from_slice(&Word::from(metadata));
     }
 
     Hasher::hash_elements(&elements)
@@ -205,6 +220,16 @@ impl OutputNote {
         }
     }
 
+    /// The recipient of the note, if its details are known.
+    ///
+    /// The recipient is only available for [OutputNote::Full] notes.
+    pub fn recipient(&self) -> Option<&NoteRecipient> {
+        match self {
+            OutputNote::Full(note) => Some(note.recipient()),
+            OutputNote::Partial(_) | OutputNote::Header(_) => None,
+        }
+    }
+
     /// Note's metadata.
     pub fn metadata(&self) -> &NoteMetadata {
         match self {
@@ -4,6 +4,7 @@ use super::{InputNote, ToInputNoteCommitments};
 use crate::{
     ACCOUNT_UPDATE_MAX_SIZE, ProvenTransactionError,
     account::delta::AccountUpdateDetails,
+    asset::FungibleAsset,
     block::BlockNumber,
     note::NoteHeader,
     transaction::{
@@ -45,11 +46,30 @@ pub struct ProvenTransaction {
     /// The block number by which the transaction will expire, as defined by the executed scripts.
     expiration_block_num: BlockNumber,
 
+    /// The fee paid by the transaction from the account's vault, if any.
+    fee: Option<FungibleAsset>,
+
     /// A STARK proof that attests to the correct execution of the transaction.
     proof: ExecutionProof,
 }
 
 impl ProvenTransaction {
+    /// The magic bytes which prefix the versioned serialization format of proven transactions.
+    ///
+    /// The legacy format of the previous minor version is not versioned and starts with the big
+    /// endian encoding of the account ID prefix. Since the prefix is a field element, it is
+    /// smaller than the field modulus `2^64 - 2^32 + 1` and hence never starts with these bytes,
+    /// which allows distinguishing the two formats.
+    pub const SERIALIZATION_MAGIC: [u8; 7] = [0xff, 0xff, 0xff, 0xff, b'P', b'T', b'X'];
+
+    /// The version of the serialization format written by [ProvenTransaction::write_into], which
+    /// follows the [ProvenTransaction::SERIALIZATION_MAGIC].
+    ///
+    /// Version `1` is identical to version `2` but does not contain the transaction fee. The
+    /// legacy format, which is identical to version `1` but is not prefixed with the magic bytes
+    /// and the version, can still be decoded.
+    pub const SERIALIZATION_VERSION: u8 = 2;
+
     /// Returns unique identifier of this transaction.
     pub fn id(&self) -> TransactionId {
         self.id
@@ -100,6 +120,21 @@ impl ProvenTransaction {
         self.expiration_block_num
     }
 
+    /// Returns `true` if the transaction has expired with respect to the provided chain tip, i.e.
+    /// if its expiration block number is less than or equal to the number of the latest block in
+    /// the chain.
+    ///
+    /// A transaction which expires at block `n` can still be included in block `n`, but once block
+    /// `n` is part of the chain, the transaction can no longer be included in the next block.
+    pub fn is_expired(&self, chain_tip: BlockNumber) -> bool {
+        self.expiration_block_num <= chain_tip
+    }
+
+    /// Returns the fee paid by the transaction from the account's vault, if any.
+    pub fn fee(&self) -> Option<FungibleAsset> {
+        self.fee
+    }
+
     /// Returns an iterator over the nullifiers of all input notes in this transaction.
     ///
     /// This includes both authenticated and unauthenticated notes.
@@ -107,9 +142,75 @@ impl ProvenTransaction {
         self.input_notes.iter().map(InputNoteCommitment::nullifier)
     }
 
+    /// Returns the size of this transaction in bytes when serialized, including its proof.
+    pub fn serialized_size(&self) -> usize {
+        self.to_bytes().len()
+    }
+
+    /// Returns the size of the proof of this transaction in bytes when serialized.
+    pub fn proof_size(&self) -> usize {
+        self.proof.to_bytes().len()
+    }
+
+    /// Returns the weight of this transaction computed with the provided coefficients.
+    ///
+    /// The weight is a measure of the cost of including the transaction in a block, e.g. for
+    /// computing fees or for selecting transactions when building a block. See
+    /// [TransactionWeightCoefficients] for details on how it is computed.
+    pub fn weight(&self, coefficients: &TransactionWeightCoefficients) -> u64 {
+        let proof_size = self.proof_size();
+        let data_size = self.serialized_size() - proof_size;
+
+        coefficients.compute_weight(data_size, proof_size)
+    }
+
     // HELPER METHODS
     // --------------------------------------------------------------------------------------------
 
+    /// Reads the fields of a proven transaction which follow the account update, in the format of
+    /// the specified serialization version.
+    fn read_fields<R: ByteReader>(
+        account_update: TxAccountUpdate,
+        source: &mut R,
+        version: u8,
+    ) -> Result<Self, DeserializationError> {
+        let input_notes = <InputNotes<InputNoteCommitment>>::read_from(source)?;
+        let output_notes = OutputNotes::read_from(source)?;
+
+        let ref_block_num = BlockNumber::read_from(source)?;
+        let ref_block_commitment = Digest::read_from(source)?;
+        let expiration_block_num = BlockNumber::read_from(source)?;
+        let fee = if version >= 2 {
+            <Option<FungibleAsset>>::read_from(source)?
+        } else {
+            None
+        };
+        let proof = ExecutionProof::read_from(source)?;
+
+        let id = TransactionId::new(
+            account_update.initial_state_commitment(),
+            account_update.final_state_commitment(),
+            input_notes.commitment(),
+            output_notes.commitment(),
+        );
+
+        let proven_transaction = Self {
+            id,
+            account_update,
+            input_notes,
+            output_notes,
+            ref_block_num,
+            ref_block_commitment,
+            expiration_block_num,
+            fee,
+            proof,
+        };
+
+        proven_transaction
+            .validate()
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+
     fn validate(self) -> Result<Self, ProvenTransactionError> {
         if self.account_id().is_public() {
             self.account_update.validate()?;
@@ -161,49 +262,50 @@ impl ProvenTransaction {
 
 impl Serializable for ProvenTransaction {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_bytes(&Self::SERIALIZATION_MAGIC);
+        target.write_u8(Self::SERIALIZATION_VERSION);
         self.account_update.write_into(target);
         self.input_notes.write_into(target);
         self.output_notes.write_into(target);
         self.ref_block_num.write_into(target);
         self.ref_block_commitment.write_into(target);
         self.expiration_block_num.write_into(target);
+        self.fee.write_into(target);
         self.proof.write_into(target);
     }
 }
 
 impl Deserializable for ProvenTransaction {
+    /// Deserializes a proven transaction from the provided source.
+    ///
+    /// In addition to the versioned formats, this also accepts the legacy format without the magic
+    /// bytes and the version, so that transactions serialized by clients of the previous minor
+    /// version can still be decoded, including as part of other objects.
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        let account_update = TxAccountUpdate::read_from(source)?;
-
-        let input_notes = <InputNotes<InputNoteCommitment>>::read_from(source)?;
-        let output_notes = OutputNotes::read_from(source)?;
-
-        let ref_block_num = BlockNumber::read_from(source)?;
-        let ref_block_commitment = Digest::read_from(source)?;
-        let expiration_block_num = BlockNumber::read_from(source)?;
-        let proof = ExecutionProof::read_from(source)?;
-
-        let id = TransactionId::new(
-            account_update.initial_state_commitment(),
-            account_update.final_state_commitment(),
-            input_notes.commitment(),
-            output_notes.commitment(),
-        );
+        let header: [u8; 8] = source.read_array()?;
+
+        // the legacy format starts with the account ID, of which the header holds the first bytes
+        if header[..7] != Self::SERIALIZATION_MAGIC {
+            let account_id_suffix: [u8; 7] = source.read_array()?;
+            let mut account_id = [0; 15];
+            account_id[..8].copy_from_slice(&header);
+            account_id[8..].copy_from_slice(&account_id_suffix);
+            let account_id = AccountId::try_from(account_id)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+
+            let account_update = TxAccountUpdate::read_after_account_id(account_id, source)?;
+            return Self::read_fields(account_update, source, 1);
+        }
 
-        let proven_transaction = Self {
-            id,
-            account_update,
-            input_notes,
-            output_notes,
-            ref_block_num,
-            ref_block_commitment,
-            expiration_block_num,
-            proof,
-        };
+        let version = header[7];
+        if version == 0 || version > Self::SERIALIZATION_VERSION {
+            return Err(DeserializationError::InvalidValue(format!(
+                "unsupported proven transaction serialization version: {version}"
+            )));
+        }
 
-        proven_transaction
-            .validate()
-            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+        let account_update = TxAccountUpdate::read_from(source)?;
+        Self::read_fields(account_update, source, version)
     }
 }
 
@@ -240,6 +342,9 @@ pub struct ProvenTransactionBuilder {
     /// The block number by which the transaction will expire, as defined by the executed scripts.
     expiration_block_num: BlockNumber,
 
+    /// The fee paid by the transaction from the account's vault, if any.
+    fee: Option<FungibleAsset>,
+
     /// A STARK proof that attests to the correct execution of the transaction.
     proof: ExecutionProof,
 }
@@ -268,6 +373,7 @@ impl ProvenTransactionBuilder {
             ref_block_num,
             ref_block_commitment,
             expiration_block_num,
+            fee: None,
             proof,
         }
     }
@@ -281,6 +387,12 @@ impl ProvenTransactionBuilder {
         self
     }
 
+    /// Sets the fee paid by the transaction from the account's vault.
+    pub fn fee(mut self, fee: Option<FungibleAsset>) -> Self {
+        self.fee = fee;
+        self
+    }
+
     /// Add notes consumed by the transaction.
     pub fn add_input_notes<I, T>(mut self, notes: I) -> Self
     where
@@ -332,6 +444,7 @@ impl ProvenTransactionBuilder {
             ref_block_num: self.ref_block_num,
             ref_block_commitment: self.ref_block_commitment,
             expiration_block_num: self.expiration_block_num,
+            fee: self.fee,
             proof: self.proof,
         };
 
@@ -339,6 +452,70 @@ impl ProvenTransactionBuilder {
     }
 }
 
+// TRANSACTION WEIGHT COEFFICIENTS
+// ================================================================================================
+
+/// The coefficients used to compute the weight of a [ProvenTransaction].
+///
+/// The weight of a transaction is computed as:
+///
+/// ```text
+/// weight = base_weight + data_size * data_byte_weight + proof_size * proof_byte_weight
+/// ```
+///
+/// where `proof_size` is the serialized size of the proof of the transaction and `data_size` is
+/// the serialized size of the remaining transaction data. The computation saturates at
+/// [`u64::MAX`].
+///
+/// The default coefficients weigh every byte of the serialized transaction equally, i.e. the
+/// default weight of a transaction is equal to its serialized size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransactionWeightCoefficients {
+    base_weight: u64,
+    data_byte_weight: u64,
+    proof_byte_weight: u64,
+}
+
+impl TransactionWeightCoefficients {
+    /// Returns new [TransactionWeightCoefficients] instantiated from the provided weights.
+    pub const fn new(base_weight: u64, data_byte_weight: u64, proof_byte_weight: u64) -> Self {
+        Self {
+            base_weight,
+            data_byte_weight,
+            proof_byte_weight,
+        }
+    }
+
+    /// Returns the weight every transaction has regardless of its size.
+    pub fn base_weight(&self) -> u64 {
+        self.base_weight
+    }
+
+    /// Returns the weight of a single byte of the serialized transaction data, excluding the proof.
+    pub fn data_byte_weight(&self) -> u64 {
+        self.data_byte_weight
+    }
+
+    /// Returns the weight of a single byte of the serialized transaction proof.
+    pub fn proof_byte_weight(&self) -> u64 {
+        self.proof_byte_weight
+    }
+
+    /// Returns the weight of a transaction with the provided data and proof sizes in bytes.
+    fn compute_weight(&self, data_size: usize, proof_size: usize) -> u64 {
+        let data_weight = (data_size as u64).saturating_mul(self.data_byte_weight);
+        let proof_weight = (proof_size as u64).saturating_mul(self.proof_byte_weight);
+
+        self.base_weight.saturating_add(data_weight).saturating_add(proof_weight)
+    }
+}
+
+impl Default for TransactionWeightCoefficients {
+    fn default() -> Self {
+        Self::new(0, 1, 1)
+    }
+}
+
 // TRANSACTION ACCOUNT UPDATE
 // ================================================================================================
 
@@ -420,6 +597,19 @@ impl TxAccountUpdate {
             Ok(())
         }
     }
+
+    /// Reads the fields of an account update which follow the ID of the account.
+    fn read_after_account_id<R: ByteReader>(
+        account_id: AccountId,
+        source: &mut R,
+    ) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            account_id,
+            init_state_commitment: Digest::read_from(source)?,
+            final_state_commitment: Digest::read_from(source)?,
+            details: AccountUpdateDetails::read_from(source)?,
+        })
+    }
 }
 
 impl Serializable for TxAccountUpdate {
@@ -433,12 +623,8 @@ impl Serializable for TxAccountUpdate {
 
 impl Deserializable for TxAccountUpdate {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        Ok(Self {
-            account_id: AccountId::read_from(source)?,
-            init_state_commitment: Digest::read_from(source)?,
-            final_state_commitment: Digest::read_from(source)?,
-            details: AccountUpdateDetails::read_from(source)?,
-        })
+        let account_id = AccountId::read_from(source)?;
+        Self::read_after_account_id(account_id, source)
     }
 }
 
@@ -541,7 +727,7 @@ impl Deserializable for InputNoteCommitment {
 
 #[cfg(test)]
 mod tests {
-    use alloc::collections::BTreeMap;
+    use alloc::{collections::BTreeMap, vec::Vec};
 
     use miden_verifier::ExecutionProof;
     use vm_core::utils::Deserializable;
@@ -555,10 +741,13 @@ mod tests {
             AccountDelta, AccountId, AccountIdVersion, AccountStorageDelta, AccountStorageMode,
             AccountType, AccountVaultDelta, StorageMapDelta, delta::AccountUpdateDetails,
         },
+        asset::FungibleAsset,
         block::BlockNumber,
-        testing::account_id::ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
-        transaction::{ProvenTransactionBuilder, TxAccountUpdate},
-        utils::Serializable,
+        testing::account_id::{
+            ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET, ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
+        },
+        transaction::{ProvenTransactionBuilder, TransactionWeightCoefficients, TxAccountUpdate},
+        utils::{ByteWriter, Serializable},
     };
 
     fn check_if_sync<T: Sync>() {}
@@ -634,6 +823,106 @@ mod tests {
 
     #[test]
     fn test_proven_tx_serde_roundtrip() {
+        let tx = build_dummy_proven_tx();
+        let deserialized = ProvenTransaction::read_from_bytes(&tx.to_bytes()).unwrap();
+
+        assert_eq!(tx, deserialized);
+    }
+
+    #[test]
+    fn test_proven_tx_with_fee_serde_roundtrip() {
+        let fee =
+            FungibleAsset::new(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET.try_into().unwrap(), 100).unwrap();
+        let tx = build_dummy_proven_tx_with_fee(Some(fee));
+        assert_eq!(tx.fee(), Some(fee));
+
+        let deserialized = ProvenTransaction::read_from_bytes(&tx.to_bytes()).unwrap();
+        assert_eq!(tx, deserialized);
+    }
+
+    #[test]
+    fn test_proven_tx_legacy_deserialization() {
+        let tx = build_dummy_proven_tx();
+        let bytes = tx.to_bytes();
+        assert_eq!(bytes[..7], ProvenTransaction::SERIALIZATION_MAGIC);
+        assert_eq!(bytes[7], ProvenTransaction::SERIALIZATION_VERSION);
+
+        // version 1 is the current format without the fee
+        let bytes = to_v1_bytes(&tx);
+        let deserialized = ProvenTransaction::read_from_bytes(&bytes).unwrap();
+        assert_eq!(tx, deserialized);
+
+        // the legacy format is version 1 without the magic bytes and the version; the first byte
+        // of the account ID is a valid version, which must not be mistaken for one
+        let legacy_bytes = &bytes[8..];
+        assert_eq!(legacy_bytes[0], 1);
+        let deserialized = ProvenTransaction::read_from_bytes(legacy_bytes).unwrap();
+        assert_eq!(tx, deserialized);
+
+        // legacy transactions can also be decoded as part of other objects
+        let mut container = Vec::new();
+        container.write_usize(2);
+        container.write_bytes(legacy_bytes);
+        container.write_bytes(&tx.to_bytes());
+        let deserialized = <Vec<ProvenTransaction>>::read_from_bytes(&container).unwrap();
+        assert_eq!(deserialized, [tx.clone(), tx.clone()]);
+
+        // unknown versions are rejected
+        let mut bytes = tx.to_bytes();
+        bytes[7] = ProvenTransaction::SERIALIZATION_VERSION + 1;
+        assert!(ProvenTransaction::read_from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn proven_transaction_weight() {
+        let tx = build_dummy_proven_tx();
+        let serialized_size = tx.serialized_size();
+        let proof_size = tx.proof_size();
+        assert_eq!(serialized_size, tx.to_bytes().len());
+        assert_eq!(proof_size, tx.proof().to_bytes().len());
+        assert!(proof_size < serialized_size);
+
+        assert_eq!(tx.weight(&TransactionWeightCoefficients::default()), serialized_size as u64);
+
+        let coefficients = TransactionWeightCoefficients::new(100, 2, 3);
+        let data_size = (serialized_size - proof_size) as u64;
+        assert_eq!(tx.weight(&coefficients), 100 + data_size * 2 + proof_size as u64 * 3);
+
+        // the weight saturates instead of overflowing
+        let coefficients = TransactionWeightCoefficients::new(1, u64::MAX, 0);
+        assert_eq!(tx.weight(&coefficients), u64::MAX);
+    }
+
+    #[test]
+    fn proven_transaction_expiration() {
+        let tx = build_dummy_proven_tx();
+        assert_eq!(tx.expiration_block_num(), BlockNumber::from(2));
+
+        assert!(!tx.is_expired(BlockNumber::from(1)));
+        assert!(tx.is_expired(BlockNumber::from(2)));
+        assert!(tx.is_expired(BlockNumber::from(3)));
+    }
+
+    /// Serializes the provided transaction in the format of serialization version `1`.
+    fn to_v1_bytes(tx: &ProvenTransaction) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.write_bytes(&ProvenTransaction::SERIALIZATION_MAGIC);
+        bytes.write_u8(1);
+        tx.account_update().write_into(&mut bytes);
+        tx.input_notes().write_into(&mut bytes);
+        tx.output_notes().write_into(&mut bytes);
+        tx.ref_block_num().write_into(&mut bytes);
+        tx.ref_block_commitment().write_into(&mut bytes);
+        tx.expiration_block_num().write_into(&mut bytes);
+        tx.proof().write_into(&mut bytes);
+        bytes
+    }
+
+    fn build_dummy_proven_tx() -> ProvenTransaction {
+        build_dummy_proven_tx_with_fee(None)
+    }
+
+    fn build_dummy_proven_tx_with_fee(fee: Option<FungibleAsset>) -> ProvenTransaction {
         let account_id = AccountId::dummy(
             [1; 15],
             AccountIdVersion::Version0,
@@ -649,7 +938,7 @@ mod tests {
         let expiration_block_num = BlockNumber::from(2);
         let proof = ExecutionProof::new(Proof::new_dummy(), Default::default());
 
-        let tx = ProvenTransactionBuilder::new(
+        ProvenTransactionBuilder::new(
             account_id,
             initial_account_commitment,
             final_account_commitment,
@@ -658,11 +947,8 @@ mod tests {
             expiration_block_num,
             proof,
         )
+        .fee(fee)
         .build()
-        .expect("failed to build proven transaction");
-
-        let deserialized = ProvenTransaction::read_from_bytes(&tx.to_bytes()).unwrap();
-
-        assert_eq!(tx, deserialized);
+        .expect("failed to build proven transaction")
     }
 }
@@ -0,0 +1,274 @@
+use alloc::vec::Vec;
+
+use super::{Felt, Word};
+use crate::{StarkField, TransactionScriptError, account::AccountId, asset::Asset};
+
+// SCRIPT ARGUMENT TYPE
+// ================================================================================================
+
+/// The type of an argument passed to a transaction script via [TransactionScriptArgs].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptArgType {
+    /// A single field element.
+    Felt,
+    /// An unsigned 32-bit integer, encoded as a single field element.
+    U32,
+    /// An unsigned 64-bit integer smaller than the field modulus, encoded as a single field
+    /// element.
+    U64,
+    /// A word.
+    Word,
+    /// An account ID, encoded as `[account_id_suffix, account_id_prefix]`.
+    AccountId,
+    /// An asset, encoded as a word.
+    Asset,
+}
+
+impl ScriptArgType {
+    /// Returns the number of field elements an argument of this type is encoded into.
+    pub fn num_elements(&self) -> usize {
+        match self {
+            Self::Felt | Self::U32 | Self::U64 => 1,
+            Self::AccountId => 2,
+            Self::Word | Self::Asset => 4,
+        }
+    }
+}
+
+// TRANSACTION SCRIPT ARGUMENTS
+// ================================================================================================
+
+/// Arguments of a transaction script, encoded according to a declared layout.
+///
+/// The arguments are provided to the transaction script as a single entry of the advice map. The
+/// script can load them onto the advice stack via `push.{KEY} adv.push_mapval`, after which the
+/// arguments can be read in the order of the layout: single elements via `adv_push.1`, account IDs
+/// via `adv_push.2` (resulting in `[account_id_prefix, account_id_suffix]` on the stack), and
+/// words and assets via `adv_loadw`.
+///
+/// The advice map entry is returned by [`Self::to_input`] and can be passed to
+/// [`TransactionScript::compile`](super::TransactionScript::compile) as a script input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionScriptArgs {
+    key: Word,
+    elements: Vec<Felt>,
+}
+
+impl TransactionScriptArgs {
+    /// Returns a [TransactionScriptArgsBuilder] for arguments with the provided layout.
+    pub fn builder(
+        layout: impl IntoIterator<Item = ScriptArgType>,
+    ) -> TransactionScriptArgsBuilder {
+        TransactionScriptArgsBuilder::new(layout)
+    }
+
+    /// Returns the key under which the arguments are stored in the advice map.
+    pub fn key(&self) -> Word {
+        self.key
+    }
+
+    /// Returns the encoded arguments.
+    pub fn elements(&self) -> &[Felt] {
+        &self.elements
+    }
+
+    /// Returns the advice map entry containing the arguments.
+    pub fn to_input(&self) -> (Word, Vec<Felt>) {
+        (self.key, self.elements.clone())
+    }
+}
+
+// TRANSACTION SCRIPT ARGUMENTS BUILDER
+// ================================================================================================
+
+/// A builder of [TransactionScriptArgs] which validates the provided values against a declared
+/// layout.
+#[derive(Debug, Clone)]
+pub struct TransactionScriptArgsBuilder {
+    layout: Vec<ScriptArgType>,
+    elements: Vec<Felt>,
+    num_args: usize,
+}
+
+impl TransactionScriptArgsBuilder {
+    /// Returns a new [TransactionScriptArgsBuilder] for arguments with the provided layout.
+    pub fn new(layout: impl IntoIterator<Item = ScriptArgType>) -> Self {
+        let layout: Vec<ScriptArgType> = layout.into_iter().collect();
+        let num_elements = layout.iter().map(ScriptArgType::num_elements).sum();
+
+        Self {
+            layout,
+            elements: Vec::with_capacity(num_elements),
+            num_args: 0,
+        }
+    }
+
+    /// Appends a field element argument.
+    ///
+    /// # Errors
+    /// Returns an error if the next argument of the layout is not of type [ScriptArgType::Felt].
+    pub fn felt(self, value: Felt) -> Result<Self, TransactionScriptError> {
+        self.push(ScriptArgType::Felt, [value])
+    }
+
+    /// Appends an unsigned 32-bit integer argument.
+    ///
+    /// # Errors
+    /// Returns an error if the next argument of the layout is not of type [ScriptArgType::U32].
+    pub fn u32(self, value: u32) -> Result<Self, TransactionScriptError> {
+        self.push(ScriptArgType::U32, [Felt::from(value)])
+    }
+
+    /// Appends an unsigned 64-bit integer argument.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The next argument of the layout is not of type [ScriptArgType::U64].
+    /// - The value is not smaller than the field modulus.
+    pub fn u64(self, value: u64) -> Result<Self, TransactionScriptError> {
+        if value >= Felt::MODULUS {
+            return Err(TransactionScriptError::ScriptArgOutOfRange {
+                index: self.num_args,
+                value,
+            });
+        }
+
+        self.push(ScriptArgType::U64, [Felt::new(value)])
+    }
+
+    /// Appends a word argument.
+    ///
+    /// # Errors
+    /// Returns an error if the next argument of the layout is not of type [ScriptArgType::Word].
+    pub fn word(self, value: Word) -> Result<Self, TransactionScriptError> {
+        self.push(ScriptArgType::Word, value)
+    }
+
+    /// Appends an account ID argument.
+    ///
+    /// # Errors
+    /// Returns an error if the next argument of the layout is not of type
+    /// [ScriptArgType::AccountId].
+    pub fn account_id(self, account_id: AccountId) -> Result<Self, TransactionScriptError> {
+        self.push(ScriptArgType::AccountId, [account_id.suffix(), account_id.prefix().as_felt()])
+    }
+
+    /// Appends an asset argument.
+    ///
+    /// # Errors
+    /// Returns an error if the next argument of the layout is not of type [ScriptArgType::Asset].
+    pub fn asset(self, asset: Asset) -> Result<Self, TransactionScriptError> {
+        self.push(ScriptArgType::Asset, Word::from(asset))
+    }
+
+    /// Builds the [TransactionScriptArgs] which are stored under the provided key in the advice
+    /// map.
+    ///
+    /// # Errors
+    /// Returns an error if fewer arguments than declared by the layout were provided.
+    pub fn build(self, key: Word) -> Result<TransactionScriptArgs, TransactionScriptError> {
+        if self.num_args != self.layout.len() {
+            return Err(TransactionScriptError::TooFewScriptArgs {
+                expected: self.layout.len(),
+                actual: self.num_args,
+            });
+        }
+
+        Ok(TransactionScriptArgs { key, elements: self.elements })
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Appends the provided elements encoding an argument of the provided type, after checking
+    /// that the type matches the next argument of the layout.
+    fn push(
+        mut self,
+        arg_type: ScriptArgType,
+        elements: impl IntoIterator<Item = Felt>,
+    ) -> Result<Self, TransactionScriptError> {
+        let expected = *self
+            .layout
+            .get(self.num_args)
+            .ok_or(TransactionScriptError::TooManyScriptArgs(self.layout.len()))?;
+
+        if expected != arg_type {
+            return Err(TransactionScriptError::ScriptArgTypeMismatch {
+                index: self.num_args,
+                expected,
+                actual: arg_type,
+            });
+        }
+
+        self.elements.extend(elements);
+        self.num_args += 1;
+
+        Ok(self)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::*;
+    use crate::{
+        ONE, ZERO, asset::FungibleAsset,
+        testing::account_id::ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
+    };
+
+    #[test]
+    fn script_args_encoding() {
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap();
+        let asset: Asset = FungibleAsset::mock(100);
+        let key = [ONE, ONE, ZERO, ZERO];
+
+        let args = TransactionScriptArgs::builder([
+            ScriptArgType::U32,
+            ScriptArgType::AccountId,
+            ScriptArgType::Asset,
+        ])
+        .u32(7)
+        .unwrap()
+        .account_id(account_id)
+        .unwrap()
+        .asset(asset)
+        .unwrap()
+        .build(key)
+        .unwrap();
+
+        let mut expected = vec![Felt::new(7), account_id.suffix(), account_id.prefix().as_felt()];
+        expected.extend(Word::from(asset));
+        assert_eq!(args.to_input(), (key, expected));
+    }
+
+    #[test]
+    fn script_args_validation() {
+        let builder = TransactionScriptArgs::builder([ScriptArgType::U64, ScriptArgType::Word]);
+
+        assert_matches!(
+            builder.clone().u64(Felt::MODULUS),
+            Err(TransactionScriptError::ScriptArgOutOfRange { index: 0, value: Felt::MODULUS })
+        );
+        assert_matches!(
+            builder.clone().u32(1),
+            Err(TransactionScriptError::ScriptArgTypeMismatch {
+                index: 0,
+                expected: ScriptArgType::U64,
+                actual: ScriptArgType::U32
+            })
+        );
+        assert_matches!(
+            builder.clone().u64(1).unwrap().build(Word::default()),
+            Err(TransactionScriptError::TooFewScriptArgs { expected: 2, actual: 1 })
+        );
+        assert_matches!(
+            builder.u64(1).unwrap().word(Word::default()).unwrap().felt(ONE),
+            Err(TransactionScriptError::TooManyScriptArgs(2))
+        );
+    }
+}
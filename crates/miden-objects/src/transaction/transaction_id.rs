@@ -1,9 +1,14 @@
 use alloc::string::String;
 use core::fmt::{Debug, Display};
 
-use super::{Digest, ExecutedTransaction, Felt, Hasher, ProvenTransaction, WORD_SIZE, Word, ZERO};
-use crate::utils::serde::{
-    ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
+use super::{
+    Digest, ExecutedTransaction, Felt, Hasher, ProvenTransaction, ToInputNoteCommitments,
+    WORD_SIZE, Word, ZERO, inputs::build_input_note_commitment,
+    outputs::build_note_headers_commitment,
+};
+use crate::{
+    note::NoteHeader,
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
 };
 
 // TRANSACTION ID
@@ -38,6 +43,32 @@ impl TransactionId {
         Self(Hasher::hash_elements(&elements))
     }
 
+    /// Returns the [TransactionId] of a transaction with the provided account commitments, which
+    /// consumes and creates the provided notes.
+    ///
+    /// This computes the same ID as the proven transaction will have, which allows referencing a
+    /// transaction before its proof has been generated, e.g. in a mempool. The notes must be
+    /// provided in the order in which the transaction consumes and creates them, and the initial
+    /// account commitment of a new account is [`Digest::default`].
+    pub fn from_notes<T: ToInputNoteCommitments>(
+        init_account_commitment: Digest,
+        final_account_commitment: Digest,
+        input_notes: &[T],
+        output_notes: &[NoteHeader],
+    ) -> Self {
+        let input_notes_commitment = build_input_note_commitment(input_notes);
+        let output_notes_commitment = build_note_headers_commitment(
+            output_notes.iter().map(|header| (header.id(), header.metadata())),
+        );
+
+        Self::new(
+            init_account_commitment,
+            final_account_commitment,
+            input_notes_commitment,
+            output_notes_commitment,
+        )
+    }
+
     /// Returns the elements representation of this transaction ID.
     pub fn as_elements(&self) -> &[Felt] {
         self.0.as_elements()
@@ -1,11 +1,18 @@
-use alloc::{collections::BTreeMap, sync::Arc, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+    vec::Vec,
+};
 
-use assembly::{Assembler, Compile};
-use miden_crypto::merkle::InnerNodeInfo;
+use assembly::{Assembler, Compile, Library, ast::QualifiedProcedureName};
+use miden_crypto::merkle::{InnerNodeInfo, MerklePath};
+use vm_core::mast::MastNode;
 
-use super::{Digest, Felt, Word};
+use super::{Digest, Felt, Hasher, Word};
 use crate::{
-    MastForest, MastNodeId, TransactionScriptError,
+    MastForest, MastNodeId, TransactionArgsError, TransactionScriptError, ZERO,
+    account::AccountCode,
+    block::BlockNumber,
     note::{NoteId, NoteRecipient},
     utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
     vm::{AdviceInputs, AdviceMap, Program},
@@ -22,11 +29,15 @@ use crate::{
 ///   different from note inputs, as the user executing the transaction can specify arbitrary note
 ///   args.
 /// - Advice inputs: Provides data needed by the runtime, like the details of public output notes.
+/// - Expiration delta: the number of blocks after the reference block at which the transaction
+///   expires. This is equivalent to calling `tx::update_expiration_block_delta` from the
+///   transaction script.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct TransactionArgs {
     tx_script: Option<TransactionScript>,
     note_args: BTreeMap<NoteId, Word>,
     advice_inputs: AdviceInputs,
+    expiration_delta: Option<u16>,
 }
 
 impl TransactionArgs {
@@ -54,6 +65,7 @@ impl TransactionArgs {
             tx_script,
             note_args: note_args.unwrap_or_default(),
             advice_inputs,
+            expiration_delta: None,
         }
     }
 
@@ -74,6 +86,17 @@ impl TransactionArgs {
         self
     }
 
+    /// Returns the provided [TransactionArgs] with the expiration delta set to the passed-in
+    /// number of blocks.
+    ///
+    /// The transaction will expire `expiration_delta` blocks after its reference block, unless the
+    /// transaction script or an input note lowers the expiration block number further. A delta of
+    /// zero is ignored.
+    pub fn with_expiration_delta(mut self, expiration_delta: u16) -> Self {
+        self.expiration_delta = (expiration_delta != 0).then_some(expiration_delta);
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -92,9 +115,20 @@ impl TransactionArgs {
         &self.advice_inputs
     }
 
+    /// Returns the requested expiration delta of the transaction, if any.
+    pub fn expiration_delta(&self) -> Option<u16> {
+        self.expiration_delta
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
+    /// Sets the note args of the note with the specified ID, replacing any previously set args of
+    /// that note.
+    pub fn add_note_args(&mut self, note_id: NoteId, note_args: Word) {
+        self.note_args.insert(note_id, note_args);
+    }
+
     /// Populates the advice inputs with the expected recipient data for creating output notes.
     ///
     /// The advice inputs' map is extended with the following keys:
@@ -134,6 +168,63 @@ impl TransactionArgs {
         }
     }
 
+    /// Inserts the provided values into the advice map under the key derived from `key` within the
+    /// specified namespace, and returns the derived key.
+    ///
+    /// The derived key is computed as `hash(NAMESPACE || KEY)`, so entries contributed by
+    /// different libraries under different namespaces cannot clobber each other. Inserting the
+    /// same values under the same key more than once is allowed.
+    ///
+    /// # Errors
+    /// Returns an error if the advice map already contains different values under the derived key.
+    pub fn add_namespaced_advice_map_entry(
+        &mut self,
+        namespace: &AdviceMapNamespace,
+        key: Digest,
+        values: Vec<Felt>,
+    ) -> Result<Digest, TransactionArgsError> {
+        let derived_key = namespace.derive_key(key);
+
+        if let Some(existing_values) = self.advice_inputs.mapped_values(&derived_key) {
+            if existing_values != values.as_slice() {
+                return Err(TransactionArgsError::AdviceMapKeyCollision {
+                    namespace: namespace.root(),
+                    key,
+                    derived_key,
+                });
+            }
+        }
+
+        self.advice_inputs.extend_map([(derived_key, values)]);
+        Ok(derived_key)
+    }
+
+    /// Populates the advice inputs with the MMR proof of the specified block, so that its
+    /// commitment can be retrieved via `tx::get_block_commitment_from_mmr_proof`.
+    ///
+    /// The `path` must authenticate the block against the chain MMR which includes all blocks up
+    /// to and including the transaction reference block, i.e., an MMR whose forest is
+    /// `reference_block_num + 1`.
+    ///
+    /// The advice inputs' map is extended with the following key:
+    ///
+    /// - [block_num, 0, 0, 0] |-> [block_commitment, path nodes from the leaf to the peak].
+    pub fn add_block_mmr_proof(
+        &mut self,
+        block_num: BlockNumber,
+        block_commitment: Digest,
+        path: &MerklePath,
+    ) {
+        let key = Digest::from([block_num.into(), ZERO, ZERO, ZERO]);
+        let values = block_commitment
+            .iter()
+            .chain(path.iter().flat_map(|node| node.iter()))
+            .copied()
+            .collect();
+
+        self.advice_inputs.extend_map([(key, values)]);
+    }
+
     /// Extends the internal advice inputs' map with the provided key-value pairs.
     pub fn extend_advice_map<T: IntoIterator<Item = (Digest, Vec<Felt>)>>(&mut self, iter: T) {
         self.advice_inputs.extend_map(iter)
@@ -145,11 +236,42 @@ impl TransactionArgs {
     }
 }
 
+// ADVICE MAP NAMESPACE
+// ================================================================================================
+
+/// A namespace for the advice map entries which a library contributes to a transaction.
+///
+/// The keys of entries added via [TransactionArgs::add_namespaced_advice_map_entry] are derived
+/// from the namespace root and the caller-chosen key, which prevents collisions between entries of
+/// different namespaces as well as with the entries the transaction kernel adds to the advice map.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AdviceMapNamespace(Digest);
+
+impl AdviceMapNamespace {
+    /// Returns a new [AdviceMapNamespace] whose root is the hash of the provided name, e.g.
+    /// `"miden::contracts::my_library"`.
+    pub fn new(name: &str) -> Self {
+        Self(Hasher::hash(name.as_bytes()))
+    }
+
+    /// Returns the root of this namespace.
+    pub fn root(&self) -> Digest {
+        self.0
+    }
+
+    /// Returns the advice map key derived from the provided key within this namespace, computed
+    /// as `hash(NAMESPACE || KEY)`.
+    pub fn derive_key(&self, key: Digest) -> Digest {
+        Hasher::merge(&[self.0, key])
+    }
+}
+
 impl Serializable for TransactionArgs {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         self.tx_script.write_into(target);
         self.note_args.write_into(target);
         self.advice_inputs.write_into(target);
+        self.expiration_delta.write_into(target);
     }
 }
 
@@ -158,8 +280,14 @@ impl Deserializable for TransactionArgs {
         let tx_script = Option::<TransactionScript>::read_from(source)?;
         let note_args = BTreeMap::<NoteId, Word>::read_from(source)?;
         let advice_inputs = AdviceInputs::read_from(source)?;
+        let expiration_delta = Option::<u16>::read_from(source)?;
 
-        Ok(Self { tx_script, note_args, advice_inputs })
+        Ok(Self {
+            tx_script,
+            note_args,
+            advice_inputs,
+            expiration_delta,
+        })
     }
 }
 
@@ -211,6 +339,40 @@ impl TransactionScript {
         Ok(Self::new(program, inputs))
     }
 
+    /// Returns a new [TransactionScript] compiled from the provided source code and inputs using
+    /// the specified assembler, after validating the procedures the script declares to call
+    /// against the code of the account the script is executed against.
+    ///
+    /// This allows detecting a script which is incompatible with the target account when it is
+    /// compiled, instead of when the transaction is executed.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Any of the procedures of the interface is not a procedure of the account code.
+    /// - The compilation of the provided source code fails.
+    /// - The script calls a procedure which is not declared in the interface.
+    pub fn compile_with_interface(
+        source_code: impl Compile,
+        inputs: impl IntoIterator<Item = (Word, Vec<Felt>)>,
+        assembler: Assembler,
+        interface: &TransactionScriptInterface,
+        account_code: &AccountCode,
+    ) -> Result<Self, TransactionScriptError> {
+        if let Some(root) = interface.procedures().find(|root| !account_code.has_procedure(*root)) {
+            return Err(TransactionScriptError::UnknownAccountProcedure(root));
+        }
+
+        let tx_script = Self::compile(source_code, inputs, assembler)?;
+
+        if let Some(root) =
+            tx_script.call_targets().into_iter().find(|root| !interface.contains(*root))
+        {
+            return Err(TransactionScriptError::UndeclaredProcedureCall(root));
+        }
+
+        Ok(tx_script)
+    }
+
     /// Returns a new [TransactionScript] instantiated from the provided components.
     ///
     /// # Panics
@@ -241,6 +403,103 @@ impl TransactionScript {
     pub fn inputs(&self) -> &BTreeMap<Digest, Vec<Felt>> {
         &self.inputs
     }
+
+    /// Returns the MAST roots of all procedures which may be `call`ed by this transaction script.
+    ///
+    /// Since account procedures are the only procedures that are `call`ed from transaction scripts
+    /// while kernel procedures are `syscall`ed, these are the account procedures the script may
+    /// invoke. Procedures invoked via `dyncall` are not included.
+    pub fn call_targets(&self) -> BTreeSet<Digest> {
+        let mut call_targets = BTreeSet::new();
+        collect_call_targets(&self.mast, self.entrypoint, &mut call_targets);
+        call_targets
+    }
+}
+
+// TRANSACTION SCRIPT INTERFACE
+// ================================================================================================
+
+/// The account procedures which a [TransactionScript] declares to call.
+///
+/// The interface is validated against the code of the target account when the script is compiled
+/// via [TransactionScript::compile_with_interface]. Procedures can be declared either by their MAST
+/// root or by their name in the library of the account component which defines them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransactionScriptInterface {
+    procedures: BTreeSet<Digest>,
+}
+
+impl TransactionScriptInterface {
+    /// Returns a new, empty [TransactionScriptInterface].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declares the account procedure with the provided MAST root.
+    pub fn with_procedure(mut self, root: Digest) -> Self {
+        self.procedures.insert(root);
+        self
+    }
+
+    /// Declares the account procedure with the provided name, which must be exported by the
+    /// provided library.
+    ///
+    /// # Errors
+    /// Returns an error if the procedure is not exported by the library.
+    pub fn with_library_procedure(
+        mut self,
+        library: &Library,
+        name: &QualifiedProcedureName,
+    ) -> Result<Self, TransactionScriptError> {
+        if !library.exports().any(|export| export == name) {
+            return Err(TransactionScriptError::ProcedureNotExported(name.clone()));
+        }
+
+        let node_id = library.get_export_node_id(name);
+        self.procedures.insert(library.mast_forest()[node_id].digest());
+        Ok(self)
+    }
+
+    /// Returns an iterator over the MAST roots of the declared procedures.
+    pub fn procedures(&self) -> impl Iterator<Item = Digest> + '_ {
+        self.procedures.iter().copied()
+    }
+
+    /// Returns `true` if the procedure with the provided MAST root is declared.
+    pub fn contains(&self, root: Digest) -> bool {
+        self.procedures.contains(&root)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Collects the MAST roots of the procedures `call`ed by the code rooted at the provided node,
+/// excluding `syscall`s.
+fn collect_call_targets(
+    mast: &MastForest,
+    node_id: MastNodeId,
+    call_targets: &mut BTreeSet<Digest>,
+) {
+    match &mast[node_id] {
+        MastNode::Join(join_node) => {
+            collect_call_targets(mast, join_node.first(), call_targets);
+            collect_call_targets(mast, join_node.second(), call_targets);
+        },
+        MastNode::Split(split_node) => {
+            collect_call_targets(mast, split_node.on_true(), call_targets);
+            collect_call_targets(mast, split_node.on_false(), call_targets);
+        },
+        MastNode::Loop(loop_node) => {
+            collect_call_targets(mast, loop_node.body(), call_targets);
+        },
+        MastNode::Call(call_node) => {
+            if !call_node.is_syscall() {
+                call_targets.insert(mast[call_node.callee()].digest());
+            }
+        },
+        MastNode::Block(_) | MastNode::Dyn(_) | MastNode::External(_) => {},
+    }
 }
 
 // SERIALIZATION
@@ -1,9 +1,13 @@
-use alloc::vec::Vec;
+use alloc::{string::ToString, vec::Vec};
 
-use super::{AdviceInputs, TransactionArgs, TransactionInputs};
+use miden_crypto::utils::SliceReader;
+
+use super::{AdviceInputs, ChainMmr, InputNote, InputNotes, TransactionArgs, TransactionInputs};
 use crate::{
-    account::AccountCode,
-    utils::serde::{ByteReader, Deserializable, DeserializationError, Serializable},
+    TransactionInputError,
+    account::{AccountCode, AccountHeader, AccountStorageHeader},
+    block::BlockHeader,
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
 };
 
 // TRANSACTION WITNESS
@@ -23,10 +27,10 @@ use crate::{
 /// - Advice witness which contains all data requested by the VM from the advice provider while
 ///   executing the transaction program.
 ///
-/// TODO: currently, the advice witness contains redundant and irrelevant data (e.g., tx inputs
-/// and tx outputs; account codes and a subset of that data in advice inputs).
-/// We should optimize it to contain only the minimum data required for executing/proving the
-/// transaction.
+/// The witness of an executed transaction contains redundant data (e.g., advice data which is
+/// rebuilt from the tx inputs, and the codes of foreign accounts which were never invoked). It can
+/// be stripped of this data before being sent to a prover via
+/// `TransactionKernel::minimize_witness` of the `miden-lib` crate.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TransactionWitness {
     pub tx_inputs: TransactionInputs,
@@ -35,6 +39,69 @@ pub struct TransactionWitness {
     pub account_codes: Vec<AccountCode>,
 }
 
+impl TransactionWitness {
+    /// Serializes the witness in chunks of at most `max_chunk_size` bytes and passes each chunk to
+    /// `sink` as soon as it is complete.
+    ///
+    /// Unlike [`Serializable::to_bytes`], this never holds more than one chunk of the serialized
+    /// witness in memory, which makes it suitable for sending large witnesses to a remote prover.
+    /// Every section of the witness (see [TransactionWitnessSection]) is split into its own
+    /// chunks, so that the chunks can be decoded incrementally by a [TransactionWitnessDecoder].
+    /// The concatenated data of all chunks is equal to the output of [`Serializable::to_bytes`].
+    ///
+    /// # Panics
+    /// Panics if `max_chunk_size` is zero.
+    pub fn write_chunks(
+        &self,
+        max_chunk_size: usize,
+        mut sink: impl FnMut(TransactionWitnessChunk),
+    ) {
+        assert!(max_chunk_size > 0, "maximum chunk size must be greater than zero");
+
+        let mut writer = ChunkWriter::new(max_chunk_size, &mut sink);
+        writer.write_section(TransactionWitnessSection::TxInputs, &self.tx_inputs);
+        writer.write_section(TransactionWitnessSection::TxArgs, &self.tx_args);
+        writer.write_section(TransactionWitnessSection::AdviceWitness, &self.advice_witness);
+        writer.write_section(TransactionWitnessSection::AccountCodes, &self.account_codes);
+    }
+
+    /// Returns this witness redacted of the private state of the account which is not required for
+    /// proving the transaction. See [RedactedTransactionWitness] for details.
+    ///
+    /// The witness must be the one produced by the executor. A witness minimized via
+    /// `TransactionKernel::minimize_witness` no longer contains the account data read by the
+    /// transaction, and therefore cannot be proven once redacted.
+    ///
+    /// # Errors
+    /// Returns an error if the transaction is executed against a new account, since proving such a
+    /// transaction requires the full initial state of the account.
+    pub fn into_redacted(self) -> Result<RedactedTransactionWitness, TransactionInputError> {
+        let Self {
+            tx_inputs,
+            tx_args,
+            advice_witness,
+            account_codes,
+        } = self;
+        let (account, _, block_header, block_chain, input_notes) = tx_inputs.into_parts();
+
+        if account.is_new() {
+            return Err(TransactionInputError::NewAccountCannotBeRedacted(account.id()));
+        }
+
+        Ok(RedactedTransactionWitness {
+            account: AccountHeader::from(&account),
+            storage_header: account.storage().get_header(),
+            account_code: account.code().clone(),
+            block_header,
+            block_chain,
+            input_notes,
+            tx_args,
+            advice_witness,
+            account_codes,
+        })
+    }
+}
+
 // SERIALIZATION
 // ================================================================================================
 
@@ -61,3 +128,338 @@ impl Deserializable for TransactionWitness {
         })
     }
 }
+
+// REDACTED TRANSACTION WITNESS
+// ================================================================================================
+
+/// A [TransactionWitness] stripped of the private state of the account which is not required for
+/// proving the transaction, e.g. to limit what a delegated prover learns about the account.
+///
+/// Instead of the full initial state of the account, a redacted witness contains only the header
+/// of the account, the header of its storage and its code. The assets in the account vault and the
+/// entries of the account storage maps are removed, except for those which were read by the
+/// transaction and are therefore part of the advice witness. The values of the storage slots are
+/// retained, since the transaction kernel requires them to authenticate the storage commitment of
+/// the account.
+///
+/// A redacted witness is created via [TransactionWitness::into_redacted] and can be proven via
+/// `LocalTransactionProver::prove_redacted` of the `miden-tx` crate.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RedactedTransactionWitness {
+    pub account: AccountHeader,
+    pub storage_header: AccountStorageHeader,
+    pub account_code: AccountCode,
+    pub block_header: BlockHeader,
+    pub block_chain: ChainMmr,
+    pub input_notes: InputNotes<InputNote>,
+    pub tx_args: TransactionArgs,
+    pub advice_witness: AdviceInputs,
+    pub account_codes: Vec<AccountCode>,
+}
+
+impl Serializable for RedactedTransactionWitness {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account.write_into(target);
+        self.storage_header.write_into(target);
+        self.account_code.write_into(target);
+        self.block_header.write_into(target);
+        self.block_chain.write_into(target);
+        self.input_notes.write_into(target);
+        self.tx_args.write_into(target);
+        self.advice_witness.write_into(target);
+        self.account_codes.write_into(target);
+    }
+}
+
+impl Deserializable for RedactedTransactionWitness {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            account: AccountHeader::read_from(source)?,
+            storage_header: AccountStorageHeader::read_from(source)?,
+            account_code: AccountCode::read_from(source)?,
+            block_header: BlockHeader::read_from(source)?,
+            block_chain: ChainMmr::read_from(source)?,
+            input_notes: InputNotes::<InputNote>::read_from(source)?,
+            tx_args: TransactionArgs::read_from(source)?,
+            advice_witness: AdviceInputs::read_from(source)?,
+            account_codes: <Vec<AccountCode>>::read_from(source)?,
+        })
+    }
+}
+
+// TRANSACTION WITNESS SECTION
+// ================================================================================================
+
+/// A section of a [TransactionWitness] in its chunked encoding.
+///
+/// The sections are serialized in the order of their discriminants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum TransactionWitnessSection {
+    /// The transaction inputs.
+    TxInputs = 0,
+    /// The transaction arguments.
+    TxArgs = 1,
+    /// The advice witness.
+    AdviceWitness = 2,
+    /// The account codes.
+    AccountCodes = 3,
+}
+
+impl TransactionWitnessSection {
+    /// Returns the section which follows this one, or `None` if this is the last section.
+    fn next(&self) -> Option<Self> {
+        match self {
+            Self::TxInputs => Some(Self::TxArgs),
+            Self::TxArgs => Some(Self::AdviceWitness),
+            Self::AdviceWitness => Some(Self::AccountCodes),
+            Self::AccountCodes => None,
+        }
+    }
+}
+
+impl TryFrom<u8> for TransactionWitnessSection {
+    type Error = DeserializationError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::TxInputs),
+            1 => Ok(Self::TxArgs),
+            2 => Ok(Self::AdviceWitness),
+            3 => Ok(Self::AccountCodes),
+            _ => Err(DeserializationError::InvalidValue(format!(
+                "invalid transaction witness section: {value}"
+            ))),
+        }
+    }
+}
+
+// TRANSACTION WITNESS CHUNK
+// ================================================================================================
+
+/// A chunk of a [TransactionWitness] serialized via [`TransactionWitness::write_chunks`].
+///
+/// Each chunk contains a part of the serialized data of a single [TransactionWitnessSection]. The
+/// last chunk of every section is marked, so that the section can be decoded as soon as it has
+/// been received.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionWitnessChunk {
+    section: TransactionWitnessSection,
+    is_last: bool,
+    data: Vec<u8>,
+}
+
+impl TransactionWitnessChunk {
+    /// Returns the section of the witness the data of this chunk belongs to.
+    pub fn section(&self) -> TransactionWitnessSection {
+        self.section
+    }
+
+    /// Returns `true` if this is the last chunk of its section.
+    pub fn is_last(&self) -> bool {
+        self.is_last
+    }
+
+    /// Returns the serialized data contained in this chunk.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl Serializable for TransactionWitnessChunk {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.section as u8);
+        target.write_u8(self.is_last as u8);
+        self.data.write_into(target);
+    }
+}
+
+impl Deserializable for TransactionWitnessChunk {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let section = TransactionWitnessSection::try_from(source.read_u8()?)?;
+        let is_last = match source.read_u8()? {
+            0 => false,
+            1 => true,
+            other => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "invalid last chunk flag: {other}"
+                )));
+            },
+        };
+        let data = <Vec<u8>>::read_from(source)?;
+
+        Ok(Self { section, is_last, data })
+    }
+}
+
+// TRANSACTION WITNESS DECODER
+// ================================================================================================
+
+/// Incrementally decodes a [TransactionWitness] from the chunks produced by
+/// [`TransactionWitness::write_chunks`].
+///
+/// The chunks must be pushed in the order in which they were produced. Each section of the
+/// witness is deserialized as soon as its last chunk is pushed, after which the buffered data of
+/// the section is released.
+#[derive(Debug)]
+pub struct TransactionWitnessDecoder {
+    next_section: Option<TransactionWitnessSection>,
+    buffer: Vec<u8>,
+    tx_inputs: Option<TransactionInputs>,
+    tx_args: Option<TransactionArgs>,
+    advice_witness: Option<AdviceInputs>,
+    account_codes: Option<Vec<AccountCode>>,
+}
+
+impl TransactionWitnessDecoder {
+    /// Returns a new [TransactionWitnessDecoder] which expects the first chunk of the witness.
+    pub fn new() -> Self {
+        Self {
+            next_section: Some(TransactionWitnessSection::TxInputs),
+            buffer: Vec::new(),
+            tx_inputs: None,
+            tx_args: None,
+            advice_witness: None,
+            account_codes: None,
+        }
+    }
+
+    /// Returns `true` if all sections of the witness have been decoded.
+    pub fn is_complete(&self) -> bool {
+        self.next_section.is_none()
+    }
+
+    /// Adds the provided chunk to the decoder, and decodes the section of the chunk if it is the
+    /// last chunk of the section.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - the chunk does not belong to the section which is currently being decoded, e.g. because
+    ///   the chunks are pushed out of order or the witness has already been decoded completely.
+    /// - the data of the section cannot be deserialized.
+    pub fn push_chunk(
+        &mut self,
+        chunk: TransactionWitnessChunk,
+    ) -> Result<(), DeserializationError> {
+        if self.next_section != Some(chunk.section) {
+            return Err(DeserializationError::InvalidValue(format!(
+                "unexpected chunk of transaction witness section {:?}, expected section {:?}",
+                chunk.section, self.next_section
+            )));
+        }
+
+        self.buffer.extend_from_slice(&chunk.data);
+        if !chunk.is_last {
+            return Ok(());
+        }
+
+        let data = core::mem::take(&mut self.buffer);
+        match chunk.section {
+            TransactionWitnessSection::TxInputs => self.tx_inputs = Some(read_section(&data)?),
+            TransactionWitnessSection::TxArgs => self.tx_args = Some(read_section(&data)?),
+            TransactionWitnessSection::AdviceWitness => {
+                self.advice_witness = Some(read_section(&data)?)
+            },
+            TransactionWitnessSection::AccountCodes => {
+                self.account_codes = Some(read_section(&data)?)
+            },
+        }
+        self.next_section = chunk.section.next();
+
+        Ok(())
+    }
+
+    /// Returns the decoded [TransactionWitness].
+    ///
+    /// # Errors
+    /// Returns an error if not all sections of the witness have been decoded.
+    pub fn finish(self) -> Result<TransactionWitness, DeserializationError> {
+        match (self.tx_inputs, self.tx_args, self.advice_witness, self.account_codes) {
+            (Some(tx_inputs), Some(tx_args), Some(advice_witness), Some(account_codes)) => {
+                Ok(TransactionWitness {
+                    tx_inputs,
+                    tx_args,
+                    advice_witness,
+                    account_codes,
+                })
+            },
+            _ => Err(DeserializationError::UnexpectedEOF),
+        }
+    }
+}
+
+impl Default for TransactionWitnessDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// A [ByteWriter] which splits the serialized data of the sections of a [TransactionWitness] into
+/// [TransactionWitnessChunk]s.
+struct ChunkWriter<'a, F: FnMut(TransactionWitnessChunk)> {
+    max_chunk_size: usize,
+    section: TransactionWitnessSection,
+    buffer: Vec<u8>,
+    sink: &'a mut F,
+}
+
+impl<'a, F: FnMut(TransactionWitnessChunk)> ChunkWriter<'a, F> {
+    fn new(max_chunk_size: usize, sink: &'a mut F) -> Self {
+        Self {
+            max_chunk_size,
+            section: TransactionWitnessSection::TxInputs,
+            buffer: Vec::with_capacity(max_chunk_size),
+            sink,
+        }
+    }
+
+    /// Writes the provided section and emits its last chunk.
+    fn write_section(&mut self, section: TransactionWitnessSection, value: &impl Serializable) {
+        self.section = section;
+        value.write_into(self);
+        self.emit(true);
+    }
+
+    /// Passes the buffered data to the sink as a chunk of the current section.
+    fn emit(&mut self, is_last: bool) {
+        let data = core::mem::replace(&mut self.buffer, Vec::with_capacity(self.max_chunk_size));
+        (self.sink)(TransactionWitnessChunk { section: self.section, is_last, data });
+    }
+}
+
+impl<F: FnMut(TransactionWitnessChunk)> ByteWriter for ChunkWriter<'_, F> {
+    fn write_u8(&mut self, value: u8) {
+        self.write_bytes(&[value]);
+    }
+
+    fn write_bytes(&mut self, mut values: &[u8]) {
+        while !values.is_empty() {
+            // a full chunk is only emitted once more data follows, so that the last chunk of a
+            // section is never empty
+            if self.buffer.len() == self.max_chunk_size {
+                self.emit(false);
+            }
+
+            let num_bytes = values.len().min(self.max_chunk_size - self.buffer.len());
+            self.buffer.extend_from_slice(&values[..num_bytes]);
+            values = &values[num_bytes..];
+        }
+    }
+}
+
+/// Deserializes a section of a [TransactionWitness] from the provided bytes, which must be
+/// consumed completely.
+fn read_section<T: Deserializable>(bytes: &[u8]) -> Result<T, DeserializationError> {
+    let mut reader = SliceReader::new(bytes);
+    let value = T::read_from(&mut reader)?;
+    if reader.has_more_bytes() {
+        return Err(DeserializationError::InvalidValue(
+            "transaction witness section contains trailing bytes".to_string(),
+        ));
+    }
+
+    Ok(value)
+}
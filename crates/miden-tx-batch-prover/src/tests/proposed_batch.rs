@@ -9,11 +9,11 @@ use miden_objects::{
     account::{Account, AccountId},
     batch::ProposedBatch,
     block::BlockNumber,
-    note::{Note, NoteType},
+    note::{Note, NoteExecutionHint, NoteType},
     testing::{account_id::AccountIdBuilder, note::NoteBuilder},
     transaction::{ChainMmr, InputNote, InputNoteCommitment, OutputNote},
 };
-use miden_tx::testing::{Auth, MockChain};
+use miden_tx::testing::{Auth, MockChain, MockChainStore, WitnessCorruption};
 use rand::{SeedableRng, rngs::SmallRng};
 use vm_core::assert_matches;
 use vm_processor::Digest;
@@ -355,6 +355,41 @@ fn unauthenticated_note_converted_to_authenticated() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Test that a note inclusion proof corrupted by the mock chain does not authenticate the
+/// unauthenticated input note it is provided for.
+#[test]
+fn corrupted_unauthenticated_note_proof() -> anyhow::Result<()> {
+    let TestSetup { mut chain, account1, account2 } = setup_chain();
+    let note0 = chain.add_p2id_note(account2.id(), account1.id(), &[], NoteType::Private, None)?;
+    let block2 = chain.seal_next_block();
+    let block3 = chain.seal_next_block();
+
+    let tx1 =
+        MockProvenTxBuilder::with_account(account1.id(), Digest::default(), account1.commitment())
+            .ref_block_commitment(block3.commitment())
+            .unauthenticated_notes(vec![note0.clone()])
+            .build()?;
+
+    chain.corrupt_witness(WitnessCorruption::MismatchedNoteProof(note0.id()));
+    let (reference_block_header, chain_mmr, note_proofs) = MockChainStore::new(&chain)
+        .get_batch_inputs([tx1.ref_block_num()], [note0.id()])?
+        .into_parts();
+
+    let error =
+        ProposedBatch::new(vec![Arc::new(tx1)], reference_block_header, chain_mmr, note_proofs)
+            .unwrap_err();
+
+    assert_matches!(error, ProposedBatchError::UnauthenticatedNoteAuthenticationFailed {
+        note_id,
+        block_num,
+        source: MerkleError::ConflictingRoots { .. },
+      } if note_id == note0.id() &&
+        block_num == block2.header().block_num()
+    );
+
+    Ok(())
+}
+
 /// Test that an authenticated input note that is also created in the same batch does not error
 /// and instead is marked as consumed.
 /// - This requires a nullifier collision on the input and output note which is very unlikely in
@@ -656,3 +691,44 @@ fn expired_transaction() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Tests that expired unauthenticated input notes cannot be consumed in a batch.
+#[test]
+fn expired_unauthenticated_input_note() -> anyhow::Result<()> {
+    let TestSetup { chain, account1, .. } = setup_chain();
+    let block1 = chain.block_header(1);
+
+    // This note expired at the batch's reference block.
+    let note = NoteBuilder::new(mock_account_id(60), SmallRng::from_seed([60; 32]))
+        .note_execution_hint(NoteExecutionHint::until_block(block1.block_num())?)
+        .build(&TransactionKernel::assembler().with_debug_mode(true))?;
+    let tx1 =
+        MockProvenTxBuilder::with_account(account1.id(), Digest::default(), account1.commitment())
+            .ref_block_commitment(block1.commitment())
+            .expiration_block_num(block1.block_num() + 3)
+            .unauthenticated_notes(vec![note.clone()])
+            .build()?;
+
+    let error = ProposedBatch::new(
+        [tx1.clone()].into_iter().map(Arc::new).collect(),
+        block1.clone(),
+        chain.latest_chain_mmr(),
+        BTreeMap::default(),
+    )
+    .unwrap_err();
+
+    assert_matches!(
+        error,
+        ProposedBatchError::ExpiredInputNote {
+            transaction_id,
+            note_id,
+            note_expiration_block_num,
+            reference_block_num
+        }  if transaction_id == tx1.id() &&
+            note_id == note.id() &&
+            note_expiration_block_num == block1.block_num() &&
+            reference_block_num == block1.block_num()
+    );
+
+    Ok(())
+}
@@ -1,4 +1,10 @@
 mod tx_authenticator;
 pub use tx_authenticator::{BasicAuthenticator, TransactionAuthenticator};
 
+mod signing_request;
+pub use signing_request::SigningRequest;
+
+mod signing_manifest;
+pub use signing_manifest::SigningManifest;
+
 pub mod signatures;
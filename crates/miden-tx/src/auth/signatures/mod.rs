@@ -1,8 +1,12 @@
 use alloc::vec::Vec;
 
 use miden_objects::{
-    Hasher,
-    crypto::dsa::rpo_falcon512::{self, Polynomial},
+    Digest, Hasher,
+    crypto::{
+        bls12_381,
+        dsa::rpo_falcon512::{self, Polynomial},
+        ecdsa_k256,
+    },
 };
 use rand::Rng;
 use vm_processor::{Felt, Word};
@@ -67,3 +71,79 @@ pub fn get_falcon_signature<R: Rng>(
     result.reverse();
     Ok(result)
 }
+
+/// Retrieves a secp256k1 ECDSA signature over a message.
+///
+/// Gets as input an ECDSA secret key and a [Word] representing a message and outputs a vector of
+/// values to be pushed onto the advice stack. The values are the ones required for an ECDSA
+/// signature verification inside the VM and they are, each represented as 8 little-endian u32
+/// limbs:
+///
+/// 1. The x-coordinate of the public key.
+/// 2. The y-coordinate of the public key.
+/// 3. The `r` component of the signature.
+/// 4. The inverse of the `s` component of the signature modulo the order of the secp256k1 group.
+pub fn get_ecdsa_k256_signature(key: &ecdsa_k256::SecretKey, message: Word) -> Vec<Felt> {
+    let sig = key.sign(message);
+
+    let mut result: Vec<Felt> = key.public_key().to_elements().to_vec();
+    result.extend_from_slice(&sig.r());
+    result.extend_from_slice(&sig.s_inv());
+
+    result.reverse();
+    result
+}
+
+/// Retrieves a BLS12-381 signature over a message.
+///
+/// Gets as input a BLS12-381 secret key and a [Word] representing a message and outputs a vector
+/// of values to be pushed onto the advice stack. The values are the ones required for a BLS12-381
+/// signature verification inside the VM and they are, each base field element represented as 12
+/// little-endian u32 limbs:
+///
+/// 1. The public key, i.e., the coefficients `x.c0`, `x.c1`, `y.c0` and `y.c1` of its coordinates.
+/// 2. The values required to hash the message to G1, see
+///    [`hash_to_curve_hint`](bls12_381::hash_to_curve_hint).
+/// 3. The x-coordinate of the signature.
+/// 4. The y-coordinate of the signature.
+pub fn get_bls12_381_signature(key: &bls12_381::SecretKey, message: Word) -> Vec<Felt> {
+    let mut result: Vec<Felt> = key.public_key().to_elements().to_vec();
+    result.extend(bls12_381::hash_to_curve_hint(message));
+    result.extend_from_slice(&key.sign(message).to_elements());
+
+    result.reverse();
+    result
+}
+
+/// Retrieves the two signatures over a message required by the two-factor auth component.
+///
+/// The two-factor auth component verifies a Falcon signature and a secp256k1 ECDSA signature over
+/// the same message. This function generates both signatures and returns them as advice map
+/// entries, keyed by the hash of the respective public key (or public key commitment) and the
+/// message. These entries can be added to the transaction args, e.g. after the message was
+/// obtained from a [`SigningRequest`](crate::auth::SigningRequest):
+///
+/// ```ignore
+/// tx_args.extend_advice_map(get_two_factor_signatures(&falcon_key, &ecdsa_key, message, rng)?);
+/// ```
+///
+/// # Errors
+/// Will return an error if the Falcon signature could not be generated, see
+/// [get_falcon_signature].
+pub fn get_two_factor_signatures<R: Rng>(
+    falcon_key: &rpo_falcon512::SecretKey,
+    ecdsa_key: &ecdsa_k256::SecretKey,
+    message: Word,
+    rng: &mut R,
+) -> Result<[(Digest, Vec<Felt>); 2], AuthenticationError> {
+    let falcon_pub_key: Word = falcon_key.public_key().into();
+    let falcon_signature = get_falcon_signature(falcon_key, message, rng)?;
+
+    let ecdsa_pub_key = ecdsa_key.public_key().commitment();
+    let ecdsa_signature = get_ecdsa_k256_signature(ecdsa_key, message);
+
+    Ok([
+        (Hasher::merge(&[falcon_pub_key.into(), message.into()]), falcon_signature),
+        (Hasher::merge(&[ecdsa_pub_key.into(), message.into()]), ecdsa_signature),
+    ])
+}
@@ -0,0 +1,113 @@
+use alloc::vec::Vec;
+
+use miden_objects::{
+    Digest,
+    account::AccountId,
+    block::BlockNumber,
+    note::NoteId,
+    transaction::TransactionArgs,
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
+
+use super::SigningRequest;
+
+// SIGNING MANIFEST
+// ================================================================================================
+
+/// The signatures required by a transaction, together with the data required to execute the
+/// transaction once the signatures have been produced.
+///
+/// A signing manifest is the result of the first phase of the two-phase signing flow, in which
+/// the transaction is executed with placeholder signatures via
+/// [`TransactionExecutor::prepare_signing`](crate::TransactionExecutor::prepare_signing) to
+/// determine which messages must be signed with which keys. This allows the keys to be held
+/// offline, e.g. in a custody setup: the manifest can be serialized and handed to the signers, and
+/// the produced signatures are then passed to
+/// [`TransactionExecutor::finalize_signing`](crate::TransactionExecutor::finalize_signing) which
+/// executes the transaction with the signatures and returns a provable transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SigningManifest {
+    account_id: AccountId,
+    initial_account_commitment: Digest,
+    block_ref: BlockNumber,
+    notes: Vec<NoteId>,
+    tx_args: TransactionArgs,
+    signing_requests: Vec<SigningRequest>,
+}
+
+impl SigningManifest {
+    /// Returns a new [SigningManifest] instantiated from the provided data.
+    pub(crate) fn new(
+        account_id: AccountId,
+        initial_account_commitment: Digest,
+        block_ref: BlockNumber,
+        notes: Vec<NoteId>,
+        tx_args: TransactionArgs,
+        signing_requests: Vec<SigningRequest>,
+    ) -> Self {
+        Self {
+            account_id,
+            initial_account_commitment,
+            block_ref,
+            notes,
+            tx_args,
+            signing_requests,
+        }
+    }
+
+    /// Returns the ID of the account against which the transaction is executed.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the commitment of the account state against which the signatures were requested.
+    pub fn initial_account_commitment(&self) -> Digest {
+        self.initial_account_commitment
+    }
+
+    /// Returns the number of the reference block of the transaction.
+    pub fn block_ref(&self) -> BlockNumber {
+        self.block_ref
+    }
+
+    /// Returns the IDs of the notes consumed by the transaction, in the order they are consumed.
+    pub fn notes(&self) -> &[NoteId] {
+        &self.notes
+    }
+
+    /// Returns the arguments the transaction is executed with.
+    pub fn tx_args(&self) -> &TransactionArgs {
+        &self.tx_args
+    }
+
+    /// Returns the signatures required by the transaction, in the order they were requested.
+    ///
+    /// This is empty if all signatures could be generated by the authenticator of the executor.
+    pub fn signing_requests(&self) -> &[SigningRequest] {
+        &self.signing_requests
+    }
+}
+
+impl Serializable for SigningManifest {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account_id.write_into(target);
+        self.initial_account_commitment.write_into(target);
+        self.block_ref.write_into(target);
+        self.notes.write_into(target);
+        self.tx_args.write_into(target);
+        self.signing_requests.write_into(target);
+    }
+}
+
+impl Deserializable for SigningManifest {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            account_id: AccountId::read_from(source)?,
+            initial_account_commitment: Digest::read_from(source)?,
+            block_ref: BlockNumber::read_from(source)?,
+            notes: Vec::<NoteId>::read_from(source)?,
+            tx_args: TransactionArgs::read_from(source)?,
+            signing_requests: Vec::<SigningRequest>::read_from(source)?,
+        })
+    }
+}
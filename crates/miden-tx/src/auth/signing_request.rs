@@ -0,0 +1,76 @@
+use miden_objects::{
+    Digest, Hasher, Word,
+    account::AccountDelta,
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
+
+// SIGNING REQUEST
+// ================================================================================================
+
+/// A request for a signature required by a transaction which could not be generated during
+/// execution, e.g. because the signing key is held by another party or on an air-gapped device.
+///
+/// Signing requests are returned via
+/// [`TransactionExecutorError::MissingSignatures`](crate::TransactionExecutorError::MissingSignatures).
+/// Once the signature has been produced externally, it is provided to the next execution of the
+/// transaction by adding it to the advice map of the transaction args under
+/// [`Self::signature_key`]:
+///
+/// ```ignore
+/// tx_args.extend_advice_map([(request.signature_key(), signature)]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SigningRequest {
+    pub_key: Word,
+    message: Word,
+    account_delta: AccountDelta,
+}
+
+impl SigningRequest {
+    /// Returns a new [SigningRequest] for a signature over `message` against `pub_key`.
+    pub fn new(pub_key: Word, message: Word, account_delta: AccountDelta) -> Self {
+        Self { pub_key, message, account_delta }
+    }
+
+    /// Returns the public key against which the signature must be generated.
+    pub fn pub_key(&self) -> Word {
+        self.pub_key
+    }
+
+    /// Returns the message to sign, usually a commitment to the transaction data.
+    pub fn message(&self) -> Word {
+        self.message
+    }
+
+    /// Returns the changes made to the account up to the point at which the signature was
+    /// requested.
+    ///
+    /// This is informational and allows the signer to review the transaction before signing. It
+    /// is not part of the signed message.
+    pub fn account_delta(&self) -> &AccountDelta {
+        &self.account_delta
+    }
+
+    /// Returns the key under which the signature must be provided in the advice map.
+    pub fn signature_key(&self) -> Digest {
+        Hasher::merge(&[self.pub_key.into(), self.message.into()])
+    }
+}
+
+impl Serializable for SigningRequest {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.pub_key.write_into(target);
+        self.message.write_into(target);
+        self.account_delta.write_into(target);
+    }
+}
+
+impl Deserializable for SigningRequest {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            pub_key: Word::read_from(source)?,
+            message: Word::read_from(source)?,
+            account_delta: AccountDelta::read_from(source)?,
+        })
+    }
+}
@@ -5,7 +5,7 @@ use miden_objects::account::{AccountDelta, AuthSecretKey};
 use rand::Rng;
 use vm_processor::{Digest, Felt, Word};
 
-use super::signatures::get_falcon_signature;
+use super::signatures::{get_bls12_381_signature, get_ecdsa_k256_signature, get_falcon_signature};
 use crate::errors::AuthenticationError;
 
 // TRANSACTION AUTHENTICATOR
@@ -69,6 +69,11 @@ impl<R: Rng> BasicAuthenticator<R> {
             rng: Arc::new(RwLock::new(rng)),
         }
     }
+
+    /// Returns the pub_key |-> secret_key mapping of the keys managed by this authenticator.
+    pub fn keys(&self) -> &BTreeMap<Digest, AuthSecretKey> {
+        &self.keys
+    }
 }
 
 impl<R: Rng> TransactionAuthenticator for BasicAuthenticator<R> {
@@ -77,6 +82,8 @@ impl<R: Rng> TransactionAuthenticator for BasicAuthenticator<R> {
     ///
     /// Supported signature schemes:
     /// - RpoFalcon512
+    /// - EcdsaK256
+    /// - Bls12381
     ///
     /// # Errors
     /// If the public key is not contained in the `keys` map,
@@ -95,6 +102,10 @@ impl<R: Rng> TransactionAuthenticator for BasicAuthenticator<R> {
                 AuthSecretKey::RpoFalcon512(falcon_key) => {
                     get_falcon_signature(falcon_key, message, &mut *rng)
                 },
+                AuthSecretKey::EcdsaK256(ecdsa_key) => {
+                    Ok(get_ecdsa_k256_signature(ecdsa_key, message))
+                },
+                AuthSecretKey::Bls12381(bls_key) => Ok(get_bls12_381_signature(bls_key, message)),
             },
             None => Err(AuthenticationError::UnknownPublicKey(format!(
                 "public key {} is not contained in the authenticator's keys",
@@ -134,6 +145,9 @@ mod test {
 
         match deserialized {
             AuthSecretKey::RpoFalcon512(key) => assert_eq!(secret_key.to_bytes(), key.to_bytes()),
+            AuthSecretKey::EcdsaK256(_) | AuthSecretKey::Bls12381(_) => {
+                panic!("expected an RpoFalcon512 key")
+            },
         }
     }
 }
@@ -1,14 +1,26 @@
-use alloc::{boxed::Box, string::String};
+use alloc::{boxed::Box, string::String, vec::Vec};
 use core::error::Error;
 
+use miden_lib::errors::tx_kernel_errors::{
+    ERR_ACCOUNT_IS_NOT_NATIVE, ERR_ACCOUNT_NONCE_DID_NOT_INCREASE_AFTER_STATE_CHANGE,
+    ERR_ACCOUNT_NONCE_INCREASE_MUST_BE_U32, ERR_ACCOUNT_PROC_NOT_PART_OF_ACCOUNT_CODE,
+    ERR_FAUCET_NON_FUNGIBLE_ASSET_TO_BURN_NOT_FOUND,
+    ERR_PROLOGUE_EXISTING_ACCOUNT_MUST_HAVE_NON_ZERO_NONCE,
+    ERR_PROLOGUE_NEW_ACCOUNT_NONCE_MUST_BE_ZERO,
+    ERR_VAULT_FUNGIBLE_ASSET_AMOUNT_LESS_THAN_AMOUNT_TO_WITHDRAW,
+    ERR_VAULT_NON_FUNGIBLE_ASSET_TO_REMOVE_NOT_FOUND, TX_KERNEL_ERRORS,
+};
 use miden_objects::{
-    AccountError, Felt, ProvenTransactionError, TransactionInputError, TransactionOutputError,
-    account::AccountId, block::BlockNumber, note::NoteId,
+    AccountError, Digest, Felt, ProvenTransactionError, TransactionInputError,
+    TransactionOutputError, account::AccountId, block::BlockNumber, note::NoteId,
 };
+use miden_prover::HashFunction;
 use miden_verifier::VerificationError;
 use thiserror::Error;
 use vm_processor::ExecutionError;
 
+use crate::{ProvingProfile, TransactionStage, auth::SigningRequest};
+
 // TRANSACTION EXECUTOR ERROR
 // ================================================================================================
 
@@ -16,6 +28,27 @@ use vm_processor::ExecutionError;
 pub enum TransactionExecutorError {
     #[error("failed to execute transaction kernel program")]
     TransactionProgramExecutionFailed(#[source] ExecutionError),
+    /// An assertion of the transaction kernel failed during the specified stage of the
+    /// transaction. The `stage` is `None` if it is unknown, i.e. if tracing is disabled in the
+    /// executor or the assertion failed outside of the tracked stages.
+    #[error("transaction kernel assertion failed: {error}")]
+    TransactionKernelAssertionFailed {
+        error: KernelAssertionError,
+        stage: Option<TransactionStage>,
+        #[source]
+        source: ExecutionError,
+    },
+    /// The transaction requires signatures which could not be generated by the authenticator of
+    /// the executor. The signatures must be produced externally and provided via the advice map of
+    /// the transaction args.
+    #[error("{} signature(s) required by the transaction could not be generated", .0.len())]
+    MissingSignatures(Vec<SigningRequest>),
+    #[error("transaction execution exceeded the maximum of {0} cycles")]
+    CycleLimitExceeded(u32),
+    #[error("advice inputs of size {size} exceed the maximum advice inputs size of {max_size}")]
+    AdviceInputsSizeExceeded { size: usize, max_size: usize },
+    #[error("max cycles {0} is not within the supported range of transaction execution cycles")]
+    InvalidMaxCycles(u32),
     #[error("failed to fetch transaction inputs from the data store")]
     FetchTransactionInputsFailed(#[source] DataStoreError),
     #[error("input account ID {input_id} does not match output account ID {output_id}")]
@@ -32,6 +65,121 @@ pub enum TransactionExecutorError {
     TransactionOutputConstructionFailed(#[source] TransactionOutputError),
     #[error("failed to create transaction host")]
     TransactionHostCreationFailed(#[source] TransactionHostError),
+    #[error(
+        "advice map entry with key {0} of the transaction args collides with an entry reserved by the transaction kernel"
+    )]
+    AdviceMapKeyCollision(Digest),
+    #[error(
+        "account has {num_slots} storage slots which exceeds the limit of {max} of the transaction kernel"
+    )]
+    StorageSlotsExceedKernelLimit { num_slots: usize, max: usize },
+    #[error(
+        "input note {note_id} has {num_assets} assets which exceeds the limit of {max} of the transaction kernel"
+    )]
+    NoteAssetsExceedKernelLimit {
+        note_id: NoteId,
+        num_assets: usize,
+        max: usize,
+    },
+    #[error(
+        "cannot rebase transaction executed against block {original} onto block {requested} which is not newer"
+    )]
+    InvalidRebaseBlock {
+        original: BlockNumber,
+        requested: BlockNumber,
+    },
+    #[error(
+        "cannot rebase transaction because the initial account commitment changed from {expected} to {actual}"
+    )]
+    RebaseAccountStateChanged { expected: Digest, actual: Digest },
+    #[error(
+        "cannot finalize signing because the initial account commitment changed from {expected} to {actual}"
+    )]
+    SigningAccountStateChanged { expected: Digest, actual: Digest },
+}
+
+impl TransactionExecutorError {
+    /// Returns a [`TransactionExecutorError::TransactionKernelAssertionFailed`] error if the
+    /// provided execution error is a failed assertion of the transaction kernel, a
+    /// [`TransactionExecutorError::CycleLimitExceeded`] error if the execution exceeded the cycle
+    /// limit, and a [`TransactionExecutorError::TransactionProgramExecutionFailed`] error
+    /// otherwise.
+    pub(crate) fn from_execution_error(
+        source: ExecutionError,
+        stage: Option<TransactionStage>,
+    ) -> Self {
+        let kernel_error = match &source {
+            ExecutionError::FailedAssertion { err_code, .. } => {
+                KernelAssertionError::from_err_code(*err_code)
+            },
+            ExecutionError::CycleLimitExceeded(max_cycles) => {
+                return Self::CycleLimitExceeded(*max_cycles);
+            },
+            _ => None,
+        };
+
+        match kernel_error {
+            Some(error) => Self::TransactionKernelAssertionFailed { error, stage, source },
+            None => Self::TransactionProgramExecutionFailed(source),
+        }
+    }
+
+    /// Returns the execution error which caused the transaction kernel program to fail, if any.
+    pub fn execution_error(&self) -> Option<&ExecutionError> {
+        match self {
+            Self::TransactionProgramExecutionFailed(err)
+            | Self::TransactionKernelAssertionFailed { source: err, .. } => Some(err),
+            _ => None,
+        }
+    }
+}
+
+// KERNEL ASSERTION ERROR
+// ================================================================================================
+
+/// A failed assertion of the transaction kernel, mapped from the error code of the assertion.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum KernelAssertionError {
+    #[error("the account vault holds less of the fungible asset than the amount to remove")]
+    InsufficientBalance,
+    #[error("the invoked procedure is not authorized to be called on the account")]
+    UnauthorizedProcedure,
+    #[error("the non-fungible asset to remove is not in the account vault")]
+    AssetNotFound,
+    #[error("the account nonce was not incremented after a state changing transaction")]
+    NonceNotIncremented,
+    #[error("the account nonce is invalid")]
+    InvalidNonce,
+    #[error("{message} (error code {err_code:#x})")]
+    Other { err_code: u32, message: &'static str },
+}
+
+impl KernelAssertionError {
+    /// Returns the [KernelAssertionError] for the provided assertion error code, or `None` if the
+    /// error code is not a transaction kernel error code.
+    pub fn from_err_code(err_code: u32) -> Option<Self> {
+        let error = match err_code {
+            ERR_VAULT_FUNGIBLE_ASSET_AMOUNT_LESS_THAN_AMOUNT_TO_WITHDRAW => {
+                Self::InsufficientBalance
+            },
+            ERR_ACCOUNT_PROC_NOT_PART_OF_ACCOUNT_CODE | ERR_ACCOUNT_IS_NOT_NATIVE => {
+                Self::UnauthorizedProcedure
+            },
+            ERR_VAULT_NON_FUNGIBLE_ASSET_TO_REMOVE_NOT_FOUND
+            | ERR_FAUCET_NON_FUNGIBLE_ASSET_TO_BURN_NOT_FOUND => Self::AssetNotFound,
+            ERR_ACCOUNT_NONCE_DID_NOT_INCREASE_AFTER_STATE_CHANGE => Self::NonceNotIncremented,
+            ERR_ACCOUNT_NONCE_INCREASE_MUST_BE_U32
+            | ERR_PROLOGUE_EXISTING_ACCOUNT_MUST_HAVE_NON_ZERO_NONCE
+            | ERR_PROLOGUE_NEW_ACCOUNT_NONCE_MUST_BE_ZERO => Self::InvalidNonce,
+            _ => {
+                let (err_code, message) =
+                    TX_KERNEL_ERRORS.iter().find(|(code, _)| *code == err_code)?;
+                Self::Other { err_code: *err_code, message }
+            },
+        };
+
+        Some(error)
+    }
 }
 
 // TRANSACTION PROVER ERROR
@@ -80,6 +228,25 @@ impl TransactionProverError {
     }
 }
 
+// PROVING OPTIONS ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum ProvingOptionsError {
+    #[error("proof security level {0} is not supported")]
+    UnsupportedSecurityLevel(u32),
+    #[error("hash function {hash_function:?} cannot be used for {profile:?} proofs")]
+    IncompatibleHashFunction {
+        profile: ProvingProfile,
+        hash_function: HashFunction,
+    },
+    #[error("hash function {hash_function:?} does not provide {security_level} bits of security")]
+    InsufficientHashFunctionSecurity {
+        hash_function: HashFunction,
+        security_level: u32,
+    },
+}
+
 // TRANSACTION VERIFIER ERROR
 // ================================================================================================
 
@@ -100,6 +267,39 @@ pub enum TransactionHostError {
     AccountProcedureIndexMapError(String),
     #[error("failed to create account procedure info")]
     AccountProcedureInfoCreationFailed(#[source] AccountError),
+    #[error("event ID {0} is reserved for transaction kernel events")]
+    ReservedEventId(u32),
+}
+
+// FOREIGN ACCOUNT INPUTS ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum ForeignAccountInputsError {
+    #[error("failed to fetch the inputs of foreign account {account_id}")]
+    FetchFailed {
+        account_id: AccountId,
+        #[source]
+        source: DataStoreError,
+    },
+    #[error(
+        "requested the inputs of foreign account {expected} but received the inputs of {actual}"
+    )]
+    AccountIdMismatch { expected: AccountId, actual: AccountId },
+    #[error("code of foreign account {0} does not match the code commitment of its header")]
+    CodeCommitmentMismatch(AccountId),
+    #[error(
+        "storage header of foreign account {0} does not match the storage commitment of its header"
+    )]
+    StorageCommitmentMismatch(AccountId),
+    #[error(
+        "storage map with root {map_root} is not part of the storage of foreign account {account_id}"
+    )]
+    UnknownStorageMap { account_id: AccountId, map_root: Digest },
+    #[error("foreign account {0} is not part of the account tree of the reference block")]
+    InvalidAccountProof(AccountId),
+    #[error("transaction references {0} foreign accounts which exceeds the maximum of 63")]
+    TooManyForeignAccounts(usize),
 }
 
 // DATA STORE ERROR
@@ -0,0 +1,180 @@
+use core::time::Duration;
+
+use miden_objects::{
+    assembly::mast::{MastForest, MastNode},
+    transaction::{TransactionArgs, TransactionInputs},
+};
+use vm_processor::{NUM_RAND_ROWS, TraceLenSummary};
+
+// TRANSACTION COST
+// ================================================================================================
+
+/// The cost of proving a transaction, expressed in terms of the execution trace it requires.
+///
+/// The cost of a transaction can either be predicted ahead of execution via
+/// [TransactionCostModel::estimate], or measured exactly after simulating the transaction via
+/// [`TransactionSimulation::cost`](super::TransactionSimulation::cost).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransactionCost {
+    /// The number of rows of the longest trace component, i.e. before padding.
+    trace_len: usize,
+}
+
+impl TransactionCost {
+    /// Returns a new [TransactionCost] for a transaction whose longest trace component has the
+    /// specified number of rows.
+    pub fn new(trace_len: usize) -> Self {
+        Self { trace_len }
+    }
+
+    /// Returns a new [TransactionCost] instantiated from the lengths of the trace components of an
+    /// executed transaction.
+    pub fn from_trace_len_summary(trace_len_summary: &TraceLenSummary) -> Self {
+        Self::new(trace_len_summary.trace_len())
+    }
+
+    /// Returns the number of rows of the longest trace component, i.e. before padding.
+    pub fn trace_len(&self) -> usize {
+        self.trace_len
+    }
+
+    /// Returns the length of the trace which has to be proven, i.e. the number of rows of the
+    /// longest trace component plus the random rows, rounded up to the next power of two.
+    ///
+    /// Proving time grows with the padded trace length, so two transactions with the same padded
+    /// trace length take roughly the same time to prove.
+    pub fn padded_trace_len(&self) -> usize {
+        (self.trace_len + NUM_RAND_ROWS).next_power_of_two()
+    }
+}
+
+// TRANSACTION COST MODEL
+// ================================================================================================
+
+/// A linear model predicting the cost of a transaction from its inputs.
+///
+/// The number of cycles spent executing a transaction is estimated as the sum of:
+/// - a fixed number of cycles for the prologue and the epilogue.
+/// - a number of cycles per input note, covering the authentication of the note and the processing
+///   of its assets and inputs.
+/// - a number of cycles per operation of each note script and of the transaction script.
+/// - a number of cycles per storage touch, i.e. per storage slot or map entry modified by the
+///   transaction, covering the update of the storage commitment.
+/// - a number of cycles per signature verified by the account, e.g. in its authentication
+///   procedure. Account procedures are not part of the scripts of the transaction, so their cost is
+///   not covered by the script operations.
+///
+/// The estimate is a heuristic: scripts containing loops or conditional branches may execute
+/// considerably more or fewer operations than they contain. The exact cost of a transaction can be
+/// measured by simulating it via
+/// [`TransactionExecutor::simulate`](super::TransactionExecutor::simulate).
+///
+/// The default parameters are calibrated against the current transaction kernel. The default
+/// proving rate is a rough figure for a multi-threaded prover on commodity hardware and should be
+/// adjusted to the hardware of the actual prover.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionCostModel {
+    /// The number of cycles spent by a transaction regardless of its inputs.
+    pub base_cycles: usize,
+    /// The number of cycles spent per input note.
+    pub cycles_per_input_note: usize,
+    /// The number of cycles spent per operation of the note scripts and the transaction script.
+    pub cycles_per_script_operation: usize,
+    /// The number of cycles spent per storage slot or map entry modified by the transaction.
+    pub cycles_per_storage_touch: usize,
+    /// The number of cycles spent per RPO Falcon512 signature verified by the account.
+    pub cycles_per_signature: usize,
+    /// The number of padded trace rows proven per second.
+    pub proving_rows_per_second: usize,
+}
+
+impl TransactionCostModel {
+    /// Returns the estimated cost of executing a transaction with the provided inputs and
+    /// arguments which modifies the specified number of storage slots or map entries and verifies
+    /// the specified number of signatures.
+    pub fn estimate(
+        &self,
+        tx_inputs: &TransactionInputs,
+        tx_args: &TransactionArgs,
+        num_storage_touches: usize,
+        num_signatures: usize,
+    ) -> TransactionCost {
+        let input_notes = tx_inputs.input_notes();
+
+        let script_operations: usize = input_notes
+            .iter()
+            .map(|note| num_operations(&note.note().script().mast()))
+            .chain(tx_args.tx_script().map(|script| num_operations(&script.mast())))
+            .sum();
+
+        let cycles = self.base_cycles
+            + input_notes.num_notes() * self.cycles_per_input_note
+            + script_operations * self.cycles_per_script_operation
+            + num_storage_touches * self.cycles_per_storage_touch
+            + num_signatures * self.cycles_per_signature;
+
+        TransactionCost::new(cycles)
+    }
+
+    /// Returns the estimated time it takes to prove a transaction with the provided cost.
+    pub fn proving_time(&self, cost: &TransactionCost) -> Duration {
+        let rate = self.proving_rows_per_second.max(1);
+
+        Duration::from_secs_f64(cost.padded_trace_len() as f64 / rate as f64)
+    }
+}
+
+impl Default for TransactionCostModel {
+    fn default() -> Self {
+        Self {
+            base_cycles: 2_650,
+            cycles_per_input_note: 2_170,
+            cycles_per_script_operation: 4,
+            cycles_per_storage_touch: 1_340,
+            cycles_per_signature: 60_200,
+            proving_rows_per_second: 32_768,
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the number of operations contained in the basic blocks of the provided MAST forest.
+fn num_operations(mast: &MastForest) -> usize {
+    mast.nodes()
+        .iter()
+        .map(|node| match node {
+            MastNode::Block(basic_block) => basic_block.num_operations() as usize,
+            _ => 0,
+        })
+        .sum()
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testing::MockChain, tests::fixtures::TransactionFixture};
+
+    #[test]
+    fn estimated_transaction_cost_matches_simulated_cost() {
+        let fixture = TransactionFixture::with_p2id_notes(&mut MockChain::new(), 3);
+        let cost = fixture
+            .executor()
+            .simulate(fixture.account_id, fixture.block_ref, &fixture.note_ids, fixture.tx_args())
+            .unwrap()
+            .cost();
+
+        // the transaction consumes three P2ID notes and verifies the signature of the account
+        let cost_model = TransactionCostModel::default();
+        let tx_context = &fixture.tx_context;
+        let estimate = cost_model.estimate(tx_context.tx_inputs(), tx_context.tx_args(), 0, 1);
+
+        assert_eq!(estimate.padded_trace_len(), cost.padded_trace_len());
+        assert!(estimate.trace_len().abs_diff(cost.trace_len()) < cost.trace_len() / 20);
+        assert_eq!(cost_model.proving_time(&estimate), cost_model.proving_time(&cost));
+    }
+}
@@ -13,6 +13,10 @@ use crate::DataStoreError;
 
 /// The [DataStore] trait defines the interface that transaction objects use to fetch data
 /// required for transaction execution.
+///
+/// When the `async` feature is enabled, the methods of this trait are `async`, so that
+/// implementations can fetch the data over the network without blocking. The execution methods of
+/// the [TransactionExecutor](crate::TransactionExecutor) become `async` accordingly.
 #[maybe_async_trait]
 pub trait DataStore {
     /// Returns account, chain, and input note data required to execute a transaction against
@@ -22,6 +26,10 @@ pub trait DataStore {
     /// recorded in the chain. In general, it is recommended that bock_ref corresponds to the
     /// latest block available in the data store.
     ///
+    /// The chain MMR of the returned inputs must track the blocks in which the authenticated input
+    /// notes were created, as well as any past blocks whose headers are read by the transaction via
+    /// `miden::tx::get_historical_block_header`.
+    ///
     /// # Errors
     /// Returns an error if:
     /// - The account with the specified ID could not be found in the data store.
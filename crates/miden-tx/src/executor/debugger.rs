@@ -0,0 +1,275 @@
+use alloc::{string::String, vec::Vec};
+
+use miden_lib::transaction::{KernelProcedure, TransactionKernel, VersionedTransactionKernel};
+use miden_objects::{Digest, Felt, ZERO};
+use vm_processor::{ContextId, ExecutionError, Operation, VmState};
+
+// BREAKPOINT
+// ================================================================================================
+
+/// A location at which the execution of a transaction is paused in
+/// [`TransactionExecutor::debug_transaction`](super::TransactionExecutor::debug_transaction).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Breakpoint {
+    /// Pauses right before the kernel procedure with the specified root is executed, i.e., when
+    /// the procedure is invoked via `exec_kernel_proc`.
+    KernelProcedure(Digest),
+    /// Pauses right before the MASM instruction which spans the specified byte offset of the
+    /// source file with the specified path is executed, e.g. an instruction of a transaction or
+    /// note script.
+    ///
+    /// Source locations are only known for code compiled in debug mode. The path of a source file
+    /// is assigned by the source manager of the assembler, e.g. `#exec` for a program compiled
+    /// from a string.
+    SourceLocation { path: String, offset: u32 },
+}
+
+impl Breakpoint {
+    /// Returns a breakpoint on the kernel procedure with the specified name, e.g.
+    /// `account_get_item`, or `None` if the latest kernel has no procedure with this name.
+    ///
+    /// The names of the kernel procedures are listed in [`TransactionKernel::PROCEDURE_NAMES`].
+    pub fn kernel_procedure(name: &str) -> Option<Self> {
+        Self::versioned_kernel_procedure(TransactionKernel::latest(), name)
+    }
+
+    /// Returns a breakpoint on the kernel procedure with the specified name in the provided
+    /// transaction kernel, or `None` if the kernel has no procedure with this name.
+    pub fn versioned_kernel_procedure(
+        kernel: VersionedTransactionKernel,
+        name: &str,
+    ) -> Option<Self> {
+        kernel
+            .procedure_names()
+            .iter()
+            .position(|&proc_name| proc_name == name)
+            .map(|index| Self::KernelProcedure(kernel.procedures()[index]))
+    }
+}
+
+impl From<KernelProcedure> for Breakpoint {
+    fn from(procedure: KernelProcedure) -> Self {
+        Self::KernelProcedure(procedure.digest())
+    }
+}
+
+// BREAKPOINT HIT
+// ================================================================================================
+
+/// A snapshot of the VM state taken when the execution of a transaction reached a [Breakpoint].
+#[derive(Debug, Clone)]
+pub struct BreakpointHit {
+    breakpoint: Breakpoint,
+    cycle: usize,
+    ctx: ContextId,
+    stack: Vec<Felt>,
+    memory: Vec<(u64, Felt)>,
+}
+
+impl BreakpointHit {
+    /// Returns the breakpoint which was hit.
+    pub fn breakpoint(&self) -> &Breakpoint {
+        &self.breakpoint
+    }
+
+    /// Returns the clock cycle at which the breakpoint was hit.
+    pub fn cycle(&self) -> usize {
+        self.cycle
+    }
+
+    /// Returns the execution context in which the breakpoint was hit.
+    pub fn ctx(&self) -> ContextId {
+        self.ctx
+    }
+
+    /// Returns the operand stack at the breakpoint, with the top of the stack at index 0.
+    pub fn stack(&self) -> &[Felt] {
+        &self.stack
+    }
+
+    /// Returns the non-empty memory of the current execution context at the breakpoint, as
+    /// `(address, value)` pairs sorted by address.
+    pub fn memory(&self) -> &[(u64, Felt)] {
+        &self.memory
+    }
+}
+
+// TRANSACTION DEBUG REPORT
+// ================================================================================================
+
+/// The result of executing a transaction in debug mode via
+/// [`TransactionExecutor::debug_transaction`](super::TransactionExecutor::debug_transaction).
+///
+/// The report contains a snapshot of the VM state for every breakpoint hit, in the order of
+/// execution. If the execution failed, the report also contains the error, and the snapshots cover
+/// the execution up to the point of failure.
+#[derive(Debug)]
+pub struct TransactionDebugReport {
+    hits: Vec<BreakpointHit>,
+    execution_error: Option<ExecutionError>,
+}
+
+impl TransactionDebugReport {
+    /// Returns the breakpoint hits in the order of execution.
+    pub fn hits(&self) -> &[BreakpointHit] {
+        &self.hits
+    }
+
+    /// Returns the error the execution failed with, if any.
+    pub fn execution_error(&self) -> Option<&ExecutionError> {
+        self.execution_error.as_ref()
+    }
+
+    /// Returns `true` if the transaction was executed successfully.
+    pub fn is_success(&self) -> bool {
+        self.execution_error.is_none()
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Collects the breakpoint hits from the provided sequence of VM states.
+pub(super) fn collect_breakpoint_hits(
+    states: impl Iterator<Item = Result<VmState, ExecutionError>>,
+    breakpoints: &[Breakpoint],
+) -> TransactionDebugReport {
+    let mut hits = Vec::new();
+    let mut execution_error = None;
+    let mut prev_state: Option<VmState> = None;
+
+    for state in states {
+        let state = match state {
+            Ok(state) => state,
+            Err(err) => {
+                execution_error = Some(err);
+                break;
+            },
+        };
+
+        if let Some(prev_state) = prev_state.as_ref() {
+            for breakpoint in breakpoints {
+                if is_breakpoint_hit(breakpoint, prev_state, &state) {
+                    // take the snapshot right before the operation which hit the breakpoint
+                    hits.push(BreakpointHit {
+                        breakpoint: breakpoint.clone(),
+                        cycle: prev_state.clk.into(),
+                        ctx: prev_state.ctx,
+                        stack: prev_state.stack.clone(),
+                        memory: prev_state.memory.clone(),
+                    });
+                }
+            }
+        }
+
+        prev_state = Some(state);
+    }
+
+    TransactionDebugReport { hits, execution_error }
+}
+
+/// Returns `true` if the transition from `prev_state` to `state` hits the provided breakpoint.
+fn is_breakpoint_hit(breakpoint: &Breakpoint, prev_state: &VmState, state: &VmState) -> bool {
+    match breakpoint {
+        Breakpoint::KernelProcedure(root) => {
+            // `exec_kernel_proc` invokes kernel procedures via `dynexec` with the memory pointer
+            // to the procedure root on top of the stack
+            state.op == Some(Operation::Dyn) && dyn_target(prev_state) == Some(*root)
+        },
+        Breakpoint::SourceLocation { path, offset } => state.asmop.as_ref().is_some_and(|asmop| {
+            let location = asmop.as_ref().location();
+            asmop.cycle_idx() == 1
+                && location.is_some_and(|location| {
+                    *location.path == **path
+                        && location.start.to_u32() <= *offset
+                        && *offset < location.end.to_u32()
+                })
+        }),
+    }
+}
+
+/// Returns the root of the procedure stored at the memory pointer on top of the stack.
+fn dyn_target(state: &VmState) -> Option<Digest> {
+    let ptr = state.stack.first()?.as_int();
+    let mut root = [ZERO; 4];
+    for (offset, element) in root.iter_mut().enumerate() {
+        let addr = ptr + offset as u64;
+        *element = state.memory.iter().find(|(mem_addr, _)| *mem_addr == addr)?.1;
+    }
+
+    Some(Digest::from(root))
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_objects::transaction::{TransactionArgs, TransactionScript};
+    use vm_processor::ONE;
+
+    use super::*;
+    use crate::{testing::TransactionContextBuilder, tests::fixtures::TransactionFixture};
+
+    #[test]
+    fn debug_transaction_reports_breakpoint_hits() {
+        let fixture =
+            TransactionFixture::new(TransactionContextBuilder::with_standard_account(ONE).build());
+
+        let tx_script_src = "
+            use.miden::account
+
+            begin
+                push.1 drop
+                exec.account::get_id drop drop
+            end
+        ";
+        let tx_script = TransactionScript::compile(
+            tx_script_src,
+            [],
+            TransactionKernel::testing_assembler().with_debug_mode(true),
+        )
+        .unwrap();
+        let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+        let get_id = Breakpoint::kernel_procedure("account_get_id").unwrap();
+        assert!(Breakpoint::kernel_procedure("not_a_kernel_procedure").is_none());
+        let source_location = Breakpoint::SourceLocation {
+            path: "#exec".into(),
+            offset: tx_script_src.find("push.1").unwrap() as u32,
+        };
+
+        let report = fixture
+            .executor()
+            .debug_transaction(
+                fixture.account_id,
+                fixture.block_ref,
+                &fixture.note_ids,
+                tx_args,
+                &[get_id.clone(), source_location.clone()],
+            )
+            .unwrap();
+        assert!(
+            report.is_success(),
+            "transaction execution failed: {:?}",
+            report.execution_error()
+        );
+
+        // the instruction at the source location is executed exactly once, followed by the call
+        // of the kernel procedure made by the script
+        let source_hits: Vec<_> = report
+            .hits()
+            .iter()
+            .filter(|hit| hit.breakpoint() == &source_location)
+            .collect();
+        assert_eq!(source_hits.len(), 1);
+
+        let get_id_hit = report
+            .hits()
+            .iter()
+            .find(|hit| hit.breakpoint() == &get_id && hit.cycle() > source_hits[0].cycle())
+            .expect("kernel procedure breakpoint should be hit after the source location");
+        assert_eq!(get_id_hit.ctx(), ContextId::root());
+        assert!(!get_id_hit.memory().is_empty());
+    }
+}
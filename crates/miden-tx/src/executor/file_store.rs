@@ -0,0 +1,355 @@
+#[cfg(feature = "async")]
+use alloc::boxed::Box;
+use alloc::{collections::BTreeSet, string::ToString, vec::Vec};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use miden_objects::{
+    Word,
+    account::{Account, AccountId},
+    block::{BlockHeader, BlockNumber},
+    crypto::merkle::{Mmr, PartialMmr},
+    note::NoteId,
+    transaction::{ChainMmr, InputNote, InputNotes, TransactionInputs},
+    utils::serde::{Deserializable, Serializable},
+};
+use vm_processor::utils::SliceReader;
+use winter_maybe_async::*;
+
+use super::DataStore;
+use crate::DataStoreError;
+
+// CONSTANTS
+// ================================================================================================
+
+const ACCOUNTS_DIR: &str = "accounts";
+const BLOCKS_DIR: &str = "blocks";
+const NOTES_DIR: &str = "notes";
+const CONSUMED_NOTES_DIR: &str = "consumed_notes";
+
+// FILE DATA STORE
+// ================================================================================================
+
+/// A [DataStore] which persists accounts, block headers and notes in a directory on disk.
+///
+/// Every entry is stored in its own file using a simple key-value layout:
+///
+/// ```text
+/// <root>/accounts/<account ID>           the account and its seed, if any
+/// <root>/blocks/<block number>           the block header
+/// <root>/notes/<note ID>                 the input note, optionally with its inclusion proof
+/// <root>/consumed_notes/<note ID>        an empty marker for a consumed note
+/// ```
+///
+/// Files are written atomically by first writing a temporary file and then renaming it. The
+/// chain MMR for a transaction is computed from the stored block headers, so the headers of all
+/// blocks up to the reference block of the transaction must be stored.
+///
+/// When the `async` feature is enabled, the store still performs blocking file system I/O.
+#[derive(Debug, Clone)]
+pub struct FileDataStore {
+    root: PathBuf,
+}
+
+impl FileDataStore {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Opens the store in the provided directory, creating the directory and its layout if they do
+    /// not exist yet.
+    ///
+    /// # Errors
+    /// Returns an error if the directories of the store cannot be created.
+    pub fn open(root: impl Into<PathBuf>) -> Result<Self, DataStoreError> {
+        let root = root.into();
+        for dir in [ACCOUNTS_DIR, BLOCKS_DIR, NOTES_DIR, CONSUMED_NOTES_DIR] {
+            fs::create_dir_all(root.join(dir)).map_err(|err| {
+                DataStoreError::other_with_source("failed to create data store directory", err)
+            })?;
+        }
+
+        Ok(Self { root })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the directory in which the store persists its data.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Returns the account with the specified ID together with its seed, if any.
+    ///
+    /// # Errors
+    /// Returns an error if the account is not stored or cannot be read.
+    pub fn get_account(
+        &self,
+        account_id: AccountId,
+    ) -> Result<(Account, Option<Word>), DataStoreError> {
+        let bytes = self
+            .read_file(&self.account_path(account_id))?
+            .ok_or(DataStoreError::AccountNotFound(account_id))?;
+
+        let mut reader = SliceReader::new(&bytes);
+        Account::read_from(&mut reader)
+            .and_then(|account| Ok((account, Option::<Word>::read_from(&mut reader)?)))
+            .map_err(|err| {
+                DataStoreError::other_with_source("failed to deserialize stored account", err)
+            })
+    }
+
+    /// Returns the header of the block with the specified number.
+    ///
+    /// # Errors
+    /// Returns an error if the block header is not stored or cannot be read.
+    pub fn get_block_header(&self, block_num: BlockNumber) -> Result<BlockHeader, DataStoreError> {
+        self.read_entry(&self.block_path(block_num))?
+            .ok_or(DataStoreError::BlockNotFound(block_num))
+    }
+
+    /// Returns the note with the specified ID.
+    ///
+    /// # Errors
+    /// Returns an error if the note is not stored, has been consumed, or cannot be read.
+    pub fn get_note(&self, note_id: NoteId) -> Result<InputNote, DataStoreError> {
+        if self.consumed_note_path(note_id).exists() {
+            return Err(DataStoreError::NoteAlreadyConsumed(note_id));
+        }
+
+        self.read_entry(&self.note_path(note_id))?
+            .ok_or(DataStoreError::NoteNotFound(note_id))
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Stores the provided account and its seed, replacing the previously stored state of the
+    /// account, if any.
+    ///
+    /// The seed must be provided for new accounts.
+    pub fn insert_account(
+        &self,
+        account: &Account,
+        account_seed: Option<Word>,
+    ) -> Result<(), DataStoreError> {
+        let mut bytes = account.to_bytes();
+        account_seed.write_into(&mut bytes);
+        self.write_file(&self.account_path(account.id()), &bytes)
+    }
+
+    /// Stores the provided block header, replacing the previously stored header of the block, if
+    /// any.
+    pub fn insert_block_header(&self, block_header: &BlockHeader) -> Result<(), DataStoreError> {
+        self.write_entry(&self.block_path(block_header.block_num()), block_header)
+    }
+
+    /// Stores the provided note, replacing the previously stored note with the same ID, if any.
+    ///
+    /// Notes stored with an inclusion proof are authenticated against the chain MMR during
+    /// execution, while notes stored without one are consumed as unauthenticated notes.
+    pub fn insert_note(&self, note: &InputNote) -> Result<(), DataStoreError> {
+        self.write_entry(&self.note_path(note.id()), note)
+    }
+
+    /// Marks the note with the specified ID as consumed, so that it is no longer returned by the
+    /// store.
+    pub fn mark_note_consumed(&self, note_id: NoteId) -> Result<(), DataStoreError> {
+        self.write_file(&self.consumed_note_path(note_id), &[])
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    fn account_path(&self, account_id: AccountId) -> PathBuf {
+        self.root.join(ACCOUNTS_DIR).join(account_id.to_hex())
+    }
+
+    fn block_path(&self, block_num: BlockNumber) -> PathBuf {
+        self.root.join(BLOCKS_DIR).join(block_num.to_string())
+    }
+
+    fn note_path(&self, note_id: NoteId) -> PathBuf {
+        self.root.join(NOTES_DIR).join(note_id.to_hex())
+    }
+
+    fn consumed_note_path(&self, note_id: NoteId) -> PathBuf {
+        self.root.join(CONSUMED_NOTES_DIR).join(note_id.to_hex())
+    }
+
+    /// Reads and deserializes the entry stored at the provided path, or returns `None` if no entry
+    /// is stored there.
+    fn read_entry<T: Deserializable>(&self, path: &Path) -> Result<Option<T>, DataStoreError> {
+        let Some(bytes) = self.read_file(path)? else {
+            return Ok(None);
+        };
+
+        T::read_from_bytes(&bytes).map(Some).map_err(|err| {
+            DataStoreError::other_with_source(
+                format!("failed to deserialize data store entry {}", path.display()),
+                err,
+            )
+        })
+    }
+
+    /// Reads the file at the provided path, or returns `None` if the file does not exist.
+    fn read_file(&self, path: &Path) -> Result<Option<Vec<u8>>, DataStoreError> {
+        match fs::read(path) {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(DataStoreError::other_with_source(
+                format!("failed to read data store entry {}", path.display()),
+                err,
+            )),
+        }
+    }
+
+    /// Serializes the provided value and stores it at the provided path.
+    fn write_entry(&self, path: &Path, value: &impl Serializable) -> Result<(), DataStoreError> {
+        self.write_file(path, &value.to_bytes())
+    }
+
+    /// Atomically writes the provided bytes to the file at the provided path.
+    fn write_file(&self, path: &Path, bytes: &[u8]) -> Result<(), DataStoreError> {
+        let tmp_path = path.with_extension("tmp");
+        fs::write(&tmp_path, bytes)
+            .and_then(|_| fs::rename(&tmp_path, path))
+            .map_err(|err| {
+                DataStoreError::other_with_source(
+                    format!("failed to write data store entry {}", path.display()),
+                    err,
+                )
+            })
+    }
+
+    /// Returns the chain MMR of the chain preceding the provided reference block which tracks the
+    /// provided blocks.
+    fn build_chain_mmr(
+        &self,
+        block_ref: BlockNumber,
+        tracked_blocks: BTreeSet<BlockNumber>,
+    ) -> Result<ChainMmr, DataStoreError> {
+        // the reference block is added to the chain MMR by the transaction kernel
+        let mut mmr = Mmr::new();
+        for block_num in 0..block_ref.as_u32() {
+            mmr.add(self.get_block_header(block_num.into())?.commitment());
+        }
+
+        let forest = mmr.forest();
+        let peaks = mmr.peaks_at(forest).map_err(|err| {
+            DataStoreError::other_with_source("failed to compute chain MMR peaks", err)
+        })?;
+        let mut partial_mmr = PartialMmr::from_peaks(peaks);
+
+        let mut block_headers = Vec::with_capacity(tracked_blocks.len());
+        for block_num in tracked_blocks {
+            let leaf_idx = block_num.as_usize();
+            let leaf = mmr.get(leaf_idx).map_err(|err| {
+                DataStoreError::other_with_source("failed to get chain MMR leaf", err)
+            })?;
+            let path = mmr.open_at(leaf_idx, forest).map_err(|err| {
+                DataStoreError::other_with_source("failed to open chain MMR leaf", err)
+            })?;
+            partial_mmr.track(leaf_idx, leaf, &path.merkle_path).map_err(|err| {
+                DataStoreError::other_with_source("failed to track chain MMR leaf", err)
+            })?;
+            block_headers.push(self.get_block_header(block_num)?);
+        }
+
+        ChainMmr::new(partial_mmr, block_headers)
+            .map_err(|err| DataStoreError::other_with_source("failed to build chain MMR", err))
+    }
+}
+
+#[maybe_async_trait]
+impl DataStore for FileDataStore {
+    #[maybe_async]
+    fn get_transaction_inputs(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: &[NoteId],
+    ) -> Result<TransactionInputs, DataStoreError> {
+        let (account, account_seed) = self.get_account(account_id)?;
+        let block_header = self.get_block_header(block_ref)?;
+
+        let input_notes = notes
+            .iter()
+            .map(|note_id| self.get_note(*note_id))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // track the blocks in which the authenticated notes were created and, for new accounts,
+        // the anchor block of the account
+        let mut tracked_blocks: BTreeSet<BlockNumber> = input_notes
+            .iter()
+            .filter_map(|note| note.location().map(|location| location.block_num()))
+            .collect();
+        if account.is_new() {
+            tracked_blocks.insert(BlockNumber::from_epoch(account_id.anchor_epoch()));
+        }
+        tracked_blocks.retain(|block_num| *block_num < block_ref);
+
+        let chain_mmr = self.build_chain_mmr(block_ref, tracked_blocks)?;
+        let input_notes =
+            InputNotes::new(input_notes).map_err(DataStoreError::InvalidTransactionInput)?;
+
+        TransactionInputs::new(account, account_seed, block_header, chain_mmr, input_notes)
+            .map_err(DataStoreError::InvalidTransactionInput)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use assert_matches::assert_matches;
+
+    use super::*;
+    use crate::{TransactionExecutor, testing::MockChain, tests::fixtures::TransactionFixture};
+
+    #[test]
+    fn file_data_store_provides_transaction_inputs() {
+        let mut mock_chain = MockChain::new();
+        let fixture = TransactionFixture::with_p2id_notes(&mut mock_chain, 1);
+        let tx_context = &fixture.tx_context;
+        let note_id = fixture.note_ids[0];
+
+        let root = std::env::temp_dir().join(format!("miden-file-data-store-{}", note_id.to_hex()));
+        let store = FileDataStore::open(&root).unwrap();
+        store.insert_account(tx_context.account(), None).unwrap();
+        for block_num in 0..=fixture.block_ref.as_usize() {
+            store.insert_block_header(&mock_chain.block_header(block_num)).unwrap();
+        }
+        for input_note in tx_context.input_notes().iter() {
+            store.insert_note(input_note).unwrap();
+        }
+
+        // the store persists its data, so a newly opened store provides the same inputs
+        let store = FileDataStore::open(&root).unwrap();
+        let tx_inputs = store
+            .get_transaction_inputs(fixture.account_id, fixture.block_ref, &fixture.note_ids)
+            .unwrap();
+        assert_eq!(tx_inputs.account(), tx_context.tx_inputs().account());
+        assert_eq!(tx_inputs.block_header(), tx_context.tx_inputs().block_header());
+        assert_eq!(tx_inputs.input_notes(), tx_context.tx_inputs().input_notes());
+        assert_eq!(tx_inputs.block_chain().peaks(), tx_context.tx_inputs().block_chain().peaks());
+
+        let executor =
+            TransactionExecutor::new(Arc::new(store.clone()), tx_context.authenticator());
+        let executed_transaction = fixture.execute(&executor, fixture.tx_args()).unwrap();
+        assert_eq!(executed_transaction.account_delta().vault().fungible().num_assets(), 1);
+
+        store.mark_note_consumed(note_id).unwrap();
+        assert_matches!(
+            store.get_transaction_inputs(fixture.account_id, fixture.block_ref, &fixture.note_ids),
+            Err(DataStoreError::NoteAlreadyConsumed(consumed_note_id)) if consumed_note_id == note_id
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,289 @@
+#[cfg(feature = "async")]
+use alloc::boxed::Box;
+use alloc::{collections::BTreeSet, vec::Vec};
+
+use miden_lib::transaction::TransactionKernel;
+use miden_objects::{
+    Digest, Hasher, Word,
+    account::{
+        AccountCode, AccountHeader, AccountId, AccountStorageHeader, StorageMap, StorageSlotType,
+    },
+    block::{BlockHeader, BlockNumber},
+    crypto::merkle::MerklePath,
+    vm::AdviceInputs,
+};
+use winter_maybe_async::*;
+
+use crate::{DataStoreError, errors::ForeignAccountInputsError};
+
+// FOREIGN ACCOUNT INPUTS
+// ================================================================================================
+
+/// The data of a foreign account which is required to invoke the procedures of the account via
+/// foreign procedure invocation (FPI).
+///
+/// The inputs consist of:
+/// - the header of the account and the header of its storage,
+/// - the code of the account,
+/// - the storage maps of the account which may be accessed by the invoked procedures,
+/// - the authentication path of the account in the account tree of the reference block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForeignAccountInputs {
+    account_header: AccountHeader,
+    storage_header: AccountStorageHeader,
+    account_code: AccountCode,
+    storage_maps: Vec<StorageMap>,
+    account_proof: MerklePath,
+}
+
+impl ForeignAccountInputs {
+    /// Returns new [ForeignAccountInputs] instantiated from the provided data.
+    ///
+    /// The inputs are not validated until they are assembled by a [ForeignAccountInputsBuilder].
+    pub fn new(
+        account_header: AccountHeader,
+        storage_header: AccountStorageHeader,
+        account_code: AccountCode,
+        storage_maps: Vec<StorageMap>,
+        account_proof: MerklePath,
+    ) -> Self {
+        Self {
+            account_header,
+            storage_header,
+            account_code,
+            storage_maps,
+            account_proof,
+        }
+    }
+
+    /// Returns the ID of the foreign account.
+    pub fn account_id(&self) -> AccountId {
+        self.account_header.id()
+    }
+
+    /// Returns the header of the foreign account.
+    pub fn account_header(&self) -> &AccountHeader {
+        &self.account_header
+    }
+
+    /// Returns the header of the storage of the foreign account.
+    pub fn storage_header(&self) -> &AccountStorageHeader {
+        &self.storage_header
+    }
+
+    /// Returns the code of the foreign account.
+    pub fn account_code(&self) -> &AccountCode {
+        &self.account_code
+    }
+
+    /// Returns the storage maps of the foreign account.
+    pub fn storage_maps(&self) -> &[StorageMap] {
+        &self.storage_maps
+    }
+
+    /// Returns the authentication path of the foreign account in the account tree.
+    pub fn account_proof(&self) -> &MerklePath {
+        &self.account_proof
+    }
+
+    /// Checks that the inputs are consistent with each other and that the account is part of the
+    /// account tree with the provided root.
+    fn validate(&self, account_root: Digest) -> Result<(), ForeignAccountInputsError> {
+        let account_id = self.account_id();
+
+        if self.account_header.code_commitment() != self.account_code.commitment() {
+            return Err(ForeignAccountInputsError::CodeCommitmentMismatch(account_id));
+        }
+
+        let storage_commitment = Hasher::hash_elements(&self.storage_header.as_elements());
+        if self.account_header.storage_commitment() != storage_commitment {
+            return Err(ForeignAccountInputsError::StorageCommitmentMismatch(account_id));
+        }
+
+        for map in self.storage_maps.iter() {
+            let map_root = map.root();
+            let is_map_slot = self.storage_header.slots().any(|(slot_type, value)| {
+                *slot_type == StorageSlotType::Map && *value == Word::from(map_root)
+            });
+            if !is_map_slot {
+                return Err(ForeignAccountInputsError::UnknownStorageMap { account_id, map_root });
+            }
+        }
+
+        let computed_root = self
+            .account_proof
+            .compute_root(account_id.prefix().as_u64(), self.account_header.commitment())
+            .map_err(|_| ForeignAccountInputsError::InvalidAccountProof(account_id))?;
+        if computed_root != account_root {
+            return Err(ForeignAccountInputsError::InvalidAccountProof(account_id));
+        }
+
+        Ok(())
+    }
+
+    /// Extends the provided advice inputs with the data of the foreign account.
+    fn extend_advice_inputs(&self, advice_inputs: &mut AdviceInputs) {
+        TransactionKernel::extend_advice_inputs_for_account(
+            advice_inputs,
+            &self.account_header,
+            &self.account_code,
+            &self.storage_header,
+            &self.account_proof,
+        )
+        .expect("account proof should have been validated");
+
+        for map in self.storage_maps.iter() {
+            advice_inputs.extend_merkle_store(map.inner_nodes());
+            advice_inputs
+                .extend_map(map.leaves().map(|(_, leaf)| (leaf.hash(), leaf.to_elements())));
+        }
+    }
+}
+
+// FOREIGN ACCOUNT PROVIDER
+// ================================================================================================
+
+/// The [ForeignAccountProvider] trait defines the interface through which a
+/// [ForeignAccountInputsBuilder] fetches the data of foreign accounts, e.g. from a node.
+///
+/// When the `async` feature is enabled, the methods of this trait are `async`.
+#[maybe_async_trait]
+pub trait ForeignAccountProvider {
+    /// Returns the inputs of the foreign account with the specified ID as of the block with the
+    /// specified number.
+    ///
+    /// # Errors
+    /// Returns an error if the account could not be found or the data could not be fetched.
+    #[maybe_async]
+    fn get_foreign_account_inputs(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+    ) -> Result<ForeignAccountInputs, DataStoreError>;
+}
+
+// FOREIGN ACCOUNT INPUTS BUILDER
+// ================================================================================================
+
+/// A builder which fetches the data of the foreign accounts referenced by a transaction and
+/// assembles validated [FpiInputs].
+///
+/// The inputs of every foreign account are fetched from a [ForeignAccountProvider] as of the
+/// reference block of the transaction and validated against the account root of its header.
+///
+/// The resulting [FpiInputs] are passed to the executor by loading the foreign account codes via
+/// [`TransactionExecutor::load_fpi_inputs`](crate::TransactionExecutor::load_fpi_inputs) and
+/// adding [`FpiInputs::advice_inputs`] to the transaction args.
+#[derive(Debug, Clone)]
+pub struct ForeignAccountInputsBuilder {
+    block_header: BlockHeader,
+    account_ids: BTreeSet<AccountId>,
+}
+
+impl ForeignAccountInputsBuilder {
+    /// The maximum number of foreign accounts which can be loaded by a single transaction.
+    pub const MAX_FOREIGN_ACCOUNTS: usize = 63;
+
+    /// Returns a new [ForeignAccountInputsBuilder] for a transaction executed against the block
+    /// with the provided header.
+    pub fn new(block_header: BlockHeader) -> Self {
+        Self {
+            block_header,
+            account_ids: BTreeSet::new(),
+        }
+    }
+
+    /// Adds a foreign account referenced by the transaction. Accounts which are added more than
+    /// once are fetched only once.
+    pub fn with_foreign_account(mut self, account_id: AccountId) -> Self {
+        self.account_ids.insert(account_id);
+        self
+    }
+
+    /// Adds the foreign accounts referenced by the transaction.
+    pub fn with_foreign_accounts(
+        mut self,
+        account_ids: impl IntoIterator<Item = AccountId>,
+    ) -> Self {
+        self.account_ids.extend(account_ids);
+        self
+    }
+
+    /// Fetches the inputs of the foreign accounts from the provider and returns the validated
+    /// [FpiInputs].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - the number of foreign accounts exceeds [`Self::MAX_FOREIGN_ACCOUNTS`].
+    /// - the inputs of any of the foreign accounts could not be fetched.
+    /// - the provider returned the inputs of a different account than the requested one.
+    /// - the code or the storage header of any account do not match the commitments in the account
+    ///   header.
+    /// - any of the storage maps is not part of the storage of the respective account.
+    /// - any of the accounts is not part of the account tree of the reference block.
+    #[maybe_async]
+    pub fn build(
+        self,
+        provider: &dyn ForeignAccountProvider,
+    ) -> Result<FpiInputs, ForeignAccountInputsError> {
+        if self.account_ids.len() > Self::MAX_FOREIGN_ACCOUNTS {
+            return Err(ForeignAccountInputsError::TooManyForeignAccounts(self.account_ids.len()));
+        }
+
+        let block_ref = self.block_header.block_num();
+        let account_root = self.block_header.account_root();
+
+        let mut accounts = Vec::with_capacity(self.account_ids.len());
+        for account_id in self.account_ids {
+            let inputs =
+                maybe_await!(provider.get_foreign_account_inputs(account_id, block_ref)).map_err(
+                    |source| ForeignAccountInputsError::FetchFailed { account_id, source },
+                )?;
+
+            if inputs.account_id() != account_id {
+                return Err(ForeignAccountInputsError::AccountIdMismatch {
+                    expected: account_id,
+                    actual: inputs.account_id(),
+                });
+            }
+            inputs.validate(account_root)?;
+
+            accounts.push(inputs);
+        }
+
+        Ok(FpiInputs { accounts })
+    }
+}
+
+// FPI INPUTS
+// ================================================================================================
+
+/// The validated inputs of all foreign accounts referenced by a transaction, assembled by a
+/// [ForeignAccountInputsBuilder].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FpiInputs {
+    accounts: Vec<ForeignAccountInputs>,
+}
+
+impl FpiInputs {
+    /// Returns the inputs of the foreign accounts, ordered by account ID.
+    pub fn accounts(&self) -> &[ForeignAccountInputs] {
+        &self.accounts
+    }
+
+    /// Returns an iterator over the codes of the foreign accounts.
+    pub fn account_codes(&self) -> impl Iterator<Item = &AccountCode> {
+        self.accounts.iter().map(|account| account.account_code())
+    }
+
+    /// Returns the advice inputs which provide the data of the foreign accounts to the
+    /// transaction kernel.
+    pub fn advice_inputs(&self) -> AdviceInputs {
+        let mut advice_inputs = AdviceInputs::default();
+        for account in self.accounts.iter() {
+            account.extend_advice_inputs(&mut advice_inputs);
+        }
+
+        advice_inputs
+    }
+}
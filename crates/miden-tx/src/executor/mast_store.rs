@@ -1,4 +1,4 @@
-use alloc::{collections::BTreeMap, sync::Arc};
+use alloc::{collections::BTreeMap, sync::Arc, vec::Vec};
 
 use miden_lib::{MidenLib, StdLibrary, transaction::TransactionKernel, utils::sync::RwLock};
 use miden_objects::{
@@ -19,25 +19,54 @@ use vm_processor::MastForestStore;
 /// a procedure which it doesn't have the code for. Thus, to execute a program which makes
 /// references to external procedures, the store must be loaded with [MastForest]s containing these
 /// procedures.
+///
+/// The store also caches loaded account code (including the code of foreign accounts) by code
+/// commitment, so that loading the same code again, e.g. when the store is reused across many
+/// transactions against the same accounts, does not re-register its procedures. The cache holds at
+/// most [Self::DEFAULT_ACCOUNT_CODE_CAPACITY] codes (or the capacity provided to
+/// [Self::with_account_code_capacity()]); when it is full, the least recently loaded code is
+/// evicted and its procedures are removed from the store.
 pub struct TransactionMastStore {
     mast_forests: RwLock<BTreeMap<Digest, Arc<MastForest>>>,
+    account_codes: RwLock<AccountCodeCache>,
 }
 
 #[allow(clippy::new_without_default)]
 impl TransactionMastStore {
+    /// The default maximum number of account codes cached by the store.
+    pub const DEFAULT_ACCOUNT_CODE_CAPACITY: usize = 256;
+
     /// Returns a new [TransactionMastStore] instantiated with the default libraries.
     ///
     /// The default libraries include:
     /// - Miden standard library (miden-stdlib).
     /// - Miden rollup library (miden-lib).
-    /// - Transaction kernel.
+    /// - All versions of the transaction kernel.
     pub fn new() -> Self {
+        Self::with_account_code_capacity(Self::DEFAULT_ACCOUNT_CODE_CAPACITY)
+    }
+
+    /// Returns a new [TransactionMastStore] instantiated with the default libraries which caches
+    /// at most `capacity` account codes.
+    ///
+    /// The capacity must be at least the number of account codes used by a single transaction,
+    /// i.e. the code of the native account and the codes of all foreign accounts it accesses.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is zero.
+    pub fn with_account_code_capacity(capacity: usize) -> Self {
+        assert!(capacity > 0, "account code cache capacity must be greater than zero");
+
         let mast_forests = RwLock::new(BTreeMap::new());
-        let store = Self { mast_forests };
+        let account_codes = RwLock::new(AccountCodeCache::new(capacity));
+        let store = Self { mast_forests, account_codes };
 
-        // load transaction kernel MAST forest
-        let kernels_forest = TransactionKernel::kernel().mast_forest().clone();
-        store.insert(kernels_forest);
+        // load the MAST forests of all transaction kernel versions
+        for version in 0..TransactionKernel::NUM_VERSIONS {
+            let kernel = TransactionKernel::for_version(version as u8)
+                .expect("kernel versions below the number of versions should be supported");
+            store.insert(kernel.kernel().mast_forest().clone());
+        }
 
         // load miden-stdlib MAST forest
         let miden_stdlib_forest = StdLibrary::default().mast_forest().clone();
@@ -51,8 +80,20 @@ impl TransactionMastStore {
     }
 
     /// Loads the provided account code into this store.
+    ///
+    /// If code with the same commitment has already been loaded, it is only marked as the most
+    /// recently loaded one. Otherwise, the code is added to the cache, evicting the least recently
+    /// loaded code if the cache is full.
     pub fn load_account_code(&self, code: &AccountCode) {
-        self.insert(code.mast().clone());
+        self.account_codes.write().load(code);
+    }
+
+    /// Returns the account code with the specified commitment, if this code is cached by this
+    /// store.
+    ///
+    /// This allows callers to skip deserializing account code which has already been loaded.
+    pub fn get_account_code(&self, code_commitment: &Digest) -> Option<AccountCode> {
+        self.account_codes.read().get(code_commitment).cloned()
     }
 
     /// Loads code required for executing a transaction with the specified inputs and args into
@@ -93,6 +134,146 @@ impl TransactionMastStore {
 
 impl MastForestStore for TransactionMastStore {
     fn get(&self, procedure_root: &Digest) -> Option<Arc<MastForest>> {
-        self.mast_forests.read().get(procedure_root).cloned()
+        if let Some(mast_forest) = self.mast_forests.read().get(procedure_root) {
+            return Some(mast_forest.clone());
+        }
+
+        self.account_codes.read().get_procedure(procedure_root)
+    }
+}
+
+// ACCOUNT CODE CACHE
+// ================================================================================================
+
+/// A cache of account code bounded by the number of cached codes.
+///
+/// Codes are evicted in the least recently loaded order.
+struct AccountCodeCache {
+    capacity: usize,
+    /// The number of loads performed so far, used to order codes by the time they were last
+    /// loaded.
+    clock: u64,
+    /// The cached codes by code commitment, together with the time they were last loaded.
+    codes: BTreeMap<Digest, (AccountCode, u64)>,
+    /// The commitments of the cached codes containing a procedure, by procedure MAST root.
+    procedures: BTreeMap<Digest, Vec<Digest>>,
+}
+
+impl AccountCodeCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            clock: 0,
+            codes: BTreeMap::new(),
+            procedures: BTreeMap::new(),
+        }
+    }
+
+    fn get(&self, commitment: &Digest) -> Option<&AccountCode> {
+        self.codes.get(commitment).map(|(code, _)| code)
+    }
+
+    fn get_procedure(&self, procedure_root: &Digest) -> Option<Arc<MastForest>> {
+        let commitment = self.procedures.get(procedure_root)?.first()?;
+        self.get(commitment).map(|code| code.mast().clone())
+    }
+
+    fn load(&mut self, code: &AccountCode) {
+        self.clock += 1;
+        let commitment = code.commitment();
+        if let Some((_, last_loaded)) = self.codes.get_mut(&commitment) {
+            *last_loaded = self.clock;
+            return;
+        }
+
+        if self.codes.len() == self.capacity {
+            self.evict_least_recently_loaded();
+        }
+
+        // only register procedures that are local to the forest of the code
+        for proc_digest in code.mast().local_procedure_digests() {
+            self.procedures.entry(proc_digest).or_default().push(commitment);
+        }
+        self.codes.insert(commitment, (code.clone(), self.clock));
+    }
+
+    fn evict_least_recently_loaded(&mut self) {
+        let Some(commitment) = self
+            .codes
+            .iter()
+            .min_by_key(|(_, (_, last_loaded))| *last_loaded)
+            .map(|(commitment, _)| *commitment)
+        else {
+            return;
+        };
+
+        let (code, _) = self.codes.remove(&commitment).expect("code should be cached");
+        for proc_digest in code.mast().local_procedure_digests() {
+            if let Some(commitments) = self.procedures.get_mut(&proc_digest) {
+                commitments.retain(|cached| *cached != commitment);
+                if commitments.is_empty() {
+                    self.procedures.remove(&proc_digest);
+                }
+            }
+        }
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_objects::utils::{Deserializable, Serializable};
+    use vm_processor::ONE;
+
+    use super::*;
+    use crate::testing::TransactionContextBuilder;
+
+    #[test]
+    fn transaction_mast_store_caches_account_code() {
+        let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+        let code = tx_context.account().code();
+        let proc_root = code.procedures()[0].mast_root();
+
+        let mast_store = TransactionMastStore::new();
+        assert!(mast_store.get_account_code(&code.commitment()).is_none());
+
+        mast_store.load_account_code(code);
+        let cached_code = mast_store.get_account_code(&code.commitment()).unwrap();
+        assert_eq!(&cached_code, code);
+        let cached_forest = cached_code.mast();
+        assert!(Arc::ptr_eq(&cached_forest, &mast_store.get(proc_root).unwrap()));
+
+        // loading a deserialized copy of the same code reuses the cached forest
+        let code_copy = AccountCode::read_from_bytes(&code.to_bytes()).unwrap();
+        assert!(!Arc::ptr_eq(&code_copy.mast(), &cached_forest));
+        mast_store.load_account_code(&code_copy);
+        assert!(Arc::ptr_eq(&cached_forest, &mast_store.get(proc_root).unwrap()));
+    }
+
+    #[test]
+    fn transaction_mast_store_evicts_least_recently_loaded_account_code() {
+        let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+        let code = tx_context.account().code();
+        let proc_root = code.procedures()[0].mast_root();
+        let other_code = AccountCode::mock();
+        assert_ne!(code.commitment(), other_code.commitment());
+
+        let mast_store = TransactionMastStore::with_account_code_capacity(1);
+        mast_store.load_account_code(code);
+        mast_store.load_account_code(code);
+        assert!(mast_store.get(proc_root).is_some());
+
+        // loading another code into the full cache evicts the code and unregisters its procedures
+        mast_store.load_account_code(&other_code);
+        assert!(mast_store.get_account_code(&code.commitment()).is_none());
+        assert!(mast_store.get(proc_root).is_none());
+        assert!(mast_store.get_account_code(&other_code.commitment()).is_some());
+
+        // loading the evicted code again registers its procedures anew
+        mast_store.load_account_code(code);
+        assert!(mast_store.get(proc_root).is_some());
+        assert!(mast_store.get_account_code(&other_code.commitment()).is_none());
     }
 }
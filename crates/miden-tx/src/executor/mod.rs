@@ -1,27 +1,65 @@
-use alloc::{collections::BTreeSet, sync::Arc, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+    vec::Vec,
+};
 
-use miden_lib::transaction::TransactionKernel;
+use miden_lib::{
+    transaction::{TransactionKernel, VersionedTransactionKernel},
+    utils::sync::RwLock,
+};
 use miden_objects::{
-    Felt, MAX_TX_EXECUTION_CYCLES, MIN_TX_EXECUTION_CYCLES, ZERO,
-    account::{AccountCode, AccountId},
+    Digest, Felt, MAX_TX_EXECUTION_CYCLES, MIN_TX_EXECUTION_CYCLES, WORD_SIZE, ZERO,
+    account::{AccountCode, AccountDelta, AccountId},
     assembly::Library,
     block::BlockNumber,
     note::NoteId,
-    transaction::{ExecutedTransaction, TransactionArgs, TransactionInputs, TransactionScript},
-    vm::StackOutputs,
+    transaction::{
+        ExecutedTransaction, OutputNote, TransactionArgs, TransactionInputs,
+        TransactionMeasurements, TransactionOutputs, TransactionScript, TransactionTimings,
+    },
+    vm::{AdviceMap, StackOutputs},
+};
+use vm_processor::{
+    AdviceInputs, AdviceProvider, ChipletsLengths, ExecutionError, ExecutionOptions,
+    MemAdviceProvider, Process, RecAdviceProvider, TraceLenSummary,
 };
-use vm_processor::{AdviceInputs, ExecutionOptions, Process, RecAdviceProvider};
 use winter_maybe_async::{maybe_async, maybe_await};
 
-use super::{TransactionExecutorError, TransactionHost};
-use crate::auth::TransactionAuthenticator;
+use super::{TransactionExecutorError, TransactionHost, TransactionProgress};
+use crate::auth::{SigningManifest, SigningRequest, TransactionAuthenticator};
 
 mod data_store;
 pub use data_store::DataStore;
 
+#[cfg(feature = "file-store")]
+mod file_store;
+#[cfg(feature = "file-store")]
+pub use file_store::FileDataStore;
+
 mod mast_store;
 pub use mast_store::TransactionMastStore;
 
+mod notes_checker;
+pub use notes_checker::{NoteConsumability, NoteConsumptionChecker};
+
+mod simulation;
+pub use simulation::TransactionSimulation;
+
+mod cost;
+pub use cost::{TransactionCost, TransactionCostModel};
+
+mod debugger;
+pub use debugger::{Breakpoint, BreakpointHit, TransactionDebugReport};
+
+mod replay;
+pub use replay::TransactionReplayBundle;
+
+mod foreign_accounts;
+pub use foreign_accounts::{
+    ForeignAccountInputs, ForeignAccountInputsBuilder, ForeignAccountProvider, FpiInputs,
+};
+
 // TRANSACTION EXECUTOR
 // ================================================================================================
 
@@ -42,6 +80,15 @@ pub struct TransactionExecutor {
     /// [Self::load_account_code()] method.
     account_codes: BTreeSet<AccountCode>,
     exec_options: ExecutionOptions,
+    /// The maximum size of the advice inputs of a transaction in field elements, if limited.
+    max_advice_size: Option<usize>,
+    /// The roots of the note and transaction scripts which have been loaded into the MAST store by
+    /// previous executions, if the executor retains its state between executions.
+    loaded_scripts: Option<RwLock<BTreeSet<Digest>>>,
+    /// Whether transactions are executed without any host-provided nondeterminism.
+    deterministic: bool,
+    /// The version of the transaction kernel transactions are executed with.
+    kernel: VersionedTransactionKernel,
 }
 
 impl TransactionExecutor {
@@ -68,6 +115,10 @@ impl TransactionExecutor {
             )
             .expect("Must not fail while max cycles is more than min trace length"),
             account_codes: BTreeSet::new(),
+            max_advice_size: None,
+            loaded_scripts: None,
+            deterministic: false,
+            kernel: TransactionKernel::latest(),
         }
     }
 
@@ -85,17 +136,101 @@ impl TransactionExecutor {
     ///
     /// When tracing is enabled, the executor will receive tracing events as various stages of the
     /// transaction kernel complete. This enables collecting basic stats about how long different
-    /// stages of transaction execution take.
+    /// stages of transaction execution take, and reporting the stage in which an assertion of the
+    /// transaction kernel failed.
     pub fn with_tracing(mut self) -> Self {
         self.exec_options = self.exec_options.with_tracing();
         self
     }
 
+    /// Sets the maximum number of cycles a transaction may take to execute.
+    ///
+    /// The execution of transactions which exceed this limit is aborted with
+    /// [`TransactionExecutorError::CycleLimitExceeded`]. By default, transactions may take up to
+    /// [MAX_TX_EXECUTION_CYCLES] cycles.
+    ///
+    /// # Errors
+    /// Returns an error if `max_cycles` is not between [MIN_TX_EXECUTION_CYCLES] and
+    /// [MAX_TX_EXECUTION_CYCLES].
+    pub fn with_max_cycles(mut self, max_cycles: u32) -> Result<Self, TransactionExecutorError> {
+        if !(MIN_TX_EXECUTION_CYCLES..=MAX_TX_EXECUTION_CYCLES).contains(&max_cycles) {
+            return Err(TransactionExecutorError::InvalidMaxCycles(max_cycles));
+        }
+
+        self.exec_options = ExecutionOptions::new(
+            Some(max_cycles),
+            MIN_TX_EXECUTION_CYCLES,
+            self.exec_options.enable_tracing(),
+            self.exec_options.enable_debugging(),
+        )
+        .expect("Must not fail while max cycles is more than min trace length");
+        Ok(self)
+    }
+
+    /// Sets the maximum size of the advice inputs of a transaction, measured in field elements.
+    ///
+    /// The size comprises the advice stack, the keys and values of the advice map and the nodes of
+    /// the Merkle store, including the data provided by the transaction kernel for the account,
+    /// the input notes and the chain, as well as the advice inputs of the transaction args.
+    /// Transactions which exceed this limit are rejected with
+    /// [`TransactionExecutorError::AdviceInputsSizeExceeded`] before they are executed.
+    ///
+    /// Together with the cycle limit, which also bounds the amount of memory a transaction can
+    /// write to, this allows bounding the resources used to execute untrusted note scripts.
+    pub fn with_max_advice_size(mut self, max_advice_size: usize) -> Self {
+        self.max_advice_size = Some(max_advice_size);
+        self
+    }
+
+    /// Enables warm starts for the created instance of [TransactionExecutor].
+    ///
+    /// By default, the code of the account, the input notes and the transaction script of every
+    /// executed transaction is registered with the MAST store of the executor anew. With warm
+    /// starts enabled, the executor keeps track of the scripts it has already loaded, so that
+    /// executing further transactions with the same note or transaction scripts, e.g. when many
+    /// small transactions consuming the same kind of notes are executed by one executor, skips
+    /// loading their code again. The code of accounts is always cached by the MAST store.
+    pub fn with_warm_start(mut self) -> Self {
+        self.loaded_scripts = Some(RwLock::new(BTreeSet::new()));
+        self
+    }
+
+    /// Puts the [TransactionExecutor] into deterministic mode.
+    ///
+    /// In deterministic mode, executing the same transaction with the same inputs always yields
+    /// bit-for-bit identical results, which allows a failing transaction to be replayed from a
+    /// [TransactionReplayBundle]. To achieve this, the executor:
+    /// - does not request signatures from its authenticator, since signatures are generated using
+    ///   randomness. All signatures required by a transaction must be provided via the advice map
+    ///   of the transaction args, and missing signatures are reported via
+    ///   [`TransactionExecutorError::MissingSignatures`].
+    /// - does not measure the wall-clock time spent in the stages of the transaction, i.e. the
+    ///   timings of the [`TransactionMeasurements`] of executed transactions are zero.
+    pub fn with_deterministic_execution(mut self) -> Self {
+        self.deterministic = true;
+        self
+    }
+
+    /// Sets the version of the transaction kernel transactions are executed with.
+    ///
+    /// By default, transactions are executed with the latest transaction kernel. Transactions
+    /// executed with an older kernel version must also be proven with that version, see
+    /// [`LocalTransactionProver::with_kernel`](crate::LocalTransactionProver::with_kernel).
+    pub fn with_kernel(mut self, kernel: VersionedTransactionKernel) -> Self {
+        self.kernel = kernel;
+        self
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
     /// Loads the provided account code into the internal MAST forest store and adds the commitment
     /// of the provided code to the commitments set.
+    ///
+    /// The code of every foreign account whose procedures may be invoked during a transaction must
+    /// be loaded. Since foreign procedures can themselves invoke procedures of other foreign
+    /// accounts (up to the nesting depth supported by the kernel's account stack), this includes
+    /// accounts which are referenced only transitively.
     pub fn load_account_code(&mut self, code: &AccountCode) {
         // load the code mast forest to the mast store
         self.mast_store.load_account_code(code);
@@ -104,6 +239,17 @@ impl TransactionExecutor {
         self.account_codes.insert(code.clone());
     }
 
+    /// Loads the codes of the foreign accounts of the provided [FpiInputs] into this executor, so
+    /// that their procedures can be invoked during a transaction.
+    ///
+    /// The transaction must additionally be executed with [`FpiInputs::advice_inputs`] in its
+    /// transaction args.
+    pub fn load_fpi_inputs(&mut self, fpi_inputs: &FpiInputs) {
+        for code in fpi_inputs.account_codes() {
+            self.load_account_code(code);
+        }
+    }
+
     /// Loads the provided library code into the internal MAST forest store.
     ///
     /// TODO: this is a work-around to support accounts which were complied with user-defined
@@ -122,9 +268,15 @@ impl TransactionExecutor {
     /// and compile the transaction into an executable program. Then, it executes the transaction
     /// program and creates an [ExecutedTransaction] object.
     ///
+    /// The input notes are consumed in the order in which they are specified in `notes`, and the
+    /// note args of each note can be provided via [TransactionArgs::add_note_args]. The note args
+    /// are put onto the stack right before the script of the respective note is executed.
+    ///
     /// # Errors:
     /// Returns an error if:
     /// - If required data can not be fetched from the [DataStore].
+    /// - If an entry in the advice map of the transaction args collides with an entry reserved by
+    ///   the transaction kernel.
     #[maybe_async]
     pub fn execute_transaction(
         &self,
@@ -135,32 +287,252 @@ impl TransactionExecutor {
     ) -> Result<ExecutedTransaction, TransactionExecutorError> {
         let tx_inputs =
             maybe_await!(self.data_store.get_transaction_inputs(account_id, block_ref, notes))
-                .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
+                .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?
+                .with_input_note_order(notes);
 
-        let (stack_inputs, advice_inputs) =
-            TransactionKernel::prepare_inputs(&tx_inputs, &tx_args, None);
+        self.execute_with_inputs(tx_inputs, tx_args)
+    }
+
+    /// Executes a transaction with placeholder signatures to determine the signatures it requires,
+    /// and returns a [SigningManifest] listing them.
+    ///
+    /// This is the first phase of the two-phase signing flow for setups in which the signing keys
+    /// are held offline. Every signature which can neither be found in the advice map of the
+    /// transaction args nor generated by the authenticator of this executor is recorded as a
+    /// [SigningRequest] and replaced by a placeholder. Since the placeholder does not pass
+    /// verification, the execution stops at the first procedure verifying a missing signature.
+    /// Authentication procedures requiring several signatures, such as the two-factor
+    /// authentication component, therefore request all of them before verifying any, so that all
+    /// requests are recorded in the manifest.
+    ///
+    /// Once the requested signatures have been produced, the transaction is executed with them via
+    /// [Self::finalize_signing].
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If required data can not be fetched from the [DataStore].
+    /// - If an entry in the advice map of the transaction args collides with an entry reserved by
+    ///   the transaction kernel.
+    /// - If the execution of the transaction fails before any signature is requested.
+    #[maybe_async]
+    pub fn prepare_signing(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: &[NoteId],
+        tx_args: TransactionArgs,
+    ) -> Result<SigningManifest, TransactionExecutorError> {
+        let tx_inputs =
+            maybe_await!(self.data_store.get_transaction_inputs(account_id, block_ref, notes))
+                .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?
+                .with_input_note_order(notes);
+
+        if let Some(key) = self.kernel.find_advice_map_collision(&tx_inputs, &tx_args) {
+            return Err(TransactionExecutorError::AdviceMapKeyCollision(key));
+        }
+        check_kernel_limits(&tx_inputs)?;
+
+        let (stack_inputs, advice_inputs) = self.kernel.prepare_inputs(&tx_inputs, &tx_args, None);
+        self.check_advice_size(&advice_inputs)?;
+        let advice_provider: MemAdviceProvider = advice_inputs.into();
+
+        self.load_transaction_code(&tx_inputs, &tx_args);
+
+        let mut host = TransactionHost::new(
+            tx_inputs.account().into(),
+            advice_provider,
+            self.mast_store.clone(),
+            self.host_authenticator(),
+            self.account_codes.iter().map(|code| code.commitment()).collect(),
+        )
+        .map_err(TransactionExecutorError::TransactionHostCreationFailed)?;
+        host.enable_placeholder_signatures();
+
+        let result =
+            vm_processor::execute(&self.kernel.main(), stack_inputs, &mut host, self.exec_options);
+
+        // a failure after a placeholder signature was provided is expected, since the placeholder
+        // does not pass verification
+        if host.signing_requests().is_empty() {
+            result.map_err(|err| map_execution_error(err, &host))?;
+        }
+
+        Ok(SigningManifest::new(
+            account_id,
+            tx_inputs.account().init_commitment(),
+            block_ref,
+            notes.to_vec(),
+            tx_args,
+            host.signing_requests().to_vec(),
+        ))
+    }
+
+    /// Executes the transaction described by the provided [SigningManifest] with the provided
+    /// signatures and returns the resulting [ExecutedTransaction], which can be proven.
+    ///
+    /// This is the second phase of the two-phase signing flow started by [Self::prepare_signing].
+    /// The signatures are keyed by [`SigningRequest::signature_key`].
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If a signature for any of the requests of the manifest is not provided.
+    /// - If required data can not be fetched from the [DataStore].
+    /// - If the state of the account in the [DataStore] differs from the state against which the
+    ///   signatures were requested.
+    /// - If the execution of the transaction fails, including when the transaction requires further
+    ///   signatures which were not requested in the first phase, e.g. because a custom
+    ///   authentication procedure verified a placeholder signature before requesting them.
+    #[maybe_async]
+    pub fn finalize_signing(
+        &self,
+        manifest: SigningManifest,
+        signatures: impl IntoIterator<Item = (Digest, Vec<Felt>)>,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        let signatures: BTreeMap<Digest, Vec<Felt>> = signatures.into_iter().collect();
+
+        let missing_signatures: Vec<SigningRequest> = manifest
+            .signing_requests()
+            .iter()
+            .filter(|request| !signatures.contains_key(&request.signature_key()))
+            .cloned()
+            .collect();
+        if !missing_signatures.is_empty() {
+            return Err(TransactionExecutorError::MissingSignatures(missing_signatures));
+        }
+
+        let tx_inputs = maybe_await!(self.data_store.get_transaction_inputs(
+            manifest.account_id(),
+            manifest.block_ref(),
+            manifest.notes()
+        ))
+        .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?
+        .with_input_note_order(manifest.notes());
+
+        let expected = manifest.initial_account_commitment();
+        let actual = tx_inputs.account().init_commitment();
+        if expected != actual {
+            return Err(TransactionExecutorError::SigningAccountStateChanged { expected, actual });
+        }
+
+        let mut tx_args = manifest.tx_args().clone();
+        tx_args.extend_advice_map(signatures);
+
+        self.execute_with_inputs(tx_inputs, tx_args)
+    }
+
+    /// Re-executes a previously executed transaction against a newer reference block and returns
+    /// the resulting [ExecutedTransaction].
+    ///
+    /// This is useful when the reference block of a prepared transaction has grown too old for the
+    /// transaction to be accepted. The block header, the chain MMR and the authentication data of
+    /// the input notes are fetched anew from the [DataStore] for `block_ref`, while the input
+    /// notes, their order and the transaction args (including the transaction script) are taken
+    /// from `executed_tx`. Notes which were consumed as unauthenticated notes may thus be
+    /// authenticated in the rebased transaction.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If `block_ref` is not greater than the reference block of `executed_tx`.
+    /// - If required data can not be fetched from the [DataStore].
+    /// - If the state of the account in the [DataStore] differs from the initial state of the
+    ///   account in `executed_tx`.
+    /// - If the re-execution of the transaction fails.
+    #[maybe_async]
+    pub fn rebase_transaction(
+        &self,
+        executed_tx: &ExecutedTransaction,
+        block_ref: BlockNumber,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        let original_block_ref = executed_tx.block_header().block_num();
+        if block_ref <= original_block_ref {
+            return Err(TransactionExecutorError::InvalidRebaseBlock {
+                original: original_block_ref,
+                requested: block_ref,
+            });
+        }
+
+        let notes: Vec<NoteId> = executed_tx.input_notes().iter().map(|note| note.id()).collect();
+        let tx_inputs = maybe_await!(self.data_store.get_transaction_inputs(
+            executed_tx.account_id(),
+            block_ref,
+            &notes
+        ))
+        .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?
+        .with_input_note_order(&notes);
+
+        let expected = executed_tx.initial_account().init_commitment();
+        let actual = tx_inputs.account().init_commitment();
+        if expected != actual {
+            return Err(TransactionExecutorError::RebaseAccountStateChanged { expected, actual });
+        }
+
+        self.execute_with_inputs(tx_inputs, executed_tx.tx_args().clone())
+    }
+
+    /// Fetches the inputs of the transaction specified by the provided arguments from the
+    /// [DataStore] and returns a [TransactionReplayBundle] from which the execution of the
+    /// transaction can be replayed.
+    ///
+    /// The bundle includes the codes of the foreign accounts loaded into this executor whose data
+    /// is provided via the advice map of `tx_args`.
+    ///
+    /// # Errors:
+    /// Returns an error if the inputs of the transaction can not be fetched from the [DataStore].
+    #[maybe_async]
+    pub fn build_replay_bundle(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: &[NoteId],
+        tx_args: TransactionArgs,
+    ) -> Result<TransactionReplayBundle, TransactionExecutorError> {
+        let tx_inputs =
+            maybe_await!(self.data_store.get_transaction_inputs(account_id, block_ref, notes))
+                .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?
+                .with_input_note_order(notes);
+
+        let foreign_account_codes = self
+            .account_codes
+            .iter()
+            .filter(|code| tx_args.advice_inputs().mapped_values(&code.commitment()).is_some())
+            .cloned()
+            .collect();
+
+        Ok(TransactionReplayBundle::new(tx_inputs, tx_args, foreign_account_codes))
+    }
+
+    /// Executes the transaction kernel with the provided inputs and arguments and returns an
+    /// [ExecutedTransaction].
+    fn execute_with_inputs(
+        &self,
+        tx_inputs: TransactionInputs,
+        tx_args: TransactionArgs,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        if let Some(key) = self.kernel.find_advice_map_collision(&tx_inputs, &tx_args) {
+            return Err(TransactionExecutorError::AdviceMapKeyCollision(key));
+        }
+        check_kernel_limits(&tx_inputs)?;
+
+        let (stack_inputs, advice_inputs) = self.kernel.prepare_inputs(&tx_inputs, &tx_args, None);
+        self.check_advice_size(&advice_inputs)?;
         let advice_recorder: RecAdviceProvider = advice_inputs.into();
 
         // load note script MAST into the MAST store
-        self.mast_store.load_transaction_code(&tx_inputs, &tx_args);
+        self.load_transaction_code(&tx_inputs, &tx_args);
 
         let mut host = TransactionHost::new(
             tx_inputs.account().into(),
             advice_recorder,
             self.mast_store.clone(),
-            self.authenticator.clone(),
+            self.host_authenticator(),
             self.account_codes.iter().map(|code| code.commitment()).collect(),
         )
         .map_err(TransactionExecutorError::TransactionHostCreationFailed)?;
 
         // execute the transaction kernel
-        let result = vm_processor::execute(
-            &TransactionKernel::main(),
-            stack_inputs,
-            &mut host,
-            self.exec_options,
-        )
-        .map_err(TransactionExecutorError::TransactionProgramExecutionFailed)?;
+        let result =
+            vm_processor::execute(&self.kernel.main(), stack_inputs, &mut host, self.exec_options)
+                .map_err(|err| map_execution_error(err, &host))?;
 
         // Attempt to retrieve used account codes based on the advice map
         let account_codes = self
@@ -180,7 +552,144 @@ impl TransactionExecutor {
             result.stack_outputs().clone(),
             host,
             account_codes,
+            !self.deterministic,
+        )
+    }
+
+    /// Executes a transaction specified by the provided arguments without producing the data
+    /// required to prove it, and returns a [TransactionSimulation].
+    ///
+    /// The transaction is executed exactly like in [Self::execute_transaction], but neither the
+    /// execution trace nor the advice witness are recorded. This makes simulation suitable for
+    /// cheap preflight checks of the effects and costs of a transaction.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If required data can not be fetched from the [DataStore].
+    /// - If an entry in the advice map of the transaction args collides with an entry reserved by
+    ///   the transaction kernel.
+    /// - If the transaction host can not be created from the provided values.
+    /// - If the execution of the transaction fails.
+    /// - If the outputs of the transaction are inconsistent.
+    #[maybe_async]
+    pub fn simulate(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: &[NoteId],
+        tx_args: TransactionArgs,
+    ) -> Result<TransactionSimulation, TransactionExecutorError> {
+        let tx_inputs =
+            maybe_await!(self.data_store.get_transaction_inputs(account_id, block_ref, notes))
+                .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?
+                .with_input_note_order(notes);
+
+        if let Some(key) = self.kernel.find_advice_map_collision(&tx_inputs, &tx_args) {
+            return Err(TransactionExecutorError::AdviceMapKeyCollision(key));
+        }
+        check_kernel_limits(&tx_inputs)?;
+
+        let (stack_inputs, advice_inputs) = self.kernel.prepare_inputs(&tx_inputs, &tx_args, None);
+        self.check_advice_size(&advice_inputs)?;
+        let advice_provider: MemAdviceProvider = advice_inputs.into();
+
+        // load note script MAST into the MAST store
+        self.load_transaction_code(&tx_inputs, &tx_args);
+
+        let mut host = TransactionHost::new(
+            tx_inputs.account().into(),
+            advice_provider,
+            self.mast_store.clone(),
+            self.host_authenticator(),
+            self.account_codes.iter().map(|code| code.commitment()).collect(),
         )
+        .map_err(TransactionExecutorError::TransactionHostCreationFailed)?;
+
+        // execute the transaction kernel without building the execution trace
+        let program = self.kernel.main();
+        let mut process = Process::new(program.kernel().clone(), stack_inputs, self.exec_options);
+        let stack_outputs = process
+            .execute(&program, &mut host)
+            .map_err(|err| map_execution_error(err, &host))?;
+
+        // measure the length of the trace which would have been built for the execution
+        let (system, _, _, range, chiplets) = process.into_parts();
+        let trace_len_summary = TraceLenSummary::new(
+            system.clk().into(),
+            range.get_number_range_checker_rows(),
+            ChipletsLengths::new(&chiplets),
+        );
+
+        let (advice_provider, account_delta, output_notes, _, tx_progress) = host.into_parts();
+        let (_, map, _) = advice_provider.into_parts();
+        let tx_outputs =
+            build_tx_outputs(&tx_inputs, &stack_outputs, map.into(), output_notes, &account_delta)?;
+
+        Ok(TransactionSimulation::new(
+            tx_outputs,
+            account_delta,
+            build_measurements(tx_progress, !self.deterministic),
+            trace_len_summary,
+        ))
+    }
+
+    /// Executes a transaction specified by the provided arguments in debug mode and returns a
+    /// [TransactionDebugReport] with a snapshot of the VM state for every [Breakpoint] hit.
+    ///
+    /// Breakpoints can be set on the kernel procedures listed in
+    /// [`TransactionKernel::PROCEDURES`] and on source locations of MASM code compiled in debug
+    /// mode. Execution in debug mode is considerably slower than regular execution and
+    /// is intended for debugging custom scripts only.
+    ///
+    /// A failure of the transaction program does not result in an error, but is recorded in the
+    /// returned report together with the breakpoint hits up to the point of failure.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If required data can not be fetched from the [DataStore].
+    /// - If an entry in the advice map of the transaction args collides with an entry reserved by
+    ///   the transaction kernel.
+    /// - If the transaction host can not be created from the provided values.
+    #[maybe_async]
+    pub fn debug_transaction(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: &[NoteId],
+        tx_args: TransactionArgs,
+        breakpoints: &[Breakpoint],
+    ) -> Result<TransactionDebugReport, TransactionExecutorError> {
+        let tx_inputs =
+            maybe_await!(self.data_store.get_transaction_inputs(account_id, block_ref, notes))
+                .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?
+                .with_input_note_order(notes);
+
+        if let Some(key) = self.kernel.find_advice_map_collision(&tx_inputs, &tx_args) {
+            return Err(TransactionExecutorError::AdviceMapKeyCollision(key));
+        }
+        check_kernel_limits(&tx_inputs)?;
+
+        let (stack_inputs, advice_inputs) = self.kernel.prepare_inputs(&tx_inputs, &tx_args, None);
+        self.check_advice_size(&advice_inputs)?;
+        let advice_provider: MemAdviceProvider = advice_inputs.into();
+
+        // load note script MAST into the MAST store
+        self.load_transaction_code(&tx_inputs, &tx_args);
+
+        let mut host = TransactionHost::new(
+            tx_inputs.account().into(),
+            advice_provider,
+            self.mast_store.clone(),
+            self.host_authenticator(),
+            self.account_codes.iter().map(|code| code.commitment()).collect(),
+        )
+        .map_err(TransactionExecutorError::TransactionHostCreationFailed)?;
+
+        // execute the transaction kernel while recording the state of the VM at every cycle
+        let program = self.kernel.main();
+        let states = vm_processor::execute_iter(&program, stack_inputs, &mut host);
+
+        Ok(debugger::collect_breakpoint_hits(states, breakpoints))
     }
 
     // SCRIPT EXECUTION
@@ -209,37 +718,159 @@ impl TransactionExecutor {
         let tx_args = TransactionArgs::new(Some(tx_script.clone()), None, Default::default());
 
         let (stack_inputs, advice_inputs) =
-            TransactionKernel::prepare_inputs(&tx_inputs, &tx_args, Some(advice_inputs));
+            self.kernel.prepare_inputs(&tx_inputs, &tx_args, Some(advice_inputs));
+        self.check_advice_size(&advice_inputs)?;
         let advice_recorder: RecAdviceProvider = advice_inputs.into();
 
         // load transaction script MAST into the MAST store
-        self.mast_store.load_transaction_code(&tx_inputs, &tx_args);
+        self.load_transaction_code(&tx_inputs, &tx_args);
 
         let mut host = TransactionHost::new(
             tx_inputs.account().into(),
             advice_recorder,
             self.mast_store.clone(),
-            self.authenticator.clone(),
+            self.host_authenticator(),
             self.account_codes.iter().map(|code| code.commitment()).collect(),
         )
         .map_err(TransactionExecutorError::TransactionHostCreationFailed)?;
 
         let mut process = Process::new(
-            TransactionKernel::tx_script_main().kernel().clone(),
+            self.kernel.tx_script_main().kernel().clone(),
             stack_inputs,
             self.exec_options,
         );
         let stack_outputs = process
-            .execute(&TransactionKernel::tx_script_main(), &mut host)
-            .map_err(TransactionExecutorError::TransactionProgramExecutionFailed)?;
+            .execute(&self.kernel.tx_script_main(), &mut host)
+            .map_err(|err| map_execution_error(err, &host))?;
 
         Ok(*stack_outputs)
     }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the authenticator to be used by the transaction host, which is `None` in
+    /// deterministic mode.
+    fn host_authenticator(&self) -> Option<Arc<dyn TransactionAuthenticator>> {
+        if self.deterministic {
+            return None;
+        }
+
+        self.authenticator.clone()
+    }
+
+    /// Loads the code required for executing a transaction with the specified inputs and args into
+    /// the MAST store, skipping the scripts loaded by previous executions if warm starts are
+    /// enabled.
+    ///
+    /// The code of the foreign accounts loaded into this executor is loaded again as well, so that
+    /// it is not evicted from the account code cache of the MAST store while it may be needed.
+    fn load_transaction_code(&self, tx_inputs: &TransactionInputs, tx_args: &TransactionArgs) {
+        for code in self.account_codes.iter() {
+            self.mast_store.load_account_code(code);
+        }
+
+        let Some(loaded_scripts) = &self.loaded_scripts else {
+            self.mast_store.load_transaction_code(tx_inputs, tx_args);
+            return;
+        };
+
+        self.mast_store.load_account_code(tx_inputs.account().code());
+
+        let note_scripts = tx_inputs.input_notes().iter().map(|note| note.note().script());
+        let note_scripts = note_scripts.map(|script| (script.root(), script.mast()));
+        let tx_script = tx_args.tx_script().map(|script| (script.root(), script.mast()));
+        for (root, mast) in note_scripts.chain(tx_script) {
+            if loaded_scripts.read().contains(&root) {
+                continue;
+            }
+
+            self.mast_store.insert(mast);
+            loaded_scripts.write().insert(root);
+        }
+    }
+
+    /// Checks that the size of the provided advice inputs does not exceed the advice size limit of
+    /// this executor, if any.
+    fn check_advice_size(
+        &self,
+        advice_inputs: &AdviceInputs,
+    ) -> Result<(), TransactionExecutorError> {
+        let Some(max_size) = self.max_advice_size else {
+            return Ok(());
+        };
+
+        let size = advice_inputs_size(advice_inputs);
+        if size > max_size {
+            return Err(TransactionExecutorError::AdviceInputsSizeExceeded { size, max_size });
+        }
+
+        Ok(())
+    }
 }
 
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Checks that the provided transaction inputs are within the limits of the transaction kernel.
+///
+/// The kernel limits can be lowered at build time (see [`TransactionKernel::MAX_NUM_STORAGE_SLOTS`]
+/// and related constants), while the objects of `miden-objects` are validated against the default
+/// limits. Inputs which are valid objects may thus exceed the limits of a kernel built with custom
+/// limits, which is checked here so that such transactions fail before execution. The limits on
+/// the outputs of a transaction are enforced by the kernel itself.
+fn check_kernel_limits(tx_inputs: &TransactionInputs) -> Result<(), TransactionExecutorError> {
+    let num_slots = tx_inputs.account().storage().slots().len();
+    if num_slots > TransactionKernel::MAX_NUM_STORAGE_SLOTS {
+        return Err(TransactionExecutorError::StorageSlotsExceedKernelLimit {
+            num_slots,
+            max: TransactionKernel::MAX_NUM_STORAGE_SLOTS,
+        });
+    }
+
+    for input_note in tx_inputs.input_notes().iter() {
+        let num_assets = input_note.note().assets().num_assets();
+        if num_assets > TransactionKernel::MAX_ASSETS_PER_NOTE {
+            return Err(TransactionExecutorError::NoteAssetsExceedKernelLimit {
+                note_id: input_note.id(),
+                num_assets,
+                max: TransactionKernel::MAX_ASSETS_PER_NOTE,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the size of the provided advice inputs in field elements.
+///
+/// Every entry of the advice map accounts for its key and its values, and every node of the Merkle
+/// store for the two child digests it stores.
+fn advice_inputs_size(advice_inputs: &AdviceInputs) -> usize {
+    // the advice map of the advice inputs can only be iterated over through an advice provider
+    let (_, advice_map, _) = MemAdviceProvider::from(advice_inputs.clone()).into_parts();
+    let map_size: usize = advice_map.values().map(|values| WORD_SIZE + values.len()).sum();
+    let store_size = advice_inputs.merkle_store().num_internal_nodes() * 2 * WORD_SIZE;
+
+    advice_inputs.stack().len() + map_size + store_size
+}
+
+/// Converts an error which occurred while executing the transaction kernel program with the
+/// provided host into a [TransactionExecutorError].
+///
+/// If the transaction required signatures which the authenticator of the host could not generate,
+/// the requests for these signatures are returned instead of the execution error.
+fn map_execution_error<A: AdviceProvider>(
+    err: ExecutionError,
+    host: &TransactionHost<A>,
+) -> TransactionExecutorError {
+    if !host.signing_requests().is_empty() {
+        return TransactionExecutorError::MissingSignatures(host.signing_requests().to_vec());
+    }
+
+    TransactionExecutorError::from_execution_error(err, host.tx_progress().current_stage())
+}
+
 /// Creates a new [ExecutedTransaction] from the provided data.
 fn build_executed_transaction(
     tx_args: TransactionArgs,
@@ -247,6 +878,7 @@ fn build_executed_transaction(
     stack_outputs: StackOutputs,
     host: TransactionHost<RecAdviceProvider>,
     account_codes: Vec<AccountCode>,
+    measure_time: bool,
 ) -> Result<ExecutedTransaction, TransactionExecutorError> {
     let (advice_recorder, account_delta, output_notes, generated_signatures, tx_progress) =
         host.into_parts();
@@ -254,7 +886,47 @@ fn build_executed_transaction(
     let (mut advice_witness, _, map, _store) = advice_recorder.finalize();
 
     let tx_outputs =
-        TransactionKernel::from_transaction_parts(&stack_outputs, &map.into(), output_notes)
+        build_tx_outputs(&tx_inputs, &stack_outputs, map.into(), output_notes, &account_delta)?;
+
+    // introduce generated signatures into the witness inputs
+    advice_witness.extend_map(generated_signatures);
+
+    Ok(ExecutedTransaction::new(
+        tx_inputs,
+        tx_outputs,
+        account_codes,
+        account_delta,
+        tx_args,
+        advice_witness,
+        build_measurements(tx_progress, measure_time),
+    ))
+}
+
+/// Returns the [TransactionMeasurements] of the provided transaction progress, without the
+/// wall-clock timings of the stages if `measure_time` is `false`.
+fn build_measurements(
+    tx_progress: TransactionProgress,
+    measure_time: bool,
+) -> TransactionMeasurements {
+    let mut measurements = TransactionMeasurements::from(tx_progress);
+    if !measure_time {
+        measurements.timings = TransactionTimings::default();
+    }
+
+    measurements
+}
+
+/// Builds the [TransactionOutputs] from the provided data and makes sure they are consistent with
+/// the transaction inputs and the account delta.
+fn build_tx_outputs(
+    tx_inputs: &TransactionInputs,
+    stack_outputs: &StackOutputs,
+    advice_map: AdviceMap,
+    output_notes: Vec<OutputNote>,
+    account_delta: &AccountDelta,
+) -> Result<TransactionOutputs, TransactionExecutorError> {
+    let tx_outputs =
+        TransactionKernel::from_transaction_parts(stack_outputs, &advice_map, output_notes)
             .map_err(TransactionExecutorError::TransactionOutputConstructionFailed)?;
 
     let final_account = &tx_outputs.account;
@@ -284,16 +956,401 @@ fn build_executed_transaction(
         });
     }
 
-    // introduce generated signatures into the witness inputs
-    advice_witness.extend_map(generated_signatures);
+    Ok(tx_outputs)
+}
 
-    Ok(ExecutedTransaction::new(
-        tx_inputs,
-        tx_outputs,
-        account_codes,
-        account_delta,
-        tx_args,
-        advice_witness,
-        tx_progress.into(),
-    ))
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use miden_lib::{
+        account::auth::TwoFactorAuth,
+        errors::tx_kernel_errors::ERR_FOREIGN_ACCOUNT_MAX_NUMBER_EXCEEDED,
+    };
+    use miden_objects::{
+        TransactionArgsError, Word,
+        crypto::{dsa::rpo_falcon512::SecretKey, ecdsa_k256},
+        transaction::AdviceMapNamespace,
+    };
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+    use vm_processor::{
+        MastForestStore, ONE,
+        utils::{Deserializable, Serializable},
+    };
+    use winter_maybe_async::maybe_async_trait;
+
+    use super::*;
+    use crate::{
+        DataStoreError, KernelAssertionError, LocalTransactionProver, TransactionProver,
+        TransactionStage,
+        auth::signatures::get_two_factor_signatures,
+        testing::{Auth, MockChain, TransactionContextBuilder},
+        tests::fixtures::TransactionFixture,
+    };
+
+    #[test]
+    fn executor_consumes_notes_in_specified_order() {
+        let mut fixture = TransactionFixture::with_mock_notes();
+        fixture.note_ids.reverse();
+        assert!(fixture.note_ids.len() > 1);
+
+        let mut tx_args = fixture.tx_args();
+        tx_args.add_note_args(fixture.note_ids[0], [ONE, ONE, ONE, ONE]);
+        assert_eq!(tx_args.get_note_args(fixture.note_ids[0]), Some(&[ONE, ONE, ONE, ONE]));
+
+        let executed_transaction = fixture.execute(&fixture.executor(), tx_args).unwrap();
+
+        let consumed_note_ids: Vec<_> =
+            executed_transaction.input_notes().iter().map(|note| note.id()).collect();
+        assert_eq!(consumed_note_ids, fixture.note_ids);
+    }
+
+    #[test]
+    fn executor_detects_advice_map_key_collisions() {
+        let fixture = TransactionFixture::with_mock_notes();
+        let tx_inputs = fixture.tx_context.tx_inputs();
+
+        // entries within a namespace can be re-inserted only with the same values
+        let namespace = AdviceMapNamespace::new("miden::testing::advice");
+        let key = Digest::from([ONE, ONE, ONE, ONE]);
+        let mut tx_args = fixture.tx_args();
+        let derived_key = tx_args
+            .add_namespaced_advice_map_entry(&namespace, key, vec![ONE, ONE])
+            .unwrap();
+        assert_eq!(derived_key, namespace.derive_key(key));
+        assert_ne!(derived_key, key);
+        assert_eq!(
+            tx_args
+                .add_namespaced_advice_map_entry(&namespace, key, vec![ONE, ONE])
+                .unwrap(),
+            derived_key
+        );
+        assert_matches!(
+            tx_args.add_namespaced_advice_map_entry(&namespace, key, vec![ONE]),
+            Err(TransactionArgsError::AdviceMapKeyCollision { derived_key: collision_key, .. })
+                if collision_key == derived_key
+        );
+        assert_eq!(TransactionKernel::find_advice_map_collision(tx_inputs, &tx_args), None);
+
+        // overriding the storage slots of the account which the kernel puts into the advice map
+        // fails
+        let storage_commitment = tx_inputs.account().storage().commitment();
+        tx_args.extend_advice_map([(storage_commitment, vec![ONE])]);
+        assert_eq!(
+            TransactionKernel::find_advice_map_collision(tx_inputs, &tx_args),
+            Some(storage_commitment)
+        );
+
+        let error = fixture.execute(&fixture.executor(), tx_args).unwrap_err();
+        assert_matches!(
+            error,
+            TransactionExecutorError::AdviceMapKeyCollision(key) if key == storage_commitment
+        );
+    }
+
+    #[test]
+    fn rebased_transaction_uses_newer_reference_block() {
+        /// A data store which serves the transaction inputs for several reference blocks.
+        struct MultiBlockDataStore(BTreeMap<BlockNumber, TransactionInputs>);
+
+        #[maybe_async_trait]
+        impl DataStore for MultiBlockDataStore {
+            #[maybe_async]
+            fn get_transaction_inputs(
+                &self,
+                _account_id: AccountId,
+                block_ref: BlockNumber,
+                _notes: &[NoteId],
+            ) -> Result<TransactionInputs, DataStoreError> {
+                self.0.get(&block_ref).cloned().ok_or(DataStoreError::BlockNotFound(block_ref))
+            }
+        }
+
+        let mut mock_chain = MockChain::new();
+        let stale = TransactionFixture::with_p2id_notes(&mut mock_chain, 1);
+        mock_chain.seal_next_block();
+        mock_chain.seal_next_block();
+        let fresh = TransactionFixture::new(
+            mock_chain.build_tx_context(stale.account_id, &stale.note_ids, &[]).build(),
+        );
+
+        let data_store = MultiBlockDataStore(BTreeMap::from([
+            (stale.block_ref, stale.tx_context.tx_inputs().clone()),
+            (fresh.block_ref, fresh.tx_context.tx_inputs().clone()),
+        ]));
+        let executor =
+            TransactionExecutor::new(Arc::new(data_store), stale.tx_context.authenticator());
+
+        let stale_tx = stale.execute(&executor, stale.tx_args()).unwrap();
+        let rebased_tx = executor.rebase_transaction(&stale_tx, fresh.block_ref).unwrap();
+
+        assert_eq!(rebased_tx.block_header(), fresh.tx_context.tx_inputs().block_header());
+        assert_eq!(rebased_tx.initial_account(), stale_tx.initial_account());
+        assert_eq!(rebased_tx.tx_args().tx_script(), stale_tx.tx_args().tx_script());
+        assert_eq!(
+            rebased_tx.input_notes().iter().map(|note| note.id()).collect::<Vec<_>>(),
+            stale.note_ids
+        );
+        assert_eq!(rebased_tx.final_account(), stale_tx.final_account());
+        assert_eq!(rebased_tx.output_notes(), stale_tx.output_notes());
+        // the transaction ID does not commit to the reference block, so it is unaffected by
+        // rebasing
+        assert_eq!(rebased_tx.id(), stale_tx.id());
+
+        // a transaction cannot be rebased onto its own or an older reference block
+        assert_matches!(
+            executor.rebase_transaction(&rebased_tx, stale.block_ref),
+            Err(TransactionExecutorError::InvalidRebaseBlock { original, requested })
+                if original == fresh.block_ref && requested == stale.block_ref
+        );
+    }
+
+    #[test]
+    fn kernel_assertion_failures_are_mapped_to_typed_errors() {
+        let fixture =
+            TransactionFixture::new(TransactionContextBuilder::with_standard_account(ONE).build());
+
+        // the script changes the account state without incrementing the nonce
+        let tx_script = TransactionScript::compile(
+            "
+            use.test::account
+            begin
+                push.91.92.93.94 push.0
+                call.account::set_item dropw dropw
+            end
+            ",
+            [],
+            TransactionKernel::testing_assembler_with_mock_account(),
+        )
+        .unwrap();
+        let tx_args = TransactionArgs::new(
+            Some(tx_script),
+            None,
+            fixture.tx_context.tx_args().advice_inputs().clone().map,
+        );
+
+        let err = fixture
+            .execute(&fixture.executor().with_tracing(), tx_args.clone())
+            .unwrap_err();
+        assert_matches!(
+            &err,
+            TransactionExecutorError::TransactionKernelAssertionFailed {
+                error: KernelAssertionError::NonceNotIncremented,
+                stage: Some(TransactionStage::Epilogue),
+                ..
+            }
+        );
+        assert_matches!(err.execution_error(), Some(ExecutionError::FailedAssertion { .. }));
+
+        // without tracing, the stage in which the assertion failed is unknown
+        let err = fixture.execute(&fixture.executor(), tx_args).unwrap_err();
+        assert_matches!(
+            &err,
+            TransactionExecutorError::TransactionKernelAssertionFailed {
+                error: KernelAssertionError::NonceNotIncremented,
+                stage: None,
+                ..
+            }
+        );
+
+        // error codes which are not raised by the transaction kernel are not mapped
+        assert_eq!(KernelAssertionError::from_err_code(0x0001_0000), None);
+        assert_eq!(
+            KernelAssertionError::from_err_code(ERR_FOREIGN_ACCOUNT_MAX_NUMBER_EXCEEDED),
+            Some(KernelAssertionError::Other {
+                err_code: ERR_FOREIGN_ACCOUNT_MAX_NUMBER_EXCEEDED,
+                message: "Maximum allowed number of foreign account to be loaded (64) was exceeded.",
+            })
+        );
+    }
+
+    #[test]
+    fn missing_signatures_are_returned_as_signing_requests() {
+        let mut mock_chain = MockChain::new();
+        let account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+        let fixture =
+            TransactionFixture::new(mock_chain.build_tx_context(account.id(), &[], &[]).build());
+        let mut tx_args = fixture.tx_args();
+
+        // the executor does not hold the signing key of the account
+        let executor = TransactionExecutor::new(fixture.tx_context.get_data_store(), None);
+        let err = fixture.execute(&executor, tx_args.clone()).unwrap_err();
+        let TransactionExecutorError::MissingSignatures(signing_requests) = err else {
+            panic!("unexpected error: {err}")
+        };
+        assert_eq!(signing_requests.len(), 1);
+
+        // sign the request on a separate device, using the mock authenticator of the account
+        let signing_request =
+            SigningRequest::read_from_bytes(&signing_requests[0].to_bytes()).unwrap();
+        let signature = fixture
+            .tx_context
+            .authenticator()
+            .unwrap()
+            .get_signature(
+                signing_request.pub_key(),
+                signing_request.message(),
+                signing_request.account_delta(),
+            )
+            .unwrap();
+
+        tx_args.extend_advice_map([(signing_request.signature_key(), signature)]);
+        let executed_transaction = fixture.execute(&executor, tx_args).unwrap();
+        assert_eq!(executed_transaction.account_delta().nonce(), Some(account.nonce() + ONE));
+    }
+
+    #[test]
+    fn executor_enforces_resource_limits() {
+        let fixture = TransactionFixture::with_mock_notes();
+
+        assert_matches!(
+            fixture.executor().with_max_cycles(0).err(),
+            Some(TransactionExecutorError::InvalidMaxCycles(0))
+        );
+
+        let executor = fixture.executor().with_max_cycles(MIN_TX_EXECUTION_CYCLES).unwrap();
+        let err = fixture.execute(&executor, fixture.tx_args()).unwrap_err();
+        assert_matches!(
+            err,
+            TransactionExecutorError::CycleLimitExceeded(max_cycles)
+                if max_cycles == MIN_TX_EXECUTION_CYCLES
+        );
+
+        let executor = fixture.executor().with_max_advice_size(1000);
+        let err = fixture.execute(&executor, fixture.tx_args()).unwrap_err();
+        assert_matches!(
+            err,
+            TransactionExecutorError::AdviceInputsSizeExceeded { size, max_size: 1000 }
+                if size > 1000
+        );
+
+        // the transaction executes within sufficient limits
+        let executor = fixture
+            .executor()
+            .with_max_cycles(1 << 20)
+            .unwrap()
+            .with_max_advice_size(1 << 20);
+        fixture.execute(&executor, fixture.tx_args()).unwrap();
+    }
+
+    #[test]
+    fn two_phase_signing_finalizes_transaction() {
+        let mut mock_chain = MockChain::new();
+        let account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+        let fixture =
+            TransactionFixture::new(mock_chain.build_tx_context(account.id(), &[], &[]).build());
+
+        // the executor does not hold the signing key of the account
+        let executor = TransactionExecutor::new(fixture.tx_context.get_data_store(), None);
+        let manifest = executor
+            .prepare_signing(fixture.account_id, fixture.block_ref, &[], fixture.tx_args())
+            .unwrap();
+        assert_eq!(manifest.initial_account_commitment(), account.commitment());
+        assert_eq!(manifest.signing_requests().len(), 1);
+
+        // the manifest is handed to an offline signer holding the mock authenticator of the account
+        let manifest = SigningManifest::read_from_bytes(&manifest.to_bytes()).unwrap();
+        let signing_request = &manifest.signing_requests()[0];
+        let signature = fixture
+            .tx_context
+            .authenticator()
+            .unwrap()
+            .get_signature(
+                signing_request.pub_key(),
+                signing_request.message(),
+                signing_request.account_delta(),
+            )
+            .unwrap();
+
+        // finalizing without the signature fails
+        let err = executor.finalize_signing(manifest.clone(), []).unwrap_err();
+        assert_matches!(err, TransactionExecutorError::MissingSignatures(requests) if requests.len() == 1);
+
+        let executed_transaction = executor
+            .finalize_signing(manifest.clone(), [(signing_request.signature_key(), signature)])
+            .unwrap();
+        assert_eq!(executed_transaction.account_delta().nonce(), Some(account.nonce() + ONE));
+        LocalTransactionProver::default().prove(executed_transaction.into()).unwrap();
+    }
+
+    #[test]
+    fn two_phase_signing_collects_all_two_factor_signing_requests() {
+        let mut rng = ChaCha20Rng::from_seed([5; 32]);
+        let falcon_key = SecretKey::with_rng(&mut rng);
+        let ecdsa_key = ecdsa_k256::SecretKey::from_bytes(&[7; 32]).unwrap();
+
+        let fixture = TransactionFixture::with_auth_component(
+            &mut MockChain::new(),
+            TwoFactorAuth::new(falcon_key.public_key(), ecdsa_key.public_key().commitment()),
+            "::miden::contracts::auth::two_factor::auth_tx_two_factor",
+        );
+        let nonce = fixture.tx_context.account().nonce();
+
+        // the manifest lists both signatures required by the two-factor auth procedure
+        let executor = TransactionExecutor::new(fixture.tx_context.get_data_store(), None);
+        let manifest = executor
+            .prepare_signing(fixture.account_id, fixture.block_ref, &[], fixture.tx_args())
+            .unwrap();
+        let pub_keys: Vec<Word> =
+            manifest.signing_requests().iter().map(|request| request.pub_key()).collect();
+        assert_eq!(pub_keys, [falcon_key.public_key().into(), ecdsa_key.public_key().commitment()]);
+
+        let message = manifest.signing_requests()[0].message();
+        let signatures =
+            get_two_factor_signatures(&falcon_key, &ecdsa_key, message, &mut rng).unwrap();
+        let executed_transaction = executor.finalize_signing(manifest, signatures).unwrap();
+        assert_eq!(executed_transaction.account_delta().nonce(), Some(nonce + ONE));
+    }
+
+    #[test]
+    fn warm_started_executor_skips_loading_known_scripts() {
+        let tx_script = TransactionScript::compile(
+            "begin push.1 drop end",
+            [],
+            TransactionKernel::testing_assembler(),
+        )
+        .unwrap();
+        // a deserialized copy of the script has the same root but its own MAST forest
+        let tx_script_copy = TransactionScript::read_from_bytes(&tx_script.to_bytes()).unwrap();
+        assert!(!Arc::ptr_eq(&tx_script.mast(), &tx_script_copy.mast()));
+
+        let fixture_with_script = |tx_script: &TransactionScript| {
+            TransactionFixture::new(
+                TransactionContextBuilder::with_standard_account(ONE)
+                    .with_mock_notes_preserved()
+                    .tx_script(tx_script.clone())
+                    .build(),
+            )
+        };
+        let fixture = fixture_with_script(&tx_script);
+        let tx_args = [fixture.tx_args(), fixture_with_script(&tx_script_copy).tx_args()];
+
+        // without warm starts, the scripts of every execution are registered anew
+        let cold_executor = fixture.executor();
+        let cold_tx = fixture.execute(&cold_executor, tx_args[0].clone()).unwrap();
+        fixture.execute(&cold_executor, tx_args[1].clone()).unwrap();
+
+        let registered_forest = cold_executor.mast_store.get(&tx_script.root()).unwrap();
+        assert!(Arc::ptr_eq(&registered_forest, &tx_script_copy.mast()));
+
+        let warm_executor = fixture.executor().with_warm_start();
+        for tx_args in tx_args {
+            let warm_tx = fixture.execute(&warm_executor, tx_args).unwrap();
+            assert_eq!(warm_tx.id(), cold_tx.id());
+            assert_eq!(warm_tx.account_delta(), cold_tx.account_delta());
+        }
+
+        // with warm starts, the scripts loaded by the first execution are retained and the second
+        // execution skips loading them
+        let loaded_scripts = warm_executor.loaded_scripts.as_ref().unwrap().read().clone();
+        assert!(loaded_scripts.contains(&tx_script.root()));
+        for note in fixture.tx_context.input_notes().iter() {
+            assert!(loaded_scripts.contains(&note.note().script().root()));
+        }
+        let registered_forest = warm_executor.mast_store.get(&tx_script.root()).unwrap();
+        assert!(Arc::ptr_eq(&registered_forest, &tx_script.mast()));
+    }
 }
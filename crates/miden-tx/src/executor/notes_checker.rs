@@ -0,0 +1,129 @@
+use miden_objects::{
+    account::AccountId, block::BlockNumber, note::NoteId, transaction::TransactionArgs,
+};
+use vm_processor::{ExecutionError, MemAdviceProvider, Process};
+use winter_maybe_async::{maybe_async, maybe_await};
+
+use super::{TransactionExecutor, map_execution_error};
+use crate::{TransactionExecutorError, TransactionHost, TransactionStage};
+
+// NOTE CONSUMABILITY
+// ================================================================================================
+
+/// Describes whether a note can currently be consumed by an account.
+#[derive(Debug)]
+pub enum NoteConsumability {
+    /// The note can be consumed by the account.
+    Consumable,
+    /// The note cannot be consumed by the account. The contained error describes the reason, e.g.
+    /// the failed assertion of the note script together with its error message.
+    NotConsumable(ExecutionError),
+}
+
+impl NoteConsumability {
+    /// Returns `true` if the note can be consumed by the account.
+    pub fn is_consumable(&self) -> bool {
+        matches!(self, Self::Consumable)
+    }
+}
+
+// NOTE CONSUMPTION CHECKER
+// ================================================================================================
+
+/// Checks whether notes can currently be consumed by an account.
+///
+/// The check is performed by simulating a transaction against the account which consumes only the
+/// note in question, i.e. by executing it without recording the data required to prove it. The
+/// transaction is not signed: the authenticator of the executor is not used, and signatures
+/// required by the account are neither generated nor required to be present.
+///
+/// The note is considered consumable if the transaction executes successfully, or if it fails only
+/// after the note was processed because of missing signatures. This makes the checker suitable for
+/// building "consumable notes" views in wallets without having to reason about the semantics of
+/// individual note scripts.
+pub struct NoteConsumptionChecker<'a>(&'a TransactionExecutor);
+
+impl<'a> NoteConsumptionChecker<'a> {
+    /// Creates a new [NoteConsumptionChecker] backed by the provided [TransactionExecutor].
+    ///
+    /// The data store of the executor must be able to provide the transaction inputs for the
+    /// checked accounts and notes.
+    pub fn new(tx_executor: &'a TransactionExecutor) -> Self {
+        NoteConsumptionChecker(tx_executor)
+    }
+
+    /// Checks whether the note with the provided ID can be consumed by the specified account
+    /// against the state at the specified reference block.
+    ///
+    /// The provided transaction arguments are used to execute the transaction, so they should
+    /// contain the note arguments and the transaction script (e.g. an authentication script)
+    /// which would be used to actually consume the note.
+    ///
+    /// Failures during the processing of the note, e.g. failed assertions of the note script, are
+    /// reported as [NoteConsumability::NotConsumable].
+    ///
+    /// # Errors
+    /// Returns an error if the transaction could not be executed for reasons unrelated to the
+    /// consumability of the note, e.g. if:
+    /// - the required data could not be fetched from the data store.
+    /// - the transaction host could not be created.
+    /// - the execution failed outside of the processing of the note, e.g. in the prologue or in the
+    ///   transaction script, for reasons other than missing signatures.
+    #[maybe_async]
+    pub fn check_note_consumability(
+        &self,
+        target_account_id: AccountId,
+        block_ref: BlockNumber,
+        note_id: NoteId,
+        tx_args: TransactionArgs,
+    ) -> Result<NoteConsumability, TransactionExecutorError> {
+        let executor = self.0;
+
+        let tx_inputs = maybe_await!(executor.data_store.get_transaction_inputs(
+            target_account_id,
+            block_ref,
+            &[note_id]
+        ))
+        .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
+
+        if let Some(key) = executor.kernel.find_advice_map_collision(&tx_inputs, &tx_args) {
+            return Err(TransactionExecutorError::AdviceMapKeyCollision(key));
+        }
+
+        let (stack_inputs, advice_inputs) =
+            executor.kernel.prepare_inputs(&tx_inputs, &tx_args, None);
+        executor.check_advice_size(&advice_inputs)?;
+        let advice_provider: MemAdviceProvider = advice_inputs.into();
+
+        // load note script MAST into the MAST store
+        executor.load_transaction_code(&tx_inputs, &tx_args);
+
+        // the host is created without an authenticator, so that the transaction is never signed
+        let mut host = TransactionHost::new(
+            tx_inputs.account().into(),
+            advice_provider,
+            executor.mast_store.clone(),
+            None,
+            executor.account_codes.iter().map(|code| code.commitment()).collect(),
+        )
+        .map_err(TransactionExecutorError::TransactionHostCreationFailed)?;
+
+        // execute the transaction kernel without building the execution trace; tracing is needed
+        // to determine the stage in which the execution failed
+        let program = executor.kernel.main();
+        let exec_options = executor.exec_options.with_tracing();
+        let mut process = Process::new(program.kernel().clone(), stack_inputs, exec_options);
+        let Err(err) = process.execute(&program, &mut host) else {
+            return Ok(NoteConsumability::Consumable);
+        };
+
+        match host.tx_progress().current_stage() {
+            Some(TransactionStage::NotesProcessing | TransactionStage::NoteExecution(_)) => {
+                Ok(NoteConsumability::NotConsumable(err))
+            },
+            // signatures are only requested after all notes have been processed
+            _ if !host.signing_requests().is_empty() => Ok(NoteConsumability::Consumable),
+            _ => Err(map_execution_error(err, &host)),
+        }
+    }
+}
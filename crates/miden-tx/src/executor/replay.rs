@@ -0,0 +1,201 @@
+#[cfg(feature = "async")]
+use alloc::boxed::Box;
+use alloc::{sync::Arc, vec::Vec};
+
+use miden_objects::{
+    account::{AccountCode, AccountId},
+    block::BlockNumber,
+    note::NoteId,
+    transaction::{ExecutedTransaction, TransactionArgs, TransactionInputs},
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
+use winter_maybe_async::*;
+
+use super::{DataStore, TransactionExecutor};
+use crate::{DataStoreError, TransactionExecutorError};
+
+// TRANSACTION REPLAY BUNDLE
+// ================================================================================================
+
+/// All inputs required to replay the execution of a transaction, e.g. to reproduce a failing
+/// transaction on another machine.
+///
+/// A bundle is created via [`TransactionExecutor::build_replay_bundle`] and replayed via
+/// [`Self::replay`], which executes the transaction in deterministic mode (see
+/// [`TransactionExecutor::with_deterministic_execution`]). Replaying a bundle thus always yields
+/// the same result, as long as all signatures required by the transaction are included in the
+/// advice map of its transaction args.
+///
+/// A bundle can also be used as the [DataStore] of an executor configured differently, e.g. with
+/// debug mode enabled. In this case, the foreign account codes of the bundle must be loaded into
+/// the executor via [`TransactionExecutor::load_account_code`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionReplayBundle {
+    tx_inputs: TransactionInputs,
+    tx_args: TransactionArgs,
+    foreign_account_codes: Vec<AccountCode>,
+}
+
+impl TransactionReplayBundle {
+    /// Returns a new [TransactionReplayBundle] instantiated from the provided data.
+    pub fn new(
+        tx_inputs: TransactionInputs,
+        tx_args: TransactionArgs,
+        foreign_account_codes: Vec<AccountCode>,
+    ) -> Self {
+        Self {
+            tx_inputs,
+            tx_args,
+            foreign_account_codes,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the inputs of the transaction.
+    pub fn tx_inputs(&self) -> &TransactionInputs {
+        &self.tx_inputs
+    }
+
+    /// Returns the arguments of the transaction.
+    pub fn tx_args(&self) -> &TransactionArgs {
+        &self.tx_args
+    }
+
+    /// Returns the codes of the foreign accounts whose procedures may be invoked by the
+    /// transaction.
+    pub fn foreign_account_codes(&self) -> &[AccountCode] {
+        &self.foreign_account_codes
+    }
+
+    /// Returns the IDs of the input notes of the transaction, in the order they are consumed.
+    pub fn note_ids(&self) -> Vec<NoteId> {
+        self.tx_inputs.input_notes().iter().map(|note| note.id()).collect()
+    }
+
+    // REPLAY
+    // --------------------------------------------------------------------------------------------
+
+    /// Executes the transaction of this bundle in deterministic mode and returns the resulting
+    /// [ExecutedTransaction].
+    ///
+    /// # Errors
+    /// Returns the error the execution of the transaction fails with.
+    #[maybe_async]
+    pub fn replay(&self) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        let mut executor =
+            TransactionExecutor::new(Arc::new(self.clone()), None).with_deterministic_execution();
+        for code in self.foreign_account_codes.iter() {
+            executor.load_account_code(code);
+        }
+
+        maybe_await!(executor.execute_transaction(
+            self.tx_inputs.account().id(),
+            self.tx_inputs.block_header().block_num(),
+            &self.note_ids(),
+            self.tx_args.clone(),
+        ))
+    }
+}
+
+#[maybe_async_trait]
+impl DataStore for TransactionReplayBundle {
+    #[maybe_async]
+    fn get_transaction_inputs(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: &[NoteId],
+    ) -> Result<TransactionInputs, DataStoreError> {
+        if account_id != self.tx_inputs.account().id() {
+            return Err(DataStoreError::AccountNotFound(account_id));
+        }
+
+        if block_ref != self.tx_inputs.block_header().block_num() {
+            return Err(DataStoreError::BlockNotFound(block_ref));
+        }
+
+        let note_ids = self.note_ids();
+        if let Some(note_id) = notes.iter().find(|note_id| !note_ids.contains(note_id)) {
+            return Err(DataStoreError::NoteNotFound(*note_id));
+        }
+
+        Ok(self.tx_inputs.clone())
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for TransactionReplayBundle {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.tx_inputs.write_into(target);
+        self.tx_args.write_into(target);
+        self.foreign_account_codes.write_into(target);
+    }
+}
+
+impl Deserializable for TransactionReplayBundle {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            tx_inputs: TransactionInputs::read_from(source)?,
+            tx_args: TransactionArgs::read_from(source)?,
+            foreign_account_codes: Vec::<AccountCode>::read_from(source)?,
+        })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_objects::transaction::TransactionTimings;
+
+    use super::*;
+    use crate::{
+        testing::{Auth, MockChain},
+        tests::fixtures::TransactionFixture,
+    };
+
+    #[test]
+    fn deterministic_execution_can_be_replayed_from_bundle() {
+        let mut mock_chain = MockChain::new();
+        let account = mock_chain.add_existing_wallet(Auth::BasicAuth, Vec::new());
+        let fixture =
+            TransactionFixture::new(mock_chain.build_tx_context(account.id(), &[], &[]).build());
+        let mut tx_args = fixture.tx_args();
+
+        // in deterministic mode, signatures are not requested from the authenticator
+        let executor = fixture.executor().with_deterministic_execution();
+        let err = fixture.execute(&executor, tx_args.clone()).unwrap_err();
+        let TransactionExecutorError::MissingSignatures(signing_requests) = err else {
+            panic!("unexpected error: {err}")
+        };
+
+        let signature = fixture
+            .tx_context
+            .authenticator()
+            .unwrap()
+            .get_signature(
+                signing_requests[0].pub_key(),
+                signing_requests[0].message(),
+                signing_requests[0].account_delta(),
+            )
+            .unwrap();
+        tx_args.extend_advice_map([(signing_requests[0].signature_key(), signature)]);
+
+        let executed_transaction = fixture.execute(&executor, tx_args.clone()).unwrap();
+        assert_eq!(executed_transaction.measurements().timings, TransactionTimings::default());
+
+        // replaying the transaction from its serialized bundle yields a bit-for-bit identical
+        // result
+        let bundle = executor
+            .build_replay_bundle(fixture.account_id, fixture.block_ref, &[], tx_args)
+            .unwrap();
+        let bundle = TransactionReplayBundle::read_from_bytes(&bundle.to_bytes()).unwrap();
+        let replayed_transaction = bundle.replay().unwrap();
+        assert_eq!(replayed_transaction.to_bytes(), executed_transaction.to_bytes());
+    }
+}
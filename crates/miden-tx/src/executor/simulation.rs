@@ -0,0 +1,112 @@
+use miden_objects::{
+    account::{AccountDelta, AccountHeader},
+    transaction::{OutputNotes, TransactionMeasurements, TransactionOutputs},
+};
+use vm_processor::TraceLenSummary;
+
+use super::TransactionCost;
+
+// TRANSACTION SIMULATION
+// ================================================================================================
+
+/// The result of simulating a transaction via
+/// [`TransactionExecutor::simulate`](super::TransactionExecutor::simulate).
+///
+/// Unlike an [ExecutedTransaction](miden_objects::transaction::ExecutedTransaction), a simulation
+/// does not contain the witness required to prove the transaction, which makes it cheap to produce.
+/// It is intended for preflight checks, e.g. to show the effects of a transaction to a user before
+/// executing and proving it.
+#[derive(Debug, Clone)]
+pub struct TransactionSimulation {
+    tx_outputs: TransactionOutputs,
+    account_delta: AccountDelta,
+    measurements: TransactionMeasurements,
+    trace_len_summary: TraceLenSummary,
+}
+
+impl TransactionSimulation {
+    /// Returns a new [TransactionSimulation] instantiated from the provided data.
+    pub(super) fn new(
+        tx_outputs: TransactionOutputs,
+        account_delta: AccountDelta,
+        measurements: TransactionMeasurements,
+        trace_len_summary: TraceLenSummary,
+    ) -> Self {
+        Self {
+            tx_outputs,
+            account_delta,
+            measurements,
+            trace_len_summary,
+        }
+    }
+
+    /// Returns the description of the account after the transaction.
+    pub fn final_account(&self) -> &AccountHeader {
+        &self.tx_outputs.account
+    }
+
+    /// Returns the changes made to the account by the transaction.
+    pub fn account_delta(&self) -> &AccountDelta {
+        &self.account_delta
+    }
+
+    /// Returns the notes created by the transaction.
+    pub fn output_notes(&self) -> &OutputNotes {
+        &self.tx_outputs.output_notes
+    }
+
+    /// Returns the number of cycles and the time spent in the individual stages of the transaction.
+    pub fn measurements(&self) -> &TransactionMeasurements {
+        &self.measurements
+    }
+
+    /// Returns the lengths of the execution trace components which would be built to prove the
+    /// transaction.
+    pub fn trace_len_summary(&self) -> &TraceLenSummary {
+        &self.trace_len_summary
+    }
+
+    /// Returns the exact cost of the simulated transaction.
+    pub fn cost(&self) -> TransactionCost {
+        TransactionCost::from_trace_len_summary(&self.trace_len_summary)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use crate::{testing::MockChain, tests::fixtures::TransactionFixture};
+
+    #[test]
+    fn simulated_transaction_matches_executed_transaction() {
+        let fixture = TransactionFixture::with_p2id_notes(&mut MockChain::new(), 1);
+        let executor = fixture.executor().with_tracing();
+
+        let simulation = executor
+            .simulate(fixture.account_id, fixture.block_ref, &fixture.note_ids, fixture.tx_args())
+            .unwrap();
+        let executed_transaction = fixture.execute(&executor, fixture.tx_args()).unwrap();
+
+        assert_eq!(simulation.final_account(), executed_transaction.final_account());
+        assert_eq!(simulation.account_delta(), executed_transaction.account_delta());
+        assert_eq!(simulation.output_notes(), executed_transaction.output_notes());
+        assert_eq!(
+            simulation.measurements().total_cycles(),
+            executed_transaction.measurements().total_cycles()
+        );
+
+        // both modes measure the time spent in the individual stages
+        for measurements in [simulation.measurements(), executed_transaction.measurements()] {
+            let timings = &measurements.timings;
+            assert!(timings.prologue > Duration::ZERO);
+            assert!(timings.epilogue > Duration::ZERO);
+            assert_eq!(timings.note_execution.len(), measurements.note_execution.len());
+            assert_eq!(timings.note_execution[0].0, fixture.note_ids[0]);
+            assert!(timings.total_time() >= timings.prologue + timings.epilogue);
+        }
+    }
+}
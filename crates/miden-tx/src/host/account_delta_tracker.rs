@@ -1,7 +1,10 @@
+use alloc::collections::BTreeMap;
+
 use miden_objects::{
-    Felt, ZERO,
+    Digest, Felt, Word, ZERO,
     account::{AccountDelta, AccountHeader, AccountStorageDelta, AccountVaultDelta},
 };
+
 // ACCOUNT DELTA TRACKER
 // ================================================================================================
 
@@ -17,7 +20,7 @@ use miden_objects::{
 /// - account code changes.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AccountDeltaTracker {
-    storage: AccountStorageDelta,
+    storage: StorageDeltaTracker,
     vault: AccountVaultDelta,
     init_nonce: Felt,
     nonce_delta: Felt,
@@ -27,7 +30,7 @@ impl AccountDeltaTracker {
     /// Returns a new [AccountDeltaTracker] instantiated for the specified account.
     pub fn new(account: &AccountHeader) -> Self {
         Self {
-            storage: AccountStorageDelta::default(),
+            storage: StorageDeltaTracker::default(),
             vault: AccountVaultDelta::default(),
             init_nonce: account.nonce(),
             nonce_delta: ZERO,
@@ -38,7 +41,8 @@ impl AccountDeltaTracker {
     pub fn into_delta(self) -> AccountDelta {
         let nonce_delta = (self.nonce_delta != ZERO).then_some(self.init_nonce + self.nonce_delta);
 
-        AccountDelta::new(self.storage, self.vault, nonce_delta).expect("invalid account delta")
+        AccountDelta::new(self.storage.into_delta(), self.vault, nonce_delta)
+            .expect("invalid account delta")
     }
 
     /// Tracks nonce delta.
@@ -51,8 +55,67 @@ impl AccountDeltaTracker {
         &mut self.vault
     }
 
-    /// Get a mutable reference to the current storage delta
-    pub fn storage_delta(&mut self) -> &mut AccountStorageDelta {
+    /// Get a mutable reference to the current storage delta tracker
+    pub fn storage_delta(&mut self) -> &mut StorageDeltaTracker {
         &mut self.storage
     }
 }
+
+// STORAGE DELTA TRACKER
+// ================================================================================================
+
+/// Keeps track of changes made to the account storage during transaction execution.
+///
+/// For every updated value slot and storage map entry, the tracker records the value it had before
+/// the first update and the value it has after the latest update. Both values are provided by the
+/// storage-write events of the kernel, which makes it possible to build an exact storage delta:
+/// slots and map entries which were updated but end up holding their initial value are not
+/// included in the delta.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StorageDeltaTracker {
+    /// Initial and latest values of the updated value slots.
+    values: BTreeMap<u8, (Word, Word)>,
+    /// Initial and latest values of the updated storage map entries.
+    maps: BTreeMap<u8, BTreeMap<Digest, (Word, Word)>>,
+}
+
+impl StorageDeltaTracker {
+    /// Tracks an update of the value slot at the specified index from `old_value` to `new_value`.
+    pub fn set_item(&mut self, slot_index: u8, old_value: Word, new_value: Word) {
+        self.values.entry(slot_index).or_insert((old_value, new_value)).1 = new_value;
+    }
+
+    /// Tracks an update of the entry under `key` in the storage map at the specified index from
+    /// `old_value` to `new_value`.
+    pub fn set_map_item(&mut self, slot_index: u8, key: Digest, old_value: Word, new_value: Word) {
+        self.maps
+            .entry(slot_index)
+            .or_default()
+            .entry(key)
+            .or_insert((old_value, new_value))
+            .1 = new_value;
+    }
+
+    /// Consumes `self` and returns the resulting [AccountStorageDelta].
+    ///
+    /// Slots and map entries whose latest value equals their initial value are omitted.
+    pub fn into_delta(self) -> AccountStorageDelta {
+        let mut delta = AccountStorageDelta::default();
+
+        for (slot_index, (initial_value, value)) in self.values {
+            if initial_value != value {
+                delta.set_item(slot_index, value);
+            }
+        }
+
+        for (slot_index, entries) in self.maps {
+            for (key, (initial_value, value)) in entries {
+                if initial_value != value {
+                    delta.set_map_item(slot_index, key, value);
+                }
+            }
+        }
+
+        delta
+    }
+}
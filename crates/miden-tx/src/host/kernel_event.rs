@@ -0,0 +1,270 @@
+use miden_lib::transaction::{KernelEventId, TransactionEvent, TransactionKernelError};
+use miden_objects::{Digest, Felt, Word, asset::Asset, note::NoteMetadata};
+use vm_processor::ProcessState;
+
+// KERNEL EVENT
+// ================================================================================================
+
+/// A typed representation of an event emitted by the transaction kernel, decoded from the event ID
+/// and the state of the operand stack at the time the event was emitted.
+///
+/// Only events which describe an effect of the transaction are represented, i.e. the events
+/// emitted before an effect is applied (e.g. [TransactionEvent::AccountVaultBeforeAddAsset]) as
+/// well as events used for internal bookkeeping of the host do not have a typed counterpart.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KernelEvent {
+    /// An asset was added to the vault of the account.
+    AccountAssetAdded { asset: Asset },
+    /// An asset was removed from the vault of the account.
+    AccountAssetRemoved { asset: Asset },
+    /// A value storage slot of the account was updated.
+    AccountStorageItemSet {
+        slot_index: u8,
+        old_value: Word,
+        new_value: Word,
+    },
+    /// An entry of a map storage slot of the account was updated.
+    AccountStorageMapItemSet {
+        slot_index: u8,
+        key: Digest,
+        old_value: Word,
+        new_value: Word,
+    },
+    /// The nonce of the account is about to be incremented by the specified value.
+    AccountNonceIncremented { delta: Felt },
+    /// An output note was created.
+    NoteCreated {
+        note_idx: usize,
+        metadata: NoteMetadata,
+        recipient_digest: Digest,
+    },
+    /// An asset is about to be added to an output note.
+    NoteAssetAdded { note_idx: usize, asset: Asset },
+    /// The recipient of an output note is about to be replaced.
+    NoteRecipientSet {
+        note_idx: usize,
+        recipient_digest: Digest,
+    },
+    /// A Falcon signature over the specified message was requested for the specified public key.
+    FalconSignatureRequested { pub_key: Word, message: Word },
+    /// A secp256k1 ECDSA signature over the specified message was requested for the public key
+    /// with the specified commitment.
+    EcdsaK256SignatureRequested { pub_key: Word, message: Word },
+    /// A BLS12-381 signature over the specified message was requested for the public key with the
+    /// specified commitment.
+    Bls12381SignatureRequested { pub_key: Word, message: Word },
+    /// The transaction was approved by the no-auth component of the account, i.e. without any
+    /// authentication.
+    UnauthenticatedTransaction,
+}
+
+impl KernelEvent {
+    /// Decodes the provided transaction event using the state of the operand stack of the process
+    /// which emitted it.
+    ///
+    /// Returns `None` if the event does not have a typed counterpart.
+    ///
+    /// # Errors
+    /// Returns an error if the data on the operand stack is not well formed for the event.
+    pub fn decode(
+        event: TransactionEvent,
+        process: ProcessState,
+    ) -> Result<Option<Self>, TransactionKernelError> {
+        Self::decode_stack(event, &process.get_stack_state())
+    }
+
+    /// Returns the ID of the event this typed event is decoded from.
+    pub fn event_id(&self) -> KernelEventId {
+        match self {
+            Self::AccountAssetAdded { .. } => KernelEventId::AccountVaultAfterAddAsset,
+            Self::AccountAssetRemoved { .. } => KernelEventId::AccountVaultAfterRemoveAsset,
+            Self::AccountStorageItemSet { .. } => KernelEventId::AccountStorageAfterSetItem,
+            Self::AccountStorageMapItemSet { .. } => KernelEventId::AccountStorageAfterSetMapItem,
+            Self::AccountNonceIncremented { .. } => KernelEventId::AccountBeforeIncrementNonce,
+            Self::NoteCreated { .. } => KernelEventId::NoteAfterCreated,
+            Self::NoteAssetAdded { .. } => KernelEventId::NoteBeforeAddAsset,
+            Self::NoteRecipientSet { .. } => KernelEventId::NoteBeforeSetRecipient,
+            Self::FalconSignatureRequested { .. } => KernelEventId::FalconSigToStack,
+            Self::EcdsaK256SignatureRequested { .. } => KernelEventId::EcdsaK256SigToStack,
+            Self::Bls12381SignatureRequested { .. } => KernelEventId::Bls12381SigToStack,
+            Self::UnauthenticatedTransaction => KernelEventId::UnauthenticatedTx,
+        }
+    }
+
+    /// Decodes the provided transaction event from the provided operand stack, where the first
+    /// element of `stack` is the top of the stack.
+    ///
+    /// The items of the event are read from the stack according to the stack layout of the event
+    /// generated from the MASM sources, see [`KernelEventId::stack_layout`].
+    fn decode_stack(
+        event: TransactionEvent,
+        stack: &[Felt],
+    ) -> Result<Option<Self>, TransactionKernelError> {
+        let felt = |item| read_felt(event, stack, item);
+        let word = |item| read_word(event, stack, item);
+
+        let event = match event {
+            TransactionEvent::AccountVaultAfterAddAsset => {
+                Self::AccountAssetAdded { asset: read_asset(word("ASSET"))? }
+            },
+            TransactionEvent::AccountVaultAfterRemoveAsset => {
+                Self::AccountAssetRemoved { asset: read_asset(word("ASSET"))? }
+            },
+            TransactionEvent::AccountStorageAfterSetItem => Self::AccountStorageItemSet {
+                slot_index: read_slot_index(felt("slot_index"))?,
+                old_value: word("OLD_VALUE"),
+                new_value: word("NEW_VALUE"),
+            },
+            TransactionEvent::AccountStorageAfterSetMapItem => Self::AccountStorageMapItemSet {
+                slot_index: read_slot_index(felt("slot_index"))?,
+                key: word("KEY").into(),
+                old_value: word("OLD_VALUE"),
+                new_value: word("NEW_VALUE"),
+            },
+            TransactionEvent::AccountBeforeIncrementNonce => {
+                Self::AccountNonceIncremented { delta: felt("nonce_delta") }
+            },
+            TransactionEvent::NoteAfterCreated => {
+                let metadata = NoteMetadata::try_from(word("NOTE_METADATA"))
+                    .map_err(TransactionKernelError::MalformedNoteMetadata)?;
+
+                Self::NoteCreated {
+                    note_idx: felt("note_idx").as_int() as usize,
+                    metadata,
+                    recipient_digest: word("RECIPIENT").into(),
+                }
+            },
+            TransactionEvent::NoteBeforeAddAsset => Self::NoteAssetAdded {
+                note_idx: felt("note_idx").as_int() as usize,
+                asset: read_asset(word("ASSET"))?,
+            },
+            TransactionEvent::NoteBeforeSetRecipient => Self::NoteRecipientSet {
+                note_idx: felt("note_idx").as_int() as usize,
+                recipient_digest: word("RECIPIENT").into(),
+            },
+            TransactionEvent::FalconSigToStack => Self::FalconSignatureRequested {
+                pub_key: word("PUB_KEY"),
+                message: word("MESSAGE"),
+            },
+            TransactionEvent::EcdsaK256SigToStack => Self::EcdsaK256SignatureRequested {
+                pub_key: word("PUB_KEY_COMMITMENT"),
+                message: word("MESSAGE"),
+            },
+            TransactionEvent::Bls12381SigToStack => Self::Bls12381SignatureRequested {
+                pub_key: word("PUB_KEY_COMMITMENT"),
+                message: word("MESSAGE"),
+            },
+            TransactionEvent::UnauthenticatedTx => Self::UnauthenticatedTransaction,
+            _ => return Ok(None),
+        };
+
+        Ok(Some(event))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Reads the specified single element item of the stack layout of the event from the stack.
+fn read_felt(event: TransactionEvent, stack: &[Felt], item: &str) -> Felt {
+    event
+        .read_felt(stack, item)
+        .expect("item should be a single element of the stack layout of the event")
+}
+
+/// Reads the specified word item of the stack layout of the event from the stack.
+fn read_word(event: TransactionEvent, stack: &[Felt], item: &str) -> Word {
+    event
+        .read_word(stack, item)
+        .expect("item should be a word of the stack layout of the event")
+}
+
+/// Converts the provided word into an asset.
+fn read_asset(word: Word) -> Result<Asset, TransactionKernelError> {
+    Asset::try_from(word).map_err(|source| TransactionKernelError::MalformedAssetInEventHandler {
+        handler: "KernelEvent::decode",
+        source,
+    })
+}
+
+/// Converts the provided element into a storage slot index.
+fn read_slot_index(slot_index: Felt) -> Result<u8, TransactionKernelError> {
+    let slot_index = slot_index.as_int();
+
+    u8::try_from(slot_index).map_err(|_| TransactionKernelError::InvalidStorageSlotIndex {
+        max: u8::MAX as u64 + 1,
+        actual: slot_index,
+    })
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use miden_objects::{
+        ONE, ZERO,
+        asset::FungibleAsset,
+        note::{NoteExecutionHint, NoteTag, NoteType},
+        testing::account_id::ACCOUNT_ID_SENDER,
+    };
+
+    use super::*;
+
+    /// Builds an operand stack following the stack layout of the provided event, with well formed
+    /// values for the items which are decoded into typed values.
+    fn build_stack(event: KernelEventId) -> Vec<Felt> {
+        let metadata = NoteMetadata::new(
+            ACCOUNT_ID_SENDER.try_into().unwrap(),
+            NoteType::Public,
+            NoteTag::from(7),
+            NoteExecutionHint::always(),
+            ZERO,
+        )
+        .unwrap();
+
+        let mut stack = Vec::new();
+        for item in event.stack_layout() {
+            let word: Word = match *item {
+                "ASSET" => FungibleAsset::mock(10).into(),
+                "NOTE_METADATA" => metadata.into(),
+                "slot_index" | "note_idx" => {
+                    stack.push(Felt::from(3u8));
+                    continue;
+                },
+                item if item.chars().any(|c| c.is_ascii_lowercase()) => {
+                    stack.push(Felt::from(stack.len() as u32));
+                    continue;
+                },
+                _ => [Felt::from(stack.len() as u32), ONE, ONE, ONE],
+            };
+            stack.extend(word.iter().rev());
+        }
+
+        stack
+    }
+
+    #[test]
+    fn kernel_events_match_generated_event_ids() {
+        let mut num_typed_events = 0;
+        for event_id in KernelEventId::ALL {
+            let stack = build_stack(event_id);
+            if let Some(event) = KernelEvent::decode_stack(event_id, &stack).unwrap() {
+                assert_eq!(event.event_id(), event_id);
+                num_typed_events += 1;
+            }
+        }
+        assert_eq!(num_typed_events, 12);
+
+        let event_id = KernelEventId::NoteBeforeAddAsset;
+        assert_eq!(
+            KernelEvent::decode_stack(event_id, &build_stack(event_id)).unwrap(),
+            Some(KernelEvent::NoteAssetAdded {
+                note_idx: 3,
+                asset: FungibleAsset::mock(10)
+            })
+        );
+    }
+}
@@ -6,6 +6,8 @@ use alloc::{
     vec::Vec,
 };
 
+use core::error::Error;
+
 use miden_lib::{
     errors::tx_kernel_errors::TX_KERNEL_ERRORS,
     transaction::{
@@ -32,16 +34,33 @@ use account_delta_tracker::AccountDeltaTracker;
 mod account_procedures;
 pub use account_procedures::AccountProcedureIndexMap;
 
+mod kernel_event;
+pub use kernel_event::KernelEvent;
+
 mod note_builder;
 use note_builder::OutputNoteBuilder;
 
 mod tx_progress;
-pub use tx_progress::TransactionProgress;
+pub use tx_progress::{AccountCommitmentStats, TransactionProgress, TransactionStage};
+
+mod u256;
+mod u384;
 
 use crate::{
-    auth::TransactionAuthenticator, errors::TransactionHostError, executor::TransactionMastStore,
+    auth::{SigningRequest, TransactionAuthenticator},
+    errors::TransactionHostError,
+    executor::TransactionMastStore,
 };
 
+/// A callback which is notified of the [KernelEvent]s handled by a [TransactionHost].
+type EventSubscriber = Box<dyn FnMut(&KernelEvent)>;
+
+/// A handler for a custom event which returns the elements to push onto the advice stack.
+type EventHandler = Box<dyn FnMut(ProcessState) -> Result<Vec<Felt>, EventHandlerError>>;
+
+/// The error returned by a custom event handler registered on a [TransactionHost].
+pub type EventHandlerError = Box<dyn Error + Send + Sync + 'static>;
+
 // TRANSACTION HOST
 // ================================================================================================
 
@@ -82,6 +101,14 @@ pub struct TransactionHost<A> {
     /// signature using the transaction authenticator.
     generated_signatures: BTreeMap<Digest, Vec<Felt>>,
 
+    /// Contains the requests for signatures which could neither be found in the advice map nor
+    /// generated by the transaction authenticator.
+    signing_requests: Vec<SigningRequest>,
+
+    /// Whether execution continues with a placeholder signature when a required signature can
+    /// neither be found in the advice map nor generated by the transaction authenticator.
+    placeholder_signatures: bool,
+
     /// Tracks the number of cycles for each of the transaction execution stages.
     ///
     /// This field is updated by the [TransactionHost::on_trace()] handler.
@@ -91,6 +118,13 @@ pub struct TransactionHost<A> {
     ///
     /// This map is initialized at construction time from the [`TX_KERNEL_ERRORS`] array.
     error_messages: BTreeMap<u32, &'static str>,
+
+    /// An optional callback which is notified of every [KernelEvent] handled by this host.
+    event_subscriber: Option<EventSubscriber>,
+
+    /// Handlers for custom events emitted by account and note code, stored as event_id |->
+    /// handler map.
+    event_handlers: BTreeMap<u32, EventHandler>,
 }
 
 impl<A: AdviceProvider> TransactionHost<A> {
@@ -119,10 +153,51 @@ impl<A: AdviceProvider> TransactionHost<A> {
             authenticator,
             tx_progress: TransactionProgress::default(),
             generated_signatures: BTreeMap::new(),
+            signing_requests: Vec::new(),
+            placeholder_signatures: false,
             error_messages: kernel_assertion_errors,
+            event_subscriber: None,
+            event_handlers: BTreeMap::new(),
         })
     }
 
+    /// Sets the callback which is notified of every [KernelEvent] handled by this host.
+    ///
+    /// The callback is invoked after the event has been handled successfully, and replaces the
+    /// previously set callback, if any.
+    pub fn set_event_subscriber(&mut self, subscriber: impl FnMut(&KernelEvent) + 'static) {
+        self.event_subscriber = Some(Box::new(subscriber));
+    }
+
+    /// Registers a handler for the custom event with the specified ID, which can be emitted by
+    /// account, note or transaction scripts via `emit.<event_id>`.
+    ///
+    /// The handler is invoked with the state of the VM at the time the event is emitted, in any
+    /// execution context. The elements returned by the handler are pushed onto the advice stack one
+    /// by one, such that the last element ends up on top of the advice stack. If the handler
+    /// returns an error, the execution of the transaction fails.
+    ///
+    /// The events of the transaction kernel always take precedence over custom events, so IDs in
+    /// the range reserved for kernel events (i.e., with the upper 16 bits equal to
+    /// [`TransactionEvent::ID_PREFIX`]) cannot be registered. A handler registered for an ID which
+    /// already has a handler replaces the previous one. Custom events without a registered handler
+    /// cause the execution to fail.
+    ///
+    /// # Errors
+    /// Returns an error if `event_id` is reserved for transaction kernel events.
+    pub fn register_event_handler(
+        &mut self,
+        event_id: u32,
+        handler: impl FnMut(ProcessState) -> Result<Vec<Felt>, EventHandlerError> + 'static,
+    ) -> Result<(), TransactionHostError> {
+        if event_id >> 16 == TransactionEvent::ID_PREFIX {
+            return Err(TransactionHostError::ReservedEventId(event_id));
+        }
+
+        self.event_handlers.insert(event_id, Box::new(handler));
+        Ok(())
+    }
+
     /// Consumes `self` and returns the advice provider, account delta, output notes, generated
     /// signatures, and transaction progress.
     pub fn into_parts(
@@ -145,6 +220,22 @@ impl<A: AdviceProvider> TransactionHost<A> {
         )
     }
 
+    /// Returns the requests for signatures which were required by the transaction but could not be
+    /// generated by the authenticator of this host.
+    pub fn signing_requests(&self) -> &[SigningRequest] {
+        &self.signing_requests
+    }
+
+    /// Makes this host continue the execution with a placeholder signature whenever a required
+    /// signature can neither be found in the advice map nor generated by the authenticator.
+    ///
+    /// The request for the signature is still recorded and can be retrieved via
+    /// [`Self::signing_requests`]. The placeholder is empty, so the execution fails once the
+    /// signature is consumed, e.g. when it is verified.
+    pub fn enable_placeholder_signatures(&mut self) {
+        self.placeholder_signatures = true;
+    }
+
     /// Returns a reference to the `tx_progress` field of this transaction host.
     pub fn tx_progress(&self) -> &TransactionProgress {
         &self.tx_progress
@@ -206,6 +297,29 @@ impl<A: AdviceProvider> TransactionHost<A> {
         Ok(())
     }
 
+    /// Replaces the recipient of the [OutputNoteBuilder] identified by the note index.
+    ///
+    /// Expected stack state: [note_ptr, RECIPIENT, note_idx]
+    fn on_note_before_set_recipient(
+        &mut self,
+        process: ProcessState,
+    ) -> Result<(), TransactionKernelError> {
+        let stack = process.get_stack_state();
+        //# => [note_ptr, RECIPIENT, note_idx]
+
+        let note_idx = stack[5].as_int();
+        let recipient_digest = Digest::new([stack[4], stack[3], stack[2], stack[1]]);
+
+        let note_builder = self
+            .output_notes
+            .get_mut(&(note_idx as usize))
+            .ok_or_else(|| TransactionKernelError::MissingNote(note_idx))?;
+
+        note_builder.set_recipient(recipient_digest, &self.adv_provider)?;
+
+        Ok(())
+    }
+
     /// Loads the index of the procedure root onto the advice stack.
     ///
     /// Expected stack state: [PROC_ROOT, ...]
@@ -272,11 +386,10 @@ impl<A: AdviceProvider> TransactionHost<A> {
             process.get_stack_item(5),
         ];
 
-        // update the delta tracker only if the current and new values are different
-        if current_slot_value != new_slot_value {
-            let slot_index = slot_index.as_int() as u8;
-            self.account_delta.storage_delta().set_item(slot_index, new_slot_value);
-        }
+        let slot_index = slot_index.as_int() as u8;
+        self.account_delta
+            .storage_delta()
+            .set_item(slot_index, current_slot_value, new_slot_value);
 
         Ok(())
     }
@@ -284,7 +397,7 @@ impl<A: AdviceProvider> TransactionHost<A> {
     /// Extracts information from the process state about the storage map being updated and
     /// records the latest values of this storage map.
     ///
-    /// Expected stack state: [slot_index, NEW_MAP_KEY, NEW_MAP_VALUE, ...]
+    /// Expected stack state: [slot_index, MAP_KEY, NEW_MAP_VALUE, OLD_MAP_VALUE, ...]
     pub fn on_account_storage_after_set_map_item(
         &mut self,
         process: ProcessState,
@@ -318,10 +431,19 @@ impl<A: AdviceProvider> TransactionHost<A> {
             process.get_stack_item(5),
         ];
 
+        // get the VALUE which was stored under the KEY before the update
+        let old_map_value = [
+            process.get_stack_item(12),
+            process.get_stack_item(11),
+            process.get_stack_item(10),
+            process.get_stack_item(9),
+        ];
+
         let slot_index = slot_index.as_int() as u8;
         self.account_delta.storage_delta().set_map_item(
             slot_index,
             new_map_key.into(),
+            old_map_value,
             new_map_value,
         );
 
@@ -382,7 +504,9 @@ impl<A: AdviceProvider> TransactionHost<A> {
     ///
     /// This signature is created during transaction execution and stored for use as advice map
     /// inputs in the proving host. If not already present in the advice map, it is requested from
-    /// the host's authenticator.
+    /// the host's authenticator. If the authenticator cannot provide the signature, a
+    /// [SigningRequest] for it is recorded and execution fails, unless placeholder signatures are
+    /// enabled.
     pub fn on_signature_requested(
         &mut self,
         process: ProcessState,
@@ -397,20 +521,26 @@ impl<A: AdviceProvider> TransactionHost<A> {
         } else {
             let account_delta = self.account_delta.clone().into_delta();
 
-            let signature: Vec<Felt> = match &self.authenticator {
-                None => {
-                    return Err(TransactionKernelError::FailedSignatureGeneration(
-                        "No authenticator assigned to transaction host",
-                    ));
-                },
+            let signature = match &self.authenticator {
+                None => None,
                 Some(authenticator) => {
-                    authenticator.get_signature(pub_key, msg, &account_delta).map_err(|_| {
-                        TransactionKernelError::FailedSignatureGeneration(
-                            "Error generating signature",
-                        )
-                    })
+                    authenticator.get_signature(pub_key, msg, &account_delta).ok()
                 },
-            }?;
+            };
+
+            let Some(signature) = signature else {
+                // record the request, so that the signature can be provided externally
+                self.signing_requests.push(SigningRequest::new(pub_key, msg, account_delta));
+                if self.placeholder_signatures {
+                    return Ok(());
+                }
+
+                let message = match self.authenticator {
+                    None => "No authenticator assigned to transaction host",
+                    Some(_) => "Error generating signature",
+                };
+                return Err(TransactionKernelError::FailedSignatureGeneration(message));
+            };
 
             self.generated_signatures.insert(signature_key, signature.clone());
             signature
@@ -425,9 +555,69 @@ impl<A: AdviceProvider> TransactionHost<A> {
         Ok(())
     }
 
+    /// Pushes the quotient and the remainder of the product of two 256-bit values divided by a
+    /// 256-bit modulus onto the advice stack.
+    ///
+    /// Each pointer points to a 256-bit value stored in memory as 8 little-endian u32 limbs. The
+    /// quotient ends up at the top of the advice stack, followed by the remainder, both as 8
+    /// little-endian u32 limbs with the least significant limb first.
+    ///
+    /// Expected stack state: [a_ptr, b_ptr, m_ptr, ...]
+    pub fn on_u256_mul_mod_requested(
+        &mut self,
+        process: ProcessState,
+    ) -> Result<(), TransactionKernelError> {
+        let values = u256_mul_mod_hint(process)?;
+        self.push_advice_values(values)
+    }
+
+    /// Pushes the quotient and the remainder of the product of two 384-bit values divided by a
+    /// 384-bit modulus onto the advice stack.
+    ///
+    /// Each pointer points to a 384-bit value stored in memory as 12 little-endian u32 limbs. The
+    /// quotient ends up at the top of the advice stack, followed by the remainder, both as 12
+    /// little-endian u32 limbs with the least significant limb first.
+    ///
+    /// Expected stack state: [a_ptr, b_ptr, m_ptr, ...]
+    pub fn on_u384_mul_mod_requested(
+        &mut self,
+        process: ProcessState,
+    ) -> Result<(), TransactionKernelError> {
+        let values = u384_mul_mod_hint(process)?;
+        self.push_advice_values(values)
+    }
+
+    /// Pushes the inverse of a 384-bit value modulo a 384-bit modulus onto the advice stack.
+    ///
+    /// Each pointer points to a 384-bit value stored in memory as 12 little-endian u32 limbs. The
+    /// inverse is pushed as 12 little-endian u32 limbs with the least significant limb at the top
+    /// of the advice stack. If the value is not invertible, zero is pushed instead, which the
+    /// procedure requesting the inverse rejects.
+    ///
+    /// Expected stack state: [a_ptr, m_ptr, ...]
+    pub fn on_u384_inv_mod_requested(
+        &mut self,
+        process: ProcessState,
+    ) -> Result<(), TransactionKernelError> {
+        let values = u384_inv_mod_hint(process)?;
+        self.push_advice_values(values)
+    }
+
     // HELPER FUNCTIONS
     // --------------------------------------------------------------------------------------------
 
+    /// Pushes the provided values onto the advice stack, such that the last value ends up at the
+    /// top of the stack.
+    fn push_advice_values(&mut self, values: Vec<Felt>) -> Result<(), TransactionKernelError> {
+        for value in values {
+            self.adv_provider
+                .push_stack(AdviceSource::Value(value))
+                .map_err(|_| TransactionKernelError::FailedToPushAdviceStack(value))?;
+        }
+
+        Ok(())
+    }
+
     /// Returns the ID of the currently executing input note, or None if the note execution hasn't
     /// started yet or has already ended.
     ///
@@ -488,12 +678,33 @@ impl<A: AdviceProvider> Host for TransactionHost<A> {
     }
 
     fn on_event(&mut self, process: ProcessState, event_id: u32) -> Result<(), ExecutionError> {
+        // IDs reserved for kernel events cannot be registered, so custom handlers never shadow the
+        // built-in ones
+        if let Some(handler) = self.event_handlers.get_mut(&event_id) {
+            let values = handler(process).map_err(ExecutionError::EventError)?;
+            for value in values {
+                self.adv_provider.push_stack(AdviceSource::Value(value))?;
+            }
+            return Ok(());
+        }
+
         let transaction_event = TransactionEvent::try_from(event_id)
             .map_err(|err| ExecutionError::EventError(Box::new(err)))?;
 
-        // only the `FalconSigToStack` event can be executed outside the root context
+        // only the events emitted by auth components, i.e. the signature, the signature
+        // verification hints and the unauthenticated transaction events, can be executed outside
+        // the root context
         if process.ctx() != ContextId::root()
-            && !matches!(transaction_event, TransactionEvent::FalconSigToStack)
+            && !matches!(
+                transaction_event,
+                TransactionEvent::FalconSigToStack
+                    | TransactionEvent::EcdsaK256SigToStack
+                    | TransactionEvent::U256MulModToStack
+                    | TransactionEvent::Bls12381SigToStack
+                    | TransactionEvent::U384MulModToStack
+                    | TransactionEvent::U384InvModToStack
+                    | TransactionEvent::UnauthenticatedTx
+            )
         {
             return Err(ExecutionError::EventError(Box::new(
                 TransactionEventError::NotRootContext(event_id),
@@ -537,9 +748,28 @@ impl<A: AdviceProvider> Host for TransactionHost<A> {
             TransactionEvent::NoteAfterAddAsset => Ok(()),
 
             TransactionEvent::FalconSigToStack => self.on_signature_requested(process),
+            TransactionEvent::EcdsaK256SigToStack => self.on_signature_requested(process),
+            TransactionEvent::U256MulModToStack => self.on_u256_mul_mod_requested(process),
+
+            TransactionEvent::UnauthenticatedTx => Ok(()),
+
+            TransactionEvent::NoteBeforeSetRecipient => self.on_note_before_set_recipient(process),
+            TransactionEvent::NoteAfterSetRecipient => Ok(()),
+
+            TransactionEvent::Bls12381SigToStack => self.on_signature_requested(process),
+            TransactionEvent::U384MulModToStack => self.on_u384_mul_mod_requested(process),
+            TransactionEvent::U384InvModToStack => self.on_u384_inv_mod_requested(process),
         }
         .map_err(|err| ExecutionError::EventError(Box::new(err)))?;
 
+        if let Some(subscriber) = self.event_subscriber.as_mut() {
+            if let Some(event) = KernelEvent::decode(transaction_event, process)
+                .map_err(|err| ExecutionError::EventError(Box::new(err)))?
+            {
+                subscriber(&event);
+            }
+        }
+
         Ok(())
     }
 
@@ -564,6 +794,13 @@ impl<A: AdviceProvider> Host for TransactionHost<A> {
             TxScriptProcessingEnd => self.tx_progress.end_tx_script_processing(process.clk()),
             EpilogueStart => self.tx_progress.start_epilogue(process.clk()),
             EpilogueEnd => self.tx_progress.end_epilogue(process.clk()),
+            AccountCommitmentCacheHit => self.tx_progress.record_account_commitment_cache_hit(),
+            AccountCommitmentComputationStart => {
+                self.tx_progress.start_account_commitment_computation(process.clk())
+            },
+            AccountCommitmentComputationEnd => {
+                self.tx_progress.end_account_commitment_computation(process.clk())
+            },
         }
 
         Ok(())
@@ -581,3 +818,195 @@ impl<A: AdviceProvider> Host for TransactionHost<A> {
         }
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the quotient and the remainder of the product of two 256-bit values divided by a
+/// 256-bit modulus, in the order in which they must be pushed onto the advice stack.
+///
+/// The values are those requested by the `U256_MUL_MOD_TO_STACK` event, see
+/// [TransactionHost::on_u256_mul_mod_requested].
+pub(crate) fn u256_mul_mod_hint(
+    process: ProcessState,
+) -> Result<Vec<Felt>, TransactionKernelError> {
+    let a = read_u256(process, 0)?;
+    let b = read_u256(process, 1)?;
+    let m = read_u256(process, 2)?;
+
+    let (quotient, remainder) =
+        u256::mul_mod(&a, &b, &m).ok_or(TransactionKernelError::MalformedU256MulModOperands)?;
+
+    Ok(remainder
+        .iter()
+        .rev()
+        .chain(quotient.iter().rev())
+        .map(|limb| Felt::from(*limb))
+        .collect())
+}
+
+/// Returns the quotient and the remainder of the product of two 384-bit values divided by a
+/// 384-bit modulus, in the order in which they must be pushed onto the advice stack.
+///
+/// The values are those requested by the `U384_MUL_MOD_TO_STACK` event, see
+/// [TransactionHost::on_u384_mul_mod_requested].
+pub(crate) fn u384_mul_mod_hint(
+    process: ProcessState,
+) -> Result<Vec<Felt>, TransactionKernelError> {
+    let a = read_u384(process, 0)?;
+    let b = read_u384(process, 1)?;
+    let m = read_u384(process, 2)?;
+
+    let (quotient, remainder) =
+        u384::mul_mod(&a, &b, &m).ok_or(TransactionKernelError::MalformedU384Operands)?;
+
+    Ok(remainder
+        .iter()
+        .rev()
+        .chain(quotient.iter().rev())
+        .map(|limb| Felt::from(*limb))
+        .collect())
+}
+
+/// Returns the inverse of a 384-bit value modulo a 384-bit modulus, or zero if the value is not
+/// invertible, in the order in which it must be pushed onto the advice stack.
+///
+/// The values are those requested by the `U384_INV_MOD_TO_STACK` event, see
+/// [TransactionHost::on_u384_inv_mod_requested].
+pub(crate) fn u384_inv_mod_hint(
+    process: ProcessState,
+) -> Result<Vec<Felt>, TransactionKernelError> {
+    let a = read_u384(process, 0)?;
+    let m = read_u384(process, 1)?;
+
+    let inverse = u384::inv_mod(&a, &m).unwrap_or_default();
+
+    Ok(inverse.iter().rev().map(|limb| Felt::from(*limb)).collect())
+}
+
+/// Reads the 256-bit value stored as 8 little-endian u32 limbs in the memory of the current
+/// context, starting at the address which is located at the specified position of the operand
+/// stack.
+fn read_u256(
+    process: ProcessState,
+    stack_idx: usize,
+) -> Result<u256::U256Limbs, TransactionKernelError> {
+    let ptr = u32::try_from(process.get_stack_item(stack_idx).as_int())
+        .map_err(|_| TransactionKernelError::MalformedU256MulModOperands)?;
+
+    let mut limbs = [0u32; 8];
+    for (i, limb) in limbs.iter_mut().enumerate() {
+        let value = process
+            .get_mem_value(process.ctx(), ptr + i as u32)
+            .map_or(0, |value| value.as_int());
+        *limb = u32::try_from(value)
+            .map_err(|_| TransactionKernelError::MalformedU256MulModOperands)?;
+    }
+
+    Ok(limbs)
+}
+
+/// Reads the 384-bit value stored as 12 little-endian u32 limbs in the memory of the current
+/// context, starting at the address which is located at the specified position of the operand
+/// stack.
+fn read_u384(
+    process: ProcessState,
+    stack_idx: usize,
+) -> Result<u384::U384Limbs, TransactionKernelError> {
+    let ptr = u32::try_from(process.get_stack_item(stack_idx).as_int())
+        .map_err(|_| TransactionKernelError::MalformedU384Operands)?;
+
+    let mut limbs = [0u32; 12];
+    for (i, limb) in limbs.iter_mut().enumerate() {
+        let value = process
+            .get_mem_value(process.ctx(), ptr + i as u32)
+            .map_or(0, |value| value.as_int());
+        *limb = u32::try_from(value).map_err(|_| TransactionKernelError::MalformedU384Operands)?;
+    }
+
+    Ok(limbs)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::rc::Rc;
+    use core::cell::RefCell;
+
+    use assert_matches::assert_matches;
+    use miden_lib::transaction::TransactionKernel;
+    use miden_objects::{asset::FungibleAsset, transaction::TransactionScript};
+    use vm_processor::ONE;
+
+    use super::*;
+    use crate::{
+        testing::{MockChain, TransactionContextBuilder},
+        tests::fixtures::{TransactionFixture, replay_kernel_events, transaction_host},
+    };
+
+    #[test]
+    fn transaction_host_notifies_event_subscriber() {
+        let fixture = TransactionFixture::with_p2id_notes(&mut MockChain::new(), 1);
+        let executed_transaction = fixture.tx_context.execute().unwrap();
+
+        // the P2ID note carries a single asset, which is added to the vault of the account
+        let asset = FungibleAsset::mock(100);
+        let events = replay_kernel_events(&executed_transaction);
+        assert_eq!(events[0], KernelEvent::AccountAssetAdded { asset });
+        assert!(events.contains(&KernelEvent::AccountNonceIncremented { delta: ONE }));
+        assert!(
+            events
+                .iter()
+                .any(|event| matches!(event, KernelEvent::FalconSignatureRequested { .. }))
+        );
+        assert!(!events.iter().any(|event| matches!(event, KernelEvent::NoteCreated { .. })));
+    }
+
+    #[test]
+    fn transaction_host_invokes_custom_event_handlers() {
+        const LOG_EVENT: u32 = 1234;
+
+        let tx_script_src = "
+            begin
+                push.7 emit.1234 drop
+                adv_push.2
+                push.1 assert_eq
+                push.2 assert_eq
+            end
+        ";
+        let tx_script =
+            TransactionScript::compile(tx_script_src, [], TransactionKernel::testing_assembler())
+                .unwrap();
+        let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+            .tx_script(tx_script)
+            .build();
+        let (stack_inputs, mut host) =
+            transaction_host(tx_context.tx_inputs(), tx_context.tx_args(), None);
+
+        // handlers cannot be registered for the events of the transaction kernel
+        assert_matches!(
+            host.register_event_handler(TransactionEvent::FalconSigToStack as u32, |_| Ok(vec![])),
+            Err(TransactionHostError::ReservedEventId(_))
+        );
+
+        let logs = Rc::new(RefCell::new(Vec::new()));
+        let recorded_logs = logs.clone();
+        host.register_event_handler(LOG_EVENT, move |process| {
+            recorded_logs.borrow_mut().push(process.get_stack_item(0));
+            Ok(vec![ONE, Felt::new(2)])
+        })
+        .unwrap();
+
+        vm_processor::execute(
+            &TransactionKernel::main(),
+            stack_inputs,
+            &mut host,
+            Default::default(),
+        )
+        .unwrap();
+
+        assert_eq!(*logs.borrow(), [Felt::new(7)]);
+    }
+}
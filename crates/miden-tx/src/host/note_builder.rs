@@ -52,68 +52,8 @@ impl OutputNoteBuilder {
         // read recipient digest from the stack and try to build note recipient object if there is
         // enough info available in the advice provider
         let recipient_digest = Digest::new([stack[8], stack[7], stack[6], stack[5]]);
-        let recipient = if let Some(data) = adv_provider.get_mapped_values(&recipient_digest) {
-            if data.len() != 12 {
-                return Err(TransactionKernelError::MalformedRecipientData(data.to_vec()));
-            }
-            let inputs_commitment = Digest::new([data[0], data[1], data[2], data[3]]);
-            let script_root = Digest::new([data[4], data[5], data[6], data[7]]);
-            let serial_num = [data[8], data[9], data[10], data[11]];
-            let script_data = adv_provider.get_mapped_values(&script_root).unwrap_or(&[]);
-
-            let inputs_data = adv_provider.get_mapped_values(&inputs_commitment);
-            let inputs = match inputs_data {
-                None => NoteInputs::default(),
-                Some(inputs) => {
-                    if inputs.is_empty() {
-                        return Err(TransactionKernelError::MissingNoteInputs);
-                    }
-
-                    let num_inputs = u64::from(inputs[0]) as usize;
-
-                    // There must be at least `num_inputs` elements in the advice provider data,
-                    // otherwise it is an error.
-                    //
-                    // It is possible to have more elements because of padding. The extra elements
-                    // will be discarded below, and later their contents will be validated by
-                    // computing the commitment and checking against the expected value.
-                    if num_inputs > (inputs.len() - 1) {
-                        return Err(TransactionKernelError::TooFewElementsForNoteInputs {
-                            specified: num_inputs as u64,
-                            actual: (inputs.len() - 1) as u64,
-                        });
-                    }
-
-                    NoteInputs::new(inputs[1..=num_inputs].to_vec())
-                        .map_err(TransactionKernelError::MalformedNoteInputs)?
-                },
-            };
-
-            if inputs.commitment() != inputs_commitment {
-                return Err(TransactionKernelError::InvalidNoteInputs {
-                    expected: inputs_commitment,
-                    actual: inputs.commitment(),
-                });
-            }
+        let recipient = read_recipient(&metadata, recipient_digest, adv_provider)?;
 
-            let script = NoteScript::try_from(script_data).map_err(|source| {
-                TransactionKernelError::MalformedNoteScript {
-                    data: script_data.to_vec(),
-                    source: Box::new(source),
-                }
-            })?;
-            let recipient = NoteRecipient::new(serial_num, script, inputs);
-
-            Some(recipient)
-        } else if metadata.is_private() {
-            None
-        } else {
-            // if there are no recipient details and the note is not private, return an error
-            return Err(TransactionKernelError::PublicNoteMissingDetails(
-                metadata,
-                recipient_digest,
-            ));
-        };
         Ok(Self {
             metadata,
             recipient_digest,
@@ -142,6 +82,24 @@ impl OutputNoteBuilder {
         Ok(())
     }
 
+    /// Replaces the recipient of the note with the recipient identified by the specified digest.
+    ///
+    /// The recipient details (when available) are retrieved from the advice provider.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Recipient information in the advice provider is present but is malformed.
+    /// - A non-private note is missing recipient details.
+    pub fn set_recipient<A: AdviceProvider>(
+        &mut self,
+        recipient_digest: Digest,
+        adv_provider: &A,
+    ) -> Result<(), TransactionKernelError> {
+        self.recipient = read_recipient(&self.metadata, recipient_digest, adv_provider)?;
+        self.recipient_digest = recipient_digest;
+        Ok(())
+    }
+
     /// Converts this builder to an [OutputNote].
     ///
     /// Depending on the available information, this may result in [OutputNote::Full] or
@@ -159,3 +117,82 @@ impl OutputNoteBuilder {
         }
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Tries to build the [NoteRecipient] with the specified digest from the data in the advice
+/// provider.
+///
+/// Returns `None` if the advice provider does not contain the recipient details and the note is
+/// private.
+///
+/// # Errors
+/// Returns an error if:
+/// - Recipient information in the advice provider is present but is malformed.
+/// - A non-private note is missing recipient details.
+fn read_recipient<A: AdviceProvider>(
+    metadata: &NoteMetadata,
+    recipient_digest: Digest,
+    adv_provider: &A,
+) -> Result<Option<NoteRecipient>, TransactionKernelError> {
+    if let Some(data) = adv_provider.get_mapped_values(&recipient_digest) {
+        if data.len() != 12 {
+            return Err(TransactionKernelError::MalformedRecipientData(data.to_vec()));
+        }
+        let inputs_commitment = Digest::new([data[0], data[1], data[2], data[3]]);
+        let script_root = Digest::new([data[4], data[5], data[6], data[7]]);
+        let serial_num = [data[8], data[9], data[10], data[11]];
+        let script_data = adv_provider.get_mapped_values(&script_root).unwrap_or(&[]);
+
+        let inputs_data = adv_provider.get_mapped_values(&inputs_commitment);
+        let inputs = match inputs_data {
+            None => NoteInputs::default(),
+            Some(inputs) => {
+                if inputs.is_empty() {
+                    return Err(TransactionKernelError::MissingNoteInputs);
+                }
+
+                let num_inputs = u64::from(inputs[0]) as usize;
+
+                // There must be at least `num_inputs` elements in the advice provider data,
+                // otherwise it is an error.
+                //
+                // It is possible to have more elements because of padding. The extra elements
+                // will be discarded below, and later their contents will be validated by
+                // computing the commitment and checking against the expected value.
+                if num_inputs > (inputs.len() - 1) {
+                    return Err(TransactionKernelError::TooFewElementsForNoteInputs {
+                        specified: num_inputs as u64,
+                        actual: (inputs.len() - 1) as u64,
+                    });
+                }
+
+                NoteInputs::new(inputs[1..=num_inputs].to_vec())
+                    .map_err(TransactionKernelError::MalformedNoteInputs)?
+            },
+        };
+
+        if inputs.commitment() != inputs_commitment {
+            return Err(TransactionKernelError::InvalidNoteInputs {
+                expected: inputs_commitment,
+                actual: inputs.commitment(),
+            });
+        }
+
+        let script = NoteScript::try_from(script_data).map_err(|source| {
+            TransactionKernelError::MalformedNoteScript {
+                data: script_data.to_vec(),
+                source: Box::new(source),
+            }
+        })?;
+        let recipient = NoteRecipient::new(serial_num, script, inputs);
+
+        Ok(Some(recipient))
+    } else if metadata.is_private() {
+        Ok(None)
+    } else {
+        // if there are no recipient details and the note is not private, return an error
+        Err(TransactionKernelError::PublicNoteMissingDetails(*metadata, recipient_digest))
+    }
+}
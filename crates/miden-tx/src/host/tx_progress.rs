@@ -1,4 +1,7 @@
 pub use alloc::vec::Vec;
+use core::{fmt, time::Duration};
+
+use miden_objects::transaction::TransactionTimings;
 
 use super::{NoteId, RowIndex, TransactionMeasurements};
 
@@ -14,6 +17,7 @@ pub struct TransactionProgress {
     note_execution: Vec<(NoteId, CycleInterval)>,
     tx_script_processing: CycleInterval,
     epilogue: CycleInterval,
+    account_commitment: AccountCommitmentStats,
 }
 
 impl TransactionProgress {
@@ -40,6 +44,36 @@ impl TransactionProgress {
         &self.epilogue
     }
 
+    pub fn account_commitment(&self) -> &AccountCommitmentStats {
+        &self.account_commitment
+    }
+
+    /// Returns the stage of the transaction which was started but not yet finished, or `None` if
+    /// the transaction is between stages.
+    ///
+    /// If the execution of the transaction failed, this is the stage in which it failed.
+    pub fn current_stage(&self) -> Option<TransactionStage> {
+        if self.epilogue.is_open() {
+            return Some(TransactionStage::Epilogue);
+        }
+        if self.tx_script_processing.is_open() {
+            return Some(TransactionStage::TxScriptProcessing);
+        }
+        if let Some((note_id, interval)) = self.note_execution.last() {
+            if interval.is_open() {
+                return Some(TransactionStage::NoteExecution(*note_id));
+            }
+        }
+        if self.notes_processing.is_open() {
+            return Some(TransactionStage::NotesProcessing);
+        }
+        if self.prologue.is_open() {
+            return Some(TransactionStage::Prologue);
+        }
+
+        None
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -84,6 +118,87 @@ impl TransactionProgress {
     pub fn end_epilogue(&mut self, cycle: RowIndex) {
         self.epilogue.set_end(cycle);
     }
+
+    pub fn record_account_commitment_cache_hit(&mut self) {
+        self.account_commitment.cache_hits += 1;
+    }
+
+    pub fn start_account_commitment_computation(&mut self, cycle: RowIndex) {
+        self.account_commitment.current_computation = CycleInterval::new(cycle);
+    }
+
+    pub fn end_account_commitment_computation(&mut self, cycle: RowIndex) {
+        let stats = &mut self.account_commitment;
+        stats.current_computation.set_end(cycle);
+        stats.computations += 1;
+        stats.computation_cycles += stats.current_computation.len();
+    }
+}
+
+// ACCOUNT COMMITMENT STATS
+// ================================================================================================
+
+/// Contains the information about how often the account commitment was computed by the
+/// transaction kernel and how often it was served from the kernel's commitment cache.
+#[derive(Clone, Default, Debug)]
+pub struct AccountCommitmentStats {
+    cache_hits: usize,
+    computations: usize,
+    computation_cycles: usize,
+    current_computation: CycleInterval,
+}
+
+impl AccountCommitmentStats {
+    /// Returns the number of times the account commitment was served from the cache.
+    pub fn cache_hits(&self) -> usize {
+        self.cache_hits
+    }
+
+    /// Returns the number of times the account commitment was computed.
+    pub fn computations(&self) -> usize {
+        self.computations
+    }
+
+    /// Returns the total number of cycles spent computing the account commitment.
+    pub fn computation_cycles(&self) -> usize {
+        self.computation_cycles
+    }
+
+    /// Returns the estimated number of cycles saved by the cache, i.e. the number of cache hits
+    /// multiplied by the average number of cycles spent on computing the commitment.
+    pub fn cycles_saved(&self) -> usize {
+        if self.computations == 0 {
+            return 0;
+        }
+
+        self.cache_hits * self.computation_cycles / self.computations
+    }
+}
+
+// TRANSACTION STAGE
+// ================================================================================================
+
+/// A stage of the execution of a transaction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransactionStage {
+    Prologue,
+    NotesProcessing,
+    /// The execution of the script of the note with the specified ID.
+    NoteExecution(NoteId),
+    TxScriptProcessing,
+    Epilogue,
+}
+
+impl fmt::Display for TransactionStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Prologue => write!(f, "prologue"),
+            Self::NotesProcessing => write!(f, "notes processing"),
+            Self::NoteExecution(note_id) => write!(f, "execution of note {note_id}"),
+            Self::TxScriptProcessing => write!(f, "transaction script processing"),
+            Self::Epilogue => write!(f, "epilogue"),
+        }
+    }
 }
 
 impl From<TransactionProgress> for TransactionMeasurements {
@@ -102,34 +217,76 @@ impl From<TransactionProgress> for TransactionMeasurements {
 
         let epilogue = tx_progress.epilogue().len();
 
+        let timings = TransactionTimings {
+            prologue: tx_progress.prologue().elapsed(),
+            notes_processing: tx_progress.notes_processing().elapsed(),
+            note_execution: tx_progress
+                .note_execution()
+                .iter()
+                .map(|(note_id, interval)| (*note_id, interval.elapsed()))
+                .collect(),
+            tx_script_processing: tx_progress.tx_script_processing().elapsed(),
+            epilogue: tx_progress.epilogue().elapsed(),
+        };
+
         Self {
             prologue,
             notes_processing,
             note_execution,
             tx_script_processing,
             epilogue,
+            account_commitment_cache_hits: tx_progress.account_commitment().cache_hits(),
+            account_commitment_cycles_saved: tx_progress.account_commitment().cycles_saved(),
+            timings,
         }
     }
 }
 
 /// Stores the cycles corresponding to the start and the end of an interval.
+///
+/// With the `std` feature, the interval additionally measures the wall-clock time which elapsed
+/// between its start and its end.
 #[derive(Clone, Default, Debug)]
 pub struct CycleInterval {
     start: Option<RowIndex>,
     end: Option<RowIndex>,
+    #[cfg(feature = "std")]
+    started_at: Option<std::time::Instant>,
+    elapsed: Duration,
 }
 
 impl CycleInterval {
     pub fn new(start: RowIndex) -> Self {
-        Self { start: Some(start), end: None }
+        let mut interval = Self::default();
+        interval.set_start(start);
+        interval
     }
 
     pub fn set_start(&mut self, s: RowIndex) {
         self.start = Some(s);
+        #[cfg(feature = "std")]
+        {
+            self.started_at = Some(std::time::Instant::now());
+        }
     }
 
     pub fn set_end(&mut self, e: RowIndex) {
         self.end = Some(e);
+        #[cfg(feature = "std")]
+        if let Some(started_at) = self.started_at {
+            self.elapsed = started_at.elapsed();
+        }
+    }
+
+    /// Returns the wall-clock time which elapsed between the start and the end of the interval,
+    /// or zero if the interval has not ended or the time was not measured.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns `true` if the interval was started but not yet ended.
+    pub fn is_open(&self) -> bool {
+        self.start.is_some() && self.end.is_none()
     }
 
     /// Calculate the length of the interval
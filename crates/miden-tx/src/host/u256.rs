@@ -0,0 +1,162 @@
+// U256 ARITHMETIC
+// ================================================================================================
+
+/// A 256-bit unsigned integer represented as 8 little-endian u32 limbs, i.e., the limb at index
+/// `0` holds the least significant 32 bits.
+///
+/// This is the representation used for 256-bit values by the procedures in
+/// `miden::crypto::ecdsa_k256`.
+pub type U256Limbs = [u32; 8];
+
+/// Returns the quotient and the remainder of `a * b` divided by `m`.
+///
+/// Returns `None` if `m` is zero or if the quotient does not fit into 256 bits, which cannot
+/// happen if at least one of `a` and `b` is smaller than `m`.
+pub fn mul_mod(a: &U256Limbs, b: &U256Limbs, m: &U256Limbs) -> Option<(U256Limbs, U256Limbs)> {
+    if m.iter().all(|&limb| limb == 0) {
+        return None;
+    }
+
+    let product = mul_wide(a, b);
+
+    // schoolbook binary long division, the remainder is always smaller than 2m < 2^257 and thus
+    // needs one extra limb
+    let mut quotient = [0u32; 16];
+    let mut remainder = [0u32; 9];
+    for bit in (0..512).rev() {
+        // remainder = 2 * remainder + bit
+        let mut carry = (product[bit / 32] >> (bit % 32)) & 1;
+        for limb in remainder.iter_mut() {
+            let next_carry = *limb >> 31;
+            *limb = (*limb << 1) | carry;
+            carry = next_carry;
+        }
+
+        if !is_less(&remainder, m) {
+            sub_assign(&mut remainder, m);
+            quotient[bit / 32] |= 1 << (bit % 32);
+        }
+    }
+
+    if quotient[8..].iter().any(|&limb| limb != 0) {
+        return None;
+    }
+
+    let quotient = quotient[..8].try_into().expect("slice should have 8 limbs");
+    let remainder = remainder[..8].try_into().expect("slice should have 8 limbs");
+    Some((quotient, remainder))
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the 512-bit product of `a` and `b` as 16 little-endian u32 limbs.
+fn mul_wide(a: &U256Limbs, b: &U256Limbs) -> [u32; 16] {
+    let mut product = [0u32; 16];
+    for (i, &a_limb) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &b_limb) in b.iter().enumerate() {
+            let value = product[i + j] as u64 + a_limb as u64 * b_limb as u64 + carry;
+            product[i + j] = value as u32;
+            carry = value >> 32;
+        }
+        product[i + 8] = carry as u32;
+    }
+    product
+}
+
+/// Returns true if the 288-bit value `a` is smaller than `m`.
+fn is_less(a: &[u32; 9], m: &U256Limbs) -> bool {
+    if a[8] != 0 {
+        return false;
+    }
+
+    for (a_limb, m_limb) in a[..8].iter().zip(m.iter()).rev() {
+        if a_limb != m_limb {
+            return a_limb < m_limb;
+        }
+    }
+
+    false
+}
+
+/// Subtracts `m` from the 288-bit value `a`, assuming `a >= m`.
+fn sub_assign(a: &mut [u32; 9], m: &U256Limbs) {
+    let mut borrow = 0u64;
+    for (i, a_limb) in a.iter_mut().enumerate() {
+        let subtrahend = m.get(i).copied().unwrap_or(0) as u64 + borrow;
+        let (value, underflow) = (*a_limb as u64).overflowing_sub(subtrahend);
+        *a_limb = value as u32;
+        borrow = underflow as u64;
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_u128(limbs: &U256Limbs) -> u128 {
+        limbs[..4].iter().rev().fold(0, |acc, &limb| (acc << 32) | limb as u128)
+    }
+
+    fn from_u64(value: u64) -> U256Limbs {
+        let mut limbs = [0u32; 8];
+        limbs[0] = value as u32;
+        limbs[1] = (value >> 32) as u32;
+        limbs
+    }
+
+    #[test]
+    fn mul_mod_small_values() {
+        let a = u64::MAX - 5;
+        let b = 0x1234_5678_9abc_def0;
+        let m = 0xffff_ffff_0000_0001;
+
+        let (quotient, remainder) = mul_mod(&from_u64(a), &from_u64(b), &from_u64(m)).unwrap();
+
+        let product = a as u128 * b as u128;
+        assert_eq!(to_u128(&quotient), product / m as u128);
+        assert_eq!(to_u128(&remainder), product % m as u128);
+    }
+
+    #[test]
+    fn mul_mod_large_values() {
+        // the result is checked via q * m + r == a * b and r < m
+        let a = [u32::MAX; 8];
+        let m = [
+            0xfffffc2f,
+            0xfffffffe,
+            u32::MAX,
+            u32::MAX,
+            u32::MAX,
+            u32::MAX,
+            u32::MAX,
+            u32::MAX,
+        ];
+        let mut b = m;
+        b[0] -= 1;
+
+        let (quotient, remainder) = mul_mod(&a, &b, &m).unwrap();
+
+        let mut expected = mul_wide(&quotient, &m);
+        let mut carry = 0u64;
+        for (i, limb) in expected.iter_mut().enumerate() {
+            let value = *limb as u64 + remainder.get(i).copied().unwrap_or(0) as u64 + carry;
+            *limb = value as u32;
+            carry = value >> 32;
+        }
+        assert_eq!(carry, 0);
+        assert_eq!(expected, mul_wide(&a, &b));
+        let mut extended_remainder = [0u32; 9];
+        extended_remainder[..8].copy_from_slice(&remainder);
+        assert!(is_less(&extended_remainder, &m));
+    }
+
+    #[test]
+    fn mul_mod_rejects_zero_modulus() {
+        assert!(mul_mod(&from_u64(3), &from_u64(5), &[0; 8]).is_none());
+    }
+}
@@ -0,0 +1,274 @@
+// U384 ARITHMETIC
+// ================================================================================================
+
+/// A 384-bit unsigned integer represented as 12 little-endian u32 limbs, i.e., the limb at index
+/// `0` holds the least significant 32 bits.
+///
+/// This is the representation used for 384-bit values by the procedures in
+/// `miden::crypto::bls12_381`.
+pub type U384Limbs = [u32; 12];
+
+/// A 416-bit unsigned integer, which holds intermediate values exceeding 384 bits.
+type WideLimbs = [u32; 13];
+
+/// Returns the quotient and the remainder of `a * b` divided by `m`.
+///
+/// Returns `None` if `m` is zero or if the quotient does not fit into 384 bits, which cannot
+/// happen if at least one of `a` and `b` is smaller than `m`.
+pub fn mul_mod(a: &U384Limbs, b: &U384Limbs, m: &U384Limbs) -> Option<(U384Limbs, U384Limbs)> {
+    if is_zero(m) {
+        return None;
+    }
+
+    let product = mul_wide(a, b);
+
+    // schoolbook binary long division, the remainder is always smaller than 2m < 2^385 and thus
+    // needs one extra limb
+    let mut quotient = [0u32; 24];
+    let mut remainder = [0u32; 13];
+    for bit in (0..768).rev() {
+        // remainder = 2 * remainder + bit
+        let mut carry = (product[bit / 32] >> (bit % 32)) & 1;
+        for limb in remainder.iter_mut() {
+            let next_carry = *limb >> 31;
+            *limb = (*limb << 1) | carry;
+            carry = next_carry;
+        }
+
+        if !is_less(&remainder, &widen(m)) {
+            sub_assign(&mut remainder, &widen(m));
+            quotient[bit / 32] |= 1 << (bit % 32);
+        }
+    }
+
+    if quotient[12..].iter().any(|&limb| limb != 0) {
+        return None;
+    }
+
+    let quotient = quotient[..12].try_into().expect("slice should have 12 limbs");
+    let remainder = remainder[..12].try_into().expect("slice should have 12 limbs");
+    Some((quotient, remainder))
+}
+
+/// Returns the multiplicative inverse of `a` modulo `m`.
+///
+/// Returns `None` if `m` is even or smaller than two, or if `a` is not invertible modulo `m`.
+pub fn inv_mod(a: &U384Limbs, m: &U384Limbs) -> Option<U384Limbs> {
+    if m[0] & 1 == 0 || is_less(&widen(m), &widen(&one())) || widen(m) == widen(&one()) {
+        return None;
+    }
+
+    let (_, a) = mul_mod(a, &one(), m)?;
+    if is_zero(&a) {
+        return None;
+    }
+
+    // binary extended Euclidean algorithm, which maintains the invariants x1 * a = u (mod m) and
+    // x2 * a = v (mod m)
+    let m = widen(m);
+    let mut u = widen(&a);
+    let mut v = m;
+    let mut x1 = widen(&one());
+    let mut x2 = [0u32; 13];
+
+    while u != widen(&one()) && v != widen(&one()) {
+        if is_zero(&u) || is_zero(&v) {
+            // gcd(a, m) is not one
+            return None;
+        }
+
+        while u[0] & 1 == 0 {
+            halve_mod(&mut u, &mut x1, &m);
+        }
+        while v[0] & 1 == 0 {
+            halve_mod(&mut v, &mut x2, &m);
+        }
+
+        if is_less(&u, &v) {
+            sub_assign(&mut v, &u);
+            sub_mod(&mut x2, &x1, &m);
+        } else {
+            sub_assign(&mut u, &v);
+            sub_mod(&mut x1, &x2, &m);
+        }
+    }
+
+    let inverse = if u == widen(&one()) { x1 } else { x2 };
+    Some(inverse[..12].try_into().expect("slice should have 12 limbs"))
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the 768-bit product of `a` and `b` as 24 little-endian u32 limbs.
+fn mul_wide(a: &U384Limbs, b: &U384Limbs) -> [u32; 24] {
+    let mut product = [0u32; 24];
+    for (i, &a_limb) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &b_limb) in b.iter().enumerate() {
+            let value = product[i + j] as u64 + a_limb as u64 * b_limb as u64 + carry;
+            product[i + j] = value as u32;
+            carry = value >> 32;
+        }
+        product[i + 12] = carry as u32;
+    }
+    product
+}
+
+/// Returns the value one.
+fn one() -> U384Limbs {
+    let mut one = [0u32; 12];
+    one[0] = 1;
+    one
+}
+
+/// Returns the provided value extended by a zero limb.
+fn widen(a: &U384Limbs) -> WideLimbs {
+    let mut wide = [0u32; 13];
+    wide[..12].copy_from_slice(a);
+    wide
+}
+
+/// Returns true if all limbs of `a` are zero.
+fn is_zero(a: &[u32]) -> bool {
+    a.iter().all(|&limb| limb == 0)
+}
+
+/// Returns true if `a` is smaller than `b`.
+fn is_less(a: &WideLimbs, b: &WideLimbs) -> bool {
+    for (a_limb, b_limb) in a.iter().zip(b.iter()).rev() {
+        if a_limb != b_limb {
+            return a_limb < b_limb;
+        }
+    }
+
+    false
+}
+
+/// Adds `b` to `a`, assuming the sum does not overflow.
+fn add_assign(a: &mut WideLimbs, b: &WideLimbs) {
+    let mut carry = 0u64;
+    for (a_limb, &b_limb) in a.iter_mut().zip(b.iter()) {
+        let value = *a_limb as u64 + b_limb as u64 + carry;
+        *a_limb = value as u32;
+        carry = value >> 32;
+    }
+}
+
+/// Subtracts `b` from `a`, assuming `a >= b`.
+fn sub_assign(a: &mut WideLimbs, b: &WideLimbs) {
+    let mut borrow = 0u64;
+    for (a_limb, &b_limb) in a.iter_mut().zip(b.iter()) {
+        let (value, underflow) = (*a_limb as u64).overflowing_sub(b_limb as u64 + borrow);
+        *a_limb = value as u32;
+        borrow = underflow as u64;
+    }
+}
+
+/// Computes `a - b (mod m)`, assuming both values are smaller than `m`.
+fn sub_mod(a: &mut WideLimbs, b: &WideLimbs, m: &WideLimbs) {
+    if is_less(a, b) {
+        add_assign(a, m);
+    }
+    sub_assign(a, b);
+}
+
+/// Halves the even value `u` and computes `x / 2 (mod m)` for the odd modulus `m`.
+fn halve_mod(u: &mut WideLimbs, x: &mut WideLimbs, m: &WideLimbs) {
+    shr_assign(u);
+    if x[0] & 1 == 1 {
+        add_assign(x, m);
+    }
+    shr_assign(x);
+}
+
+/// Shifts `a` to the right by one bit.
+fn shr_assign(a: &mut WideLimbs) {
+    let mut carry = 0;
+    for limb in a.iter_mut().rev() {
+        let next_carry = *limb & 1;
+        *limb = (*limb >> 1) | (carry << 31);
+        carry = next_carry;
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_u64(value: u64) -> U384Limbs {
+        let mut limbs = [0u32; 12];
+        limbs[0] = value as u32;
+        limbs[1] = (value >> 32) as u32;
+        limbs
+    }
+
+    /// The modulus of the base field of BLS12-381.
+    const P: U384Limbs = [
+        0xffffaaab, 0xb9feffff, 0xb153ffff, 0x1eabfffe, 0xf6b0f624, 0x6730d2a0, 0xf38512bf,
+        0x64774b84, 0x434bacd7, 0x4b1ba7b6, 0x397fe69a, 0x1a0111ea,
+    ];
+
+    #[test]
+    fn mul_mod_small_values() {
+        let a = u64::MAX - 5;
+        let b = 0x1234_5678_9abc_def0;
+        let m = 0xffff_ffff_0000_0001;
+
+        let (quotient, remainder) = mul_mod(&from_u64(a), &from_u64(b), &from_u64(m)).unwrap();
+
+        let product = a as u128 * b as u128;
+        let expected_quotient = product / m as u128;
+        assert_eq!(quotient[..4], [0, 1, 2, 3].map(|i| (expected_quotient >> (32 * i)) as u32));
+        assert_eq!(remainder, from_u64((product % m as u128) as u64));
+    }
+
+    #[test]
+    fn mul_mod_large_values() {
+        // the result is checked via q * m + r == a * b and r < m
+        let mut a = P;
+        a[0] -= 1;
+        let b = a;
+
+        let (quotient, remainder) = mul_mod(&a, &b, &P).unwrap();
+
+        let mut expected = mul_wide(&quotient, &P);
+        let mut carry = 0u64;
+        for (i, limb) in expected.iter_mut().enumerate() {
+            let value = *limb as u64 + remainder.get(i).copied().unwrap_or(0) as u64 + carry;
+            *limb = value as u32;
+            carry = value >> 32;
+        }
+        assert_eq!(carry, 0);
+        assert_eq!(expected, mul_wide(&a, &b));
+        assert!(is_less(&widen(&remainder), &widen(&P)));
+
+        // (p - 1)^2 = 1 (mod p)
+        assert_eq!(remainder, one());
+    }
+
+    #[test]
+    fn inv_mod_field_elements() {
+        for a in [from_u64(2), from_u64(u64::MAX), [0x1234_5678; 12]] {
+            let inverse = inv_mod(&a, &P).unwrap();
+            let (_, product) = mul_mod(&a, &inverse, &P).unwrap();
+            assert_eq!(product, one());
+        }
+    }
+
+    #[test]
+    fn inv_mod_rejects_non_invertible_values() {
+        assert!(inv_mod(&[0; 12], &P).is_none());
+        assert!(inv_mod(&P, &P).is_none());
+        assert!(inv_mod(&from_u64(3), &from_u64(15)).is_none());
+        assert!(inv_mod(&from_u64(3), &from_u64(16)).is_none());
+    }
+
+    #[test]
+    fn mul_mod_rejects_zero_modulus() {
+        assert!(mul_mod(&from_u64(3), &from_u64(5), &[0; 12]).is_none());
+    }
+}
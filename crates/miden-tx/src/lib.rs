@@ -9,20 +9,33 @@ extern crate std;
 pub use miden_objects::transaction::TransactionInputs;
 
 mod executor;
-pub use executor::{DataStore, TransactionExecutor, TransactionMastStore};
+#[cfg(feature = "file-store")]
+pub use executor::FileDataStore;
+pub use executor::{
+    Breakpoint, BreakpointHit, DataStore, ForeignAccountInputs, ForeignAccountInputsBuilder,
+    ForeignAccountProvider, FpiInputs, NoteConsumability, NoteConsumptionChecker, TransactionCost,
+    TransactionCostModel, TransactionDebugReport, TransactionExecutor, TransactionMastStore,
+    TransactionReplayBundle, TransactionSimulation,
+};
 
 pub mod host;
-pub use host::{TransactionHost, TransactionProgress};
+pub use host::{
+    EventHandlerError, KernelEvent, TransactionHost, TransactionProgress, TransactionStage,
+};
 
 mod prover;
-pub use prover::{LocalTransactionProver, ProvingOptions, TransactionProver};
+pub use prover::{
+    LocalTransactionProver, ProvingOptions, ProvingProfile, TransactionProver,
+    TransactionProvingOptions,
+};
 
 mod verifier;
 pub use verifier::TransactionVerifier;
 
 mod errors;
 pub use errors::{
-    AuthenticationError, DataStoreError, TransactionExecutorError, TransactionProverError,
+    AuthenticationError, DataStoreError, ForeignAccountInputsError, KernelAssertionError,
+    ProvingOptionsError, TransactionExecutorError, TransactionHostError, TransactionProverError,
     TransactionVerifierError,
 };
 
@@ -2,11 +2,15 @@
 use alloc::boxed::Box;
 use alloc::{sync::Arc, vec::Vec};
 
-use miden_lib::transaction::TransactionKernel;
+use miden_lib::transaction::{TransactionKernel, VersionedTransactionKernel};
 use miden_objects::{
-    account::delta::AccountUpdateDetails,
+    account::{AccountCode, AccountDelta, AccountHeader, delta::AccountUpdateDetails},
     assembly::Library,
-    transaction::{OutputNote, ProvenTransaction, ProvenTransactionBuilder, TransactionWitness},
+    transaction::{
+        OutputNote, ProvenTransaction, ProvenTransactionBuilder, RedactedTransactionWitness,
+        TransactionOutputs, TransactionWitness,
+    },
+    vm::{AdviceInputs, ExecutionProof, StackInputs},
 };
 pub use miden_prover::ProvingOptions;
 use miden_prover::prove;
@@ -16,11 +20,21 @@ use winter_maybe_async::*;
 use super::{TransactionHost, TransactionProverError};
 use crate::executor::TransactionMastStore;
 
+mod options;
+pub use options::{ProvingProfile, TransactionProvingOptions};
+
 // TRANSACTION PROVER TRAIT
 // ================================================================================================
 
 /// The [TransactionProver] trait defines the interface that transaction witness objects use to
 /// prove transactions and generate a [ProvenTransaction].
+///
+/// Proving only requires the [TransactionWitness] of a transaction and produces a
+/// [ProvenTransaction], both of which are serializable. This allows proving to be delegated to a
+/// remote service: the [LocalTransactionProver] proves transactions in-process, while the
+/// `RemoteTransactionProver` of the `miden-proving-service-client` crate sends the serialized
+/// witness to a proving service and deserializes the proven transaction from its response. Code
+/// written against this trait can switch between both without further changes.
 #[maybe_async_trait]
 pub trait TransactionProver {
     /// Proves the provided transaction and returns a [ProvenTransaction].
@@ -45,6 +59,7 @@ pub trait TransactionProver {
 pub struct LocalTransactionProver {
     mast_store: Arc<TransactionMastStore>,
     proof_options: ProvingOptions,
+    kernel: VersionedTransactionKernel,
 }
 
 impl LocalTransactionProver {
@@ -53,9 +68,25 @@ impl LocalTransactionProver {
         Self {
             mast_store: Arc::new(TransactionMastStore::new()),
             proof_options,
+            kernel: TransactionKernel::latest(),
         }
     }
 
+    /// Creates a new [LocalTransactionProver] instance which generates proofs according to the
+    /// provided [TransactionProvingOptions].
+    pub fn with_options(options: TransactionProvingOptions) -> Self {
+        Self::new(options.into())
+    }
+
+    /// Sets the version of the transaction kernel transactions are proven with.
+    ///
+    /// By default, transactions are proven with the latest transaction kernel. The kernel must be
+    /// the same as the one the transaction was executed with, otherwise proving fails.
+    pub fn with_kernel(mut self, kernel: VersionedTransactionKernel) -> Self {
+        self.kernel = kernel;
+        self
+    }
+
     /// Loads the provided library code into the internal MAST forest store.
     ///
     /// TODO: this is a work-around to support accounts which were complied with user-defined
@@ -70,44 +101,144 @@ impl Default for LocalTransactionProver {
         Self {
             mast_store: Arc::new(TransactionMastStore::new()),
             proof_options: Default::default(),
+            kernel: TransactionKernel::latest(),
         }
     }
 }
 
-#[maybe_async_trait]
-impl TransactionProver for LocalTransactionProver {
+#[cfg(all(feature = "std", not(feature = "async")))]
+impl LocalTransactionProver {
+    /// Proves the provided independent transactions in parallel using `num_threads` worker
+    /// threads and returns the result of proving each transaction.
+    ///
+    /// The results are in the same order as the provided witnesses, and a failure to prove one
+    /// transaction does not affect the others. Transactions are independent if none of them
+    /// depends on the outputs of another, e.g. they are executed against different accounts.
+    pub fn prove_many(
+        &self,
+        tx_witnesses: Vec<TransactionWitness>,
+        num_threads: core::num::NonZeroUsize,
+    ) -> Vec<Result<ProvenTransaction, TransactionProverError>> {
+        use std::sync::Mutex;
+
+        let num_witnesses = tx_witnesses.len();
+        let num_threads = num_threads.get().min(num_witnesses);
+        let queue = Mutex::new(tx_witnesses.into_iter().enumerate());
+
+        let mut results: Vec<_> = std::thread::scope(|scope| {
+            let workers: Vec<_> = (0..num_threads)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut results = Vec::new();
+                        loop {
+                            // release the lock before proving so that other workers can proceed
+                            let next =
+                                queue.lock().expect("queue lock should not be poisoned").next();
+                            let Some((index, tx_witness)) = next else {
+                                break;
+                            };
+                            results.push((index, self.prove(tx_witness)));
+                        }
+                        results
+                    })
+                })
+                .collect();
+
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().expect("proving thread should not panic"))
+                .collect()
+        });
+
+        results.sort_by_key(|(index, _)| *index);
+        debug_assert_eq!(results.len(), num_witnesses);
+
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+}
+
+impl LocalTransactionProver {
+    /// Proves the transaction described by the provided [RedactedTransactionWitness] and returns a
+    /// [ProvenTransaction].
+    ///
+    /// This allows proving to be delegated without revealing the account vault assets and storage
+    /// map entries which were not accessed by the transaction.
+    ///
+    /// # Errors
+    /// - If the input note data in the transaction witness is corrupt.
+    /// - If the transaction program cannot be proven.
+    /// - If the transaction result is corrupt.
     #[maybe_async]
-    fn prove(
+    pub fn prove_redacted(
         &self,
-        tx_witness: TransactionWitness,
+        tx_witness: RedactedTransactionWitness,
     ) -> Result<ProvenTransaction, TransactionProverError> {
-        let TransactionWitness {
-            tx_inputs,
-            tx_args,
-            advice_witness,
+        // load the store with account/note/tx_script MASTs
+        self.mast_store.load_account_code(&tx_witness.account_code);
+        for note in tx_witness.input_notes.iter() {
+            self.mast_store.insert(note.note().script().mast().clone());
+        }
+        if let Some(tx_script) = tx_witness.tx_args.tx_script() {
+            self.mast_store.insert(tx_script.mast().clone());
+        }
+
+        let (stack_inputs, advice_inputs) = self.kernel.prepare_redacted_inputs(&tx_witness);
+
+        let RedactedTransactionWitness {
+            account,
+            block_header,
+            input_notes,
             account_codes,
+            ..
         } = tx_witness;
 
-        for account_code in &account_codes {
-            // load the code mast forest to the mast store
-            self.mast_store.load_account_code(account_code);
-        }
+        let (tx_outputs, account_delta, proof) = maybe_await!(self.prove_transaction_program(
+            account.clone(),
+            stack_inputs,
+            advice_inputs,
+            &account_codes
+        ))?;
 
-        let account = tx_inputs.account();
-        let input_notes = tx_inputs.input_notes();
-        let ref_block_num = tx_inputs.block_header().block_num();
-        let ref_block_commitment = tx_inputs.block_header().commitment();
+        // erase private note information (convert private full notes to just headers)
+        let output_notes: Vec<_> = tx_outputs.output_notes.iter().map(OutputNote::shrink).collect();
 
-        // execute and prove
-        let (stack_inputs, advice_inputs) =
-            TransactionKernel::prepare_inputs(&tx_inputs, &tx_args, Some(advice_witness));
-        let advice_provider: MemAdviceProvider = advice_inputs.into();
+        let builder = ProvenTransactionBuilder::new(
+            account.id(),
+            account.commitment(),
+            tx_outputs.account.commitment(),
+            block_header.block_num(),
+            block_header.commitment(),
+            tx_outputs.expiration_block_num,
+            proof,
+        )
+        .fee(tx_outputs.fee)
+        .add_input_notes(&input_notes)
+        .add_output_notes(output_notes);
 
-        // load the store with account/note/tx_script MASTs
-        self.mast_store.load_transaction_code(&tx_inputs, &tx_args);
+        // redacted witnesses cannot be created for new accounts, so the account update of a public
+        // account is always a delta
+        let builder = match account.id().is_public() {
+            true => builder.account_update_details(AccountUpdateDetails::Delta(account_delta)),
+            false => builder,
+        };
+
+        builder.build().map_err(TransactionProverError::ProvenTransactionBuildFailed)
+    }
+
+    /// Proves the transaction kernel program with the provided inputs and returns the outputs of
+    /// the transaction, the delta of the account and the proof.
+    #[maybe_async]
+    fn prove_transaction_program(
+        &self,
+        account: AccountHeader,
+        stack_inputs: StackInputs,
+        advice_inputs: AdviceInputs,
+        account_codes: &[AccountCode],
+    ) -> Result<(TransactionOutputs, AccountDelta, ExecutionProof), TransactionProverError> {
+        let advice_provider: MemAdviceProvider = advice_inputs.into();
 
         let mut host: TransactionHost<_> = TransactionHost::new(
-            account.into(),
+            account,
             advice_provider,
             self.mast_store.clone(),
             None,
@@ -116,7 +247,7 @@ impl TransactionProver for LocalTransactionProver {
         .map_err(TransactionProverError::TransactionHostCreationFailed)?;
 
         let (stack_outputs, proof) = maybe_await!(prove(
-            &TransactionKernel::main(),
+            &self.kernel.main(),
             stack_inputs,
             &mut host,
             self.proof_options.clone()
@@ -131,6 +262,48 @@ impl TransactionProver for LocalTransactionProver {
             TransactionKernel::from_transaction_parts(&stack_outputs, &map.into(), output_notes)
                 .map_err(TransactionProverError::TransactionOutputConstructionFailed)?;
 
+        Ok((tx_outputs, account_delta, proof))
+    }
+}
+
+#[maybe_async_trait]
+impl TransactionProver for LocalTransactionProver {
+    #[maybe_async]
+    fn prove(
+        &self,
+        tx_witness: TransactionWitness,
+    ) -> Result<ProvenTransaction, TransactionProverError> {
+        let TransactionWitness {
+            tx_inputs,
+            tx_args,
+            advice_witness,
+            account_codes,
+        } = tx_witness;
+
+        for account_code in &account_codes {
+            // load the code mast forest to the mast store
+            self.mast_store.load_account_code(account_code);
+        }
+
+        let account = tx_inputs.account();
+        let input_notes = tx_inputs.input_notes();
+        let ref_block_num = tx_inputs.block_header().block_num();
+        let ref_block_commitment = tx_inputs.block_header().commitment();
+
+        // execute and prove
+        let (stack_inputs, advice_inputs) =
+            self.kernel.prepare_inputs(&tx_inputs, &tx_args, Some(advice_witness));
+
+        // load the store with account/note/tx_script MASTs
+        self.mast_store.load_transaction_code(&tx_inputs, &tx_args);
+
+        let (tx_outputs, account_delta, proof) = maybe_await!(self.prove_transaction_program(
+            account.into(),
+            stack_inputs,
+            advice_inputs,
+            &account_codes
+        ))?;
+
         // erase private note information (convert private full notes to just headers)
         let output_notes: Vec<_> = tx_outputs.output_notes.iter().map(OutputNote::shrink).collect();
 
@@ -143,6 +316,7 @@ impl TransactionProver for LocalTransactionProver {
             tx_outputs.expiration_block_num,
             proof,
         )
+        .fee(tx_outputs.fee)
         .add_input_notes(input_notes)
         .add_output_notes(output_notes);
 
@@ -167,3 +341,132 @@ impl TransactionProver for LocalTransactionProver {
         builder.build().map_err(TransactionProverError::ProvenTransactionBuildFailed)
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use miden_lib::transaction::TransactionKernelError;
+    use miden_objects::{
+        MIN_PROOF_SECURITY_LEVEL,
+        transaction::{TransactionArgs, TransactionScript},
+        utils::{Deserializable, Serializable},
+    };
+
+    use super::*;
+    use crate::{TransactionVerifier, tests::fixtures::TransactionFixture};
+
+    #[test]
+    fn prove_many_reports_results_per_transaction() {
+        let executed_transaction =
+            TransactionFixture::with_mock_notes().tx_context.execute().unwrap();
+        let executed_transaction_id = executed_transaction.id();
+        let tx_witness = TransactionWitness::from(executed_transaction);
+
+        // a witness whose transaction script fails cannot be proven
+        let failing_script = TransactionScript::compile(
+            "begin push.0 assert end",
+            [],
+            TransactionKernel::testing_assembler(),
+        )
+        .unwrap();
+        let mut failing_witness = tx_witness.clone();
+        failing_witness.tx_args = TransactionArgs::with_tx_script(failing_script);
+        // the recorded advice witness replays the original transaction script root, so it is
+        // dropped to make the prover read the root of the failing script instead
+        failing_witness.advice_witness = AdviceInputs::default();
+
+        let prover = LocalTransactionProver::default();
+        let results = prover.prove_many(
+            vec![failing_witness, tx_witness],
+            core::num::NonZeroUsize::new(2).unwrap(),
+        );
+
+        assert_eq!(results.len(), 2);
+        assert_matches!(
+            results[0],
+            Err(TransactionProverError::TransactionProgramExecutionFailed(_))
+        );
+        assert_eq!(results[1].as_ref().unwrap().id(), executed_transaction_id);
+    }
+
+    #[test]
+    fn minimized_witness_can_be_proven() {
+        let tx_context = TransactionFixture::with_mock_notes().tx_context;
+        let code_commitment = tx_context.account().code().commitment();
+        let executed_transaction = tx_context.execute().unwrap();
+        let executed_transaction_id = executed_transaction.id();
+        let mut tx_witness = TransactionWitness::from(executed_transaction);
+
+        // the code of an account which is never invoked by the transaction is removed
+        let unused_code = AccountCode::mock();
+        assert_ne!(unused_code.commitment(), code_commitment);
+        tx_witness.account_codes.push(unused_code);
+
+        let minimized_witness = TransactionKernel::minimize_witness(tx_witness.clone());
+        assert!(minimized_witness.account_codes.is_empty());
+        assert_eq!(minimized_witness.advice_witness.stack(), tx_witness.advice_witness.stack());
+        assert!(minimized_witness.to_bytes().len() < tx_witness.to_bytes().len());
+
+        // the kernel data is rebuilt from the transaction inputs, so the advice witness does not
+        // need to contain the procedures of the native account
+        assert!(tx_witness.advice_witness.mapped_values(&code_commitment).is_some());
+        assert!(minimized_witness.advice_witness.mapped_values(&code_commitment).is_none());
+
+        let proven_transaction =
+            LocalTransactionProver::default().prove(minimized_witness).unwrap();
+        assert_eq!(proven_transaction.id(), executed_transaction_id);
+    }
+
+    #[test]
+    fn prove_redacted_witness() {
+        let fixture = TransactionFixture::with_mock_notes();
+        let executed_transaction = fixture.execute(&fixture.executor(), fixture.tx_args()).unwrap();
+        let executed_transaction_id = executed_transaction.id();
+
+        let tx_witness = TransactionWitness::from(executed_transaction);
+        let witness_size = tx_witness.to_bytes().len();
+        let redacted_witness = tx_witness.into_redacted().unwrap();
+
+        // the redacted witness does not contain the full account state
+        let serialized_witness = redacted_witness.to_bytes();
+        assert!(serialized_witness.len() < witness_size);
+        let redacted_witness =
+            RedactedTransactionWitness::read_from_bytes(&serialized_witness).unwrap();
+
+        let prover = LocalTransactionProver::new(ProvingOptions::default());
+        let proven_transaction = prover.prove_redacted(redacted_witness).unwrap();
+        assert_eq!(proven_transaction.id(), executed_transaction_id);
+
+        let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
+        assert!(verifier.verify(&proven_transaction).is_ok());
+    }
+
+    #[test]
+    fn execute_and_prove_with_kernel_version() {
+        let err =
+            TransactionKernel::for_version(TransactionKernel::NUM_VERSIONS as u8).unwrap_err();
+        assert_matches!(err, TransactionKernelError::UnsupportedKernelVersion { .. });
+
+        let kernel = TransactionKernel::for_version(TransactionKernel::LATEST_VERSION).unwrap();
+        assert_eq!(kernel, TransactionKernel::latest());
+        assert_eq!(kernel.program_info(), TransactionKernel::program_info());
+        assert_eq!(kernel.commitment(), TransactionKernel::commitment(kernel.version()));
+
+        let fixture = TransactionFixture::with_mock_notes();
+        for version in 0..TransactionKernel::NUM_VERSIONS as u8 {
+            let kernel = TransactionKernel::for_version(version).unwrap();
+
+            let executor = fixture.executor().with_kernel(kernel);
+            let executed_transaction = fixture.execute(&executor, fixture.tx_args()).unwrap();
+
+            let prover = LocalTransactionProver::new(ProvingOptions::default()).with_kernel(kernel);
+            let proven_transaction = prover.prove(executed_transaction.into()).unwrap();
+
+            let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL).with_kernel(kernel);
+            assert!(verifier.verify(&proven_transaction).is_ok());
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use miden_objects::MIN_PROOF_SECURITY_LEVEL;
+use miden_prover::{HashFunction, ProvingOptions};
+use winter_air::FieldExtension;
+
+use crate::errors::ProvingOptionsError;
+
+// PROVING PROFILE
+// ================================================================================================
+
+/// The kind of proofs generated by the transaction prover.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProvingProfile {
+    /// Proofs which are fast to generate and verify natively, using a BLAKE3 based commitment
+    /// scheme.
+    Fast,
+    /// Proofs which are efficient to verify inside the Miden VM, e.g. for recursive batch and block
+    /// proving, using an algebraic hash function.
+    RecursionFriendly,
+}
+
+// TRANSACTION PROVING OPTIONS
+// ================================================================================================
+
+/// Validated options for proving transactions with the [LocalTransactionProver].
+///
+/// The options consist of a [ProvingProfile], a target security level in bits and the hash
+/// function used by the commitment scheme of the proof. The following combinations are
+/// supported:
+///
+/// | Profile             | Security level | Hash functions             |
+/// | ------------------- | -------------- | -------------------------- |
+/// | `Fast`              | 96             | `Blake3_192`, `Blake3_256` |
+/// | `Fast`              | 128            | `Blake3_256`               |
+/// | `RecursionFriendly` | 96, 128        | `Rpo256`, `Rpx256`         |
+///
+/// [LocalTransactionProver]: super::LocalTransactionProver
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransactionProvingOptions {
+    profile: ProvingProfile,
+    security_level: u32,
+    hash_function: HashFunction,
+}
+
+impl TransactionProvingOptions {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The security levels, in bits, for which proofs can be generated.
+    pub const SUPPORTED_SECURITY_LEVELS: [u32; 2] = [96, 128];
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns new [TransactionProvingOptions] for the provided profile and security level.
+    ///
+    /// The hash function defaults to `Blake3_192` for fast proofs with 96-bit security,
+    /// `Blake3_256` for fast proofs with 128-bit security and `Rpo256` for recursion-friendly
+    /// proofs.
+    ///
+    /// # Errors
+    /// Returns an error if the security level is not one of
+    /// [`Self::SUPPORTED_SECURITY_LEVELS`].
+    pub fn new(profile: ProvingProfile, security_level: u32) -> Result<Self, ProvingOptionsError> {
+        if !Self::SUPPORTED_SECURITY_LEVELS.contains(&security_level)
+            || security_level < MIN_PROOF_SECURITY_LEVEL
+        {
+            return Err(ProvingOptionsError::UnsupportedSecurityLevel(security_level));
+        }
+
+        let hash_function = match (profile, security_level) {
+            (ProvingProfile::Fast, 96) => HashFunction::Blake3_192,
+            (ProvingProfile::Fast, _) => HashFunction::Blake3_256,
+            (ProvingProfile::RecursionFriendly, _) => HashFunction::Rpo256,
+        };
+
+        Ok(Self { profile, security_level, hash_function })
+    }
+
+    /// Returns the options with the hash function of the commitment scheme set to the provided
+    /// one.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - a non-algebraic hash function is chosen for recursion-friendly proofs, or an algebraic
+    ///   hash function is chosen for fast proofs.
+    /// - the hash function does not provide the target security level.
+    pub fn with_hash_function(
+        mut self,
+        hash_function: HashFunction,
+    ) -> Result<Self, ProvingOptionsError> {
+        let is_algebraic = matches!(hash_function, HashFunction::Rpo256 | HashFunction::Rpx256);
+        if is_algebraic != (self.profile == ProvingProfile::RecursionFriendly) {
+            return Err(ProvingOptionsError::IncompatibleHashFunction {
+                profile: self.profile,
+                hash_function,
+            });
+        }
+
+        if hash_function == HashFunction::Blake3_192 && self.security_level > 96 {
+            return Err(ProvingOptionsError::InsufficientHashFunctionSecurity {
+                hash_function,
+                security_level: self.security_level,
+            });
+        }
+
+        self.hash_function = hash_function;
+        Ok(self)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the proving profile.
+    pub fn profile(&self) -> ProvingProfile {
+        self.profile
+    }
+
+    /// Returns the target security level in bits.
+    pub fn security_level(&self) -> u32 {
+        self.security_level
+    }
+
+    /// Returns the hash function used by the commitment scheme of the proof.
+    pub fn hash_function(&self) -> HashFunction {
+        self.hash_function
+    }
+}
+
+impl Default for TransactionProvingOptions {
+    /// Returns the options for fast proofs with 96-bit security, which are equivalent to the
+    /// default [ProvingOptions].
+    fn default() -> Self {
+        Self::new(ProvingProfile::Fast, MIN_PROOF_SECURITY_LEVEL)
+            .expect("minimum proof security level should be supported")
+    }
+}
+
+impl From<TransactionProvingOptions> for ProvingOptions {
+    fn from(options: TransactionProvingOptions) -> Self {
+        // the FRI parameters match the ones of ProvingOptions::with_96_bit_security and
+        // ProvingOptions::with_128_bit_security
+        let (num_queries, blowup_factor, grinding_factor, field_extension) =
+            if options.security_level == 96 {
+                (27, 8, 16, FieldExtension::Quadratic)
+            } else {
+                (27, 16, 21, FieldExtension::Cubic)
+            };
+        let (fri_folding_factor, fri_remainder_max_degree) =
+            match (options.profile, options.security_level) {
+                (ProvingProfile::Fast, _) => (8, 255),
+                (ProvingProfile::RecursionFriendly, 96) => (4, 127),
+                (ProvingProfile::RecursionFriendly, _) => (4, 7),
+            };
+
+        ProvingOptions::new(
+            num_queries,
+            blowup_factor,
+            grinding_factor,
+            field_extension,
+            fri_folding_factor,
+            fri_remainder_max_degree,
+            options.hash_function,
+        )
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::*;
+
+    #[test]
+    fn transaction_proving_options() {
+        let options = TransactionProvingOptions::default();
+        assert_eq!(options.hash_function(), HashFunction::Blake3_192);
+        assert_eq!(ProvingOptions::from(options).hash_fn(), ProvingOptions::default().hash_fn());
+
+        let options = TransactionProvingOptions::new(ProvingProfile::RecursionFriendly, 128)
+            .unwrap()
+            .with_hash_function(HashFunction::Rpx256)
+            .unwrap();
+        assert_eq!(ProvingOptions::from(options).hash_fn(), HashFunction::Rpx256);
+
+        assert_matches!(
+            TransactionProvingOptions::new(ProvingProfile::Fast, 64),
+            Err(ProvingOptionsError::UnsupportedSecurityLevel(64))
+        );
+        assert_matches!(
+            TransactionProvingOptions::default().with_hash_function(HashFunction::Rpo256),
+            Err(ProvingOptionsError::IncompatibleHashFunction { .. })
+        );
+        assert_matches!(
+            TransactionProvingOptions::new(ProvingProfile::Fast, 128)
+                .unwrap()
+                .with_hash_function(HashFunction::Blake3_192),
+            Err(ProvingOptionsError::InsufficientHashFunctionSecurity { security_level: 128, .. })
+        );
+    }
+}
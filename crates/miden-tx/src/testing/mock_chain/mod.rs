@@ -1,15 +1,19 @@
 use alloc::{
     collections::{BTreeMap, BTreeSet},
+    string::ToString,
     vec::Vec,
 };
 
+#[cfg(feature = "block-prover")]
+use miden_block_prover::{LocalBlockProver, ProvenBlockError};
 use miden_lib::{
     account::{auth::RpoFalcon512, faucets::BasicFungibleFaucet, wallets::BasicWallet},
     note::{create_p2id_note, create_p2idr_note},
     transaction::{TransactionKernel, memory},
 };
 use miden_objects::{
-    ACCOUNT_TREE_DEPTH, AccountError, NoteError, ProposedBatchError, ProposedBlockError,
+    ACCOUNT_TREE_DEPTH, AccountError, MIN_PROOF_SECURITY_LEVEL, NoteError, ProposedBatchError,
+    ProposedBlockError,
     account::{
         Account, AccountBuilder, AccountComponent, AccountDelta, AccountId, AccountIdAnchor,
         AccountType, AuthSecretKey, delta::AccountUpdateDetails,
@@ -24,22 +28,35 @@ use miden_objects::{
         dsa::rpo_falcon512::SecretKey,
         merkle::{LeafIndex, Mmr, Smt},
     },
-    note::{Note, NoteHeader, NoteId, NoteInclusionProof, NoteType, Nullifier},
+    note::{
+        Note, NoteHeader, NoteId, NoteInclusionProof, NoteTag, NoteTagFilter, NoteType, Nullifier,
+    },
     testing::account_code::DEFAULT_AUTH_SCRIPT,
     transaction::{
         ChainMmr, ExecutedTransaction, InputNote, InputNotes, OutputNote, ProvenTransaction,
-        ToInputNoteCommitments, TransactionId, TransactionInputs, TransactionScript,
+        ProvenTransactionBuilder, ToInputNoteCommitments, TransactionId, TransactionInputs,
+        TransactionScript,
     },
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    vm::ExecutionProof,
 };
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha20Rng;
 use vm_processor::{
-    Digest, Felt, Word, ZERO,
+    Digest, Felt, ONE, Word, ZERO,
     crypto::{RpoRandomCoin, SimpleSmt},
 };
+use winter_air::proof::Proof;
+use winter_maybe_async::*;
 
 use super::TransactionContextBuilder;
-use crate::auth::BasicAuthenticator;
+use crate::{
+    LocalTransactionProver, TransactionProver, TransactionProverError, TransactionVerifier,
+    auth::BasicAuthenticator,
+};
+
+mod store;
+pub use store::{BatchInputs, MockChainStore, NoteSyncRecord, NoteSyncUpdate};
 
 // AUTH
 // ================================================================================================
@@ -58,10 +75,15 @@ pub enum Auth {
 impl Auth {
     /// Converts `self` into its corresponding authentication [`AccountComponent`] and a
     /// [`BasicAuthenticator`] or `None` when [`Auth::NoAuth`] is passed.
-    fn build_component(&self) -> Option<(AccountComponent, BasicAuthenticator<ChaCha20Rng>)> {
+    ///
+    /// The key of the authenticator is derived from the provided RNG.
+    fn build_component(
+        &self,
+        rng: &mut ChaCha20Rng,
+    ) -> Option<(AccountComponent, BasicAuthenticator<ChaCha20Rng>)> {
         match self {
             Auth::BasicAuth => {
-                let mut rng = ChaCha20Rng::from_seed(Default::default());
+                let mut rng = ChaCha20Rng::from_seed(rng.random());
                 let sec_key = SecretKey::with_rng(&mut rng);
                 let pub_key = sec_key.public_key();
 
@@ -121,7 +143,6 @@ impl MockAccount {
         MockAccount { account, seed, authenticator }
     }
 
-    #[allow(dead_code)]
     pub fn apply_delta(&mut self, delta: &AccountDelta) -> Result<(), AccountError> {
         self.account.apply_delta(delta)
     }
@@ -191,6 +212,163 @@ impl PendingObjects {
     }
 }
 
+// MOCK CHAIN RNG
+// ================================================================================================
+
+/// The random number generators of the [MockChain], which are all derived from a single seed.
+///
+/// Each kind of randomness is drawn from its own stream of the seeded RNG, so that e.g. adding an
+/// account does not change the serial numbers of the notes created afterwards.
+#[derive(Debug, Clone)]
+struct MockChainRng {
+    seed: [u8; 32],
+    /// RNG for the seeds of account IDs.
+    accounts: ChaCha20Rng,
+    /// RNG for the keys of account authenticators.
+    auth: ChaCha20Rng,
+    /// RNG for the serial numbers of notes.
+    notes: ChaCha20Rng,
+}
+
+impl MockChainRng {
+    const ACCOUNTS_STREAM: u64 = 0;
+    const AUTH_STREAM: u64 = 1;
+    const NOTES_STREAM: u64 = 2;
+
+    fn new(seed: [u8; 32]) -> Self {
+        let stream = |stream| {
+            let mut rng = ChaCha20Rng::from_seed(seed);
+            rng.set_stream(stream);
+            rng
+        };
+
+        Self {
+            seed,
+            accounts: stream(Self::ACCOUNTS_STREAM),
+            auth: stream(Self::AUTH_STREAM),
+            notes: stream(Self::NOTES_STREAM),
+        }
+    }
+}
+
+// MOCK CHAIN SNAPSHOT
+// ================================================================================================
+
+/// A snapshot of the state of a [MockChain] taken with [`MockChain::snapshot`].
+///
+/// The snapshot captures the blocks, the account and nullifier trees, the available accounts and
+/// notes, the pending objects, the final states of private accounts, the RNG, the timestamp set for
+/// the next block and the witness corruptions of the chain. It is restored with
+/// [`MockChain::restore`], which allows tests to branch from a common setup. Since restoring
+/// consumes the snapshot, a snapshot which should be restored more than once has to be cloned.
+#[derive(Debug, Clone)]
+pub struct MockChainSnapshot {
+    chain: Mmr,
+    blocks: Vec<ProvenBlock>,
+    nullifiers: Smt,
+    accounts: SimpleSmt<ACCOUNT_TREE_DEPTH>,
+    pending_objects: PendingObjects,
+    available_notes: BTreeMap<NoteId, InputNote>,
+    available_accounts: BTreeMap<AccountId, MockAccount>,
+    removed_notes: Vec<NoteId>,
+    rng: MockChainRng,
+    private_account_states: BTreeMap<TransactionId, Account>,
+    next_block_timestamp: Option<u32>,
+    witness_corruptions: BTreeSet<WitnessCorruption>,
+}
+
+// MOCK CHAIN REORG
+// ================================================================================================
+
+/// Describes the switch of the canonical head of a [MockChain] to a competing fork, as performed
+/// by [`MockChain::reorg`].
+#[derive(Debug, Clone)]
+pub struct MockChainReorg {
+    fork_point: BlockNumber,
+    reverted_blocks: Vec<ProvenBlock>,
+    applied_blocks: Vec<ProvenBlock>,
+}
+
+impl MockChainReorg {
+    /// Returns the number of the latest block which both the previous and the new canonical chain
+    /// have in common.
+    pub fn fork_point(&self) -> BlockNumber {
+        self.fork_point
+    }
+
+    /// Returns the blocks of the previous canonical chain after the fork point, which are no
+    /// longer part of the chain.
+    pub fn reverted_blocks(&self) -> &[ProvenBlock] {
+        &self.reverted_blocks
+    }
+
+    /// Returns the blocks of the new canonical chain after the fork point.
+    pub fn applied_blocks(&self) -> &[ProvenBlock] {
+        &self.applied_blocks
+    }
+
+    /// Returns the IDs of the transactions and the IDs of the accounts they were executed against
+    /// which were included in the reverted blocks, but not in the applied blocks.
+    ///
+    /// These transactions are no longer part of the chain. They can be executed again on the new
+    /// canonical chain with [`MockChain::replay_transaction`].
+    pub fn invalidated_transactions(&self) -> Vec<(TransactionId, AccountId)> {
+        let applied_transactions: BTreeSet<_> =
+            self.applied_blocks.iter().flat_map(ProvenBlock::transactions).collect();
+
+        self.reverted_blocks
+            .iter()
+            .flat_map(ProvenBlock::transactions)
+            .filter(|tx| !applied_transactions.contains(tx))
+            .collect()
+    }
+}
+
+// PROVING MODE
+// ================================================================================================
+
+/// Specifies how the [MockChain] proves transactions, batches and blocks.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ProvingMode {
+    /// Transactions are proven with dummy proofs and batch and block proving skips the
+    /// verification of the proofs they contain. This is fast and sufficient for most tests.
+    #[default]
+    Mocked,
+
+    /// Transactions are proven with the [LocalTransactionProver], and the transaction proofs are
+    /// verified when proving batches and blocks.
+    ///
+    /// Since proving is expensive, transaction proofs are cached by the chain and proving the
+    /// same transaction again returns the cached proof.
+    Full,
+}
+
+// WITNESS CORRUPTION
+// ================================================================================================
+
+/// A deliberate corruption of a witness provided by the [MockChain], which allows testing the
+/// error paths of [`ProposedBatch::new`] and [`ProposedBlock::new`] systematically.
+///
+/// Corruptions are registered with [`MockChain::corrupt_witness`] and affect all witnesses the
+/// chain provides for the targeted object, i.e. the witnesses returned by
+/// [`MockChain::account_witnesses`], [`MockChain::nullifier_witnesses`] and
+/// [`MockChain::unauthenticated_note_proofs`], as well as all block and batch inputs built from
+/// them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WitnessCorruption {
+    /// The witness of the account proves the state of the account before its latest update, or
+    /// the empty state if the account was updated only once, instead of its current state.
+    StaleAccountProof(AccountId),
+
+    /// The witness of the nullifier proves a different leaf of the nullifier tree, so that it does
+    /// not contain a proof for the nullifier.
+    WrongNullifierPath(Nullifier),
+
+    /// The inclusion proof of the note contains a wrong node in its merkle path, so that it does
+    /// not authenticate the note against the note root of its block.
+    MismatchedNoteProof(NoteId),
+}
+
 // MOCK CHAIN
 // ================================================================================================
 
@@ -278,7 +456,29 @@ pub struct MockChain {
 
     removed_notes: Vec<NoteId>,
 
-    rng: ChaCha20Rng, // RNG field
+    /// The RNGs from which all randomness of the chain is derived.
+    rng: MockChainRng,
+
+    /// The mode in which transactions, batches and blocks are proven.
+    proving_mode: ProvingMode,
+
+    /// TransactionId |-> ProvenTransaction mapping of the transactions proven in
+    /// [`ProvingMode::Full`] to avoid proving the same transaction more than once.
+    proven_transactions: BTreeMap<TransactionId, ProvenTransaction>,
+
+    /// TransactionId |-> Account mapping of the final account states of proven transactions
+    /// against private accounts, whose details are not part of the blocks of the chain.
+    private_account_states: BTreeMap<TransactionId, Account>,
+
+    /// The number of seconds by which the timestamp of each sealed block advances over the
+    /// timestamp of the previous block.
+    timestamp_step_secs: u32,
+
+    /// The timestamp explicitly set for the next block, if any.
+    next_block_timestamp: Option<u32>,
+
+    /// The corruptions applied to the witnesses provided by the chain.
+    witness_corruptions: BTreeSet<WitnessCorruption>,
 }
 
 impl Default for MockChain {
@@ -292,7 +492,13 @@ impl Default for MockChain {
             available_notes: BTreeMap::new(),
             available_accounts: BTreeMap::new(),
             removed_notes: vec![],
-            rng: ChaCha20Rng::from_seed(Default::default()), // Initialize RNG with default seed
+            rng: MockChainRng::new(Default::default()),
+            proving_mode: ProvingMode::default(),
+            proven_transactions: BTreeMap::new(),
+            private_account_states: BTreeMap::new(),
+            timestamp_step_secs: Self::TIMESTAMP_STEP_SECS,
+            next_block_timestamp: None,
+            witness_corruptions: BTreeSet::new(),
         }
     }
 }
@@ -306,7 +512,8 @@ impl MockChain {
     pub const TIMESTAMP_START_SECS: u32 = 1700000000;
 
     /// The number of seconds by which a block's timestamp increases over the previous block's
-    /// timestamp, unless overwritten when calling [`Self::seal_block`].
+    /// timestamp by default, unless overwritten with [`Self::set_timestamp_step`] or when calling
+    /// [`Self::seal_block`].
     pub const TIMESTAMP_STEP_SECS: u32 = 10;
 
     // CONSTRUCTORS
@@ -342,9 +549,177 @@ impl MockChain {
         chain
     }
 
+    /// Creates a new `MockChain` with two blocks whose randomness is derived from the provided
+    /// seed.
+    ///
+    /// The seeds of account IDs, the keys of authenticators and the serial numbers of notes
+    /// created by the chain are all derived from this seed, so a test can be replayed exactly by
+    /// using the seed it printed, see [`Self::rng_seed`].
+    pub fn with_rng_seed(seed: [u8; 32]) -> Self {
+        let mut chain = MockChain::default();
+        chain.set_rng_seed(seed);
+        chain.seal_next_block();
+        chain
+    }
+
     /// Sets the seed for the internal RNG.
     pub fn set_rng_seed(&mut self, seed: [u8; 32]) {
-        self.rng = ChaCha20Rng::from_seed(seed);
+        self.rng = MockChainRng::new(seed);
+    }
+
+    /// Returns the seed from which the randomness of the chain is derived.
+    pub fn rng_seed(&self) -> [u8; 32] {
+        self.rng.seed
+    }
+
+    /// Returns a snapshot of the current state of the chain.
+    ///
+    /// The snapshot can later be restored with [`Self::restore`] to undo all changes made to the
+    /// chain after the snapshot was taken.
+    pub fn snapshot(&self) -> MockChainSnapshot {
+        MockChainSnapshot {
+            chain: self.chain.clone(),
+            blocks: self.blocks.clone(),
+            nullifiers: self.nullifiers.clone(),
+            accounts: self.accounts.clone(),
+            pending_objects: self.pending_objects.clone(),
+            available_notes: self.available_notes.clone(),
+            available_accounts: self.available_accounts.clone(),
+            removed_notes: self.removed_notes.clone(),
+            rng: self.rng.clone(),
+            private_account_states: self.private_account_states.clone(),
+            next_block_timestamp: self.next_block_timestamp,
+            witness_corruptions: self.witness_corruptions.clone(),
+        }
+    }
+
+    /// Restores the state of the chain captured in the provided snapshot.
+    ///
+    /// The snapshot is moved into the chain without cloning its state again. The proving mode of
+    /// the chain and the transaction proofs it has cached are retained, so transactions proven on
+    /// one branch do not have to be proven again on another.
+    pub fn restore(&mut self, snapshot: MockChainSnapshot) {
+        let MockChainSnapshot {
+            chain,
+            blocks,
+            nullifiers,
+            accounts,
+            pending_objects,
+            available_notes,
+            available_accounts,
+            removed_notes,
+            rng,
+            private_account_states,
+            next_block_timestamp,
+            witness_corruptions,
+        } = snapshot;
+
+        self.chain = chain;
+        self.blocks = blocks;
+        self.nullifiers = nullifiers;
+        self.accounts = accounts;
+        self.pending_objects = pending_objects;
+        self.available_notes = available_notes;
+        self.available_accounts = available_accounts;
+        self.removed_notes = removed_notes;
+        self.rng = rng;
+        self.private_account_states = private_account_states;
+        self.next_block_timestamp = next_block_timestamp;
+        self.witness_corruptions = witness_corruptions;
+    }
+
+    /// Switches the canonical head of the chain to the competing fork captured in the provided
+    /// snapshot and returns a description of the reorg.
+    ///
+    /// Forks are built by taking a snapshot of the chain at the fork point, building one branch,
+    /// taking a snapshot of it, restoring the fork point and building the other branch. Calling
+    /// this method then makes the first branch canonical again, which reverts all blocks of the
+    /// current branch after the fork point.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the chain and the fork do not share the genesis block.
+    pub fn reorg(&mut self, fork: MockChainSnapshot) -> MockChainReorg {
+        let num_common_blocks = self
+            .blocks
+            .iter()
+            .zip(fork.blocks.iter())
+            .take_while(|(block, fork_block)| block.commitment() == fork_block.commitment())
+            .count();
+        assert!(num_common_blocks > 0, "chain and fork should share the genesis block");
+
+        let reverted_blocks = self.blocks[num_common_blocks..].to_vec();
+        let applied_blocks = fork.blocks[num_common_blocks..].to_vec();
+        let fork_point = self.blocks[num_common_blocks - 1].header().block_num();
+
+        self.restore(fork);
+
+        MockChainReorg {
+            fork_point,
+            reverted_blocks,
+            applied_blocks,
+        }
+    }
+
+    /// Sets the mode in which transactions, batches and blocks are proven.
+    pub fn set_proving_mode(&mut self, proving_mode: ProvingMode) {
+        self.proving_mode = proving_mode;
+    }
+
+    /// Sets the number of seconds by which the timestamp of each sealed block advances over the
+    /// timestamp of the previous block, unless a timestamp is set explicitly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step_secs` is zero, since block timestamps must strictly increase.
+    pub fn set_timestamp_step(&mut self, step_secs: u32) {
+        assert!(step_secs > 0, "timestamp step must be greater than zero");
+        self.timestamp_step_secs = step_secs;
+    }
+
+    /// Sets the timestamp of the next block which is sealed or proposed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `timestamp` is not strictly greater than the timestamp of the latest block.
+    pub fn set_next_block_timestamp(&mut self, timestamp: u32) {
+        if let Some(latest_block) = self.blocks.last() {
+            assert!(
+                timestamp > latest_block.header().timestamp(),
+                "provided timestamp must be strictly greater than the previous block's timestamp"
+            );
+        }
+        self.next_block_timestamp = Some(timestamp);
+    }
+
+    /// Sets the timestamp of the next block which is sealed or proposed to the timestamp of the
+    /// latest block advanced by `secs` seconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `secs` is zero or the chain does not contain any blocks.
+    pub fn advance_time(&mut self, secs: u32) {
+        let latest_timestamp = self
+            .blocks
+            .last()
+            .expect("chain should contain at least one block")
+            .header()
+            .timestamp();
+        self.set_next_block_timestamp(latest_timestamp + secs);
+    }
+
+    /// Registers a corruption of the witnesses provided by the chain, which remains in effect
+    /// until [`Self::clear_witness_corruptions`] is called or a snapshot taken before is restored.
+    ///
+    /// This allows testing the error paths of batch and block validation without crafting invalid
+    /// inputs by hand.
+    pub fn corrupt_witness(&mut self, corruption: WitnessCorruption) {
+        self.witness_corruptions.insert(corruption);
+    }
+
+    /// Removes all witness corruptions registered with [`Self::corrupt_witness`].
+    pub fn clear_witness_corruptions(&mut self) {
+        self.witness_corruptions.clear();
     }
 
     /// Applies the transaction, adding the entities to the mockchain.
@@ -398,7 +773,7 @@ impl MockChain {
         note_type: NoteType,
         reclaim_height: Option<BlockNumber>,
     ) -> Result<Note, NoteError> {
-        let mut rng = RpoRandomCoin::new(Word::default());
+        let mut rng = RpoRandomCoin::new(self.rng.notes.random::<[u64; 4]>().map(Felt::new));
 
         let note = if let Some(height) = reclaim_height {
             create_p2idr_note(
@@ -432,6 +807,51 @@ impl MockChain {
         self.pending_objects.created_nullifiers.push(nullifier);
     }
 
+    /// Proves the provided executed transaction according to the proving mode of the chain and
+    /// returns the resulting [`ProvenTransaction`].
+    ///
+    /// In [`ProvingMode::Full`] the transaction is proven with the [`LocalTransactionProver`] and
+    /// the proof is cached, so that proving the same transaction again returns the cached proof.
+    /// In [`ProvingMode::Mocked`] the proven transaction contains a dummy proof.
+    ///
+    /// This method does not modify the chain state, but the chain remembers the final state of
+    /// private accounts, so that [`Self::apply_block`] can update them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if proving the transaction or building the proven transaction fails.
+    #[maybe_async]
+    pub fn prove_transaction(
+        &mut self,
+        executed_transaction: ExecutedTransaction,
+    ) -> Result<ProvenTransaction, TransactionProverError> {
+        let tx_id = executed_transaction.id();
+
+        if !executed_transaction.initial_account().is_public() {
+            let mut account = executed_transaction.initial_account().clone();
+            account
+                .apply_delta(executed_transaction.account_delta())
+                .map_err(TransactionProverError::AccountDeltaApplyFailed)?;
+            self.private_account_states.insert(tx_id, account);
+        }
+
+        match self.proving_mode {
+            ProvingMode::Mocked => mock_prove_transaction(executed_transaction),
+            ProvingMode::Full => {
+                if let Some(proven_transaction) = self.proven_transactions.get(&tx_id) {
+                    return Ok(proven_transaction.clone());
+                }
+
+                let proven_transaction = maybe_await!(
+                    LocalTransactionProver::default().prove(executed_transaction.into())
+                )?;
+                self.proven_transactions.insert(tx_id, proven_transaction.clone());
+
+                Ok(proven_transaction)
+            },
+        }
+    }
+
     /// Proposes a new transaction batch from the provided transactions and returns it.
     ///
     /// This method does not modify the chain state.
@@ -458,10 +878,25 @@ impl MockChain {
         )
     }
 
-    /// Mock-proves a proposed transaction batch from the provided [`ProposedBatch`] and returns it.
+    /// Proves a proposed transaction batch from the provided [`ProposedBatch`] and returns it.
+    ///
+    /// In [`ProvingMode::Full`] the proofs of all transactions in the batch are verified before
+    /// the batch is built. Batches themselves are not proven yet, so in both modes the resulting
+    /// batch is built without a batch proof.
     ///
     /// This method does not modify the chain state.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the chain is in [`ProvingMode::Full`] and any transaction proof is invalid.
     pub fn prove_transaction_batch(&self, proposed_batch: ProposedBatch) -> ProvenBatch {
+        if self.proving_mode == ProvingMode::Full {
+            let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
+            for tx in proposed_batch.transactions() {
+                verifier.verify(tx).expect("transaction proof should be valid");
+            }
+        }
+
         let (
             _transactions,
             block_header,
@@ -498,27 +933,88 @@ impl MockChain {
         let batches: Vec<_> = batches.into_iter().collect();
         let block_inputs = self.get_block_inputs(batches.iter());
         // We can't access system time because the testing feature does not depend on std at this
-        // time. So we use the minimally correct next timestamp.
-        let timestamp = block_inputs.prev_block_header().timestamp() + 1;
+        // time. So unless a timestamp was set explicitly, we use the minimally correct next
+        // timestamp.
+        let timestamp = self
+            .next_block_timestamp
+            .unwrap_or(block_inputs.prev_block_header().timestamp() + 1);
 
         let proposed_block = ProposedBlock::new_at(block_inputs, batches, timestamp)?;
 
         Ok(proposed_block)
     }
 
+    /// Proves the provided [`ProposedBlock`] with the [`LocalBlockProver`] and returns the
+    /// resulting [`ProvenBlock`].
+    ///
+    /// In [`ProvingMode::Mocked`] the verification of the batches contained in the block is
+    /// skipped.
+    ///
+    /// This method does not modify the chain state. Use [`Self::apply_block`] to add the proven
+    /// block to the chain.
+    #[cfg(feature = "block-prover")]
+    pub fn prove_block(
+        &self,
+        proposed_block: ProposedBlock,
+    ) -> Result<ProvenBlock, ProvenBlockError> {
+        let prover = LocalBlockProver::new(MIN_PROOF_SECURITY_LEVEL);
+
+        match self.proving_mode {
+            ProvingMode::Mocked => prover.prove_without_batch_verification(proposed_block),
+            ProvingMode::Full => prover.prove(proposed_block),
+        }
+    }
+
+    /// Proves the provided executed transactions, puts them into a single batch and proves the
+    /// next block containing this batch, and applies the block to the chain.
+    ///
+    /// All proving steps happen according to the proving mode of the chain. Objects which are
+    /// pending in the chain are not part of the block and remain pending.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no transactions are provided or if proving any of the transactions, the batch or
+    /// the block fails.
+    #[cfg(feature = "block-prover")]
+    #[maybe_async]
+    pub fn prove_next_block(
+        &mut self,
+        executed_transactions: impl IntoIterator<Item = ExecutedTransaction>,
+    ) -> ProvenBlock {
+        let mut proven_transactions = Vec::new();
+        for executed_transaction in executed_transactions {
+            let proven_transaction = maybe_await!(self.prove_transaction(executed_transaction))
+                .expect("transaction should be provable");
+            proven_transactions.push(proven_transaction);
+        }
+
+        let proposed_batch = self
+            .propose_transaction_batch(proven_transactions)
+            .expect("transaction batch should be valid");
+        let proven_batch = self.prove_transaction_batch(proposed_batch);
+
+        let proposed_block = self.propose_block([proven_batch]).expect("block should be valid");
+        let proven_block = self.prove_block(proposed_block).expect("block should be provable");
+
+        self.apply_block(proven_block.clone());
+
+        proven_block
+    }
+
     // OTHER IMPLEMENTATIONS
     // ----------------------------------------------------------------------------------------
 
     /// Adds a new wallet with the specified authentication method and assets.
     pub fn add_new_wallet(&mut self, auth_method: Auth) -> Account {
-        let account_builder = AccountBuilder::new(self.rng.random()).with_component(BasicWallet);
+        let account_builder =
+            AccountBuilder::new(self.rng.accounts.random()).with_component(BasicWallet);
 
         self.add_from_account_builder(auth_method, account_builder, AccountState::New)
     }
 
     /// Adds an existing wallet (nonce == 1) with the specified authentication method and assets.
     pub fn add_existing_wallet(&mut self, auth_method: Auth, assets: Vec<Asset>) -> Account {
-        let account_builder = Account::builder(self.rng.random())
+        let account_builder = Account::builder(self.rng.accounts.random())
             .with_component(BasicWallet)
             .with_assets(assets);
 
@@ -532,7 +1028,7 @@ impl MockChain {
         token_symbol: &str,
         max_supply: u64,
     ) -> MockFungibleFaucet {
-        let account_builder = AccountBuilder::new(self.rng.random())
+        let account_builder = AccountBuilder::new(self.rng.accounts.random())
             .account_type(AccountType::FungibleFaucet)
             .with_component(
                 BasicFungibleFaucet::new(
@@ -558,7 +1054,7 @@ impl MockChain {
         max_supply: u64,
         total_issuance: Option<u64>,
     ) -> MockFungibleFaucet {
-        let mut account_builder = AccountBuilder::new(self.rng.random())
+        let mut account_builder = AccountBuilder::new(self.rng.accounts.random())
             .with_component(
                 BasicFungibleFaucet::new(
                     TokenSymbol::new(token_symbol).unwrap(),
@@ -569,7 +1065,7 @@ impl MockChain {
             )
             .account_type(AccountType::FungibleFaucet);
 
-        let authenticator = match auth_method.build_component() {
+        let authenticator = match auth_method.build_component(&mut self.rng.auth) {
             Some((auth_component, authenticator)) => {
                 account_builder = account_builder.with_component(auth_component);
                 Some(authenticator)
@@ -604,7 +1100,7 @@ impl MockChain {
         mut account_builder: AccountBuilder,
         account_state: AccountState,
     ) -> Account {
-        let authenticator = match auth_method.build_component() {
+        let authenticator = match auth_method.build_component(&mut self.rng.auth) {
             Some((auth_component, authenticator)) => {
                 account_builder = account_builder.with_component(auth_component);
                 Some(authenticator)
@@ -680,6 +1176,38 @@ impl MockChain {
         tx_context_builder
     }
 
+    /// Returns a [TransactionContextBuilder] which executes the provided transaction again
+    /// against the current state of the chain, e.g. after the transaction was invalidated by a
+    /// [reorg](Self::reorg).
+    ///
+    /// The transaction is executed against the same account with the same input notes and
+    /// transaction script. Input notes which are available on the chain are authenticated and all
+    /// other input notes are provided as unauthenticated notes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the account of the transaction is not available on the chain.
+    pub fn replay_transaction(
+        &mut self,
+        transaction: &ExecutedTransaction,
+    ) -> TransactionContextBuilder {
+        let (note_ids, unauthenticated_notes): (Vec<_>, Vec<_>) = transaction
+            .input_notes()
+            .iter()
+            .map(|input_note| input_note.note())
+            .partition(|note| self.available_notes.contains_key(&note.id()));
+        let note_ids: Vec<_> = note_ids.into_iter().map(Note::id).collect();
+        let unauthenticated_notes: Vec<_> = unauthenticated_notes.into_iter().cloned().collect();
+
+        let tx_context_builder =
+            self.build_tx_context(transaction.account_id(), &note_ids, &unauthenticated_notes);
+
+        match transaction.tx_args().tx_script() {
+            Some(tx_script) => tx_context_builder.tx_script(tx_script.clone()),
+            None => tx_context_builder,
+        }
+    }
+
     /// Returns a valid [TransactionInputs] for the specified entities.
     pub fn get_transaction_inputs(
         &self,
@@ -800,7 +1328,10 @@ impl MockChain {
     /// If `block_num` is `None`, the next block is created, otherwise all blocks from the next
     /// block up to and including `block_num` will be created.
     ///
-    /// If a `timestamp` is provided, it will be set on the block with `block_num`.
+    /// If a `timestamp` is provided, it will be set on the block with `block_num`. Otherwise, the
+    /// timestamp set with [`Self::set_next_block_timestamp`] or [`Self::advance_time`] is used for
+    /// the next block, and the timestamp of all other blocks advances by the configured
+    /// [timestamp step](Self::set_timestamp_step).
     pub fn seal_block(&mut self, block_num: Option<u32>, timestamp: Option<u32>) -> ProvenBlock {
         let next_block_num =
             self.blocks.last().map_or(0, |b| b.header().block_num().child().as_u32());
@@ -852,8 +1383,10 @@ impl MockChain {
             let nullifier_root = self.nullifiers.root();
             let note_root = notes_tree.root();
 
-            let mut block_timestamp = previous.map_or(Self::TIMESTAMP_START_SECS, |block| {
-                block.header().timestamp() + Self::TIMESTAMP_STEP_SECS
+            let mut block_timestamp = self.next_block_timestamp.take().unwrap_or_else(|| {
+                previous.map_or(Self::TIMESTAMP_START_SECS, |block| {
+                    block.header().timestamp() + self.timestamp_step_secs
+                })
             });
 
             // Overwrite the block timestamp if we're building the target block.
@@ -941,11 +1474,111 @@ impl MockChain {
         last_block.expect("There should be at least one block generated")
     }
 
+    /// Adds the provided [`ProvenBlock`] to the chain, which must be the next block of the chain.
+    ///
+    /// This updates the account and nullifier trees with the changes of the block, makes its
+    /// public output notes available for consumption and removes the notes it consumed. Objects
+    /// which are pending in the chain are not affected.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - the block does not reference the latest block of the chain.
+    /// - the block updates an available private account through a transaction which was not
+    ///   proven by [`Self::prove_transaction`].
+    /// - the account or nullifier root of the block does not match the roots of the chain after
+    ///   applying the block.
+    pub fn apply_block(&mut self, block: ProvenBlock) {
+        let header = block.header().clone();
+        assert_eq!(
+            header.prev_block_commitment(),
+            self.blocks.last().map_or(Digest::default(), |block| block.commitment()),
+            "block should reference the latest block of the chain"
+        );
+
+        for update in block.updated_accounts() {
+            self.accounts
+                .insert(update.account_id().into(), *update.final_state_commitment());
+
+            if let Some(mock_account) = self.available_accounts.get_mut(&update.account_id()) {
+                match update.details() {
+                    AccountUpdateDetails::New(account) => mock_account.account = account.clone(),
+                    AccountUpdateDetails::Delta(delta) => mock_account
+                        .apply_delta(delta)
+                        .expect("account delta should be applicable to the available account"),
+                    AccountUpdateDetails::Private => {
+                        let last_tx_id = update
+                            .transactions()
+                            .last()
+                            .expect("account update should contain at least one transaction");
+                        mock_account.account = self
+                            .private_account_states
+                            .get(last_tx_id)
+                            .cloned()
+                            .expect("transactions against private accounts should be proven by the chain");
+                    },
+                }
+            }
+
+            for tx_id in update.transactions() {
+                self.private_account_states.remove(tx_id);
+            }
+        }
+
+        // TODO: Implement nullifier tree reset once defined at the protocol level.
+        for nullifier in block.created_nullifiers() {
+            self.nullifiers
+                .insert(nullifier.inner(), [header.block_num().into(), ZERO, ZERO, ZERO]);
+        }
+
+        assert_eq!(header.account_root(), self.accounts.root(), "account roots should match");
+        assert_eq!(header.nullifier_root(), self.nullifiers.root(), "nullifier roots should match");
+
+        let notes_tree = block.build_output_note_tree();
+        for (block_note_index, note) in block.output_notes() {
+            if let OutputNote::Full(note) = note {
+                let note_path = notes_tree.get_note_path(block_note_index);
+                let note_inclusion_proof = NoteInclusionProof::new(
+                    header.block_num(),
+                    block_note_index.leaf_index_value(),
+                    note_path,
+                )
+                .unwrap();
+
+                self.available_notes.insert(
+                    note.id(),
+                    InputNote::authenticated(note.clone(), note_inclusion_proof),
+                );
+            }
+        }
+
+        let created_nullifiers: BTreeSet<_> = block.created_nullifiers().iter().copied().collect();
+        self.available_notes
+            .retain(|_, note| !created_nullifiers.contains(&note.note().nullifier()));
+
+        self.chain.add(header.commitment());
+        self.blocks.push(block);
+        self.next_block_timestamp = None;
+    }
+
     fn reset_pending(&mut self) {
         self.pending_objects = PendingObjects::new();
         self.removed_notes = vec![];
     }
 
+    /// Returns the state commitment of the account before its latest update in the chain, or the
+    /// empty commitment if the account was updated at most once.
+    fn previous_account_commitment(&self, account_id: AccountId) -> Digest {
+        self.blocks
+            .iter()
+            .rev()
+            .flat_map(ProvenBlock::updated_accounts)
+            .filter(|update| update.account_id() == account_id)
+            .map(BlockAccountUpdate::final_state_commitment)
+            .nth(1)
+            .unwrap_or_default()
+    }
+
     // ACCESSORS
     // =========================================================================================
 
@@ -999,7 +1632,16 @@ impl MockChain {
 
         for account_id in account_ids {
             let proof = self.accounts.open(&account_id.into());
-            account_witnesses.insert(account_id, AccountWitness::new(proof.value, proof.path));
+            let state_commitment = if self
+                .witness_corruptions
+                .contains(&WitnessCorruption::StaleAccountProof(account_id))
+            {
+                self.previous_account_commitment(account_id)
+            } else {
+                proof.value
+            };
+
+            account_witnesses.insert(account_id, AccountWitness::new(state_commitment, proof.path));
         }
 
         account_witnesses
@@ -1013,7 +1655,16 @@ impl MockChain {
         let mut nullifier_proofs = BTreeMap::new();
 
         for nullifier in nullifiers {
-            let proof = self.nullifiers.open(&nullifier.inner());
+            let mut key: Word = nullifier.inner().into();
+            if self
+                .witness_corruptions
+                .contains(&WitnessCorruption::WrongNullifierPath(nullifier))
+            {
+                // the leaf index of a key is determined by its most significant element
+                key[3] += ONE;
+            }
+
+            let proof = self.nullifiers.open(&key.into());
             nullifier_proofs.insert(nullifier, NullifierWitness::new(proof));
         }
 
@@ -1030,13 +1681,24 @@ impl MockChain {
         let mut proofs = BTreeMap::default();
         for note in notes {
             if let Some(input_note) = self.available_notes.get(&note) {
-                proofs.insert(
-                    note,
-                    input_note
-                        .proof()
-                        .cloned()
-                        .expect("all notes tracked by the chain are authenticated"),
-                );
+                let mut proof = input_note
+                    .proof()
+                    .cloned()
+                    .expect("all notes tracked by the chain are authenticated");
+
+                if self.witness_corruptions.contains(&WitnessCorruption::MismatchedNoteProof(note))
+                {
+                    let mut note_path = proof.note_path().clone();
+                    note_path[0] = self.latest_block_header().commitment();
+                    proof = NoteInclusionProof::new(
+                        proof.location().block_num(),
+                        proof.location().node_index_in_block(),
+                        note_path,
+                    )
+                    .expect("node index of the proof should be valid");
+                }
+
+                proofs.insert(note, proof);
             }
         }
 
@@ -1068,6 +1730,53 @@ impl MockChain {
         &self.available_notes
     }
 
+    /// Returns the currently available notes whose tags match the provided filter.
+    pub fn available_notes_matching(&self, filter: &NoteTagFilter) -> Vec<InputNote> {
+        self.notes_by_tag(filter).into_iter().cloned().collect()
+    }
+
+    /// Returns references to the unconsumed notes on the chain whose tags match the provided
+    /// filter.
+    pub fn notes_by_tag(&self, filter: &NoteTagFilter) -> Vec<&InputNote> {
+        self.available_notes
+            .values()
+            .filter(|note| filter.matches(note.note().metadata().tag()))
+            .collect()
+    }
+
+    /// Returns references to the unconsumed notes on the chain which target the provided account.
+    ///
+    /// A note targets an account if its tag is the tag derived from the account ID for the
+    /// execution mode of the tag, as done by [`NoteTag::from_account_id`].
+    pub fn unconsumed_notes_for(&self, account_id: AccountId) -> Vec<&InputNote> {
+        self.available_notes
+            .values()
+            .filter(|note| {
+                let tag = note.note().metadata().tag();
+                NoteTag::from_account_id(account_id, tag.execution_mode())
+                    .is_ok_and(|account_tag| account_tag == tag)
+            })
+            .collect()
+    }
+
+    /// Returns `true` if the provided nullifier has been recorded in the nullifier tree of the
+    /// chain, i.e. if the corresponding note has been consumed in a sealed block.
+    pub fn is_nullifier_spent(&self, nullifier: Nullifier) -> bool {
+        self.nullifiers.get_value(&nullifier.inner()) != Smt::EMPTY_VALUE
+    }
+
+    /// Returns the notes which will be created in the next block and whose tags match the
+    /// provided filter.
+    pub fn pending_notes_matching(&self, filter: &NoteTagFilter) -> Vec<&OutputNote> {
+        self.pending_objects
+            .output_note_batches
+            .iter()
+            .flatten()
+            .map(|(_, note)| note)
+            .filter(|note| filter.matches(note.metadata().tag()))
+            .collect()
+    }
+
     /// Returns a reference to the account identifed by the given account ID and panics if it does
     /// not exist.
     pub fn available_account(&self, account_id: AccountId) -> &Account {
@@ -1081,6 +1790,259 @@ impl MockChain {
     pub fn accounts(&self) -> &SimpleSmt<ACCOUNT_TREE_DEPTH> {
         &self.accounts
     }
+
+    /// Returns the mode in which transactions, batches and blocks are proven.
+    pub fn proving_mode(&self) -> ProvingMode {
+        self.proving_mode
+    }
+
+    /// Returns the timestamp the next sealed block will have, unless it is overwritten when
+    /// calling [`Self::seal_block`].
+    pub fn next_block_timestamp(&self) -> u32 {
+        self.next_block_timestamp.unwrap_or_else(|| {
+            self.blocks.last().map_or(Self::TIMESTAMP_START_SECS, |block| {
+                block.header().timestamp() + self.timestamp_step_secs
+            })
+        })
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Builds a [`ProvenTransaction`] from the provided executed transaction with a dummy proof.
+fn mock_prove_transaction(
+    executed_transaction: ExecutedTransaction,
+) -> Result<ProvenTransaction, TransactionProverError> {
+    let initial_account = executed_transaction.initial_account();
+    let account_update_details = if initial_account.is_public() {
+        if initial_account.is_new() {
+            let mut account = initial_account.clone();
+            account
+                .apply_delta(executed_transaction.account_delta())
+                .map_err(TransactionProverError::AccountDeltaApplyFailed)?;
+
+            AccountUpdateDetails::New(account)
+        } else {
+            AccountUpdateDetails::Delta(executed_transaction.account_delta().clone())
+        }
+    } else {
+        AccountUpdateDetails::Private
+    };
+
+    // erase private note information (convert private full notes to just headers)
+    let output_notes: Vec<_> =
+        executed_transaction.output_notes().iter().map(OutputNote::shrink).collect();
+
+    ProvenTransactionBuilder::new(
+        executed_transaction.account_id(),
+        initial_account.init_commitment(),
+        executed_transaction.final_account().commitment(),
+        executed_transaction.block_header().block_num(),
+        executed_transaction.block_header().commitment(),
+        executed_transaction.expiration_block_num(),
+        ExecutionProof::new(Proof::new_dummy(), Default::default()),
+    )
+    .fee(executed_transaction.fee())
+    .add_input_notes(executed_transaction.input_notes())
+    .add_output_notes(output_notes)
+    .account_update_details(account_update_details)
+    .build()
+    .map_err(TransactionProverError::ProvenTransactionBuildFailed)
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for MockAccount {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account.write_into(target);
+        self.seed.write_into(target);
+        let keys: Option<Vec<(Digest, AuthSecretKey)>> = self.authenticator.as_ref().map(|auth| {
+            auth.keys()
+                .iter()
+                .map(|(pub_key, sec_key)| (*pub_key, sec_key.clone()))
+                .collect()
+        });
+        keys.write_into(target);
+    }
+}
+
+impl Deserializable for MockAccount {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let account = Account::read_from(source)?;
+        let seed = <Option<Word>>::read_from(source)?;
+        // the state of the RNG of the authenticator is not serialized, which only affects the
+        // randomness of the generated signatures
+        let authenticator =
+            <Option<Vec<(Digest, AuthSecretKey)>>>::read_from(source)?.map(|keys| {
+                let keys: Vec<_> =
+                    keys.into_iter().map(|(pub_key, sec_key)| (pub_key.into(), sec_key)).collect();
+                BasicAuthenticator::new_with_rng(&keys, ChaCha20Rng::from_seed(Default::default()))
+            });
+
+        Ok(Self { account, seed, authenticator })
+    }
+}
+
+impl Serializable for PendingObjects {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.updated_accounts.write_into(target);
+        self.output_note_batches.write_into(target);
+        self.created_nullifiers.write_into(target);
+        self.included_transactions.write_into(target);
+    }
+}
+
+impl Deserializable for PendingObjects {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            updated_accounts: <Vec<BlockAccountUpdate>>::read_from(source)?,
+            output_note_batches: <Vec<OutputNoteBatch>>::read_from(source)?,
+            created_nullifiers: <Vec<Nullifier>>::read_from(source)?,
+            included_transactions: <Vec<(TransactionId, AccountId)>>::read_from(source)?,
+        })
+    }
+}
+
+impl Serializable for MockChainRng {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_bytes(&self.seed);
+        for rng in [&self.accounts, &self.auth, &self.notes] {
+            let word_pos = rng.get_word_pos();
+            target.write_u64(word_pos as u64);
+            target.write_u64((word_pos >> 64) as u64);
+        }
+    }
+}
+
+impl Deserializable for MockChainRng {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let mut rng = Self::new(source.read_array()?);
+        for rng in [&mut rng.accounts, &mut rng.auth, &mut rng.notes] {
+            let low = source.read_u64()? as u128;
+            let high = source.read_u64()? as u128;
+            rng.set_word_pos(low | (high << 64));
+        }
+
+        Ok(rng)
+    }
+}
+
+impl Serializable for ProvingMode {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        let tag: u8 = match self {
+            ProvingMode::Mocked => 0,
+            ProvingMode::Full => 1,
+        };
+        target.write_u8(tag);
+    }
+}
+
+impl Deserializable for ProvingMode {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(ProvingMode::Mocked),
+            1 => Ok(ProvingMode::Full),
+            tag => Err(DeserializationError::InvalidValue(format!("unknown proving mode {tag}"))),
+        }
+    }
+}
+
+/// Serializes the entire state of the chain, so that e.g. large test fixtures can be generated
+/// once and reloaded in later test runs.
+///
+/// The block chain MMR is not serialized but rebuilt from the commitments of the blocks during
+/// deserialization. Witness corruptions are not part of the state of the chain and are therefore
+/// not serialized.
+impl Serializable for MockChain {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.blocks.write_into(target);
+
+        let nullifiers: Vec<(Digest, Word)> = self.nullifiers.entries().cloned().collect();
+        nullifiers.write_into(target);
+
+        let accounts: Vec<(u64, Word)> =
+            self.accounts.leaves().map(|(index, leaf)| (index, *leaf)).collect();
+        accounts.write_into(target);
+
+        self.pending_objects.write_into(target);
+        self.available_notes.values().cloned().collect::<Vec<_>>().write_into(target);
+        self.available_accounts.values().cloned().collect::<Vec<_>>().write_into(target);
+        self.removed_notes.write_into(target);
+        self.rng.write_into(target);
+        self.proving_mode.write_into(target);
+        self.proven_transactions
+            .values()
+            .cloned()
+            .collect::<Vec<_>>()
+            .write_into(target);
+
+        let private_account_states: Vec<(TransactionId, Account)> = self
+            .private_account_states
+            .iter()
+            .map(|(tx_id, account)| (*tx_id, account.clone()))
+            .collect();
+        private_account_states.write_into(target);
+
+        self.timestamp_step_secs.write_into(target);
+        self.next_block_timestamp.write_into(target);
+    }
+}
+
+impl Deserializable for MockChain {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let blocks = <Vec<ProvenBlock>>::read_from(source)?;
+        let mut chain = Mmr::default();
+        for block in blocks.iter() {
+            chain.add(block.commitment());
+        }
+
+        let nullifiers = Smt::with_entries(<Vec<(Digest, Word)>>::read_from(source)?)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+        let accounts =
+            SimpleSmt::<ACCOUNT_TREE_DEPTH>::with_leaves(<Vec<(u64, Word)>>::read_from(source)?)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+
+        let pending_objects = PendingObjects::read_from(source)?;
+        let available_notes = <Vec<InputNote>>::read_from(source)?
+            .into_iter()
+            .map(|note| (note.id(), note))
+            .collect();
+        let available_accounts = <Vec<MockAccount>>::read_from(source)?
+            .into_iter()
+            .map(|mock_account| (mock_account.account.id(), mock_account))
+            .collect();
+        let removed_notes = <Vec<NoteId>>::read_from(source)?;
+        let rng = MockChainRng::read_from(source)?;
+        let proving_mode = ProvingMode::read_from(source)?;
+        let proven_transactions = <Vec<ProvenTransaction>>::read_from(source)?
+            .into_iter()
+            .map(|tx| (tx.id(), tx))
+            .collect();
+        let private_account_states =
+            <Vec<(TransactionId, Account)>>::read_from(source)?.into_iter().collect();
+        let timestamp_step_secs = u32::read_from(source)?;
+        let next_block_timestamp = <Option<u32>>::read_from(source)?;
+
+        Ok(Self {
+            chain,
+            blocks,
+            nullifiers,
+            accounts,
+            pending_objects,
+            available_notes,
+            available_accounts,
+            removed_notes,
+            rng,
+            proving_mode,
+            proven_transactions,
+            private_account_states,
+            timestamp_step_secs,
+            next_block_timestamp,
+            witness_corruptions: BTreeSet::new(),
+        })
+    }
 }
 
 // HELPER TYPES
@@ -1092,3 +2054,198 @@ pub enum AccountState {
     New,
     Exists,
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mock_chain_notes_matching_tag_filter() {
+        let mut mock_chain = MockChain::new();
+        let sender = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+        let target_1 = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+        let target_2 = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+
+        let note_1 = mock_chain
+            .add_p2id_note(sender.id(), target_1.id(), &[], NoteType::Public, None)
+            .unwrap();
+        let note_2 = mock_chain
+            .add_p2id_note(sender.id(), target_2.id(), &[], NoteType::Public, None)
+            .unwrap();
+
+        let filter = NoteTagFilter::new().with_tag(note_1.metadata().tag());
+        let pending_notes = mock_chain.pending_notes_matching(&filter);
+        assert_eq!(pending_notes.iter().map(|note| note.id()).collect::<Vec<_>>(), [note_1.id()]);
+
+        mock_chain.seal_next_block();
+        assert!(mock_chain.pending_notes_matching(&filter).is_empty());
+
+        let available_notes = mock_chain.available_notes_matching(&filter);
+        assert_eq!(available_notes.iter().map(|note| note.id()).collect::<Vec<_>>(), [note_1.id()]);
+
+        let filter = filter.with_tag(note_2.metadata().tag());
+        assert_eq!(mock_chain.available_notes_matching(&filter).len(), 2);
+    }
+
+    #[test]
+    fn mock_chain_randomness_is_derived_from_seed() {
+        let build_chain = |seed| {
+            let mut mock_chain = MockChain::with_rng_seed(seed);
+            let sender = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+            let target = mock_chain.add_new_wallet(Auth::BasicAuth);
+            let note = mock_chain
+                .add_p2id_note(sender.id(), target.id(), &[], NoteType::Public, None)
+                .unwrap();
+            (mock_chain, sender, target, note)
+        };
+
+        let (mock_chain, sender_1, target_1, note_1) = build_chain([1; 32]);
+        assert_eq!(mock_chain.rng_seed(), [1; 32]);
+
+        // the same seed results in the same accounts, keys and notes
+        let (_, sender_2, target_2, note_2) = build_chain([1; 32]);
+        assert_eq!(sender_1.commitment(), sender_2.commitment());
+        assert_eq!(target_1.commitment(), target_2.commitment());
+        assert_eq!(note_1.id(), note_2.id());
+
+        let (_, sender_3, target_3, note_3) = build_chain([2; 32]);
+        assert_ne!(sender_1.id(), sender_3.id());
+        assert_ne!(target_1.commitment(), target_3.commitment());
+        assert_ne!(note_1.serial_num(), note_3.serial_num());
+
+        // the serial numbers of notes do not depend on the accounts created before
+        let mut mock_chain = MockChain::with_rng_seed([1; 32]);
+        let note_4 = mock_chain
+            .add_p2id_note(sender_1.id(), target_1.id(), &[], NoteType::Public, None)
+            .unwrap();
+        assert_eq!(note_4.id(), note_1.id());
+    }
+
+    #[test]
+    fn mock_chain_block_timestamps() {
+        let mut mock_chain = MockChain::new();
+        let genesis_timestamp = mock_chain.latest_block_header().timestamp();
+        assert_eq!(genesis_timestamp, MockChain::TIMESTAMP_START_SECS);
+
+        // blocks advance by the configured step
+        mock_chain.set_timestamp_step(60);
+        assert_eq!(mock_chain.next_block_timestamp(), genesis_timestamp + 60);
+        let block = mock_chain.seal_next_block();
+        assert_eq!(block.header().timestamp(), genesis_timestamp + 60);
+
+        // an explicitly set timestamp only applies to the next block
+        mock_chain.set_next_block_timestamp(genesis_timestamp + 1000);
+        let block = mock_chain.seal_next_block();
+        assert_eq!(block.header().timestamp(), genesis_timestamp + 1000);
+        let block = mock_chain.seal_next_block();
+        assert_eq!(block.header().timestamp(), genesis_timestamp + 1060);
+
+        // advancing the time is relative to the latest block
+        mock_chain.advance_time(3600);
+        let block = mock_chain.seal_next_block();
+        assert_eq!(block.header().timestamp(), genesis_timestamp + 4660);
+    }
+
+    #[test]
+    fn mock_chain_restore_discards_later_changes() {
+        let mut mock_chain = MockChain::new();
+        let account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+        let snapshot = mock_chain.snapshot();
+        let latest_block_num = mock_chain.latest_block_header().block_num();
+
+        mock_chain.corrupt_witness(WitnessCorruption::StaleAccountProof(account.id()));
+        mock_chain.seal_next_block();
+
+        mock_chain.restore(snapshot);
+        assert_eq!(mock_chain.latest_block_header().block_num(), latest_block_num);
+        assert!(mock_chain.witness_corruptions.is_empty());
+        assert!(mock_chain.private_account_states.is_empty());
+    }
+
+    #[test]
+    fn mock_chain_serialization() {
+        let mut mock_chain = MockChain::with_rng_seed([3; 32]);
+        let sender = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![FungibleAsset::mock(100)]);
+        let target = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+        let note = mock_chain
+            .add_p2id_note(sender.id(), target.id(), &[FungibleAsset::mock(10)], NoteType::Public, None)
+            .unwrap();
+        mock_chain.seal_next_block();
+        mock_chain.add_nullifier(Nullifier::dummy(1));
+        mock_chain.seal_next_block();
+        mock_chain.set_next_block_timestamp(MockChain::TIMESTAMP_START_SECS + 1000);
+
+        let mut deserialized = MockChain::read_from_bytes(&mock_chain.to_bytes()).unwrap();
+
+        assert_eq!(deserialized.latest_block_header(), mock_chain.latest_block_header());
+        assert_eq!(
+            deserialized.block_chain().peaks().hash_peaks(),
+            mock_chain.block_chain().peaks().hash_peaks()
+        );
+        assert_eq!(deserialized.nullifiers().root(), mock_chain.nullifiers().root());
+        assert_eq!(deserialized.accounts().root(), mock_chain.accounts().root());
+        assert_eq!(deserialized.available_notes_map(), mock_chain.available_notes_map());
+        assert_eq!(
+            deserialized.available_account(target.id()),
+            mock_chain.available_account(target.id())
+        );
+        assert_eq!(deserialized.rng_seed(), mock_chain.rng_seed());
+        assert_eq!(deserialized.next_block_timestamp(), mock_chain.next_block_timestamp());
+
+        // the deserialized chain continues where the original chain left off
+        let note_1 = mock_chain
+            .add_p2id_note(sender.id(), target.id(), &[], NoteType::Public, None)
+            .unwrap();
+        let note_2 = deserialized
+            .add_p2id_note(sender.id(), target.id(), &[], NoteType::Public, None)
+            .unwrap();
+        assert_eq!(note_1.id(), note_2.id());
+
+        // the authenticators of the accounts are restored
+        deserialized
+            .build_tx_context(target.id(), &[note.id()], &[])
+            .build()
+            .execute()
+            .unwrap();
+    }
+
+    #[test]
+    fn mock_chain_note_and_nullifier_queries() {
+        let mut mock_chain = MockChain::new();
+        let sender = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+        let target_1 = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+        let target_2 = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+
+        let note_1 = mock_chain
+            .add_p2id_note(sender.id(), target_1.id(), &[], NoteType::Public, None)
+            .unwrap();
+        let note_2 = mock_chain
+            .add_p2id_note(sender.id(), target_2.id(), &[], NoteType::Public, None)
+            .unwrap();
+        mock_chain.seal_next_block();
+
+        let note_ids = |notes: Vec<&InputNote>| notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+        assert_eq!(note_ids(mock_chain.unconsumed_notes_for(target_1.id())), [note_1.id()]);
+        assert_eq!(note_ids(mock_chain.unconsumed_notes_for(target_2.id())), [note_2.id()]);
+        assert!(mock_chain.unconsumed_notes_for(sender.id()).is_empty());
+
+        let filter = NoteTagFilter::new().with_tag(note_2.metadata().tag());
+        assert_eq!(note_ids(mock_chain.notes_by_tag(&filter)), [note_2.id()]);
+        assert!(!mock_chain.is_nullifier_spent(note_1.nullifier()));
+
+        let executed_transaction = mock_chain
+            .build_tx_context(target_1.id(), &[note_1.id()], &[])
+            .build()
+            .execute()
+            .unwrap();
+        mock_chain.apply_executed_transaction(&executed_transaction);
+        mock_chain.seal_next_block();
+
+        assert!(mock_chain.unconsumed_notes_for(target_1.id()).is_empty());
+        assert!(mock_chain.is_nullifier_spent(note_1.nullifier()));
+        assert!(!mock_chain.is_nullifier_spent(note_2.nullifier()));
+    }
+}
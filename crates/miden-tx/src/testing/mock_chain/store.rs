@@ -0,0 +1,299 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+
+use miden_objects::{
+    account::AccountId,
+    block::{BlockHeader, BlockInputs, BlockNumber},
+    note::{NoteId, NoteInclusionProof, NoteTagFilter, Nullifier},
+    transaction::{ChainMmr, OutputNote},
+};
+
+use super::MockChain;
+use crate::DataStoreError;
+
+// MOCK CHAIN STORE
+// ================================================================================================
+
+/// An adapter which exposes a [`MockChain`] through the access patterns of the store of a Miden
+/// node.
+///
+/// This allows components which are built on top of a node store, e.g. batch and block builders
+/// or clients syncing notes, to be tested against a [`MockChain`] instead of a running node.
+///
+/// Unlike the methods of [`MockChain`], the methods of the adapter do not panic on unknown blocks
+/// but return a [`DataStoreError`] instead.
+#[derive(Debug, Clone, Copy)]
+pub struct MockChainStore<'chain> {
+    chain: &'chain MockChain,
+}
+
+impl<'chain> MockChainStore<'chain> {
+    /// Returns a new [`MockChainStore`] reading from the provided chain.
+    pub fn new(chain: &'chain MockChain) -> Self {
+        Self { chain }
+    }
+
+    /// Returns the number of the latest block in the chain.
+    pub fn chain_tip(&self) -> BlockNumber {
+        self.chain.latest_block_header().block_num()
+    }
+
+    /// Returns the header of the block with the provided number or the header of the latest block
+    /// if `block_num` is `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the block does not exist in the chain.
+    pub fn get_block_header(
+        &self,
+        block_num: Option<BlockNumber>,
+    ) -> Result<BlockHeader, DataStoreError> {
+        let block_num = block_num.unwrap_or_else(|| self.chain_tip());
+        self.check_block_exists(block_num)?;
+
+        Ok(self.chain.block_header(block_num.as_usize()))
+    }
+
+    /// Returns the inputs for a transaction batch whose transactions reference the provided blocks
+    /// and consume the provided unauthenticated notes.
+    ///
+    /// The latest block of the chain is used as the reference block of the batch. Inclusion proofs
+    /// are only returned for the notes which have been created on chain and not yet consumed,
+    /// and the chain MMR tracks the blocks in which these notes were created.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the referenced blocks does not exist in the chain.
+    pub fn get_batch_inputs(
+        &self,
+        tx_reference_blocks: impl IntoIterator<Item = BlockNumber>,
+        unauthenticated_notes: impl IntoIterator<Item = NoteId>,
+    ) -> Result<BatchInputs, DataStoreError> {
+        let tx_reference_blocks = self.check_blocks_exist(tx_reference_blocks)?;
+        let note_proofs = self.chain.unauthenticated_note_proofs(unauthenticated_notes);
+
+        let (batch_reference_block_header, chain_mmr) = self.chain.latest_selective_chain_mmr(
+            tx_reference_blocks
+                .into_iter()
+                .chain(note_proofs.values().map(|proof| proof.location().block_num())),
+        );
+
+        Ok(BatchInputs {
+            batch_reference_block_header,
+            chain_mmr,
+            note_proofs,
+        })
+    }
+
+    /// Returns the inputs for a block which updates the provided accounts, creates the provided
+    /// nullifiers, consumes the provided unauthenticated notes and contains batches referencing
+    /// the provided blocks.
+    ///
+    /// The latest block of the chain is used as the previous block of the new block. As for
+    /// [`Self::get_batch_inputs`], inclusion proofs are only returned for the notes which have
+    /// been created on chain and not yet consumed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the referenced blocks does not exist in the chain.
+    pub fn get_block_inputs(
+        &self,
+        account_ids: impl IntoIterator<Item = AccountId>,
+        nullifiers: impl IntoIterator<Item = Nullifier>,
+        unauthenticated_notes: impl IntoIterator<Item = NoteId>,
+        reference_blocks: impl IntoIterator<Item = BlockNumber>,
+    ) -> Result<BlockInputs, DataStoreError> {
+        let reference_blocks = self.check_blocks_exist(reference_blocks)?;
+        let unauthenticated_note_proofs =
+            self.chain.unauthenticated_note_proofs(unauthenticated_notes);
+
+        let (prev_block_header, chain_mmr) =
+            self.chain.latest_selective_chain_mmr(reference_blocks.into_iter().chain(
+                unauthenticated_note_proofs.values().map(|proof| proof.location().block_num()),
+            ));
+
+        Ok(BlockInputs::new(
+            prev_block_header,
+            chain_mmr,
+            self.chain.account_witnesses(account_ids),
+            self.chain.nullifier_witnesses(nullifiers),
+            unauthenticated_note_proofs,
+        ))
+    }
+
+    /// Returns the notes matching the provided filter of the first block after `block_num` which
+    /// contains any such notes.
+    ///
+    /// If no later block contains matching notes, the returned update refers to the latest block
+    /// of the chain and contains no notes. Notes are returned regardless of whether they have been
+    /// consumed since, so repeatedly syncing from the block of the last update returns all
+    /// matching notes of the chain.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the block with number `block_num` does not exist in the chain.
+    pub fn sync_notes(
+        &self,
+        block_num: BlockNumber,
+        filter: &NoteTagFilter,
+    ) -> Result<NoteSyncUpdate, DataStoreError> {
+        self.check_block_exists(block_num)?;
+
+        for block in self.chain.blocks.iter().skip(block_num.as_usize() + 1) {
+            let note_tree = block.build_output_note_tree();
+            let notes: Vec<_> = block
+                .output_notes()
+                .filter(|(_, note)| filter.matches(note.metadata().tag()))
+                .map(|(note_index, note)| {
+                    let inclusion_proof = NoteInclusionProof::new(
+                        block.header().block_num(),
+                        note_index.leaf_index_value(),
+                        note_tree.get_note_path(note_index),
+                    )
+                    .expect("note index should be valid for the block note tree");
+
+                    NoteSyncRecord { note: note.clone(), inclusion_proof }
+                })
+                .collect();
+
+            if !notes.is_empty() {
+                return Ok(NoteSyncUpdate {
+                    chain_tip: self.chain_tip(),
+                    block_header: block.header().clone(),
+                    notes,
+                });
+            }
+        }
+
+        Ok(NoteSyncUpdate {
+            chain_tip: self.chain_tip(),
+            block_header: self.chain.latest_block_header(),
+            notes: Vec::new(),
+        })
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns an error if the block with the provided number does not exist in the chain.
+    fn check_block_exists(&self, block_num: BlockNumber) -> Result<(), DataStoreError> {
+        if block_num > self.chain_tip() {
+            return Err(DataStoreError::BlockNotFound(block_num));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the deduplicated provided block numbers or an error if any of the blocks does not
+    /// exist in the chain.
+    fn check_blocks_exist(
+        &self,
+        block_nums: impl IntoIterator<Item = BlockNumber>,
+    ) -> Result<BTreeSet<BlockNumber>, DataStoreError> {
+        let block_nums: BTreeSet<_> = block_nums.into_iter().collect();
+        for block_num in block_nums.iter() {
+            self.check_block_exists(*block_num)?;
+        }
+
+        Ok(block_nums)
+    }
+}
+
+// BATCH INPUTS
+// ================================================================================================
+
+/// The inputs for proposing a transaction batch, as returned by
+/// [`MockChainStore::get_batch_inputs`].
+#[derive(Debug, Clone)]
+pub struct BatchInputs {
+    batch_reference_block_header: BlockHeader,
+    chain_mmr: ChainMmr,
+    note_proofs: BTreeMap<NoteId, NoteInclusionProof>,
+}
+
+impl BatchInputs {
+    /// Returns the header of the reference block of the batch.
+    pub fn batch_reference_block_header(&self) -> &BlockHeader {
+        &self.batch_reference_block_header
+    }
+
+    /// Returns the chain MMR tracking the blocks referenced by the transactions of the batch and
+    /// the blocks in which the unauthenticated notes were created.
+    pub fn chain_mmr(&self) -> &ChainMmr {
+        &self.chain_mmr
+    }
+
+    /// Returns the inclusion proofs of the unauthenticated notes which have been found in the
+    /// chain.
+    pub fn note_proofs(&self) -> &BTreeMap<NoteId, NoteInclusionProof> {
+        &self.note_proofs
+    }
+
+    /// Consumes self and returns the reference block header, the chain MMR and the note inclusion
+    /// proofs, in the order expected by
+    /// [`ProposedBatch::new`](miden_objects::batch::ProposedBatch::new).
+    pub fn into_parts(self) -> (BlockHeader, ChainMmr, BTreeMap<NoteId, NoteInclusionProof>) {
+        (self.batch_reference_block_header, self.chain_mmr, self.note_proofs)
+    }
+}
+
+// NOTE SYNC UPDATE
+// ================================================================================================
+
+/// The result of syncing notes by tag, as returned by [`MockChainStore::sync_notes`].
+#[derive(Debug, Clone)]
+pub struct NoteSyncUpdate {
+    chain_tip: BlockNumber,
+    block_header: BlockHeader,
+    notes: Vec<NoteSyncRecord>,
+}
+
+impl NoteSyncUpdate {
+    /// Returns the number of the latest block in the chain at the time of the sync.
+    pub fn chain_tip(&self) -> BlockNumber {
+        self.chain_tip
+    }
+
+    /// Returns the header of the block which contains the returned notes.
+    pub fn block_header(&self) -> &BlockHeader {
+        &self.block_header
+    }
+
+    /// Returns the notes of the block which match the filter of the sync.
+    pub fn notes(&self) -> &[NoteSyncRecord] {
+        &self.notes
+    }
+
+    /// Returns `true` if the block of the update is the latest block of the chain, i.e. if there
+    /// are no further notes to sync.
+    pub fn is_at_chain_tip(&self) -> bool {
+        self.block_header.block_num() == self.chain_tip
+    }
+}
+
+/// A note returned by [`MockChainStore::sync_notes`] together with its inclusion proof.
+#[derive(Debug, Clone)]
+pub struct NoteSyncRecord {
+    note: OutputNote,
+    inclusion_proof: NoteInclusionProof,
+}
+
+impl NoteSyncRecord {
+    /// Returns the ID of the note.
+    pub fn id(&self) -> NoteId {
+        self.note.id()
+    }
+
+    /// Returns the note as it was created in the block. The note only contains the full note
+    /// details if it is public.
+    pub fn note(&self) -> &OutputNote {
+        &self.note
+    }
+
+    /// Returns the proof of inclusion of the note in its block.
+    pub fn inclusion_proof(&self) -> &NoteInclusionProof {
+        &self.inclusion_proof
+    }
+}
@@ -2,10 +2,10 @@ use alloc::{boxed::Box, collections::BTreeMap, rc::Rc, string::ToString, sync::A
 
 use miden_lib::{
     errors::tx_kernel_errors::TX_KERNEL_ERRORS,
-    transaction::{TransactionEvent, TransactionEventError},
+    transaction::{TransactionEvent, TransactionEventError, TransactionKernelError},
 };
 use miden_objects::{
-    Digest,
+    Digest, Felt,
     account::{AccountHeader, AccountVaultDelta},
 };
 use vm_processor::{
@@ -13,7 +13,10 @@ use vm_processor::{
     MastForestStore, MemAdviceProvider, ProcessState,
 };
 
-use crate::{TransactionMastStore, host::AccountProcedureIndexMap};
+use crate::{
+    TransactionMastStore,
+    host::{AccountProcedureIndexMap, u256_mul_mod_hint, u384_inv_mod_hint, u384_mul_mod_hint},
+};
 
 // MOCK HOST
 // ================================================================================================
@@ -73,6 +76,19 @@ impl MockHost {
         self.adv_provider.push_stack(AdviceSource::Value(proc_idx.into()))?;
         Ok(())
     }
+
+    /// Pushes the values computed by the host for a modular arithmetic event onto the advice
+    /// stack.
+    fn push_advice_values(
+        &mut self,
+        values: Result<Vec<Felt>, TransactionKernelError>,
+    ) -> Result<(), ExecutionError> {
+        let values = values.map_err(|err| ExecutionError::EventError(Box::new(err)))?;
+        for value in values {
+            self.adv_provider.push_stack(AdviceSource::Value(value))?;
+        }
+        Ok(())
+    }
 }
 
 impl Host for MockHost {
@@ -104,6 +120,15 @@ impl Host for MockHost {
             TransactionEvent::AccountPushProcedureIndex => {
                 self.on_push_account_procedure_index(process)
             },
+            TransactionEvent::U256MulModToStack => {
+                self.push_advice_values(u256_mul_mod_hint(process))
+            },
+            TransactionEvent::U384MulModToStack => {
+                self.push_advice_values(u384_mul_mod_hint(process))
+            },
+            TransactionEvent::U384InvModToStack => {
+                self.push_advice_values(u384_inv_mod_hint(process))
+            },
             _ => Ok(()),
         }?;
 
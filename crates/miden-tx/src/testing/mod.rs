@@ -4,9 +4,17 @@ pub use mock_host::MockHost;
 mod mock_host;
 
 mod mock_chain;
-pub use mock_chain::{AccountState, Auth, MockChain, MockFungibleFaucet};
+pub use mock_chain::{
+    AccountState, Auth, BatchInputs, MockChain, MockChainReorg, MockChainSnapshot, MockChainStore,
+    MockFungibleFaucet, NoteSyncRecord, NoteSyncUpdate, ProvingMode, WitnessCorruption,
+};
 
 mod tx_context;
 pub use tx_context::{TransactionContext, TransactionContextBuilder};
 
+#[cfg(feature = "block-prover")]
+mod test_vectors;
+#[cfg(feature = "block-prover")]
+pub use test_vectors::{TestVector, generate_test_vectors};
+
 pub mod utils;
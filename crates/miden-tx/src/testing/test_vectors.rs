@@ -0,0 +1,237 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+use miden_objects::{
+    Digest,
+    asset::FungibleAsset,
+    note::NoteType,
+    utils::{serde::Serializable, to_hex},
+};
+use winter_maybe_async::*;
+
+use super::{Auth, MockChain};
+
+// TEST VECTOR
+// ================================================================================================
+
+/// A canonically serialized object together with the identifiers derived from it.
+///
+/// Test vectors allow alternative implementations of the protocol, e.g. in other languages, and
+/// the node to verify that they serialize objects and derive their IDs and commitments exactly
+/// like this crate does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestVector {
+    name: &'static str,
+    bytes: Vec<u8>,
+    id: Option<String>,
+    commitment: Option<Digest>,
+}
+
+impl TestVector {
+    /// Returns a new [TestVector] with the provided name holding the serialized object.
+    fn new(name: &'static str, object: &impl Serializable) -> Self {
+        Self {
+            name,
+            bytes: object.to_bytes(),
+            id: None,
+            commitment: None,
+        }
+    }
+
+    /// Sets the hex-encoded ID of the object.
+    fn with_id(mut self, id: impl ToString) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
+
+    /// Sets the commitment to the object.
+    fn with_commitment(mut self, commitment: Digest) -> Self {
+        self.commitment = Some(commitment);
+        self
+    }
+
+    /// Returns the name of the test vector, which identifies the kind of the serialized object.
+    pub fn name(&self) -> &str {
+        self.name
+    }
+
+    /// Returns the canonical serialization of the object.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the hex-encoded ID of the object, if the object has an ID.
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// Returns the commitment to the object, if the object has a commitment which is distinct
+    /// from its ID.
+    pub fn commitment(&self) -> Option<Digest> {
+        self.commitment
+    }
+}
+
+/// Formats the test vector as a line-based record, e.g.:
+///
+/// ```text
+/// [note]
+/// id = 0x...
+/// commitment = 0x...
+/// bytes = ...
+/// ```
+impl fmt::Display for TestVector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "[{}]", self.name)?;
+        if let Some(id) = &self.id {
+            writeln!(f, "id = {id}")?;
+        }
+        if let Some(commitment) = &self.commitment {
+            writeln!(f, "commitment = {}", commitment.to_hex())?;
+        }
+        writeln!(f, "bytes = {}", to_hex(&self.bytes))
+    }
+}
+
+// TEST VECTOR GENERATION
+// ================================================================================================
+
+/// Generates the test vectors of a fixed scenario on a [MockChain] whose randomness is derived
+/// from the provided seed, so that the same seed always results in the same vectors.
+///
+/// In the scenario, a wallet sends a public P2ID note with a fungible asset to another wallet,
+/// which consumes the note in a transaction that is included in a batch and a block. The returned
+/// vectors contain, in this order:
+/// - the sending and the receiving account,
+/// - the P2ID note,
+/// - the proven transaction consuming the note,
+/// - the proposed and the proven batch containing the transaction,
+/// - the proposed and the proven block containing the batch.
+///
+/// Transactions are proven in [`ProvingMode::Mocked`](super::ProvingMode::Mocked), so the proofs
+/// contained in the vectors are dummy proofs.
+///
+/// The vectors generated from the seed `[5; 32]`, formatted via [TestVector]'s `Display`
+/// implementation and separated by empty lines, are committed in `src/testing/test_vectors.txt`.
+///
+/// # Panics
+///
+/// Panics if any step of the scenario fails, which indicates a bug in the chain or the protocol.
+#[maybe_async]
+pub fn generate_test_vectors(seed: [u8; 32]) -> Vec<TestVector> {
+    let mut mock_chain = MockChain::with_rng_seed(seed);
+    let sender = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![FungibleAsset::mock(100)]);
+    let target = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let note = mock_chain
+        .add_p2id_note(
+            sender.id(),
+            target.id(),
+            &[FungibleAsset::mock(100)],
+            NoteType::Public,
+            None,
+        )
+        .expect("p2id note should be valid");
+    mock_chain.seal_next_block();
+
+    let tx_context = mock_chain.build_tx_context(target.id(), &[note.id()], &[]).build();
+    let executed_transaction =
+        maybe_await!(tx_context.execute()).expect("note consumption should succeed");
+    let proven_transaction = maybe_await!(mock_chain.prove_transaction(executed_transaction))
+        .expect("transaction should be provable");
+
+    let proposed_batch = mock_chain
+        .propose_transaction_batch([proven_transaction.clone()])
+        .expect("transaction batch should be valid");
+    let proven_batch = mock_chain.prove_transaction_batch(proposed_batch.clone());
+
+    let proposed_block =
+        mock_chain.propose_block([proven_batch.clone()]).expect("block should be valid");
+    let proven_block = mock_chain
+        .prove_block(proposed_block.clone())
+        .expect("block should be provable");
+
+    vec![
+        TestVector::new("sender_account", &sender)
+            .with_id(sender.id())
+            .with_commitment(sender.commitment()),
+        TestVector::new("target_account", &target)
+            .with_id(target.id())
+            .with_commitment(target.commitment()),
+        TestVector::new("note", &note)
+            .with_id(note.id())
+            .with_commitment(note.commitment()),
+        TestVector::new("proven_transaction", &proven_transaction).with_id(proven_transaction.id()),
+        TestVector::new("proposed_batch", &proposed_batch).with_id(proposed_batch.id()),
+        TestVector::new("proven_batch", &proven_batch).with_id(proven_batch.id()),
+        TestVector::new("proposed_block", &proposed_block),
+        TestVector::new("proven_block", &proven_block).with_commitment(proven_block.commitment()),
+    ]
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    use miden_objects::{
+        account::Account, batch::ProposedBatch, block::ProvenBlock, note::Note,
+        transaction::ProvenTransaction, utils::serde::Deserializable,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_vectors_are_deterministic_and_consistent() {
+        let vectors = generate_test_vectors([5; 32]);
+        assert_eq!(vectors, generate_test_vectors([5; 32]));
+
+        // the vectors match the committed golden vectors; intended changes to the serialization of
+        // the objects (or to the MASM code they commit to) require regenerating the golden file
+        let formatted = vectors.iter().map(ToString::to_string).collect::<Vec<_>>().join("\n");
+        assert_eq!(formatted, include_str!("test_vectors.txt"));
+        assert_ne!(vectors, generate_test_vectors([6; 32]));
+
+        let names: Vec<_> = vectors.iter().map(|vector| vector.name()).collect();
+        assert_eq!(
+            names,
+            [
+                "sender_account",
+                "target_account",
+                "note",
+                "proven_transaction",
+                "proposed_batch",
+                "proven_batch",
+                "proposed_block",
+                "proven_block"
+            ]
+        );
+
+        // the recorded IDs and commitments match the ones derived from the deserialized objects
+        let account = Account::read_from_bytes(vectors[0].bytes()).unwrap();
+        assert_eq!(vectors[0].id(), Some(account.id().to_hex().as_str()));
+        assert_eq!(vectors[0].commitment(), Some(account.commitment()));
+
+        let note = Note::read_from_bytes(vectors[2].bytes()).unwrap();
+        assert_eq!(vectors[2].id(), Some(note.id().to_hex().as_str()));
+        assert_eq!(vectors[2].commitment(), Some(note.commitment()));
+
+        let transaction = ProvenTransaction::read_from_bytes(vectors[3].bytes()).unwrap();
+        assert_eq!(vectors[3].id(), Some(transaction.id().to_hex().as_str()));
+
+        let batch = ProposedBatch::read_from_bytes(vectors[4].bytes()).unwrap();
+        assert_eq!(vectors[4].id(), Some(batch.id().to_hex().as_str()));
+        assert_eq!(batch.to_bytes(), vectors[4].bytes());
+
+        let block = ProvenBlock::read_from_bytes(vectors[7].bytes()).unwrap();
+        assert_eq!(vectors[7].commitment(), Some(block.commitment()));
+        assert!(vectors[7].to_string().starts_with(&format!(
+            "[proven_block]\ncommitment = {}\n",
+            block.commitment().to_hex()
+        )));
+    }
+}
@@ -137,6 +137,12 @@ impl TransactionContext {
     pub fn get_data_store(&self) -> Arc<dyn DataStore> {
         Arc::new(self.tx_inputs().clone())
     }
+
+    pub fn authenticator(&self) -> Option<Arc<dyn TransactionAuthenticator>> {
+        self.authenticator
+            .clone()
+            .map(|auth| Arc::new(auth) as Arc<dyn TransactionAuthenticator>)
+    }
 }
 
 #[maybe_async_trait]
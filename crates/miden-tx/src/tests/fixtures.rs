@@ -0,0 +1,176 @@
+use alloc::{collections::BTreeSet, format, rc::Rc, sync::Arc, vec::Vec};
+use core::cell::RefCell;
+
+use miden_lib::{account::wallets::BasicWallet, transaction::TransactionKernel};
+use miden_objects::{
+    account::{AccountBuilder, AccountComponent, AccountId, AccountType},
+    asset::FungibleAsset,
+    block::BlockNumber,
+    note::{NoteId, NoteType},
+    transaction::{ExecutedTransaction, TransactionArgs, TransactionInputs, TransactionScript},
+    vm::StackInputs,
+};
+use vm_processor::{AdviceInputs, MemAdviceProvider, ONE};
+
+use crate::{
+    KernelEvent, TransactionExecutor, TransactionExecutorError, TransactionHost,
+    TransactionMastStore,
+    testing::{AccountState, Auth, MockChain, TransactionContext, TransactionContextBuilder},
+};
+
+// TRANSACTION FIXTURE
+// ================================================================================================
+
+/// A transaction context together with the arguments needed to execute its transaction through a
+/// [TransactionExecutor].
+pub struct TransactionFixture {
+    pub tx_context: TransactionContext,
+    pub account_id: AccountId,
+    pub block_ref: BlockNumber,
+    pub note_ids: Vec<NoteId>,
+}
+
+impl TransactionFixture {
+    /// Returns a fixture for the transaction described by the provided context.
+    pub fn new(tx_context: TransactionContext) -> Self {
+        let account_id = tx_context.account().id();
+        let block_ref = tx_context.tx_inputs().block_header().block_num();
+        let note_ids = tx_context.input_notes().iter().map(|note| note.id()).collect();
+
+        Self {
+            tx_context,
+            account_id,
+            block_ref,
+            note_ids,
+        }
+    }
+
+    /// Returns a fixture for a transaction of the standard mock account which consumes the mock
+    /// notes that preserve the assets.
+    pub fn with_mock_notes() -> Self {
+        Self::new(
+            TransactionContextBuilder::with_standard_account(ONE)
+                .with_mock_notes_preserved()
+                .build(),
+        )
+    }
+
+    /// Returns a fixture for a transaction of an existing wallet which consumes `num_notes` P2ID
+    /// notes created by another wallet.
+    ///
+    /// The notes are added to the chain in a new block, and the transaction references the latest
+    /// block of the chain.
+    pub fn with_p2id_notes(mock_chain: &mut MockChain, num_notes: u64) -> Self {
+        let sender = mock_chain.add_existing_wallet(Auth::BasicAuth, Vec::new());
+        let target = mock_chain.add_existing_wallet(Auth::BasicAuth, Vec::new());
+        let note_ids: Vec<NoteId> = (0..num_notes)
+            .map(|i| {
+                mock_chain
+                    .add_p2id_note(
+                        sender.id(),
+                        target.id(),
+                        &[FungibleAsset::mock(100 + i)],
+                        NoteType::Public,
+                        None,
+                    )
+                    .unwrap()
+                    .id()
+            })
+            .collect();
+        mock_chain.seal_next_block();
+
+        Self::new(mock_chain.build_tx_context(target.id(), &note_ids, &[]).build())
+    }
+
+    /// Returns a fixture for a transaction of an existing wallet authenticated by the provided
+    /// auth component, whose transaction script calls the provided auth procedure.
+    pub fn with_auth_component(
+        mock_chain: &mut MockChain,
+        auth_component: impl Into<AccountComponent>,
+        auth_procedure: &str,
+    ) -> Self {
+        let account = mock_chain.add_from_account_builder(
+            Auth::NoAuth,
+            AccountBuilder::new([3; 32])
+                .account_type(AccountType::RegularAccountImmutableCode)
+                .with_component(auth_component)
+                .with_component(BasicWallet),
+            AccountState::Exists,
+        );
+
+        let tx_script = TransactionScript::compile(
+            format!("begin call.{auth_procedure} end"),
+            [],
+            TransactionKernel::testing_assembler(),
+        )
+        .unwrap();
+
+        Self::new(mock_chain.build_tx_context(account.id(), &[], &[]).tx_script(tx_script).build())
+    }
+
+    /// Returns an executor reading the transaction inputs from the context and signing with its
+    /// authenticator, if any.
+    pub fn executor(&self) -> TransactionExecutor {
+        TransactionExecutor::new(self.tx_context.get_data_store(), self.tx_context.authenticator())
+    }
+
+    /// Returns the transaction arguments of the context.
+    pub fn tx_args(&self) -> TransactionArgs {
+        self.tx_context.tx_args().clone()
+    }
+
+    /// Executes the transaction of the fixture with the provided executor and arguments.
+    pub fn execute(
+        &self,
+        executor: &TransactionExecutor,
+        tx_args: TransactionArgs,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        executor.execute_transaction(self.account_id, self.block_ref, &self.note_ids, tx_args)
+    }
+}
+
+// TRANSACTION HOST
+// ================================================================================================
+
+/// Returns the stack inputs of the transaction kernel and a [TransactionHost] for executing the
+/// transaction described by the provided inputs and arguments.
+pub fn transaction_host(
+    tx_inputs: &TransactionInputs,
+    tx_args: &TransactionArgs,
+    advice_inputs: Option<AdviceInputs>,
+) -> (StackInputs, TransactionHost<MemAdviceProvider>) {
+    let (stack_inputs, advice_inputs) =
+        TransactionKernel::prepare_inputs(tx_inputs, tx_args, advice_inputs);
+    let mast_store = Arc::new(TransactionMastStore::new());
+    mast_store.load_transaction_code(tx_inputs, tx_args);
+
+    let host = TransactionHost::new(
+        tx_inputs.account().into(),
+        advice_inputs.into(),
+        mast_store,
+        None,
+        BTreeSet::new(),
+    )
+    .unwrap();
+
+    (stack_inputs, host)
+}
+
+/// Re-executes the provided transaction with its advice witness and returns the [KernelEvent]s
+/// emitted by the transaction kernel, in the order in which they were handled.
+pub fn replay_kernel_events(executed_transaction: &ExecutedTransaction) -> Vec<KernelEvent> {
+    let (stack_inputs, mut host) = transaction_host(
+        executed_transaction.tx_inputs(),
+        executed_transaction.tx_args(),
+        Some(executed_transaction.advice_witness().clone()),
+    );
+
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let recorded_events = events.clone();
+    host.set_event_subscriber(move |event| recorded_events.borrow_mut().push(event.clone()));
+
+    vm_processor::execute(&TransactionKernel::main(), stack_inputs, &mut host, Default::default())
+        .unwrap();
+
+    events.take()
+}
@@ -1,15 +1,17 @@
+use alloc::string::String;
+
 use miden_lib::{
     errors::tx_kernel_errors::{
         ERR_ACCOUNT_ID_EPOCH_MUST_BE_LESS_THAN_U16_MAX,
         ERR_ACCOUNT_ID_LEAST_SIGNIFICANT_BYTE_MUST_BE_ZERO, ERR_ACCOUNT_ID_UNKNOWN_STORAGE_MODE,
-        ERR_ACCOUNT_ID_UNKNOWN_VERSION, TX_KERNEL_ERRORS,
+        ERR_ACCOUNT_ID_UNKNOWN_VERSION, ERR_ACCOUNT_PROC_INDEX_OUT_OF_BOUNDS, TX_KERNEL_ERRORS,
     },
     transaction::TransactionKernel,
 };
 use miden_objects::{
     account::{
-        AccountBuilder, AccountCode, AccountComponent, AccountId, AccountStorage, AccountType,
-        StorageSlot,
+        Account, AccountBuilder, AccountCode, AccountComponent, AccountId, AccountStorage,
+        AccountType, StorageSlot,
     },
     assembly::Library,
     testing::{
@@ -28,7 +30,10 @@ use rand_chacha::ChaCha20Rng;
 use vm_processor::{Digest, ExecutionError, MemAdviceProvider, ProcessState};
 
 use super::{Felt, ONE, StackInputs, Word, ZERO, word_to_masm_push_string};
-use crate::testing::{TransactionContextBuilder, executor::CodeExecutor};
+use crate::{
+    assert_execution_error,
+    testing::{TransactionContextBuilder, executor::CodeExecutor},
+};
 
 // ACCOUNT CODE TESTS
 // ================================================================================================
@@ -327,6 +332,110 @@ fn test_get_map_item() {
     }
 }
 
+#[test]
+fn test_get_map_item_with_presence() {
+    let account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
+        .with_component(
+            AccountMockComponent::new_with_slots(
+                TransactionKernel::testing_assembler(),
+                vec![AccountStorage::mock_item_2().slot],
+            )
+            .unwrap(),
+        )
+        .build_existing()
+        .unwrap();
+
+    let tx_context = TransactionContextBuilder::new(account).build();
+
+    let absent_key = Digest::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+    let entries = STORAGE_LEAVES_2.iter().map(|(key, value)| (*key, *value, 1)).chain([(
+        absent_key,
+        Word::default(),
+        0,
+    )]);
+
+    for (key, value, is_present) in entries {
+        let code = format!(
+            "
+            use.kernel::account
+            use.kernel::prologue
+
+            begin
+                exec.prologue::prepare_transaction
+
+                # get the map item
+                push.{map_key}
+                push.0
+                exec.account::get_map_item_with_presence
+                # => [VALUE, is_present, leaf_depth]
+
+                push.{value} assert_eqw
+                push.{is_present} assert_eq
+                push.64 assert_eq
+            end
+            ",
+            map_key = word_to_masm_push_string(&key),
+            value = word_to_masm_push_string(&value),
+        );
+
+        tx_context.execute_code(&code).unwrap();
+    }
+}
+
+#[test]
+fn test_get_procedure_info() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let procedures = tx_context.account().code().procedures();
+
+    let procedure_assertions: String = procedures
+        .iter()
+        .enumerate()
+        .map(|(index, procedure)| {
+            format!(
+                "
+                push.{index} exec.account::get_procedure_info
+                # => [PROC_ROOT, storage_offset, storage_size]
+
+                push.{proc_root} assert_eqw
+                push.{storage_offset} assert_eq
+                push.{storage_size} assert_eq
+                ",
+                proc_root = word_to_masm_push_string(&(*procedure.mast_root()).into()),
+                storage_offset = procedure.storage_offset(),
+                storage_size = procedure.storage_size(),
+            )
+        })
+        .collect();
+
+    let code = format!(
+        "
+        use.kernel::prologue
+        use.miden::account
+
+        begin
+            exec.prologue::prepare_transaction
+            {procedure_assertions}
+        end
+        "
+    );
+    tx_context.execute_code(&code).unwrap();
+
+    let code = format!(
+        "
+        use.kernel::prologue
+        use.miden::account
+
+        begin
+            exec.prologue::prepare_transaction
+            push.{index} exec.account::get_procedure_info
+        end
+        ",
+        index = procedures.len(),
+    );
+    let process = tx_context.execute_code(&code);
+    assert_execution_error!(process, ERR_ACCOUNT_PROC_INDEX_OUT_OF_BOUNDS);
+}
+
 #[test]
 fn test_get_storage_slot_type() {
     for storage_item in [
@@ -666,6 +775,140 @@ fn test_get_vault_root() {
     tx_context.execute_code(&code).unwrap();
 }
 
+// ACCOUNT COMMITMENT TESTS
+// ================================================================================================
+
+#[test]
+fn test_get_current_commitment_is_cached() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+
+    let account = tx_context.account();
+    let new_nonce = account.nonce() + Felt::new(5);
+    let updated_account = Account::from_parts(
+        account.id(),
+        account.vault().clone(),
+        account.storage().clone(),
+        account.code().clone(),
+        new_nonce,
+    );
+
+    let code = format!(
+        "
+        use.kernel::account
+        use.kernel::memory
+        use.kernel::prologue
+
+        begin
+            exec.prologue::prepare_transaction
+
+            # the commitment is not cached after the prologue
+            exec.memory::is_acct_commitment_cached assertz
+
+            # the first call computes the commitment and caches it
+            exec.account::get_current_commitment
+            push.{init_commitment} assert_eqw
+            exec.memory::is_acct_commitment_cached assert
+            exec.memory::get_acct_commitment_cache
+            push.{init_commitment} assert_eqw
+
+            # the second call returns the cached commitment
+            exec.account::get_current_commitment
+            push.{init_commitment} assert_eqw
+
+            # modifying the core account data invalidates the cache
+            push.{new_nonce} exec.memory::set_acct_nonce
+            exec.memory::is_acct_commitment_cached assertz
+
+            # the commitment is recomputed from the updated account data
+            exec.account::get_current_commitment
+            push.{updated_commitment} assert_eqw
+            exec.memory::is_acct_commitment_cached assert
+        end
+        ",
+        init_commitment = word_to_masm_push_string(&account.commitment()),
+        updated_commitment = word_to_masm_push_string(&updated_account.commitment()),
+    );
+
+    tx_context.execute_code(&code).unwrap();
+}
+
+#[test]
+fn test_get_current_commitment_after_storage_writes() {
+    let account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
+        .with_component(
+            AccountMockComponent::new_with_slots(
+                TransactionKernel::testing_assembler(),
+                AccountStorage::mock_storage_slots(),
+            )
+            .unwrap(),
+        )
+        .build_existing()
+        .unwrap();
+
+    let new_value: Word = [Felt::new(91), Felt::new(92), Felt::new(93), Felt::new(94)];
+    let (new_key, new_map_value): (Word, Word) = (
+        [Felt::new(109), Felt::new(110), Felt::new(111), Felt::new(112)],
+        [Felt::new(9), Felt::new(10), Felt::new(11), Felt::new(12)],
+    );
+
+    let mut account_after_set_item = account.clone();
+    account_after_set_item.storage_mut().set_item(0, new_value).unwrap();
+    let mut account_after_set_map_item = account_after_set_item.clone();
+    account_after_set_map_item
+        .storage_mut()
+        .set_map_item(2, new_key, new_map_value)
+        .unwrap();
+
+    let tx_context = TransactionContextBuilder::new(account.clone()).build();
+
+    let code = format!(
+        "
+        use.std::sys
+
+        use.test::account
+        use.kernel::account->kernel_account
+        use.kernel::memory
+        use.kernel::prologue
+
+        begin
+            exec.prologue::prepare_transaction
+
+            # cache the initial commitment
+            exec.kernel_account::get_current_commitment
+            push.{init_commitment} assert_eqw
+
+            # setting a storage item invalidates the cache
+            push.{new_value} push.0
+            call.account::set_item dropw dropw
+            exec.memory::is_acct_commitment_cached assertz
+
+            exec.kernel_account::get_current_commitment
+            push.{commitment_after_set_item} assert_eqw
+
+            # setting a storage map item invalidates the cache
+            push.{new_map_value} push.{new_key} push.2
+            call.account::set_map_item dropw dropw
+            exec.memory::is_acct_commitment_cached assertz
+
+            exec.kernel_account::get_current_commitment
+            push.{commitment_after_set_map_item} assert_eqw
+
+            # truncate the stack
+            exec.sys::truncate_stack
+        end
+        ",
+        init_commitment = word_to_masm_push_string(&account.commitment()),
+        new_value = word_to_masm_push_string(&new_value),
+        new_key = word_to_masm_push_string(&new_key),
+        new_map_value = word_to_masm_push_string(&new_map_value),
+        commitment_after_set_item = word_to_masm_push_string(&account_after_set_item.commitment()),
+        commitment_after_set_map_item =
+            word_to_masm_push_string(&account_after_set_map_item.commitment()),
+    );
+
+    tx_context.execute_code(&code).unwrap();
+}
+
 // PROCEDURE AUTHENTICATION TESTS
 // ================================================================================================
 
@@ -0,0 +1,430 @@
+use alloc::{sync::Arc, vec::Vec};
+
+use assert_matches::assert_matches;
+use k256::{
+    ecdsa::signature::hazmat::PrehashVerifier,
+    elliptic_curve::{Field, PrimeField, ops::Reduce, point::AffineCoordinates},
+};
+use miden_lib::{
+    account::{
+        auth::{Bls12381, EcdsaK256, NoAuth, TwoFactorAuth},
+        wallets::BasicWallet,
+    },
+    errors::tx_kernel_errors::{
+        ERR_AUTH_BLS12_381_HASH_TO_CURVE_COUNTER_NOT_MINIMAL,
+        ERR_AUTH_BLS12_381_INVALID_HASH_TO_CURVE_HINT,
+        ERR_AUTH_BLS12_381_PUBLIC_KEY_DOES_NOT_MATCH_COMMITMENT,
+        ERR_AUTH_BLS12_381_PUBLIC_KEY_NOT_IN_SUBGROUP, ERR_AUTH_BLS12_381_SIGNATURE_NOT_ON_CURVE,
+        ERR_AUTH_ECDSA_K256_INVALID_SIGNATURE,
+    },
+};
+use miden_objects::{
+    AccountError, Felt, Word,
+    account::{AccountBuilder, AccountType, AuthSecretKey},
+    crypto::{bls12_381, dsa::rpo_falcon512::SecretKey, ecdsa_k256},
+    utils::word_to_masm_push_string,
+};
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use vm_processor::{AdviceInputs, Digest, ExecutionError, ONE};
+
+use crate::{
+    KernelEvent, TransactionExecutor, TransactionExecutorError, assert_execution_error,
+    auth::{
+        BasicAuthenticator,
+        signatures::{
+            get_bls12_381_signature, get_ecdsa_k256_signature, get_two_factor_signatures,
+        },
+    },
+    testing::{MockChain, TransactionContextBuilder},
+    tests::fixtures::{TransactionFixture, replay_kernel_events},
+};
+
+// ECDSA K256 TESTS
+// ================================================================================================
+
+#[test]
+fn ecdsa_k256_auth_component_authenticates_transactions() {
+    let secret_key = ecdsa_k256::SecretKey::from_bytes(&[7; 32]).unwrap();
+    let pub_key_commitment = secret_key.public_key().commitment();
+
+    let fixture = TransactionFixture::with_auth_component(
+        &mut MockChain::new(),
+        EcdsaK256::new(pub_key_commitment),
+        "::miden::contracts::auth::ecdsa_k256::auth_tx_ecdsa_k256",
+    );
+    let nonce = fixture.tx_context.account().nonce();
+
+    // the signature is produced by the authenticator and verified by the auth component
+    let authenticator = BasicAuthenticator::<ChaCha20Rng>::new_with_rng(
+        &[(pub_key_commitment, AuthSecretKey::EcdsaK256(secret_key.clone()))],
+        ChaCha20Rng::from_seed(Default::default()),
+    );
+    let executor = TransactionExecutor::new(
+        fixture.tx_context.get_data_store(),
+        Some(Arc::new(authenticator)),
+    );
+    let executed_transaction = fixture.execute(&executor, fixture.tx_args()).unwrap();
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(nonce + ONE));
+
+    // a signature over a different message is rejected
+    let executor = TransactionExecutor::new(fixture.tx_context.get_data_store(), None);
+    let err = fixture.execute(&executor, fixture.tx_args()).unwrap_err();
+    let TransactionExecutorError::MissingSignatures(signing_requests) = err else {
+        panic!("unexpected error: {err}")
+    };
+    assert_eq!(signing_requests[0].pub_key(), pub_key_commitment);
+
+    let mut tx_args = fixture.tx_args();
+    tx_args.extend_advice_map([(
+        signing_requests[0].signature_key(),
+        get_ecdsa_k256_signature(&secret_key, [ONE; 4]),
+    )]);
+    let err = fixture.execute(&executor, tx_args).unwrap_err();
+    assert_matches!(
+        err.execution_error(),
+        Some(ExecutionError::FailedAssertion { err_code, .. })
+            if *err_code == ERR_AUTH_ECDSA_K256_INVALID_SIGNATURE
+    );
+}
+
+#[test]
+fn ecdsa_k256_verify_accepts_valid_signatures() {
+    let secret_key = [7; 32];
+    let (message, r, s_inv) = ecdsa_k256_signature_with_scalars(&secret_key, 0xbeef, 0xcafe);
+
+    execute_ecdsa_k256_verify(&secret_key, message, r, s_inv).unwrap();
+
+    // doubling s^-1 doubles both scalars, so the verifier computes 2 * R instead of R, whose
+    // x-coordinate does not match r
+    let result = execute_ecdsa_k256_verify(&secret_key, message, r, s_inv.double());
+    assert_execution_error!(result, ERR_AUTH_ECDSA_K256_INVALID_SIGNATURE);
+}
+
+// TWO FACTOR AUTH TESTS
+// ================================================================================================
+
+#[test]
+fn two_factor_auth_component_requires_both_signatures() {
+    let mut rng = ChaCha20Rng::from_seed([5; 32]);
+    let falcon_key = SecretKey::with_rng(&mut rng);
+    let ecdsa_key = ecdsa_k256::SecretKey::from_bytes(&[7; 32]).unwrap();
+
+    let fixture = TransactionFixture::with_auth_component(
+        &mut MockChain::new(),
+        TwoFactorAuth::new(falcon_key.public_key(), ecdsa_key.public_key().commitment()),
+        "::miden::contracts::auth::two_factor::auth_tx_two_factor",
+    );
+    let nonce = fixture.tx_context.account().nonce();
+
+    // the executor holds only the Falcon key, so the ECDSA signature is missing
+    let authenticator = BasicAuthenticator::<ChaCha20Rng>::new_with_rng(
+        &[(falcon_key.public_key().into(), AuthSecretKey::RpoFalcon512(falcon_key.clone()))],
+        ChaCha20Rng::from_seed(Default::default()),
+    );
+    let executor = TransactionExecutor::new(
+        fixture.tx_context.get_data_store(),
+        Some(Arc::new(authenticator)),
+    );
+    let err = fixture.execute(&executor, fixture.tx_args()).unwrap_err();
+    let TransactionExecutorError::MissingSignatures(signing_requests) = err else {
+        panic!("unexpected error: {err}")
+    };
+    assert_eq!(signing_requests.len(), 1);
+    assert_eq!(signing_requests[0].pub_key(), ecdsa_key.public_key().commitment());
+
+    // both signatures are provided via the advice map
+    let signatures =
+        get_two_factor_signatures(&falcon_key, &ecdsa_key, signing_requests[0].message(), &mut rng)
+            .unwrap();
+    let mut tx_args = fixture.tx_args();
+    tx_args.extend_advice_map(signatures);
+
+    let executor = TransactionExecutor::new(fixture.tx_context.get_data_store(), None);
+    let executed_transaction = fixture.execute(&executor, tx_args).unwrap();
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(nonce + ONE));
+}
+
+// NO AUTH TESTS
+// ================================================================================================
+
+#[test]
+fn no_auth_component_increments_nonce_and_emits_event() {
+    // the no-auth component cannot be used by accounts with updatable code
+    assert_matches!(
+        AccountBuilder::new([3; 32])
+            .account_type(AccountType::RegularAccountUpdatableCode)
+            .with_component(NoAuth)
+            .with_component(BasicWallet)
+            .build_existing(),
+        Err(AccountError::BuildError(_, Some(source)))
+            if matches!(*source, AccountError::UnsupportedComponentForAccountType { .. })
+    );
+
+    let fixture = TransactionFixture::with_auth_component(
+        &mut MockChain::new(),
+        NoAuth,
+        "::miden::contracts::auth::no_auth::auth_tx_no_auth",
+    );
+    let nonce = fixture.tx_context.account().nonce();
+
+    let executed_transaction = fixture.execute(&fixture.executor(), fixture.tx_args()).unwrap();
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(nonce + ONE));
+
+    // re-execute the transaction with the witness and check the emitted events
+    let events = replay_kernel_events(&executed_transaction);
+    assert!(events.contains(&KernelEvent::UnauthenticatedTransaction));
+}
+
+// BLS12-381 TESTS
+// ================================================================================================
+
+#[test]
+fn bls12_381_auth_component_requests_signature() {
+    let secret_key = bls12_381::SecretKey::from_bytes(&[7; 32]).unwrap();
+    let pub_key_commitment = secret_key.public_key().commitment();
+
+    let fixture = TransactionFixture::with_auth_component(
+        &mut MockChain::new(),
+        Bls12381::new(pub_key_commitment),
+        "::miden::contracts::auth::bls12_381::auth_tx_bls12_381",
+    );
+
+    let executor = TransactionExecutor::new(fixture.tx_context.get_data_store(), None);
+    let err = fixture.execute(&executor, fixture.tx_args()).unwrap_err();
+    let TransactionExecutorError::MissingSignatures(signing_requests) = err else {
+        panic!("unexpected error: {err}")
+    };
+    let signing_request = &signing_requests[0];
+    assert_eq!(signing_request.pub_key(), pub_key_commitment);
+
+    let signature = secret_key.sign(signing_request.message());
+    assert!(secret_key.public_key().verify(signing_request.message(), &signature));
+}
+
+#[test]
+fn bls12_381_verify_checks_public_key_and_message_point() {
+    // verifying a valid signature requires computing two pairings, which takes more than 10^8
+    // cycles, so only the checks preceding the pairings are executed: a signature which is not a
+    // point on the curve is rejected only after the public key, the point the message is hashed to
+    // and the multiplication of that point by the cofactor have been checked
+    let secret_key = bls12_381::SecretKey::from_bytes(&[7; 32]).unwrap();
+    let pub_key_commitment = secret_key.public_key().commitment();
+    let message = [ONE, Felt::new(2), Felt::new(3), Felt::new(4)];
+
+    // the values are [pk, ctr, w_0, ..., w_k, h_y, s_x, s_y] once put into the order of the advice
+    // stack
+    let mut signature = get_bls12_381_signature(&secret_key, message);
+    signature.reverse();
+    let sig_y_idx = signature.len() - bls12_381::FIELD_ELEMENT_NUM_LIMBS;
+
+    let mut invalid_signature = signature.clone();
+    invalid_signature[sig_y_idx] = Felt::new(invalid_signature[sig_y_idx].as_int() ^ 1);
+    let result = execute_bls12_381_verify(pub_key_commitment, message, invalid_signature);
+    assert_matches!(
+        result,
+        Err(ExecutionError::FailedAssertion { err_code, .. })
+            if err_code == ERR_AUTH_BLS12_381_SIGNATURE_NOT_ON_CURVE
+    );
+
+    let mut invalid_point = signature.clone();
+    invalid_point[sig_y_idx - 2 * bls12_381::FIELD_ELEMENT_NUM_LIMBS] += ONE;
+    let result = execute_bls12_381_verify(pub_key_commitment, message, invalid_point);
+    assert_matches!(
+        result,
+        Err(ExecutionError::FailedAssertion { err_code, .. })
+            if err_code == ERR_AUTH_BLS12_381_INVALID_HASH_TO_CURVE_HINT
+    );
+
+    let other_pub_key_commitment =
+        bls12_381::SecretKey::from_bytes(&[9; 32]).unwrap().public_key().commitment();
+    let result = execute_bls12_381_verify(other_pub_key_commitment, message, signature);
+    assert_matches!(
+        result,
+        Err(ExecutionError::FailedAssertion { err_code, .. })
+            if err_code == ERR_AUTH_BLS12_381_PUBLIC_KEY_DOES_NOT_MATCH_COMMITMENT
+    );
+}
+
+#[test]
+fn bls12_381_verify_rejects_non_minimal_counter() {
+    let secret_key = bls12_381::SecretKey::from_bytes(&[7; 32]).unwrap();
+    let pub_key_commitment = secret_key.public_key().commitment();
+    let message = [ONE, Felt::new(2), Felt::new(3), Felt::new(4)];
+
+    let mut signature = get_bls12_381_signature(&secret_key, message);
+    signature.reverse();
+    let counter_idx = bls12_381::PUBLIC_KEY_NUM_LIMBS;
+    let h_y_idx = signature.len() - 3 * bls12_381::FIELD_ELEMENT_NUM_LIMBS;
+
+    // skipping the smallest valid counter requires proving that it does not yield a point of the
+    // curve, for which the y-coordinate of the point it does yield is not a valid witness
+    let mut skipped_counter = signature.clone();
+    skipped_counter[counter_idx] += ONE;
+    let h_y = signature[h_y_idx..h_y_idx + bls12_381::FIELD_ELEMENT_NUM_LIMBS].to_vec();
+    skipped_counter.splice(h_y_idx..h_y_idx, h_y);
+    let result = execute_bls12_381_verify(pub_key_commitment, message, skipped_counter);
+    assert_matches!(
+        result,
+        Err(ExecutionError::FailedAssertion { err_code, .. })
+            if err_code == ERR_AUTH_BLS12_381_HASH_TO_CURVE_COUNTER_NOT_MINIMAL
+    );
+}
+
+#[test]
+fn bls12_381_verify_rejects_public_key_outside_subgroup() {
+    let secret_key = bls12_381::SecretKey::from_bytes(&[7; 32]).unwrap();
+    let message = [ONE, Felt::new(2), Felt::new(3), Felt::new(4)];
+
+    // the public key is a point of the curve, so it passes all checks but the subgroup check
+    let public_key = bls12_381::PublicKey::outside_subgroup();
+    let mut signature = get_bls12_381_signature(&secret_key, message);
+    signature.reverse();
+    signature[..bls12_381::PUBLIC_KEY_NUM_LIMBS].copy_from_slice(&public_key.to_elements());
+
+    let result = execute_bls12_381_verify(public_key.commitment(), message, signature);
+    assert_matches!(
+        result,
+        Err(ExecutionError::FailedAssertion { err_code, .. })
+            if err_code == ERR_AUTH_BLS12_381_PUBLIC_KEY_NOT_IN_SUBGROUP
+    );
+}
+
+#[test]
+fn bls12_381_verify_aggregated_signature() {
+    // this test verifies a signature including the pairings, which takes more than 10^8 cycles and
+    // is therefore excluded from `make test-dev`
+    let secret_keys: Vec<bls12_381::SecretKey> =
+        (7..10).map(|i| bls12_381::SecretKey::from_bytes(&[i; 32]).unwrap()).collect();
+    let message = [ONE, Felt::new(2), Felt::new(3), Felt::new(4)];
+
+    // the public keys are registered together with their proofs of possession
+    let keys: Vec<_> = secret_keys
+        .iter()
+        .map(|secret_key| (secret_key.public_key(), secret_key.prove_possession()))
+        .collect();
+    let public_key = bls12_381::PublicKey::aggregate(&keys).unwrap();
+
+    let signatures: Vec<_> =
+        secret_keys.iter().map(|secret_key| secret_key.sign(message)).collect();
+    let signature = bls12_381::Signature::aggregate(&signatures).unwrap();
+
+    let mut advice_stack = public_key.to_elements().to_vec();
+    advice_stack.extend(bls12_381::hash_to_curve_hint(message));
+    advice_stack.extend_from_slice(&signature.to_elements());
+
+    execute_bls12_381_verify(public_key.commitment(), message, advice_stack).unwrap();
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a message and an ECDSA signature `(r, s^-1)` over it by the provided secp256k1 secret
+/// key, such that the scalars `u1 = z * s^-1` and `u2 = r * s^-1` computed by the verifier are
+/// the provided values.
+///
+/// The verifier performs a point doubling for each bit of the larger scalar, so verifying a
+/// signature with full-size scalars takes tens of millions of cycles, whereas a valid signature
+/// with small scalars can be verified quickly. Such a signature is obtained by choosing the nonce
+/// `k = u1 + u2 * d`, which results in `R = k * G`, `s^-1 = u2 / r` and `z = u1 / s^-1`.
+fn ecdsa_k256_signature_with_scalars(
+    secret_key: &[u8; 32],
+    u1: u64,
+    u2: u64,
+) -> (Word, k256::Scalar, k256::Scalar) {
+    let d = k256::Scalar::from_repr((*secret_key).into()).unwrap();
+    let u1 = k256::Scalar::from(u1);
+    let u2 = k256::Scalar::from(u2);
+
+    let nonce_point = (k256::ProjectivePoint::GENERATOR * (u1 + u2 * d)).to_affine();
+    let r = <k256::Scalar as Reduce<k256::U256>>::reduce_bytes(&nonce_point.x());
+    let s_inv = u2 * r.invert().unwrap();
+    let z = u1 * s_inv.invert().unwrap();
+
+    // the message is the word whose elements are the little-endian u64 limbs of z
+    let mut z_bytes = z.to_bytes();
+    z_bytes.reverse();
+    let message: Word = core::array::from_fn(|i| {
+        let limb = u64::from_le_bytes(z_bytes[8 * i..8 * (i + 1)].try_into().unwrap());
+        Felt::try_from(limb).expect("limbs of the message should be valid field elements")
+    });
+
+    // make sure that this is a valid ECDSA signature over the message
+    let signature = k256::ecdsa::Signature::from_scalars(r, s_inv.invert().unwrap()).unwrap();
+    let verifying_key = *k256::ecdsa::SigningKey::from_slice(secret_key).unwrap().verifying_key();
+    let mut prehash = z_bytes;
+    prehash.reverse();
+    verifying_key.verify_prehash(&prehash, &signature).unwrap();
+
+    (message, r, s_inv)
+}
+
+/// Executes `miden::crypto::ecdsa_k256::verify` for the public key of the provided secret key, the
+/// message and the signature `(r, s^-1)`.
+fn execute_ecdsa_k256_verify(
+    secret_key: &[u8; 32],
+    message: Word,
+    r: k256::Scalar,
+    s_inv: k256::Scalar,
+) -> Result<(), ExecutionError> {
+    let public_key = ecdsa_k256::SecretKey::from_bytes(secret_key).unwrap().public_key();
+    let scalar_limbs = |scalar: k256::Scalar| {
+        scalar
+            .to_bytes()
+            .rchunks_exact(4)
+            .map(|chunk| Felt::from(u32::from_be_bytes(chunk.try_into().unwrap())))
+            .collect::<Vec<_>>()
+    };
+
+    let mut signature = public_key.to_elements().to_vec();
+    signature.extend(scalar_limbs(r));
+    signature.extend(scalar_limbs(s_inv));
+
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .advice_inputs(AdviceInputs::default().with_map([(Digest::from(message), signature)]))
+        .build();
+
+    let code = format!(
+        "
+        use.miden::crypto::ecdsa_k256
+
+        begin
+            push.{message} adv.push_mapval
+            push.{pub_key_commitment}
+            exec.ecdsa_k256::verify
+        end
+        ",
+        message = word_to_masm_push_string(&message),
+        pub_key_commitment = word_to_masm_push_string(&public_key.commitment()),
+    );
+
+    tx_context.execute_code(&code).map(|_| ())
+}
+
+/// Executes `miden::crypto::bls12_381::verify` for the provided public key commitment and message,
+/// with the provided values on the advice stack.
+fn execute_bls12_381_verify(
+    pub_key_commitment: Word,
+    message: Word,
+    advice_stack: Vec<Felt>,
+) -> Result<(), ExecutionError> {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .advice_inputs(AdviceInputs::default().with_map([(Digest::from(message), advice_stack)]))
+        .build();
+
+    let code = format!(
+        "
+        use.miden::crypto::bls12_381
+
+        begin
+            push.{message} adv.push_mapval
+            push.{pub_key_commitment}
+            exec.bls12_381::verify
+        end
+        ",
+        message = word_to_masm_push_string(&message),
+        pub_key_commitment = word_to_masm_push_string(&pub_key_commitment),
+    );
+
+    tx_context.execute_code(&code).map(|_| ())
+}
@@ -1,9 +1,16 @@
-use alloc::{string::ToString, vec::Vec};
+use alloc::{string::ToString, sync::Arc, vec::Vec};
 
+use ::assembly::{
+    LibraryPath,
+    ast::{Module, ModuleKind},
+};
+use assert_matches::assert_matches;
 use miden_lib::{
+    account::{filters::AssetAllowlist, wallets::BasicWallet},
     errors::tx_kernel_errors::{
-        ERR_ACCOUNT_NONCE_DID_NOT_INCREASE_AFTER_STATE_CHANGE,
+        ERR_ACCOUNT_NONCE_DID_NOT_INCREASE_AFTER_STATE_CHANGE, ERR_ACCOUNT_PROC_CALLER_NOT_ALLOWED,
         ERR_EPILOGUE_TOTAL_NUMBER_OF_ASSETS_MUST_STAY_THE_SAME, ERR_TX_INVALID_EXPIRATION_DELTA,
+        ERR_VAULT_ASSET_NOT_ALLOWED, ERR_VAULT_FUNGIBLE_ASSET_AMOUNT_LESS_THAN_AMOUNT_TO_WITHDRAW,
     },
     transaction::{
         TransactionKernel,
@@ -11,14 +18,28 @@ use miden_lib::{
     },
 };
 use miden_objects::{
-    account::Account,
-    transaction::{OutputNote, OutputNotes},
+    Digest, EMPTY_WORD, Word,
+    account::{
+        Account, AccountBuilder, AccountCode, AccountComponent, AccountId, ProcedureCallers,
+        StorageSlot,
+    },
+    assembly::DefaultSourceManager,
+    asset::{Asset, FungibleAsset},
+    note::NoteType,
+    testing::{
+        account_id::{ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET, ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_1},
+        note::NoteBuilder,
+    },
+    transaction::{OutputNote, OutputNotes, TransactionArgs, TransactionScript},
 };
-use vm_processor::{Felt, ONE, ProcessState};
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use vm_processor::{ExecutionError, Felt, ONE, ProcessState};
 
-use super::{ZERO, output_notes_data_procedure};
+use super::{ZERO, output_notes_data_procedure, word_to_masm_push_string};
 use crate::{
-    assert_execution_error, testing::TransactionContextBuilder,
+    TransactionExecutor, assert_execution_error,
+    testing::{AccountState, Auth, MockChain, TransactionContextBuilder},
     tests::kernel_tests::read_root_mem_word,
 };
 
@@ -50,7 +71,7 @@ fn test_epilogue() {
             exec.epilogue::finalize_transaction
 
             # truncate the stack
-            movupw.3 dropw movupw.3 dropw movup.9 drop
+            repeat.13 movup.13 drop end
         end
         "
     );
@@ -76,8 +97,9 @@ fn test_epilogue() {
     let mut expected_stack = Vec::with_capacity(16);
     expected_stack.extend(output_notes.commitment().as_elements().iter().rev());
     expected_stack.extend(final_account.commitment().as_elements().iter().rev());
+    expected_stack.extend([ZERO; 4]); // Value for tx fee
     expected_stack.push(Felt::from(u32::MAX)); // Value for tx expiration block number
-    expected_stack.extend((9..16).map(|_| ZERO));
+    expected_stack.extend((13..16).map(|_| ZERO));
 
     assert_eq!(
         *process.stack.build_stack_outputs().unwrap(),
@@ -115,7 +137,7 @@ fn test_compute_output_note_id() {
                 exec.epilogue::finalize_transaction
 
                 # truncate the stack
-                movupw.3 dropw movupw.3 dropw movup.9 drop
+                repeat.13 movup.13 drop end
             end
             "
         );
@@ -166,7 +188,7 @@ fn test_epilogue_asset_preservation_violation_too_few_input() {
             exec.epilogue::finalize_transaction
             
             # truncate the stack
-            movupw.3 dropw movupw.3 dropw movup.9 drop
+            repeat.13 movup.13 drop end
         end
         "
     );
@@ -200,7 +222,7 @@ fn test_epilogue_asset_preservation_violation_too_many_fungible_input() {
             exec.epilogue::finalize_transaction
                         
             # truncate the stack
-            movupw.3 dropw movupw.3 dropw movup.9 drop
+            repeat.13 movup.13 drop end
         end
         "
     );
@@ -232,7 +254,7 @@ fn test_block_expiration_height_monotonically_decreases() {
             exec.epilogue::finalize_transaction
                         
             # truncate the stack
-            movupw.3 dropw movupw.3 dropw movup.9 drop
+            repeat.13 movup.13 drop end
         end
         ";
 
@@ -249,7 +271,7 @@ fn test_block_expiration_height_monotonically_decreases() {
         // (which can only decrease, not increase)
         let expected_expiry =
             v1.min(v2) + tx_context.tx_inputs().block_header().block_num().as_u64();
-        assert_eq!(process_state.get_stack_item(8).as_int(), expected_expiry);
+        assert_eq!(process_state.get_stack_item(12).as_int(), expected_expiry);
     }
 }
 
@@ -292,7 +314,7 @@ fn test_no_expiration_delta_set() {
         exec.epilogue::finalize_transaction
                     
         # truncate the stack
-        movupw.3 dropw movupw.3 dropw movup.9 drop
+        repeat.13 movup.13 drop end
     end
     ";
 
@@ -300,7 +322,36 @@ fn test_no_expiration_delta_set() {
     let process_state: ProcessState = process.into();
 
     // Default value should be equal to u32::max, set in the prologue
-    assert_eq!(process_state.get_stack_item(8).as_int() as u32, u32::MAX);
+    assert_eq!(process_state.get_stack_item(12).as_int() as u32, u32::MAX);
+}
+
+#[test]
+fn test_expiration_delta_from_tx_args() {
+    let mut tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    tx_context.set_tx_args(TransactionArgs::default().with_expiration_delta(5));
+
+    let code = "
+    use.kernel::prologue
+    use.kernel::epilogue
+    use.kernel::tx
+
+    begin
+        exec.prologue::prepare_transaction
+
+        exec.tx::get_expiration_delta push.5 assert_eq
+
+        exec.epilogue::finalize_transaction
+
+        # truncate the stack
+        repeat.13 movup.13 drop end
+    end
+    ";
+
+    let process = &tx_context.execute_code(code).unwrap();
+    let process_state: ProcessState = process.into();
+
+    let expected_expiry = tx_context.tx_inputs().block_header().block_num().as_u64() + 5;
+    assert_eq!(process_state.get_stack_item(12).as_int(), expected_expiry);
 }
 
 #[test]
@@ -374,7 +425,7 @@ fn test_epilogue_increment_nonce_violation() {
             exec.epilogue::finalize_transaction
 
             # truncate the stack
-            movupw.3 dropw movupw.3 dropw movup.9 drop
+            repeat.13 movup.13 drop end
         end
         "
     );
@@ -382,3 +433,412 @@ fn test_epilogue_increment_nonce_violation() {
     let process = tx_context.execute_code(&code);
     assert_execution_error!(process, ERR_ACCOUNT_NONCE_DID_NOT_INCREASE_AFTER_STATE_CHANGE)
 }
+
+#[test]
+fn test_epilogue_invokes_final_state_validator() {
+    const ERR_VALUE_TOO_LARGE: u32 = 0x0001_0001;
+
+    let component_code = format!(
+        "
+        use.miden::account
+
+        const.ERR_VALUE_TOO_LARGE={ERR_VALUE_TOO_LARGE}
+
+        export.set_value
+            push.0 exec.account::set_item dropw
+            push.1 exec.account::incr_nonce
+        end
+
+        export.validate_final_state
+            # the first element of the value in slot 0 must be smaller than 100
+            push.0 exec.account::get_item drop drop drop
+            push.100 lt assert.err=ERR_VALUE_TOO_LARGE
+        end
+        "
+    );
+    let component = AccountComponent::compile(
+        component_code,
+        TransactionKernel::testing_assembler(),
+        vec![StorageSlot::Value(Word::default())],
+    )
+    .unwrap()
+    .with_supports_all_types();
+    let set_value = component
+        .library()
+        .exports()
+        .find(|export| export.name.as_str() == "set_value")
+        .map(|export| {
+            component.mast_forest()[component.library().get_export_node_id(export)].digest()
+        })
+        .unwrap();
+
+    let account = AccountBuilder::new([7; 32]).with_component(component).build_existing().unwrap();
+    assert!(account.code().procedures().iter().any(|proc| proc.is_final_state_validator()));
+
+    let execute_with_value = |value: u64| {
+        let tx_script_program = TransactionKernel::testing_assembler()
+            .assemble_program(format!("begin push.{value}.0.0.0 call.{set_value} dropw end"))
+            .unwrap();
+        let tx_script = TransactionScript::new(tx_script_program, vec![]);
+
+        TransactionContextBuilder::new(account.clone())
+            .tx_script(tx_script)
+            .build()
+            .execute()
+    };
+
+    // the final state satisfies the invariant of the account
+    execute_with_value(5).unwrap();
+
+    // the final state violates the invariant of the account, which fails the transaction
+    let err = execute_with_value(500).unwrap_err();
+    assert_matches!(
+        err.execution_error(),
+        Some(ExecutionError::FailedAssertion { err_code, .. }) if *err_code == ERR_VALUE_TOO_LARGE
+    );
+}
+
+#[test]
+fn test_epilogue_invokes_asset_filter() {
+    let allowed_faucet = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET).unwrap();
+    let other_faucet = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_1).unwrap();
+
+    let mut mock_chain = MockChain::new();
+    let sender = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let account_builder = AccountBuilder::new([7; 32])
+        .with_component(BasicWallet)
+        .with_component(AssetAllowlist::new([allowed_faucet.prefix()]));
+    let account =
+        mock_chain.add_from_account_builder(Auth::BasicAuth, account_builder, AccountState::Exists);
+    assert!(account.code().procedures().iter().any(|proc| proc.is_asset_filter()));
+
+    let allowed_note = mock_chain
+        .add_p2id_note(
+            sender.id(),
+            account.id(),
+            &[FungibleAsset::new(allowed_faucet, 10).unwrap().into()],
+            NoteType::Public,
+            None,
+        )
+        .unwrap();
+    let other_note = mock_chain
+        .add_p2id_note(
+            sender.id(),
+            account.id(),
+            &[FungibleAsset::new(other_faucet, 10).unwrap().into()],
+            NoteType::Public,
+            None,
+        )
+        .unwrap();
+    mock_chain.seal_next_block();
+
+    // assets issued by an allowed faucet are accepted
+    mock_chain
+        .build_tx_context(account.id(), &[allowed_note.id()], &[])
+        .build()
+        .execute()
+        .unwrap();
+
+    // assets issued by any other faucet are rejected by the filter
+    let err = mock_chain
+        .build_tx_context(account.id(), &[other_note.id()], &[])
+        .build()
+        .execute()
+        .unwrap_err();
+    assert_matches!(
+        err.execution_error(),
+        Some(ExecutionError::FailedAssertion { err_code, .. })
+            if *err_code == ERR_VAULT_ASSET_NOT_ALLOWED
+    );
+}
+
+#[test]
+fn test_epilogue_invokes_asset_filter_for_assets_added_via_syscall() {
+    let allowed_faucet = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET).unwrap();
+    let other_faucet = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_1).unwrap();
+
+    // the external library adds assets by invoking the kernel procedure directly instead of going
+    // through `miden::account::add_asset`
+    const EXTERNAL_LIBRARY_CODE: &str = "
+        use.miden::kernel_proc_offsets
+
+        export.add_asset_via_syscall
+            exec.kernel_proc_offsets::account_add_asset_offset
+            push.0.0.0 movdn.7 movdn.7 movdn.7 padw padw swapdw
+            syscall.exec_kernel_proc
+            swapdw dropw dropw swapw dropw
+        end";
+
+    const ACCOUNT_COMPONENT_CODE: &str = "
+        use.external_library::external_module
+
+        export.receive_asset_via_syscall
+            exec.external_module::add_asset_via_syscall
+        end";
+
+    let source_manager = Arc::new(DefaultSourceManager::default());
+    let external_library_module = Module::parser(ModuleKind::Library)
+        .parse_str(
+            LibraryPath::new("external_library::external_module").unwrap(),
+            EXTERNAL_LIBRARY_CODE,
+            &source_manager,
+        )
+        .unwrap();
+    let external_library = TransactionKernel::assembler()
+        .assemble_library([external_library_module])
+        .unwrap();
+
+    let mut assembler = TransactionKernel::assembler();
+    assembler.add_library(&external_library).unwrap();
+    let component = AccountComponent::compile(ACCOUNT_COMPONENT_CODE, assembler, vec![])
+        .unwrap()
+        .with_supports_all_types();
+    let receive_asset_via_syscall = component
+        .library()
+        .exports()
+        .find(|export| export.name.as_str() == "receive_asset_via_syscall")
+        .map(|export| {
+            component.mast_forest()[component.library().get_export_node_id(export)].digest()
+        })
+        .unwrap();
+
+    let account = AccountBuilder::new([7; 32])
+        .with_component(component)
+        .with_component(AssetAllowlist::new([allowed_faucet.prefix()]))
+        .build_existing()
+        .unwrap();
+
+    let asset: Asset = FungibleAsset::new(other_faucet, 10).unwrap().into();
+    let tx_script_program = TransactionKernel::testing_assembler()
+        .assemble_program(format!(
+            "begin push.{asset} call.{receive_asset_via_syscall} dropw end",
+            asset = word_to_masm_push_string(&asset.into())
+        ))
+        .unwrap();
+    let tx_script = TransactionScript::new(tx_script_program, vec![]);
+
+    let tx_context = TransactionContextBuilder::new(account).tx_script(tx_script).build();
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let mut executor = TransactionExecutor::new(tx_context.get_data_store(), None);
+    executor.load_library(&external_library);
+
+    // the asset is rejected by the filter even though `miden::account::add_asset` was bypassed
+    let err = executor
+        .execute_transaction(account_id, block_ref, &[], tx_context.tx_args().clone())
+        .unwrap_err();
+    assert_matches!(
+        err.execution_error(),
+        Some(ExecutionError::FailedAssertion { err_code, .. })
+            if *err_code == ERR_VAULT_ASSET_NOT_ALLOWED
+    );
+}
+
+#[test]
+fn test_epilogue_hooks_are_not_affected_by_caller_restrictions() {
+    // every procedure reads from storage so that the kernel checks its caller restrictions
+    let component = AccountComponent::compile(
+        "
+        use.miden::account
+
+        export.read_value
+            push.0 exec.account::get_item swapw dropw
+        end
+
+        export.validate_final_state
+            push.0 exec.account::get_item dropw
+        end
+
+        export.filter_incoming_asset
+            push.0 exec.account::get_item dropw
+        end
+        ",
+        TransactionKernel::testing_assembler(),
+        vec![StorageSlot::Value(Word::default())],
+    )
+    .unwrap()
+    .with_supports_all_types()
+    .with_allowed_callers("read_value", ProcedureCallers::TX_SCRIPT)
+    .with_allowed_callers(AccountCode::FINAL_STATE_VALIDATOR_NAME, ProcedureCallers::TX_SCRIPT)
+    .with_allowed_callers(AccountCode::ASSET_FILTER_NAME, ProcedureCallers::TX_SCRIPT);
+    let read_value = component
+        .library()
+        .exports()
+        .find(|export| export.name.as_str() == "read_value")
+        .map(|export| {
+            component.mast_forest()[component.library().get_export_node_id(export)].digest()
+        })
+        .unwrap();
+
+    let mut mock_chain = MockChain::new();
+    let sender = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let account_builder = AccountBuilder::new([7; 32])
+        .with_component(BasicWallet)
+        .with_component(component);
+    let account =
+        mock_chain.add_from_account_builder(Auth::BasicAuth, account_builder, AccountState::Exists);
+    let procedures = account.code().procedures();
+    assert!(procedures.iter().any(|proc| proc.is_final_state_validator()));
+    assert!(procedures.iter().any(|proc| proc.is_asset_filter()));
+
+    let p2id_note = mock_chain
+        .add_p2id_note(
+            sender.id(),
+            account.id(),
+            &[FungibleAsset::mock(10)],
+            NoteType::Public,
+            None,
+        )
+        .unwrap();
+    let read_value_note = NoteBuilder::new(sender.id(), ChaCha20Rng::from_seed([3; 32]))
+        .code(format!("begin call.{read_value} dropw end"))
+        .build(&TransactionKernel::testing_assembler())
+        .unwrap();
+    mock_chain.add_pending_note(read_value_note.clone());
+    mock_chain.seal_next_block();
+
+    // the transaction script may invoke the procedure, and the hooks invoked by the kernel in the
+    // epilogue are not restricted even though assets were added to the vault by a note script
+    let tx_script = TransactionScript::compile(
+        format!(
+            "
+            begin
+                call.{read_value} dropw
+
+                padw padw padw padw
+                call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+                dropw dropw dropw dropw
+            end
+            "
+        ),
+        vec![],
+        TransactionKernel::testing_assembler(),
+    )
+    .unwrap();
+    mock_chain
+        .build_tx_context(account.id(), &[p2id_note.id()], &[])
+        .tx_script(tx_script)
+        .build()
+        .execute()
+        .unwrap();
+
+    // note scripts may not invoke the procedure
+    let err = mock_chain
+        .build_tx_context(account.id(), &[read_value_note.id()], &[])
+        .build()
+        .execute()
+        .unwrap_err();
+    assert_matches!(
+        err.execution_error(),
+        Some(ExecutionError::FailedAssertion { err_code, .. })
+            if *err_code == ERR_ACCOUNT_PROC_CALLER_NOT_ALLOWED
+    );
+}
+
+#[test]
+fn test_epilogue_deducts_fee_from_account_vault() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET).unwrap();
+    let balance = FungibleAsset::new(faucet_id, 100).unwrap();
+
+    let component = AccountComponent::compile(
+        "
+        use.miden::account
+        use.miden::tx
+
+        export.pay_fee
+            exec.tx::set_fee
+            push.1 exec.account::incr_nonce
+        end
+
+        export.pay_fee_without_nonce_increment
+            exec.tx::set_fee
+        end
+        ",
+        TransactionKernel::testing_assembler(),
+        vec![],
+    )
+    .unwrap()
+    .with_supports_all_types();
+    let procedure_root = |name: &str| {
+        component
+            .library()
+            .exports()
+            .find(|export| export.name.as_str() == name)
+            .map(|export| {
+                component.mast_forest()[component.library().get_export_node_id(export)].digest()
+            })
+            .unwrap()
+    };
+    let pay_fee = procedure_root("pay_fee");
+    let pay_fee_without_nonce_increment = procedure_root("pay_fee_without_nonce_increment");
+
+    let account = AccountBuilder::new([7; 32])
+        .with_component(component)
+        .with_assets([balance.into()])
+        .build_existing()
+        .unwrap();
+
+    let execute_with_fee = |procedure: Digest, fee: FungibleAsset| {
+        // a fee with an amount of zero is stored as an empty word
+        let expected_fee = if fee.amount() == 0 { EMPTY_WORD } else { fee.into() };
+        let tx_script_program = TransactionKernel::testing_assembler()
+            .assemble_program(format!(
+                "
+                use.miden::tx
+
+                begin
+                    push.{fee}
+                    call.{procedure}
+                    dropw
+
+                    # the fee can be read back after it was set
+                    exec.tx::get_fee push.{expected_fee} assert_eqw
+                end
+                ",
+                fee = word_to_masm_push_string(&fee.into()),
+                expected_fee = word_to_masm_push_string(&expected_fee),
+            ))
+            .unwrap();
+        let tx_script = TransactionScript::new(tx_script_program, vec![]);
+
+        TransactionContextBuilder::new(account.clone())
+            .tx_script(tx_script)
+            .build()
+            .execute()
+    };
+
+    // the fee is removed from the account vault and exposed in the transaction outputs
+    let fee = FungibleAsset::new(faucet_id, 30).unwrap();
+    let executed_tx = execute_with_fee(pay_fee, fee).unwrap();
+    assert_eq!(executed_tx.fee(), Some(fee));
+    assert_eq!(
+        executed_tx.account_delta().vault().removed_assets().collect::<Vec<_>>(),
+        vec![Asset::from(fee)]
+    );
+
+    // the fee cannot exceed the balance of the account
+    let err = execute_with_fee(pay_fee, FungibleAsset::new(faucet_id, 101).unwrap()).unwrap_err();
+    assert_matches!(
+        err.execution_error(),
+        Some(ExecutionError::FailedAssertion { err_code, .. })
+            if *err_code == ERR_VAULT_FUNGIBLE_ASSET_AMOUNT_LESS_THAN_AMOUNT_TO_WITHDRAW
+    );
+
+    // a fee with an amount of zero is not paid, even if the account does not hold any assets
+    // issued by the fee faucet
+    let absent_faucet_id = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_1).unwrap();
+    let executed_tx =
+        execute_with_fee(pay_fee, FungibleAsset::new(absent_faucet_id, 0).unwrap()).unwrap();
+    assert_eq!(executed_tx.fee(), None);
+    assert_eq!(executed_tx.account_delta().vault().removed_assets().count(), 0);
+
+    // paying the fee changes the account vault, so the nonce must be incremented even if the fee
+    // is the only state change of the transaction
+    let err = execute_with_fee(pay_fee_without_nonce_increment, fee).unwrap_err();
+    assert_matches!(
+        err.execution_error(),
+        Some(ExecutionError::FailedAssertion { err_code, .. })
+            if *err_code == ERR_ACCOUNT_NONCE_DID_NOT_INCREASE_AFTER_STATE_CHANGE
+    );
+}
@@ -1,10 +1,25 @@
 use alloc::vec::Vec;
 use std::{string::ToString, vec};
 
+use assert_matches::assert_matches;
 use miden_lib::{
-    errors::tx_kernel_errors::{
-        ERR_FOREIGN_ACCOUNT_CONTEXT_AGAINST_NATIVE_ACCOUNT, ERR_FOREIGN_ACCOUNT_MAX_NUMBER_EXCEEDED,
+    account::{
+        counters::AtomicCounter,
+        interface::{AccountInterface, ForeignProcedure},
+        oracles::{PriceEntry, PricePair, StandardPriceOracle},
+    },
+    errors::{
+        note_script_errors::{
+            ERR_PSWAP_PRICE_ABOVE_MAXIMUM, ERR_PSWAP_PRICE_BELOW_MINIMUM, ERR_PSWAP_PRICE_NOT_SET,
+            ERR_PSWAP_PRICE_TOO_OLD,
+        },
+        tx_kernel_errors::{
+            ERR_ACCOUNT_PRICE_ORACLE_STALE_PRICE, ERR_ACCOUNT_PROC_CALLER_NOT_ALLOWED,
+            ERR_FOREIGN_ACCOUNT_CONTEXT_AGAINST_NATIVE_ACCOUNT,
+            ERR_FOREIGN_ACCOUNT_MAX_NUMBER_EXCEEDED,
+        },
     },
+    note::{create_pswap_note, oracle::OraclePriceBounds},
     transaction::{
         TransactionKernel,
         memory::{
@@ -16,23 +31,41 @@ use miden_lib::{
     },
 };
 use miden_objects::{
-    ACCOUNT_TREE_DEPTH,
+    ACCOUNT_TREE_DEPTH, Felt,
     account::{
-        Account, AccountBuilder, AccountComponent, AccountProcedureInfo, AccountStorage,
+        Account, AccountBuilder, AccountComponent, AccountId, AccountProcedureInfo, AccountStorage,
         StorageSlot,
     },
-    crypto::merkle::{LeafIndex, MerklePath},
-    testing::{account_component::AccountMockComponent, storage::STORAGE_LEAVES_2},
+    assembly::{LibraryNamespace, LibraryPath, ProcedureName},
+    asset::{FungibleAsset, NonFungibleAsset},
+    block::BlockNumber,
+    crypto::{
+        merkle::{LeafIndex, MerklePath},
+        rand::RpoRandomCoin,
+    },
+    note::{Note, NoteType},
+    testing::{
+        account_component::AccountMockComponent,
+        account_id::{
+            ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET, ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_1,
+            ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_2,
+        },
+        note::NoteBuilder,
+        storage::STORAGE_LEAVES_2,
+    },
     transaction::TransactionScript,
+    utils::word_to_masm_push_string,
 };
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha20Rng;
 use vm_processor::AdviceInputs;
+use winter_maybe_async::{maybe_async, maybe_async_trait};
 
 use super::{Process, Word, ZERO};
 use crate::{
-    TransactionExecutor, TransactionExecutorError, assert_execution_error,
-    testing::MockChain,
+    DataStoreError, ForeignAccountInputs, ForeignAccountInputsBuilder, ForeignAccountInputsError,
+    ForeignAccountProvider, TransactionExecutor, TransactionExecutorError, assert_execution_error,
+    testing::{AccountState, Auth, MockChain},
     tests::kernel_tests::{read_root_mem_word, try_read_root_mem_word},
 };
 
@@ -1041,11 +1074,11 @@ fn test_nested_fpi_stack_overflow() {
                 tx_context.tx_args().clone(),
             ).unwrap_err();
 
-        let TransactionExecutorError::TransactionProgramExecutionFailed(err) = err else {
+        let TransactionExecutorError::TransactionKernelAssertionFailed { source, .. } = err else {
             panic!("unexpected error")
         };
 
-        assert_execution_error!(Err::<(), _>(err), ERR_FOREIGN_ACCOUNT_MAX_NUMBER_EXCEEDED);
+        assert_execution_error!(Err::<(), _>(source), ERR_FOREIGN_ACCOUNT_MAX_NUMBER_EXCEEDED);
     }).expect("thread panic external").join().expect("thread panic internal");
 }
 
@@ -1166,11 +1199,558 @@ fn test_nested_fpi_native_account_invocation() {
         .execute_transaction(native_account.id(), block_ref, &[], tx_context.tx_args().clone())
         .unwrap_err();
 
-    let TransactionExecutorError::TransactionProgramExecutionFailed(err) = err else {
+    let TransactionExecutorError::TransactionKernelAssertionFailed { source, .. } = err else {
         panic!("unexpected error")
     };
 
-    assert_execution_error!(Err::<(), _>(err), ERR_FOREIGN_ACCOUNT_CONTEXT_AGAINST_NATIVE_ACCOUNT);
+    assert_execution_error!(
+        Err::<(), _>(source),
+        ERR_FOREIGN_ACCOUNT_CONTEXT_AGAINST_NATIVE_ACCOUNT
+    );
+}
+
+// NOTE SCRIPT FPI TESTS
+// ================================================================================================
+
+/// Tests that the PSWAP note reads the price from the oracle account via FPI and can only be
+/// consumed while the price is recent enough and within the bounds specified by the note.
+#[test]
+fn test_fpi_pswap_note() {
+    let pair = PricePair::new(
+        AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET).unwrap(),
+        AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_1).unwrap(),
+    );
+    let offered_asset = FungibleAsset::mock(100);
+    let requested_asset = NonFungibleAsset::mock(&[1, 2, 3, 4]);
+
+    let mut mock_chain = MockChain::new();
+    let oracle_account = mock_chain.add_from_account_builder(
+        Auth::BasicAuth,
+        AccountBuilder::new(ChaCha20Rng::from_os_rng().random()).with_component(
+            StandardPriceOracle::new([(pair, PriceEntry::new(Felt::new(50), 1000))]),
+        ),
+        AccountState::Exists,
+    );
+
+    // update the price of the oracle
+    let price_timestamp = mock_chain.latest_block_header().timestamp();
+    let push_price_script = format!(
+        "
+        use.miden::contracts::oracles::standard->oracle
+
+        begin
+            padw padw push.0.0 push.{price_timestamp}.150 push.{pair}
+            # => [PAIR, price, timestamp, pad(10)]
+
+            call.oracle::push_price
+            dropw dropw dropw dropw
+
+            call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+        end
+        ",
+        pair = word_to_masm_push_string(&pair.into()),
+    );
+    let tx_script =
+        TransactionScript::compile(push_price_script, vec![], TransactionKernel::assembler())
+            .unwrap();
+    let executed_transaction = mock_chain
+        .build_tx_context(oracle_account.id(), &[], &[])
+        .tx_script(tx_script)
+        .build()
+        .execute()
+        .unwrap();
+    let oracle_account = mock_chain.apply_executed_transaction(&executed_transaction);
+    assert_eq!(
+        PriceEntry::from_storage_value(
+            oracle_account.storage().get_map_item(0, pair.storage_key().into()).unwrap()
+        ),
+        Some(PriceEntry::new(Felt::new(150), price_timestamp))
+    );
+
+    // notes consumed by the oracle cannot update its prices
+    let push_price_note = NoteBuilder::new(oracle_account.id(), ChaCha20Rng::from_seed([4; 32]))
+        .code(format!(
+            "
+            use.miden::contracts::oracles::standard->oracle
+
+            begin
+                padw padw push.0.0 push.1020.1 push.{pair}
+                call.oracle::push_price
+                dropw dropw dropw dropw
+            end
+            ",
+            pair = word_to_masm_push_string(&pair.into()),
+        ))
+        .build(&TransactionKernel::assembler())
+        .unwrap();
+    mock_chain.add_pending_note(push_price_note.clone());
+    mock_chain.seal_next_block();
+    let err = mock_chain
+        .build_tx_context(oracle_account.id(), &[push_price_note.id()], &[])
+        .build()
+        .execute()
+        .unwrap_err();
+    let TransactionExecutorError::TransactionKernelAssertionFailed { source, .. } = err else {
+        panic!("unexpected error")
+    };
+    assert_execution_error!(Err::<(), _>(source), ERR_ACCOUNT_PROC_CALLER_NOT_ALLOWED);
+
+    let sender_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let target_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![requested_asset]);
+
+    let mut rng = RpoRandomCoin::new(Word::default());
+    let mut create_note = |pair: PricePair, min_price: u64, max_price: u64, max_price_age: u32| {
+        let price_bounds = OraclePriceBounds::new(
+            oracle_account.id(),
+            pair,
+            Felt::new(min_price),
+            Felt::new(max_price),
+            max_price_age,
+        );
+        let (note, payback_note) = create_pswap_note(
+            sender_account.id(),
+            offered_asset,
+            requested_asset,
+            &price_bounds,
+            NoteType::Public,
+            ZERO,
+            &mut rng,
+        )
+        .unwrap();
+        mock_chain.add_pending_note(note.clone());
+        (note, payback_note)
+    };
+
+    let (note_in_bounds, payback_note) = create_note(pair, 100, 200, 3600);
+    let (note_below_minimum, _) = create_note(pair, 151, 200, 3600);
+    let (note_above_maximum, _) = create_note(pair, 100, 149, 3600);
+    // blocks were sealed since the price was pushed, so a zero maximum age rejects the price
+    let (note_too_old, _) = create_note(pair, 100, 200, 0);
+
+    // the price of a pair which was never pushed is 0 with a timestamp of 0, which must be
+    // rejected even if the bounds and the maximum age would allow it
+    let unset_pair = PricePair::new(
+        AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET).unwrap(),
+        AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_2).unwrap(),
+    );
+    let (note_unset_pair, _) = create_note(unset_pair, 0, 200, u32::MAX);
+    mock_chain.seal_next_block();
+
+    let advice_inputs = get_mock_fpi_adv_inputs(vec![&oracle_account], &mock_chain);
+    let mut execute = |note: &Note| {
+        mock_chain
+            .build_tx_context(target_account.id(), &[note.id()], &[])
+            .foreign_account_codes(vec![oracle_account.code().clone()])
+            .advice_inputs(advice_inputs.clone())
+            .build()
+            .execute()
+    };
+
+    let executed_transaction = execute(&note_in_bounds).unwrap();
+    let output_notes = executed_transaction.output_notes();
+    assert_eq!(output_notes.num_notes(), 1);
+    assert_eq!(output_notes.get_note(0).id(), payback_note.id());
+
+    for (note, expected_err) in [
+        (note_below_minimum, ERR_PSWAP_PRICE_BELOW_MINIMUM),
+        (note_above_maximum, ERR_PSWAP_PRICE_ABOVE_MAXIMUM),
+        (note_too_old, ERR_PSWAP_PRICE_TOO_OLD),
+        (note_unset_pair, ERR_PSWAP_PRICE_NOT_SET),
+    ] {
+        let TransactionExecutorError::TransactionProgramExecutionFailed(err) =
+            execute(&note).unwrap_err()
+        else {
+            panic!("unexpected error")
+        };
+        assert_execution_error!(Err::<(), _>(err), expected_err);
+    }
+}
+
+/// Tests that the counter of the atomic counter component is incremented by a transaction against
+/// the counter account and can be read by another account via FPI.
+#[test]
+fn test_fpi_atomic_counter() {
+    let mut mock_chain = MockChain::new();
+    let counter_account = mock_chain.add_from_account_builder(
+        Auth::BasicAuth,
+        AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
+            .with_component(AtomicCounter::new(5)),
+        AccountState::Exists,
+    );
+
+    let increment_script = "
+        use.miden::contracts::counters::atomic->counter
+
+        begin
+            padw padw padw padw
+            call.counter::increment_and_get
+            # => [count, pad(15)]
+
+            push.6 assert_eq push.0
+            # => [pad(16)]
+
+            call.counter::increment_and_get
+            # => [count, pad(15)]
+
+            push.7 assert_eq
+            dropw dropw dropw drop drop drop
+
+            call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+        end
+    ";
+    let tx_script =
+        TransactionScript::compile(increment_script, vec![], TransactionKernel::assembler())
+            .unwrap();
+    let executed_transaction = mock_chain
+        .build_tx_context(counter_account.id(), &[], &[])
+        .tx_script(tx_script)
+        .build()
+        .execute()
+        .unwrap();
+    let counter_account = mock_chain.apply_executed_transaction(&executed_transaction);
+    assert_eq!(
+        counter_account.storage().get_item(0).unwrap(),
+        [Felt::new(7), ZERO, ZERO, ZERO].into()
+    );
+
+    let native_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    mock_chain.seal_next_block();
+
+    let read_script = format!(
+        "
+        use.std::sys
+
+        use.miden::tx
+
+        begin
+            # pad the stack for the `execute_foreign_procedure` execution
+            padw padw padw push.0.0.0
+            # => [pad(15)]
+
+            push.{get_count_root}
+            push.{counter_suffix}.{counter_prefix}
+            # => [counter_account_id_prefix, counter_account_id_suffix, GET_COUNT_ROOT, pad(15)]
+
+            exec.tx::execute_foreign_procedure
+            # => [count, pad(15)]
+
+            push.7 assert_eq
+
+            # truncate the stack
+            exec.sys::truncate_stack
+        end
+        ",
+        get_count_root = AtomicCounter::get_count_root(),
+        counter_prefix = counter_account.id().prefix().as_felt(),
+        counter_suffix = counter_account.id().suffix(),
+    );
+    let tx_script =
+        TransactionScript::compile(read_script, vec![], TransactionKernel::assembler()).unwrap();
+
+    mock_chain
+        .build_tx_context(native_account.id(), &[], &[])
+        .foreign_account_codes(vec![counter_account.code().clone()])
+        .advice_inputs(get_mock_fpi_adv_inputs(vec![&counter_account], &mock_chain))
+        .tx_script(tx_script)
+        .build()
+        .execute()
+        .unwrap();
+}
+
+/// Tests that prices pushed to the standard price oracle by the oracle account can be read by
+/// another account via FPI, and that stale prices are rejected.
+#[test]
+fn test_fpi_standard_price_oracle() {
+    let quote = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_1).unwrap();
+    let pair =
+        PricePair::new(AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET).unwrap(), quote);
+    // a pair sharing the quote asset, whose price must not interfere with the price of the pair
+    let other_pair =
+        PricePair::new(AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_2).unwrap(), quote);
+
+    let mut mock_chain = MockChain::new();
+    let oracle_account = mock_chain.add_from_account_builder(
+        Auth::BasicAuth,
+        AccountBuilder::new(ChaCha20Rng::from_os_rng().random()).with_component(
+            StandardPriceOracle::new([
+                (pair, PriceEntry::new(Felt::new(100), 1000)),
+                (other_pair, PriceEntry::new(Felt::new(200), 900)),
+            ]),
+        ),
+        AccountState::Exists,
+    );
+
+    let push_price_script = |price: u64, timestamp: u32| {
+        let code = format!(
+            "
+            use.miden::contracts::oracles::standard->oracle
+
+            begin
+                padw padw push.0.0
+                push.{timestamp}.{price}
+                push.{pair}
+                # => [PAIR, price, timestamp, pad(10)]
+
+                call.oracle::push_price
+                dropw dropw dropw dropw
+
+                call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+            end
+            ",
+            pair = word_to_masm_push_string(&pair.into()),
+        );
+        TransactionScript::compile(code, vec![], TransactionKernel::assembler()).unwrap()
+    };
+
+    // a price which is not newer than the stored one is rejected
+    let err = mock_chain
+        .build_tx_context(oracle_account.id(), &[], &[])
+        .tx_script(push_price_script(110, 1000))
+        .build()
+        .execute()
+        .unwrap_err();
+    let TransactionExecutorError::TransactionKernelAssertionFailed { source, .. } = err else {
+        panic!("unexpected error")
+    };
+    assert_execution_error!(Err::<(), _>(source), ERR_ACCOUNT_PRICE_ORACLE_STALE_PRICE);
+
+    let executed_transaction = mock_chain
+        .build_tx_context(oracle_account.id(), &[], &[])
+        .tx_script(push_price_script(120, 1010))
+        .build()
+        .execute()
+        .unwrap();
+    let oracle_account = mock_chain.apply_executed_transaction(&executed_transaction);
+    assert_eq!(
+        PriceEntry::from_storage_value(
+            oracle_account.storage().get_map_item(0, pair.storage_key().into()).unwrap()
+        ),
+        Some(PriceEntry::new(Felt::new(120), 1010))
+    );
+    assert_eq!(
+        PriceEntry::from_storage_value(
+            oracle_account
+                .storage()
+                .get_map_item(0, other_pair.storage_key().into())
+                .unwrap()
+        ),
+        Some(PriceEntry::new(Felt::new(200), 900))
+    );
+
+    let native_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    mock_chain.seal_next_block();
+
+    let read_script = format!(
+        "
+        use.std::sys
+
+        use.miden::tx
+
+        begin
+            # pad the stack for the `execute_foreign_procedure` execution
+            padw padw push.0.0.0
+            push.{pair}
+            # => [PAIR, pad(11)]
+
+            push.{read_price_root}
+            push.{oracle_suffix}.{oracle_prefix}
+            # => [oracle_account_id_prefix, oracle_account_id_suffix, READ_PRICE_ROOT, PAIR, pad(11)]
+
+            exec.tx::execute_foreign_procedure
+            # => [price, timestamp, pad(14)]
+
+            push.120 assert_eq push.1010 assert_eq
+
+            # truncate the stack
+            exec.sys::truncate_stack
+        end
+        ",
+        pair = word_to_masm_push_string(&pair.into()),
+        read_price_root = StandardPriceOracle::read_price_root(),
+        oracle_prefix = oracle_account.id().prefix().as_felt(),
+        oracle_suffix = oracle_account.id().suffix(),
+    );
+    let tx_script =
+        TransactionScript::compile(read_script, vec![], TransactionKernel::assembler()).unwrap();
+
+    mock_chain
+        .build_tx_context(native_account.id(), &[], &[])
+        .foreign_account_codes(vec![oracle_account.code().clone()])
+        .advice_inputs(get_mock_fpi_adv_inputs(vec![&oracle_account], &mock_chain))
+        .tx_script(tx_script)
+        .build()
+        .execute()
+        .unwrap();
+}
+
+/// Tests that the FPI stubs generated from the interface of a foreign account invoke the foreign
+/// procedures.
+#[test]
+fn test_fpi_stubs() {
+    let mut mock_chain = MockChain::new();
+    let counter_account = mock_chain.add_from_account_builder(
+        Auth::BasicAuth,
+        AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
+            .with_component(AtomicCounter::new(5)),
+        AccountState::Exists,
+    );
+    let native_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    mock_chain.seal_next_block();
+
+    let get_count = ForeignProcedure::new(
+        ProcedureName::new("get_count").unwrap(),
+        AtomicCounter::get_count_root(),
+        0,
+        1,
+    )
+    .unwrap();
+    let stubs = AccountInterface::from(&counter_account)
+        .build_fpi_stubs(LibraryPath::new("counter_fpi::atomic").unwrap(), &[get_count])
+        .unwrap();
+    let linker = TransactionKernel::library_linker()
+        .with_library(LibraryNamespace::new("counter_fpi").unwrap(), stubs.library().clone())
+        .unwrap();
+
+    let tx_script = linker
+        .compile_tx_script(
+            "
+            use.counter_fpi::atomic
+
+            begin
+                exec.atomic::get_count
+                # => [count]
+
+                push.5 assert_eq
+            end
+            ",
+            vec![],
+        )
+        .unwrap();
+
+    let tx_context = mock_chain
+        .build_tx_context(native_account.id(), &[], &[])
+        .advice_inputs(get_mock_fpi_adv_inputs(vec![&counter_account], &mock_chain))
+        .tx_script(tx_script.script().clone())
+        .build();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    // the stubs library is linked dynamically, so its code must be loaded into the executor
+    let mut executor = TransactionExecutor::new(tx_context.get_data_store(), None);
+    executor.load_account_code(counter_account.code());
+    executor.load_library(stubs.library());
+
+    executor
+        .execute_transaction(native_account.id(), block_ref, &[], tx_context.tx_args().clone())
+        .unwrap();
+}
+
+#[test]
+fn test_fpi_inputs_builder() {
+    struct MockProvider(Vec<ForeignAccountInputs>);
+
+    #[maybe_async_trait]
+    impl ForeignAccountProvider for MockProvider {
+        #[maybe_async]
+        fn get_foreign_account_inputs(
+            &self,
+            account_id: AccountId,
+            _block_ref: BlockNumber,
+        ) -> Result<ForeignAccountInputs, DataStoreError> {
+            self.0
+                .iter()
+                .find(|inputs| inputs.account_id() == account_id)
+                .cloned()
+                .ok_or(DataStoreError::AccountNotFound(account_id))
+        }
+    }
+
+    let foreign_account_component = AccountComponent::compile(
+        "export.foo push.1 drop end",
+        TransactionKernel::testing_assembler(),
+        vec![AccountStorage::mock_item_0().slot, AccountStorage::mock_item_2().slot],
+    )
+    .unwrap()
+    .with_supports_all_types();
+    let foreign_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
+        .with_component(foreign_account_component)
+        .build_existing()
+        .unwrap();
+    let native_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
+        .with_component(
+            AccountMockComponent::new_with_slots(TransactionKernel::testing_assembler(), vec![])
+                .unwrap(),
+        )
+        .build_existing()
+        .unwrap();
+
+    let mut mock_chain =
+        MockChain::with_accounts(&[native_account.clone(), foreign_account.clone()]);
+    mock_chain.seal_next_block();
+
+    let account_proof = MerklePath::new(
+        mock_chain
+            .accounts()
+            .open(
+                &LeafIndex::<ACCOUNT_TREE_DEPTH>::new(
+                    foreign_account.id().prefix().as_felt().as_int(),
+                )
+                .unwrap(),
+            )
+            .path
+            .into(),
+    );
+    let storage_maps = foreign_account
+        .storage()
+        .slots()
+        .iter()
+        .filter_map(|slot| match slot {
+            StorageSlot::Map(map) => Some(map.clone()),
+            StorageSlot::Value(_) => None,
+        })
+        .collect();
+    let foreign_inputs = ForeignAccountInputs::new(
+        (&foreign_account).into(),
+        foreign_account.storage().get_header(),
+        foreign_account.code().clone(),
+        storage_maps,
+        account_proof,
+    );
+
+    let builder = ForeignAccountInputsBuilder::new(mock_chain.latest_block_header())
+        .with_foreign_accounts([foreign_account.id(), foreign_account.id()]);
+    let fpi_inputs = builder.clone().build(&MockProvider(vec![foreign_inputs.clone()])).unwrap();
+    assert_eq!(fpi_inputs.accounts(), core::slice::from_ref(&foreign_inputs));
+    assert_eq!(
+        fpi_inputs.advice_inputs(),
+        get_mock_fpi_adv_inputs(vec![&foreign_account], &mock_chain)
+    );
+
+    // inputs whose code does not match the account header are rejected
+    let tampered_inputs = ForeignAccountInputs::new(
+        foreign_inputs.account_header().clone(),
+        foreign_inputs.storage_header().clone(),
+        native_account.code().clone(),
+        foreign_inputs.storage_maps().to_vec(),
+        foreign_inputs.account_proof().clone(),
+    );
+    assert_matches!(
+        builder.clone().build(&MockProvider(vec![tampered_inputs])),
+        Err(ForeignAccountInputsError::CodeCommitmentMismatch(account_id))
+            if account_id == foreign_account.id()
+    );
+
+    // inputs of accounts which are not part of the account tree are rejected
+    let stale_inputs = ForeignAccountInputs::new(
+        foreign_inputs.account_header().clone(),
+        foreign_inputs.storage_header().clone(),
+        foreign_inputs.account_code().clone(),
+        foreign_inputs.storage_maps().to_vec(),
+        MerklePath::new(vec![Default::default(); ACCOUNT_TREE_DEPTH as usize]),
+    );
+    assert_matches!(
+        builder.build(&MockProvider(vec![stale_inputs])),
+        Err(ForeignAccountInputsError::InvalidAccountProof(_))
+    );
 }
 
 // HELPER FUNCTIONS
@@ -1,21 +1,33 @@
 use alloc::{collections::BTreeMap, string::String};
 
 use miden_lib::{
-    errors::tx_kernel_errors::ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_SENDER_FROM_INCORRECT_CONTEXT,
+    errors::tx_kernel_errors::{
+        ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_METADATA_FROM_INCORRECT_CONTEXT,
+        ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_SENDER_FROM_INCORRECT_CONTEXT,
+        ERR_NOTE_INPUT_INDEX_OUT_OF_BOUNDS, ERR_NOTE_INPUT_NOT_U32,
+        ERR_NOTE_INPUTS_START_INDEX_OUT_OF_BOUNDS, ERR_NOTE_SENDER_MISMATCH,
+    },
     transaction::{TransactionKernel, memory::CURRENT_INPUT_NOTE_PTR},
 };
 use miden_objects::{
     WORD_SIZE,
     account::AccountId,
+    asset::FungibleAsset,
     note::{
-        Note, NoteExecutionHint, NoteExecutionMode, NoteInputs, NoteMetadata, NoteTag, NoteType,
+        LargeNoteInputs, Note, NoteAssets, NoteExecutionHint, NoteExecutionMode, NoteInputs,
+        NoteMetadata, NoteRecipient, NoteScript, NoteTag, NoteType, SenderCommitment,
+    },
+    testing::{
+        account_id::{
+            ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET, ACCOUNT_ID_REGULAR_PRIVATE_ACCOUNT_UPDATABLE_CODE,
+        },
+        note::NoteBuilder,
     },
-    testing::{account_id::ACCOUNT_ID_REGULAR_PRIVATE_ACCOUNT_UPDATABLE_CODE, note::NoteBuilder},
     transaction::TransactionArgs,
 };
 use rand::SeedableRng;
 use rand_chacha::ChaCha20Rng;
-use vm_processor::{EMPTY_WORD, ONE, ProcessState, Word};
+use vm_processor::{AdviceInputs, EMPTY_WORD, ExecutionError, ONE, ProcessState, Word};
 
 use super::{Felt, Process, ZERO, word_to_masm_push_string};
 use crate::{
@@ -77,10 +89,69 @@ fn test_get_sender() {
     let process = tx_context.execute_code(code).unwrap();
 
     let sender = tx_context.input_notes().get_note(0).note().metadata().sender();
+    let sender = sender.account_id().unwrap();
     assert_eq!(process.stack.get(0), sender.prefix().as_felt());
     assert_eq!(process.stack.get(1), sender.suffix());
 }
 
+#[test]
+fn test_get_metadata() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+
+    // calling get_metadata should return the metadata in the layout used by create_note
+    let code = "
+        use.kernel::prologue
+        use.kernel::note->note_internal
+        use.miden::note
+
+        begin
+            exec.prologue::prepare_transaction
+            exec.note_internal::prepare_note
+            dropw dropw dropw dropw
+            exec.note::get_metadata
+
+            # truncate the stack
+            swapw dropw
+        end
+        ";
+
+    let process = tx_context.execute_code(code).unwrap();
+
+    let metadata = tx_context.input_notes().get_note(0).note().metadata();
+    assert_eq!(process.stack.get(0), Felt::from(metadata.tag()));
+    assert_eq!(process.stack.get(1), metadata.aux());
+    assert_eq!(process.stack.get(2), Felt::from(metadata.note_type()));
+    assert_eq!(process.stack.get(3), Felt::from(metadata.execution_hint()));
+}
+
+#[test]
+fn test_get_metadata_no_note() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let code = "
+        use.kernel::memory
+        use.kernel::prologue
+        use.miden::note
+
+        begin
+            exec.prologue::prepare_transaction
+
+            # force the current input note pointer to 0
+            push.0 exec.memory::set_current_input_note_ptr
+
+            exec.note::get_metadata
+        end
+        ";
+
+    let process = tx_context.execute_code(code);
+
+    assert_execution_error!(
+        process,
+        ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_METADATA_FROM_INCORRECT_CONTEXT
+    );
+}
+
 #[test]
 fn test_get_vault_data() {
     let tx_context = TransactionContextBuilder::with_standard_account(ONE)
@@ -312,6 +383,78 @@ fn test_get_inputs() {
     tx_context.execute_code(&code).unwrap();
 }
 
+#[test]
+fn test_get_inputs_range() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+
+    let note0 = tx_context.input_notes().get_note(0).note();
+    let num_inputs = note0.inputs().num_values() as usize;
+
+    // read the inputs in ranges of 8, including a range starting right after the last input
+    let mut input_assertions = String::new();
+    for start_index in (0..=num_inputs).step_by(8).chain([num_inputs]) {
+        let mut expected = [ZERO; 8];
+        let values = &note0.inputs().values()[start_index..];
+        let len = values.len().min(8);
+        expected[..len].copy_from_slice(&values[..len]);
+
+        input_assertions += &format!(
+            "
+            push.{start_index} exec.note::get_inputs_range
+            # => [inputs(8), num_inputs]
+
+            push.{expected_0} assert_eqw
+            push.{expected_1} assert_eqw
+            push.{num_inputs} assert_eq
+            ",
+            expected_0 =
+                word_to_masm_push_string(&[expected[3], expected[2], expected[1], expected[0]]),
+            expected_1 =
+                word_to_masm_push_string(&[expected[7], expected[6], expected[5], expected[4]]),
+        );
+    }
+
+    let code = format!(
+        "
+        use.kernel::prologue
+        use.kernel::note->note_internal
+        use.miden::note
+
+        begin
+            exec.prologue::prepare_transaction
+            exec.note_internal::prepare_note
+            # => [NOTE_SCRIPT_ROOT, NOTE_ARGS]
+
+            dropw dropw
+            # => []
+
+            {input_assertions}
+        end
+        "
+    );
+    tx_context.execute_code(&code).unwrap();
+
+    let code = format!(
+        "
+        use.kernel::prologue
+        use.kernel::note->note_internal
+        use.miden::note
+
+        begin
+            exec.prologue::prepare_transaction
+            exec.note_internal::prepare_note dropw dropw
+
+            push.{start_index} exec.note::get_inputs_range
+        end
+        ",
+        start_index = num_inputs + 1,
+    );
+    let process = tx_context.execute_code(&code);
+    assert_execution_error!(process, ERR_NOTE_INPUTS_START_INDEX_OUT_OF_BOUNDS);
+}
+
 #[test]
 fn test_note_setup() {
     let tx_context = TransactionContextBuilder::with_standard_account(ONE)
@@ -680,3 +823,234 @@ pub fn test_timelock() {
     let tx_context = TransactionContextBuilder::new(account).tx_inputs(tx_inputs).build();
     tx_context.execute().unwrap();
 }
+
+#[test]
+fn test_get_large_inputs() {
+    let mut mock_chain = MockChain::new();
+    let account = mock_chain.add_existing_wallet(Auth::NoAuth, vec![]);
+
+    let large_inputs = LargeNoteInputs::new((0..300).map(|i| Felt::new(i * 3)).collect()).unwrap();
+
+    let code = format!(
+        "
+        use.miden::note
+
+        begin
+            # store the note inputs to memory starting at address 0
+            push.0 exec.note::get_inputs
+            # => [num_inputs, inputs_ptr]
+
+            eq.5 assert drop
+            # => []
+
+            # load the large inputs to memory starting at address 1000
+            push.1000 mem_load.4 padw mem_loadw.0
+            # => [DATA_COMMITMENT, num_values, dest_ptr]
+
+            exec.note::get_large_inputs
+            # => [num_values, dest_ptr]
+
+            push.{num_values} assert_eq drop
+            # => []
+
+            # check the first and last values
+            mem_load.1000 push.{first_value} assert_eq
+            mem_load.1299 push.{last_value} assert_eq
+        end",
+        num_values = large_inputs.num_values(),
+        first_value = large_inputs.values()[0],
+        last_value = large_inputs.values()[299],
+    );
+
+    let note = NoteBuilder::new(account.id(), &mut ChaCha20Rng::from_os_rng())
+        .note_inputs(large_inputs.to_note_inputs(&[]).unwrap().values().to_vec())
+        .unwrap()
+        .code(code)
+        .build(&TransactionKernel::testing_assembler_with_mock_account())
+        .unwrap();
+
+    mock_chain.add_pending_note(note.clone());
+    mock_chain.seal_next_block();
+
+    let tx_inputs = mock_chain.get_transaction_inputs(account.clone(), None, &[note.id()], &[]);
+
+    // executing the note without the large inputs in the advice map fails
+    let tx_context = TransactionContextBuilder::new(account.clone())
+        .tx_inputs(tx_inputs.clone())
+        .build();
+    assert!(tx_context.execute().is_err());
+
+    let tx_context = TransactionContextBuilder::new(account)
+        .tx_inputs(tx_inputs)
+        .advice_inputs(AdviceInputs::default().with_map([large_inputs.to_advice_map_entry()]))
+        .build();
+    tx_context.execute().unwrap();
+}
+
+#[test]
+fn test_is_sender() -> anyhow::Result<()> {
+    let mut mock_chain = MockChain::new();
+    let account = mock_chain.add_existing_wallet(Auth::NoAuth, vec![]);
+    let salt = [Felt::new(3), Felt::new(5), Felt::new(7), Felt::new(9)];
+
+    let code = format!(
+        "
+        use.miden::note
+
+        begin
+            # the salt is ignored for notes which reveal their sender
+            push.{salt} exec.note::is_sender assert
+            padw exec.note::is_sender assert
+        end",
+        salt = word_to_masm_push_string(&salt),
+    );
+    let public_sender_note = NoteBuilder::new(account.id(), ChaCha20Rng::from_seed([1; 32]))
+        .code(code)
+        .build(&TransactionKernel::testing_assembler_with_mock_account())?;
+
+    let code = format!(
+        "
+        use.miden::note
+
+        begin
+            push.{salt} exec.note::is_sender assert
+            padw exec.note::is_sender assertz
+        end",
+        salt = word_to_masm_push_string(&salt),
+    );
+    let script =
+        NoteScript::compile(code, TransactionKernel::testing_assembler_with_mock_account())?;
+    let metadata = NoteMetadata::new_with_sender(
+        SenderCommitment::new(account.id(), salt).into(),
+        NoteType::Public,
+        NoteTag::from_account_id(account.id(), NoteExecutionMode::Local)?,
+        NoteExecutionHint::always(),
+        ZERO,
+    )?;
+    let recipient = NoteRecipient::new(salt, script, NoteInputs::default());
+    let private_sender_note = Note::new(NoteAssets::default(), metadata, recipient);
+
+    mock_chain.add_pending_note(public_sender_note.clone());
+    mock_chain.add_pending_note(private_sender_note.clone());
+    mock_chain.seal_next_block();
+
+    let tx_inputs = mock_chain.get_transaction_inputs(
+        account.clone(),
+        None,
+        &[public_sender_note.id(), private_sender_note.id()],
+        &[],
+    );
+    TransactionContextBuilder::new(account).tx_inputs(tx_inputs).build().execute()?;
+
+    Ok(())
+}
+
+#[test]
+fn test_note_utils() -> anyhow::Result<()> {
+    let mut mock_chain = MockChain::new();
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET)?;
+    let account = mock_chain
+        .add_existing_wallet(Auth::NoAuth, vec![FungibleAsset::new(faucet_id, 100)?.into()]);
+    let large_input = Felt::new(1 << 40);
+
+    let code = format!(
+        "
+        use.miden::note_utils
+
+        begin
+            # words
+            push.1.2.3.4 push.1.2.3.4 exec.note_utils::word_eq assert
+            push.1.2.3.4 push.1.2.3.5 exec.note_utils::word_eq assertz
+            padw exec.note_utils::word_is_empty assert
+            push.0.0.0.1 exec.note_utils::word_is_empty assertz
+
+            # account balance
+            push.100 push.{faucet_id_suffix} push.{faucet_id_prefix}
+            exec.note_utils::has_balance_at_least assert
+            push.101 push.{faucet_id_suffix} push.{faucet_id_prefix}
+            exec.note_utils::has_balance_at_least assertz
+
+            # note inputs
+            push.0 exec.note_utils::get_input_account_id
+            push.{account_id_prefix} assert_eq push.{account_id_suffix} assert_eq
+            push.2 exec.note_utils::get_input_u32 push.7 assert_eq
+            push.5 exec.note_utils::get_input_felt push.{large_input} assert_eq
+            push.2 exec.note_utils::get_input_word push.7.8.9.{large_input} assert_eqw
+
+            # note sender
+            push.{account_id_suffix} push.{account_id_prefix} exec.note_utils::assert_sender_is
+        end",
+        faucet_id_prefix = faucet_id.prefix().as_felt(),
+        faucet_id_suffix = faucet_id.suffix(),
+        account_id_prefix = account.id().prefix().as_felt(),
+        account_id_suffix = account.id().suffix(),
+    );
+
+    let note = NoteBuilder::new(account.id(), ChaCha20Rng::from_seed([2; 32]))
+        .note_inputs([
+            account.id().suffix(),
+            account.id().prefix().as_felt(),
+            Felt::new(7),
+            Felt::new(8),
+            Felt::new(9),
+            large_input,
+        ])?
+        .code(code)
+        .build(&TransactionKernel::testing_assembler_with_mock_account())?;
+
+    mock_chain.add_pending_note(note.clone());
+    mock_chain.seal_next_block();
+
+    let tx_inputs = mock_chain.get_transaction_inputs(account.clone(), None, &[note.id()], &[]);
+    let tx_context = TransactionContextBuilder::new(account).tx_inputs(tx_inputs).build();
+    tx_context.clone().execute()?;
+
+    // the utilities panic on out of bounds inputs, non-u32 inputs and a mismatching sender
+    let sender_mismatch = format!(
+        "push.{} push.{} exec.note_utils::assert_sender_is",
+        faucet_id.suffix(),
+        faucet_id.prefix().as_felt()
+    );
+    let failing_cases = [
+        ("push.6 exec.note_utils::get_input_felt", ERR_NOTE_INPUT_INDEX_OUT_OF_BOUNDS),
+        ("push.3 exec.note_utils::get_input_word", ERR_NOTE_INPUT_INDEX_OUT_OF_BOUNDS),
+        (
+            "push.5 exec.note_utils::get_input_account_id",
+            ERR_NOTE_INPUT_INDEX_OUT_OF_BOUNDS,
+        ),
+        (sender_mismatch.as_str(), ERR_NOTE_SENDER_MISMATCH),
+    ];
+
+    let execute_snippet = |snippet: &str| {
+        let code = format!(
+            "
+            use.kernel::prologue
+            use.kernel::note->note_internal
+            use.miden::note_utils
+
+            begin
+                exec.prologue::prepare_transaction
+                exec.note_internal::prepare_note dropw dropw
+
+                {snippet}
+            end
+            "
+        );
+
+        tx_context.execute_code(&code)
+    };
+
+    for (snippet, expected_err) in failing_cases {
+        assert_execution_error!(execute_snippet(snippet), expected_err);
+    }
+
+    // the u32 check is performed with `u32assert`, which fails with its own error variant
+    let process = execute_snippet("push.5 exec.note_utils::get_input_u32");
+    assert!(matches!(
+        process,
+        Err(ExecutionError::NotU32Value(value, err_code))
+            if value == large_input && err_code == Felt::from(ERR_NOTE_INPUT_NOT_U32)
+    ));
+
+    Ok(())
+}
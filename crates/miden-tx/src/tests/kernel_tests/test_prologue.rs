@@ -4,7 +4,7 @@ use anyhow::Context;
 use miden_lib::{
     account::wallets::BasicWallet,
     errors::tx_kernel_errors::{
-        ERR_ACCOUNT_SEED_ANCHOR_BLOCK_COMMITMENT_DIGEST_MISMATCH,
+        ERR_ACCOUNT_SEED_ANCHOR_BLOCK_COMMITMENT_DIGEST_MISMATCH, ERR_PROLOGUE_INPUT_NOTE_EXPIRED,
         ERR_PROLOGUE_NEW_FUNGIBLE_FAUCET_RESERVED_SLOT_MUST_BE_EMPTY,
         ERR_PROLOGUE_NEW_NON_FUNGIBLE_FAUCET_RESERVED_SLOT_MUST_BE_VALID_EMPY_SMT,
     },
@@ -34,10 +34,12 @@ use miden_objects::{
         AccountProcedureInfo, AccountStorageMode, AccountType, StorageSlot,
     },
     block::{BlockHeader, BlockNumber},
+    note::NoteExecutionHint,
     testing::{
         account_component::AccountMockComponent,
         account_id::{ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET, ACCOUNT_ID_PUBLIC_NON_FUNGIBLE_FAUCET},
         constants::FUNGIBLE_FAUCET_INITIAL_BALANCE,
+        note::NoteBuilder,
     },
     transaction::{TransactionArgs, TransactionScript},
 };
@@ -47,9 +49,9 @@ use vm_processor::{AdviceInputs, Digest, ExecutionError, ONE, Process};
 
 use super::{Felt, Word, ZERO};
 use crate::{
-    assert_execution_error,
+    TransactionExecutor, TransactionExecutorError, TransactionStage, assert_execution_error,
     testing::{
-        MockChain, TransactionContext, TransactionContextBuilder, utils::input_note_data_ptr,
+        Auth, MockChain, TransactionContext, TransactionContextBuilder, utils::input_note_data_ptr,
     },
     tests::kernel_tests::read_root_mem_word,
 };
@@ -693,6 +695,50 @@ fn test_get_blk_timestamp() {
     assert_eq!(process.stack.get(0), tx_context.tx_inputs().block_header().timestamp().into());
 }
 
+#[test]
+fn test_input_note_expiration() -> anyhow::Result<()> {
+    let mut mock_chain = MockChain::new();
+    let account = mock_chain.add_existing_wallet(Auth::NoAuth, vec![]);
+
+    // the note is consumable until two blocks after the block in which it is created
+    let note_expiration_block_num = mock_chain.latest_block_header().block_num() + 3;
+    let note = NoteBuilder::new(account.id(), ChaCha20Rng::from_seed([3; 32]))
+        .note_execution_hint(NoteExecutionHint::until_block(note_expiration_block_num)?)
+        .build(&TransactionKernel::testing_assembler_with_mock_account())?;
+
+    mock_chain.add_pending_note(note.clone());
+    mock_chain.seal_next_block();
+
+    // consuming the note caps the expiration block number of the transaction
+    let tx_inputs = mock_chain.get_transaction_inputs(account.clone(), None, &[note.id()], &[]);
+    let executed_tx = TransactionContextBuilder::new(account.clone())
+        .tx_inputs(tx_inputs)
+        .build()
+        .execute()?;
+    assert_eq!(executed_tx.expiration_block_num(), note_expiration_block_num);
+
+    // consuming the note fails once its expiration block is the reference block
+    mock_chain.seal_next_block();
+    mock_chain.seal_next_block();
+    let tx_inputs = mock_chain.get_transaction_inputs(account.clone(), None, &[note.id()], &[]);
+    let tx_context = TransactionContextBuilder::new(account.clone()).tx_inputs(tx_inputs).build();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    // tracing is required to report the stage in which the transaction kernel failed
+    let err = TransactionExecutor::new(tx_context.get_data_store(), None)
+        .with_tracing()
+        .execute_transaction(account.id(), block_ref, &[note.id()], tx_context.tx_args().clone())
+        .unwrap_err();
+    let TransactionExecutorError::TransactionKernelAssertionFailed { stage, source, .. } = err
+    else {
+        panic!("unexpected error")
+    };
+    assert_eq!(stage, Some(TransactionStage::Prologue));
+    assert_execution_error!(Err::<(), _>(source), ERR_PROLOGUE_INPUT_NOTE_EXPIRED);
+
+    Ok(())
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
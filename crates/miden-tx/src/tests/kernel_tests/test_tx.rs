@@ -3,7 +3,9 @@ use std::string::String;
 
 use miden_lib::{
     errors::tx_kernel_errors::{
-        ERR_NON_FUNGIBLE_ASSET_ALREADY_EXISTS, ERR_TX_NUMBER_OF_OUTPUT_NOTES_EXCEEDS_LIMIT,
+        ERR_NON_FUNGIBLE_ASSET_ALREADY_EXISTS, ERR_NOTE_INVALID_INDEX,
+        ERR_TX_BLOCK_MMR_PROOF_INVALID, ERR_TX_BLOCK_NUMBER_EXCEEDS_REFERENCE_BLOCK_NUMBER,
+        ERR_TX_INPUT_NOTE_INDEX_OUT_OF_BOUNDS, ERR_TX_NUMBER_OF_OUTPUT_NOTES_EXCEEDS_LIMIT,
     },
     transaction::memory::{
         NOTE_MEM_SIZE, NUM_OUTPUT_NOTES_PTR, OUTPUT_NOTE_ASSETS_OFFSET,
@@ -11,23 +13,24 @@ use miden_lib::{
     },
 };
 use miden_objects::{
-    FieldElement,
+    FieldElement, Hasher,
     account::AccountId,
     asset::NonFungibleAsset,
     note::{
         Note, NoteAssets, NoteExecutionHint, NoteExecutionMode, NoteInputs, NoteMetadata,
-        NoteRecipient, NoteTag, NoteType,
+        NoteRecipient, NoteTag, NoteType, SenderCommitment,
     },
     testing::{
         account_id::{ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET, ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_2},
         constants::NON_FUNGIBLE_ASSET_DATA_2,
     },
-    transaction::{OutputNote, OutputNotes},
+    transaction::{InputNotes, OutputNote, OutputNotes, TransactionArgs, TransactionInputs},
 };
 
 use super::{Felt, ONE, ProcessState, Word, ZERO, word_to_masm_push_string};
 use crate::{
-    assert_execution_error, testing::TransactionContextBuilder,
+    assert_execution_error,
+    testing::{Auth, MockChain, TransactionContextBuilder},
     tests::kernel_tests::read_root_mem_word,
 };
 
@@ -115,6 +118,66 @@ fn test_create_note() {
     );
 }
 
+#[test]
+fn test_create_note_with_sender_commitment() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let account_id = tx_context.account().id();
+
+    let recipient = [ZERO, ONE, Felt::new(2), Felt::new(3)];
+    let salt = [Felt::new(7), Felt::new(11), Felt::new(13), Felt::new(17)];
+    let tag = NoteTag::from_account_id(account_id, NoteExecutionMode::Local).unwrap();
+
+    let code = format!(
+        "
+        use.kernel::prologue
+        use.kernel::tx
+
+        begin
+            exec.prologue::prepare_transaction
+
+            push.{salt}
+            push.{recipient}
+            push.{note_execution_hint}
+            push.{PUBLIC_NOTE}
+            push.{aux}
+            push.{tag}
+
+            exec.tx::create_note_with_sender_commitment
+            # => [note_idx]
+
+            # truncate the stack
+            swap drop
+        end
+        ",
+        salt = word_to_masm_push_string(&salt),
+        recipient = word_to_masm_push_string(&recipient),
+        note_execution_hint = Felt::from(NoteExecutionHint::always()),
+        PUBLIC_NOTE = NoteType::Public as u8,
+        aux = Felt::new(27),
+    );
+
+    let process = &tx_context.execute_code(&code).unwrap();
+
+    let expected_note_metadata: Word = NoteMetadata::new_with_sender(
+        SenderCommitment::new(account_id, salt).into(),
+        NoteType::Public,
+        tag,
+        NoteExecutionHint::always(),
+        Felt::new(27),
+    )
+    .unwrap()
+    .into();
+
+    assert_eq!(
+        read_root_mem_word(
+            &process.into(),
+            OUTPUT_NOTE_SECTION_OFFSET + OUTPUT_NOTE_METADATA_OFFSET
+        ),
+        expected_note_metadata,
+        "metadata must contain the sender commitment",
+    );
+}
+
 #[test]
 fn test_create_note_with_invalid_tag() {
     let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
@@ -565,6 +628,102 @@ fn test_create_note_and_add_same_nft_twice() {
     assert_execution_error!(process, ERR_NON_FUNGIBLE_ASSET_ALREADY_EXISTS);
 }
 
+#[test]
+fn test_create_note_and_set_recipient() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+
+    let recipient = [ZERO, ONE, Felt::new(2), Felt::new(3)];
+    let new_recipient = [Felt::new(4), Felt::new(5), Felt::new(6), Felt::new(7)];
+    let tag = Felt::new(4);
+
+    let code = format!(
+        "
+        use.kernel::prologue
+        use.test::account
+        use.miden::contracts::wallets::basic->wallet
+
+        begin
+            exec.prologue::prepare_transaction
+            # => []
+
+            padw padw
+            push.{recipient}
+            push.{execution_hint_always}
+            push.{PUBLIC_NOTE}
+            push.{aux}
+            push.{tag}
+
+            call.wallet::create_note
+            # => [note_idx, pad(15)]
+
+            push.{new_recipient}
+            call.account::set_note_recipient
+            # => [note_idx, pad(15)]
+
+            # truncate the stack
+            repeat.5 swapw dropw end
+        end
+        ",
+        recipient = word_to_masm_push_string(&recipient),
+        new_recipient = word_to_masm_push_string(&new_recipient),
+        PUBLIC_NOTE = NoteType::Public as u8,
+        execution_hint_always = Felt::from(NoteExecutionHint::always()),
+        aux = Felt::new(0),
+        tag = tag,
+    );
+
+    let process = &tx_context.execute_code(&code).unwrap();
+    let process_state: ProcessState = process.into();
+
+    assert_eq!(
+        read_root_mem_word(
+            &process_state,
+            OUTPUT_NOTE_SECTION_OFFSET + OUTPUT_NOTE_RECIPIENT_OFFSET
+        ),
+        new_recipient,
+        "recipient must be replaced by the new recipient",
+    );
+
+    assert_eq!(
+        process_state.get_stack_item(0),
+        ZERO,
+        "top item on the stack is the index to the output note"
+    );
+}
+
+#[test]
+fn test_set_recipient_of_nonexistent_note() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+
+    let recipient = [ZERO, ONE, Felt::new(2), Felt::new(3)];
+
+    let code = format!(
+        "
+        use.kernel::prologue
+        use.test::account
+
+        begin
+            exec.prologue::prepare_transaction
+            # => []
+
+            # no output note exists yet, so index 0 is invalid
+            padw padw push.0.0.0 push.0
+            push.{recipient}
+            call.account::set_note_recipient
+            # => [note_idx, pad(15)]
+
+            # truncate the stack
+            swapdw dropw dropw
+        end
+        ",
+        recipient = word_to_masm_push_string(&recipient),
+    );
+
+    let process = tx_context.execute_code(&code);
+
+    assert_execution_error!(process, ERR_NOTE_INVALID_INDEX);
+}
+
 #[test]
 fn test_build_recipient_hash() {
     let tx_context = TransactionContextBuilder::with_standard_account(ONE)
@@ -695,3 +854,294 @@ fn test_block_procedures() {
         "sixth element on the stack should be equal to the block number"
     );
 }
+
+#[test]
+fn test_get_block_randomness() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+
+    let code = "
+        use.miden::tx
+        use.kernel::prologue
+
+        begin
+            exec.prologue::prepare_transaction
+
+            exec.tx::get_block_randomness
+            # => [RANDOMNESS]
+
+            # truncate the stack
+            swapw dropw
+        end
+        ";
+
+    let process = &tx_context.execute_code(code).unwrap();
+
+    let tx_inputs = tx_context.tx_inputs();
+    let expected = Hasher::merge(&[
+        tx_inputs.block_header().commitment(),
+        tx_inputs.input_notes().commitment(),
+    ]);
+    assert_eq!(process.stack.get_word(0), expected.as_elements());
+}
+
+#[test]
+fn test_get_historical_block_header() {
+    let mut mock_chain = MockChain::new();
+    let account = mock_chain.add_existing_wallet(Auth::NoAuth, vec![]);
+    mock_chain.seal_next_block();
+    mock_chain.seal_next_block();
+    mock_chain.seal_next_block();
+
+    // track a past block in the chain MMR so that its header can be read by the transaction
+    let historical_block = mock_chain.block_header(1);
+    let (ref_block, chain_mmr) =
+        mock_chain.latest_selective_chain_mmr([historical_block.block_num()]);
+    let tx_inputs = TransactionInputs::new(
+        account.clone(),
+        None,
+        ref_block.clone(),
+        chain_mmr,
+        InputNotes::default(),
+    )
+    .unwrap();
+    let tx_context = TransactionContextBuilder::new(account).tx_inputs(tx_inputs).build();
+
+    const DEST_POINTER: u32 = 100000000;
+
+    let code = format!(
+        "
+        use.miden::tx
+        use.kernel::prologue
+
+        begin
+            exec.prologue::prepare_transaction
+
+            # read the header of the historical block
+            push.{DEST_POINTER} push.{historical_block_num}
+            exec.tx::get_historical_block_header
+            # => [BLOCK_COMMITMENT, dest_ptr]
+
+            push.{historical_block_commitment} assert_eqw
+            # => [dest_ptr]
+
+            # the commitment of the reference block can be read as well
+            push.{ref_block_num}
+            exec.tx::get_historical_block_commitment
+            push.{ref_block_commitment} assert_eqw
+            # => [dest_ptr]
+
+            drop
+        end
+        ",
+        historical_block_num = historical_block.block_num(),
+        historical_block_commitment = word_to_masm_push_string(&historical_block.commitment()),
+        ref_block_num = ref_block.block_num(),
+        ref_block_commitment = word_to_masm_push_string(&ref_block.commitment()),
+    );
+
+    let process = &tx_context.execute_code(&code).unwrap();
+    let process_state: ProcessState = process.into();
+
+    assert_eq!(
+        read_root_mem_word(&process_state, DEST_POINTER),
+        historical_block.prev_block_commitment().as_elements(),
+        "the previous block commitment should be stored at the start of the header"
+    );
+    assert_eq!(
+        read_root_mem_word(&process_state, DEST_POINTER + 28),
+        [
+            Felt::from(historical_block.block_num()),
+            Felt::from(historical_block.version()),
+            Felt::from(historical_block.timestamp()),
+            ZERO
+        ],
+        "the block number, version and timestamp should be stored in the eighth word of the header"
+    );
+    assert_eq!(
+        read_root_mem_word(&process_state, DEST_POINTER + 32),
+        historical_block.note_root().as_elements(),
+        "the note root should be stored at the end of the header"
+    );
+
+    // blocks after the reference block cannot be read
+    let code = format!(
+        "
+        use.miden::tx
+        use.kernel::prologue
+
+        begin
+            exec.prologue::prepare_transaction
+
+            push.{block_num}
+            exec.tx::get_historical_block_commitment
+            dropw
+        end
+        ",
+        block_num = ref_block.block_num().as_u32() + 1,
+    );
+
+    let process = tx_context.execute_code(&code);
+    assert_execution_error!(process, ERR_TX_BLOCK_NUMBER_EXCEEDS_REFERENCE_BLOCK_NUMBER);
+}
+
+#[test]
+fn test_get_block_commitment_from_mmr_proof() {
+    let mut mock_chain = MockChain::new();
+    let account = mock_chain.add_existing_wallet(Auth::NoAuth, vec![]);
+    for _ in 0..4 {
+        mock_chain.seal_next_block();
+    }
+
+    // none of the past blocks is tracked by the chain MMR of the transaction inputs
+    let (ref_block, chain_mmr) = mock_chain.latest_selective_chain_mmr([]);
+    let tx_inputs = TransactionInputs::new(
+        account.clone(),
+        None,
+        ref_block.clone(),
+        chain_mmr,
+        InputNotes::default(),
+    )
+    .unwrap();
+
+    // the proofs are opened against the full chain, which includes the reference block
+    let forest = mock_chain.block_chain().forest();
+    let historical_block = mock_chain.block_header(1);
+    let historical_block_proof = mock_chain
+        .block_chain()
+        .open_at(historical_block.block_num().as_usize(), forest)
+        .unwrap();
+    let ref_block_proof = mock_chain
+        .block_chain()
+        .open_at(ref_block.block_num().as_usize(), forest)
+        .unwrap();
+
+    let mut tx_args = TransactionArgs::default();
+    tx_args.add_block_mmr_proof(
+        historical_block.block_num(),
+        historical_block.commitment(),
+        &historical_block_proof.merkle_path,
+    );
+    tx_args.add_block_mmr_proof(
+        ref_block.block_num(),
+        ref_block.commitment(),
+        &ref_block_proof.merkle_path,
+    );
+    let tx_context = TransactionContextBuilder::new(account.clone())
+        .tx_inputs(tx_inputs.clone())
+        .advice_inputs(tx_args.advice_inputs().clone())
+        .build();
+
+    let code = format!(
+        "
+        use.miden::tx
+        use.kernel::prologue
+
+        begin
+            exec.prologue::prepare_transaction
+
+            push.{historical_block_num}
+            exec.tx::get_block_commitment_from_mmr_proof
+            push.{historical_block_commitment} assert_eqw
+
+            push.{ref_block_num}
+            exec.tx::get_block_commitment_from_mmr_proof
+            push.{ref_block_commitment} assert_eqw
+        end
+        ",
+        historical_block_num = historical_block.block_num(),
+        historical_block_commitment = word_to_masm_push_string(&historical_block.commitment()),
+        ref_block_num = ref_block.block_num(),
+        ref_block_commitment = word_to_masm_push_string(&ref_block.commitment()),
+    );
+
+    tx_context.execute_code(&code).unwrap();
+
+    // a proof for a different block commitment is rejected
+    let mut tx_args = TransactionArgs::default();
+    tx_args.add_block_mmr_proof(
+        historical_block.block_num(),
+        mock_chain.block_header(2).commitment(),
+        &historical_block_proof.merkle_path,
+    );
+    let tx_context = TransactionContextBuilder::new(account)
+        .tx_inputs(tx_inputs)
+        .advice_inputs(tx_args.advice_inputs().clone())
+        .build();
+
+    let code = format!(
+        "
+        use.miden::tx
+        use.kernel::prologue
+
+        begin
+            exec.prologue::prepare_transaction
+
+            push.{historical_block_num}
+            exec.tx::get_block_commitment_from_mmr_proof
+            dropw
+        end
+        ",
+        historical_block_num = historical_block.block_num(),
+    );
+
+    let process = tx_context.execute_code(&code);
+    assert_execution_error!(process, ERR_TX_BLOCK_MMR_PROOF_INVALID);
+}
+
+#[test]
+fn test_input_note_procedures() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+
+    let input_notes = tx_context.tx_inputs().input_notes();
+    let note_id_assertions: String = input_notes
+        .iter()
+        .enumerate()
+        .map(|(idx, note)| {
+            format!(
+                "
+                push.{idx} exec.tx::get_input_note_id
+                push.{note_id} assert_eqw
+                ",
+                note_id = word_to_masm_push_string(&note.id().into()),
+            )
+        })
+        .collect();
+
+    let code = format!(
+        "
+        use.kernel::prologue
+        use.miden::tx
+
+        begin
+            exec.prologue::prepare_transaction
+
+            exec.tx::get_num_input_notes
+            push.{num_input_notes} assert_eq
+
+            {note_id_assertions}
+        end
+        ",
+        num_input_notes = input_notes.num_notes(),
+    );
+    tx_context.execute_code(&code).unwrap();
+
+    let code = format!(
+        "
+        use.kernel::prologue
+        use.miden::tx
+
+        begin
+            exec.prologue::prepare_transaction
+
+            push.{note_idx} exec.tx::get_input_note_id
+        end
+        ",
+        note_idx = input_notes.num_notes(),
+    );
+    let process = tx_context.execute_code(&code);
+    assert_execution_error!(process, ERR_TX_INPUT_NOTE_INDEX_OUT_OF_BOUNDS);
+}
@@ -7,17 +7,18 @@ use alloc::{
 
 use ::assembly::{
     LibraryPath,
-    ast::{Module, ModuleKind},
+    ast::{Module, ModuleKind, ProcedureName, QualifiedProcedureName},
 };
+use assert_matches::assert_matches;
 use miden_lib::transaction::TransactionKernel;
 use miden_objects::{
-    Felt, MIN_PROOF_SECURITY_LEVEL, Word,
-    account::{AccountBuilder, AccountComponent, AccountStorage, StorageSlot},
+    Felt, MIN_PROOF_SECURITY_LEVEL, TransactionScriptError, Word,
+    account::{AccountBuilder, AccountCode, AccountComponent, AccountStorage, StorageSlot},
     assembly::DefaultSourceManager,
     asset::{Asset, AssetVault, FungibleAsset, NonFungibleAsset},
     note::{
-        Note, NoteAssets, NoteExecutionHint, NoteExecutionMode, NoteHeader, NoteId, NoteInputs,
-        NoteMetadata, NoteRecipient, NoteScript, NoteTag, NoteType,
+        Note, NoteAssets, NoteExecutionHint, NoteExecutionMode, NoteFile, NoteHeader, NoteId,
+        NoteInputs, NoteMetadata, NoteRecipient, NoteScript, NoteTag, NoteType,
     },
     testing::{
         account_component::AccountMockComponent,
@@ -27,9 +28,13 @@ use miden_objects::{
         },
         constants::{FUNGIBLE_ASSET_AMOUNT, NON_FUNGIBLE_ASSET_DATA},
         note::DEFAULT_NOTE_CODE,
-        storage::{STORAGE_INDEX_0, STORAGE_INDEX_2},
+        storage::{STORAGE_INDEX_0, STORAGE_INDEX_2, STORAGE_LEAVES_2, STORAGE_VALUE_0},
+    },
+    transaction::{
+        ProvenTransaction, ScriptArgType, TransactionArgs, TransactionAuditRecord, TransactionId,
+        TransactionScript, TransactionScriptArgs, TransactionScriptInterface, TransactionTimings,
+        TransactionWitness, TransactionWitnessChunk, TransactionWitnessDecoder,
     },
-    transaction::{ProvenTransaction, TransactionArgs, TransactionScript},
     utils::word_to_masm_push_string,
 };
 use miden_prover::ProvingOptions;
@@ -44,8 +49,11 @@ use super::{
     LocalTransactionProver, TransactionExecutor, TransactionHost, TransactionProver,
     TransactionVerifier,
 };
-use crate::{TransactionMastStore, testing::TransactionContextBuilder};
+use crate::{
+    TransactionMastStore, testing::TransactionContextBuilder, tests::fixtures::TransactionFixture,
+};
 
+pub mod fixtures;
 mod kernel_tests;
 
 // TESTS
@@ -120,6 +128,38 @@ fn transaction_executor_witness() {
     assert_eq!(executed_transaction.output_notes(), &tx_outputs.output_notes);
 }
 
+#[test]
+fn transaction_witness_chunked_serialization() {
+    const MAX_CHUNK_SIZE: usize = 256;
+
+    let executed_transaction = TransactionFixture::with_mock_notes().tx_context.execute().unwrap();
+    let tx_witness = TransactionWitness::from(executed_transaction);
+
+    let mut chunks = Vec::new();
+    tx_witness.write_chunks(MAX_CHUNK_SIZE, |chunk| chunks.push(chunk));
+    assert!(chunks.iter().all(|chunk| chunk.data().len() <= MAX_CHUNK_SIZE));
+    assert_eq!(chunks.iter().filter(|chunk| chunk.is_last()).count(), 4);
+
+    // the concatenated chunks are equal to the witness serialized at once
+    let data: Vec<u8> = chunks.iter().flat_map(|chunk| chunk.data().to_vec()).collect();
+    assert_eq!(data, tx_witness.to_bytes());
+
+    let mut decoder = TransactionWitnessDecoder::new();
+    for chunk in chunks.iter() {
+        assert!(!decoder.is_complete());
+        let chunk = TransactionWitnessChunk::read_from_bytes(&chunk.to_bytes()).unwrap();
+        decoder.push_chunk(chunk).unwrap();
+    }
+    assert!(decoder.is_complete());
+    assert!(decoder.push_chunk(chunks[0].clone()).is_err());
+    assert_eq!(decoder.finish().unwrap(), tx_witness);
+
+    // chunks cannot be decoded out of order
+    let mut decoder = TransactionWitnessDecoder::new();
+    assert!(decoder.push_chunk(chunks.last().unwrap().clone()).is_err());
+    assert!(decoder.finish().is_err());
+}
+
 #[test]
 fn executed_transaction_account_delta_new() {
     let account_assets = AssetVault::mock().assets().collect::<Vec<Asset>>();
@@ -420,6 +460,83 @@ fn test_empty_delta_nonce_update() {
     assert_eq!(executed_transaction.account_delta().storage().maps().len(), 0);
 }
 
+#[test]
+fn executed_transaction_account_delta_omits_reverted_storage_updates() {
+    let account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
+        .with_component(
+            AccountMockComponent::new_with_slots(
+                TransactionKernel::testing_assembler(),
+                AccountStorage::mock_storage_slots(),
+            )
+            .unwrap(),
+        )
+        .build_existing()
+        .unwrap();
+
+    let mut tx_context = TransactionContextBuilder::new(account).build();
+
+    let (existing_map_key, existing_map_value) = STORAGE_LEAVES_2[0];
+    let new_map_key = [Felt::new(14), Felt::new(15), Felt::new(16), Felt::new(17)];
+    let temporary_value = [Felt::new(7), Felt::new(9), Felt::new(11), Felt::new(13)];
+
+    // every storage write below is reverted by a later write, so the storage delta must be empty
+    let tx_script_src = format!(
+        "
+        use.test::account
+
+        begin
+            # update the value slot and restore its initial value
+            push.{TEMPORARY_VALUE} push.{STORAGE_INDEX_0}
+            call.account::set_item dropw dropw
+            push.{STORAGE_VALUE_0} push.{STORAGE_INDEX_0}
+            call.account::set_item dropw dropw
+            # => []
+
+            # update an existing map entry and restore its initial value
+            push.{TEMPORARY_VALUE} push.{EXISTING_MAP_KEY} push.{STORAGE_INDEX_2}
+            call.account::set_map_item dropw dropw dropw
+            push.{EXISTING_MAP_VALUE} push.{EXISTING_MAP_KEY} push.{STORAGE_INDEX_2}
+            call.account::set_map_item dropw dropw dropw
+            # => []
+
+            # insert a new map entry and remove it again
+            push.{TEMPORARY_VALUE} push.{NEW_MAP_KEY} push.{STORAGE_INDEX_2}
+            call.account::set_map_item dropw dropw dropw
+            padw push.{NEW_MAP_KEY} push.{STORAGE_INDEX_2}
+            call.account::set_map_item dropw dropw dropw
+            # => []
+
+            push.1 call.account::incr_nonce drop
+            # => []
+        end
+        ",
+        TEMPORARY_VALUE = word_to_masm_push_string(&temporary_value),
+        STORAGE_VALUE_0 = word_to_masm_push_string(&STORAGE_VALUE_0),
+        EXISTING_MAP_KEY = word_to_masm_push_string(&existing_map_key.into()),
+        EXISTING_MAP_VALUE = word_to_masm_push_string(&existing_map_value),
+        NEW_MAP_KEY = word_to_masm_push_string(&new_map_key),
+    );
+
+    let tx_script = TransactionScript::compile(
+        tx_script_src,
+        [],
+        TransactionKernel::testing_assembler_with_mock_account(),
+    )
+    .unwrap();
+
+    let tx_args = TransactionArgs::new(
+        Some(tx_script),
+        None,
+        tx_context.tx_args().advice_inputs().clone().map,
+    );
+    tx_context.set_tx_args(tx_args);
+
+    let executed_transaction = tx_context.execute().unwrap();
+
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(2)));
+    assert!(executed_transaction.account_delta().storage().is_empty());
+}
+
 #[test]
 fn test_send_note_proc() {
     let tx_context = TransactionContextBuilder::with_standard_account(ONE)
@@ -807,6 +924,87 @@ fn executed_transaction_output_notes() {
     let output_note_3 = executed_transaction.output_notes().get_note(5);
     assert_eq!(expected_output_note_3.id(), output_note_3.id());
     assert_eq!(expected_output_note_3.assets(), output_note_3.assets().unwrap());
+
+    // the details of the private note without recipient details are not known
+    assert!(executed_transaction.output_notes().get_note(3).recipient().is_none());
+    assert_eq!(output_note.recipient(), Some(expected_output_note_2.recipient()));
+    let full_output_note_ids: Vec<NoteId> =
+        executed_transaction.full_output_notes().map(|note| note.id()).collect();
+    assert!(!full_output_note_ids.contains(&output_note_id_3));
+    assert!(full_output_note_ids.contains(&expected_output_note_2.id()));
+    assert!(full_output_note_ids.contains(&expected_output_note_3.id()));
+
+    let note_files = executed_transaction.output_note_files();
+    assert_eq!(note_files.len(), full_output_note_ids.len());
+    let NoteFile::NoteDetails { details, after_block_num, tag, .. } =
+        note_files.last().expect("note files should not be empty")
+    else {
+        panic!("output note file should contain note details");
+    };
+    assert_eq!(details.id(), expected_output_note_3.id());
+    assert_eq!(*after_block_num, block_ref);
+    assert_eq!(*tag, Some(tag3));
+}
+
+#[test]
+fn executed_transaction_audit_record() {
+    let executed_transaction = TransactionFixture::with_mock_notes().tx_context.execute().unwrap();
+
+    let record = executed_transaction.to_audit_record();
+    assert_eq!(record.tx_id(), executed_transaction.id());
+    assert_eq!(record.account_id(), executed_transaction.account_id());
+    assert_eq!(
+        record.final_account_commitment(),
+        executed_transaction.final_account().commitment()
+    );
+    assert_eq!(record.ref_block_num(), executed_transaction.block_header().block_num());
+    assert_eq!(record.account_delta(), executed_transaction.account_delta());
+    // the timings of the stages are not archived
+    let mut measurements = executed_transaction.measurements().clone();
+    measurements.timings = TransactionTimings::default();
+    assert_eq!(record.measurements(), &measurements);
+
+    let input_notes: Vec<_> = record.input_notes().iter().map(|note| note.nullifier()).collect();
+    let expected_input_notes: Vec<_> = executed_transaction
+        .input_notes()
+        .iter()
+        .map(|note| note.note().nullifier())
+        .collect();
+    assert_eq!(input_notes, expected_input_notes);
+    assert_eq!(record.output_notes().len(), executed_transaction.output_notes().num_notes());
+    assert_eq!(
+        record.tx_script_root(),
+        executed_transaction.tx_args().tx_script().map(|script| script.root())
+    );
+
+    // the serialized record is prefixed with the schema version
+    let bytes = record.to_bytes();
+    assert_eq!(bytes[0], TransactionAuditRecord::SCHEMA_VERSION);
+    assert_eq!(TransactionAuditRecord::read_from_bytes(&bytes).unwrap(), record);
+
+    let mut bytes = bytes;
+    bytes[0] = TransactionAuditRecord::SCHEMA_VERSION + 1;
+    assert!(TransactionAuditRecord::read_from_bytes(&bytes).is_err());
+}
+
+#[test]
+fn transaction_id_from_notes_matches_executed_transaction() {
+    let executed_transaction = TransactionFixture::with_mock_notes().tx_context.execute().unwrap();
+
+    let input_notes: Vec<_> = executed_transaction.input_notes().iter().cloned().collect();
+    let output_notes: Vec<_> = executed_transaction
+        .output_notes()
+        .iter()
+        .map(|note| NoteHeader::new(note.id(), *note.metadata()))
+        .collect();
+
+    let tx_id = TransactionId::from_notes(
+        executed_transaction.initial_account().init_commitment(),
+        executed_transaction.final_account().commitment(),
+        &input_notes,
+        &output_notes,
+    );
+    assert_eq!(tx_id, executed_transaction.id());
 }
 
 #[test]
@@ -904,6 +1102,128 @@ fn test_tx_script() {
     );
 }
 
+#[test]
+fn test_tx_script_args() {
+    let fixture = TransactionFixture::with_mock_notes();
+    let account_id = fixture.account_id;
+
+    let asset = FungibleAsset::mock(100);
+    let args = TransactionScriptArgs::builder([
+        ScriptArgType::U32,
+        ScriptArgType::AccountId,
+        ScriptArgType::Asset,
+    ])
+    .u32(7)
+    .unwrap()
+    .account_id(account_id)
+    .unwrap()
+    .asset(asset)
+    .unwrap()
+    .build([Felt::new(9999), Felt::new(8888), Felt::new(9999), Felt::new(8888)])
+    .unwrap();
+
+    let tx_script_src = format!(
+        "
+    begin
+        # load the arguments onto the advice stack
+        push.{key} adv.push_mapval dropw
+
+        # read the arguments in the order of the layout
+        adv_push.1 push.7 assert_eq
+        adv_push.2 push.{prefix} assert_eq push.{suffix} assert_eq
+        padw adv_loadw push.{asset} assert_eqw
+    end
+",
+        key = word_to_masm_push_string(&args.key()),
+        prefix = account_id.prefix().as_felt(),
+        suffix = account_id.suffix(),
+        asset = word_to_masm_push_string(&Word::from(asset)),
+    );
+
+    let tx_script = TransactionScript::compile(
+        tx_script_src,
+        [args.to_input()],
+        TransactionKernel::testing_assembler(),
+    )
+    .unwrap();
+    let tx_args = TransactionArgs::new(
+        Some(tx_script),
+        None,
+        fixture.tx_context.tx_args().advice_inputs().clone().map,
+    );
+
+    fixture
+        .execute(&fixture.executor(), tx_args)
+        .expect("transaction script should read the encoded arguments");
+}
+
+#[test]
+fn transaction_script_is_validated_against_interface() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let account_code = tx_context.account().code();
+    let library = AccountCode::mock_library(TransactionKernel::testing_assembler());
+    let procedure_name = |name: &str| {
+        QualifiedProcedureName::new(
+            LibraryPath::new("test::account").unwrap(),
+            ProcedureName::new(name).unwrap(),
+        )
+    };
+    let interface = TransactionScriptInterface::new()
+        .with_library_procedure(&library, &procedure_name("incr_nonce"))
+        .unwrap();
+
+    let tx_script_code = "
+        use.test::account
+
+        begin
+            push.1 call.account::incr_nonce drop
+        end
+    ";
+    TransactionScript::compile_with_interface(
+        tx_script_code,
+        vec![],
+        TransactionKernel::testing_assembler_with_mock_account(),
+        &interface,
+        account_code,
+    )
+    .unwrap();
+
+    // calling a procedure which was not declared is rejected
+    let tx_script_code = "
+        use.test::account
+
+        begin
+            push.1 call.account::incr_nonce drop
+            push.0.0.0.0.0 call.account::set_item dropw dropw
+        end
+    ";
+    let err = TransactionScript::compile_with_interface(
+        tx_script_code,
+        vec![],
+        TransactionKernel::testing_assembler_with_mock_account(),
+        &interface,
+        account_code,
+    )
+    .unwrap_err();
+    assert_matches!(err, TransactionScriptError::UndeclaredProcedureCall(_));
+
+    // declaring a procedure which the account does not have is rejected
+    let err = TransactionScript::compile_with_interface(
+        tx_script_code,
+        vec![],
+        TransactionKernel::testing_assembler_with_mock_account(),
+        &interface.clone().with_procedure(Digest::default()),
+        account_code,
+    )
+    .unwrap_err();
+    assert_matches!(err, TransactionScriptError::UnknownAccountProcedure(root) if root == Digest::default());
+
+    let err = interface
+        .with_library_procedure(&library, &procedure_name("missing_procedure"))
+        .unwrap_err();
+    assert_matches!(err, TransactionScriptError::ProcedureNotExported(_));
+}
+
 /// Tests that an account can call code in a custom library when loading that library into the
 /// executor.
 ///
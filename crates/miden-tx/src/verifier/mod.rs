@@ -1,9 +1,21 @@
-use miden_lib::transaction::TransactionKernel;
-use miden_objects::{transaction::ProvenTransaction, vm::ProgramInfo};
+use alloc::vec::Vec;
+
+use miden_lib::transaction::{TransactionKernel, VersionedTransactionKernel};
+use miden_objects::{
+    transaction::ProvenTransaction,
+    vm::{ExecutionProof, ProgramInfo, StackInputs, StackOutputs},
+};
 use miden_verifier::verify;
 
 use super::TransactionVerifierError;
 
+/// The minimum number of proofs verified by each thread in [`TransactionVerifier::verify_many`].
+///
+/// Verifying a proof takes only a few milliseconds, so spawning a thread for fewer proofs than
+/// this is not worth the overhead.
+#[cfg(feature = "concurrent")]
+const MIN_PROOFS_PER_THREAD: usize = 4;
+
 // TRANSACTION VERIFIER
 // ================================================================================================
 
@@ -24,6 +36,14 @@ impl TransactionVerifier {
         Self { tx_program_info, proof_security_level }
     }
 
+    /// Sets the version of the transaction kernel against which transactions are verified.
+    ///
+    /// By default, transactions are verified against the latest transaction kernel.
+    pub fn with_kernel(mut self, kernel: VersionedTransactionKernel) -> Self {
+        self.tx_program_info = kernel.program_info();
+        self
+    }
+
     /// Verifies the provided [ProvenTransaction] against the transaction kernel.
     ///
     /// # Errors
@@ -31,28 +51,53 @@ impl TransactionVerifier {
     /// - Transaction verification fails.
     /// - The security level of the verified proof is insufficient.
     pub fn verify(&self, transaction: &ProvenTransaction) -> Result<(), TransactionVerifierError> {
-        // build stack inputs and outputs
-        let stack_inputs = TransactionKernel::build_input_stack(
-            transaction.account_id(),
-            transaction.account_update().initial_state_commitment(),
-            transaction.input_notes().commitment(),
-            transaction.ref_block_commitment(),
-            transaction.ref_block_num(),
-        );
-        let stack_outputs = TransactionKernel::build_output_stack(
-            transaction.account_update().final_state_commitment(),
-            transaction.output_notes().commitment(),
-            transaction.expiration_block_num(),
-        );
+        let (stack_inputs, stack_outputs) = build_stacks(transaction);
+        self.verify_proof(stack_inputs, stack_outputs, transaction.proof())
+    }
+
+    /// Verifies the provided [ProvenTransaction]s against the transaction kernel.
+    ///
+    /// The public inputs of all transactions are built up front, after which the proofs are
+    /// verified against the program info of this verifier. When the `concurrent` feature is
+    /// enabled and enough transactions are provided, the proofs are verified in parallel using the
+    /// available parallelism of the machine. All transactions are verified, even if some of them
+    /// fail verification.
+    ///
+    /// # Errors
+    /// Returns the index and the verification error of every transaction which failed
+    /// verification, in ascending order of the index. See [`Self::verify`] for the reasons a
+    /// transaction may fail verification.
+    pub fn verify_many(
+        &self,
+        transactions: &[ProvenTransaction],
+    ) -> Result<(), Vec<(usize, TransactionVerifierError)>> {
+        let public_inputs: Vec<_> = transactions
+            .iter()
+            .map(|transaction| {
+                let (stack_inputs, stack_outputs) = build_stacks(transaction);
+                (stack_inputs, stack_outputs, transaction.proof())
+            })
+            .collect();
 
+        let failures = self.verify_all(&public_inputs);
+        if failures.is_empty() { Ok(()) } else { Err(failures) }
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Verifies the provided proof against the transaction kernel and the provided stack inputs
+    /// and outputs, and checks the security level of the proof.
+    fn verify_proof(
+        &self,
+        stack_inputs: StackInputs,
+        stack_outputs: StackOutputs,
+        proof: &ExecutionProof,
+    ) -> Result<(), TransactionVerifierError> {
         // verify transaction proof
-        let proof_security_level = verify(
-            self.tx_program_info.clone(),
-            stack_inputs,
-            stack_outputs,
-            transaction.proof().clone(),
-        )
-        .map_err(TransactionVerifierError::TransactionVerificationFailed)?;
+        let proof_security_level =
+            verify(self.tx_program_info.clone(), stack_inputs, stack_outputs, proof.clone())
+                .map_err(TransactionVerifierError::TransactionVerificationFailed)?;
 
         // check security level
         if proof_security_level < self.proof_security_level {
@@ -64,4 +109,160 @@ impl TransactionVerifier {
 
         Ok(())
     }
+
+    /// Verifies the provided proofs and returns the failures.
+    ///
+    /// The proofs are split across as many threads as the machine supports, as long as every
+    /// thread gets at least [`MIN_PROOFS_PER_THREAD`] proofs to verify. Otherwise, the proofs are
+    /// verified on the current thread.
+    #[cfg(feature = "concurrent")]
+    fn verify_all(
+        &self,
+        public_inputs: &[PublicInputs<'_>],
+    ) -> Vec<(usize, TransactionVerifierError)> {
+        let max_threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+        let num_threads = (public_inputs.len() / MIN_PROOFS_PER_THREAD).min(max_threads);
+        if num_threads <= 1 {
+            return self.verify_chunk(public_inputs, 0);
+        }
+
+        let chunk_size = public_inputs.len().div_ceil(num_threads);
+        std::thread::scope(|scope| {
+            let workers: Vec<_> = public_inputs
+                .chunks(chunk_size)
+                .enumerate()
+                .map(|(chunk_idx, chunk)| {
+                    scope.spawn(move || self.verify_chunk(chunk, chunk_idx * chunk_size))
+                })
+                .collect();
+
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().expect("verification thread should not panic"))
+                .collect()
+        })
+    }
+
+    /// Verifies the provided proofs sequentially and returns the failures.
+    #[cfg(not(feature = "concurrent"))]
+    fn verify_all(
+        &self,
+        public_inputs: &[PublicInputs<'_>],
+    ) -> Vec<(usize, TransactionVerifierError)> {
+        self.verify_chunk(public_inputs, 0)
+    }
+
+    /// Verifies a chunk of proofs whose first proof is at index `offset` and returns the failures.
+    fn verify_chunk(
+        &self,
+        public_inputs: &[PublicInputs<'_>],
+        offset: usize,
+    ) -> Vec<(usize, TransactionVerifierError)> {
+        public_inputs
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, (stack_inputs, stack_outputs, proof))| {
+                self.verify_proof(stack_inputs.clone(), stack_outputs.clone(), proof)
+                    .err()
+                    .map(|err| (offset + idx, err))
+            })
+            .collect()
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// The stack inputs, stack outputs and proof of a transaction to be verified.
+type PublicInputs<'tx> = (StackInputs, StackOutputs, &'tx ExecutionProof);
+
+/// Builds the stack inputs and outputs against which the proof of the provided transaction is
+/// verified.
+fn build_stacks(transaction: &ProvenTransaction) -> (StackInputs, StackOutputs) {
+    let stack_inputs = TransactionKernel::build_input_stack(
+        transaction.account_id(),
+        transaction.account_update().initial_state_commitment(),
+        transaction.input_notes().commitment(),
+        transaction.ref_block_commitment(),
+        transaction.ref_block_num(),
+    );
+    let stack_outputs = TransactionKernel::build_output_stack(
+        transaction.account_update().final_state_commitment(),
+        transaction.output_notes().commitment(),
+        transaction.fee(),
+        transaction.expiration_block_num(),
+    );
+
+    (stack_inputs, stack_outputs)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use miden_objects::MIN_PROOF_SECURITY_LEVEL;
+    use vm_processor::Kernel;
+
+    use super::*;
+    use crate::{
+        LocalTransactionProver, ProvingOptions, TransactionProver,
+        tests::fixtures::TransactionFixture,
+    };
+
+    #[test]
+    fn verify_many_reports_failures_with_their_index() {
+        let executed_transaction =
+            TransactionFixture::with_mock_notes().tx_context.execute().unwrap();
+        let proven_transaction =
+            LocalTransactionProver::default().prove(executed_transaction.into()).unwrap();
+
+        let transactions = [proven_transaction.clone(), proven_transaction];
+        let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
+        assert!(verifier.verify_many(&transactions).is_ok());
+
+        // proofs with insufficient security level are reported with their index
+        let failures = TransactionVerifier::new(128).verify_many(&transactions).unwrap_err();
+        assert_eq!(failures.iter().map(|(idx, _)| *idx).collect::<Vec<_>>(), [0, 1]);
+        assert_matches!(
+            failures[0].1,
+            TransactionVerifierError::InsufficientProofSecurityLevel { expected_minimum: 128, .. }
+        );
+    }
+
+    #[test]
+    fn verify_fails_against_mismatched_program_info() {
+        let kernel = TransactionKernel::for_version(0).unwrap();
+        let fixture = TransactionFixture::with_mock_notes();
+        let executor = fixture.executor().with_kernel(kernel);
+        let executed_transaction = fixture.execute(&executor, fixture.tx_args()).unwrap();
+        let proven_transaction = LocalTransactionProver::new(ProvingOptions::default())
+            .with_kernel(kernel)
+            .prove(executed_transaction.into())
+            .unwrap();
+
+        let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL).with_kernel(kernel);
+        assert!(verifier.verify(&proven_transaction).is_ok());
+
+        // a different program committing to the same kernel procedures
+        let program_info = kernel.program_info();
+        let other_program =
+            ProgramInfo::new(kernel.tx_script_main().hash(), program_info.kernel().clone());
+
+        // the same program committing to a different set of kernel procedures
+        let other_kernel = Kernel::new(&program_info.kernel().proc_hashes()[1..]).unwrap();
+        let other_kernel = ProgramInfo::new(*program_info.program_hash(), other_kernel);
+
+        for tx_program_info in [other_program, other_kernel] {
+            let verifier = TransactionVerifier {
+                tx_program_info,
+                proof_security_level: MIN_PROOF_SECURITY_LEVEL,
+            };
+            assert_matches!(
+                verifier.verify(&proven_transaction),
+                Err(TransactionVerifierError::TransactionVerificationFailed(_))
+            );
+        }
+    }
 }
@@ -28,7 +28,12 @@ macro_rules! assert_transaction_executor_error {
         match $execution_result {
             Err(miden_tx::TransactionExecutorError::TransactionProgramExecutionFailed(
                 miden_prover::ExecutionError::FailedAssertion { clk: _, err_code, err_msg: _ }
-            )) => {
+            ))
+            | Err(miden_tx::TransactionExecutorError::TransactionKernelAssertionFailed {
+                source:
+                    miden_prover::ExecutionError::FailedAssertion { clk: _, err_code, err_msg: _ },
+                ..
+            }) => {
                 assert!(
                     err_code == $expected_err_code,
                     "Execution failed on assertion with an unexpected error code (Actual err_code: {}, expected {}).",
@@ -1,17 +1,31 @@
 extern crate alloc;
 
 use miden_lib::{
-    errors::tx_kernel_errors::ERR_FUNGIBLE_ASSET_DISTRIBUTE_WOULD_CAUSE_MAX_SUPPLY_TO_BE_EXCEEDED,
+    account::faucets::{BasicFungibleFaucet, BasicNonFungibleFaucet},
+    errors::tx_kernel_errors::{
+        ERR_FAUCET_NON_FUNGIBLE_ASSET_ALREADY_ISSUED,
+        ERR_FUNGIBLE_ASSET_DISTRIBUTE_AFTER_MINTER_AUTHENTICATION,
+        ERR_FUNGIBLE_ASSET_DISTRIBUTE_BY_MINTER_MINTER_NOT_SET,
+        ERR_FUNGIBLE_ASSET_DISTRIBUTE_WOULD_CAUSE_MAX_SUPPLY_TO_BE_EXCEEDED,
+        ERR_FUNGIBLE_ASSET_MINTER_ACCOUNT_STATE_ALREADY_CHANGED,
+        ERR_NON_FUNGIBLE_ASSET_DISTRIBUTE_BATCH_INVALID_NUM_ASSETS,
+    },
     transaction::TransactionKernel,
 };
 use miden_objects::{
-    Felt,
-    asset::{Asset, FungibleAsset},
+    Felt, Word, ZERO,
+    account::{Account, AccountBuilder, AccountType, AuthSecretKey},
+    asset::{Asset, FungibleAsset, NonFungibleAsset, TokenSymbol},
+    crypto::dsa::rpo_falcon512::SecretKey,
     note::{NoteAssets, NoteExecutionHint, NoteId, NoteMetadata, NoteTag, NoteType},
     transaction::TransactionScript,
     utils::word_to_masm_push_string,
 };
-use miden_tx::testing::{Auth, MockChain};
+use miden_tx::{
+    auth::BasicAuthenticator,
+    testing::{AccountState, Auth, MockChain},
+};
+use rand_chacha::{ChaCha20Rng, rand_core::SeedableRng};
 
 use crate::{
     assert_transaction_executor_error, get_note_with_fungible_asset_and_script,
@@ -153,6 +167,303 @@ fn faucet_contract_mint_fungible_asset_fails_exceeds_max_supply() {
     );
 }
 
+#[test]
+fn prove_faucet_contract_mint_fungible_asset_by_minter_succeeds() {
+    let mut mock_chain = MockChain::new();
+    let mut rng = ChaCha20Rng::from_seed([1u8; 32]);
+    let minter_key = SecretKey::with_rng(&mut rng);
+    let minter_pub_key = minter_key.public_key();
+
+    let faucet = mock_chain.add_from_account_builder(
+        Auth::BasicAuth,
+        AccountBuilder::new([7u8; 32])
+            .account_type(AccountType::FungibleFaucet)
+            .with_component(
+                BasicFungibleFaucet::new(TokenSymbol::new("TST").unwrap(), 10, Felt::new(200))
+                    .unwrap()
+                    .with_minter(minter_pub_key),
+            ),
+        AccountState::Exists,
+    );
+
+    let recipient = [Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)];
+    let tag = NoteTag::for_local_use_case(0, 0).unwrap();
+    let aux = Felt::new(27);
+    let note_execution_hint = NoteExecutionHint::on_block_slot(5, 6, 7);
+    let note_type = NoteType::Private;
+    let amount = Felt::new(100);
+
+    let metadata =
+        NoteMetadata::new(faucet.id(), note_type, tag, note_execution_hint, aux).unwrap();
+    let message = BasicFungibleFaucet::minter_message(
+        faucet.id(),
+        faucet.nonce(),
+        amount.as_int(),
+        &metadata,
+        recipient.into(),
+    );
+    let signature = minter_key.sign(message);
+    assert!(minter_pub_key.verify(message, &signature));
+
+    let tx_script_code = format!(
+        "
+            begin
+                # pad the stack before call
+                push.0.0.0 padw
+
+                push.{recipient}
+                push.{note_execution_hint}
+                push.{note_type}
+                push.{aux}
+                push.{tag}
+                push.{amount}
+                # => [amount, tag, aux, note_type, execution_hint, RECIPIENT, pad(7)]
+
+                call.::miden::contracts::faucets::basic_fungible::auth_tx_minter
+                # => [note_idx, pad(15)]
+
+                # truncate the stack
+                dropw dropw dropw dropw
+            end
+            ",
+        note_type = note_type as u8,
+        recipient = word_to_masm_push_string(&recipient),
+        aux = aux,
+        tag = u32::from(tag),
+        note_execution_hint = Felt::from(note_execution_hint)
+    );
+
+    let tx_script =
+        TransactionScript::compile(tx_script_code, vec![], TransactionKernel::testing_assembler())
+            .unwrap();
+
+    // only the minter signs the transaction, the faucet owner is not involved
+    let minter_authenticator = BasicAuthenticator::<ChaCha20Rng>::new_with_rng(
+        &[(minter_pub_key.into(), AuthSecretKey::RpoFalcon512(minter_key))],
+        rng,
+    );
+    let tx_context = mock_chain
+        .build_tx_context(faucet.id(), &[], &[])
+        .authenticator(Some(minter_authenticator))
+        .tx_script(tx_script)
+        .build();
+
+    let executed_transaction = tx_context.execute().unwrap();
+
+    prove_and_verify_transaction(executed_transaction.clone()).unwrap();
+
+    let fungible_asset: Asset = FungibleAsset::new(faucet.id(), amount.into()).unwrap().into();
+    let assets = NoteAssets::new(vec![fungible_asset]).unwrap();
+    let output_note = executed_transaction.output_notes().get_note(0).clone();
+
+    assert_eq!(output_note.id(), NoteId::new(recipient.into(), assets.commitment()));
+    assert_eq!(output_note.metadata(), &metadata);
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(2)));
+
+    // the initial commitment of the transaction is recorded as the last minter transaction in
+    // slot 3
+    assert_eq!(
+        executed_transaction.account_delta().storage().values().get(&3),
+        Some(&Word::from(faucet.commitment()))
+    );
+}
+
+#[test]
+fn faucet_contract_minter_cannot_distribute_more_than_signed() {
+    let mut mock_chain = MockChain::new();
+    let mut rng = ChaCha20Rng::from_seed([3u8; 32]);
+    let minter_key = SecretKey::with_rng(&mut rng);
+    let minter_pub_key = minter_key.public_key();
+
+    let faucet = mock_chain.add_from_account_builder(
+        Auth::BasicAuth,
+        AccountBuilder::new([8u8; 32])
+            .account_type(AccountType::FungibleFaucet)
+            .with_component(
+                BasicFungibleFaucet::new(TokenSymbol::new("TST").unwrap(), 10, Felt::new(200))
+                    .unwrap()
+                    .with_minter(minter_pub_key),
+            ),
+        AccountState::Exists,
+    );
+
+    let recipient = [Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)];
+    let tag = NoteTag::for_local_use_case(0, 0).unwrap();
+    let note_type = NoteType::Private;
+    let amount = Felt::new(10);
+    let metadata =
+        NoteMetadata::new(faucet.id(), note_type, tag, NoteExecutionHint::always(), ZERO).unwrap();
+    let distribute_args = format!(
+        "
+        push.0.0.0 padw
+        push.{recipient}
+        push.{execution_hint}
+        push.{note_type}
+        push.0
+        push.{tag}
+        push.{amount}
+        ",
+        recipient = word_to_masm_push_string(&recipient),
+        execution_hint = Felt::from(NoteExecutionHint::always()),
+        note_type = note_type as u8,
+        tag = u32::from(tag),
+    );
+
+    // the minter signs a single distribution, after which the owner-only `distribute` is called
+    let tx_script_code = format!(
+        "
+            begin
+                {distribute_args}
+                call.::miden::contracts::faucets::basic_fungible::auth_tx_minter
+                dropw dropw dropw dropw
+
+                {distribute_args}
+                call.::miden::contracts::faucets::basic_fungible::distribute
+                dropw dropw dropw dropw
+            end
+            ",
+    );
+
+    let tx_script =
+        TransactionScript::compile(tx_script_code, vec![], TransactionKernel::testing_assembler())
+            .unwrap();
+
+    let message = BasicFungibleFaucet::minter_message(
+        faucet.id(),
+        faucet.nonce(),
+        amount.as_int(),
+        &metadata,
+        recipient.into(),
+    );
+    assert!(minter_pub_key.verify(message, &minter_key.sign(message)));
+
+    let minter_authenticator = BasicAuthenticator::<ChaCha20Rng>::new_with_rng(
+        &[(minter_pub_key.into(), AuthSecretKey::RpoFalcon512(minter_key))],
+        rng,
+    );
+    let tx = mock_chain
+        .build_tx_context(faucet.id(), &[], &[])
+        .authenticator(Some(minter_authenticator))
+        .tx_script(tx_script)
+        .build()
+        .execute();
+
+    assert_transaction_executor_error!(
+        tx,
+        ERR_FUNGIBLE_ASSET_DISTRIBUTE_AFTER_MINTER_AUTHENTICATION
+    );
+}
+
+#[test]
+fn faucet_contract_mint_fungible_asset_by_minter_fails_without_minter() {
+    let mut mock_chain = MockChain::new();
+    let faucet = mock_chain.add_existing_faucet(Auth::BasicAuth, "TST", 200, None);
+
+    let tx_script_code = format!(
+        "
+            begin
+                push.0.0.0 padw
+                push.{recipient}
+                push.{execution_hint}
+                push.{note_type}
+                push.27 push.4 push.100
+                # => [amount, tag, aux, note_type, execution_hint, RECIPIENT, pad(7)]
+
+                call.::miden::contracts::faucets::basic_fungible::auth_tx_minter
+                dropw dropw dropw dropw
+            end
+            ",
+        note_type = NoteType::Private as u8,
+        execution_hint = Felt::from(NoteExecutionHint::always()),
+        recipient =
+            word_to_masm_push_string(&[Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)]),
+    );
+
+    let tx_script =
+        TransactionScript::compile(tx_script_code, vec![], TransactionKernel::testing_assembler())
+            .unwrap();
+    let tx = mock_chain
+        .build_tx_context(faucet.account().id(), &[], &[])
+        .tx_script(tx_script)
+        .build()
+        .execute();
+
+    assert_transaction_executor_error!(tx, ERR_FUNGIBLE_ASSET_DISTRIBUTE_BY_MINTER_MINTER_NOT_SET);
+}
+
+#[test]
+fn faucet_contract_owner_rotates_minter() {
+    let mut mock_chain = MockChain::new();
+    let faucet = mock_chain.add_existing_faucet(Auth::BasicAuth, "TST", 200, None);
+
+    let mut rng = ChaCha20Rng::from_seed([2u8; 32]);
+    let minter_pub_key: Word = SecretKey::with_rng(&mut rng).public_key().into();
+
+    let set_minter_script = format!(
+        "
+            begin
+                padw padw padw push.{minter_pub_key}
+                call.::miden::contracts::faucets::basic_fungible::set_minter
+                dropw dropw dropw dropw
+
+                call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+            end
+            ",
+        minter_pub_key = word_to_masm_push_string(&minter_pub_key),
+    );
+
+    let tx_script = TransactionScript::compile(
+        set_minter_script,
+        vec![],
+        TransactionKernel::testing_assembler(),
+    )
+    .unwrap();
+    let executed_transaction = mock_chain
+        .build_tx_context(faucet.account().id(), &[], &[])
+        .tx_script(tx_script)
+        .build()
+        .execute()
+        .unwrap();
+
+    // the faucet component is added before the auth component, so its metadata is stored in slot
+    // 1 and the minter public key in slot 2
+    assert_eq!(
+        executed_transaction.account_delta().storage().values().get(&2),
+        Some(&minter_pub_key)
+    );
+
+    // the minter cannot be set once the transaction changed the account state, e.g. via a previous
+    // call to `set_minter` or `auth_tx_minter`
+    let set_minter_twice_script = format!(
+        "
+            begin
+                padw padw padw push.{minter_pub_key}
+                call.::miden::contracts::faucets::basic_fungible::set_minter
+                dropw dropw dropw dropw
+
+                padw padw padw push.{minter_pub_key}
+                call.::miden::contracts::faucets::basic_fungible::set_minter
+                dropw dropw dropw dropw
+            end
+            ",
+        minter_pub_key = word_to_masm_push_string(&minter_pub_key),
+    );
+
+    let tx_script = TransactionScript::compile(
+        set_minter_twice_script,
+        vec![],
+        TransactionKernel::testing_assembler(),
+    )
+    .unwrap();
+    let tx = mock_chain
+        .build_tx_context(faucet.account().id(), &[], &[])
+        .tx_script(tx_script)
+        .build()
+        .execute();
+
+    assert_transaction_executor_error!(tx, ERR_FUNGIBLE_ASSET_MINTER_ACCOUNT_STATE_ALREADY_CHANGED);
+}
+
 // TESTS BURN FUNGIBLE ASSET
 // ================================================================================================
 
@@ -214,3 +525,145 @@ fn prove_faucet_contract_burn_fungible_asset_succeeds() {
     assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(3)));
     assert_eq!(executed_transaction.input_notes().get_note(0).id(), note.id());
 }
+
+// TESTS MINT NON-FUNGIBLE ASSET BATCH
+// ================================================================================================
+
+/// Returns a transaction script distributing the non-fungible assets with the provided data
+/// hashes in a single note to the provided recipient.
+fn distribute_batch_script(data_hashes: &[Word], recipient: Word) -> TransactionScript {
+    distribute_batch_script_with_num_assets(data_hashes, data_hashes.len() as u64, recipient)
+}
+
+/// Returns a transaction script distributing the non-fungible assets with the provided data
+/// hashes to the provided recipient, passing `num_assets` as the number of assets.
+fn distribute_batch_script_with_num_assets(
+    data_hashes: &[Word],
+    num_assets: u64,
+    recipient: Word,
+) -> TransactionScript {
+    let (commitment, advice_data) =
+        BasicNonFungibleFaucet::distribute_batch_advice_inputs(data_hashes).unwrap();
+
+    let tx_script_code = format!(
+        "
+            begin
+                # pad the stack before call
+                push.0.0.0
+
+                push.{recipient}
+                push.{note_execution_hint}
+                push.{note_type}
+                push.27
+                push.{tag}
+                push.{num_assets}
+                push.{commitment}
+                # => [DATA_HASHES_COMMITMENT, num_assets, tag, aux, note_type, execution_hint,
+                #     RECIPIENT, pad(3)]
+
+                call.::miden::contracts::faucets::basic_non_fungible::distribute_batch
+                # => [note_idx, pad(15)]
+
+                call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+                # => [note_idx, pad(15)]
+
+                # truncate the stack
+                dropw dropw dropw dropw
+            end
+            ",
+        recipient = word_to_masm_push_string(&recipient),
+        note_execution_hint = Felt::from(NoteExecutionHint::always()),
+        note_type = NoteType::Private as u8,
+        tag = u32::from(NoteTag::for_local_use_case(0, 0).unwrap()),
+        commitment = word_to_masm_push_string(&commitment.into()),
+    );
+
+    TransactionScript::compile(
+        tx_script_code,
+        [(commitment.into(), advice_data)],
+        TransactionKernel::testing_assembler(),
+    )
+    .unwrap()
+}
+
+/// Adds an existing account with the basic non-fungible faucet component to the mock chain.
+fn add_non_fungible_faucet(mock_chain: &mut MockChain) -> Account {
+    mock_chain.add_from_account_builder(
+        Auth::BasicAuth,
+        AccountBuilder::new([9u8; 32])
+            .account_type(AccountType::NonFungibleFaucet)
+            .with_component(BasicNonFungibleFaucet),
+        AccountState::Exists,
+    )
+}
+
+#[test]
+fn prove_faucet_contract_mint_non_fungible_asset_batch_succeeds() {
+    let mut mock_chain = MockChain::new();
+    let faucet = add_non_fungible_faucet(&mut mock_chain);
+
+    let recipient = [Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)];
+    let data_hashes: Vec<Word> = (1..=3).map(|i| [Felt::new(i); 4]).collect();
+
+    let tx_context = mock_chain
+        .build_tx_context(faucet.id(), &[], &[])
+        .tx_script(distribute_batch_script(&data_hashes, recipient))
+        .build();
+
+    let executed_transaction = tx_context.execute().unwrap();
+
+    prove_and_verify_transaction(executed_transaction.clone()).unwrap();
+
+    let assets: Vec<Asset> = data_hashes
+        .iter()
+        .map(|data_hash| {
+            NonFungibleAsset::from_parts(faucet.id().prefix(), *data_hash).unwrap().into()
+        })
+        .collect();
+    let assets = NoteAssets::new(assets).unwrap();
+    let output_note = executed_transaction.output_notes().get_note(0).clone();
+
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+    assert_eq!(output_note.id(), NoteId::new(recipient.into(), assets.commitment()));
+}
+
+#[test]
+fn faucet_contract_mint_non_fungible_asset_batch_fails_on_duplicate() {
+    let mut mock_chain = MockChain::new();
+    let faucet = add_non_fungible_faucet(&mut mock_chain);
+
+    let recipient = [Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)];
+    let data_hashes = [[Felt::new(1); 4], [Felt::new(2); 4], [Felt::new(1); 4]];
+
+    let tx = mock_chain
+        .build_tx_context(faucet.id(), &[], &[])
+        .tx_script(distribute_batch_script(&data_hashes, recipient))
+        .build()
+        .execute();
+
+    assert_transaction_executor_error!(tx, ERR_FAUCET_NON_FUNGIBLE_ASSET_ALREADY_ISSUED);
+}
+
+#[test]
+fn faucet_contract_mint_non_fungible_asset_batch_fails_on_non_u32_num_assets() {
+    let mut mock_chain = MockChain::new();
+    let faucet = add_non_fungible_faucet(&mut mock_chain);
+
+    let recipient = [Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)];
+    let data_hashes = [[Felt::new(1); 4], [Felt::new(2); 4]];
+
+    let tx = mock_chain
+        .build_tx_context(faucet.id(), &[], &[])
+        .tx_script(distribute_batch_script_with_num_assets(
+            &data_hashes,
+            u32::MAX as u64 + 2,
+            recipient,
+        ))
+        .build()
+        .execute();
+
+    assert_transaction_executor_error!(
+        tx,
+        ERR_NON_FUNGIBLE_ASSET_DISTRIBUTE_BATCH_INVALID_NUM_ASSETS
+    );
+}
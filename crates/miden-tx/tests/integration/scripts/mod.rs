@@ -3,3 +3,4 @@ mod p2id;
 mod p2idr;
 mod send_note;
 mod swap;
+mod templates;
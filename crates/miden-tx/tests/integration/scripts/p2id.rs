@@ -1,13 +1,19 @@
+use std::sync::Arc;
+
+use assert_matches::assert_matches;
 use miden_lib::{
-    errors::note_script_errors::ERR_P2ID_TARGET_ACCT_MISMATCH, note::create_p2id_note,
+    errors::note_script_errors::{ERR_P2ID_TARGET_ACCT_MISMATCH, ERR_P2IDM_TARGET_ACCT_MISMATCH},
+    note::{create_multi_asset_p2id_note, create_p2id_note, create_p2idm_note, memo::PaymentMemo},
     transaction::TransactionKernel,
 };
 use miden_objects::{
-    Felt,
+    Felt, FieldElement, NoteError, Word,
     account::Account,
-    asset::{Asset, AssetVault, FungibleAsset},
+    asset::{Asset, AssetVault, FungibleAsset, NonFungibleAsset},
+    batch::ProposedBatch,
+    block::{BlockNumber, ProposedBlock},
     crypto::rand::RpoRandomCoin,
-    note::NoteType,
+    note::{NoteAssets, NoteTagFilter, NoteType},
     testing::account_id::{
         ACCOUNT_ID_PRIVATE_FUNGIBLE_FAUCET, ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_2,
         ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
@@ -16,7 +22,12 @@ use miden_objects::{
     transaction::{OutputNote, TransactionScript},
     utils::word_to_masm_push_string,
 };
-use miden_tx::testing::{Auth, MockChain};
+use miden_prover::ExecutionError;
+use miden_tx::{
+    DataStoreError, NoteConsumability, NoteConsumptionChecker, TransactionExecutor,
+    TransactionExecutorError,
+    testing::{Auth, MockChain, MockChainStore, ProvingMode},
+};
 
 use crate::{assert_transaction_executor_error, prove_and_verify_transaction};
 
@@ -90,6 +101,49 @@ fn p2id_script_multiple_assets() {
     assert_transaction_executor_error!(executed_transaction_2, ERR_P2ID_TARGET_ACCT_MISMATCH)
 }
 
+/// Consumes a P2IDM note and checks that the memo matches the note.
+#[test]
+fn p2idm_script() {
+    let mut mock_chain = MockChain::new();
+
+    let fungible_asset: Asset = FungibleAsset::mock(100);
+
+    let sender_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let target_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+
+    let memo = PaymentMemo::new("deposit:user-1337");
+    let note = create_p2idm_note(
+        sender_account.id(),
+        target_account.id(),
+        vec![fungible_asset],
+        NoteType::Private,
+        Default::default(),
+        &memo,
+        &mut RpoRandomCoin::new(Word::default()),
+    )
+    .unwrap();
+    mock_chain.add_pending_note(note.clone());
+    mock_chain.seal_next_block();
+
+    // the target can match the memo it received off-chain to the note
+    assert!(memo.verify(note.recipient()));
+    assert!(!PaymentMemo::new("deposit:user-1338").verify(note.recipient()));
+
+    let executed_transaction = mock_chain
+        .build_tx_context(target_account.id(), &[note.id()], &[])
+        .build()
+        .execute()
+        .unwrap();
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(2)));
+
+    // the note can still only be consumed by the target
+    let executed_transaction = mock_chain
+        .build_tx_context(sender_account.id(), &[note.id()], &[])
+        .build()
+        .execute();
+    assert_transaction_executor_error!(executed_transaction, ERR_P2IDM_TARGET_ACCT_MISMATCH);
+}
+
 /// Consumes an existing note with a new account
 #[test]
 fn prove_consume_note_with_new_account() {
@@ -300,3 +354,326 @@ fn test_create_consume_multiple_notes() {
     assert_eq!(account.vault().get_balance(input_note_faucet_id).unwrap(), 111);
     assert_eq!(account.vault().get_balance(FungibleAsset::mock_issuer()).unwrap(), 5);
 }
+
+/// Checks the consumability of a P2ID note by its target and by some other account, without
+/// signing the transactions.
+#[test]
+fn p2id_note_consumability() {
+    let mut mock_chain = MockChain::new();
+
+    let sender_account = mock_chain.add_new_wallet(Auth::BasicAuth);
+    let target_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let other_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+
+    let note = mock_chain
+        .add_p2id_note(
+            sender_account.id(),
+            target_account.id(),
+            &[FungibleAsset::mock(100)],
+            NoteType::Public,
+            None,
+        )
+        .unwrap();
+    mock_chain.seal_next_block();
+
+    let mut check = |account_id, tx_script: Option<TransactionScript>| {
+        let mut tx_context = mock_chain.build_tx_context(account_id, &[note.id()], &[]);
+        if let Some(tx_script) = tx_script {
+            tx_context = tx_context.tx_script(tx_script);
+        }
+        let tx_context = tx_context.build();
+        let block_ref = tx_context.tx_inputs().block_header().block_num();
+        let executor = TransactionExecutor::new(tx_context.get_data_store(), None);
+
+        NoteConsumptionChecker::new(&executor).check_note_consumability(
+            account_id,
+            block_ref,
+            note.id(),
+            tx_context.tx_args().clone(),
+        )
+    };
+
+    let auth_script = TransactionScript::compile(
+        "begin call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512 end",
+        [],
+        TransactionKernel::assembler(),
+    )
+    .unwrap();
+    assert!(check(target_account.id(), Some(auth_script)).unwrap().is_consumable());
+    assert_matches!(
+        check(other_account.id(), None).unwrap(),
+        NoteConsumability::NotConsumable(ExecutionError::FailedAssertion { err_code, .. })
+            if err_code == ERR_P2ID_TARGET_ACCT_MISMATCH
+    );
+
+    // failures of the transaction script are not attributed to the note
+    let failing_script =
+        TransactionScript::compile("begin push.0 assert end", [], TransactionKernel::assembler())
+            .unwrap();
+    assert_matches!(
+        check(target_account.id(), Some(failing_script)),
+        Err(TransactionExecutorError::TransactionProgramExecutionFailed(
+            ExecutionError::FailedAssertion { .. }
+        ))
+    );
+}
+
+/// Creates a private P2ID note carrying multiple assets and consumes it from its details.
+#[test]
+fn multi_asset_p2id_note() {
+    let mut mock_chain = MockChain::new();
+
+    let sender_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let target_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+
+    let fungible_asset_1: Asset = FungibleAsset::mock(100);
+    let fungible_asset_2: Asset =
+        FungibleAsset::new(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_2.try_into().unwrap(), 50)
+            .unwrap()
+            .into();
+    let non_fungible_asset = NonFungibleAsset::mock(&[1, 2, 3]);
+    let assets = vec![fungible_asset_1, fungible_asset_2, non_fungible_asset];
+
+    let mut rng = RpoRandomCoin::new(Word::default());
+    let (note, details) = create_multi_asset_p2id_note(
+        sender_account.id(),
+        target_account.id(),
+        assets.clone(),
+        NoteType::Private,
+        Default::default(),
+        &mut rng,
+    )
+    .unwrap();
+    assert_eq!(details.id(), note.id());
+    assert_eq!(details.assets().iter().copied().collect::<Vec<_>>(), assets);
+
+    mock_chain.add_pending_note(note.clone());
+    mock_chain.seal_next_block();
+
+    let executed_transaction = mock_chain
+        .build_tx_context(target_account.id(), &[note.id()], &[])
+        .build()
+        .execute()
+        .unwrap();
+    assert_eq!(
+        executed_transaction.final_account().vault_root(),
+        AssetVault::new(&assets).unwrap().root()
+    );
+
+    // invalid asset bundles are rejected
+    let create = |assets| {
+        create_multi_asset_p2id_note(
+            sender_account.id(),
+            target_account.id(),
+            assets,
+            NoteType::Private,
+            Default::default(),
+            &mut RpoRandomCoin::new(Word::default()),
+        )
+    };
+    assert_matches!(create(vec![]), Err(NoteError::EmptyAssets));
+    assert_matches!(
+        create(vec![FungibleAsset::mock(0)]),
+        Err(NoteError::ZeroAmountFungibleAsset(_))
+    );
+    assert_matches!(
+        create(vec![fungible_asset_1, FungibleAsset::mock(1)]),
+        Err(NoteError::DuplicateFungibleAsset(_))
+    );
+    let too_many_assets = (0..=NoteAssets::MAX_NUM_ASSETS as u8)
+        .map(|i| NonFungibleAsset::mock(&[i]))
+        .collect();
+    assert_matches!(create(too_many_assets), Err(NoteError::TooManyAssets(_)));
+}
+
+/// Consumes a P2ID note and proves the transaction, the batch and the block the transaction is
+/// included in with a mock chain in full proving mode.
+#[test]
+fn prove_p2id_consumption_in_full_proving_mode() {
+    let mut mock_chain = MockChain::new();
+    mock_chain.set_proving_mode(ProvingMode::Full);
+
+    let fungible_asset = FungibleAsset::mock(100);
+    let sender_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let target_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let note = mock_chain
+        .add_p2id_note(
+            sender_account.id(),
+            target_account.id(),
+            &[fungible_asset],
+            NoteType::Public,
+            None,
+        )
+        .unwrap();
+    mock_chain.seal_next_block();
+
+    let executed_transaction = mock_chain
+        .build_tx_context(target_account.id(), &[note.id()], &[])
+        .build()
+        .execute()
+        .unwrap();
+
+    // proving the same transaction twice returns the cached proven transaction
+    let proven_transaction = mock_chain.prove_transaction(executed_transaction.clone()).unwrap();
+    assert_eq!(proven_transaction.id(), executed_transaction.id());
+    assert_eq!(
+        mock_chain.prove_transaction(executed_transaction.clone()).unwrap(),
+        proven_transaction
+    );
+
+    let block = mock_chain.prove_next_block([executed_transaction]);
+
+    assert_eq!(mock_chain.latest_block_header(), *block.header());
+    assert_eq!(block.created_nullifiers(), &[note.nullifier()]);
+    assert!(!mock_chain.available_notes_map().contains_key(&note.id()));
+
+    let target_account = mock_chain.available_account(target_account.id());
+    assert_eq!(target_account.nonce(), Felt::new(2));
+    assert_eq!(target_account.vault().assets().collect::<Vec<_>>(), vec![fungible_asset]);
+}
+
+/// Consumes a P2ID note on a fork of the chain which is then replaced by a competing fork, and
+/// replays the invalidated transaction on the new canonical chain.
+#[test]
+fn p2id_consumption_is_replayed_after_reorg() {
+    let mut mock_chain = MockChain::new();
+
+    let fungible_asset = FungibleAsset::mock(100);
+    let sender_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let target_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let note = mock_chain
+        .add_p2id_note(
+            sender_account.id(),
+            target_account.id(),
+            &[fungible_asset],
+            NoteType::Public,
+            None,
+        )
+        .unwrap();
+    mock_chain.seal_next_block();
+    let fork_point_block_num = mock_chain.latest_block_header().block_num();
+    let fork_point = mock_chain.snapshot();
+
+    // build a fork with two empty blocks
+    mock_chain.seal_next_block();
+    mock_chain.seal_next_block();
+    let competing_fork = mock_chain.snapshot();
+
+    // build a fork on which the note is consumed
+    mock_chain.restore(fork_point);
+    let executed_transaction = mock_chain
+        .build_tx_context(target_account.id(), &[note.id()], &[])
+        .build()
+        .execute()
+        .unwrap();
+    mock_chain.apply_executed_transaction(&executed_transaction);
+    mock_chain.seal_next_block();
+    assert!(!mock_chain.available_notes_map().contains_key(&note.id()));
+
+    // switching to the competing fork invalidates the transaction
+    let reorg = mock_chain.reorg(competing_fork);
+    assert_eq!(reorg.fork_point(), fork_point_block_num);
+    assert_eq!(reorg.reverted_blocks().len(), 1);
+    assert_eq!(reorg.applied_blocks().len(), 2);
+    assert_eq!(
+        reorg.invalidated_transactions(),
+        vec![(executed_transaction.id(), target_account.id())]
+    );
+    assert!(mock_chain.available_notes_map().contains_key(&note.id()));
+    assert_eq!(mock_chain.available_account(target_account.id()).nonce(), Felt::ONE);
+
+    // the invalidated transaction can be replayed on the new canonical chain
+    let replayed_transaction =
+        mock_chain.replay_transaction(&executed_transaction).build().execute().unwrap();
+    assert_eq!(replayed_transaction.input_notes().get_note(0).id(), note.id());
+    assert_eq!(
+        replayed_transaction.final_account().vault_root(),
+        AssetVault::new(&[fungible_asset]).unwrap().root()
+    );
+}
+
+/// Syncs, consumes and re-syncs a P2ID note, fetching all batch and block inputs from the chain
+/// through a [`MockChainStore`] as a node-based batch and block builder would.
+#[test]
+fn p2id_note_lifecycle_through_mock_chain_store() {
+    let mut mock_chain = MockChain::new();
+
+    let sender_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let target_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let note = mock_chain
+        .add_p2id_note(
+            sender_account.id(),
+            target_account.id(),
+            &[FungibleAsset::mock(100)],
+            NoteType::Public,
+            None,
+        )
+        .unwrap();
+    let note_block = mock_chain.seal_next_block();
+    mock_chain.seal_next_block();
+
+    // the note is found by syncing its tag from genesis
+    let filter = NoteTagFilter::new().with_tag(note.metadata().tag());
+    let sync_update = MockChainStore::new(&mock_chain)
+        .sync_notes(BlockNumber::GENESIS, &filter)
+        .unwrap();
+    assert_eq!(sync_update.block_header(), note_block.header());
+    assert!(!sync_update.is_at_chain_tip());
+    assert_eq!(sync_update.notes().len(), 1);
+    assert_eq!(sync_update.notes()[0].id(), note.id());
+    assert_eq!(
+        Some(sync_update.notes()[0].inclusion_proof()),
+        mock_chain.available_notes_map()[&note.id()].proof()
+    );
+
+    // consume the note in a batch and block built from the inputs provided by the store
+    let executed_transaction = mock_chain
+        .build_tx_context(target_account.id(), &[note.id()], &[])
+        .build()
+        .execute()
+        .unwrap();
+    let proven_transaction = mock_chain.prove_transaction(executed_transaction).unwrap();
+
+    let (batch_reference_block_header, chain_mmr, note_proofs) = MockChainStore::new(&mock_chain)
+        .get_batch_inputs([proven_transaction.ref_block_num()], [])
+        .unwrap()
+        .into_parts();
+    let proposed_batch = ProposedBatch::new(
+        vec![Arc::new(proven_transaction)],
+        batch_reference_block_header,
+        chain_mmr,
+        note_proofs,
+    )
+    .unwrap();
+    let proven_batch = mock_chain.prove_transaction_batch(proposed_batch);
+
+    let block_inputs = MockChainStore::new(&mock_chain)
+        .get_block_inputs(
+            proven_batch.updated_accounts(),
+            proven_batch.created_nullifiers(),
+            [],
+            [proven_batch.reference_block_num()],
+        )
+        .unwrap();
+    let proposed_block =
+        ProposedBlock::new_at(block_inputs, vec![proven_batch], mock_chain.next_block_timestamp())
+            .unwrap();
+    let block = mock_chain.prove_block(proposed_block).unwrap();
+    mock_chain.apply_block(block);
+
+    // the consumed note is still returned when syncing from genesis, but there are no new notes
+    // after the block of the note
+    let store = MockChainStore::new(&mock_chain);
+    assert!(mock_chain.is_nullifier_spent(note.nullifier()));
+    assert_eq!(store.sync_notes(BlockNumber::GENESIS, &filter).unwrap().notes().len(), 1);
+
+    let sync_update = store.sync_notes(note_block.header().block_num(), &filter).unwrap();
+    assert!(sync_update.is_at_chain_tip());
+    assert!(sync_update.notes().is_empty());
+
+    let unknown_block = store.chain_tip().child();
+    assert_matches!(
+        store.get_block_header(Some(unknown_block)),
+        Err(DataStoreError::BlockNotFound(block_num)) if block_num == unknown_block
+    );
+}
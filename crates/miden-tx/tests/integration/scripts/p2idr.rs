@@ -1,13 +1,21 @@
-use miden_lib::errors::note_script_errors::{
-    ERR_P2IDR_RECLAIM_ACCT_IS_NOT_SENDER, ERR_P2IDR_RECLAIM_HEIGHT_NOT_REACHED,
+use assert_matches::assert_matches;
+use miden_lib::{
+    errors::note_script_errors::{
+        ERR_P2IDR_RECLAIM_ACCT_IS_NOT_FALLBACK, ERR_P2IDR_RECLAIM_ACCT_IS_NOT_SENDER,
+        ERR_P2IDR_RECLAIM_HEIGHT_NOT_REACHED, ERR_P2IDR_WRONG_NUMBER_OF_INPUTS,
+    },
+    note::create_p2idr_note_with_fallback,
 };
 use miden_objects::{
-    Felt,
+    Felt, Word,
     account::Account,
     asset::{Asset, AssetVault, FungibleAsset},
+    crypto::rand::RpoRandomCoin,
     note::NoteType,
+    vm::AdviceInputs,
 };
 use miden_tx::testing::{Auth, MockChain};
+use vm_processor::ExecutionError;
 
 use crate::assert_transaction_executor_error;
 
@@ -127,3 +135,145 @@ fn p2idr_script() {
         ERR_P2IDR_RECLAIM_ACCT_IS_NOT_SENDER
     );
 }
+
+#[test]
+fn p2idr_script_with_fallback() {
+    let mut mock_chain = MockChain::new();
+    mock_chain.seal_block(Some(3), None);
+
+    let fungible_asset: Asset = FungibleAsset::mock(100);
+
+    let sender_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let target_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let fallback_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+
+    let mut rng = RpoRandomCoin::new(Word::default());
+
+    // Create a note which is not yet reclaimable and one which is
+    let note_in_time = create_p2idr_note_with_fallback(
+        sender_account.id(),
+        target_account.id(),
+        fallback_account.id(),
+        vec![fungible_asset],
+        NoteType::Public,
+        Default::default(),
+        7.into(),
+        &mut rng,
+    )
+    .unwrap();
+    let note_reclaimable = create_p2idr_note_with_fallback(
+        sender_account.id(),
+        target_account.id(),
+        fallback_account.id(),
+        vec![fungible_asset],
+        NoteType::Public,
+        Default::default(),
+        2.into(),
+        &mut rng,
+    )
+    .unwrap();
+
+    mock_chain.add_pending_note(note_in_time.clone());
+    mock_chain.add_pending_note(note_reclaimable.clone());
+    mock_chain.seal_next_block();
+
+    // --------------------------------------------------------------------------------------------
+    // Case "in time": Only the target account can consume the note.
+    // --------------------------------------------------------------------------------------------
+    let executed_transaction = mock_chain
+        .build_tx_context(target_account.id(), &[note_in_time.id()], &[])
+        .build()
+        .execute()
+        .unwrap();
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(2)));
+
+    // The fallback account tries to consume the note too early
+    let executed_transaction = mock_chain
+        .build_tx_context(fallback_account.id(), &[note_in_time.id()], &[])
+        .build()
+        .execute();
+    assert_transaction_executor_error!(executed_transaction, ERR_P2IDR_RECLAIM_HEIGHT_NOT_REACHED);
+
+    // --------------------------------------------------------------------------------------------
+    // Case "reclaimable": Both target and fallback accounts can consume the note, the sender can
+    // not.
+    // --------------------------------------------------------------------------------------------
+    let executed_transaction = mock_chain
+        .build_tx_context(target_account.id(), &[note_reclaimable.id()], &[])
+        .build()
+        .execute()
+        .unwrap();
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(2)));
+
+    let executed_transaction = mock_chain
+        .build_tx_context(fallback_account.id(), &[note_reclaimable.id()], &[])
+        .build()
+        .execute()
+        .unwrap();
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(2)));
+
+    let executed_transaction = mock_chain
+        .build_tx_context(sender_account.id(), &[note_reclaimable.id()], &[])
+        .build()
+        .execute();
+    assert_transaction_executor_error!(
+        executed_transaction,
+        ERR_P2IDR_RECLAIM_ACCT_IS_NOT_FALLBACK
+    );
+}
+
+#[test]
+fn p2idr_script_with_fallback_rejects_forged_number_of_inputs() {
+    let mut mock_chain = MockChain::new();
+    mock_chain.seal_block(Some(3), None);
+
+    let sender_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let target_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let fallback_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+
+    let note = create_p2idr_note_with_fallback(
+        sender_account.id(),
+        target_account.id(),
+        fallback_account.id(),
+        vec![FungibleAsset::mock(100)],
+        NoteType::Public,
+        Default::default(),
+        2.into(),
+        &mut RpoRandomCoin::new(Word::default()),
+    )
+    .unwrap();
+    mock_chain.add_pending_note(note.clone());
+    mock_chain.seal_next_block();
+
+    // the number of inputs is not bound by the inputs commitment, so a malicious prover can claim
+    // that the note only has 3 inputs without changing the committed (padded) input values
+    let inputs = note.recipient().inputs();
+    let mut forged_inputs = inputs.format_for_advice();
+    forged_inputs[0] = Felt::from(3u32);
+    let forged_advice = AdviceInputs::default().with_map([(inputs.commitment(), forged_inputs)]);
+
+    // the sender executes the note script directly to bypass the advice map collision check of
+    // the transaction executor
+    let tx_context = mock_chain
+        .build_tx_context(sender_account.id(), &[note.id()], &[])
+        .advice_inputs(forged_advice)
+        .build();
+    let code = "
+        use.kernel::prologue
+        use.kernel::note->note_internal
+
+        begin
+            exec.prologue::prepare_transaction
+            exec.note_internal::prepare_note
+            # => [note_script_root_ptr, NOTE_ARGS, pad(11)]
+
+            dyncall
+            dropw dropw dropw dropw
+        end
+        ";
+    assert_matches!(
+        tx_context.execute_code(code),
+        Err(ExecutionError::FailedAssertion { err_code, .. })
+            if err_code == ERR_P2IDR_WRONG_NUMBER_OF_INPUTS
+    );
+}
@@ -1,15 +1,24 @@
-use miden_lib::{account::interface::AccountInterface, transaction::TransactionKernel};
+use assert_matches::assert_matches;
+use miden_lib::{
+    account::{
+        interface::{AccountInterface, AccountInterfaceError},
+        wallets::BasicWalletV2,
+    },
+    transaction::{TransactionKernel, TransactionRequestBuilder, TransactionRequestError},
+};
 use miden_objects::{
-    Felt, ONE,
-    asset::{Asset, FungibleAsset},
+    Felt, ONE, Word,
+    account::Account,
+    asset::{Asset, FungibleAsset, NonFungibleAsset},
     crypto::rand::{FeltRng, RpoRandomCoin},
     note::{
         Note, NoteAssets, NoteExecutionHint, NoteExecutionMode, NoteInputs, NoteMetadata,
         NoteRecipient, NoteScript, NoteTag, NoteType, PartialNote,
     },
+    testing::account_id::ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_2,
     transaction::OutputNote,
 };
-use miden_tx::testing::{Auth, MockChain};
+use miden_tx::testing::{AccountState, Auth, MockChain};
 
 /// Tests the execution of the generated send_note transaction script in case the sending account
 /// has the [`BasicWallet`][wallet] interface.
@@ -109,3 +118,266 @@ fn test_send_note_script_basic_fungible_faucet() {
         .execute()
         .unwrap();
 }
+
+/// Tests the execution of the generated send_note transaction script in case the sending account
+/// has the [`BasicWalletV2`][wallet] interface.
+///
+/// [wallet]: miden_lib::account::interface::AccountComponentInterface::BasicWalletV2
+#[test]
+fn test_send_note_script_basic_wallet_v2() {
+    let mut mock_chain = MockChain::new();
+    let sender_account = mock_chain.add_from_account_builder(
+        Auth::BasicAuth,
+        Account::builder([3; 32])
+            .with_component(BasicWalletV2)
+            .with_assets(vec![FungibleAsset::mock(100), NonFungibleAsset::mock(&[1, 2, 3, 4])]),
+        AccountState::Exists,
+    );
+
+    let sender_account_interface = AccountInterface::from(&sender_account);
+
+    // the note type, tag and execution hint of each note are chosen by the caller
+    let note_script =
+        NoteScript::compile("begin nop end", TransactionKernel::testing_assembler()).unwrap();
+    let mut rng = RpoRandomCoin::new([ONE, Felt::new(2), Felt::new(3), Felt::new(4)]);
+    let notes = [
+        (NoteType::Public, NoteExecutionHint::always(), FungibleAsset::mock(10)),
+        (
+            NoteType::Private,
+            NoteExecutionHint::after_block(5.into()).unwrap(),
+            NonFungibleAsset::mock(&[1, 2, 3, 4]),
+        ),
+    ]
+    .map(|(note_type, execution_hint, asset)| {
+        let tag = NoteTag::from_account_id(sender_account.id(), NoteExecutionMode::Local).unwrap();
+        let metadata =
+            NoteMetadata::new(sender_account.id(), note_type, tag, execution_hint, Felt::new(7))
+                .unwrap();
+        let recipient =
+            NoteRecipient::new(rng.draw_word(), note_script.clone(), NoteInputs::default());
+
+        Note::new(NoteAssets::new(vec![asset]).unwrap(), metadata, recipient)
+    });
+    let partial_notes: Vec<PartialNote> = notes.iter().cloned().map(Into::into).collect();
+
+    let send_note_transaction_script = sender_account_interface
+        .build_send_notes_script(&partial_notes, None, false)
+        .unwrap();
+
+    let executed_transaction = mock_chain
+        .build_tx_context(sender_account.id(), &[], &[])
+        .tx_script(send_note_transaction_script)
+        .expected_notes(notes.iter().cloned().map(OutputNote::Full).collect())
+        .build()
+        .execute()
+        .unwrap();
+
+    assert_eq!(executed_transaction.output_notes().num_notes(), 2);
+    for (index, note) in notes.iter().enumerate() {
+        assert_eq!(executed_transaction.output_notes().get_note(index).id(), note.id());
+        assert_eq!(executed_transaction.output_notes().get_note(index).metadata(), note.metadata());
+    }
+
+    // `send_asset` moves a single asset, so notes with multiple assets cannot be sent
+    let multi_asset_note = Note::new(
+        NoteAssets::new(vec![FungibleAsset::mock(10), NonFungibleAsset::mock(&[1, 2, 3, 4])])
+            .unwrap(),
+        *notes[0].metadata(),
+        notes[0].recipient().clone(),
+    );
+    assert_matches!(
+        sender_account_interface.build_send_notes_script(&[multi_asset_note.into()], None, false),
+        Err(AccountInterfaceError::SendAssetNoteWithoutSingleAsset)
+    );
+}
+
+/// Tests the execution of the generated transaction script which sends a P2ID note for each of
+/// the provided payments.
+#[test]
+fn test_send_p2id_notes_script() {
+    let mut mock_chain = MockChain::new();
+    let sender_account =
+        mock_chain.add_existing_wallet(Auth::BasicAuth, vec![FungibleAsset::mock(100)]);
+    let targets = [
+        mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]),
+        mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]),
+        mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]),
+    ];
+
+    let payments: Vec<_> = targets
+        .iter()
+        .zip([10, 20, 30])
+        .map(|(target, amount)| (target.id(), FungibleAsset::mock(amount)))
+        .collect();
+
+    let sender_account_interface = AccountInterface::from(&sender_account);
+    let (send_notes_transaction_script, note_details) = sender_account_interface
+        .build_send_p2id_notes_script(
+            &payments,
+            NoteType::Private,
+            [ONE, Felt::new(2), Felt::new(3), Felt::new(4)],
+            None,
+            false,
+        )
+        .unwrap();
+    assert_eq!(note_details.len(), payments.len());
+
+    let executed_transaction = mock_chain
+        .build_tx_context(sender_account.id(), &[], &[])
+        .tx_script(send_notes_transaction_script)
+        .build()
+        .execute()
+        .unwrap();
+
+    let output_notes = executed_transaction.output_notes();
+    assert_eq!(output_notes.num_notes(), payments.len());
+    for (output_note, details) in output_notes.iter().zip(note_details.iter()) {
+        assert_eq!(output_note.id(), details.id());
+    }
+}
+
+/// Tests the execution of the generated transaction script which pays an amount using the assets
+/// of several notes and sends the remainder back to the sender in a change note.
+#[test]
+fn test_send_with_change_script() {
+    let mut mock_chain = MockChain::new();
+    let funding_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let sender_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let target_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+
+    let other_asset: Asset =
+        FungibleAsset::new(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_2.try_into().unwrap(), 7)
+            .unwrap()
+            .into();
+    let notes: Vec<Note> = [
+        vec![FungibleAsset::mock(30)],
+        vec![FungibleAsset::mock(50), other_asset],
+        vec![FungibleAsset::mock(20)],
+    ]
+    .into_iter()
+    .map(|assets| {
+        mock_chain
+            .add_p2id_note(
+                funding_account.id(),
+                sender_account.id(),
+                &assets,
+                NoteType::Public,
+                None,
+            )
+            .unwrap()
+    })
+    .collect();
+    mock_chain.seal_next_block();
+
+    let payment = FungibleAsset::mock(70).unwrap_fungible();
+    let sender_account_interface = AccountInterface::from(&sender_account);
+    let (tx_script, input_note_ids, note_details) = sender_account_interface
+        .build_send_with_change_script(
+            &notes,
+            target_account.id(),
+            payment,
+            NoteType::Private,
+            [ONE, Felt::new(2), Felt::new(3), Felt::new(4)],
+            None,
+            false,
+        )
+        .unwrap();
+
+    // the notes with the largest balances are selected
+    assert_eq!(input_note_ids, [notes[1].id(), notes[0].id()]);
+    assert_eq!(note_details.len(), 2);
+    assert_eq!(note_details[0].assets().iter().collect::<Vec<_>>(), [&Asset::from(payment)]);
+    assert_eq!(
+        note_details[1].assets().iter().collect::<Vec<_>>(),
+        [&FungibleAsset::mock(10), &other_asset]
+    );
+
+    let executed_transaction = mock_chain
+        .build_tx_context(sender_account.id(), &input_note_ids, &[])
+        .tx_script(tx_script)
+        .build()
+        .execute()
+        .unwrap();
+
+    let output_notes = executed_transaction.output_notes();
+    assert_eq!(output_notes.num_notes(), 2);
+    for (output_note, details) in output_notes.iter().zip(note_details.iter()) {
+        assert_eq!(output_note.id(), details.id());
+    }
+
+    // the sender does not have enough assets to pay more than the balance of all notes
+    let result = sender_account_interface.build_send_with_change_script(
+        &notes,
+        target_account.id(),
+        FungibleAsset::mock(101).unwrap_fungible(),
+        NoteType::Private,
+        Word::default(),
+        None,
+        false,
+    );
+    assert_matches!(
+        result,
+        Err(AccountInterfaceError::InsufficientFungibleAssetBalance {
+            required: 101,
+            available: 100,
+            ..
+        })
+    );
+}
+
+/// Tests the execution of a transaction built by the [`TransactionRequestBuilder`] which consumes
+/// a note and makes a payment.
+#[test]
+fn test_transaction_request_builder() {
+    let mut mock_chain = MockChain::new();
+    let funding_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let sender_account =
+        mock_chain.add_existing_wallet(Auth::BasicAuth, vec![FungibleAsset::mock(100)]);
+    let target_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+
+    let input_note = mock_chain
+        .add_p2id_note(
+            funding_account.id(),
+            sender_account.id(),
+            &[FungibleAsset::mock(20)],
+            NoteType::Public,
+            None,
+        )
+        .unwrap();
+    mock_chain.seal_next_block();
+
+    let request = TransactionRequestBuilder::new(
+        AccountInterface::from(&sender_account),
+        [ONE, Felt::new(2), Felt::new(3), Felt::new(4)],
+    )
+    .with_input_note(input_note.id(), None)
+    .with_payment(target_account.id(), FungibleAsset::mock(70))
+    .with_expiration_delta(10)
+    .build()
+    .unwrap();
+    assert_eq!(request.input_note_ids(), [input_note.id()]);
+    assert_eq!(request.expected_output_notes().len(), 1);
+    assert_eq!(request.tx_args().expiration_delta(), Some(10));
+
+    let mut tx_context = mock_chain
+        .build_tx_context(sender_account.id(), request.input_note_ids(), &[])
+        .build();
+    tx_context.set_tx_args(request.tx_args().clone());
+    let block_num = tx_context.tx_inputs().block_header().block_num();
+    let executed_transaction = tx_context.execute().unwrap();
+
+    let output_notes = executed_transaction.output_notes();
+    assert_eq!(output_notes.num_notes(), 1);
+    assert_eq!(output_notes.get_note(0).id(), request.expected_output_notes()[0].id());
+    assert_eq!(executed_transaction.expiration_block_num(), block_num + 10);
+
+    // account calls can take at most 16 inputs
+    let result =
+        TransactionRequestBuilder::new(AccountInterface::from(&sender_account), Word::default())
+            .with_account_call("::miden::account::get_id", vec![ONE; 17])
+            .build();
+    assert_matches!(
+        result,
+        Err(TransactionRequestError::TooManyAccountCallInputs { num_inputs: 17, .. })
+    );
+}
@@ -0,0 +1,141 @@
+use miden_lib::transaction::TransactionScriptTemplate;
+use miden_objects::{
+    Felt, Word,
+    asset::{Asset, FungibleAsset},
+    note::{NoteAssets, NoteDetails, NoteType},
+};
+use miden_tx::testing::{Auth, MockChain};
+
+use crate::{get_note_with_fungible_asset_and_script, prove_and_verify_transaction};
+
+const SERIAL_NUM: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+
+#[test]
+fn send_asset_template() {
+    let mut mock_chain = MockChain::new();
+    let sender = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![FungibleAsset::mock(100)]);
+    let target = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+
+    let asset = FungibleAsset::mock(30);
+    let (tx_script, note_details) =
+        TransactionScriptTemplate::send_asset(target.id(), asset, NoteType::Private, SERIAL_NUM)
+            .unwrap();
+    assert_eq!(tx_script.root(), TransactionScriptTemplate::SendAsset.program().hash());
+
+    let executed_transaction = mock_chain
+        .build_tx_context(sender.id(), &[], &[])
+        .tx_script(tx_script)
+        .build()
+        .execute()
+        .unwrap();
+
+    let output_notes = executed_transaction.output_notes();
+    assert_eq!(output_notes.num_notes(), 1);
+    assert_eq!(output_notes.get_note(0).id(), note_details.id());
+    assert_eq!(output_notes.get_note(0).metadata().sender().account_id(), Some(sender.id()));
+
+    prove_and_verify_transaction(executed_transaction).unwrap();
+}
+
+#[test]
+fn mint_template() {
+    let mut mock_chain = MockChain::new();
+    let faucet = mock_chain.add_existing_faucet(Auth::BasicAuth, "TST", 200, None);
+    let target = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let faucet_id = faucet.account().id();
+
+    let (tx_script, note_details) =
+        TransactionScriptTemplate::mint(faucet_id, target.id(), 50, NoteType::Private, SERIAL_NUM)
+            .unwrap();
+    let expected_asset: Asset = FungibleAsset::new(faucet_id, 50).unwrap().into();
+    assert_eq!(note_details.assets(), &NoteAssets::new(vec![expected_asset]).unwrap());
+
+    let executed_transaction = mock_chain
+        .build_tx_context(faucet_id, &[], &[])
+        .tx_script(tx_script)
+        .build()
+        .execute()
+        .unwrap();
+
+    let output_notes = executed_transaction.output_notes();
+    assert_eq!(output_notes.num_notes(), 1);
+    assert_eq!(output_notes.get_note(0).id(), note_details.id());
+
+    prove_and_verify_transaction(executed_transaction).unwrap();
+}
+
+#[test]
+fn burn_template() {
+    let mut mock_chain = MockChain::new();
+    let faucet = mock_chain.add_existing_faucet(Auth::BasicAuth, "TST", 200, Some(100));
+    let asset = FungibleAsset::new(faucet.account().id(), 100).unwrap();
+
+    // the note only carries the asset to the faucet, the asset is burned by the template
+    let note = get_note_with_fungible_asset_and_script(asset, "begin dropw end");
+    mock_chain.add_pending_note(note.clone());
+    mock_chain.seal_next_block();
+
+    let tx_script = TransactionScriptTemplate::burn(asset).unwrap();
+    let executed_transaction = mock_chain
+        .build_tx_context(faucet.account().id(), &[note.id()], &[])
+        .tx_script(tx_script)
+        .build()
+        .execute()
+        .unwrap();
+
+    assert_eq!(executed_transaction.input_notes().get_note(0).id(), note.id());
+    assert_eq!(executed_transaction.output_notes().num_notes(), 0);
+    assert!(executed_transaction.account_delta().nonce().is_some());
+
+    prove_and_verify_transaction(executed_transaction).unwrap();
+}
+
+#[test]
+fn consume_and_forward_template() {
+    let mut mock_chain = MockChain::new();
+    let sender = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let forwarder = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let target = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+
+    let note_ids: Vec<_> = [20, 30]
+        .into_iter()
+        .map(|amount| {
+            mock_chain
+                .add_p2id_note(
+                    sender.id(),
+                    forwarder.id(),
+                    &[FungibleAsset::mock(amount)],
+                    NoteType::Public,
+                    None,
+                )
+                .unwrap()
+                .id()
+        })
+        .collect();
+    mock_chain.seal_next_block();
+
+    let faucet_id = FungibleAsset::mock_issuer();
+    let (tx_script, recipient) = TransactionScriptTemplate::consume_and_forward(
+        faucet_id,
+        target.id(),
+        NoteType::Private,
+        SERIAL_NUM,
+    )
+    .unwrap();
+
+    let executed_transaction = mock_chain
+        .build_tx_context(forwarder.id(), &note_ids, &[])
+        .tx_script(tx_script)
+        .build()
+        .execute()
+        .unwrap();
+
+    // the entire balance received from the consumed notes is forwarded to the target
+    let forwarded_assets = NoteAssets::new(vec![FungibleAsset::mock(50)]).unwrap();
+    let expected_note = NoteDetails::new(forwarded_assets, recipient);
+    let output_notes = executed_transaction.output_notes();
+    assert_eq!(output_notes.num_notes(), 1);
+    assert_eq!(output_notes.get_note(0).id(), expected_note.id());
+
+    prove_and_verify_transaction(executed_transaction).unwrap();
+}